@@ -1,11 +1,18 @@
 //! This module contains any re-exported operations that are used as
 //! activation functions in the layers of the neural network.
 
+pub use crate::operations::uninitialised::elu::Operation as ELU;
+pub use crate::operations::uninitialised::gelu::Operation as GELU;
 pub use crate::operations::uninitialised::linear::Operation as Linear;
 pub use crate::operations::uninitialised::relu::Operation as ReLU;
+pub use crate::operations::uninitialised::selu::Operation as SELU;
 pub use crate::operations::uninitialised::sigmoid::Operation as Sigmoid;
+pub use crate::operations::uninitialised::silu::Operation as SiLU;
 pub use crate::operations::uninitialised::tanh::Operation as Tanh;
 
+/// The Swish activation function, which is another name for `SiLU`.
+pub type Swish = SiLU;
+
 /// This marker trait is used to identify those operations that are
 /// considered activation functions that can then be used to define a layer.
 pub trait ActivationFunction: crate::operations::UninitialisedOperation {}
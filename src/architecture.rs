@@ -0,0 +1,312 @@
+//! This module contains a declarative description of the architecture of a network, so that
+//! the layers of a network can be decided by a configuration file rather than by the type of a
+//! chain of layers. An architecture can be parsed from a short text form, such as
+//! `"dense 300 tanh; dense 10 linear"`, or (with the *serde* feature) deserialized from
+//! any format supported by serde such as JSON or TOML. It's then built into a `DynNetwork`.
+//!
+//! A `DynNetwork` always runs its layers as they are during inference, so layers which make random
+//! decisions during training (such as dropout) are rejected with `training::ConfigError::StochasticLayer`.
+//!
+//! ```
+//! use eidetic::architecture::Architecture;
+//! use eidetic::layers::{Chain, Custom, Input};
+//! use eidetic::operations::UninitialisedOperation;
+//! let architecture = "dense 300 tanh; dense 10 linear"
+//!     .parse::<Architecture>()
+//!     .unwrap();
+//! let network = Input::new(784)
+//!     .chain(Custom::new(architecture.network()))
+//!     .with_seed(42);
+//! ```
+
+use crate::activations::{Linear, ReLU, SiLU, Sigmoid, Tanh, ELU, GELU, SELU};
+use crate::layers::{Dense, DynNetwork};
+use crate::training::ConfigError;
+use crate::{ElementType, Error, Result};
+use core::str::FromStr;
+use std::vec::Vec;
+
+/// The activation function of a layer in an architecture.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Activation {
+    /// The linear (identity) activation function, written as `linear`.
+    Linear,
+    /// The `ReLU` activation function, written as `relu`.
+    ReLU,
+    /// The leaky `ReLU` activation function with the given factor, written as `leaky_relu 0.01`.
+    #[cfg_attr(feature = "serde", serde(rename = "leaky_relu"))]
+    LeakyReLU(ElementType),
+    /// The `ELU` activation function with the given alpha, written as `elu 1.0`.
+    ELU(ElementType),
+    /// The `GELU` activation function, written as `gelu`.
+    GELU,
+    /// The `SELU` activation function, written as `selu`.
+    SELU,
+    /// The sigmoid activation function, written as `sigmoid`.
+    Sigmoid,
+    /// The `SiLU` (swish) activation function, written as `silu` or `swish`.
+    SiLU,
+    /// The tanh activation function, written as `tanh`.
+    Tanh,
+}
+
+impl Activation {
+    /// Parses an activation function from the given words, consuming the name and any argument.
+    fn parse<'a>(words: &mut impl Iterator<Item = &'a str>) -> Result<Self> {
+        let activation = match words.next().ok_or(Error::InvalidText)? {
+            "linear" => Self::Linear,
+            "relu" => Self::ReLU,
+            "leaky_relu" => Self::LeakyReLU(parse_word(words)?),
+            "elu" => Self::ELU(parse_word(words)?),
+            "gelu" => Self::GELU,
+            "selu" => Self::SELU,
+            "sigmoid" => Self::Sigmoid,
+            "silu" | "swish" => Self::SiLU,
+            "tanh" => Self::Tanh,
+            _ => return Err(Error::InvalidText),
+        };
+        Ok(activation)
+    }
+}
+
+/// A single layer of an architecture.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Layer {
+    /// A dense layer with the given number of output neurons and activation function, written
+    /// as `dense 300 tanh`. If the activation function is left out then it's linear.
+    Dense {
+        /// The number of output neurons of the layer.
+        neurons: u16,
+        /// The activation function of the layer.
+        activation: Activation,
+    },
+}
+
+impl FromStr for Layer {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self> {
+        let mut words = text.split_whitespace().peekable();
+        let layer = match words.next().ok_or(Error::InvalidText)? {
+            "dense" => {
+                let neurons = parse_word(&mut words)?;
+                let activation = if words.peek().is_some() {
+                    Activation::parse(&mut words)?
+                } else {
+                    Activation::Linear
+                };
+                Self::Dense {
+                    neurons,
+                    activation,
+                }
+            }
+            "dropout" => return Err(Error::InvalidConfig(ConfigError::StochasticLayer)),
+            _ => return Err(Error::InvalidText),
+        };
+        if words.next().is_some() {
+            return Err(Error::InvalidText);
+        }
+        Ok(layer)
+    }
+}
+
+/// Parses the next of the given words as a number.
+fn parse_word<'a, T: FromStr>(words: &mut impl Iterator<Item = &'a str>) -> Result<T> {
+    words
+        .next()
+        .ok_or(Error::InvalidText)?
+        .parse()
+        .map_err(|_| Error::InvalidText)
+}
+
+/// The architecture of a network, as a list of the layers that follow the input layer.
+///
+/// In the text form, layers are separated by semicolons or new lines, and blank layers are ignored.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Architecture {
+    layers: Vec<Layer>,
+}
+
+impl Architecture {
+    /// Constructs a new architecture with the given layers.
+    #[must_use]
+    pub const fn new(layers: Vec<Layer>) -> Self {
+        Self { layers }
+    }
+
+    /// Gets the layers of the architecture.
+    #[must_use]
+    pub fn layers(&self) -> &[Layer] {
+        &self.layers
+    }
+
+    /// Builds the uninitialised network that's described by this architecture. The network can then
+    /// be wrapped in a `Custom` layer and chained after an `Input` layer to be initialised and trained.
+    #[must_use]
+    pub fn network(&self) -> DynNetwork {
+        self.layers
+            .iter()
+            .fold(DynNetwork::new(), |network, layer| match *layer {
+                Layer::Dense {
+                    neurons,
+                    activation,
+                } => push_dense(network, neurons, activation),
+            })
+    }
+}
+
+impl FromStr for Architecture {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self> {
+        let layers = text
+            .split([';', '\n'])
+            .filter(|layer| !layer.trim().is_empty())
+            .map(str::parse)
+            .collect::<Result<_>>()?;
+        Ok(Self { layers })
+    }
+}
+
+/// Pushes a dense layer with the given activation function onto the network.
+fn push_dense(network: DynNetwork, neurons: u16, activation: Activation) -> DynNetwork {
+    match activation {
+        Activation::Linear => network.push(Dense::new(neurons, Linear::new())),
+        Activation::ReLU => network.push(Dense::new(neurons, ReLU::new())),
+        Activation::LeakyReLU(factor) => network.push(Dense::new(neurons, ReLU::leaky(factor))),
+        Activation::ELU(alpha) => network.push(Dense::new(neurons, ELU::new(alpha))),
+        Activation::GELU => network.push(Dense::new(neurons, GELU::new())),
+        Activation::SELU => network.push(Dense::new(neurons, SELU::new())),
+        Activation::Sigmoid => network.push(Dense::new(neurons, Sigmoid::new())),
+        Activation::SiLU => network.push(Dense::new(neurons, SiLU::new())),
+        Activation::Tanh => network.push(Dense::new(neurons, Tanh::new())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layers::{Chain, Custom, CustomLayer, Input};
+    use crate::operations::{InitialisedOperation, UninitialisedOperation};
+    use crate::tensors::{rank, Tensor};
+
+    #[test]
+    fn test_parse() {
+        // Arrange
+        let expected = Architecture::new(
+            [
+                Layer::Dense {
+                    neurons: 300,
+                    activation: Activation::Tanh,
+                },
+                Layer::Dense {
+                    neurons: 10,
+                    activation: Activation::Linear,
+                },
+                Layer::Dense {
+                    neurons: 1,
+                    activation: Activation::LeakyReLU(0.25),
+                },
+            ]
+            .to_vec(),
+        );
+
+        // Act
+        let architecture = "dense 300 tanh\ndense 10;\n dense 1 leaky_relu 0.25 ;"
+            .parse::<Architecture>()
+            .unwrap();
+
+        // Assert
+        assert_eq!(architecture, expected);
+    }
+
+    #[test]
+    fn test_parse_failure() {
+        // Arrange
+        let texts = [
+            "dense",
+            "dense ten relu",
+            "dense 10 unknown",
+            "dense 10 relu extra",
+            "dense 10 elu",
+            "relu",
+            "conv 3",
+        ];
+
+        // Act
+        let results = texts.map(str::parse::<Architecture>);
+
+        // Assert
+        assert!(results
+            .into_iter()
+            .all(|result| result == Err(Error::InvalidText)));
+    }
+
+    #[test]
+    fn test_parse_dropout() {
+        // Arrange
+        let text = "dense 300 tanh; dropout 0.5; dense 10 linear";
+
+        // Act
+        let result = text.parse::<Architecture>();
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(Error::InvalidConfig(ConfigError::StochasticLayer))
+        );
+    }
+
+    #[test]
+    fn test_network() {
+        // Arrange
+        let architecture = "dense 3 relu; dense 2 sigmoid"
+            .parse::<Architecture>()
+            .unwrap();
+        let parameters = (0u8..17).map(|elem| ElementType::from(elem) / 10.0 - 0.8);
+        let expected = Input::new(2)
+            .chain(Dense::new(3, ReLU::new()))
+            .chain(Dense::new(2, Sigmoid::new()))
+            .with_iter(parameters.clone())
+            .unwrap();
+        let input = Tensor::<rank::Two>::new((2, 2), [1.0, 2.0, -3.0, 0.5]).unwrap();
+
+        // Act
+        let network = architecture.network();
+        let output = Input::new(2)
+            .chain(Custom::new(network.clone()))
+            .with_iter(parameters)
+            .unwrap()
+            .predict(input.clone())
+            .unwrap();
+
+        // Assert
+        assert_eq!(network.len(), 2);
+        assert_eq!(network.output_neuron_count(2), 2);
+        assert_eq!(output, expected.predict(input).unwrap());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize() {
+        // Arrange
+        let json = r#"{"layers": [
+            {"dense": {"neurons": 300, "activation": "tanh"}},
+            {"dense": {"neurons": 10, "activation": {"elu": 1.0}}}
+        ]}"#;
+        let expected = "dense 300 tanh; dense 10 elu 1.0"
+            .parse::<Architecture>()
+            .unwrap();
+
+        // Act
+        let architecture = serde_json::from_str::<Architecture>(json).unwrap();
+
+        // Assert
+        assert_eq!(architecture, expected);
+    }
+}
@@ -0,0 +1,278 @@
+//! This module contains abstractions for feeding data into training without having to materialise
+//! the entire dataset into a single tensor up front. A `Dataset` provides individual samples by
+//! index, and a `DataLoader` gathers those samples into shuffled batches for each epoch, which can
+//! be trained on with `Trainer::train_with_loader`.
+
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+use ndarray::Array;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+/// A sample of a dataset, holding the features that are fed into a network and the targets
+/// that the network should predict for them.
+pub type Sample = (Tensor<rank::One>, Tensor<rank::One>);
+
+/// This trait is implemented by collections of samples that can be trained on. Samples are only
+/// requested when they're needed for a batch, so a dataset can load or generate them lazily.
+pub trait Dataset {
+    /// Gets the number of samples in the dataset.
+    fn len(&self) -> usize;
+
+    /// Checks whether the dataset has no samples.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets the sample at the given index, which is less than the length of the dataset.
+    ///
+    /// # Errors
+    /// `Error` if the sample can't be provided, such as if the index is out of range.
+    fn get(&self, index: usize) -> Result<Sample>;
+}
+
+/// A dataset which is held in memory as a tensor of features and a tensor of targets, where
+/// each row is a sample.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TensorDataset {
+    data: Tensor<rank::Two>,
+    targets: Tensor<rank::Two>,
+}
+
+impl TensorDataset {
+    /// Constructs a new dataset from the given features and targets.
+    ///
+    /// # Errors
+    /// `Error` if the number of rows in the data doesn't match the number of rows in the targets.
+    pub fn new(data: Tensor<rank::Two>, targets: Tensor<rank::Two>) -> Result<Self> {
+        if data.0.nrows() == targets.0.nrows() {
+            Ok(Self { data, targets })
+        } else {
+            Err(Error::BatchRowMismatch {
+                expected: data.0.nrows(),
+                actual: targets.0.nrows(),
+            })
+        }
+    }
+}
+
+impl Dataset for TensorDataset {
+    fn len(&self) -> usize {
+        self.data.0.nrows()
+    }
+
+    fn get(&self, index: usize) -> Result<Sample> {
+        if index < self.len() {
+            let data = Tensor(self.data.0.row(index).to_owned());
+            let targets = Tensor(self.targets.0.row(index).to_owned());
+            Ok((data, targets))
+        } else {
+            Err(Error::InvalidArgument)
+        }
+    }
+}
+
+/// Gathers the samples of a dataset into batches for training, shuffling the order of the
+/// samples each epoch by default. The final batch of an epoch holds any remaining samples so
+/// may be smaller than the batch size.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DataLoader<D> {
+    dataset: D,
+    batch_size: usize,
+    shuffle: bool,
+}
+
+impl<D: Dataset> DataLoader<D> {
+    /// Constructs a new data loader which gathers the samples of the given dataset into shuffled
+    /// batches of the given size. A batch size of zero is treated as one.
+    #[must_use]
+    pub const fn new(dataset: D, batch_size: usize) -> Self {
+        Self {
+            dataset,
+            batch_size,
+            shuffle: true,
+        }
+    }
+
+    /// Sets whether the samples are shuffled each epoch. Without shuffling, batches are
+    /// gathered in the order of the dataset.
+    #[must_use]
+    pub const fn with_shuffle(mut self, shuffle: bool) -> Self {
+        self.shuffle = shuffle;
+        self
+    }
+
+    /// Gets the dataset that samples are gathered from.
+    pub const fn dataset(&self) -> &D {
+        &self.dataset
+    }
+
+    /// Gets an iterator over the batches of features and targets for a single epoch, where the
+    /// samples are shuffled using the given seed.
+    pub fn batches(
+        &self,
+        seed: u64,
+    ) -> impl Iterator<Item = Result<(Tensor<rank::Two>, Tensor<rank::Two>)>> + '_ {
+        let mut indices = (0..self.dataset.len()).collect::<Vec<_>>();
+        if self.shuffle {
+            indices.shuffle(&mut StdRng::seed_from_u64(seed));
+        }
+        let batch_size = self.batch_size.max(1);
+        (0..indices.len()).step_by(batch_size).map(move |start| {
+            let end = indices.len().min(start + batch_size);
+            self.batch(&indices[start..end])
+        })
+    }
+
+    /// Gathers the samples at the given indices into a batch of features and targets.
+    fn batch(&self, indices: &[usize]) -> Result<(Tensor<rank::Two>, Tensor<rank::Two>)> {
+        let mut data = Vec::new();
+        let mut targets = Vec::new();
+        let mut shape: Option<(usize, usize)> = None;
+        for index in indices {
+            let (sample_data, sample_targets) = self.dataset.get(*index)?;
+            let (features, outputs) =
+                *shape.get_or_insert((sample_data.len(), sample_targets.len()));
+            Error::check_size(features, sample_data.len())?;
+            Error::check_size(outputs, sample_targets.len())?;
+            data.extend(sample_data);
+            targets.extend(sample_targets);
+        }
+        let (features, outputs) = shape.unwrap_or_default();
+        Ok((
+            tensor(indices.len(), features, data),
+            tensor(indices.len(), outputs, targets),
+        ))
+    }
+}
+
+/// Constructs a rank 2 tensor with the given number of rows and columns from elements which
+/// are known to be the right length.
+fn tensor(rows: usize, cols: usize, elements: Vec<ElementType>) -> Tensor<rank::Two> {
+    Tensor(Array::from_shape_vec((rows, cols), elements).unwrap()) // unwrapping is safe because every sample was checked to have the same length
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dataset() -> TensorDataset {
+        let data =
+            Tensor::<rank::Two>::new((5, 2), [0.0, 0.0, 1.0, 1.0, 2.0, 2.0, 3.0, 3.0, 4.0, 4.0])
+                .unwrap();
+        let targets = Tensor::<rank::Two>::new((5, 1), [0.0, 1.0, 2.0, 3.0, 4.0]).unwrap();
+        TensorDataset::new(data, targets).unwrap()
+    }
+
+    #[test]
+    fn test_tensor_dataset() {
+        // Arrange
+        let dataset = dataset();
+
+        // Act
+        let sample = dataset.get(3);
+
+        // Assert
+        assert_eq!(dataset.len(), 5);
+        assert_eq!(
+            sample,
+            Ok((
+                Tensor::<rank::One>::new([3.0, 3.0]),
+                Tensor::<rank::One>::new([3.0])
+            ))
+        );
+        assert_eq!(dataset.get(5), Err(Error::InvalidArgument));
+    }
+
+    #[test]
+    fn test_tensor_dataset_row_mismatch() {
+        // Arrange
+        let data = Tensor::<rank::Two>::new((2, 1), [0.0, 1.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((1, 1), [0.0]).unwrap();
+
+        // Act
+        let output = TensorDataset::new(data, targets);
+
+        // Assert
+        assert_eq!(
+            output,
+            Err(Error::BatchRowMismatch {
+                expected: 2,
+                actual: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_batches_without_shuffle() {
+        // Arrange
+        let loader = DataLoader::new(dataset(), 2).with_shuffle(false);
+
+        // Act
+        let batches = loader.batches(42).collect::<Result<Vec<_>>>().unwrap();
+
+        // Assert
+        let targets = batches
+            .iter()
+            .map(|(_, targets)| targets.clone().into_iter().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            targets,
+            [[0.0, 1.0].to_vec(), [2.0, 3.0].to_vec(), [4.0].to_vec()]
+        );
+        assert_eq!(batches[0].0.dim(), (2, 2));
+    }
+
+    #[test]
+    fn test_batches_with_shuffle() {
+        // Arrange
+        let loader = DataLoader::new(dataset(), 2);
+
+        // Act
+        let first = loader.batches(42).collect::<Result<Vec<_>>>().unwrap();
+        let second = loader.batches(42).collect::<Result<Vec<_>>>().unwrap();
+
+        // Assert (the features of each row match the target and every sample is seen once)
+        let mut targets = Vec::new();
+        for (data, batch_targets) in &first {
+            for (row, target) in data.0.rows().into_iter().zip(batch_targets.0.iter()) {
+                assert_eq!(row[0], *target);
+                targets.push(*target);
+            }
+        }
+        targets.sort_by(ElementType::total_cmp);
+        assert_eq!(targets, [0.0, 1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_batches_with_inconsistent_samples() {
+        // Arrange
+        struct Ragged;
+        impl Dataset for Ragged {
+            fn len(&self) -> usize {
+                2
+            }
+
+            fn get(&self, index: usize) -> Result<Sample> {
+                let data = Tensor::<rank::One>::new((0..=index).map(|_| 1.0));
+                Ok((data, Tensor::<rank::One>::new([1.0])))
+            }
+        }
+        let loader = DataLoader::new(Ragged, 2).with_shuffle(false);
+
+        // Act
+        let output = loader.batches(42).next().unwrap();
+
+        // Assert
+        assert_eq!(
+            output,
+            Err(Error::ShapeMismatch {
+                expected: 1,
+                actual: 2
+            })
+        );
+    }
+}
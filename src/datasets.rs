@@ -0,0 +1,355 @@
+//! This module contains loaders for well known datasets that are useful for trying out networks, such as
+//! MNIST and Fashion-MNIST. The dataset is returned as tensors that can be passed straight to the training functions.
+//!
+//! The files of a dataset aren't downloaded by the library, so they need to be downloaded once by hand into a directory
+//! of the caller's choosing. The four gzip compressed files of a dataset (`train-images-idx3-ubyte.gz`,
+//! `train-labels-idx1-ubyte.gz`, `t10k-images-idx3-ubyte.gz` and `t10k-labels-idx1-ubyte.gz`) can be downloaded from
+//! the URL given by `Source::url`, and then decompressed into the directory (such as with `gzip --decompress`).
+//!
+//! ```no_run
+//! use eidetic::datasets::load;
+//! let dataset = load("data/mnist").unwrap();
+//! assert_eq!(dataset.training().images().dim(), (60_000, 784));
+//! assert_eq!(dataset.testing().labels().dim(), (10_000, 10));
+//! ```
+
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use std::fs::{metadata, read};
+use std::path::Path;
+use std::vec::Vec;
+
+/// The number of classes that the labels of the datasets are one-hot encoded into.
+const CLASSES: usize = 10;
+
+/// The names of the files of the training images, training labels, testing images and testing labels,
+/// which are the same for every source.
+const FILES: [&str; 4] = [
+    "train-images-idx3-ubyte",
+    "train-labels-idx1-ubyte",
+    "t10k-images-idx3-ubyte",
+    "t10k-labels-idx1-ubyte",
+];
+
+/// The sizes in bytes of the uncompressed files of the training images, training labels, testing images
+/// and testing labels, which are the same for every source.
+const SIZES: [u64; 4] = [47_040_016, 60_008, 7_840_016, 10_008];
+
+/// The datasets that can be loaded. Each is made up of 28x28 greyscale images which are labelled
+/// with one of ten classes, split into 60,000 training images and 10,000 testing images.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Source {
+    /// The MNIST dataset of handwritten digits.
+    Mnist,
+    /// The Fashion-MNIST dataset of images of clothing, which is a drop in replacement for MNIST
+    /// that's harder to classify.
+    FashionMnist,
+}
+
+impl Source {
+    /// Gets the URL that the compressed files of the dataset can be downloaded from, by appending
+    /// the name of each file.
+    #[must_use]
+    pub const fn url(self) -> &'static str {
+        match self {
+            Self::Mnist => "https://ossci-datasets.s3.amazonaws.com/mnist/",
+            Self::FashionMnist => {
+                "https://raw.githubusercontent.com/zalandoresearch/fashion-mnist/master/data/fashion/"
+            }
+        }
+    }
+}
+
+/// A split of a dataset, holding a row for each image with its pixels scaled to between 0 and 1, and
+/// a row for each label which is one-hot encoded.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Split {
+    images: Tensor<rank::Two>,
+    labels: Tensor<rank::Two>,
+}
+
+impl Split {
+    /// Gets the images of the split, with a row for each image.
+    #[must_use]
+    pub const fn images(&self) -> &Tensor<rank::Two> {
+        &self.images
+    }
+
+    /// Gets the one-hot encoded labels of the split, with a row for each image.
+    #[must_use]
+    pub const fn labels(&self) -> &Tensor<rank::Two> {
+        &self.labels
+    }
+
+    /// Converts the split into its images and labels.
+    #[must_use]
+    pub fn into_tensors(self) -> (Tensor<rank::Two>, Tensor<rank::Two>) {
+        (self.images, self.labels)
+    }
+}
+
+/// A dataset that has been loaded, which is split into training data and testing data.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dataset {
+    training: Split,
+    testing: Split,
+}
+
+impl Dataset {
+    /// Gets the split of the dataset used for training.
+    #[must_use]
+    pub const fn training(&self) -> &Split {
+        &self.training
+    }
+
+    /// Gets the split of the dataset used for testing.
+    #[must_use]
+    pub const fn testing(&self) -> &Split {
+        &self.testing
+    }
+
+    /// Converts the dataset into its training split and testing split.
+    #[must_use]
+    pub fn into_splits(self) -> (Split, Split) {
+        (self.training, self.testing)
+    }
+}
+
+/// Loads MNIST or Fashion-MNIST (whose files have the same names and sizes) from the uncompressed files
+/// in the given directory, which need downloading by hand as described in the documentation of the module.
+/// The size of each file is checked against the known size of the file before any of them are read, so
+/// that a truncated or corrupt download is reported rather than partly read.
+///
+/// # Errors
+/// `Error::FileAccess` if a file is missing or can't be read, or `Error::InvalidDataset` if a file
+/// doesn't have the known size or isn't a valid dataset file.
+pub fn load(directory: impl AsRef<Path>) -> Result<Dataset> {
+    let directory = directory.as_ref();
+    for (file, size) in FILES.into_iter().zip(SIZES) {
+        let metadata = metadata(directory.join(file)).map_err(|_| Error::FileAccess)?;
+        if metadata.len() != size {
+            return Err(Error::InvalidDataset);
+        }
+    }
+    read_dir(directory)
+}
+
+/// Reads a dataset from the uncompressed IDX files in the given directory, which have the same names as the files of
+/// MNIST but can hold any number of images and labels, without checking their sizes.
+///
+/// # Errors
+/// `Error` if a file can't be read, or if a file isn't a valid dataset file.
+pub fn read_dir(directory: impl AsRef<Path>) -> Result<Dataset> {
+    let directory = directory.as_ref();
+    let [training_images, training_labels, testing_images, testing_labels] =
+        FILES.map(|file| read(directory.join(file)).map_err(|_| Error::FileAccess));
+    Ok(Dataset {
+        training: split(&training_images?, &training_labels?)?,
+        testing: split(&testing_images?, &testing_labels?)?,
+    })
+}
+
+/// Parses the images and labels of a split from the bytes of their IDX files.
+fn split(images: &[u8], labels: &[u8]) -> Result<Split> {
+    let images = parse_images(images)?;
+    let labels = parse_labels(labels)?;
+    Error::check_shape((images.0.nrows(), CLASSES), labels.dim())
+        .map_err(|_| Error::InvalidDataset)?;
+    Ok(Split { images, labels })
+}
+
+/// Parses an IDX file of images into a tensor with a row for each image, scaling each pixel to be
+/// between 0 and 1.
+fn parse_images(bytes: &[u8]) -> Result<Tensor<rank::Two>> {
+    let (dimensions, data) = parse(bytes)?;
+    let [count, rows, columns] = *dimensions.as_slice() else {
+        return Err(Error::InvalidDataset);
+    };
+    Tensor::<rank::Two>::new(
+        (count, rows * columns),
+        data.iter().map(|pixel| ElementType::from(*pixel) / 255.0),
+    )
+    .map_err(|_| Error::InvalidDataset)
+}
+
+/// Parses an IDX file of labels into a tensor with a one-hot encoded row for each label.
+fn parse_labels(bytes: &[u8]) -> Result<Tensor<rank::Two>> {
+    let (dimensions, data) = parse(bytes)?;
+    let [count] = *dimensions.as_slice() else {
+        return Err(Error::InvalidDataset);
+    };
+    if data.iter().any(|label| usize::from(*label) >= CLASSES) {
+        return Err(Error::InvalidDataset);
+    }
+    Tensor::<rank::Two>::new(
+        (count, CLASSES),
+        data.iter().flat_map(|label| {
+            (0..CLASSES).map(|class| {
+                if class == usize::from(*label) {
+                    1.0
+                } else {
+                    0.0
+                }
+            })
+        }),
+    )
+    .map_err(|_| Error::InvalidDataset)
+}
+
+/// Parses an IDX file of unsigned bytes into its dimensions and data. The format is two zero bytes, the
+/// type of the data (8 for unsigned bytes) and the number of dimensions, followed by the size of each
+/// dimension as a big endian 32 bit integer and then the data in row major order.
+fn parse(bytes: &[u8]) -> Result<(Vec<usize>, &[u8])> {
+    let [0, 0, 8, count, rest @ ..] = bytes else {
+        return Err(Error::InvalidDataset);
+    };
+    let (sizes, data) = rest
+        .split_at_checked(usize::from(*count) * 4)
+        .ok_or(Error::InvalidDataset)?;
+    let dimensions = sizes
+        .chunks_exact(4)
+        .map(|size| {
+            usize::try_from(u32::from_be_bytes(size.try_into().unwrap())) // unwrapping is safe because every chunk has 4 bytes
+                .map_err(|_| Error::InvalidDataset)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if dimensions.iter().product::<usize>() == data.len() {
+        Ok((dimensions, data))
+    } else {
+        Err(Error::InvalidDataset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::create_dir_all;
+
+    /// Encodes the given data as an IDX file with the given dimensions.
+    fn idx(dimensions: &[u32], data: &[u8]) -> Vec<u8> {
+        let mut bytes = [0, 0, 8, u8::try_from(dimensions.len()).unwrap()].to_vec();
+        for size in dimensions {
+            bytes.extend_from_slice(&size.to_be_bytes());
+        }
+        bytes.extend_from_slice(data);
+        bytes
+    }
+
+    #[test]
+    fn test_parse_images() {
+        // Arrange
+        let bytes = idx(&[2, 1, 2], &[0, 255, 255, 0]);
+
+        // Act
+        let images = parse_images(&bytes).unwrap();
+
+        // Assert
+        assert_eq!(
+            images,
+            Tensor::<rank::Two>::new((2, 2), [0.0, 1.0, 1.0, 0.0]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_labels() {
+        // Arrange
+        let bytes = idx(&[2], &[3, 9]);
+
+        // Act
+        let labels = parse_labels(&bytes).unwrap();
+
+        // Assert
+        let expected = [
+            0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, //
+            0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+        ];
+        assert_eq!(labels, Tensor::<rank::Two>::new((2, 10), expected).unwrap());
+    }
+
+    #[test]
+    fn test_parse_failure() {
+        // Arrange
+        let files = [
+            idx(&[2], &[1]),
+            idx(&[2], &[1, 10]),
+            idx(&[1, 1], &[1]),
+            b"\x00\x00\x0d\x01\x00\x00\x00\x01\x00".to_vec(),
+            b"\x00\x00\x08\x01\x00".to_vec(),
+        ];
+
+        // Act
+        let results = files.map(|bytes| parse_labels(&bytes));
+
+        // Assert
+        assert!(results
+            .into_iter()
+            .all(|result| result == Err(Error::InvalidDataset)));
+    }
+
+    #[test]
+    fn test_split_mismatch() {
+        // Arrange
+        let images = idx(&[2, 1, 1], &[0, 255]);
+        let labels = idx(&[1], &[0]);
+
+        // Act
+        let output = split(&images, &labels);
+
+        // Assert
+        assert_eq!(output, Err(Error::InvalidDataset));
+    }
+
+    #[test]
+    fn test_read_dir() {
+        // Arrange
+        let directory = std::env::temp_dir().join("eidetic_test_read_dir");
+        create_dir_all(&directory).unwrap();
+        let contents = [
+            idx(&[2, 1, 1], &[0, 255]),
+            idx(&[2], &[1, 2]),
+            idx(&[1, 1, 1], &[255]),
+            idx(&[1], &[3]),
+        ];
+        for (file, contents) in FILES.into_iter().zip(contents) {
+            std::fs::write(directory.join(file), contents).unwrap();
+        }
+
+        // Act
+        let dataset = read_dir(&directory).unwrap();
+        let _ = std::fs::remove_dir_all(&directory);
+
+        // Assert
+        let (training, testing) = dataset.into_splits();
+        assert_eq!(training.images().dim(), (2, 1));
+        assert_eq!(training.labels().dim(), (2, 10));
+        let (images, labels) = testing.into_tensors();
+        assert_eq!(images.into_iter().collect::<Vec<_>>(), [1.0]);
+        assert_eq!(labels.into_iter().position(|label| label == 1.0), Some(3));
+        assert_eq!(read_dir(directory.join("missing")), Err(Error::FileAccess));
+    }
+
+    #[test]
+    fn test_load_checks_sizes() {
+        // Arrange
+        let directory = std::env::temp_dir().join("eidetic_test_load_checks_sizes");
+        create_dir_all(&directory).unwrap();
+        let contents = [
+            idx(&[2, 1, 1], &[0, 255]),
+            idx(&[2], &[1, 2]),
+            idx(&[1, 1, 1], &[255]),
+            idx(&[1], &[3]),
+        ];
+        for (file, contents) in FILES.into_iter().zip(contents) {
+            std::fs::write(directory.join(file), contents).unwrap();
+        }
+
+        // Act
+        let output = load(&directory);
+        let missing = load(directory.join("missing"));
+        let _ = std::fs::remove_dir_all(&directory);
+
+        // Assert
+        assert_eq!(output, Err(Error::InvalidDataset));
+        assert_eq!(missing, Err(Error::FileAccess));
+    }
+}
@@ -0,0 +1,215 @@
+//! This module contains fixed size networks, whose shapes are known at compile time through const generics.
+//! They hold their parameters in arrays and predict from arrays, so inference never allocates memory on the heap,
+//! which suits microcontrollers and control loops where allocation is unavailable or unpredictable.
+//!
+//! Only small networks of dense layers are supported. A fixed size network is usually made from a network
+//! that's been trained as normal, by reading the parameters of each dense layer from its parameter iterator
+//! in the same order as `with_iter` takes them.
+//!
+//! ```
+//! use eidetic::activations::{Linear, ReLU};
+//! use eidetic::fixed::{Activation, Dense, FixedOperation};
+//! use eidetic::layers::{Chain, Dense as DenseLayer, Input};
+//! use eidetic::operations::{InitialisedOperation, UninitialisedOperation};
+//! let network = Input::new(2)
+//!     .chain(DenseLayer::new(3, ReLU::new()))
+//!     .chain(DenseLayer::new(1, Linear::new()))
+//!     .with_seed(42);
+//! let mut parameters = network.iter();
+//! let hidden = Dense::<2, 3>::with_iter(&mut parameters, Activation::ReLU).unwrap();
+//! let output = Dense::<3, 1>::with_iter(&mut parameters, Activation::Linear).unwrap();
+//! let fixed = hidden.chain(output);
+//! let prediction = fixed.predict(&[1.0, 2.0]);
+//! assert_eq!(prediction.len(), 1);
+//! ```
+
+use crate::{ElementType, Error, Result};
+
+/// The activation function applied to the output of a fixed size dense layer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Activation {
+    /// Leaves the output unchanged.
+    Linear,
+    /// Replaces negative outputs with zero.
+    ReLU,
+    /// Squashes the output into the range 0 to 1.
+    Sigmoid,
+    /// Squashes the output into the range -1 to 1.
+    Tanh,
+}
+
+impl Activation {
+    /// Applies the activation function to a single element.
+    fn apply(self, elem: ElementType) -> ElementType {
+        match self {
+            Self::Linear => elem,
+            Self::ReLU => elem.max(0.0),
+            Self::Sigmoid => 1.0 / (1.0 + (-elem).exp()),
+            Self::Tanh => elem.tanh(),
+        }
+    }
+}
+
+/// An operation of a fixed size network, which takes an array of `I` elements and produces an array of `O` elements.
+pub trait FixedOperation<const I: usize, const O: usize> {
+    /// Runs the input through the operation to produce a prediction.
+    fn predict(&self, input: &[ElementType; I]) -> [ElementType; O];
+
+    /// Chains this operation with another, which takes the output of this operation as its input.
+    fn chain<T, const P: usize>(self, rhs: T) -> Chain<Self, T, O>
+    where
+        Self: Sized,
+        T: FixedOperation<O, P>,
+    {
+        Chain { lhs: self, rhs }
+    }
+}
+
+/// A dense layer with `I` input neurons and `O` output neurons.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dense<const I: usize, const O: usize> {
+    weights: [[ElementType; O]; I],
+    biases: [ElementType; O],
+    activation: Activation,
+}
+
+impl<const I: usize, const O: usize> Dense<I, O> {
+    /// Constructs a new dense layer from its weights (with a row for each input neuron), biases and activation function.
+    #[must_use]
+    pub const fn new(
+        weights: [[ElementType; O]; I],
+        biases: [ElementType; O],
+        activation: Activation,
+    ) -> Self {
+        Self {
+            weights,
+            biases,
+            activation,
+        }
+    }
+
+    /// Constructs a new dense layer by taking its weights and then its biases from the given iterator, in the same
+    /// order as `with_iter` initialises a `Dense` layer. Any remaining parameters are left in the iterator, so that
+    /// the parameters of an initialised network can be read layer by layer.
+    ///
+    /// # Errors
+    /// `Error::InsufficientWeights` if the iterator runs out of parameters.
+    pub fn with_iter(
+        iter: &mut impl Iterator<Item = ElementType>,
+        activation: Activation,
+    ) -> Result<Self> {
+        let error = Error::InsufficientWeights {
+            layer_index: 0,
+            expected: I * O + O,
+        };
+        let mut weights = [[0.0; O]; I];
+        for weight in weights.iter_mut().flatten() {
+            *weight = iter.next().ok_or_else(|| error.clone())?;
+        }
+        let mut biases = [0.0; O];
+        for bias in &mut biases {
+            *bias = iter.next().ok_or_else(|| error.clone())?;
+        }
+        Ok(Self::new(weights, biases, activation))
+    }
+}
+
+impl<const I: usize, const O: usize> FixedOperation<I, O> for Dense<I, O> {
+    fn predict(&self, input: &[ElementType; I]) -> [ElementType; O] {
+        let mut output = self.biases;
+        for (input, weights) in input.iter().zip(&self.weights) {
+            for (output, weight) in output.iter_mut().zip(weights) {
+                *output = input.mul_add(*weight, *output);
+            }
+        }
+        output.map(|elem| self.activation.apply(elem))
+    }
+}
+
+/// Two fixed size operations chained together, where the first produces `H` elements for the second.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Chain<T, U, const H: usize> {
+    lhs: T,
+    rhs: U,
+}
+
+impl<T, U, const I: usize, const H: usize, const O: usize> FixedOperation<I, O> for Chain<T, U, H>
+where
+    T: FixedOperation<I, H>,
+    U: FixedOperation<H, O>,
+{
+    fn predict(&self, input: &[ElementType; I]) -> [ElementType; O] {
+        self.rhs.predict(&self.lhs.predict(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::{Linear, ReLU, Sigmoid, Tanh};
+    use crate::layers::{self, Input};
+    use crate::operations::{InitialisedOperation, UninitialisedOperation};
+    use crate::tensors::{rank, Tensor};
+    use layers::Chain as _;
+
+    #[test]
+    fn test_predict() {
+        // Arrange
+        let dense = Dense::new([[1.0, -1.0], [2.0, 0.5]], [0.5, -3.0], Activation::ReLU);
+
+        // Act
+        let output = dense.predict(&[1.0, 2.0]);
+
+        // Assert
+        assert_eq!(output, [5.5, 0.0]);
+    }
+
+    #[test]
+    fn test_with_iter_matches_network() {
+        // Arrange
+        let network = Input::new(3)
+            .chain(layers::Dense::new(4, Tanh::new()))
+            .chain(layers::Dense::new(2, Sigmoid::new()))
+            .chain(layers::Dense::new(1, Linear::new()))
+            .with_seed(42);
+        let input = [0.5, -1.0, 2.0];
+        let expected = network
+            .predict(Tensor::<rank::Two>::new((1, 3), input).unwrap())
+            .unwrap();
+
+        // Act
+        let mut parameters = network.iter();
+        let fixed = Dense::<3, 4>::with_iter(&mut parameters, Activation::Tanh)
+            .unwrap()
+            .chain(Dense::<4, 2>::with_iter(&mut parameters, Activation::Sigmoid).unwrap())
+            .chain(Dense::<2, 1>::with_iter(&mut parameters, Activation::Linear).unwrap());
+        let output = fixed.predict(&input);
+
+        // Assert
+        assert!(parameters.next().is_none());
+        assert!(Tensor::<rank::Two>::new((1, 1), output)
+            .unwrap()
+            .approx_eq(&expected, 1e-5));
+    }
+
+    #[test]
+    fn test_with_iter_insufficient_weights() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(layers::Dense::new(2, ReLU::new()))
+            .with_seed(42);
+
+        // Act
+        let result = Dense::<2, 3>::with_iter(&mut network.iter(), Activation::ReLU);
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(Error::InsufficientWeights {
+                layer_index: 0,
+                expected: 9
+            })
+        );
+    }
+}
@@ -0,0 +1,175 @@
+//! This module contains the strategies that can be used to generate the initial
+//! weights of a layer when a network is initialised from a random seed.
+
+use crate::ElementType;
+use rand::Rng;
+use rand_distr::{Distribution, Normal};
+
+/// This enum represents the strategy to use when generating the initial weights of a layer
+/// from a random seed. The default is Xavier uniform initialisation. A strategy can be chosen
+/// for a single layer with its `with_initialiser` function, or for every layer that hasn't been
+/// given one when the network is initialised with `with_seed_and_initialiser`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Initialiser {
+    /// Samples weights uniformly in the range of +/- sqrt(6 / (inputs + outputs)).
+    /// This is a good general purpose initialisation for sigmoid and tanh layers.
+    #[default]
+    XavierUniform,
+
+    /// Samples weights from a normal distribution with a mean of 0 and a variance of
+    /// 2 / (inputs + outputs), which has the same variance as Xavier uniform initialisation.
+    XavierNormal,
+
+    /// Samples weights uniformly in the range of +/- sqrt(6 / inputs). This is the
+    /// initialisation of Kaiming He et al. which is preferred for `ReLU` layers.
+    HeUniform,
+
+    /// Samples weights from a normal distribution with a mean of 0 and a variance of
+    /// 2 / inputs, which has the same variance as He uniform initialisation.
+    HeNormal,
+
+    /// Samples weights uniformly in the range of +/- sqrt(3 / inputs).
+    LeCunUniform,
+
+    /// Samples weights from a normal distribution with a mean of 0 and a variance of
+    /// 1 / inputs. Used alongside the SELU activation function for self-normalising networks.
+    LeCunNormal,
+
+    /// Sets every weight to the given value.
+    Constant(ElementType),
+
+    /// Sets every weight to 0, which is the same as a constant of 0.
+    Zeros,
+}
+
+impl Initialiser {
+    /// Generates a single weight for a layer with the given number of input and output neurons.
+    pub(crate) fn sample(
+        self,
+        generator: &mut impl Rng,
+        input_neurons: u16,
+        output_neurons: u16,
+    ) -> ElementType {
+        let (inputs, outputs) = (
+            ElementType::from(input_neurons),
+            ElementType::from(output_neurons),
+        );
+        match self {
+            Self::XavierUniform => uniform(generator, 6.0, inputs + outputs),
+            Self::XavierNormal => normal(generator, 2.0, inputs + outputs),
+            Self::HeUniform => uniform(generator, 6.0, inputs),
+            Self::HeNormal => normal(generator, 2.0, inputs),
+            Self::LeCunUniform => uniform(generator, 3.0, inputs),
+            Self::LeCunNormal => normal(generator, 1.0, inputs),
+            Self::Constant(value) => value,
+            Self::Zeros => 0.0,
+        }
+    }
+}
+
+/// Samples uniformly in the range of +/- sqrt(numerator / neurons).
+fn uniform(generator: &mut impl Rng, numerator: ElementType, neurons: ElementType) -> ElementType {
+    let limit = numerator.sqrt() / neurons.sqrt();
+    generator.gen_range(-limit..=limit)
+}
+
+/// Samples from a normal distribution with a mean of 0 and a variance of numerator / neurons.
+fn normal(generator: &mut impl Rng, numerator: ElementType, neurons: ElementType) -> ElementType {
+    let std_dev = numerator.sqrt() / neurons.sqrt();
+    Normal::new(0.0, std_dev).unwrap().sample(generator) // unwrapping is safe because the standard deviation is never negative
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_default() {
+        // Act
+        let initialiser = Initialiser::default();
+
+        // Assert
+        assert_eq!(initialiser, Initialiser::XavierUniform);
+    }
+
+    #[test]
+    fn test_xavier_uniform_range() {
+        // Arrange
+        let mut generator = StdRng::seed_from_u64(42);
+        let xavier_delta = ElementType::sqrt(6.0) / ElementType::sqrt(20.0);
+
+        // Act
+        let mut samples =
+            (0..1000).map(|_| Initialiser::XavierUniform.sample(&mut generator, 4, 16));
+
+        // Assert
+        assert!(samples.all(|sample| sample.abs() <= xavier_delta));
+    }
+
+    #[test]
+    fn test_lecun_normal_variance() {
+        // Arrange
+        const COUNT: u16 = 40_000;
+        let mut generator = StdRng::seed_from_u64(42);
+
+        // Act
+        let samples = (0..COUNT)
+            .map(|_| Initialiser::LeCunNormal.sample(&mut generator, 400, 10))
+            .collect::<ndarray::Array1<_>>();
+
+        // Assert
+        assert!(samples.mean().unwrap().abs() < 0.001);
+        assert!(samples.var(0.0).mul_add(400.0, -1.0).abs() < 0.02);
+    }
+
+    #[test]
+    fn test_he_uniform_range() {
+        // Arrange
+        let mut generator = StdRng::seed_from_u64(42);
+        let limit = ElementType::sqrt(6.0) / ElementType::sqrt(4.0);
+
+        // Act
+        let mut samples = (0..1000).map(|_| Initialiser::HeUniform.sample(&mut generator, 4, 16));
+
+        // Assert
+        assert!(samples.all(|sample| sample.abs() <= limit));
+    }
+
+    #[test]
+    fn test_normal_variances() {
+        // Arrange
+        const COUNT: u16 = 40_000;
+        let cases = [
+            (Initialiser::XavierNormal, 2.0 / 500.0),
+            (Initialiser::HeNormal, 2.0 / 400.0),
+        ];
+
+        for (initialiser, variance) in cases {
+            let mut generator = StdRng::seed_from_u64(42);
+
+            // Act
+            let samples = (0..COUNT)
+                .map(|_| initialiser.sample(&mut generator, 400, 100))
+                .collect::<ndarray::Array1<_>>();
+
+            // Assert
+            assert!((samples.var(0.0) / variance - 1.0).abs() < 0.02);
+        }
+    }
+
+    #[test]
+    fn test_constants() {
+        // Arrange
+        let mut generator = StdRng::seed_from_u64(42);
+
+        // Act
+        let constant = Initialiser::Constant(0.5).sample(&mut generator, 4, 4);
+        let zero = Initialiser::Zeros.sample(&mut generator, 4, 4);
+
+        // Assert
+        assert_eq!(constant, 0.5);
+        assert_eq!(zero, 0.0);
+    }
+}
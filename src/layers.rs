@@ -3,8 +3,93 @@
 //! the level of unit that clients will generally compose together into
 //! networks.
 
+pub use crate::operations::uninitialised::alpha_dropout::Operation as AlphaDropout;
 pub use crate::operations::uninitialised::composite::Chain;
 pub use crate::operations::uninitialised::composite::Operation as Composite;
+pub use crate::operations::uninitialised::conv1d::Operation as Conv1D;
+pub use crate::operations::uninitialised::conv2d::Operation as Conv2D;
+pub use crate::operations::uninitialised::conv_transpose2d::Operation as ConvTranspose2D;
+pub use crate::operations::uninitialised::custom::CustomLayer;
+pub use crate::operations::uninitialised::custom::Operation as Custom;
 pub use crate::operations::uninitialised::dense::Operation as Dense;
+pub use crate::operations::uninitialised::depthwise_conv2d::Operation as DepthwiseConv2D;
 pub use crate::operations::uninitialised::dropout::Operation as Dropout;
+pub use crate::operations::uninitialised::dynamic::Network as DynNetwork;
+pub use crate::operations::uninitialised::frozen::Operation as Frozen;
+pub use crate::operations::uninitialised::gru::Operation as GRU;
+pub use crate::operations::uninitialised::image_input::Operation as ImageInput;
 pub use crate::operations::uninitialised::input::Operation as Input;
+pub use crate::operations::uninitialised::multi_input::Operation as MultiInput;
+pub use crate::operations::uninitialised::parallel::{Merge, Operation as Parallel};
+pub use crate::operations::uninitialised::pooling2d::{Average, Max, Pool};
+pub use crate::operations::uninitialised::reshape::Operation as Reshape;
+pub use crate::operations::uninitialised::sequence_input::Operation as SequenceInput;
+pub use crate::operations::uninitialised::spatial_dropout::Operation as SpatialDropout;
+pub use crate::operations::uninitialised::stochastic_depth::Operation as StochasticDepth;
+pub use crate::operations::uninitialised::upsample2d::{Interpolation, Operation as Upsample2D};
+
+/// A 2D pooling layer which takes the largest element of each window.
+pub type MaxPooling2D = crate::operations::uninitialised::pooling2d::Operation<Max>;
+
+/// A 2D pooling layer which takes the mean of the elements of each window.
+pub type AveragePooling2D = crate::operations::uninitialised::pooling2d::Operation<Average>;
+
+/// A layer which flattens each item of a batch into a row with the given number of features,
+/// so that the feature maps of convolutional layers can be fed into dense layers.
+pub type Flatten<R> =
+    crate::operations::uninitialised::reshape::Operation<R, crate::tensors::rank::Two>;
+
+/// Builds a network from a list of layers, chaining them together in order. Each layer is written as the
+/// name of a layer in this module followed by the arguments to its `new` function, and activation functions
+/// which don't take any arguments can be given by name alone. Any other argument can be an arbitrary expression.
+///
+/// ```
+/// use eidetic::activations::ELU;
+/// use eidetic::layers::{Chain, Dense, Dropout, Input};
+/// use eidetic::network;
+/// use eidetic::operations::UninitialisedOperation;
+/// let units = 300;
+/// let network = network![
+///     Input(784),
+///     Dense(units, Tanh),
+///     Dropout(0.5),
+///     Dense(100, ELU::new(1.0)),
+///     Dense(10, Linear),
+/// ];
+/// let expected = Input::new(784)
+///     .chain(Dense::new(units, eidetic::activations::Tanh::new()))
+///     .chain(Dropout::new(0.5))
+///     .chain(Dense::new(100, ELU::new(1.0)))
+///     .chain(Dense::new(10, eidetic::activations::Linear::new()));
+/// assert_eq!(network.with_seed(42), expected.with_seed(42));
+/// ```
+#[macro_export]
+macro_rules! network {
+    ($first:ident ( $($first_args:tt)* ) $(, $layer:ident ( $($args:tt)* ))* $(,)?) => {{
+        let network = $crate::network!(@call $first [] $($first_args)*);
+        $(
+            let network = $crate::layers::Chain::chain(
+                network,
+                $crate::network!(@call $layer [] $($args)*),
+            );
+        )*
+        network
+    }};
+    (@call $name:ident [$($done:expr,)*]) => {
+        $crate::layers::$name::new($($done),*)
+    };
+    (@call $name:ident [$($done:expr,)*] $value:ident $(, $($rest:tt)*)?) => {
+        $crate::network!(@call $name [$($done,)* $crate::network!(@value $value),] $($($rest)*)?)
+    };
+    (@call $name:ident [$($done:expr,)*] $value:expr $(, $($rest:tt)*)?) => {
+        $crate::network!(@call $name [$($done,)* $value,] $($($rest)*)?)
+    };
+    (@value GELU) => { $crate::activations::GELU::new() };
+    (@value Linear) => { $crate::activations::Linear::new() };
+    (@value ReLU) => { $crate::activations::ReLU::new() };
+    (@value SELU) => { $crate::activations::SELU::new() };
+    (@value Sigmoid) => { $crate::activations::Sigmoid::new() };
+    (@value SiLU) => { $crate::activations::SiLU::new() };
+    (@value Tanh) => { $crate::activations::Tanh::new() };
+    (@value $value:ident) => { $value };
+}
@@ -1,5 +1,19 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![deny(warnings, missing_docs, clippy::pedantic, clippy::nursery)]
+#![allow(clippy::too_long_first_doc_paragraph)]
+#![cfg_attr(
+    test,
+    allow(
+        clippy::cast_lossless,
+        clippy::cast_precision_loss,
+        clippy::excessive_precision,
+        clippy::float_cmp,
+        clippy::items_after_statements,
+        clippy::should_panic_without_expect,
+        clippy::unreadable_literal,
+        clippy::used_underscore_items
+    )
+)]
 
 //! Eidetic is a simple pure rust library for neural networks and deep learning.
 //! Implemented alongside working through the "Deep learning from scratch in Python" book in order to get hands on with it.
@@ -45,8 +59,19 @@
 //! assert_eq!(tensor_sum, 21.0);
 //! ```
 //!
-//! Unfortunately at this time, the API for processing the data produced by Eidetic is limited to a flattened iterator and doesn't support more complex
-//! interactions like iterating rows/columns/etc. This could be handled by your linear algebra library of choice if needed.
+//! Individual samples can be examined with `row` and `rows` on rank 2 tensors (or `item` and `items` for tensors of any batched rank), and parts of a tensor
+//! can be taken with `slice`. Anything more complex could be handled by your linear algebra library of choice if needed.
+//!
+//! ```
+//! use eidetic::tensors::{Tensor, rank};
+//! let predictions = Tensor::<rank::Two>::new((2, 3), [0.1, 0.7, 0.2, 0.6, 0.3, 0.1]).unwrap();
+//! let second = predictions.row(1).unwrap();
+//! assert_eq!(second.into_iter().collect::<Vec<_>>(), [0.6, 0.3, 0.1]);
+//! assert_eq!(predictions.slice(.., 1..).unwrap().dim(), (2, 2));
+//! ```
+//!
+//! Enabling the Cargo feature *ndarray-interop* implements `From` conversions in both directions between a `Tensor` and an `ndarray` array of the corresponding
+//! dimensionality, which move the elements rather than copying them. This isn't enabled by default because `ndarray` hasn't reached 1.0 and so isn't part of the stable API.
 //!
 //! ### `ElementType`
 //! As you may have noticed in the above example, the data type that Eidetic works with is type-aliased to `eidetic::ElementType` which defaults to f64.
@@ -54,6 +79,71 @@
 //! Eidetic doesn't work with any custom data types to avoid annoying trait bounds, etc. we would need for the generics so any data conversion must be done before providing the data to
 //! Eidetic, or after getting the data from Eidetic.
 //!
+//! ### Sharing Between Threads
+//! Initialised networks are `Send` and `Sync`, and `predict` only borrows the network, so a single trained network can serve concurrent predictions (for example
+//! from the request handlers of a web server) by sharing it behind an `Arc` without any locking. Custom layers added to a `DynNetwork` must be `Send` and `Sync` too.
+//!
+//! ```
+//! # use eidetic::activations::Linear;
+//! # use eidetic::layers::{Chain, Dense, Input};
+//! # use eidetic::operations::{InitialisedOperation, UninitialisedOperation};
+//! # use eidetic::tensors::{rank, Tensor};
+//! use std::sync::Arc;
+//! let network = Arc::new(Input::new(2).chain(Dense::new(1, Linear::new())).with_seed(42));
+//! let handles = (0..4).map(|_| {
+//!     let network = Arc::clone(&network);
+//!     std::thread::spawn(move || {
+//!         let input = Tensor::<rank::Two>::new((1, 2), [1.0, 2.0]).unwrap();
+//!         network.predict(input).unwrap()
+//!     })
+//! }).collect::<Vec<_>>();
+//! for handle in handles {
+//!     assert_eq!(handle.join().unwrap().dim(), (1, 1));
+//! }
+//! ```
+//!
+//! ### Embedded
+//! Without the *std* feature, Eidetic only uses `core` and `alloc` itself, although its `ndarray` dependency is still built with its default features.
+//! Small networks of dense layers can be converted into the fixed size networks of the `fixed` module, whose shapes are const generic parameters, so that
+//! inference runs entirely on the stack without allocating.
+//!
+//! ### WebAssembly
+//! Eidetic can be built for the *wasm32-unknown-unknown* target so that trained networks can run in the browser. Without the *std* feature it never asks the platform for entropy,
+//! and layers such as dropout which weren't given a seed use a fixed one. With *std*, `rand` gets its entropy from `getrandom`, which needs its *js* feature enabled by the final binary
+//! crate to work in the browser.
+//! Files can't be read there, so the parameters of a trained network are best embedded into the binary and loaded from the byte slice with `with_bytes`.
+//!
+//! ```ignore
+//! let network = Input::new(784)
+//!     .chain(Dense::new(10, Linear::new()))
+//!     .with_bytes(include_bytes!("network.bin"))?;
+//! ```
+//!
+//! ### BLAS
+//! Enabling the Cargo feature *blas* turns on `ndarray`'s BLAS support, so that the matrix multiplications of layers such as `Dense` use an optimised BLAS implementation
+//! rather than the pure Rust one. Eidetic doesn't choose a BLAS provider itself, so the final binary crate must link one through `blas-src` (the version that `ndarray` expects),
+//! otherwise linking fails with undefined `cblas_*` symbols. For example, to use the system's `OpenBLAS`:
+//!
+//! ```toml
+//! [dependencies]
+//! blas-src = { version = "0.8", features = ["openblas"] }
+//! openblas-src = { version = "0.10", features = ["cblas", "system"] }
+//! ```
+//!
+//! along with `extern crate blas_src;` in the binary. For the same reason, `cargo test --all-features` can't link, and the features to test should be listed instead.
+//!
+//! ### Serialization
+//! Enabling the Cargo feature *serde* implements serde's `Serialize` and `Deserialize` traits for `Tensor` and for all operations in the initialised typestate. This allows a trained network
+//! to be saved with both its structure and weights in any format supported by serde (JSON, bincode, etc.), and then deserialized straight back into the same initialised network type
+//! without needing to replay the weights through `with_iter` in the correct order.
+//!
+//! Operations in the trainable typestate and the built in optimisers and learning rate handlers (other than the custom handler) implement the traits too, so a network
+//! which is part way through training can be saved along with the internal state of its optimisers, and training resumed later from the `ResumeState` recorded in its `TrainingHistory`.
+//!
+//! Without serde, the parameters of an initialised network can be saved with `to_bytes` (or `save` with the *std* feature) in a format which records the element type and the shape of each layer,
+//! and loaded into an uninitialised network of the same structure with `with_bytes` (or `load`). Parameters can also be exchanged with other libraries as `NumPy` .npz archives (`to_npz` and `with_npz`)
+//! or in the safetensors format (`to_safetensors` and `with_safetensors`).
+//!
 //! ### Operation Chain
 //! In Eidetic, there's no dedicated "Network" type and instead the API operates on a chain of operations. This functionality is provided by the `Chain` trait and an operation chain
 //! *MUST* begin with the `Input` layer due to the unique semantics it provides. An example of a Linear regression type of network can then be built as follows:
@@ -92,6 +182,14 @@
 //! let network = network.into_initialised(); // Once we're done training, we can put it back into an "initialised" typestate which will allow us to get access to the weights in the network.
 //! ```
 //!
+//! ### Parallelism
+//! Enabling the Cargo feature *rayon* adds `training::Trainer::train_data_parallel`, which splits each batch between replicas of the network whose forward and backward passes run in parallel
+//! across threads, averaging their gradients before the network is optimised. It also adds `predict_parallel` to initialised networks, which predicts chunks of the rows of a large batch in parallel.
+//!
+//! ### Progress
+//! Enabling the Cargo feature *progress* adds `training::Progress`, a callback which reports the loss of each batch and epoch to standard error (or any other writer)
+//! as training runs, along with an estimate of the time remaining.
+//!
 //! # Examples
 //! All the examples can be found inside the "examples" directory and run through the standard procedure of:
 //!
@@ -101,24 +199,204 @@
 //!
 //! Note that if you try to run an example that requires a feature to be active (for example to download an additional crate) then it will tell you about it.
 
+extern crate alloc;
+
 pub mod activations;
+#[cfg(feature = "std")]
+pub mod architecture;
+pub mod data;
+#[cfg(feature = "datasets")]
+pub mod datasets;
+pub mod fixed;
+pub mod initialisers;
 pub mod layers;
 pub mod loss;
+pub mod metrics;
 pub mod operations;
 pub mod optimisers;
+pub mod preprocessing;
 mod private;
+pub mod quantization;
+pub mod summary;
 pub mod tensors;
 pub mod training;
 
 #[cfg(feature = "thiserror")]
 use thiserror::Error;
 
-/// This is a generic error that's emitted for when something in Eidetic
-/// goes wrong. Having a custom error type means we can typedef Result too.
-#[derive(Debug, Eq, PartialEq)]
+/// This is the error that's emitted for when something in Eidetic goes wrong. Since the API uses
+/// typestates and catches most issues at compile time, this will usually be caused by data of
+/// an invalid shape (e.g. an incorrect column count). Having a custom error type means we can typedef Result too.
+///
+/// The variants describe why the operation failed so that it can be handled programmatically.
+/// More variants may be added in the future.
+#[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "thiserror", derive(Error))]
-#[cfg_attr(feature = "thiserror", error("An error that can occur during runtime operation of Eidetic. Since the API uses typestates and catches issues at compile time, this will usually be an invalid shape (e.g. incorrect column count in the data)."))]
-pub struct Error(pub(crate) ());
+#[non_exhaustive]
+pub enum Error {
+    /// The size of a tensor along some dimension (such as the number of columns/neurons, the
+    /// number of channels, or the total number of elements) didn't match what was expected.
+    #[cfg_attr(
+        feature = "thiserror",
+        error("shape mismatch: expected a size of {expected} but found {actual}")
+    )]
+    ShapeMismatch {
+        /// The size that was expected.
+        expected: usize,
+        /// The size that was provided.
+        actual: usize,
+    },
+
+    /// There weren't enough weights provided to initialise a layer of the network. The
+    /// layer index counts only those layers which have parameters, starting from zero.
+    #[cfg_attr(
+        feature = "thiserror",
+        error("insufficient weights: layer {layer_index} expected {expected} weights")
+    )]
+    InsufficientWeights {
+        /// The index of the layer that couldn't be initialised.
+        layer_index: usize,
+        /// The number of weights the layer expected.
+        expected: usize,
+    },
+
+    /// Two tensors that are paired up row by row (such as the predictions and targets, or the
+    /// gradient and the last input of an operation) have a different number of rows.
+    #[cfg_attr(
+        feature = "thiserror",
+        error("batch row mismatch: expected {expected} rows but found {actual}")
+    )]
+    BatchRowMismatch {
+        /// The number of rows that was expected.
+        expected: usize,
+        /// The number of rows that was provided.
+        actual: usize,
+    },
+
+    /// The data provided didn't contain anything to work with, such as a tensor with no rows.
+    #[cfg_attr(feature = "thiserror", error("the provided data is empty"))]
+    EmptyData,
+
+    /// An argument was outside the range of values that are valid for the operation.
+    #[cfg_attr(feature = "thiserror", error("an argument is out of range"))]
+    InvalidArgument,
+
+    /// The text describing a network couldn't be parsed, or describes a network of a different shape.
+    #[cfg_attr(feature = "thiserror", error("the network text is invalid"))]
+    InvalidText,
+
+    /// The bytes of an archive of parameters (such as a `NumPy` .npz file) couldn't be read, or describe a
+    /// network of a different shape.
+    #[cfg_attr(feature = "thiserror", error("the archive of parameters is invalid"))]
+    InvalidArchive,
+
+    /// Parameters were saved in a version of the Eidetic format that isn't supported by this version of the library.
+    #[cfg_attr(
+        feature = "thiserror",
+        error("unsupported format version: version {version} can't be read")
+    )]
+    UnsupportedVersion {
+        /// The version of the format that the parameters were saved in.
+        version: u16,
+    },
+
+    /// Parameters were saved by a network using a different element type, such as loading the parameters
+    /// of a network built with the *f32* feature into one without it.
+    #[cfg_attr(
+        feature = "thiserror",
+        error("element type mismatch: expected {expected} bit elements but found {actual} bit elements")
+    )]
+    ElementTypeMismatch {
+        /// The number of bits in the element type of this build.
+        expected: usize,
+        /// The number of bits in the element type the parameters were saved with.
+        actual: usize,
+    },
+
+    /// A file couldn't be read or written.
+    #[cfg_attr(feature = "thiserror", error("the file couldn't be accessed"))]
+    FileAccess,
+
+    /// The bytes of a file of a dataset (such as an IDX file of MNIST images) couldn't be read.
+    #[cfg_attr(feature = "thiserror", error("the dataset file is invalid"))]
+    InvalidDataset,
+
+    /// The loss or the gradients of a batch became NaN or infinite during training, which is only
+    /// reported when training is configured to abort with `NonFiniteHandling::Abort`.
+    #[cfg_attr(
+        feature = "thiserror",
+        error("non-finite value: the loss or gradients of batch {batch} in epoch {epoch} were NaN or infinite")
+    )]
+    NonFiniteValue {
+        /// The (zero based) epoch that was being trained.
+        epoch: u32,
+        /// The (zero based) index of the batch within the epoch.
+        batch: usize,
+    },
+
+    /// A checkpoint of the network couldn't be saved during training.
+    #[cfg_attr(feature = "thiserror", error("the checkpoint couldn't be saved"))]
+    CheckpointFailed,
+
+    /// The network contains a layer that isn't supported by the operation, such as quantizing a
+    /// network with a convolutional layer.
+    #[cfg_attr(
+        feature = "thiserror",
+        error("the network contains an unsupported layer")
+    )]
+    UnsupportedLayer,
+
+    /// A `Trainer` couldn't be built because its configuration is invalid.
+    #[cfg_attr(feature = "thiserror", error("invalid training configuration: {0}"))]
+    InvalidConfig(training::ConfigError),
+}
+
+impl Error {
+    /// Checks that the size of a tensor along some dimension is the expected size.
+    pub(crate) const fn check_size(expected: usize, actual: usize) -> Result<()> {
+        if expected == actual {
+            Ok(())
+        } else {
+            Err(Self::ShapeMismatch { expected, actual })
+        }
+    }
+
+    /// Checks that a rank 2 tensor has the expected (rows, columns) shape. A mismatch in
+    /// the number of columns is reported ahead of a mismatch in the number of rows.
+    pub(crate) const fn check_shape(
+        expected: (usize, usize),
+        actual: (usize, usize),
+    ) -> Result<()> {
+        if expected.1 != actual.1 {
+            Err(Self::ShapeMismatch {
+                expected: expected.1,
+                actual: actual.1,
+            })
+        } else if expected.0 != actual.0 {
+            Err(Self::BatchRowMismatch {
+                expected: expected.0,
+                actual: actual.0,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Offsets the index of the layer that's reported when there are insufficient weights,
+    /// used when the layer was initialised after the given number of other layers.
+    pub(crate) const fn offset_layer_index(self, offset: usize) -> Self {
+        match self {
+            Self::InsufficientWeights {
+                layer_index,
+                expected,
+            } => Self::InsufficientWeights {
+                layer_index: layer_index + offset,
+                expected,
+            },
+            error => error,
+        }
+    }
+}
 
 /// This is the Result type alias defined by Eidetic to
 /// hard code the error type to be `eidetic::Error`.
@@ -1,17 +1,92 @@
-use crate::loss::Loss;
-use crate::private::Sealed;
+use crate::loss::{Loss, Reduction};
 use crate::tensors::{rank, Tensor};
 use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+use ndarray::{Array, ArrayView, Axis};
 
-/// This structure defines the "Mean Squared Error" loss function.
-pub struct MeanSquaredError(());
+/// This structure defines the "Mean Squared Error" loss function. By default, the squared errors of
+/// each sample are summed and then averaged over the samples of the batch.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MeanSquaredError {
+    reduction: Reduction,
+    class_weights: Option<Vec<ElementType>>,
+}
 
 impl MeanSquaredError {
     /// Constructs a new instance of the `MeanSquaredError` loss
     /// function.
     #[must_use]
     pub const fn new() -> Self {
-        Self(())
+        Self {
+            reduction: Reduction::Mean,
+            class_weights: None,
+        }
+    }
+
+    /// Sets how the losses of the samples of a batch are reduced into a single loss.
+    #[must_use]
+    pub fn with_reduction(self, reduction: Reduction) -> Self {
+        Self { reduction, ..self }
+    }
+
+    /// Sets a weight for each output (column), which the squared errors of that output are multiplied by.
+    /// This allows the errors of some outputs to count for more than others.
+    #[must_use]
+    pub fn with_class_weights(self, weights: impl IntoIterator<Item = ElementType>) -> Self {
+        Self {
+            class_weights: Some(weights.into_iter().collect()),
+            ..self
+        }
+    }
+
+    /// Calculates the loss and gradient with the squared error of each element multiplied by the weight
+    /// of its column and the weight of its sample (if given).
+    fn weighted(
+        &self,
+        predictions: &Tensor<rank::Two>,
+        targets: &Tensor<rank::Two>,
+        sample_weights: Option<&Tensor<rank::One>>,
+    ) -> Result<(ElementType, Tensor<rank::Two>)> {
+        let (predictions, targets) = (&predictions.0, &targets.0);
+        Error::check_shape(targets.dim(), predictions.dim())?;
+
+        // Get the weight of each element from the weights of its column and row.
+        let mut weights = Array::ones(predictions.raw_dim());
+        if let Some(class_weights) = &self.class_weights {
+            Error::check_size(predictions.ncols(), class_weights.len())?;
+            weights *= &ArrayView::from(class_weights.as_slice());
+        }
+        if let Some(sample_weights) = sample_weights {
+            Error::check_shape((predictions.nrows(), 1), (sample_weights.dim(), 1))?;
+            weights *= &sample_weights.0.view().insert_axis(Axis(1));
+        }
+
+        // Get the error first (squared error sum).
+        let error = predictions - targets;
+        let squared_error = &error * &error * &weights;
+        let squared_error_sum = squared_error.sum();
+        let count: ElementType = match self.reduction {
+            Reduction::Mean => u16::try_from(predictions.nrows())
+                .map_err(|_| Error::InvalidArgument)?
+                .into(),
+            Reduction::Sum => 1.0,
+        };
+        let squared_error_sum = squared_error_sum / count;
+
+        // Calculate the output gradient/loss gradient.
+        let average_error = error * weights / count;
+        let average_error = average_error * 2.0;
+        let average_error = Tensor(average_error);
+
+        // Return both.
+        Ok((squared_error_sum, average_error))
+    }
+}
+
+impl Default for MeanSquaredError {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -21,31 +96,22 @@ impl Loss for MeanSquaredError {
         predictions: &Tensor<rank::Two>,
         targets: &Tensor<rank::Two>,
     ) -> Result<(ElementType, Tensor<rank::Two>)> {
-        let (predictions, targets) = (&predictions.0, &targets.0);
-        let predictions_dim = predictions.raw_dim();
-        let targets_dim = targets.raw_dim();
-        if predictions_dim == targets_dim {
-            // Get the error first (squared error sum).
-            let error = predictions - targets;
-            let squared_error = &error * &error;
-            let squared_error_sum = squared_error.sum();
-            let count = u16::try_from(predictions.nrows()).map_err(|_| Error(()))?;
-            let count: ElementType = count.into();
-            let squared_error_sum = squared_error_sum / count;
-
-            // Calculate the output gradient/loss gradient.
-            let average_error = error / count;
-            let average_error = average_error * 2.0;
-            let average_error = Tensor(average_error);
-
-            // Return both.
-            Ok((squared_error_sum, average_error))
-        } else {
-            Err(Error(()))
-        }
+        self.weighted(predictions, targets, None)
+    }
+
+    fn reduction(&self) -> Option<Reduction> {
+        Some(self.reduction)
+    }
+
+    fn weighted_loss(
+        &self,
+        predictions: &Tensor<rank::Two>,
+        targets: &Tensor<rank::Two>,
+        weights: &Tensor<rank::One>,
+    ) -> Result<(ElementType, Tensor<rank::Two>)> {
+        self.weighted(predictions, targets, Some(weights))
     }
 }
-impl Sealed for MeanSquaredError {}
 
 #[cfg(test)]
 mod tests {
@@ -73,6 +139,93 @@ mod tests {
         assert_eq!(output_gradient, expected_output_gradient);
     }
 
+    #[test]
+    fn test_loss_with_sum_reduction() {
+        // Arrange
+        let mse = MeanSquaredError::new().with_reduction(Reduction::Sum);
+        let predictions = Tensor::<rank::Two>::new((3, 1), [23.0, -17.0, 22.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((3, 1), [12.0, 13.0, -7.0]).unwrap();
+        let expected_output_gradient =
+            Tensor::<rank::Two>::new((3, 1), [22.0, -60.0, 58.0]).unwrap();
+
+        // Act
+        let (loss, output_gradient) = mse.loss(&predictions, &targets).unwrap();
+
+        // Assert
+        assert_eq!(loss, 1862.0);
+        assert_eq!(output_gradient, expected_output_gradient);
+    }
+
+    #[test]
+    fn test_loss_with_class_weights() {
+        // Arrange
+        let mse = MeanSquaredError::new().with_class_weights([1.0, 3.0]);
+        let predictions = Tensor::<rank::Two>::new((2, 2), [2.0, 1.0, 0.0, -1.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((2, 2), [0.0, 0.0, 0.0, 1.0]).unwrap();
+        let expected_output_gradient =
+            Tensor::<rank::Two>::new((2, 2), [2.0, 3.0, 0.0, -6.0]).unwrap();
+
+        // Act
+        let (loss, output_gradient) = mse.loss(&predictions, &targets).unwrap();
+
+        // Assert
+        assert_eq!(loss, 9.5);
+        assert_eq!(output_gradient, expected_output_gradient);
+    }
+
+    #[test]
+    fn test_weighted_loss() {
+        // Arrange
+        let mse = MeanSquaredError::new();
+        let predictions = Tensor::<rank::Two>::new((2, 1), [2.0, -1.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((2, 1), [0.0, 1.0]).unwrap();
+        let weights = Tensor::<rank::One>::new([0.5, 2.0]);
+        let expected_output_gradient = Tensor::<rank::Two>::new((2, 1), [1.0, -4.0]).unwrap();
+
+        // Act
+        let (loss, output_gradient) = mse.weighted_loss(&predictions, &targets, &weights).unwrap();
+
+        // Assert
+        assert_eq!(loss, 5.0);
+        assert_eq!(output_gradient, expected_output_gradient);
+    }
+
+    #[test]
+    fn test_weighted_loss_row_mismatch() {
+        // Arrange
+        let mse = MeanSquaredError::new();
+        let predictions = Tensor::<rank::Two>::new((2, 1), [2.0, -1.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((2, 1), [0.0, 1.0]).unwrap();
+        let weights = Tensor::<rank::One>::new([0.5, 2.0, 1.0]);
+
+        // Act
+        let result = mse.weighted_loss(&predictions, &targets, &weights);
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(Error::BatchRowMismatch {
+                expected: 2,
+                actual: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_sample_losses() {
+        // Arrange
+        let mse = MeanSquaredError::new();
+        let predictions =
+            Tensor::<rank::Two>::new((3, 2), [23.0, 0.0, -17.0, 1.0, 22.0, 2.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((3, 2), [12.0, 0.0, 13.0, 0.0, -7.0, 0.0]).unwrap();
+
+        // Act
+        let losses = mse.sample_losses(&predictions, &targets).unwrap();
+
+        // Assert
+        assert_eq!(losses, Tensor::<rank::One>::new([121.0, 901.0, 845.0]));
+    }
+
     #[test]
     fn test_loss_error() {
         // Arrange
@@ -85,6 +238,32 @@ mod tests {
         let result = mse.loss(&predictions, &targets);
 
         // Assert
-        assert!(result.is_err());
+        assert_eq!(
+            result,
+            Err(Error::ShapeMismatch {
+                expected: 2,
+                actual: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_loss_row_mismatch() {
+        // Arrange
+        let mse = MeanSquaredError::new();
+        let predictions = Tensor::<rank::Two>::new((2, 1), [23.0, -17.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((3, 1), [12.0, 13.0, -7.0]).unwrap();
+
+        // Act
+        let result = mse.loss(&predictions, &targets);
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(Error::BatchRowMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
     }
 }
@@ -3,18 +3,34 @@
 //! various loss functions we can use.
 
 mod mean_squared_error;
+mod multi_head;
 mod softmax_cross_entropy;
 
 pub use mean_squared_error::MeanSquaredError;
+pub use multi_head::MultiHead;
 pub use softmax_cross_entropy::SoftmaxCrossEntropy;
 
-use crate::private::Sealed;
 use crate::tensors::{rank, Tensor};
-use crate::{ElementType, Result};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+
+/// How the losses of the samples (rows) of a batch are reduced into the single loss of the batch.
+/// The gradient is scaled in the same way, so that it's always the gradient of the reduced loss.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Reduction {
+    /// The losses of the samples are averaged, so the loss doesn't depend on the batch size.
+    Mean,
+    /// The losses of the samples are added together.
+    Sum,
+}
 
 /// This trait defines a loss function that can be used to calculate loss
 /// and the loss gradient for training a neural network.
-pub trait Loss: Sealed {
+///
+/// Unlike most traits in the library this trait isn't sealed, so loss functions specific to a
+/// domain (such as a quantile loss) can be implemented outside of the crate and passed to `train`.
+pub trait Loss {
     /// Calculates the loss given predictions, along with the associated targets.
     /// If the shapes don't match then an error is returned, otherwise returns the
     /// loss value, along with the loss gradient tensor.
@@ -26,4 +42,186 @@ pub trait Loss: Sealed {
         predictions: &Tensor<rank::Two>,
         targets: &Tensor<rank::Two>,
     ) -> Result<(ElementType, Tensor<rank::Two>)>;
+
+    /// Gets how the losses of the samples (rows) of a batch are reduced into the loss of the batch, which decides how
+    /// the losses and gradients of parts of a batch are combined, such as by `Trainer::train_data_parallel`. By default this
+    /// is `None`, meaning the reduction is unknown, so loss functions which average or sum the losses should say so.
+    fn reduction(&self) -> Option<Reduction> {
+        None
+    }
+
+    /// Calculates the loss in the same way as `loss`, but with the loss (and gradient) of each sample (row)
+    /// multiplied by the weight at the same index of the given weights, so that some samples count for more
+    /// than others. The provided losses reduce the weighted losses according to their reduction, but by default
+    /// the loss and gradient of each row are calculated as a batch of its own and the weighted losses averaged.
+    ///
+    /// # Errors
+    /// Returns an error if the predictions and targets don't have the same shape, if there isn't a weight
+    /// for each sample, or if there are no samples.
+    fn weighted_loss(
+        &self,
+        predictions: &Tensor<rank::Two>,
+        targets: &Tensor<rank::Two>,
+        weights: &Tensor<rank::One>,
+    ) -> Result<(ElementType, Tensor<rank::Two>)> {
+        Error::check_shape(targets.dim(), predictions.dim())?;
+        Error::check_shape((predictions.nrows(), 1), (weights.dim(), 1))?;
+        if predictions.nrows() == 0 {
+            return Err(Error::EmptyData);
+        }
+        let count = rows_to_element(predictions.nrows());
+        let mut loss = 0.0;
+        let mut gradient = Vec::with_capacity(predictions.len());
+        for (row, weight) in weights.clone().into_iter().enumerate() {
+            let (row_loss, row_gradient) = self.loss(
+                &predictions.slice_items(row..=row)?,
+                &targets.slice_items(row..=row)?,
+            )?;
+            loss = weight.mul_add(row_loss, loss);
+            gradient.extend(row_gradient.into_iter().map(|elem| elem * weight / count));
+        }
+        Ok((
+            loss / count,
+            Tensor::<rank::Two>::new(predictions.dim(), gradient)?,
+        ))
+    }
+
+    /// Calculates the loss of each sample (row) separately without reducing them, returning a tensor
+    /// with the loss of each sample in order. By default, this calculates the loss of each row as a
+    /// batch of its own, which gives the loss of the sample for any loss function which sums or averages
+    /// over the rows of a batch.
+    ///
+    /// # Errors
+    /// Returns an error if the predictions and targets don't have the same shape.
+    fn sample_losses(
+        &self,
+        predictions: &Tensor<rank::Two>,
+        targets: &Tensor<rank::Two>,
+    ) -> Result<Tensor<rank::One>> {
+        Error::check_shape(targets.dim(), predictions.dim())?;
+        let losses = (0..predictions.nrows())
+            .map(|row| {
+                let predictions = predictions.slice_items(row..=row)?;
+                let targets = targets.slice_items(row..=row)?;
+                Ok(self.loss(&predictions, &targets)?.0)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Tensor::<rank::One>::new(losses))
+    }
+}
+
+/// Converts a number of rows into an element, which may lose precision for huge batches.
+#[allow(clippy::cast_precision_loss)]
+pub(crate) const fn rows_to_element(rows: usize) -> ElementType {
+    rows as ElementType
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::Linear;
+    use crate::layers::{Chain, Dense, Input};
+    use crate::operations::{
+        InitialisedOperation, TrainableOperation, UninitialisedOperation, WithOptimiser,
+    };
+    use crate::optimisers::learning_rate_handlers::FixedLearningRateHandler;
+    use crate::optimisers::SGD;
+    use crate::training::train;
+
+    /// A quantile (pinball) loss implemented only with the public API, as a downstream crate would.
+    struct QuantileLoss(ElementType);
+
+    impl Loss for QuantileLoss {
+        fn loss(
+            &self,
+            predictions: &Tensor<rank::Two>,
+            targets: &Tensor<rank::Two>,
+        ) -> Result<(ElementType, Tensor<rank::Two>)> {
+            let count = predictions.len() as ElementType;
+            let (loss, gradient): (ElementType, Vec<_>) = predictions
+                .clone()
+                .into_iter()
+                .zip(targets.clone())
+                .map(|(prediction, target)| {
+                    let error = target - prediction;
+                    if error >= 0.0 {
+                        (self.0 * error, -self.0 / count)
+                    } else {
+                        ((self.0 - 1.0) * error, (1.0 - self.0) / count)
+                    }
+                })
+                .fold((0.0, Vec::new()), |(sum, mut gradient), (loss, elem)| {
+                    gradient.push(elem);
+                    (sum + loss, gradient)
+                });
+            Ok((
+                loss / count,
+                Tensor::<rank::Two>::new(predictions.dim(), gradient)?,
+            ))
+        }
+    }
+
+    #[test]
+    fn test_custom_loss() {
+        // Arrange
+        let network = Input::new(1)
+            .chain(Dense::new(1, Linear::new()))
+            .with_iter([0.0, 0.0].into_iter())
+            .unwrap()
+            .with_optimiser(SGD::new(FixedLearningRateHandler::new(0.1)));
+        let batch = Tensor::<rank::Two>::new((4, 1), [1.0, 1.0, 1.0, 1.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((4, 1), [1.0, 2.0, 3.0, 4.0]).unwrap();
+        let loss_function = QuantileLoss(0.9);
+
+        // Act
+        let network = train(
+            network,
+            &loss_function,
+            batch.clone(),
+            targets.clone(),
+            &batch,
+            &targets,
+            50,
+            50,
+            4,
+            42,
+        )
+        .unwrap()
+        .into_initialised();
+        let output = network.predict(batch).unwrap().into_iter().next().unwrap();
+
+        // Assert
+        // the 0.9 quantile predicts above most of the targets.
+        assert!(output > 3.0);
+    }
+
+    #[test]
+    fn test_default_weighted_loss() {
+        // Arrange
+        let predictions = Tensor::<rank::Two>::new((2, 1), [0.0, 0.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((2, 1), [1.0, -1.0]).unwrap();
+        let weights = Tensor::<rank::One>::new([2.0, 1.0]);
+        let expected_gradient = Tensor::<rank::Two>::new((2, 1), [-0.9, 0.05]).unwrap();
+
+        // Act
+        let (loss, gradient) = QuantileLoss(0.9)
+            .weighted_loss(&predictions, &targets, &weights)
+            .unwrap();
+
+        // Assert
+        assert!((loss - 0.95).abs() < 1e-5);
+        assert!(gradient.approx_eq(&expected_gradient, 1e-5));
+    }
+
+    #[test]
+    fn test_default_reduction() {
+        // Arrange
+        let loss_function = QuantileLoss(0.9);
+
+        // Act
+        let reduction = loss_function.reduction();
+
+        // Assert
+        assert_eq!(reduction, None);
+    }
 }
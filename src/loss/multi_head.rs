@@ -0,0 +1,246 @@
+use crate::loss::{Loss, Reduction};
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt;
+use ndarray::{concatenate, ArrayBase, Axis};
+
+/// A single head of a `MultiHead` loss, covering a number of consecutive outputs of the network.
+struct Head {
+    loss: Box<dyn Loss + Send + Sync>,
+    outputs: usize,
+    weight: ElementType,
+}
+
+/// This is a loss function for networks with several output heads (such as classification and regression
+/// heads on a shared trunk) whose outputs are placed side by side, as with `Parallel::heads`. Each head takes
+/// its own columns of the predictions and targets, in the order the heads are added, and has its own loss function.
+/// The loss is the weighted sum of the losses of the heads, and the gradient of each head is scaled by its weight.
+#[derive(Default)]
+pub struct MultiHead {
+    heads: Vec<Head>,
+}
+
+impl MultiHead {
+    /// Constructs a new instance of the `MultiHead` loss function without any heads.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { heads: Vec::new() }
+    }
+
+    /// Adds a head covering the next `outputs` columns of the predictions and targets, whose loss is calculated
+    /// with the given loss function and multiplied by the given weight.
+    #[must_use]
+    pub fn with_head(
+        mut self,
+        loss: impl Loss + Send + Sync + 'static,
+        outputs: usize,
+        weight: ElementType,
+    ) -> Self {
+        self.heads.push(Head {
+            loss: Box::new(loss),
+            outputs,
+            weight,
+        });
+        self
+    }
+
+    /// Gets the total number of outputs covered by the heads.
+    #[must_use]
+    pub fn outputs(&self) -> usize {
+        self.heads.iter().map(|head| head.outputs).sum()
+    }
+
+    /// Calculates the (unweighted) loss of each head separately, which is useful for monitoring
+    /// the heads during training.
+    ///
+    /// # Errors
+    /// Returns an error if the predictions and targets don't have the same shape, or if the heads
+    /// don't cover all of their columns.
+    pub fn head_losses(
+        &self,
+        predictions: &Tensor<rank::Two>,
+        targets: &Tensor<rank::Two>,
+    ) -> Result<Vec<ElementType>> {
+        let mut losses = Vec::with_capacity(self.heads.len());
+        self.combine(predictions, targets, |loss, predictions, targets| {
+            let (head_loss, gradient) = loss.loss(predictions, targets)?;
+            losses.push(head_loss);
+            Ok((head_loss, gradient))
+        })?;
+        Ok(losses)
+    }
+
+    /// Calculates the loss of each head with the given function on its own columns, and combines
+    /// them into the loss and gradient of the whole network.
+    fn combine(
+        &self,
+        predictions: &Tensor<rank::Two>,
+        targets: &Tensor<rank::Two>,
+        mut f: impl FnMut(
+            &dyn Loss,
+            &Tensor<rank::Two>,
+            &Tensor<rank::Two>,
+        ) -> Result<(ElementType, Tensor<rank::Two>)>,
+    ) -> Result<(ElementType, Tensor<rank::Two>)> {
+        Error::check_shape(targets.dim(), predictions.dim())?;
+        Error::check_size(self.outputs(), predictions.ncols())?;
+        let mut total = 0.0;
+        let mut gradients = Vec::with_capacity(self.heads.len());
+        let mut start = 0;
+        for head in &self.heads {
+            let columns = start..start + head.outputs;
+            start = columns.end;
+            let (loss, gradient) = f(
+                &*head.loss,
+                &predictions.slice(.., columns.clone())?,
+                &targets.slice(.., columns)?,
+            )?;
+            total = head.weight.mul_add(loss, total);
+            gradients.push(gradient.0 * head.weight);
+        }
+        let gradients = gradients.iter().map(ArrayBase::view).collect::<Vec<_>>();
+        let gradient = concatenate(Axis(1), &gradients).map_err(|_| Error::EmptyData)?;
+        Ok((total, Tensor(gradient)))
+    }
+}
+
+impl fmt::Debug for MultiHead {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MultiHead")
+            .field(
+                "heads",
+                &self
+                    .heads
+                    .iter()
+                    .map(|head| (head.outputs, head.weight))
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl Loss for MultiHead {
+    fn loss(
+        &self,
+        predictions: &Tensor<rank::Two>,
+        targets: &Tensor<rank::Two>,
+    ) -> Result<(ElementType, Tensor<rank::Two>)> {
+        self.combine(predictions, targets, |loss, predictions, targets| {
+            loss.loss(predictions, targets)
+        })
+    }
+
+    fn reduction(&self) -> Option<Reduction> {
+        let mut reductions = self.heads.iter().map(|head| head.loss.reduction());
+        let first = reductions.next().unwrap_or(Some(Reduction::Mean));
+        if reductions.all(|reduction| reduction == first) {
+            first
+        } else {
+            None
+        }
+    }
+
+    fn weighted_loss(
+        &self,
+        predictions: &Tensor<rank::Two>,
+        targets: &Tensor<rank::Two>,
+        weights: &Tensor<rank::One>,
+    ) -> Result<(ElementType, Tensor<rank::Two>)> {
+        self.combine(predictions, targets, |loss, predictions, targets| {
+            loss.weighted_loss(predictions, targets, weights)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::loss::{MeanSquaredError, SoftmaxCrossEntropy};
+
+    fn heads() -> MultiHead {
+        MultiHead::new()
+            .with_head(SoftmaxCrossEntropy::new(), 2, 0.5)
+            .with_head(MeanSquaredError::new(), 1, 2.0)
+    }
+
+    #[test]
+    fn test_loss() {
+        // Arrange
+        let predictions =
+            Tensor::<rank::Two>::new((2, 3), [0.25, 0.75, 3.0, 0.75, 0.25, -1.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((2, 3), [0.0, 1.0, 1.0, 1.0, 0.0, 0.0]).unwrap();
+        let classification = predictions.slice(.., 0..2).unwrap();
+        let (expected_loss, expected_gradient) = SoftmaxCrossEntropy::new()
+            .loss(&classification, &targets.slice(.., 0..2).unwrap())
+            .unwrap();
+
+        // Act
+        let (loss, gradient) = heads().loss(&predictions, &targets).unwrap();
+
+        // Assert
+        assert!((loss - expected_loss.mul_add(0.5, 5.0)).abs() < 1e-5);
+        assert!(gradient
+            .slice(.., 0..2)
+            .unwrap()
+            .approx_eq(&Tensor(expected_gradient.0 * 0.5), 1e-5));
+        assert_eq!(
+            gradient.slice(.., 2..3).unwrap(),
+            Tensor::<rank::Two>::new((2, 1), [4.0, -2.0]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_head_losses() {
+        // Arrange
+        let predictions =
+            Tensor::<rank::Two>::new((2, 3), [0.25, 0.75, 3.0, 0.75, 0.25, -1.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((2, 3), [0.0, 1.0, 1.0, 1.0, 0.0, 0.0]).unwrap();
+
+        // Act
+        let losses = heads().head_losses(&predictions, &targets).unwrap();
+
+        // Assert
+        assert_eq!(losses.len(), 2);
+        assert_eq!(losses[1], 2.5);
+    }
+
+    #[test]
+    fn test_loss_with_uncovered_outputs() {
+        // Arrange
+        let predictions = Tensor::<rank::Two>::new((1, 4), [0.25, 0.75, 3.0, 1.0]).unwrap();
+
+        // Act
+        let result = heads().loss(&predictions, &predictions);
+
+        // Assert
+        assert_eq!(
+            result.map(|_| ()),
+            Err(Error::ShapeMismatch {
+                expected: 3,
+                actual: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_reduction() {
+        // Arrange
+        let summed = MultiHead::new()
+            .with_head(SoftmaxCrossEntropy::new(), 2, 0.5)
+            .with_head(
+                MeanSquaredError::new().with_reduction(Reduction::Sum),
+                1,
+                2.0,
+            );
+
+        // Act
+        let mixed = heads().reduction();
+        let summed = summed.reduction();
+
+        // Assert
+        assert_eq!(mixed, None);
+        assert_eq!(summed, Some(Reduction::Sum));
+    }
+}
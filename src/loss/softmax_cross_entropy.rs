@@ -1,13 +1,27 @@
-use crate::loss::Loss;
-use crate::private::Sealed;
+use crate::loss::{rows_to_element, Loss, Reduction};
 use crate::tensors::{rank, Tensor};
 use crate::{ElementType, Error, Result};
-use ndarray::{Array, Axis, Ix2};
+use alloc::vec::Vec;
+use ndarray::{Array, ArrayView, Axis, Ix1, Ix2};
 
 /// This is a loss function which is specialised for calculating the loss
 /// for classification problems where the outputs should represent probabilities of
 /// being in a certain class. If there's only a single feature/column then it will use
-pub struct SoftmaxCrossEntropy(());
+/// that column as the positive class of a binary classification, adding one minus each
+/// prediction and target as the negative class, and only the gradient of the original
+/// column is returned.
+///
+/// By default, the losses of the samples of a batch are summed.
+///
+/// Class weights can be given to counter imbalanced classes, in which case the loss and gradient of
+/// each sample are multiplied by the weights of the classes it belongs to (according to its targets).
+/// For a single column, the weights are of the positive class and then the negative class.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SoftmaxCrossEntropy {
+    reduction: Reduction,
+    class_weights: Option<Vec<ElementType>>,
+}
 
 impl SoftmaxCrossEntropy {
     /// Constructs a new instance of the `SoftmaxCrossEntropy` loss
@@ -15,57 +29,120 @@ impl SoftmaxCrossEntropy {
     /// where the output is based on probabilities.
     #[must_use]
     pub const fn new() -> Self {
-        Self(())
+        Self {
+            reduction: Reduction::Sum,
+            class_weights: None,
+        }
     }
-}
 
-impl Loss for SoftmaxCrossEntropy {
-    fn loss(
+    /// Sets how the losses of the samples of a batch are reduced into a single loss.
+    #[must_use]
+    pub fn with_reduction(self, reduction: Reduction) -> Self {
+        Self { reduction, ..self }
+    }
+
+    /// Sets a weight for each class, which the losses of the samples of that class are multiplied by.
+    /// This allows rare classes to count for more than common ones in imbalanced problems.
+    #[must_use]
+    pub fn with_class_weights(self, weights: impl IntoIterator<Item = ElementType>) -> Self {
+        Self {
+            class_weights: Some(weights.into_iter().collect()),
+            ..self
+        }
+    }
+
+    /// Calculates the loss and gradient with the loss of each sample multiplied by the weights of its
+    /// classes and its own weight (if given).
+    fn weighted(
         &self,
         predictions: &Tensor<rank::Two>,
         targets: &Tensor<rank::Two>,
+        sample_weights: Option<&Tensor<rank::One>>,
     ) -> Result<(ElementType, Tensor<rank::Two>)> {
         let (predictions, targets) = (&predictions.0, &targets.0);
-        let (predictions_dim, targets_dim) = (predictions.raw_dim(), targets.raw_dim());
-        if predictions_dim == targets_dim {
-            // Map the single class predictions to multi-class ones by adding a dummy feature.
-            let is_single_class = predictions_dim[1] == 1;
-            let (predictions, targets) = if is_single_class {
-                (
-                    single_class_to_dual(predictions),
-                    single_class_to_dual(targets),
-                )
-            } else {
-                ((*predictions).clone(), (*targets).clone())
-            };
-
-            // calculate the softmaxed predictions.
-            let predictions = calculate_softmax_predictions(predictions);
-
-            // calculate the output sum.
-            let minuend = targets.mapv(|elem| -elem) * predictions.mapv(ElementType::ln);
-            let subtrahend =
-                targets.mapv(|elem| 1.0 - elem) * predictions.mapv(|elem| (1.0 - elem).ln());
-            let loss = minuend - subtrahend;
-            let loss = loss.sum();
-
-            // calculate the input gradient for the backward pass.
-            let loss_gradient = predictions - targets;
-            let loss_gradient = if is_single_class {
-                dual_class_to_single(&loss_gradient)
-            } else {
-                loss_gradient
-            };
-            let loss_gradient = Tensor(loss_gradient);
-
-            // done!
-            Ok((loss, loss_gradient))
+        Error::check_shape(targets.dim(), predictions.dim())?;
+
+        // Map the single class predictions to multi-class ones by adding a dummy feature.
+        let is_single_class = predictions.ncols() == 1;
+        let (predictions, targets) = if is_single_class {
+            (
+                single_class_to_dual(predictions),
+                single_class_to_dual(targets),
+            )
         } else {
-            Err(Error(()))
+            ((*predictions).clone(), (*targets).clone())
+        };
+
+        // calculate the weight of each sample from its classes and its own weight.
+        let mut weights = match &self.class_weights {
+            Some(class_weights) => {
+                Error::check_size(targets.ncols(), class_weights.len())?;
+                targets.dot(&ArrayView::from(class_weights.as_slice()))
+            }
+            None => Array::<ElementType, Ix1>::ones(predictions.nrows()),
+        };
+        if let Some(sample_weights) = sample_weights {
+            Error::check_shape((predictions.nrows(), 1), (sample_weights.dim(), 1))?;
+            weights *= &sample_weights.0;
         }
+        let weights = weights.insert_axis(Axis(1));
+
+        // calculate the softmaxed predictions.
+        let predictions = calculate_softmax_predictions(predictions);
+
+        // calculate the output sum.
+        let minuend = targets.mapv(|elem| -elem) * predictions.mapv(ElementType::ln);
+        let subtrahend =
+            targets.mapv(|elem| 1.0 - elem) * predictions.mapv(|elem| (1.0 - elem).ln());
+        let loss = (minuend - subtrahend) * &weights;
+        let count = match self.reduction {
+            Reduction::Mean => rows_to_element(predictions.nrows()),
+            Reduction::Sum => 1.0,
+        };
+        let loss = loss.sum() / count;
+
+        // calculate the input gradient for the backward pass.
+        let loss_gradient = (predictions - targets) * &weights / count;
+        let loss_gradient = if is_single_class {
+            dual_class_to_single(&loss_gradient)
+        } else {
+            loss_gradient
+        };
+        let loss_gradient = Tensor(loss_gradient);
+
+        // done!
+        Ok((loss, loss_gradient))
+    }
+}
+
+impl Default for SoftmaxCrossEntropy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Loss for SoftmaxCrossEntropy {
+    fn loss(
+        &self,
+        predictions: &Tensor<rank::Two>,
+        targets: &Tensor<rank::Two>,
+    ) -> Result<(ElementType, Tensor<rank::Two>)> {
+        self.weighted(predictions, targets, None)
+    }
+
+    fn reduction(&self) -> Option<Reduction> {
+        Some(self.reduction)
+    }
+
+    fn weighted_loss(
+        &self,
+        predictions: &Tensor<rank::Two>,
+        targets: &Tensor<rank::Two>,
+        weights: &Tensor<rank::One>,
+    ) -> Result<(ElementType, Tensor<rank::Two>)> {
+        self.weighted(predictions, targets, Some(weights))
     }
 }
-impl Sealed for SoftmaxCrossEntropy {}
 
 fn calculate_softmax_predictions(predictions: Array<ElementType, Ix2>) -> Array<ElementType, Ix2> {
     assert_ne!(predictions.ncols(), 1); // shouldn't be called with only a single feature.
@@ -109,22 +186,19 @@ mod tests {
     #[test]
     fn test_softmax_with_three_classes_and_a_single_observation() {
         // Arrange
-        let input = Array::from_iter([5.0, 3.0, 2.0].into_iter())
+        let input = Array::from_iter([5.0, 3.0, 2.0])
             .into_shape((1, 3))
             .unwrap();
         #[cfg(not(feature = "f32"))]
-        let expected = Array::from_iter(
-            [
-                0.8437947344813395,
-                0.11419519938459449,
-                0.042010066134066056,
-            ]
-            .into_iter(),
-        )
+        let expected = Array::from_iter([
+            0.8437947344813395,
+            0.11419519938459449,
+            0.042010066134066056,
+        ])
         .into_shape((1, 3))
         .unwrap();
         #[cfg(feature = "f32")]
-        let expected = Array::from_iter([0.8437947, 0.1141952, 0.042010065].into_iter())
+        let expected = Array::from_iter([0.8437947, 0.1141952, 0.042010065])
             .into_shape((1, 3))
             .unwrap();
 
@@ -138,41 +212,35 @@ mod tests {
     #[test]
     fn test_softmax_with_three_classes_and_three_observations() {
         // Arrange
-        let input = Array::from_iter([1.0, 2.0, 3.0, 6.0, 4.0, 5.0, 8.0, 7.0, 9.0].into_iter())
+        let input = Array::from_iter([1.0, 2.0, 3.0, 6.0, 4.0, 5.0, 8.0, 7.0, 9.0])
             .into_shape((3, 3))
             .unwrap();
         #[cfg(not(feature = "f32"))]
-        let expected = Array::from_iter(
-            [
-                0.09003057317038046,
-                0.24472847105479767,
-                0.6652409557748219,
-                0.6652409557748219,
-                0.09003057317038045,
-                0.24472847105479764,
-                0.24472847105479764,
-                0.09003057317038045,
-                0.6652409557748219,
-            ]
-            .into_iter(),
-        )
+        let expected = Array::from_iter([
+            0.09003057317038046,
+            0.24472847105479767,
+            0.6652409557748219,
+            0.6652409557748219,
+            0.09003057317038045,
+            0.24472847105479764,
+            0.24472847105479764,
+            0.09003057317038045,
+            0.6652409557748219,
+        ])
         .into_shape((3, 3))
         .unwrap();
         #[cfg(feature = "f32")]
-        let expected = Array::from_iter(
-            [
-                0.09003057,
-                0.24472848,
-                0.66524094,
-                0.66524094,
-                0.090030566,
-                0.24472846,
-                0.24472846,
-                0.09003057,
-                0.66524094,
-            ]
-            .into_iter(),
-        )
+        let expected = Array::from_iter([
+            0.09003057,
+            0.24472848,
+            0.66524094,
+            0.66524094,
+            0.090030566,
+            0.24472846,
+            0.24472846,
+            0.09003057,
+            0.66524094,
+        ])
         .into_shape((3, 3))
         .unwrap();
 
@@ -193,7 +261,7 @@ mod tests {
     #[should_panic]
     fn test_single_class_to_dual_with_too_many_features() {
         single_class_to_dual(
-            &Array::from_iter([1.0, 2.0, 3.0, 4.0, 5.0, 6.0].into_iter())
+            &Array::from_iter([1.0, 2.0, 3.0, 4.0, 5.0, 6.0])
                 .into_shape((3, 2))
                 .unwrap(),
         );
@@ -202,10 +270,10 @@ mod tests {
     #[test]
     fn test_single_class_to_dual_with_a_single_feature() {
         // Arrange
-        let input = Array::from_iter([0.5, 0.75, 0.35].into_iter())
+        let input = Array::from_iter([0.5, 0.75, 0.35])
             .into_shape((3, 1))
             .unwrap();
-        let expected = Array::from_iter([0.5, 0.5, 0.75, 0.25, 0.35, 0.65].into_iter())
+        let expected = Array::from_iter([0.5, 0.5, 0.75, 0.25, 0.35, 0.65])
             .into_shape((3, 2))
             .unwrap();
 
@@ -220,7 +288,7 @@ mod tests {
     #[should_panic]
     fn test_dual_class_to_single_with_too_few_features() {
         dual_class_to_single(
-            &Array::from_iter([0.5, 0.25, 0.35].into_iter())
+            &Array::from_iter([0.5, 0.25, 0.35])
                 .into_shape((3, 1))
                 .unwrap(),
         );
@@ -230,7 +298,7 @@ mod tests {
     #[should_panic]
     fn test_dual_class_to_single_with_too_many_features() {
         dual_class_to_single(
-            &Array::from_iter([0.5, 0.25, 0.35, 0.5, 0.25, 0.35, 0.5, 0.25, 0.35].into_iter())
+            &Array::from_iter([0.5, 0.25, 0.35, 0.5, 0.25, 0.35, 0.5, 0.25, 0.35])
                 .into_shape((3, 3))
                 .unwrap(),
         );
@@ -239,10 +307,10 @@ mod tests {
     #[test]
     fn test_dual_class_to_single_with_two_features() {
         // Arrange
-        let input = Array::from_iter([0.5, 0.5, 0.75, 0.25, 0.35, 0.65].into_iter())
+        let input = Array::from_iter([0.5, 0.5, 0.75, 0.25, 0.35, 0.65])
             .into_shape((3, 2))
             .unwrap();
-        let expected = Array::from_iter([0.5, 0.75, 0.35].into_iter())
+        let expected = Array::from_iter([0.5, 0.75, 0.35])
             .into_shape((3, 1))
             .unwrap();
 
@@ -330,6 +398,123 @@ mod tests {
         assert_eq!(gradient, expected_gradient);
     }
 
+    #[test]
+    fn test_loss_with_mean_reduction() {
+        // Arrange
+        let predictions =
+            Tensor::<rank::Two>::new((3, 2), [0.25, 0.75, 0.75, 0.25, 0.45, 0.55]).unwrap();
+        let targets = Tensor::<rank::Two>::new((3, 2), [0.0, 1.0, 1.0, 0.0, 0.0, 1.0]).unwrap();
+        let (sum, sum_gradient) = SoftmaxCrossEntropy::new()
+            .loss(&predictions, &targets)
+            .unwrap();
+
+        // Act
+        let (mean, mean_gradient) = SoftmaxCrossEntropy::new()
+            .with_reduction(Reduction::Mean)
+            .loss(&predictions, &targets)
+            .unwrap();
+
+        // Assert
+        assert!(mean.mul_add(3.0, -sum).abs() < 1e-5);
+        assert!(Tensor(mean_gradient.0 * 3.0).approx_eq(&sum_gradient, 1e-5));
+    }
+
+    #[test]
+    fn test_loss_with_class_weights() {
+        // Arrange
+        let predictions =
+            Tensor::<rank::Two>::new((3, 2), [0.25, 0.75, 0.75, 0.25, 0.45, 0.55]).unwrap();
+        let targets = Tensor::<rank::Two>::new((3, 2), [0.0, 1.0, 1.0, 0.0, 0.0, 1.0]).unwrap();
+        let loss_function = SoftmaxCrossEntropy::new();
+        let (_, unweighted_gradient) = loss_function.loss(&predictions, &targets).unwrap();
+        let losses = loss_function.sample_losses(&predictions, &targets).unwrap();
+        let expected = losses
+            .into_iter()
+            .zip([1.0, 4.0, 1.0])
+            .map(|(loss, weight)| loss * weight)
+            .sum::<ElementType>();
+
+        // Act
+        let (loss, gradient) = loss_function
+            .with_class_weights([4.0, 1.0])
+            .loss(&predictions, &targets)
+            .unwrap();
+
+        // Assert
+        assert!((loss - expected).abs() < 1e-5);
+        assert!(gradient.slice_items(1..=1).unwrap().approx_eq(
+            &Tensor(unweighted_gradient.slice_items(1..=1).unwrap().0 * 4.0),
+            1e-5
+        ));
+    }
+
+    #[test]
+    fn test_loss_with_class_weights_and_single_class() {
+        // Arrange
+        let predictions = Tensor::<rank::Two>::new((2, 1), [0.25, 0.75]).unwrap();
+        let targets = Tensor::<rank::Two>::new((2, 1), [1.0, 0.0]).unwrap();
+        let loss_function = SoftmaxCrossEntropy::new();
+        let (_, unweighted_gradient) = loss_function.loss(&predictions, &targets).unwrap();
+
+        // Act
+        let (_, gradient) = loss_function
+            .with_class_weights([3.0, 0.5])
+            .loss(&predictions, &targets)
+            .unwrap();
+
+        // Assert
+        let expected = unweighted_gradient
+            .into_iter()
+            .zip([3.0, 0.5])
+            .map(|(elem, weight)| elem * weight);
+        assert!(gradient.approx_eq(&Tensor::<rank::Two>::new((2, 1), expected).unwrap(), 1e-5));
+    }
+
+    #[test]
+    fn test_weighted_loss() {
+        // Arrange
+        let predictions =
+            Tensor::<rank::Two>::new((3, 2), [0.25, 0.75, 0.75, 0.25, 0.45, 0.55]).unwrap();
+        let targets = Tensor::<rank::Two>::new((3, 2), [0.0, 1.0, 1.0, 0.0, 0.0, 1.0]).unwrap();
+        let weights = Tensor::<rank::One>::new([0.0, 2.0, 1.0]);
+        let loss_function = SoftmaxCrossEntropy::new();
+        let losses = loss_function.sample_losses(&predictions, &targets).unwrap();
+        let expected = losses
+            .into_iter()
+            .zip(weights.clone())
+            .map(|(loss, weight)| loss * weight)
+            .sum::<ElementType>();
+
+        // Act
+        let (loss, gradient) = loss_function
+            .weighted_loss(&predictions, &targets, &weights)
+            .unwrap();
+
+        // Assert
+        assert!((loss - expected).abs() < 1e-5);
+        assert_eq!(
+            gradient.slice_items(0..=0).unwrap(),
+            Tensor::<rank::Two>::new((1, 2), [0.0, 0.0]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_sample_losses() {
+        // Arrange
+        let predictions =
+            Tensor::<rank::Two>::new((3, 2), [0.25, 0.75, 0.75, 0.25, 0.45, 0.55]).unwrap();
+        let targets = Tensor::<rank::Two>::new((3, 2), [0.0, 1.0, 1.0, 0.0, 0.0, 1.0]).unwrap();
+        let loss_function = SoftmaxCrossEntropy::new();
+        let (expected, _) = loss_function.loss(&predictions, &targets).unwrap();
+
+        // Act
+        let losses = loss_function.sample_losses(&predictions, &targets).unwrap();
+
+        // Assert
+        assert_eq!(losses.len(), 3);
+        assert!((losses.into_iter().sum::<ElementType>() - expected).abs() < 1e-5);
+    }
+
     #[test]
     fn test_loss_error() {
         // Arrange
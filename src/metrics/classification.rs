@@ -0,0 +1,447 @@
+use crate::metrics::Metric;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use ndarray::{Array, ArrayView, Axis, Ix1, Ix2};
+
+/// Finds the index of the largest element in the row, which is the class that
+/// a row of predictions (or one-hot encoded targets) represents.
+pub fn class_of(row: ArrayView<ElementType, Ix1>) -> usize {
+    row.iter()
+        .enumerate()
+        .max_by(|(_, lhs), (_, rhs)| lhs.total_cmp(rhs))
+        .map_or(0, |(index, _)| index)
+}
+
+/// Builds the confusion matrix for the predictions of a multi-class classifier, where each
+/// row of the predictions and targets is a class (one-hot encoded in the case of the targets).
+/// The element at (i, j) counts the observations of class i which were predicted as class j.
+fn confusion_matrix(
+    predictions: &Tensor<rank::Two>,
+    targets: &Tensor<rank::Two>,
+) -> Result<Array<ElementType, Ix2>> {
+    let (predictions, targets) = (&predictions.0, &targets.0);
+    Error::check_shape(predictions.dim(), targets.dim())?;
+    if predictions.is_empty() {
+        return Err(Error::EmptyData);
+    }
+    let classes = predictions.ncols();
+    let mut matrix = Array::zeros((classes, classes));
+    predictions
+        .rows()
+        .into_iter()
+        .zip(targets.rows())
+        .for_each(|(prediction, target)| matrix[[class_of(target), class_of(prediction)]] += 1.0);
+    Ok(matrix)
+}
+
+/// Calculates the precision and recall of each class from the confusion matrix, for those classes
+/// which appear in the targets. A class which is never predicted has a precision of zero.
+fn precision_recall(
+    matrix: &Array<ElementType, Ix2>,
+) -> impl Iterator<Item = (ElementType, ElementType)> + '_ {
+    let predicted = matrix.sum_axis(Axis(0));
+    matrix
+        .rows()
+        .into_iter()
+        .enumerate()
+        .filter_map(move |(class, row)| {
+            let (support, true_positives) = (row.sum(), row[class]);
+            let precision = if predicted[class] > 0.0 {
+                true_positives / predicted[class]
+            } else {
+                0.0
+            };
+            (support > 0.0).then(|| (precision, true_positives / support))
+        })
+}
+
+/// Calculates the mean of the given values.
+fn mean(values: impl Iterator<Item = ElementType>) -> ElementType {
+    let (sum, count) = values.fold((0.0, 0.0), |(sum, count), value| (sum + value, count + 1.0));
+    sum / count
+}
+
+/// Calculates the balanced accuracy of a multi-class classifier, which is the mean of the
+/// recall of each class (the proportion of the observations of the class which were predicted
+/// correctly). Unlike plain accuracy, each class contributes equally regardless of how many
+/// observations it has, which makes it a fairer measure when classes are imbalanced.
+/// Each row of the targets should be one-hot encoded, and the predicted class for a row is the
+/// column with the largest prediction. Classes which don't appear in the targets are ignored.
+///
+/// # Errors
+/// `Error` if the predictions and targets aren't the same shape, or if they're empty.
+pub fn balanced_accuracy(
+    predictions: &Tensor<rank::Two>,
+    targets: &Tensor<rank::Two>,
+) -> Result<ElementType> {
+    let matrix = confusion_matrix(predictions, targets)?;
+    let (recall_sum, class_count) = matrix
+        .rows()
+        .into_iter()
+        .enumerate()
+        .filter_map(|(class, row)| {
+            let support = row.sum();
+            (support > 0.0).then(|| row[class] / support)
+        })
+        .fold((0.0, 0.0), |(sum, count), recall| {
+            (sum + recall, count + 1.0)
+        });
+    Ok(recall_sum / class_count)
+}
+
+/// A metric which calculates the proportion of rows where the predicted class (the column with the
+/// largest prediction) matches the class of the one-hot encoded targets.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Accuracy;
+
+impl Accuracy {
+    /// Constructs a new accuracy metric.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Metric for Accuracy {
+    fn evaluate(
+        &self,
+        predictions: &Tensor<rank::Two>,
+        targets: &Tensor<rank::Two>,
+    ) -> Result<ElementType> {
+        let matrix = confusion_matrix(predictions, targets)?;
+        Ok(matrix.diag().sum() / matrix.sum())
+    }
+}
+
+/// A metric which calculates the precision of a multi-class classifier, which is the proportion of the
+/// rows predicted as a class that really are of that class. The precision is calculated for each class
+/// that appears in the (one-hot encoded) targets and then averaged, so that each class contributes equally.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Precision;
+
+impl Precision {
+    /// Constructs a new precision metric.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Metric for Precision {
+    fn evaluate(
+        &self,
+        predictions: &Tensor<rank::Two>,
+        targets: &Tensor<rank::Two>,
+    ) -> Result<ElementType> {
+        let matrix = confusion_matrix(predictions, targets)?;
+        Ok(mean(
+            precision_recall(&matrix).map(|(precision, _)| precision),
+        ))
+    }
+}
+
+/// A metric which calculates the recall of a multi-class classifier, which is the proportion of the
+/// rows of a class that were predicted as that class. The recall is calculated for each class that
+/// appears in the (one-hot encoded) targets and then averaged, which makes it the same as the balanced accuracy.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Recall;
+
+impl Recall {
+    /// Constructs a new recall metric.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Metric for Recall {
+    fn evaluate(
+        &self,
+        predictions: &Tensor<rank::Two>,
+        targets: &Tensor<rank::Two>,
+    ) -> Result<ElementType> {
+        balanced_accuracy(predictions, targets)
+    }
+}
+
+/// A metric which calculates the F1 score of a multi-class classifier, which is the harmonic mean of the
+/// precision and recall. The score is calculated for each class that appears in the (one-hot encoded)
+/// targets and then averaged, with a class that has neither precision nor recall scoring zero.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct F1;
+
+impl F1 {
+    /// Constructs a new F1 score metric.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Metric for F1 {
+    fn evaluate(
+        &self,
+        predictions: &Tensor<rank::Two>,
+        targets: &Tensor<rank::Two>,
+    ) -> Result<ElementType> {
+        let matrix = confusion_matrix(predictions, targets)?;
+        Ok(mean(precision_recall(&matrix).map(
+            |(precision, recall)| {
+                if precision + recall > 0.0 {
+                    2.0 * precision * recall / (precision + recall)
+                } else {
+                    0.0
+                }
+            },
+        )))
+    }
+}
+
+/// A metric which calculates the proportion of rows where the class of the one-hot encoded targets
+/// is one of the k classes with the largest predictions.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TopK {
+    k: usize,
+}
+
+impl TopK {
+    /// Constructs a new top-k accuracy metric which counts a row as correct if the
+    /// target class is within the given number of largest predictions.
+    #[must_use]
+    pub const fn new(k: usize) -> Self {
+        Self { k }
+    }
+}
+
+impl Metric for TopK {
+    fn evaluate(
+        &self,
+        predictions: &Tensor<rank::Two>,
+        targets: &Tensor<rank::Two>,
+    ) -> Result<ElementType> {
+        let (predictions, targets) = (&predictions.0, &targets.0);
+        Error::check_shape(predictions.dim(), targets.dim())?;
+        if predictions.is_empty() {
+            return Err(Error::EmptyData);
+        }
+        if self.k == 0 {
+            return Err(Error::InvalidArgument);
+        }
+        Ok(mean(
+            predictions
+                .rows()
+                .into_iter()
+                .zip(targets.rows())
+                .map(|(prediction, target)| {
+                    let target_prediction = prediction[class_of(target)];
+                    let ranked_above = prediction
+                        .iter()
+                        .filter(|elem| **elem > target_prediction)
+                        .count();
+                    if ranked_above < self.k {
+                        1.0
+                    } else {
+                        0.0
+                    }
+                }),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_confusion_matrix() {
+        // Arrange
+        let predictions = Tensor::<rank::Two>::new((3, 2), [0.9, 0.1, 0.4, 0.6, 0.8, 0.2]).unwrap();
+        let targets = Tensor::<rank::Two>::new((3, 2), [1.0, 0.0, 1.0, 0.0, 0.0, 1.0]).unwrap();
+        let expected = Array::from_shape_vec((2, 2), [1.0, 1.0, 1.0, 0.0].to_vec()).unwrap();
+
+        // Act
+        let output = confusion_matrix(&predictions, &targets).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_balanced_accuracy_imbalanced() {
+        // Arrange
+        let predictions = Tensor::<rank::Two>::new(
+            (10, 3),
+            [
+                [0.8, 0.1, 0.1],
+                [0.8, 0.1, 0.1],
+                [0.8, 0.1, 0.1],
+                [0.8, 0.1, 0.1],
+                [0.8, 0.1, 0.1],
+                [0.8, 0.1, 0.1],
+                [0.8, 0.1, 0.1],
+                [0.8, 0.1, 0.1],
+                [0.1, 0.8, 0.1],
+                [0.8, 0.1, 0.1],
+            ]
+            .into_iter()
+            .flatten(),
+        )
+        .unwrap();
+        let targets = Tensor::<rank::Two>::new(
+            (10, 3),
+            [
+                [1.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0],
+                [0.0, 0.0, 1.0],
+            ]
+            .into_iter()
+            .flatten(),
+        )
+        .unwrap();
+        let accuracy = 0.9;
+
+        // Act
+        let output = balanced_accuracy(&predictions, &targets).unwrap();
+
+        // Assert
+        assert!((output - 2.0 / 3.0).abs() < 1e-6);
+        assert!(accuracy - output > 0.2);
+    }
+
+    #[test]
+    fn test_balanced_accuracy_perfect() {
+        // Arrange
+        let predictions = Tensor::<rank::Two>::new((3, 2), [0.9, 0.1, 0.4, 0.6, 0.8, 0.2]).unwrap();
+        let targets = Tensor::<rank::Two>::new((3, 2), [1.0, 0.0, 0.0, 1.0, 1.0, 0.0]).unwrap();
+
+        // Act
+        let output = balanced_accuracy(&predictions, &targets).unwrap();
+
+        // Assert
+        assert_eq!(output, 1.0);
+    }
+
+    fn predictions_and_targets() -> (Tensor<rank::Two>, Tensor<rank::Two>) {
+        // predicts classes 0, 1, 1, 2, 0 for targets of classes 0, 1, 0, 2, 2.
+        let predictions = Tensor::<rank::Two>::new(
+            (5, 3),
+            [
+                0.7, 0.2, 0.1, 0.1, 0.8, 0.1, 0.3, 0.6, 0.1, 0.1, 0.2, 0.7, 0.5, 0.1, 0.4,
+            ],
+        )
+        .unwrap();
+        let targets = Tensor::<rank::Two>::new(
+            (5, 3),
+            [
+                1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0,
+            ],
+        )
+        .unwrap();
+        (predictions, targets)
+    }
+
+    #[test]
+    fn test_accuracy() {
+        // Arrange
+        let (predictions, targets) = predictions_and_targets();
+
+        // Act
+        let output = Accuracy::new().evaluate(&predictions, &targets).unwrap();
+
+        // Assert
+        assert!((output - 0.6).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_precision() {
+        // Arrange
+        let (predictions, targets) = predictions_and_targets();
+        // class 0 is 1/2, class 1 is 1/2 and class 2 is 1/1.
+        let expected = 2.0 / 3.0;
+
+        // Act
+        let output = Precision::new().evaluate(&predictions, &targets).unwrap();
+
+        // Assert
+        assert!((output - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_recall() {
+        // Arrange
+        let (predictions, targets) = predictions_and_targets();
+        // class 0 is 1/2, class 1 is 1/1 and class 2 is 1/2.
+        let expected = 2.0 / 3.0;
+
+        // Act
+        let output = Recall::new().evaluate(&predictions, &targets).unwrap();
+
+        // Assert
+        assert!((output - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_f1() {
+        // Arrange
+        let (predictions, targets) = predictions_and_targets();
+        // class 0 is 1/2, class 1 is 2/3 and class 2 is 2/3.
+        let expected = (0.5 + 2.0 / 3.0 + 2.0 / 3.0) / 3.0;
+
+        // Act
+        let output = F1::new().evaluate(&predictions, &targets).unwrap();
+
+        // Assert
+        assert!((output - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_top_k() {
+        // Arrange
+        let (predictions, targets) = predictions_and_targets();
+
+        // Act
+        let top_one = TopK::new(1).evaluate(&predictions, &targets).unwrap();
+        let top_two = TopK::new(2).evaluate(&predictions, &targets).unwrap();
+        let top_three = TopK::new(3).evaluate(&predictions, &targets).unwrap();
+
+        // Assert
+        assert!((top_one - 0.6).abs() < 1e-6);
+        assert!((top_two - 1.0).abs() < 1e-6);
+        assert!((top_three - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_top_k_failure() {
+        // Arrange
+        let (predictions, targets) = predictions_and_targets();
+
+        // Act
+        let result = TopK::new(0).evaluate(&predictions, &targets);
+
+        // Assert
+        assert_eq!(result, Err(Error::InvalidArgument));
+    }
+
+    #[test]
+    fn test_balanced_accuracy_failure() {
+        // Arrange
+        let predictions = Tensor::<rank::Two>::new((1, 2), [0.9, 0.1]).unwrap();
+        let targets = Tensor::<rank::Two>::new((1, 3), [1.0, 0.0, 0.0]).unwrap();
+        let empty = Tensor::<rank::Two>::new((0, 2), []).unwrap();
+
+        // Act
+        let mismatched = balanced_accuracy(&predictions, &targets);
+        let empty = balanced_accuracy(&empty, &empty);
+
+        // Assert
+        assert!(mismatched.is_err());
+        assert!(empty.is_err());
+    }
+}
@@ -0,0 +1,30 @@
+//! This module contains metrics which can be used to evaluate the predictions made
+//! by a trained network against the expected targets. Unlike loss functions these aren't
+//! used for training, but give a more interpretable measure of how well a network performs.
+
+mod classification;
+mod roc;
+
+pub(crate) use classification::class_of;
+pub use classification::{balanced_accuracy, Accuracy, Precision, Recall, TopK, F1};
+pub use roc::roc_auc;
+
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Result};
+
+/// This trait is implemented by metrics which measure how well a set of predictions agrees with
+/// the expected targets, producing a single value. A metric can be given to a `Trainer` with `metric` to
+/// be evaluated against the testing data alongside the loss and recorded in the training history.
+///
+/// Unlike most traits in the library this trait isn't sealed, so metrics specific to a domain can be implemented.
+pub trait Metric {
+    /// Evaluates the metric for the given predictions against the given targets.
+    ///
+    /// # Errors
+    /// `Error` if the predictions and targets aren't shaped as the metric expects.
+    fn evaluate(
+        &self,
+        predictions: &Tensor<rank::Two>,
+        targets: &Tensor<rank::Two>,
+    ) -> Result<ElementType>;
+}
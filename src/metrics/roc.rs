@@ -0,0 +1,148 @@
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+
+/// Calculates the area under the receiver operating characteristic (ROC) curve for
+/// a binary classifier with a single output column. The curve is swept by sorting the
+/// predictions and treating each distinct prediction as a threshold, and the area under it
+/// is calculated with the trapezoidal rule. Targets of 0.5 or above are treated as the positive class.
+///
+/// # Errors
+/// `Error` if the predictions and targets aren't the same shape, if they have more than a single
+/// column, or if the targets don't contain both positive and negative observations.
+pub fn roc_auc(
+    predictions: &Tensor<rank::Two>,
+    targets: &Tensor<rank::Two>,
+) -> Result<ElementType> {
+    let (predictions, targets) = (&predictions.0, &targets.0);
+    Error::check_shape(predictions.dim(), targets.dim())?;
+    Error::check_size(1, predictions.ncols())?;
+
+    // pair up each prediction with whether it's a positive, highest predictions first.
+    let mut pairs = predictions
+        .iter()
+        .zip(targets.iter())
+        .map(|(prediction, target)| (*prediction, *target >= 0.5))
+        .collect::<Vec<_>>();
+    pairs.sort_by(|lhs, rhs| rhs.0.total_cmp(&lhs.0));
+
+    // sweep the thresholds, only emitting a point once all tied predictions have been passed.
+    let (mut true_positives, mut false_positives) = (0.0, 0.0);
+    let (mut last_true_positives, mut last_false_positives) = (0.0, 0.0);
+    let mut area = 0.0;
+    for (index, (prediction, positive)) in pairs.iter().enumerate() {
+        if *positive {
+            true_positives += 1.0;
+        } else {
+            false_positives += 1.0;
+        }
+        let is_last_of_threshold = pairs
+            .get(index + 1)
+            .is_none_or(|(next, _)| next.total_cmp(prediction).is_ne());
+        if is_last_of_threshold {
+            area += (false_positives - last_false_positives)
+                * (true_positives + last_true_positives)
+                / 2.0;
+            (last_true_positives, last_false_positives) = (true_positives, false_positives);
+        }
+    }
+
+    // normalise the area by the number of positives and negatives.
+    if true_positives == 0.0 || false_positives == 0.0 {
+        Err(Error::InvalidArgument)
+    } else {
+        Ok(area / (true_positives * false_positives))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_roc_auc_separable() {
+        // Arrange
+        let predictions = Tensor::<rank::Two>::new((4, 1), [0.9, 0.1, 0.8, 0.3]).unwrap();
+        let targets = Tensor::<rank::Two>::new((4, 1), [1.0, 0.0, 1.0, 0.0]).unwrap();
+
+        // Act
+        let auc = roc_auc(&predictions, &targets).unwrap();
+
+        // Assert
+        assert_eq!(auc, 1.0);
+    }
+
+    #[test]
+    fn test_roc_auc_inverted() {
+        // Arrange
+        let predictions = Tensor::<rank::Two>::new((4, 1), [0.1, 0.9, 0.3, 0.8]).unwrap();
+        let targets = Tensor::<rank::Two>::new((4, 1), [1.0, 0.0, 1.0, 0.0]).unwrap();
+
+        // Act
+        let auc = roc_auc(&predictions, &targets).unwrap();
+
+        // Assert
+        assert_eq!(auc, 0.0);
+    }
+
+    #[test]
+    fn test_roc_auc_ties() {
+        // Arrange
+        let predictions = Tensor::<rank::Two>::new((4, 1), [0.5, 0.5, 0.5, 0.5]).unwrap();
+        let targets = Tensor::<rank::Two>::new((4, 1), [1.0, 0.0, 1.0, 0.0]).unwrap();
+
+        // Act
+        let auc = roc_auc(&predictions, &targets).unwrap();
+
+        // Assert
+        assert_eq!(auc, 0.5);
+    }
+
+    #[test]
+    fn test_roc_auc_random() {
+        // Arrange
+        const COUNT: usize = 10_000;
+        let mut generator = StdRng::seed_from_u64(42);
+        let predictions =
+            Tensor::<rank::Two>::new((COUNT, 1), (0..COUNT).map(|_| generator.gen())).unwrap();
+        let targets = Tensor::<rank::Two>::new(
+            (COUNT, 1),
+            (0..COUNT).map(|_| if generator.gen() { 1.0 } else { 0.0 }),
+        )
+        .unwrap();
+
+        // Act
+        let auc = roc_auc(&predictions, &targets).unwrap();
+
+        // Assert
+        assert!((auc - 0.5).abs() < 0.02);
+    }
+
+    #[test]
+    fn test_roc_auc_single_class_failure() {
+        // Arrange
+        let predictions = Tensor::<rank::Two>::new((2, 1), [0.9, 0.1]).unwrap();
+        let targets = Tensor::<rank::Two>::new((2, 1), [1.0, 1.0]).unwrap();
+
+        // Act
+        let result = roc_auc(&predictions, &targets);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_roc_auc_shape_failure() {
+        // Arrange
+        let predictions = Tensor::<rank::Two>::new((2, 2), [0.9, 0.1, 0.1, 0.9]).unwrap();
+        let targets = Tensor::<rank::Two>::new((2, 2), [1.0, 0.0, 0.0, 1.0]).unwrap();
+
+        // Act
+        let result = roc_auc(&predictions, &targets);
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
@@ -2,6 +2,8 @@ use crate::operations::{trainable, BackwardOperation};
 use crate::optimisers::base::Optimiser;
 use crate::private::Sealed;
 use crate::tensors::{rank, Tensor};
+use crate::ElementType;
+use alloc::vec::Vec;
 
 #[derive(Debug, PartialEq)]
 pub struct Operation<'a, T: 'a> {
@@ -12,10 +14,35 @@ pub struct Operation<'a, T: 'a> {
 impl<'a, T: 'a> Sealed for Operation<'a, T> {}
 impl<'a, T: Optimiser<Tensor<rank::Two>> + 'a> BackwardOperation for Operation<'a, T> {
     fn optimise(self) {
+        let global_norm = self.gradient_norm_squared().sqrt();
+        self.optimise_with_norm(global_norm);
+    }
+
+    fn gradient_norm_squared(&self) -> ElementType {
+        self.parameter_gradient
+            .0
+            .iter()
+            .map(|elem| elem * elem)
+            .sum()
+    }
+
+    fn push_gradients(&self, gradients: &mut Vec<ElementType>) {
+        gradients.extend(self.parameter_gradient.0.iter());
+    }
+
+    fn set_gradients(&mut self, gradients: &mut impl Iterator<Item = ElementType>) {
+        self.parameter_gradient
+            .0
+            .iter_mut()
+            .zip(gradients)
+            .for_each(|(gradient, replacement)| *gradient = replacement);
+    }
+
+    fn optimise_with_norm(self, global_norm: ElementType) {
         let parameter = &mut self.borrow.initialised.parameter;
         let parameter_gradient = &self.parameter_gradient;
         let optimiser = &mut self.borrow.optimiser;
-        optimiser.optimise(parameter, parameter_gradient);
+        optimiser.optimise_with_norm(parameter, parameter_gradient, global_norm);
     }
 }
 
@@ -31,9 +58,11 @@ mod tests {
             *parameter = Tensor(parameter.0.clone() - gradient.0.clone());
         }
 
-        fn init(&mut self, _epochs: u16) {}
+        fn init(&mut self, _epochs: u32) {}
 
         fn end_epoch(&mut self) {}
+
+        fn end_batch(&mut self) {}
     }
 
     #[test]
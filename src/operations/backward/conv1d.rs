@@ -0,0 +1,64 @@
+use crate::operations::BackwardOperation;
+use crate::private::Sealed;
+use crate::ElementType;
+use alloc::vec::Vec;
+
+pub struct Operation<T>(pub(crate) T);
+
+impl<T> Sealed for Operation<T> {}
+impl<T: BackwardOperation> BackwardOperation for Operation<T> {
+    fn optimise(self) {
+        self.0.optimise();
+    }
+
+    fn gradient_norm_squared(&self) -> ElementType {
+        self.0.gradient_norm_squared()
+    }
+
+    fn push_gradients(&self, gradients: &mut Vec<ElementType>) {
+        self.0.push_gradients(gradients);
+    }
+
+    fn set_gradients(&mut self, gradients: &mut impl Iterator<Item = ElementType>) {
+        self.0.set_gradients(gradients);
+    }
+
+    fn optimise_with_norm(self, global_norm: ElementType) {
+        self.0.optimise_with_norm(global_norm);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::activations::Linear;
+    use crate::layers::{Chain, Conv1D, SequenceInput};
+    use crate::operations::{
+        BackwardOperation, Forward, ForwardOperation, InitialisedOperation, TrainableOperation,
+        UninitialisedOperation, WithOptimiser,
+    };
+    use crate::optimisers::learning_rate_handlers::FixedLearningRateHandler;
+    use crate::optimisers::SGD;
+    use crate::tensors::{rank, Tensor};
+
+    #[test]
+    fn test_optimise() {
+        // Arrange
+        let mut network = SequenceInput::new(1)
+            .chain(Conv1D::new(1, 2, 1, 0, Linear::new()))
+            .with_iter([0.0, 0.0, 0.0].into_iter())
+            .unwrap()
+            .with_optimiser(SGD::new(FixedLearningRateHandler::new(1.0)));
+        let input = Tensor::<rank::Three>::new((1, 3, 1), [1.0, 2.0, 3.0]).unwrap();
+        let output_gradient = Tensor::<rank::Three>::new((1, 2, 1), [1.0, 1.0]).unwrap();
+        // each kernel weight sees two input elements, and the bias sees both outputs.
+        let expected = [-3.0, -5.0, -2.0];
+
+        // Act
+        let (forward, _) = network.forward(input).unwrap();
+        let (backward, _) = forward.backward(output_gradient).unwrap();
+        backward.optimise();
+
+        // Assert
+        assert!(network.into_initialised().iter().eq(expected));
+    }
+}
@@ -0,0 +1,68 @@
+use crate::operations::{backward, BackwardOperation};
+use crate::private::Sealed;
+use crate::ElementType;
+use alloc::vec::Vec;
+
+pub struct Operation<T, U, V>(pub(crate) backward::dense::Operation<T, U, V>);
+
+impl<T, U, V> Sealed for Operation<T, U, V> {}
+impl<T: BackwardOperation, U: BackwardOperation, V: BackwardOperation> BackwardOperation
+    for Operation<T, U, V>
+{
+    fn optimise(self) {
+        self.0.optimise();
+    }
+
+    fn gradient_norm_squared(&self) -> ElementType {
+        self.0.gradient_norm_squared()
+    }
+
+    fn push_gradients(&self, gradients: &mut Vec<ElementType>) {
+        self.0.push_gradients(gradients);
+    }
+
+    fn set_gradients(&mut self, gradients: &mut impl Iterator<Item = ElementType>) {
+        self.0.set_gradients(gradients);
+    }
+
+    fn optimise_with_norm(self, global_norm: ElementType) {
+        self.0.optimise_with_norm(global_norm);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::activations::Linear;
+    use crate::layers::{Chain, ConvTranspose2D, ImageInput};
+    use crate::operations::{
+        BackwardOperation, Forward, ForwardOperation, InitialisedOperation, TrainableOperation,
+        UninitialisedOperation, WithOptimiser,
+    };
+    use crate::optimisers::learning_rate_handlers::FixedLearningRateHandler;
+    use crate::optimisers::SGD;
+    use crate::tensors::{rank, Tensor};
+
+    #[test]
+    fn test_optimise() {
+        // Arrange
+        let mut network = ImageInput::new(1)
+            .chain(ConvTranspose2D::new(1, 2, 2, 0, Linear::new()))
+            .with_iter([0.0; 5].into_iter())
+            .unwrap()
+            .with_optimiser(SGD::new(FixedLearningRateHandler::new(1.0)));
+        let input = Tensor::<rank::Four>::new((1, 1, 1, 2), [1.0, 2.0]).unwrap();
+        let output_gradient =
+            Tensor::<rank::Four>::new((1, 1, 2, 4), [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0])
+                .unwrap();
+        // each kernel weight sees both input pixels, and the bias sees every output pixel.
+        let expected = [-3.0, -3.0, -3.0, -3.0, -8.0];
+
+        // Act
+        let (forward, _) = network.forward(input).unwrap();
+        let (backward, _) = forward.backward(output_gradient).unwrap();
+        backward.optimise();
+
+        // Assert
+        assert!(network.into_initialised().iter().eq(expected));
+    }
+}
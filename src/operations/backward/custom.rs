@@ -0,0 +1,93 @@
+use crate::operations::{trainable, BackwardOperation};
+use crate::optimisers::base::Optimiser;
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::ElementType;
+use alloc::vec::Vec;
+
+#[derive(Debug, PartialEq)]
+pub struct Operation<'a, L: 'a, T: 'a> {
+    pub(crate) borrow: &'a mut trainable::custom::Operation<L, T>,
+    pub(crate) parameter_gradient: Tensor<rank::One>,
+}
+
+impl<'a, L: 'a, T: 'a> Sealed for Operation<'a, L, T> {}
+impl<'a, L: 'a, T: Optimiser<Tensor<rank::One>> + 'a> BackwardOperation for Operation<'a, L, T> {
+    fn optimise(self) {
+        let global_norm = self.gradient_norm_squared().sqrt();
+        self.optimise_with_norm(global_norm);
+    }
+
+    fn gradient_norm_squared(&self) -> ElementType {
+        self.parameter_gradient
+            .0
+            .iter()
+            .map(|elem| elem * elem)
+            .sum()
+    }
+
+    fn push_gradients(&self, gradients: &mut Vec<ElementType>) {
+        gradients.extend(self.parameter_gradient.0.iter());
+    }
+
+    fn set_gradients(&mut self, gradients: &mut impl Iterator<Item = ElementType>) {
+        self.parameter_gradient
+            .0
+            .iter_mut()
+            .zip(gradients)
+            .for_each(|(gradient, replacement)| *gradient = replacement);
+    }
+
+    fn optimise_with_norm(self, global_norm: ElementType) {
+        let parameters = &mut self.borrow.initialised.parameters;
+        let parameter_gradient = &self.parameter_gradient;
+        let optimiser = &mut self.borrow.optimiser;
+        optimiser.optimise_with_norm(parameters, parameter_gradient, global_norm);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::initialised;
+    use crate::operations::uninitialised::custom::tests::Scale;
+
+    struct DummyOptimiser;
+
+    impl Optimiser<Tensor<rank::One>> for DummyOptimiser {
+        fn optimise(&mut self, parameter: &mut Tensor<rank::One>, gradient: &Tensor<rank::One>) {
+            *parameter = Tensor(parameter.0.clone() - gradient.0.clone());
+        }
+
+        fn init(&mut self, _epochs: u32) {}
+
+        fn end_epoch(&mut self) {}
+
+        fn end_batch(&mut self) {}
+    }
+
+    #[test]
+    fn test_optimise() {
+        // Arrange
+        let mut train = trainable::custom::Operation {
+            optimiser: DummyOptimiser,
+            initialised: initialised::custom::Operation {
+                layer: Scale,
+                parameters: Tensor::<rank::One>::new([2.0, -1.0]),
+            },
+            last_input: Tensor::default(),
+            saved: Vec::new(),
+        };
+        let backward = Operation {
+            borrow: &mut train,
+            parameter_gradient: Tensor::<rank::One>::new([1.0, 1.0]),
+        };
+        let expected = Tensor::<rank::One>::new([1.0, -2.0]);
+
+        // Act
+        backward.optimise();
+
+        // Assert
+        assert_eq!(train.initialised.parameters, expected);
+    }
+}
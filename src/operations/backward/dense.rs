@@ -1,5 +1,7 @@
 use crate::operations::BackwardOperation;
 use crate::private::Sealed;
+use crate::ElementType;
+use alloc::vec::Vec;
 
 #[derive(Debug, Eq, PartialEq)]
 pub struct Operation<T, U, V> {
@@ -13,9 +15,32 @@ impl<T: BackwardOperation, U: BackwardOperation, V: BackwardOperation> BackwardO
     for Operation<T, U, V>
 {
     fn optimise(self) {
-        self.weight_multiply.optimise();
-        self.bias_add.optimise();
-        self.activation_function.optimise();
+        let global_norm = self.gradient_norm_squared().sqrt();
+        self.optimise_with_norm(global_norm);
+    }
+
+    fn gradient_norm_squared(&self) -> ElementType {
+        self.weight_multiply.gradient_norm_squared()
+            + self.bias_add.gradient_norm_squared()
+            + self.activation_function.gradient_norm_squared()
+    }
+
+    fn push_gradients(&self, gradients: &mut Vec<ElementType>) {
+        self.weight_multiply.push_gradients(gradients);
+        self.bias_add.push_gradients(gradients);
+        self.activation_function.push_gradients(gradients);
+    }
+
+    fn set_gradients(&mut self, gradients: &mut impl Iterator<Item = ElementType>) {
+        self.weight_multiply.set_gradients(gradients);
+        self.bias_add.set_gradients(gradients);
+        self.activation_function.set_gradients(gradients);
+    }
+
+    fn optimise_with_norm(self, global_norm: ElementType) {
+        self.weight_multiply.optimise_with_norm(global_norm);
+        self.bias_add.optimise_with_norm(global_norm);
+        self.activation_function.optimise_with_norm(global_norm);
     }
 }
 
@@ -56,15 +81,18 @@ mod tests {
             *parameter = Tensor(&parameter.0 - &gradient.0);
         }
 
-        fn init(&mut self, _epochs: u16) {}
+        fn init(&mut self, _epochs: u32) {}
 
         fn end_epoch(&mut self) {}
+
+        fn end_batch(&mut self) {}
     }
 
     impl Optimiser<()> for DummyOptimiser {
         fn optimise(&mut self, _parameter: &mut (), _gradient: &()) {}
-        fn init(&mut self, _epochs: u16) {}
+        fn init(&mut self, _epochs: u32) {}
         fn end_epoch(&mut self) {}
+        fn end_batch(&mut self) {}
     }
 
     #[test]
@@ -0,0 +1,76 @@
+use crate::operations::{trainable, BackwardOperation};
+use crate::optimisers::base::Optimiser;
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::ElementType;
+use alloc::vec::Vec;
+
+pub struct Operation<'a, T: 'a> {
+    pub(crate) borrow: &'a mut trainable::depthwise_multiply::Operation<T>,
+    pub(crate) parameter_gradient: Tensor<rank::Two>,
+}
+
+impl<'a, T: 'a> Sealed for Operation<'a, T> {}
+impl<'a, T: Optimiser<Tensor<rank::Two>> + 'a> BackwardOperation for Operation<'a, T> {
+    fn optimise(self) {
+        let global_norm = self.gradient_norm_squared().sqrt();
+        self.optimise_with_norm(global_norm);
+    }
+
+    fn gradient_norm_squared(&self) -> ElementType {
+        self.parameter_gradient
+            .0
+            .iter()
+            .map(|elem| elem * elem)
+            .sum()
+    }
+
+    fn push_gradients(&self, gradients: &mut Vec<ElementType>) {
+        gradients.extend(self.parameter_gradient.0.iter());
+    }
+
+    fn set_gradients(&mut self, gradients: &mut impl Iterator<Item = ElementType>) {
+        self.parameter_gradient
+            .0
+            .iter_mut()
+            .zip(gradients)
+            .for_each(|(gradient, replacement)| *gradient = replacement);
+    }
+
+    fn optimise_with_norm(self, global_norm: ElementType) {
+        let parameter = &mut self.borrow.initialised.parameter;
+        let parameter_gradient = &self.parameter_gradient;
+        let optimiser = &mut self.borrow.optimiser;
+        optimiser.optimise_with_norm(parameter, parameter_gradient, global_norm);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::{initialised, Forward, ForwardOperation, WithOptimiser};
+    use crate::optimisers::learning_rate_handlers::FixedLearningRateHandler;
+    use crate::optimisers::SGD;
+
+    #[test]
+    fn test_optimise() {
+        // Arrange
+        let mut operation = initialised::depthwise_multiply::Operation {
+            groups: 2,
+            parameter: Tensor::<rank::Two>::new((2, 1), [1.0, 2.0]).unwrap(),
+        }
+        .with_optimiser(SGD::new(FixedLearningRateHandler::new(1.0)));
+        let input = Tensor::<rank::Two>::new((1, 2), [3.0, 4.0]).unwrap();
+        let output_gradient = Tensor::<rank::Two>::new((1, 2), [1.0, 0.5]).unwrap();
+        let expected = Tensor::<rank::Two>::new((2, 1), [-2.0, 0.0]).unwrap();
+
+        // Act
+        let (forward, _) = operation.forward(input).unwrap();
+        let (backward, _) = forward.backward(output_gradient).unwrap();
+        assert_eq!(backward.gradient_norm_squared(), 13.0);
+        backward.optimise();
+
+        // Assert
+        assert_eq!(operation.initialised.parameter, expected);
+    }
+}
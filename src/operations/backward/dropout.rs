@@ -6,9 +6,9 @@ pub struct Operation<'a> {
     pub(crate) _forward: forward::dropout::Operation<'a>,
 }
 
-impl<'a> Sealed for Operation<'a> {}
+impl Sealed for Operation<'_> {}
 
-impl<'a> BackwardOperation for Operation<'a> {
+impl BackwardOperation for Operation<'_> {
     fn optimise(self) {}
 }
 
@@ -25,6 +25,7 @@ mod tests {
             initialised: initialised::dropout::Operation {
                 keep_probability: 0.8,
                 seed: None,
+                inverted: false,
             },
         };
         let backward = Operation {
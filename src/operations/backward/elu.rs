@@ -0,0 +1,23 @@
+use crate::operations::BackwardOperation;
+use crate::private::Sealed;
+
+pub struct Operation(pub(crate) ());
+
+impl Sealed for Operation {}
+impl BackwardOperation for Operation {
+    fn optimise(self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_optimise() {
+        // Arrange
+        let operation = Operation(());
+
+        // Act
+        operation.optimise();
+    }
+}
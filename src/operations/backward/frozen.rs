@@ -0,0 +1,52 @@
+use crate::operations::BackwardOperation;
+use crate::private::Sealed;
+
+pub struct Operation<T>(pub(crate) T);
+
+impl<T> Sealed for Operation<T> {}
+impl<T: BackwardOperation> BackwardOperation for Operation<T> {
+    fn optimise(self) {
+        // the gradients of a frozen block are discarded, leaving its parameters unchanged.
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::activations::Linear;
+    use crate::layers::{Chain, Dense, Frozen, Input};
+    use crate::operations::{
+        BackwardOperation, Forward, ForwardOperation, InitialisedOperation, TrainableOperation,
+        UninitialisedOperation, WithOptimiser,
+    };
+    use crate::optimisers::learning_rate_handlers::FixedLearningRateHandler;
+    use crate::optimisers::SGD;
+    use crate::tensors::{rank, Tensor};
+
+    #[test]
+    fn test_optimise() {
+        // Arrange
+        let mut operation = Input::new(1)
+            .chain(Frozen::new(Dense::new(1, Linear::new())))
+            .chain(Dense::new(1, Linear::new()))
+            .with_iter([2.0, 0.0, 1.0, 0.0].into_iter())
+            .unwrap()
+            .with_optimiser(SGD::new(FixedLearningRateHandler::new(1.0)));
+        let input = Tensor::<rank::Two>::new((1, 1), [1.0]).unwrap();
+        let output_gradient = Tensor::<rank::Two>::new((1, 1), [1.0]).unwrap();
+
+        // Act
+        let (forward, _) = operation.forward(input).unwrap();
+        let (backward, input_gradient) = forward.backward(output_gradient).unwrap();
+        let norm = backward.gradient_norm_squared();
+        backward.optimise();
+
+        // Assert (only the head moves, by the hidden output of 2 and the bias gradient of 1)
+        let parameters = operation
+            .into_initialised()
+            .iter()
+            .collect::<alloc::vec::Vec<_>>();
+        assert_eq!(parameters, [2.0, 0.0, -1.0, -1.0]);
+        assert_eq!(input_gradient.0[[0, 0]], 2.0);
+        assert_eq!(norm, 5.0);
+    }
+}
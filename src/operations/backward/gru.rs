@@ -0,0 +1,99 @@
+use crate::operations::{trainable, BackwardOperation};
+use crate::optimisers::base::Optimiser;
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::ElementType;
+use alloc::vec::Vec;
+
+#[derive(Debug, PartialEq)]
+pub struct Operation<'a, T: 'a> {
+    pub(crate) borrow: &'a mut trainable::gru::Operation<T>,
+    pub(crate) gradients: [Tensor<rank::Two>; 3],
+}
+
+impl<'a, T: 'a> Sealed for Operation<'a, T> {}
+impl<'a, T: Optimiser<Tensor<rank::Two>> + 'a> BackwardOperation for Operation<'a, T> {
+    fn optimise(self) {
+        let global_norm = self.gradient_norm_squared().sqrt();
+        self.optimise_with_norm(global_norm);
+    }
+
+    fn gradient_norm_squared(&self) -> ElementType {
+        self.gradients
+            .iter()
+            .flat_map(|gradient| gradient.0.iter())
+            .map(|elem| elem * elem)
+            .sum()
+    }
+
+    fn push_gradients(&self, gradients: &mut Vec<ElementType>) {
+        gradients.extend(self.gradients.iter().flat_map(|gradient| gradient.0.iter()));
+    }
+
+    fn set_gradients(&mut self, gradients: &mut impl Iterator<Item = ElementType>) {
+        self.gradients
+            .iter_mut()
+            .flat_map(|gradient| gradient.0.iter_mut())
+            .zip(gradients)
+            .for_each(|(gradient, replacement)| *gradient = replacement);
+    }
+
+    fn optimise_with_norm(self, global_norm: ElementType) {
+        let initialised = &mut self.borrow.initialised;
+        let parameters = [
+            &mut initialised.input_weights,
+            &mut initialised.hidden_weights,
+            &mut initialised.bias,
+        ];
+        parameters
+            .into_iter()
+            .zip(&mut self.borrow.optimisers)
+            .zip(&self.gradients)
+            .for_each(|((parameter, optimiser), gradient)| {
+                optimiser.optimise_with_norm(parameter, gradient, global_norm);
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::layers::GRU;
+    use crate::operations::{
+        BackwardOperation, Forward, ForwardOperation, InitialisedOperation, TrainableOperation,
+        UninitialisedOperation, WithOptimiser,
+    };
+    use crate::optimisers::learning_rate_handlers::FixedLearningRateHandler;
+    use crate::optimisers::SGD;
+    use crate::tensors::{rank, Tensor};
+    use crate::ElementType;
+
+    #[test]
+    fn test_optimise() {
+        // Arrange
+        let (initialised, _) = GRU::new(2).with_seed_private(42, 3);
+        let input =
+            Tensor::<rank::Three>::new((1, 2, 3), (1u8..=6).map(ElementType::from)).unwrap();
+        let output_gradient = Tensor::<rank::Two>::new((1, 2), [1.0, 1.0]).unwrap();
+        let mut operation = initialised
+            .clone()
+            .with_optimiser(SGD::new(FixedLearningRateHandler::new(0.1)));
+        let (forward, _) = operation.forward(input).unwrap();
+        let (backward, _) = forward.backward(output_gradient).unwrap();
+        let expected = initialised
+            .iter()
+            .zip(
+                backward
+                    .gradients
+                    .iter()
+                    .flat_map(|gradient| gradient.0.iter()),
+            )
+            .map(|(parameter, gradient)| parameter - 0.1 * gradient)
+            .collect::<alloc::vec::Vec<_>>();
+
+        // Act
+        backward.optimise();
+
+        // Assert
+        assert!(operation.into_initialised().iter().eq(expected));
+    }
+}
@@ -2,18 +2,40 @@
 //! final stage of an operation in a training epoch. That of the operation
 //! having had the backward pass ran and ready for optimisation.
 
+pub mod alpha_dropout;
 pub mod bias_add;
 pub mod composite;
+pub mod conv1d;
+pub mod conv2d;
+pub mod conv_transpose2d;
+pub mod custom;
 pub mod dense;
+pub mod depthwise_multiply;
 pub mod dropout;
+pub mod elu;
+pub mod frozen;
+pub mod gelu;
+pub mod gru;
+pub mod image_input;
 pub mod input;
 pub mod linear;
+pub mod parallel;
+pub mod pooling2d;
 pub mod relu;
+pub mod reshape;
+pub mod selu;
+pub mod sequence_input;
 pub mod sigmoid;
+pub mod silu;
+pub mod spatial_dropout;
+pub mod stochastic_depth;
 pub mod tanh;
+pub mod upsample2d;
 pub mod weight_multiply;
 
 use crate::private::Sealed;
+use crate::ElementType;
+use alloc::vec::Vec;
 
 /// This trait represents the state of the operation after having the backward
 /// pass applied and is the final state of the operation. At this point if the
@@ -23,4 +45,31 @@ pub trait Operation: Sealed {
     /// Function which consumes this instance and uses the built in optimiser
     /// to update the parameters of the operation.
     fn optimise(self);
+
+    /// Gets the sum of the squares of the gradients calculated for the parameters
+    /// of this operation, which is used to find the norm of all the gradients in a network.
+    #[doc(hidden)]
+    fn gradient_norm_squared(&self) -> ElementType {
+        0.0
+    }
+
+    /// Appends the gradients calculated for the parameters of this operation to the given vector,
+    /// in the same order that `set_gradients` replaces them.
+    #[doc(hidden)]
+    fn push_gradients(&self, _gradients: &mut Vec<ElementType>) {}
+
+    /// Replaces the gradients calculated for the parameters of this operation with the next
+    /// elements of the given iterator, so that they're used instead when optimising.
+    #[doc(hidden)]
+    fn set_gradients(&mut self, _gradients: &mut impl Iterator<Item = ElementType>) {}
+
+    /// Optimises the parameters of the operation as with `optimise`, additionally providing
+    /// the optimisers with the norm of the gradients of every parameter being optimised together.
+    #[doc(hidden)]
+    fn optimise_with_norm(self, _global_norm: ElementType)
+    where
+        Self: Sized,
+    {
+        self.optimise();
+    }
 }
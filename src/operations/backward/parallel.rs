@@ -0,0 +1,40 @@
+use crate::operations::BackwardOperation;
+use crate::private::Sealed;
+use crate::ElementType;
+use alloc::vec::Vec;
+
+pub struct Operation<T, U> {
+    pub(crate) lhs: T,
+    pub(crate) rhs: U,
+}
+
+impl<T, U> Sealed for Operation<T, U> {}
+impl<T, U> BackwardOperation for Operation<T, U>
+where
+    T: BackwardOperation,
+    U: BackwardOperation,
+{
+    fn optimise(self) {
+        let global_norm = self.gradient_norm_squared().sqrt();
+        self.optimise_with_norm(global_norm);
+    }
+
+    fn gradient_norm_squared(&self) -> ElementType {
+        self.lhs.gradient_norm_squared() + self.rhs.gradient_norm_squared()
+    }
+
+    fn push_gradients(&self, gradients: &mut Vec<ElementType>) {
+        self.lhs.push_gradients(gradients);
+        self.rhs.push_gradients(gradients);
+    }
+
+    fn set_gradients(&mut self, gradients: &mut impl Iterator<Item = ElementType>) {
+        self.lhs.set_gradients(gradients);
+        self.rhs.set_gradients(gradients);
+    }
+
+    fn optimise_with_norm(self, global_norm: ElementType) {
+        self.lhs.optimise_with_norm(global_norm);
+        self.rhs.optimise_with_norm(global_norm);
+    }
+}
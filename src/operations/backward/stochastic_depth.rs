@@ -0,0 +1,86 @@
+use crate::operations::BackwardOperation;
+use crate::private::Sealed;
+use crate::ElementType;
+use alloc::vec::Vec;
+
+pub enum Operation<T> {
+    Skipped,
+    Applied(T),
+}
+
+impl<T> Sealed for Operation<T> {}
+impl<T: BackwardOperation> BackwardOperation for Operation<T> {
+    fn optimise(self) {
+        if let Self::Applied(backward) = self {
+            backward.optimise();
+        }
+    }
+
+    fn gradient_norm_squared(&self) -> ElementType {
+        match self {
+            Self::Skipped => 0.0,
+            Self::Applied(backward) => backward.gradient_norm_squared(),
+        }
+    }
+
+    fn push_gradients(&self, gradients: &mut Vec<ElementType>) {
+        if let Self::Applied(backward) = self {
+            backward.push_gradients(gradients);
+        }
+    }
+
+    fn set_gradients(&mut self, gradients: &mut impl Iterator<Item = ElementType>) {
+        if let Self::Applied(backward) = self {
+            backward.set_gradients(gradients);
+        }
+    }
+
+    fn optimise_with_norm(self, global_norm: ElementType) {
+        if let Self::Applied(backward) = self {
+            backward.optimise_with_norm(global_norm);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::activations::Linear;
+    use crate::layers::{Chain, Dense, Input, StochasticDepth};
+    use crate::operations::{
+        BackwardOperation, Forward, ForwardOperation, InitialisedOperation, TrainableOperation,
+        UninitialisedOperation, WithOptimiser,
+    };
+    use crate::optimisers::learning_rate_handlers::FixedLearningRateHandler;
+    use crate::optimisers::SGD;
+    use crate::tensors::{rank, Tensor};
+
+    #[test]
+    fn test_optimise() {
+        // Arrange
+        let mut operation = Input::new(1)
+            .chain(StochasticDepth::new(Dense::new(1, Linear::new()), 0.5))
+            .with_iter([2.0, 0.0].into_iter())
+            .unwrap()
+            .with_optimiser(SGD::new(FixedLearningRateHandler::new(1.0)));
+        let input = Tensor::<rank::Two>::new((1, 1), [1.0]).unwrap();
+        let output_gradient = Tensor::<rank::Two>::new((1, 1), [1.0]).unwrap();
+
+        // Act
+        let applied = (0..10)
+            .filter(|_| {
+                let (forward, output) = operation.forward(input.clone()).unwrap();
+                let (backward, _) = forward.backward(output_gradient.clone()).unwrap();
+                backward.optimise();
+                output != input
+            })
+            .count();
+
+        // Assert (each applied pass moves the weight and bias down by 1)
+        let parameters = operation
+            .into_initialised()
+            .iter()
+            .collect::<alloc::vec::Vec<_>>();
+        assert!(applied > 0 && applied < 10);
+        assert_eq!(parameters[1], -(applied as crate::ElementType));
+    }
+}
@@ -0,0 +1,86 @@
+use crate::operations::{backward, trainable, ForwardOperation};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+
+pub struct Operation<'a> {
+    pub(crate) borrow: &'a mut trainable::alpha_dropout::Operation,
+    pub(crate) mask: Tensor<rank::Two>,
+}
+
+impl Sealed for Operation<'_> {}
+impl ForwardOperation for Operation<'_> {
+    type Output = Tensor<rank::Two>;
+    type Input = Tensor<rank::Two>;
+    type Backward = backward::alpha_dropout::Operation;
+
+    fn backward(self, output_gradient: Self::Output) -> Result<(Self::Backward, Self::Input)> {
+        Error::check_shape(self.mask.0.dim(), output_gradient.0.dim())?;
+        // dropped out neurons are set to a constant so only the kept neurons have a gradient.
+        let (scale, _) = self.borrow.0.affine();
+        let input_gradient = Tensor(output_gradient.0 * &self.mask.0 * scale);
+        Ok((backward::alpha_dropout::Operation(()), input_gradient))
+    }
+
+    fn backward_recording(
+        self,
+        output_gradient: Self::Output,
+        norms: &mut Vec<ElementType>,
+    ) -> Result<(Self::Backward, Self::Input)> {
+        norms.push(output_gradient.l2_norm());
+        self.backward(output_gradient)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::initialised;
+
+    fn backing() -> trainable::alpha_dropout::Operation {
+        trainable::alpha_dropout::Operation(initialised::alpha_dropout::Operation(
+            initialised::dropout::Operation {
+                keep_probability: 0.5,
+                seed: None,
+                inverted: false,
+            },
+        ))
+    }
+
+    #[test]
+    fn test_backward_success() {
+        // Arrange
+        let mut backing = backing();
+        let (scale, _) = backing.0.affine();
+        let forward = Operation {
+            borrow: &mut backing,
+            mask: Tensor::<rank::Two>::new((1, 3), [1.0, 0.0, 1.0]).unwrap(),
+        };
+        let output_gradient = Tensor::<rank::Two>::new((1, 3), [1.0, 2.0, 3.0]).unwrap();
+        let expected = Tensor::<rank::Two>::new((1, 3), [scale, 0.0, 3.0 * scale]).unwrap();
+
+        // Act
+        let (_, input_gradient) = forward.backward(output_gradient).unwrap();
+
+        // Assert
+        assert_eq!(input_gradient, expected);
+    }
+
+    #[test]
+    fn test_backward_failure() {
+        // Arrange
+        let mut backing = backing();
+        let forward = Operation {
+            borrow: &mut backing,
+            mask: Tensor::<rank::Two>::new((1, 3), [1.0, 0.0, 1.0]).unwrap(),
+        };
+        let output_gradient = Tensor::<rank::Two>::new((1, 2), [1.0, 2.0]).unwrap();
+
+        // Act
+        let result = forward.backward(output_gradient);
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
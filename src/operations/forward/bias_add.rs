@@ -3,7 +3,8 @@ use crate::operations::{backward, trainable};
 use crate::optimisers::base::Optimiser;
 use crate::private::Sealed;
 use crate::tensors::{rank, Tensor};
-use crate::{Error, Result};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
 use ndarray::{Array, Axis};
 
 pub struct Operation<'a, T: 'a> {
@@ -17,30 +18,35 @@ impl<'a, T: 'a + Optimiser<Tensor<rank::Two>>> ForwardOperation for Operation<'a
     type Backward = backward::bias_add::Operation<'a, T>;
 
     fn backward(self, output_gradient: Self::Output) -> Result<(Self::Backward, Self::Input)> {
-        let output_gradient_dim = output_gradient.0.raw_dim();
+        Error::check_shape(self.borrow.last_input.0.dim(), output_gradient.0.dim())?;
         let input_dim = self.borrow.last_input.0.raw_dim();
-        if output_gradient_dim == input_dim {
-            let input_gradient = Tensor(Array::ones(input_dim) * &output_gradient.0);
-            let borrow = self.borrow;
-            let initialised = &borrow.initialised;
-            let parameter = &initialised.parameter.0;
-            let parameter_dim = parameter.raw_dim();
-            let parameter_gradient = Array::ones(parameter_dim) * output_gradient.0;
-            let parameter_cols = parameter_gradient.ncols();
-            let parameter_gradient = parameter_gradient
-                .map_axis(Axis(0), |view| view.sum())
-                .into_shape((1, parameter_cols))
-                .unwrap();
-            let parameter_gradient = Tensor(parameter_gradient);
-            let backward = Self::Backward {
-                borrow,
-                parameter_gradient,
-            };
-            let returns = (backward, input_gradient);
-            Ok(returns)
-        } else {
-            Err(Error(()))
-        }
+        let input_gradient = Tensor(Array::ones(input_dim) * &output_gradient.0);
+        let borrow = self.borrow;
+        let initialised = &borrow.initialised;
+        let parameter = &initialised.parameter.0;
+        let parameter_dim = parameter.raw_dim();
+        let parameter_gradient = Array::ones(parameter_dim) * output_gradient.0;
+        let parameter_cols = parameter_gradient.ncols();
+        let parameter_gradient = parameter_gradient
+            .map_axis(Axis(0), |view| view.sum())
+            .into_shape((1, parameter_cols))
+            .unwrap();
+        let parameter_gradient = Tensor(parameter_gradient);
+        let backward = Self::Backward {
+            borrow,
+            parameter_gradient,
+        };
+        let returns = (backward, input_gradient);
+        Ok(returns)
+    }
+
+    fn backward_recording(
+        self,
+        output_gradient: Self::Output,
+        norms: &mut Vec<ElementType>,
+    ) -> Result<(Self::Backward, Self::Input)> {
+        norms.push(output_gradient.l2_norm());
+        self.backward(output_gradient)
     }
 }
 
@@ -1,6 +1,7 @@
 use crate::operations::{backward, ForwardOperation};
 use crate::private::Sealed;
-use crate::Result;
+use crate::{ElementType, Result};
+use alloc::vec::Vec;
 
 pub struct Operation<T, U> {
     pub(crate) lhs: T,
@@ -29,6 +30,21 @@ where
         };
         Ok((backward, input_gradient))
     }
+
+    fn backward_recording(
+        self,
+        output_gradient: Self::Output,
+        norms: &mut Vec<ElementType>,
+    ) -> Result<(Self::Backward, Self::Input)> {
+        let (rhs_backward, output_gradient) =
+            self.rhs.backward_recording(output_gradient, norms)?;
+        let (lhs_backward, input_gradient) = self.lhs.backward_recording(output_gradient, norms)?;
+        let backward = Self::Backward {
+            lhs: lhs_backward,
+            rhs: rhs_backward,
+        };
+        Ok((backward, input_gradient))
+    }
 }
 
 #[cfg(test)]
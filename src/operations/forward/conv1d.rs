@@ -0,0 +1,126 @@
+use crate::operations::initialised::conv1d::Window;
+use crate::operations::{backward, ForwardOperation};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Result};
+use alloc::vec::Vec;
+
+pub struct Operation<T> {
+    pub(crate) window: Window,
+    pub(crate) input_shape: (usize, usize, usize),
+    pub(crate) dense: T,
+}
+
+impl<T> Sealed for Operation<T> {}
+impl<T: ForwardOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>> ForwardOperation
+    for Operation<T>
+{
+    type Output = Tensor<rank::Three>;
+    type Input = Tensor<rank::Three>;
+    type Backward = backward::conv1d::Operation<T::Backward>;
+
+    fn backward(self, output_gradient: Self::Output) -> Result<(Self::Backward, Self::Input)> {
+        let (window, input_shape) = (self.window, self.input_shape);
+        let output_gradient = window.feature_rows(&output_gradient.0, input_shape)?;
+        let (dense, column_gradient) = self.dense.backward(Tensor(output_gradient))?;
+        let input_gradient = window.sequence(&column_gradient.0, input_shape)?;
+        Ok((backward::conv1d::Operation(dense), Tensor(input_gradient)))
+    }
+
+    fn backward_recording(
+        self,
+        output_gradient: Self::Output,
+        norms: &mut Vec<ElementType>,
+    ) -> Result<(Self::Backward, Self::Input)> {
+        // reshaping the gradient entering the layer for the kernels doesn't change its norm.
+        let (window, input_shape) = (self.window, self.input_shape);
+        let output_gradient = window.feature_rows(&output_gradient.0, input_shape)?;
+        let (dense, column_gradient) = self
+            .dense
+            .backward_recording(Tensor(output_gradient), norms)?;
+        let input_gradient = window.sequence(&column_gradient.0, input_shape)?;
+        Ok((backward::conv1d::Operation(dense), Tensor(input_gradient)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::activations::Linear;
+    use crate::layers::{Chain, Conv1D, SequenceInput};
+    use crate::operations::{
+        Forward, ForwardOperation, InitialisedOperation, UninitialisedOperation, WithOptimiser,
+    };
+    use crate::optimisers::NullOptimiser;
+    use crate::tensors::{rank, Tensor};
+    use crate::ElementType;
+
+    #[test]
+    fn test_backward_success() {
+        // Arrange
+        // with all kernel weights as 1, the gradient of each input element is the
+        // number of patches it appears in (when the output gradient is 1 everywhere).
+        let mut network = SequenceInput::new(1)
+            .chain(Conv1D::new(1, 2, 1, 0, Linear::new()))
+            .with_iter([1.0, 1.0, 0.0].into_iter())
+            .unwrap()
+            .with_optimiser(NullOptimiser::new());
+        let input = Tensor::<rank::Three>::new((1, 3, 1), [1.0, 2.0, 3.0]).unwrap();
+        let output_gradient = Tensor::<rank::Three>::new((1, 2, 1), [1.0; 2]).unwrap();
+        let expected = Tensor::<rank::Three>::new((1, 3, 1), [1.0, 2.0, 1.0]).unwrap();
+
+        // Act
+        let (forward, _) = network.forward(input).unwrap();
+        let (_, input_gradient) = forward.backward(output_gradient).unwrap();
+
+        // Assert
+        assert_eq!(input_gradient, expected);
+    }
+
+    #[test]
+    fn test_backward_matches_numerical_gradient() {
+        // Arrange
+        const DELTA: ElementType = 1e-3;
+        let initialised = SequenceInput::new(2)
+            .chain(Conv1D::new(2, 2, 2, 1, Linear::new()).with_dilation(2))
+            .with_seed(42);
+        let input = Tensor::<rank::Three>::new(
+            (1, 3, 2),
+            (0u8..6).map(|elem| ElementType::from(elem) / 10.0),
+        )
+        .unwrap();
+        let output_gradient = Tensor::<rank::Three>::new((1, 2, 2), [1.0; 4]).unwrap();
+        let mut network = initialised.clone().with_optimiser(NullOptimiser::new());
+
+        // Act
+        let (forward, _) = network.forward(input.clone()).unwrap();
+        let (_, input_gradient) = forward.backward(output_gradient).unwrap();
+
+        // Assert
+        for (index, gradient) in input_gradient.0.indexed_iter() {
+            let mut nudged = input.clone();
+            nudged.0[index] += DELTA;
+            let before = initialised.predict(input.clone()).unwrap().0.sum();
+            let after = initialised.predict(nudged).unwrap().0.sum();
+            let numerical = (after - before) / DELTA;
+            assert!((numerical - gradient).abs() < 1e-2);
+        }
+    }
+
+    #[test]
+    fn test_backward_failure() {
+        // Arrange
+        let mut network = SequenceInput::new(1)
+            .chain(Conv1D::new(1, 2, 1, 0, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(NullOptimiser::new());
+        let input = Tensor::<rank::Three>::new((1, 3, 1), [1.0; 3]).unwrap();
+        let output_gradient = Tensor::<rank::Three>::new((1, 3, 1), [1.0; 3]).unwrap();
+
+        // Act
+        let (forward, _) = network.forward(input).unwrap();
+        let result = forward.backward(output_gradient);
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,190 @@
+use crate::operations::initialised::conv2d::Window;
+use crate::operations::{backward, ForwardOperation};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Result};
+use alloc::vec::Vec;
+
+pub struct Operation<T, U, V> {
+    pub(crate) window: Window,
+    pub(crate) output_shape: (usize, usize, usize, usize),
+    pub(crate) weight_multiply: T,
+    pub(crate) bias_add: U,
+    pub(crate) activation_function: V,
+}
+
+/// The backward operation of the layer along with the gradient with respect to its input.
+type Backpropagated<T, U, V> = (
+    backward::conv_transpose2d::Operation<T, U, V>,
+    Tensor<rank::Four>,
+);
+
+/// Backpropagates the gradient with respect to the (unactivated) pixels of the output through
+/// the biases and kernels, given the backward operation of the activation function.
+fn backward_pixels<T, U, V>(
+    (window, output_shape): (Window, (usize, usize, usize, usize)),
+    weight_multiply: T,
+    bias_add: U,
+    activation_function: V,
+    pixel_gradient: Tensor<rank::Two>,
+) -> Result<Backpropagated<T::Backward, U::Backward, V>>
+where
+    T: ForwardOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+    U: ForwardOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+{
+    let (bias_add, pixel_gradient) = bias_add.backward(pixel_gradient)?;
+    let image_gradient = window
+        .pointwise()
+        .feature_maps(pixel_gradient.0, output_shape)?;
+    let patch_gradient = window.columns(&image_gradient)?;
+    let (weight_multiply, input_gradient) = weight_multiply.backward(Tensor(patch_gradient))?;
+    let input_gradient = window.feature_maps(input_gradient.0, output_shape)?;
+    let backward = backward::dense::Operation {
+        weight_multiply,
+        bias_add,
+        activation_function,
+    };
+    Ok((
+        backward::conv_transpose2d::Operation(backward),
+        Tensor(input_gradient),
+    ))
+}
+
+impl<T, U, V> Sealed for Operation<T, U, V> {}
+impl<T, U, V> ForwardOperation for Operation<T, U, V>
+where
+    T: ForwardOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+    U: ForwardOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+    V: ForwardOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+{
+    type Output = Tensor<rank::Four>;
+    type Input = Tensor<rank::Four>;
+    type Backward = backward::conv_transpose2d::Operation<T::Backward, U::Backward, V::Backward>;
+
+    fn backward(self, output_gradient: Self::Output) -> Result<(Self::Backward, Self::Input)> {
+        let shapes = (self.window, self.output_shape);
+        let output_gradient = shapes
+            .0
+            .pointwise()
+            .feature_rows(&output_gradient.0, shapes.1)?;
+        let activation_function = self.activation_function;
+        let (activation_function, pixel_gradient) =
+            activation_function.backward(Tensor(output_gradient))?;
+        let (weight_multiply, bias_add) = (self.weight_multiply, self.bias_add);
+        backward_pixels(
+            shapes,
+            weight_multiply,
+            bias_add,
+            activation_function,
+            pixel_gradient,
+        )
+    }
+
+    fn backward_recording(
+        self,
+        output_gradient: Self::Output,
+        norms: &mut Vec<ElementType>,
+    ) -> Result<(Self::Backward, Self::Input)> {
+        // the gradient entering the activation function is the gradient entering the layer.
+        let shapes = (self.window, self.output_shape);
+        let output_gradient = shapes
+            .0
+            .pointwise()
+            .feature_rows(&output_gradient.0, shapes.1)?;
+        let activation_function = self.activation_function;
+        let (activation_function, pixel_gradient) =
+            activation_function.backward_recording(Tensor(output_gradient), norms)?;
+        let (weight_multiply, bias_add) = (self.weight_multiply, self.bias_add);
+        backward_pixels(
+            shapes,
+            weight_multiply,
+            bias_add,
+            activation_function,
+            pixel_gradient,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::activations::Linear;
+    use crate::layers::{Chain, ConvTranspose2D, ImageInput};
+    use crate::operations::{
+        Forward, ForwardOperation, InitialisedOperation, UninitialisedOperation, WithOptimiser,
+    };
+    use crate::optimisers::NullOptimiser;
+    use crate::tensors::{rank, Tensor};
+    use crate::ElementType;
+
+    #[test]
+    fn test_backward_success() {
+        // Arrange
+        // with all kernel weights as 1, the gradient of each input pixel is the sum of the
+        // output gradient over the patch it was spread across.
+        let mut network = ImageInput::new(1)
+            .chain(ConvTranspose2D::new(1, 2, 2, 0, Linear::new()))
+            .with_iter([1.0, 1.0, 1.0, 1.0, 0.0].into_iter())
+            .unwrap()
+            .with_optimiser(NullOptimiser::new());
+        let input = Tensor::<rank::Four>::new((1, 1, 1, 2), [1.0, 2.0]).unwrap();
+        let output_gradient =
+            Tensor::<rank::Four>::new((1, 1, 2, 4), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0])
+                .unwrap();
+        let expected = Tensor::<rank::Four>::new((1, 1, 1, 2), [14.0, 22.0]).unwrap();
+
+        // Act
+        let (forward, _) = network.forward(input).unwrap();
+        let (_, input_gradient) = forward.backward(output_gradient).unwrap();
+
+        // Assert
+        assert_eq!(input_gradient, expected);
+    }
+
+    #[test]
+    fn test_backward_matches_numerical_gradient() {
+        // Arrange
+        const DELTA: ElementType = 1e-3;
+        let initialised = ImageInput::new(2)
+            .chain(ConvTranspose2D::new(2, 3, 2, 1, Linear::new()))
+            .with_seed(42);
+        let input = Tensor::<rank::Four>::new(
+            (1, 2, 2, 2),
+            (0u8..8).map(|elem| ElementType::from(elem) / 10.0),
+        )
+        .unwrap();
+        let output_gradient = Tensor::<rank::Four>::new((1, 2, 3, 3), [1.0; 18]).unwrap();
+        let mut network = initialised.clone().with_optimiser(NullOptimiser::new());
+
+        // Act
+        let (forward, _) = network.forward(input.clone()).unwrap();
+        let (_, input_gradient) = forward.backward(output_gradient).unwrap();
+
+        // Assert
+        for (index, gradient) in input_gradient.0.indexed_iter() {
+            let mut nudged = input.clone();
+            nudged.0[index] += DELTA;
+            let before = initialised.predict(input.clone()).unwrap().0.sum();
+            let after = initialised.predict(nudged).unwrap().0.sum();
+            let numerical = (after - before) / DELTA;
+            assert!((numerical - gradient).abs() < 1e-2);
+        }
+    }
+
+    #[test]
+    fn test_backward_failure() {
+        // Arrange
+        let mut network = ImageInput::new(1)
+            .chain(ConvTranspose2D::new(1, 2, 2, 0, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(NullOptimiser::new());
+        let input = Tensor::<rank::Four>::new((1, 1, 1, 2), [1.0; 2]).unwrap();
+        let output_gradient = Tensor::<rank::Four>::new((1, 1, 1, 2), [1.0; 2]).unwrap();
+
+        // Act
+        let (forward, _) = network.forward(input).unwrap();
+        let result = forward.backward(output_gradient);
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
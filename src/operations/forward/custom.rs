@@ -0,0 +1,104 @@
+use crate::operations::uninitialised::custom::CustomLayer;
+use crate::operations::ForwardOperation;
+use crate::operations::{backward, trainable};
+use crate::optimisers::base::Optimiser;
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+
+pub struct Operation<'a, L: 'a, T: 'a> {
+    pub(crate) borrow: &'a mut trainable::custom::Operation<L, T>,
+}
+
+impl<'a, L: 'a, T: 'a> Sealed for Operation<'a, L, T> {}
+impl<'a, L: 'a + CustomLayer, T: 'a + Optimiser<Tensor<rank::One>>> ForwardOperation
+    for Operation<'a, L, T>
+{
+    type Output = Tensor<rank::Two>;
+    type Input = Tensor<rank::Two>;
+    type Backward = backward::custom::Operation<'a, L, T>;
+
+    fn backward(self, output_gradient: Self::Output) -> Result<(Self::Backward, Self::Input)> {
+        let borrow = self.borrow;
+        let initialised = &borrow.initialised;
+        let (input_gradient, parameter_gradient) = initialised.layer.backward_saved(
+            initialised.parameters(),
+            &borrow.last_input,
+            &mut borrow.saved,
+            &output_gradient,
+        )?;
+        Error::check_size(initialised.parameters.len(), parameter_gradient.len())?;
+        let backward = Self::Backward {
+            borrow,
+            parameter_gradient: Tensor::<rank::One>::new(parameter_gradient),
+        };
+        Ok((backward, input_gradient))
+    }
+
+    fn backward_recording(
+        self,
+        output_gradient: Self::Output,
+        norms: &mut Vec<ElementType>,
+    ) -> Result<(Self::Backward, Self::Input)> {
+        norms.push(output_gradient.l2_norm());
+        self.backward(output_gradient)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::initialised;
+    use crate::operations::uninitialised::custom::tests::Scale;
+    use crate::optimisers::base::OptimiserFactory;
+    use crate::optimisers::NullOptimiser;
+
+    #[test]
+    fn test_backward() {
+        // Arrange
+        let mut train = trainable::custom::Operation {
+            optimiser: <NullOptimiser as OptimiserFactory<()>>::instantiate(&NullOptimiser::new()),
+            initialised: initialised::custom::Operation {
+                layer: Scale,
+                parameters: Tensor::<rank::One>::new([2.0, -1.0]),
+            },
+            last_input: Tensor::<rank::Two>::new((2, 2), [1.0, 2.0, 3.0, 4.0]).unwrap(),
+            saved: Vec::new(),
+        };
+        let output_gradient = Tensor::<rank::Two>::new((2, 2), [1.0, 1.0, 2.0, 0.5]).unwrap();
+        let expected_input_gradient =
+            Tensor::<rank::Two>::new((2, 2), [2.0, -1.0, 4.0, -0.5]).unwrap();
+        let expected_parameter_gradient = Tensor::<rank::One>::new([7.0, 4.0]);
+        let forward = Operation { borrow: &mut train };
+
+        // Act
+        let (backward, input_gradient) = forward.backward(output_gradient).unwrap();
+
+        // Assert
+        assert_eq!(input_gradient, expected_input_gradient);
+        assert_eq!(backward.parameter_gradient, expected_parameter_gradient);
+    }
+
+    #[test]
+    fn test_backward_failure() {
+        // Arrange
+        let mut train = trainable::custom::Operation {
+            optimiser: <NullOptimiser as OptimiserFactory<()>>::instantiate(&NullOptimiser::new()),
+            initialised: initialised::custom::Operation {
+                layer: Scale,
+                parameters: Tensor::<rank::One>::new([2.0, -1.0]),
+            },
+            last_input: Tensor::<rank::Two>::new((2, 2), [1.0, 2.0, 3.0, 4.0]).unwrap(),
+            saved: Vec::new(),
+        };
+        let output_gradient = Tensor::<rank::Two>::new((1, 2), [1.0, 1.0]).unwrap();
+        let forward = Operation { borrow: &mut train };
+
+        // Act
+        let result = forward.backward(output_gradient);
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
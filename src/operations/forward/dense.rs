@@ -1,6 +1,7 @@
 use crate::operations::{backward, ForwardOperation};
 use crate::private::Sealed;
-use crate::Result;
+use crate::{ElementType, Result};
+use alloc::vec::Vec;
 
 pub struct Operation<T, U, V> {
     pub(crate) weight_multiply: T,
@@ -36,6 +37,26 @@ impl<
         };
         Ok((backward, input_gradient))
     }
+
+    fn backward_recording(
+        self,
+        output_gradient: Self::Output,
+        norms: &mut Vec<ElementType>,
+    ) -> Result<(Self::Backward, Self::Input)> {
+        // the gradient entering the activation function is the gradient entering the layer.
+        let activation_function = self.activation_function;
+        let activation_function_result =
+            activation_function.backward_recording(output_gradient, norms);
+        let (activation_function, output_gradient) = activation_function_result?;
+        let (bias_add, output_gradient) = self.bias_add.backward(output_gradient)?;
+        let (weight_multiply, input_gradient) = self.weight_multiply.backward(output_gradient)?;
+        let backward = Self::Backward {
+            weight_multiply,
+            bias_add,
+            activation_function,
+        };
+        Ok((backward, input_gradient))
+    }
 }
 
 #[cfg(test)]
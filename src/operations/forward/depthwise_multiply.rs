@@ -0,0 +1,109 @@
+use crate::operations::initialised::depthwise_multiply::group;
+use crate::operations::{backward, trainable, ForwardOperation};
+use crate::optimisers::base::Optimiser;
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+use ndarray::{Array, Axis, Slice};
+
+pub struct Operation<'a, T: 'a> {
+    pub(crate) borrow: &'a mut trainable::depthwise_multiply::Operation<T>,
+}
+
+impl<'a, T: 'a> Sealed for Operation<'a, T> {}
+impl<'a, T: 'a + Optimiser<Tensor<rank::Two>>> ForwardOperation for Operation<'a, T> {
+    type Output = Tensor<rank::Two>;
+    type Input = Tensor<rank::Two>;
+    type Backward = backward::depthwise_multiply::Operation<'a, T>;
+
+    fn backward(self, output_gradient: Self::Output) -> Result<(Self::Backward, Self::Input)> {
+        let (parameter, last_input) = (
+            &self.borrow.initialised.parameter.0,
+            &self.borrow.last_input.0,
+        );
+        let groups = self.borrow.initialised.groups as usize;
+        let expected_dim = (last_input.nrows(), groups * parameter.ncols());
+        Error::check_shape(expected_dim, output_gradient.0.dim())?;
+        let mut input_gradient = Array::zeros(last_input.dim());
+        let mut parameter_gradient = Array::zeros(parameter.dim());
+        for index in 0..groups {
+            let output_gradient = group(&output_gradient.0, Axis(1), groups, index);
+            let weights = group(parameter, Axis(0), groups, index);
+            let input = group(last_input, Axis(1), groups, index);
+            let size = parameter.nrows() / groups;
+            let range = Slice::from(index * size..(index + 1) * size);
+            input_gradient
+                .slice_axis_mut(Axis(1), range)
+                .assign(&output_gradient.dot(&weights.t()));
+            parameter_gradient
+                .slice_axis_mut(Axis(0), range)
+                .assign(&input.t().dot(&output_gradient));
+        }
+        let backward = backward::depthwise_multiply::Operation {
+            borrow: self.borrow,
+            parameter_gradient: Tensor(parameter_gradient),
+        };
+        Ok((backward, Tensor(input_gradient)))
+    }
+
+    fn backward_recording(
+        self,
+        output_gradient: Self::Output,
+        norms: &mut Vec<ElementType>,
+    ) -> Result<(Self::Backward, Self::Input)> {
+        norms.push(output_gradient.l2_norm());
+        self.backward(output_gradient)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::{initialised, Forward, WithOptimiser};
+    use crate::optimisers::NullOptimiser;
+
+    #[test]
+    fn test_backward_success() {
+        // Arrange
+        let mut operation = initialised::depthwise_multiply::Operation {
+            groups: 2,
+            parameter: Tensor::<rank::Two>::new((4, 1), [1.0, 2.0, 3.0, 4.0]).unwrap(),
+        }
+        .with_optimiser(NullOptimiser::new());
+        let input =
+            Tensor::<rank::Two>::new((2, 4), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]).unwrap();
+        let output_gradient = Tensor::<rank::Two>::new((2, 2), [1.0, 0.0, 1.0, 2.0]).unwrap();
+        let expected_input_gradient =
+            Tensor::<rank::Two>::new((2, 4), [1.0, 2.0, 0.0, 0.0, 1.0, 2.0, 6.0, 8.0]).unwrap();
+        let expected_parameter_gradient =
+            Tensor::<rank::Two>::new((4, 1), [6.0, 8.0, 14.0, 16.0]).unwrap();
+
+        // Act
+        let (forward, _) = operation.forward(input).unwrap();
+        let (backward, input_gradient) = forward.backward(output_gradient).unwrap();
+
+        // Assert
+        assert_eq!(input_gradient, expected_input_gradient);
+        assert_eq!(backward.parameter_gradient, expected_parameter_gradient);
+    }
+
+    #[test]
+    fn test_backward_failure() {
+        // Arrange
+        let mut operation = initialised::depthwise_multiply::Operation {
+            groups: 2,
+            parameter: Tensor::<rank::Two>::new((4, 1), [1.0, 2.0, 3.0, 4.0]).unwrap(),
+        }
+        .with_optimiser(NullOptimiser::new());
+        let input = Tensor::<rank::Two>::new((1, 4), [1.0; 4]).unwrap();
+        let output_gradient = Tensor::<rank::Two>::new((1, 1), [1.0]).unwrap();
+
+        // Act
+        let (forward, _) = operation.forward(input).unwrap();
+        let result = forward.backward(output_gradient);
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
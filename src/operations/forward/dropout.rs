@@ -1,7 +1,8 @@
 use crate::operations::{backward, trainable, ForwardOperation};
 use crate::private::Sealed;
 use crate::tensors::{rank, Tensor};
-use crate::{Error, Result};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
 
 #[derive(Debug, PartialEq)]
 pub struct Operation<'a> {
@@ -9,7 +10,7 @@ pub struct Operation<'a> {
     pub(crate) mask: Tensor<rank::Two>,
 }
 
-impl<'a> Sealed for Operation<'a> {}
+impl Sealed for Operation<'_> {}
 
 impl<'a> ForwardOperation for Operation<'a> {
     type Output = Tensor<rank::Two>;
@@ -17,15 +18,19 @@ impl<'a> ForwardOperation for Operation<'a> {
     type Backward = backward::dropout::Operation<'a>;
 
     fn backward(self, output_gradient: Self::Output) -> Result<(Self::Backward, Self::Input)> {
-        let output_shape = output_gradient.0.raw_dim();
-        let mask_shape = self.mask.0.raw_dim();
-        if output_shape == mask_shape {
-            let input_gradient = Tensor(output_gradient.0 * &self.mask.0);
-            let backward = Self::Backward { _forward: self };
-            Ok((backward, input_gradient))
-        } else {
-            Err(Error(()))
-        }
+        Error::check_shape(self.mask.0.dim(), output_gradient.0.dim())?;
+        let input_gradient = Tensor(output_gradient.0 * &self.mask.0);
+        let backward = Self::Backward { _forward: self };
+        Ok((backward, input_gradient))
+    }
+
+    fn backward_recording(
+        self,
+        output_gradient: Self::Output,
+        norms: &mut Vec<ElementType>,
+    ) -> Result<(Self::Backward, Self::Input)> {
+        norms.push(output_gradient.l2_norm());
+        self.backward(output_gradient)
     }
 }
 
@@ -41,12 +46,14 @@ mod tests {
             initialised: initialised::dropout::Operation {
                 keep_probability: 0.6,
                 seed: Some(42),
+                inverted: false,
             },
         };
         let mut working_backing = trainable::dropout::Operation {
             initialised: initialised::dropout::Operation {
                 keep_probability: 0.6,
                 seed: Some(42),
+                inverted: false,
             },
         };
         let expected_backward = backward::dropout::Operation {
@@ -77,6 +84,7 @@ mod tests {
             initialised: initialised::dropout::Operation {
                 keep_probability: 0.6,
                 seed: Some(42),
+                inverted: false,
             },
         };
         let forward = Operation {
@@ -0,0 +1,94 @@
+use crate::operations::{backward, forward, trainable};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+
+#[derive(Debug, PartialEq)]
+pub struct Operation<'a>(pub(crate) &'a mut trainable::elu::Operation);
+
+impl Sealed for Operation<'_> {}
+impl forward::Operation for Operation<'_> {
+    type Output = Tensor<rank::Two>;
+    type Input = Tensor<rank::Two>;
+    type Backward = backward::elu::Operation;
+
+    fn backward(self, output_gradient: Self::Output) -> Result<(Self::Backward, Self::Input)> {
+        Error::check_shape(self.0.last_output.0.dim(), output_gradient.0.dim())?;
+        // for negative inputs the derivative is alpha * exp(x), which is the output plus alpha.
+        let alpha = self.0.initialised.alpha;
+        let partial = self
+            .0
+            .last_output
+            .0
+            .mapv(|elem| if elem > 0.0 { 1.0 } else { elem + alpha });
+        let input_gradient = Tensor(partial * output_gradient.0);
+        Ok((backward::elu::Operation(()), input_gradient))
+    }
+
+    fn backward_recording(
+        self,
+        output_gradient: Self::Output,
+        norms: &mut Vec<ElementType>,
+    ) -> Result<(Self::Backward, Self::Input)> {
+        norms.push(output_gradient.l2_norm());
+        self.backward(output_gradient)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::{initialised, ForwardOperation};
+
+    #[test]
+    fn test_backward_success() {
+        // Arrange
+        let initialised = initialised::elu::Operation {
+            neurons: 3,
+            alpha: 2.0,
+        };
+        let input = Tensor::<rank::Two>::new((2, 3), [-2.0, -1.0, 0.0, 1.0, 2.0, 3.0]).unwrap();
+        let last_output = Tensor(input.0.mapv(|elem| initialised.elu(elem)));
+        let mut operation = trainable::elu::Operation {
+            initialised,
+            last_output,
+        };
+        let forward = Operation(&mut operation);
+        let output_gradient =
+            Tensor::<rank::Two>::new((2, 3), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        let expected = input
+            .0
+            .mapv(|elem| if elem > 0.0 { 1.0 } else { 2.0 * elem.exp() })
+            * &output_gradient.0;
+
+        // Act
+        let input_gradient = forward.backward(output_gradient).unwrap().1;
+
+        // Assert
+        input_gradient
+            .into_iter()
+            .zip(expected)
+            .for_each(|(output, expected)| assert!((output - expected).abs() < 1e-6));
+    }
+
+    #[test]
+    fn test_backward_failure() {
+        // Arrange
+        let mut operation = trainable::elu::Operation {
+            initialised: initialised::elu::Operation {
+                neurons: 3,
+                alpha: 1.0,
+            },
+            last_output: Tensor::default(),
+        };
+        let forward = Operation(&mut operation);
+        let output_gradient = Tensor::<rank::Two>::new((1, 4), [1.0, 2.0, 3.0, 4.0]).unwrap();
+
+        // Act
+        let result = forward.backward(output_gradient);
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
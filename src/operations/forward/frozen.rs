@@ -0,0 +1,27 @@
+use crate::operations::{backward, ForwardOperation};
+use crate::private::Sealed;
+use crate::{ElementType, Result};
+use alloc::vec::Vec;
+
+pub struct Operation<T>(pub(crate) T);
+
+impl<T> Sealed for Operation<T> {}
+impl<T: ForwardOperation> ForwardOperation for Operation<T> {
+    type Output = T::Output;
+    type Input = T::Input;
+    type Backward = backward::frozen::Operation<T::Backward>;
+
+    fn backward(self, output_gradient: Self::Output) -> Result<(Self::Backward, Self::Input)> {
+        let (backward, input_gradient) = self.0.backward(output_gradient)?;
+        Ok((backward::frozen::Operation(backward), input_gradient))
+    }
+
+    fn backward_recording(
+        self,
+        output_gradient: Self::Output,
+        norms: &mut Vec<ElementType>,
+    ) -> Result<(Self::Backward, Self::Input)> {
+        let (backward, input_gradient) = self.0.backward_recording(output_gradient, norms)?;
+        Ok((backward::frozen::Operation(backward), input_gradient))
+    }
+}
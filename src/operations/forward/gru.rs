@@ -0,0 +1,197 @@
+use crate::operations::{backward, trainable, ForwardOperation};
+use crate::optimisers::base::Optimiser;
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+use ndarray::{concatenate, s, Array, Axis};
+
+pub struct Operation<'a, T: 'a> {
+    pub(crate) borrow: &'a mut trainable::gru::Operation<T>,
+}
+
+impl<'a, T: 'a> Sealed for Operation<'a, T> {}
+impl<'a, T: 'a + Optimiser<Tensor<rank::Two>>> ForwardOperation for Operation<'a, T> {
+    type Output = Tensor<rank::Two>;
+    type Input = Tensor<rank::Three>;
+    type Backward = backward::gru::Operation<'a, T>;
+
+    fn backward(self, output_gradient: Self::Output) -> Result<(Self::Backward, Self::Input)> {
+        let initialised = &self.borrow.initialised;
+        let units = initialised.units();
+        let batch = self.borrow.last_input.0.dim().0;
+        Error::check_shape((batch, units), output_gradient.0.dim())?;
+        let input_weights = &initialised.input_weights.0;
+        let gate_weights = initialised.hidden_weights.0.slice(s![.., ..units * 2]);
+        let candidate_weights = initialised.hidden_weights.0.slice(s![.., units * 2..]);
+        let mut input_weights_gradient = Array::zeros(input_weights.dim());
+        let mut hidden_weights_gradient = Array::zeros(initialised.hidden_weights.0.dim());
+        let mut bias_gradient = Array::zeros(initialised.bias.0.dim());
+        let mut input_gradient = Array::zeros(self.borrow.last_input.0.dim());
+        let mut hidden_gradient = output_gradient.0;
+        let initial_hidden = Array::zeros((batch, units));
+
+        // backpropagate through time, starting from the final step.
+        for (time, step) in self.borrow.last_steps.iter().enumerate().rev() {
+            let previous_hidden = time
+                .checked_sub(1)
+                .map_or(&initial_hidden, |time| &self.borrow.last_steps[time].hidden);
+            let input = self.borrow.last_input.0.slice(s![.., time, ..]);
+
+            // gradients of the gates before their activation functions were applied.
+            let candidate_gradient = &hidden_gradient
+                * &step.update.mapv(|update| 1.0 - update)
+                * step
+                    .candidate
+                    .mapv(|candidate| candidate.mul_add(-candidate, 1.0));
+            let update_gradient = &hidden_gradient
+                * &(previous_hidden - &step.candidate)
+                * step.update.mapv(|update| update * (1.0 - update));
+            let reset_hidden_gradient = candidate_gradient.dot(&candidate_weights.t());
+            let reset_gradient = &reset_hidden_gradient
+                * previous_hidden
+                * step.reset.mapv(|reset| reset * (1.0 - reset));
+            let gate_gradient = concatenate![Axis(1), update_gradient, reset_gradient];
+            let gradient = concatenate![Axis(1), gate_gradient, candidate_gradient];
+
+            input_weights_gradient += &input.t().dot(&gradient);
+            let mut gate_weights_gradient = hidden_weights_gradient.slice_mut(s![.., ..units * 2]);
+            gate_weights_gradient += &previous_hidden.t().dot(&gate_gradient);
+            let mut candidate_weights_gradient =
+                hidden_weights_gradient.slice_mut(s![.., units * 2..]);
+            candidate_weights_gradient +=
+                &(&step.reset * previous_hidden).t().dot(&candidate_gradient);
+            bias_gradient += &gradient.sum_axis(Axis(0));
+            input_gradient
+                .slice_mut(s![.., time, ..])
+                .assign(&gradient.dot(&input_weights.t()));
+
+            hidden_gradient = &hidden_gradient * &step.update
+                + reset_hidden_gradient * &step.reset
+                + gate_gradient.dot(&gate_weights.t());
+        }
+
+        let backward = backward::gru::Operation {
+            borrow: self.borrow,
+            gradients: [
+                Tensor(input_weights_gradient),
+                Tensor(hidden_weights_gradient),
+                Tensor(bias_gradient),
+            ],
+        };
+        Ok((backward, Tensor(input_gradient)))
+    }
+
+    fn backward_recording(
+        self,
+        output_gradient: Self::Output,
+        norms: &mut Vec<ElementType>,
+    ) -> Result<(Self::Backward, Self::Input)> {
+        norms.push(output_gradient.l2_norm());
+        self.backward(output_gradient)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layers::GRU;
+    use crate::operations::{
+        initialised, Forward, InitialisedOperation, UninitialisedOperation, WithOptimiser,
+    };
+    use crate::optimisers::NullOptimiser;
+
+    const EPSILON: ElementType = 1e-3;
+    const TOLERANCE: ElementType = 1e-2;
+
+    fn network() -> initialised::gru::Operation {
+        GRU::new(2).with_seed_private(42, 3).0
+    }
+
+    fn input() -> Tensor<rank::Three> {
+        let elements = (0u8..12).map(|elem| ElementType::from(elem) / 10.0 - 0.5);
+        Tensor::<rank::Three>::new((2, 2, 3), elements).unwrap()
+    }
+
+    // the loss used for checking gradients is just the sum of the outputs.
+    fn loss(network: &initialised::gru::Operation, input: Tensor<rank::Three>) -> ElementType {
+        network.predict(input).unwrap().0.sum()
+    }
+
+    #[test]
+    fn test_backward_input_gradient() {
+        // Arrange
+        let mut operation = network().with_optimiser(NullOptimiser::new());
+        let (forward, output) = operation.forward(input()).unwrap();
+        let output_gradient = Tensor(Array::ones(output.0.dim()));
+
+        // Act
+        let (_, input_gradient) = forward.backward(output_gradient).unwrap();
+
+        // Assert
+        input_gradient
+            .0
+            .indexed_iter()
+            .for_each(|(index, gradient)| {
+                let (mut plus, mut minus) = (input(), input());
+                plus.0[index] += EPSILON;
+                minus.0[index] -= EPSILON;
+                let numeric = (loss(&network(), plus) - loss(&network(), minus)) / (2.0 * EPSILON);
+                assert!((numeric - gradient).abs() < TOLERANCE);
+            });
+    }
+
+    #[test]
+    fn test_backward_parameter_gradients() {
+        // Arrange
+        let mut operation = network().with_optimiser(NullOptimiser::new());
+        let (forward, output) = operation.forward(input()).unwrap();
+        let output_gradient = Tensor(Array::ones(output.0.dim()));
+
+        // Act
+        let (backward, _) = forward.backward(output_gradient).unwrap();
+        let gradients = backward
+            .gradients
+            .iter()
+            .flat_map(|gradient| gradient.0.iter())
+            .collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(gradients.len(), network().iter().count());
+        gradients
+            .into_iter()
+            .enumerate()
+            .for_each(|(index, gradient)| {
+                let perturbed = |offset: ElementType| {
+                    let mut network = network();
+                    let mut count = 0;
+                    network.map_parameters(&mut |elem| {
+                        count += 1;
+                        if count - 1 == index {
+                            elem + offset
+                        } else {
+                            elem
+                        }
+                    });
+                    network
+                };
+                let (plus, minus) = (perturbed(EPSILON), perturbed(-EPSILON));
+                let numeric = (loss(&plus, input()) - loss(&minus, input())) / (2.0 * EPSILON);
+                assert!((numeric - gradient).abs() < TOLERANCE);
+            });
+    }
+
+    #[test]
+    fn test_backward_failure() {
+        // Arrange
+        let mut operation = network().with_optimiser(NullOptimiser::new());
+        let (forward, _) = operation.forward(input()).unwrap();
+        let output_gradient = Tensor::<rank::Two>::new((1, 2), [1.0, 1.0]).unwrap();
+
+        // Act
+        let result = forward.backward(output_gradient);
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
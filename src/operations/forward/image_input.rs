@@ -0,0 +1,68 @@
+use crate::operations::{backward, forward, trainable};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Operation<'a>(pub(crate) &'a mut trainable::image_input::Operation);
+
+impl Sealed for Operation<'_> {}
+impl forward::Operation for Operation<'_> {
+    type Output = Tensor<rank::Four>;
+    type Input = Tensor<rank::Four>;
+    type Backward = backward::image_input::Operation;
+
+    fn backward(self, output_gradient: Self::Output) -> Result<(Self::Backward, Self::Input)> {
+        let channels = output_gradient.0.dim().1;
+        let expected_channels = self.0 .0.channels as usize;
+        if channels == expected_channels {
+            Ok((backward::image_input::Operation(()), output_gradient))
+        } else {
+            Err(Error::ShapeMismatch {
+                expected: expected_channels,
+                actual: channels,
+            })
+        }
+    }
+
+    fn backward_recording(
+        self,
+        output_gradient: Self::Output,
+        norms: &mut Vec<ElementType>,
+    ) -> Result<(Self::Backward, Self::Input)> {
+        norms.push(output_gradient.l2_norm());
+        self.backward(output_gradient)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::{initialised, ForwardOperation};
+
+    #[test]
+    fn test_backward_success() {
+        let mut operation =
+            trainable::image_input::Operation(initialised::image_input::Operation { channels: 1 });
+        let forward = Operation(&mut operation);
+        let output_gradient = Tensor::<rank::Four>::new((1, 1, 1, 2), [1.0, 2.0]).unwrap();
+        let expected = output_gradient.clone();
+
+        let input_gradient = forward.backward(output_gradient).unwrap().1;
+
+        assert_eq!(input_gradient, expected);
+    }
+
+    #[test]
+    fn test_backward_failure() {
+        let mut operation =
+            trainable::image_input::Operation(initialised::image_input::Operation { channels: 3 });
+        let forward = Operation(&mut operation);
+        let output_gradient = Tensor::<rank::Four>::new((1, 1, 1, 2), [1.0, 2.0]).unwrap();
+
+        let result = forward.backward(output_gradient);
+
+        assert!(result.is_err());
+    }
+}
@@ -1,13 +1,14 @@
 use crate::operations::{backward, forward, trainable};
 use crate::private::Sealed;
 use crate::tensors::{rank, Tensor};
-use crate::{Error, Result};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
 
 #[derive(Debug, Eq, PartialEq)]
 pub struct Operation<'a>(pub(crate) &'a mut trainable::input::Operation);
 
 impl Sealed for Operation<'_> {}
-impl<'a> forward::Operation for Operation<'a> {
+impl forward::Operation for Operation<'_> {
     type Output = Tensor<rank::Two>;
     type Input = Tensor<rank::Two>;
     type Backward = backward::input::Operation;
@@ -18,9 +19,21 @@ impl<'a> forward::Operation for Operation<'a> {
         if neurons == expected_neurons {
             Ok((backward::input::Operation(()), output_gradient))
         } else {
-            Err(Error(()))
+            Err(Error::ShapeMismatch {
+                expected: expected_neurons,
+                actual: neurons,
+            })
         }
     }
+
+    fn backward_recording(
+        self,
+        output_gradient: Self::Output,
+        norms: &mut Vec<ElementType>,
+    ) -> Result<(Self::Backward, Self::Input)> {
+        norms.push(output_gradient.l2_norm());
+        self.backward(output_gradient)
+    }
 }
 
 #[cfg(test)]
@@ -3,20 +3,42 @@
 //! run on it for training and so will produce a structure
 //! ready for running the backward pass.
 
+pub mod alpha_dropout;
 pub mod bias_add;
 pub mod composite;
+pub mod conv1d;
+pub mod conv2d;
+pub mod conv_transpose2d;
+pub mod custom;
 pub mod dense;
+pub mod depthwise_multiply;
 pub mod dropout;
+pub mod elu;
+pub mod frozen;
+pub mod gelu;
+pub mod gru;
+pub mod image_input;
 pub mod input;
 pub mod linear;
+pub mod multi_input;
+pub mod parallel;
+pub mod pooling2d;
 pub mod relu;
+pub mod reshape;
+pub mod selu;
+pub mod sequence_input;
 pub mod sigmoid;
+pub mod silu;
+pub mod spatial_dropout;
+pub mod stochastic_depth;
 pub mod tanh;
+pub mod upsample2d;
 pub mod weight_multiply;
 
 use crate::operations::{BackwardOperation, ForwardOperation, TrainableOperation};
 use crate::private::Sealed;
-use crate::Result;
+use crate::{ElementType, Result};
+use alloc::vec::Vec;
 
 /// This trait begins a forward pass on an operation and is required to be separate from
 /// the `TrainableOperation` trait because we need to vary the `Forward` handle type based on
@@ -61,4 +83,17 @@ pub trait Operation: Sealed {
     /// # Errors
     /// `Error` if the backward pass fails such as due to an invalid shape output gradient.
     fn backward(self, output_gradient: Self::Output) -> Result<(Self::Backward, Self::Input)>;
+
+    /// Runs the backward pass in the same way as `backward`, additionally pushing the L2 norm
+    /// of the output gradient entering each layer onto the given vector. As this is the backward
+    /// pass, the norms are pushed from the last layer to the first.
+    ///
+    /// # Errors
+    /// `Error` if the backward pass fails such as due to an invalid shape output gradient.
+    #[doc(hidden)]
+    fn backward_recording(
+        self,
+        output_gradient: Self::Output,
+        norms: &mut Vec<ElementType>,
+    ) -> Result<(Self::Backward, Self::Input)>;
 }
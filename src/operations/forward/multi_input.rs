@@ -0,0 +1,95 @@
+use crate::operations::forward::parallel::split;
+use crate::operations::uninitialised::parallel::Merge;
+use crate::operations::{backward, ForwardOperation};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+use ndarray::{concatenate, Axis};
+
+pub struct Operation<T, U> {
+    pub(crate) lhs: T,
+    pub(crate) rhs: U,
+    pub(crate) merge: Merge,
+    pub(crate) neurons: (usize, usize), // the number of output neurons of each branch
+}
+
+impl<T, U> Sealed for Operation<T, U> {}
+impl<T, U> ForwardOperation for Operation<T, U>
+where
+    T: ForwardOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+    U: ForwardOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+{
+    type Output = Tensor<rank::Two>;
+    type Input = Tensor<rank::Two>;
+    type Backward = backward::parallel::Operation<T::Backward, U::Backward>;
+
+    fn backward(self, output_gradient: Self::Output) -> Result<(Self::Backward, Self::Input)> {
+        let (lhs_gradient, rhs_gradient) = split(self.merge, self.neurons, output_gradient)?;
+        let (lhs, lhs_input_gradient) = self.lhs.backward(lhs_gradient)?;
+        let (rhs, rhs_input_gradient) = self.rhs.backward(rhs_gradient)?;
+        // each branch took its own columns of the input, so their input gradients are placed side by side.
+        Error::check_size(lhs_input_gradient.0.nrows(), rhs_input_gradient.0.nrows())?;
+        let input_gradient = Tensor(
+            concatenate(
+                Axis(1),
+                &[lhs_input_gradient.0.view(), rhs_input_gradient.0.view()],
+            )
+            .unwrap(),
+        );
+        Ok((backward::parallel::Operation { lhs, rhs }, input_gradient))
+    }
+
+    fn backward_recording(
+        self,
+        output_gradient: Self::Output,
+        norms: &mut Vec<ElementType>,
+    ) -> Result<(Self::Backward, Self::Input)> {
+        // the branches are a single layer, so only the gradient entering the layer is recorded.
+        norms.push(output_gradient.l2_norm());
+        self.backward(output_gradient)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::activations::Linear;
+    use crate::layers::{Chain, Dense, Input, Merge, MultiInput};
+    use crate::operations::{
+        BackwardOperation, Forward, ForwardOperation, InitialisedOperation, TrainableOperation,
+        UninitialisedOperation, WithOptimiser,
+    };
+    use crate::optimisers::learning_rate_handlers::FixedLearningRateHandler;
+    use crate::optimisers::SGD;
+    use crate::tensors::{rank, Tensor};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_backward() {
+        // Arrange
+        let mut operation = MultiInput::new(
+            Input::new(1).chain(Dense::new(1, Linear::new())),
+            Input::new(2).chain(Dense::new(1, Linear::new())),
+            Merge::Concatenate,
+        )
+        .with_iter([2.0, 0.0, 3.0, 4.0, 0.0].into_iter())
+        .unwrap()
+        .with_optimiser(SGD::new(FixedLearningRateHandler::new(1.0)));
+        let input = Tensor::<rank::Two>::new((1, 3), [1.0, 1.0, 1.0]).unwrap();
+        let output_gradient = Tensor::<rank::Two>::new((1, 2), [1.0, 0.0]).unwrap();
+        let expected = Tensor::<rank::Two>::new((1, 3), [2.0, 0.0, 0.0]).unwrap();
+
+        // Act
+        let (forward, _) = operation.forward(input).unwrap();
+        let (backward, input_gradient) = forward.backward(output_gradient).unwrap();
+        backward.optimise();
+
+        // Assert
+        assert_eq!(input_gradient, expected);
+        // only the parameters of the branch with a gradient are updated.
+        assert_eq!(
+            operation.into_initialised().iter().collect::<Vec<_>>(),
+            [1.0, -1.0, 3.0, 4.0, 0.0]
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use crate::operations::uninitialised::parallel::Merge;
+use crate::operations::{backward, ForwardOperation};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+use ndarray::Axis;
+
+pub struct Operation<T, U> {
+    pub(crate) lhs: T,
+    pub(crate) rhs: U,
+    pub(crate) merge: Merge,
+    pub(crate) neurons: (usize, usize), // the number of output neurons of each branch
+}
+
+/// Splits the output gradient of a layer with two branches merged in the given way into the output gradients
+/// of each branch, given the number of output neurons of each branch.
+pub fn split(
+    merge: Merge,
+    neurons: (usize, usize),
+    output_gradient: Tensor<rank::Two>,
+) -> Result<(Tensor<rank::Two>, Tensor<rank::Two>)> {
+    let (lhs_neurons, rhs_neurons) = neurons;
+    match merge {
+        Merge::Concatenate => {
+            Error::check_size(lhs_neurons + rhs_neurons, output_gradient.0.ncols())?;
+            let (lhs, rhs) = output_gradient.0.view().split_at(Axis(1), lhs_neurons);
+            Ok((Tensor(lhs.to_owned()), Tensor(rhs.to_owned())))
+        }
+        Merge::Add => Ok((output_gradient.clone(), output_gradient)),
+    }
+}
+
+impl<T, U> Sealed for Operation<T, U> {}
+impl<T, U> ForwardOperation for Operation<T, U>
+where
+    T: ForwardOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+    U: ForwardOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+{
+    type Output = Tensor<rank::Two>;
+    type Input = Tensor<rank::Two>;
+    type Backward = backward::parallel::Operation<T::Backward, U::Backward>;
+
+    fn backward(self, output_gradient: Self::Output) -> Result<(Self::Backward, Self::Input)> {
+        let (lhs_gradient, rhs_gradient) = split(self.merge, self.neurons, output_gradient)?;
+        let (lhs, lhs_input_gradient) = self.lhs.backward(lhs_gradient)?;
+        let (rhs, rhs_input_gradient) = self.rhs.backward(rhs_gradient)?;
+        Error::check_shape(lhs_input_gradient.0.dim(), rhs_input_gradient.0.dim())?;
+        let input_gradient = Tensor(lhs_input_gradient.0 + rhs_input_gradient.0);
+        Ok((Self::Backward { lhs, rhs }, input_gradient))
+    }
+
+    fn backward_recording(
+        self,
+        output_gradient: Self::Output,
+        norms: &mut Vec<ElementType>,
+    ) -> Result<(Self::Backward, Self::Input)> {
+        // the branches are a single layer, so only the gradient entering the layer is recorded.
+        norms.push(output_gradient.l2_norm());
+        self.backward(output_gradient)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::activations::Linear;
+    use crate::layers::{Chain, Dense, Input, Merge, Parallel};
+    use crate::operations::{Forward, ForwardOperation, UninitialisedOperation, WithOptimiser};
+    use crate::optimisers::NullOptimiser;
+    use crate::tensors::{rank, Tensor};
+    use crate::Error;
+
+    #[test]
+    fn test_backward_concatenate() {
+        // Arrange
+        let mut operation = Input::new(1)
+            .chain(Parallel::new(
+                Dense::new(1, Linear::new()),
+                Dense::new(2, Linear::new()),
+                Merge::Concatenate,
+            ))
+            .with_iter([2.0, 0.0, 3.0, 4.0, 0.0, 0.0].into_iter())
+            .unwrap()
+            .with_optimiser(NullOptimiser::new());
+        let input = Tensor::<rank::Two>::new((1, 1), [1.0]).unwrap();
+        let output_gradient = Tensor::<rank::Two>::new((1, 3), [1.0, 1.0, -1.0]).unwrap();
+        let expected = Tensor::<rank::Two>::new((1, 1), [1.0]).unwrap();
+
+        // Act
+        let (forward, _) = operation.forward(input).unwrap();
+        let (_, input_gradient) = forward.backward(output_gradient).unwrap();
+
+        // Assert
+        assert_eq!(input_gradient, expected);
+    }
+
+    #[test]
+    fn test_backward_add() {
+        // Arrange
+        let mut operation = Input::new(1)
+            .chain(Parallel::new(
+                Dense::new(1, Linear::new()),
+                Dense::new(1, Linear::new()),
+                Merge::Add,
+            ))
+            .with_iter([2.0, 0.0, 3.0, 0.0].into_iter())
+            .unwrap()
+            .with_optimiser(NullOptimiser::new());
+        let input = Tensor::<rank::Two>::new((1, 1), [1.0]).unwrap();
+        let output_gradient = Tensor::<rank::Two>::new((1, 1), [2.0]).unwrap();
+        let expected = Tensor::<rank::Two>::new((1, 1), [10.0]).unwrap();
+
+        // Act
+        let (forward, _) = operation.forward(input).unwrap();
+        let (_, input_gradient) = forward.backward(output_gradient).unwrap();
+
+        // Assert
+        assert_eq!(input_gradient, expected);
+    }
+
+    #[test]
+    fn test_backward_failure() {
+        // Arrange
+        let mut operation = Input::new(1)
+            .chain(Parallel::new(
+                Dense::new(1, Linear::new()),
+                Dense::new(2, Linear::new()),
+                Merge::Concatenate,
+            ))
+            .with_seed(42)
+            .with_optimiser(NullOptimiser::new());
+        let input = Tensor::<rank::Two>::new((1, 1), [1.0]).unwrap();
+        let output_gradient = Tensor::<rank::Two>::new((1, 2), [1.0, 1.0]).unwrap();
+
+        // Act
+        let (forward, _) = operation.forward(input).unwrap();
+        let result = forward.backward(output_gradient);
+
+        // Assert
+        assert_eq!(
+            result.map(|(_, gradient)| gradient),
+            Err(Error::ShapeMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+    }
+}
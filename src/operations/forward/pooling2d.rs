@@ -0,0 +1,109 @@
+use crate::operations::uninitialised::pooling2d::Pool;
+use crate::operations::{backward, forward, trainable};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Result};
+use alloc::vec::Vec;
+use ndarray::{Array, Ix4};
+
+pub struct Operation<'a, T> {
+    pub(crate) borrow: &'a mut trainable::pooling2d::Operation<T>,
+    pub(crate) input_shape: (usize, usize, usize, usize),
+    pub(crate) positions: Array<usize, Ix4>, // where in each window the gradient is routed to
+}
+
+impl<T> Sealed for Operation<'_, T> {}
+impl<T: Pool> forward::Operation for Operation<'_, T> {
+    type Output = Tensor<rank::Four>;
+    type Input = Tensor<rank::Four>;
+    type Backward = backward::pooling2d::Operation;
+
+    fn backward(self, output_gradient: Self::Output) -> Result<(Self::Backward, Self::Input)> {
+        let input_gradient =
+            self.borrow
+                .0
+                .route(&output_gradient.0, &self.positions, self.input_shape)?;
+        Ok((backward::pooling2d::Operation(()), Tensor(input_gradient)))
+    }
+
+    fn backward_recording(
+        self,
+        output_gradient: Self::Output,
+        norms: &mut Vec<ElementType>,
+    ) -> Result<(Self::Backward, Self::Input)> {
+        norms.push(output_gradient.l2_norm());
+        self.backward(output_gradient)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::layers::{AveragePooling2D, Chain, ImageInput, MaxPooling2D};
+    use crate::operations::{Forward, ForwardOperation, UninitialisedOperation, WithOptimiser};
+    use crate::optimisers::NullOptimiser;
+    use crate::tensors::{rank, Tensor};
+
+    #[test]
+    fn test_backward_max_routes_to_argmax() {
+        // Arrange
+        let mut network = ImageInput::new(1)
+            .chain(MaxPooling2D::new(2, 1))
+            .with_seed(42)
+            .with_optimiser(NullOptimiser::new());
+        let input =
+            Tensor::<rank::Four>::new((1, 1, 3, 3), [1.0, 5.0, 2.0, 0.0, 3.0, 4.0, 7.0, 1.0, 6.0])
+                .unwrap();
+        let output_gradient =
+            Tensor::<rank::Four>::new((1, 1, 2, 2), [1.0, 2.0, 3.0, 4.0]).unwrap();
+        // the 5 is the largest in both windows on the top row, so receives both gradients.
+        let expected =
+            Tensor::<rank::Four>::new((1, 1, 3, 3), [0.0, 3.0, 0.0, 0.0, 0.0, 0.0, 3.0, 0.0, 4.0])
+                .unwrap();
+
+        // Act
+        let (forward, _) = network.forward(input).unwrap();
+        let (_, input_gradient) = forward.backward(output_gradient).unwrap();
+
+        // Assert
+        assert_eq!(input_gradient, expected);
+    }
+
+    #[test]
+    fn test_backward_average_shares_gradient() {
+        // Arrange
+        let mut network = ImageInput::new(1)
+            .chain(AveragePooling2D::new(2, 2))
+            .with_seed(42)
+            .with_optimiser(NullOptimiser::new());
+        let input = Tensor::<rank::Four>::new((1, 1, 2, 4), [1.0; 8]).unwrap();
+        let output_gradient = Tensor::<rank::Four>::new((1, 1, 1, 2), [4.0, 8.0]).unwrap();
+        let expected =
+            Tensor::<rank::Four>::new((1, 1, 2, 4), [1.0, 1.0, 2.0, 2.0, 1.0, 1.0, 2.0, 2.0])
+                .unwrap();
+
+        // Act
+        let (forward, _) = network.forward(input).unwrap();
+        let (_, input_gradient) = forward.backward(output_gradient).unwrap();
+
+        // Assert
+        assert_eq!(input_gradient, expected);
+    }
+
+    #[test]
+    fn test_backward_failure() {
+        // Arrange
+        let mut network = ImageInput::new(1)
+            .chain(MaxPooling2D::new(2, 2))
+            .with_seed(42)
+            .with_optimiser(NullOptimiser::new());
+        let input = Tensor::<rank::Four>::new((1, 1, 2, 2), [1.0; 4]).unwrap();
+        let output_gradient = Tensor::<rank::Four>::new((1, 1, 2, 2), [1.0; 4]).unwrap();
+
+        // Act
+        let (forward, _) = network.forward(input).unwrap();
+        let result = forward.backward(output_gradient);
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
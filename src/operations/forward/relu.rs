@@ -1,31 +1,38 @@
 use crate::operations::{backward, forward, trainable};
 use crate::private::Sealed;
 use crate::tensors::{rank, Tensor};
-use crate::{Error, Result};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
 
 #[derive(Debug, PartialEq)]
 pub struct Operation<'a>(pub(crate) &'a mut trainable::relu::Operation);
 
 impl Sealed for Operation<'_> {}
-impl<'a> forward::Operation for Operation<'a> {
+impl forward::Operation for Operation<'_> {
     type Output = Tensor<rank::Two>;
     type Input = Tensor<rank::Two>;
     type Backward = backward::relu::Operation;
 
     fn backward(self, output_gradient: Self::Output) -> Result<(Self::Backward, Self::Input)> {
-        if output_gradient.0.raw_dim() == self.0.last_output.0.raw_dim() {
-            let partial = self.0.last_output.0.mapv(|elem| {
-                if elem > 0.0 {
-                    1.0
-                } else {
-                    self.0.initialised.factor
-                }
-            });
-            let input_gradient = Tensor(partial * output_gradient.0);
-            Ok((backward::relu::Operation(()), input_gradient))
-        } else {
-            Err(Error(()))
-        }
+        Error::check_shape(self.0.last_output.0.dim(), output_gradient.0.dim())?;
+        let partial = self.0.last_output.0.mapv(|elem| {
+            if elem > 0.0 {
+                1.0
+            } else {
+                self.0.initialised.factor
+            }
+        });
+        let input_gradient = Tensor(partial * output_gradient.0);
+        Ok((backward::relu::Operation(()), input_gradient))
+    }
+
+    fn backward_recording(
+        self,
+        output_gradient: Self::Output,
+        norms: &mut Vec<ElementType>,
+    ) -> Result<(Self::Backward, Self::Input)> {
+        norms.push(output_gradient.l2_norm());
+        self.backward(output_gradient)
     }
 }
 
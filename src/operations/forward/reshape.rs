@@ -0,0 +1,84 @@
+use crate::operations::initialised::reshape::reshape;
+use crate::operations::{backward, forward};
+use crate::private::Sealed;
+use crate::tensors::rank::{Batched, Rank};
+use crate::tensors::Tensor;
+use crate::{ElementType, Result};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+pub struct Operation<R: Rank, S> {
+    pub(crate) input_shape: R::Internal,
+    pub(crate) output_rank: PhantomData<S>,
+}
+
+impl<R: Rank, S> Sealed for Operation<R, S> {}
+impl<R: Batched, S: Batched> forward::Operation for Operation<R, S> {
+    type Output = Tensor<S>;
+    type Input = Tensor<R>;
+    type Backward = backward::reshape::Operation;
+
+    fn backward(self, output_gradient: Self::Output) -> Result<(Self::Backward, Self::Input)> {
+        let input_gradient = reshape(&output_gradient.0, self.input_shape)?;
+        Ok((backward::reshape::Operation(()), Tensor(input_gradient)))
+    }
+
+    fn backward_recording(
+        self,
+        output_gradient: Self::Output,
+        norms: &mut Vec<ElementType>,
+    ) -> Result<(Self::Backward, Self::Input)> {
+        norms.push(output_gradient.l2_norm());
+        self.backward(output_gradient)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::layers::{Chain, Flatten, ImageInput};
+    use crate::operations::{Forward, ForwardOperation, UninitialisedOperation, WithOptimiser};
+    use crate::optimisers::NullOptimiser;
+    use crate::tensors::{rank, Tensor};
+    use crate::ElementType;
+
+    #[test]
+    fn test_backward_success() {
+        // Arrange
+        let mut network = ImageInput::new(2)
+            .chain(Flatten::new(4))
+            .with_seed(42)
+            .with_optimiser(NullOptimiser::new());
+        let input =
+            Tensor::<rank::Four>::new((2, 2, 1, 2), (0u8..8).map(ElementType::from)).unwrap();
+        let (forward, _) = network.forward(input).unwrap();
+        let output_gradient =
+            Tensor::<rank::Two>::new((2, 4), (10u8..18).map(ElementType::from)).unwrap();
+        let expected =
+            Tensor::<rank::Four>::new((2, 2, 1, 2), (10u8..18).map(ElementType::from)).unwrap();
+
+        // Act
+        let (_, input_gradient) = forward.backward(output_gradient).unwrap();
+
+        // Assert
+        assert_eq!(input_gradient, expected);
+    }
+
+    #[test]
+    fn test_backward_failure() {
+        // Arrange
+        let mut network = ImageInput::new(2)
+            .chain(Flatten::new(4))
+            .with_seed(42)
+            .with_optimiser(NullOptimiser::new());
+        let input =
+            Tensor::<rank::Four>::new((2, 2, 1, 2), (0u8..8).map(ElementType::from)).unwrap();
+        let (forward, _) = network.forward(input).unwrap();
+        let output_gradient = Tensor::<rank::Two>::new((1, 4), [1.0, 2.0, 3.0, 4.0]).unwrap();
+
+        // Act
+        let result = forward.backward(output_gradient);
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,93 @@
+use crate::operations::initialised::selu::{ALPHA, SCALE};
+use crate::operations::{backward, forward, trainable};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+
+#[derive(Debug, PartialEq)]
+pub struct Operation<'a>(pub(crate) &'a mut trainable::selu::Operation);
+
+impl Sealed for Operation<'_> {}
+impl forward::Operation for Operation<'_> {
+    type Output = Tensor<rank::Two>;
+    type Input = Tensor<rank::Two>;
+    type Backward = backward::selu::Operation;
+
+    fn backward(self, output_gradient: Self::Output) -> Result<(Self::Backward, Self::Input)> {
+        Error::check_shape(self.0.last_output.0.dim(), output_gradient.0.dim())?;
+        let partial = self.0.last_output.0.mapv(|elem| {
+            if elem > 0.0 {
+                SCALE
+            } else {
+                SCALE.mul_add(ALPHA, elem)
+            }
+        });
+        let input_gradient = Tensor(partial * output_gradient.0);
+        Ok((backward::selu::Operation(()), input_gradient))
+    }
+
+    fn backward_recording(
+        self,
+        output_gradient: Self::Output,
+        norms: &mut Vec<ElementType>,
+    ) -> Result<(Self::Backward, Self::Input)> {
+        norms.push(output_gradient.l2_norm());
+        self.backward(output_gradient)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::{initialised, ForwardOperation};
+
+    #[test]
+    fn test_backward_success() {
+        // Arrange
+        let input = Tensor::<rank::Two>::new((2, 3), [-2.0, -1.0, 0.0, 1.0, 2.0, 3.0]).unwrap();
+        let last_output = Tensor(input.0.mapv(initialised::selu::selu));
+        let mut operation = trainable::selu::Operation {
+            initialised: initialised::selu::Operation { neurons: 3 },
+            last_output,
+        };
+        let forward = Operation(&mut operation);
+        let output_gradient =
+            Tensor::<rank::Two>::new((2, 3), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        let expected = Tensor::<rank::Two>(
+            input.0.mapv(|elem| {
+                if elem > 0.0 {
+                    SCALE
+                } else {
+                    SCALE * ALPHA * elem.exp()
+                }
+            }) * output_gradient.0.clone(),
+        );
+
+        // Act
+        let input_gradient = forward.backward(output_gradient).unwrap().1;
+
+        // Assert
+        input_gradient
+            .into_iter()
+            .zip(expected)
+            .for_each(|(output, expected)| assert!((output - expected).abs() < 1e-6));
+    }
+
+    #[test]
+    fn test_backward_failure() {
+        // Arrange
+        let mut operation = trainable::selu::Operation {
+            initialised: initialised::selu::Operation { neurons: 3 },
+            last_output: Tensor::default(),
+        };
+        let forward = Operation(&mut operation);
+        let output_gradient = Tensor::<rank::Two>::new((1, 4), [1.0, 2.0, 3.0, 4.0]).unwrap();
+
+        // Act
+        let result = forward.backward(output_gradient);
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,72 @@
+use crate::operations::{backward, forward, trainable};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Operation<'a>(pub(crate) &'a mut trainable::sequence_input::Operation);
+
+impl Sealed for Operation<'_> {}
+impl forward::Operation for Operation<'_> {
+    type Output = Tensor<rank::Three>;
+    type Input = Tensor<rank::Three>;
+    type Backward = backward::sequence_input::Operation;
+
+    fn backward(self, output_gradient: Self::Output) -> Result<(Self::Backward, Self::Input)> {
+        let features = output_gradient.0.dim().2;
+        let expected_features = self.0 .0.features as usize;
+        if features == expected_features {
+            Ok((backward::sequence_input::Operation(()), output_gradient))
+        } else {
+            Err(Error::ShapeMismatch {
+                expected: expected_features,
+                actual: features,
+            })
+        }
+    }
+
+    fn backward_recording(
+        self,
+        output_gradient: Self::Output,
+        norms: &mut Vec<ElementType>,
+    ) -> Result<(Self::Backward, Self::Input)> {
+        norms.push(output_gradient.l2_norm());
+        self.backward(output_gradient)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::{initialised, ForwardOperation};
+
+    #[test]
+    fn test_backward_success() {
+        let mut operation =
+            trainable::sequence_input::Operation(initialised::sequence_input::Operation {
+                features: 1,
+            });
+        let forward = Operation(&mut operation);
+        let output_gradient = Tensor::<rank::Three>::new((1, 2, 1), [1.0, 2.0]).unwrap();
+        let expected = output_gradient.clone();
+
+        let input_gradient = forward.backward(output_gradient).unwrap().1;
+
+        assert_eq!(input_gradient, expected);
+    }
+
+    #[test]
+    fn test_backward_failure() {
+        let mut operation =
+            trainable::sequence_input::Operation(initialised::sequence_input::Operation {
+                features: 3,
+            });
+        let forward = Operation(&mut operation);
+        let output_gradient = Tensor::<rank::Three>::new((1, 2, 1), [1.0, 2.0]).unwrap();
+
+        let result = forward.backward(output_gradient);
+
+        assert!(result.is_err());
+    }
+}
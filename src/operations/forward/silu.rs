@@ -0,0 +1,84 @@
+use crate::operations::initialised::silu::silu_derivative;
+use crate::operations::{backward, forward, trainable};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+
+#[derive(Debug, PartialEq)]
+pub struct Operation<'a>(pub(crate) &'a mut trainable::silu::Operation);
+
+impl Sealed for Operation<'_> {}
+impl forward::Operation for Operation<'_> {
+    type Output = Tensor<rank::Two>;
+    type Input = Tensor<rank::Two>;
+    type Backward = backward::silu::Operation;
+
+    fn backward(self, output_gradient: Self::Output) -> Result<(Self::Backward, Self::Input)> {
+        Error::check_shape(self.0.last_input.0.dim(), output_gradient.0.dim())?;
+        let partial = self.0.last_input.0.mapv(silu_derivative);
+        let input_gradient = Tensor(partial * output_gradient.0);
+        Ok((backward::silu::Operation(()), input_gradient))
+    }
+
+    fn backward_recording(
+        self,
+        output_gradient: Self::Output,
+        norms: &mut Vec<ElementType>,
+    ) -> Result<(Self::Backward, Self::Input)> {
+        norms.push(output_gradient.l2_norm());
+        self.backward(output_gradient)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::initialised::silu::silu;
+    use crate::operations::{initialised, ForwardOperation};
+
+    #[test]
+    fn test_backward_success() {
+        // Arrange
+        let last_input =
+            Tensor::<rank::Two>::new((2, 3), [-2.0, -1.0, 0.0, 1.0, 2.0, 3.0]).unwrap();
+        let mut operation = trainable::silu::Operation {
+            initialised: initialised::silu::Operation { neurons: 3 },
+            last_input: last_input.clone(),
+        };
+        let forward = Operation(&mut operation);
+        let output_gradient =
+            Tensor::<rank::Two>::new((2, 3), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        // compare against the numerical derivative of the function.
+        let expected = last_input
+            .0
+            .mapv(|elem| (silu(elem + 1e-3) - silu(elem - 1e-3)) / 2e-3)
+            * &output_gradient.0;
+
+        // Act
+        let input_gradient = forward.backward(output_gradient).unwrap().1;
+
+        // Assert
+        input_gradient
+            .into_iter()
+            .zip(expected)
+            .for_each(|(output, expected)| assert!((output - expected).abs() < 1e-3));
+    }
+
+    #[test]
+    fn test_backward_failure() {
+        // Arrange
+        let mut operation = trainable::silu::Operation {
+            initialised: initialised::silu::Operation { neurons: 3 },
+            last_input: Tensor::default(),
+        };
+        let forward = Operation(&mut operation);
+        let output_gradient = Tensor::<rank::Two>::new((1, 4), [1.0, 2.0, 3.0, 4.0]).unwrap();
+
+        // Act
+        let result = forward.backward(output_gradient);
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
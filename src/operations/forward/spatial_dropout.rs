@@ -0,0 +1,94 @@
+use crate::operations::{backward, trainable, ForwardOperation};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+
+pub struct Operation<'a> {
+    pub(crate) _borrow: &'a mut trainable::spatial_dropout::Operation,
+    pub(crate) mask: Tensor<rank::Four>,
+}
+
+impl Sealed for Operation<'_> {}
+impl ForwardOperation for Operation<'_> {
+    type Output = Tensor<rank::Four>;
+    type Input = Tensor<rank::Four>;
+    type Backward = backward::spatial_dropout::Operation;
+
+    fn backward(self, output_gradient: Self::Output) -> Result<(Self::Backward, Self::Input)> {
+        let (batch, channels, height, width) = self.mask.0.dim();
+        let actual = output_gradient.0.dim();
+        Error::check_shape((batch, channels), (actual.0, actual.1))?;
+        Error::check_size(height, actual.2)?;
+        Error::check_size(width, actual.3)?;
+        let input_gradient = Tensor(output_gradient.0 * &self.mask.0);
+        Ok((backward::spatial_dropout::Operation(()), input_gradient))
+    }
+
+    fn backward_recording(
+        self,
+        output_gradient: Self::Output,
+        norms: &mut Vec<ElementType>,
+    ) -> Result<(Self::Backward, Self::Input)> {
+        norms.push(output_gradient.l2_norm());
+        self.backward(output_gradient)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::initialised;
+
+    fn backing() -> trainable::spatial_dropout::Operation {
+        trainable::spatial_dropout::Operation(initialised::spatial_dropout::Operation(
+            initialised::dropout::Operation {
+                keep_probability: 0.5,
+                seed: None,
+                inverted: false,
+            },
+        ))
+    }
+
+    #[test]
+    fn test_backward_success() {
+        // Arrange
+        let mut backing = backing();
+        let forward = Operation {
+            _borrow: &mut backing,
+            mask: Tensor::<rank::Four>::new((1, 2, 1, 2), [1.0, 1.0, 0.0, 0.0]).unwrap(),
+        };
+        let output_gradient =
+            Tensor::<rank::Four>::new((1, 2, 1, 2), [1.0, 2.0, 3.0, 4.0]).unwrap();
+        let expected = Tensor::<rank::Four>::new((1, 2, 1, 2), [1.0, 2.0, 0.0, 0.0]).unwrap();
+
+        // Act
+        let (_, input_gradient) = forward.backward(output_gradient).unwrap();
+
+        // Assert
+        assert_eq!(input_gradient, expected);
+    }
+
+    #[test]
+    fn test_backward_failure() {
+        // Arrange
+        let mut backing = backing();
+        let forward = Operation {
+            _borrow: &mut backing,
+            mask: Tensor::<rank::Four>::new((1, 2, 1, 2), [1.0; 4]).unwrap(),
+        };
+        let output_gradient = Tensor::<rank::Four>::new((1, 2, 2, 1), [1.0; 4]).unwrap();
+
+        // Act
+        let result = forward.backward(output_gradient);
+
+        // Assert
+        assert_eq!(
+            result.map(|(_, gradient)| gradient),
+            Err(Error::ShapeMismatch {
+                expected: 1,
+                actual: 2
+            })
+        );
+    }
+}
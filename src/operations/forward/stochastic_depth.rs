@@ -0,0 +1,84 @@
+use crate::operations::{backward, ForwardOperation};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Result};
+use alloc::vec::Vec;
+
+pub enum Operation<T> {
+    Skipped,
+    Applied(T),
+}
+
+impl<T> Sealed for Operation<T> {}
+impl<T: ForwardOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>> ForwardOperation
+    for Operation<T>
+{
+    type Output = Tensor<rank::Two>;
+    type Input = Tensor<rank::Two>;
+    type Backward = backward::stochastic_depth::Operation<T::Backward>;
+
+    fn backward(self, output_gradient: Self::Output) -> Result<(Self::Backward, Self::Input)> {
+        match self {
+            Self::Skipped => Ok((Self::Backward::Skipped, output_gradient)),
+            Self::Applied(forward) => {
+                let (backward, input_gradient) = forward.backward(output_gradient)?;
+                Ok((Self::Backward::Applied(backward), input_gradient))
+            }
+        }
+    }
+
+    fn backward_recording(
+        self,
+        output_gradient: Self::Output,
+        norms: &mut Vec<ElementType>,
+    ) -> Result<(Self::Backward, Self::Input)> {
+        match self {
+            Self::Skipped => {
+                norms.push(output_gradient.l2_norm());
+                Ok((Self::Backward::Skipped, output_gradient))
+            }
+            Self::Applied(forward) => {
+                let (backward, input_gradient) =
+                    forward.backward_recording(output_gradient, norms)?;
+                Ok((Self::Backward::Applied(backward), input_gradient))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::activations::Linear;
+    use crate::layers::{Chain, Dense, Input, StochasticDepth};
+    use crate::operations::{Forward, ForwardOperation, UninitialisedOperation, WithOptimiser};
+    use crate::optimisers::NullOptimiser;
+    use crate::tensors::{rank, Tensor};
+
+    #[test]
+    fn test_backward() {
+        // Arrange
+        let mut operation = Input::new(1)
+            .chain(StochasticDepth::new(Dense::new(1, Linear::new()), 0.5))
+            .with_iter([2.0, 0.0].into_iter())
+            .unwrap()
+            .with_optimiser(NullOptimiser::new());
+        let input = Tensor::<rank::Two>::new((1, 1), [1.0]).unwrap();
+        let output_gradient = Tensor::<rank::Two>::new((1, 1), [1.0]).unwrap();
+
+        // Act
+        let gradients = (0..100)
+            .map(|_| {
+                let (forward, _) = operation.forward(input.clone()).unwrap();
+                let (_, input_gradient) = forward.backward(output_gradient.clone()).unwrap();
+                input_gradient.0[[0, 0]]
+            })
+            .collect::<alloc::vec::Vec<_>>();
+
+        // Assert
+        assert!(gradients.contains(&1.0));
+        assert!(gradients.contains(&2.0));
+        assert!(gradients
+            .iter()
+            .all(|gradient| *gradient == 1.0 || *gradient == 2.0));
+    }
+}
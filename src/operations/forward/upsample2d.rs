@@ -0,0 +1,116 @@
+use crate::operations::{backward, forward, trainable};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Result};
+use alloc::vec::Vec;
+
+pub struct Operation<'a> {
+    pub(crate) borrow: &'a mut trainable::upsample2d::Operation,
+    pub(crate) input_shape: (usize, usize, usize, usize),
+}
+
+impl Sealed for Operation<'_> {}
+impl forward::Operation for Operation<'_> {
+    type Output = Tensor<rank::Four>;
+    type Input = Tensor<rank::Four>;
+    type Backward = backward::upsample2d::Operation;
+
+    fn backward(self, output_gradient: Self::Output) -> Result<(Self::Backward, Self::Input)> {
+        let input_gradient = self.borrow.0.route(&output_gradient.0, self.input_shape)?;
+        Ok((backward::upsample2d::Operation(()), Tensor(input_gradient)))
+    }
+
+    fn backward_recording(
+        self,
+        output_gradient: Self::Output,
+        norms: &mut Vec<ElementType>,
+    ) -> Result<(Self::Backward, Self::Input)> {
+        norms.push(output_gradient.l2_norm());
+        self.backward(output_gradient)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::layers::{Chain, ImageInput, Interpolation, Upsample2D};
+    use crate::operations::{
+        Forward, ForwardOperation, InitialisedOperation, UninitialisedOperation, WithOptimiser,
+    };
+    use crate::optimisers::NullOptimiser;
+    use crate::tensors::{rank, Tensor};
+    use crate::ElementType;
+
+    #[test]
+    fn test_backward_nearest_sums_blocks() {
+        // Arrange
+        let mut network = ImageInput::new(1)
+            .chain(Upsample2D::new(2, Interpolation::Nearest))
+            .with_seed(42)
+            .with_optimiser(NullOptimiser::new());
+        let input = Tensor::<rank::Four>::new((1, 1, 1, 2), [1.0, 2.0]).unwrap();
+        let output_gradient =
+            Tensor::<rank::Four>::new((1, 1, 2, 4), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0])
+                .unwrap();
+        let expected = Tensor::<rank::Four>::new((1, 1, 1, 2), [14.0, 22.0]).unwrap();
+
+        // Act
+        let (forward, _) = network.forward(input).unwrap();
+        let (_, input_gradient) = forward.backward(output_gradient).unwrap();
+
+        // Assert
+        assert_eq!(input_gradient, expected);
+    }
+
+    #[test]
+    fn test_backward_bilinear_matches_numerical_gradient() {
+        // Arrange
+        // the upsampling is linear, so a large nudge gives an exact gradient.
+        const DELTA: ElementType = 1.0;
+        let initialised = ImageInput::new(2)
+            .chain(Upsample2D::new(3, Interpolation::Bilinear))
+            .with_seed(42);
+        let input = Tensor::<rank::Four>::new(
+            (1, 2, 2, 3),
+            (0u8..12).map(|elem| ElementType::from(elem) / 10.0),
+        )
+        .unwrap();
+        let output_gradient = Tensor::<rank::Four>::new(
+            (1, 2, 6, 9),
+            (0u8..108).map(|elem| ElementType::from(elem % 7)),
+        )
+        .unwrap();
+        let mut network = initialised.clone().with_optimiser(NullOptimiser::new());
+
+        // Act
+        let (forward, _) = network.forward(input.clone()).unwrap();
+        let (_, input_gradient) = forward.backward(output_gradient.clone()).unwrap();
+
+        // Assert
+        for (index, gradient) in input_gradient.0.indexed_iter() {
+            let mut nudged = input.clone();
+            nudged.0[index] += DELTA;
+            let before = initialised.predict(input.clone()).unwrap().0 * &output_gradient.0;
+            let after = initialised.predict(nudged).unwrap().0 * &output_gradient.0;
+            let numerical = (after.sum() - before.sum()) / DELTA;
+            assert!((numerical - gradient).abs() < 1e-2);
+        }
+    }
+
+    #[test]
+    fn test_backward_failure() {
+        // Arrange
+        let mut network = ImageInput::new(1)
+            .chain(Upsample2D::new(2, Interpolation::Nearest))
+            .with_seed(42)
+            .with_optimiser(NullOptimiser::new());
+        let input = Tensor::<rank::Four>::new((1, 1, 2, 2), [1.0; 4]).unwrap();
+        let output_gradient = Tensor::<rank::Four>::new((1, 1, 2, 2), [1.0; 4]).unwrap();
+
+        // Act
+        let (forward, _) = network.forward(input).unwrap();
+        let result = forward.backward(output_gradient);
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
@@ -2,7 +2,8 @@ use crate::operations::{backward, trainable, ForwardOperation};
 use crate::optimisers::base::Optimiser;
 use crate::private::Sealed;
 use crate::tensors::{rank, Tensor};
-use crate::{Error, Result};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
 
 pub struct Operation<'a, T: 'a> {
     pub(crate) borrow: &'a mut trainable::weight_multiply::Operation<T>,
@@ -12,20 +13,20 @@ pub struct Operation<'a, T: 'a> {
 // coverage. Won't change the results, but hopefully will trick the code coverage
 impl<'a, T: 'a> Operation<'a, T> {
     fn get_input_gradient(&self, output_gradient: &Tensor<rank::Two>) -> Tensor<rank::Two> {
-        let output_gradient = &output_gradient.0;
-        let reversed_axes = self.borrow.initialised.parameter.0.clone().reversed_axes();
-        let dot_product = output_gradient.dot(&reversed_axes);
+        // the transposed view is passed straight to the matrix multiplication rather than copied,
+        // which BLAS (when enabled) multiplies without any rearranging.
+        let dot_product = output_gradient
+            .0
+            .dot(&self.borrow.initialised.parameter.0.t());
         Tensor(dot_product)
     }
 
     fn get_parameter_gradient(&self, output_gradient: &Tensor<rank::Two>) -> Tensor<rank::Two> {
-        let output_gradient = &output_gradient.0;
-        let reversed_axes = self.borrow.last_input.0.clone().reversed_axes();
-        let dot_product = reversed_axes.dot(output_gradient);
+        let dot_product = self.borrow.last_input.0.t().dot(&output_gradient.0);
         Tensor(dot_product)
     }
 
-    fn into_backward(
+    const fn into_backward(
         self,
         parameter_gradient: Tensor<rank::Two>,
     ) -> backward::weight_multiply::Operation<'a, T> {
@@ -44,15 +45,23 @@ impl<'a, T: 'a + Optimiser<Tensor<rank::Two>>> ForwardOperation for Operation<'a
     type Backward = backward::weight_multiply::Operation<'a, T>;
 
     fn backward(self, output_gradient: Self::Output) -> Result<(Self::Backward, Self::Input)> {
-        if output_gradient.0.ncols() == self.borrow.initialised.parameter.0.ncols()
-            && self.borrow.last_input.0.nrows() == output_gradient.0.nrows()
-        {
-            let input_gradient = self.get_input_gradient(&output_gradient);
-            let parameter_gradient = self.get_parameter_gradient(&output_gradient);
-            Ok((self.into_backward(parameter_gradient), input_gradient))
-        } else {
-            Err(Error(()))
-        }
+        let expected_dim = (
+            self.borrow.last_input.0.nrows(),
+            self.borrow.initialised.parameter.0.ncols(),
+        );
+        Error::check_shape(expected_dim, output_gradient.0.dim())?;
+        let input_gradient = self.get_input_gradient(&output_gradient);
+        let parameter_gradient = self.get_parameter_gradient(&output_gradient);
+        Ok((self.into_backward(parameter_gradient), input_gradient))
+    }
+
+    fn backward_recording(
+        self,
+        output_gradient: Self::Output,
+        norms: &mut Vec<ElementType>,
+    ) -> Result<(Self::Backward, Self::Input)> {
+        norms.push(output_gradient.l2_norm());
+        self.backward(output_gradient)
     }
 }
 
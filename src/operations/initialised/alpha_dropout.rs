@@ -0,0 +1,178 @@
+use crate::operations::initialised::selu::{ALPHA, SCALE};
+use crate::operations::initialised::Stage;
+use crate::operations::{initialised, trainable, InitialisedOperation, WithOptimiser};
+use crate::optimisers::base::OptimiserFactory;
+use crate::private::Sealed;
+use crate::summary::{LayerOutput, LayerSummary};
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Result};
+use alloc::vec::Vec;
+use core::iter::{empty, Empty};
+use ndarray::{Array, Ix2, Zip};
+use rand::rngs::StdRng;
+
+/// The value that the SELU function tends towards for large negative inputs, which is
+/// what dropped out neurons are set to before the affine transformation.
+const SATURATION: ElementType = -SCALE * ALPHA;
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation(pub(crate) initialised::dropout::Operation);
+
+impl Operation {
+    /// Gets the scale and shift of the affine transformation applied after dropping out,
+    /// chosen so that inputs with zero mean and unit variance keep them.
+    pub(crate) fn affine(&self) -> (ElementType, ElementType) {
+        let keep_probability = self.0.keep_probability;
+        let drop_probability = 1.0 - keep_probability;
+        let variance = (SATURATION * SATURATION * keep_probability)
+            .mul_add(drop_probability, keep_probability);
+        let scale = variance.sqrt().recip();
+        (scale, -scale * SATURATION * drop_probability)
+    }
+
+    /// Sets the elements of the input which are dropped in the mask to the saturation value,
+    /// and then applies the affine transformation to every element.
+    pub(crate) fn drop_out(
+        &self,
+        mut input: Array<ElementType, Ix2>,
+        mask: &Array<ElementType, Ix2>,
+    ) -> Array<ElementType, Ix2> {
+        let (scale, shift) = self.affine();
+        Zip::from(&mut input).and(mask).for_each(|elem, kept| {
+            let value = if *kept == 0.0 { SATURATION } else { *elem };
+            *elem = scale.mul_add(value, shift);
+        });
+        input
+    }
+}
+
+impl Sealed for Operation {}
+impl InitialisedOperation for Operation {
+    type Input = Tensor<rank::Two>;
+    type Output = Tensor<rank::Two>;
+    type ParameterIter = Empty<ElementType>;
+
+    fn iter(&self) -> Self::ParameterIter {
+        empty()
+    }
+
+    fn predict(&self, input: Self::Input) -> Result<Self::Output> {
+        Ok(input)
+    }
+
+    fn predict_stochastic(
+        &self,
+        input: Self::Input,
+        generator: &mut StdRng,
+    ) -> Result<Self::Output> {
+        let mask = self.0.generate_mask(input.0.dim(), generator);
+        Ok(Tensor(self.drop_out(input.0, &mask.0)))
+    }
+
+    fn push_layer_summaries(
+        &self,
+        input_shape: Vec<Option<usize>>,
+        summaries: &mut Vec<LayerSummary>,
+    ) -> Vec<Option<usize>> {
+        summaries.push(LayerSummary::new("AlphaDropout", input_shape.clone(), 0));
+        input_shape
+    }
+
+    fn predict_with_layer_outputs(
+        &self,
+        input: Self::Input,
+        outputs: &mut Vec<LayerOutput>,
+    ) -> Result<Self::Output> {
+        let output = self.predict(input)?;
+        outputs.push(LayerOutput::new("AlphaDropout", &output));
+        Ok(output)
+    }
+
+    fn push_quantization_stages(&self, _stages: &mut Vec<Stage>) -> Result<()> {
+        // alpha dropout leaves its input unchanged when predicting, so it has no stage.
+        Ok(())
+    }
+}
+
+impl<T: OptimiserFactory<()>> WithOptimiser<T> for Operation {
+    type Trainable = trainable::alpha_dropout::Operation;
+
+    fn with_optimiser(self, _optimiser: T) -> Self::Trainable {
+        trainable::alpha_dropout::Operation(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    fn operation(keep_probability: ElementType) -> Operation {
+        Operation(initialised::dropout::Operation {
+            keep_probability,
+            seed: None,
+            inverted: false,
+        })
+    }
+
+    #[test]
+    fn test_predict() {
+        // Arrange
+        let input = Tensor::<rank::Two>::new((1, 3), [1.0, -2.0, 4.0]).unwrap();
+
+        // Act
+        let output = operation(0.5).predict(input.clone()).unwrap();
+
+        // Assert
+        assert_eq!(output, input);
+    }
+
+    #[test]
+    fn test_drop_out() {
+        // Arrange
+        let operation = operation(0.5);
+        let (scale, shift) = operation.affine();
+        let input = Array::from_shape_vec((1, 2), [2.0, 3.0].to_vec()).unwrap();
+        let mask = Array::from_shape_vec((1, 2), [1.0, 0.0].to_vec()).unwrap();
+
+        // Act
+        let output = operation.drop_out(input, &mask);
+
+        // Assert
+        assert_eq!(output[[0, 0]], scale.mul_add(2.0, shift));
+        assert_eq!(output[[0, 1]], scale.mul_add(SATURATION, shift));
+    }
+
+    #[test]
+    fn test_predict_stochastic_keeps_mean() {
+        // Arrange
+        // a dropped out zero is moved away from zero, but on average the mean is unchanged.
+        let mut generator = StdRng::seed_from_u64(42);
+        let input = Tensor(Array::zeros((100, 100)));
+
+        // Act
+        let output = operation(0.8)
+            .predict_stochastic(input, &mut generator)
+            .unwrap();
+
+        // Assert
+        assert!(output.0.mean().unwrap().abs() < 0.05);
+        assert!(output.0.iter().any(|elem| *elem != 0.0));
+    }
+
+    #[test]
+    fn test_keeping_everything_is_identity() {
+        // Arrange
+        let mut generator = StdRng::seed_from_u64(42);
+        let input = Tensor::<rank::Two>::new((1, 3), [1.0, -2.0, 4.0]).unwrap();
+
+        // Act
+        let output = operation(1.0)
+            .predict_stochastic(input.clone(), &mut generator)
+            .unwrap();
+
+        // Assert
+        assert_eq!(output, input);
+    }
+}
@@ -2,9 +2,10 @@ use crate::operations::{trainable, InitialisedOperation, WithOptimiser};
 use crate::optimisers::base::OptimiserFactory;
 use crate::private::Sealed;
 use crate::tensors::{rank, Tensor, TensorIterator};
-use crate::{Error, Result};
+use crate::{ElementType, Error, Result};
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Operation {
     pub(crate) parameter: Tensor<rank::Two>,
 }
@@ -23,9 +24,19 @@ impl InitialisedOperation for Operation {
         if input.0.ncols() == self.parameter.0.ncols() && self.parameter.0.nrows() == 1 {
             Ok(Tensor(input.0 + &self.parameter.0))
         } else {
-            Err(Error(()))
+            Err(Error::ShapeMismatch {
+                expected: self.parameter.0.ncols(),
+                actual: input.0.ncols(),
+            })
         }
     }
+
+    fn map_parameters(&mut self, f: &mut impl FnMut(ElementType) -> ElementType) {
+        self.parameter
+            .0
+            .iter_mut()
+            .for_each(|elem| *elem = f(*elem));
+    }
 }
 
 impl<T: OptimiserFactory<Tensor<rank::Two>>> WithOptimiser<T> for Operation {
@@ -1,9 +1,25 @@
+use crate::loss::Loss;
+use crate::operations::initialised::Stage;
 use crate::operations::{trainable, InitialisedOperation, WithOptimiser};
-use crate::private::Sealed;
-use crate::Result;
+use crate::private::named::named_tensors;
+use crate::private::{native, npy, safetensors, zip, Sealed};
+use crate::quantization::{Granularity, QuantizedNetwork};
+use crate::summary::{LayerOutput, LayerSummary, Summary};
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
 use core::iter::Chain;
+use ndarray::{concatenate, Array, Axis};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rand_distr::StandardNormal;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Operation<T, U> {
     pub(crate) lhs: T,
     pub(crate) rhs: U,
@@ -33,6 +49,347 @@ impl<
         let input = self.rhs.predict(input)?;
         Ok(input)
     }
+
+    fn predict_stochastic(
+        &self,
+        input: Self::Input,
+        generator: &mut StdRng,
+    ) -> Result<Self::Output> {
+        let input = self.lhs.predict_stochastic(input, generator)?;
+        let input = self.rhs.predict_stochastic(input, generator)?;
+        Ok(input)
+    }
+
+    fn map_parameters(&mut self, f: &mut impl FnMut(ElementType) -> ElementType) {
+        self.lhs.map_parameters(f);
+        self.rhs.map_parameters(f);
+    }
+
+    fn push_layer_parameters(&self, layers: &mut Vec<((usize, usize), Vec<ElementType>)>) {
+        self.lhs.push_layer_parameters(layers);
+        self.rhs.push_layer_parameters(layers);
+    }
+
+    fn push_layer_summaries(
+        &self,
+        input_shape: Vec<Option<usize>>,
+        summaries: &mut Vec<LayerSummary>,
+    ) -> Vec<Option<usize>> {
+        let shape = self.lhs.push_layer_summaries(input_shape, summaries);
+        self.rhs.push_layer_summaries(shape, summaries)
+    }
+
+    fn predict_with_layer_outputs(
+        &self,
+        input: Self::Input,
+        outputs: &mut Vec<LayerOutput>,
+    ) -> Result<Self::Output> {
+        let output = self.lhs.predict_with_layer_outputs(input, outputs)?;
+        self.rhs.predict_with_layer_outputs(output, outputs)
+    }
+
+    fn push_quantization_stages(&self, stages: &mut Vec<Stage>) -> Result<()> {
+        self.lhs.push_quantization_stages(stages)?;
+        self.rhs.push_quantization_stages(stages)
+    }
+}
+
+impl<
+        T: InitialisedOperation,
+        U: InitialisedOperation<Input = <T as InitialisedOperation>::Output>,
+    > Operation<T, U>
+{
+    /// Calculates the L2 norm of the parameters of each layer in the network, in the order
+    /// the layers were chained. Layers without any parameters (such as the input layer or
+    /// dropout) are skipped. Steadily growing norms during training can indicate instability.
+    #[must_use]
+    pub fn layer_parameter_norms(&self) -> Vec<ElementType> {
+        let mut layers = Vec::new();
+        self.push_layer_parameters(&mut layers);
+        layers
+            .into_iter()
+            .map(|(_, parameters)| {
+                parameters
+                    .into_iter()
+                    .fold(0.0, |sum: ElementType, elem| elem.mul_add(elem, sum))
+                    .sqrt()
+            })
+            .collect()
+    }
+
+    /// Summarises the network with the type, output shape and number of parameters of each layer in the
+    /// order they were chained, along with the total number of parameters, in the same way as Keras'
+    /// `model.summary()`. Activation functions are part of their layers so aren't listed separately,
+    /// and the summary can be printed as a table with `Display`.
+    #[must_use]
+    pub fn summary(&self) -> Summary {
+        let mut layers = Vec::new();
+        self.push_layer_summaries(Vec::new(), &mut layers);
+        Summary::new(layers)
+    }
+
+    /// Runs the input through the network in the same way as `predict`, additionally returning the output
+    /// of each layer in the order they were chained (the same layers that are listed by `summary`). This can
+    /// be used to debug a network, such as finding `ReLU` units which are never active, or to visualise the
+    /// features learned by each layer.
+    ///
+    /// # Errors
+    /// `Error` if the prediction fails such as if the input is incorrectly shaped.
+    pub fn predict_with_activations(
+        &self,
+        input: T::Input,
+    ) -> Result<(U::Output, Vec<LayerOutput>)> {
+        let mut outputs = Vec::new();
+        let output = self.predict_with_layer_outputs(input, &mut outputs)?;
+        Ok((output, outputs))
+    }
+
+    /// Produces a human-readable dump of the network with one line per layer that has
+    /// parameters. Each line holds the shape of the layer (input neurons by output neurons)
+    /// followed by the flattened parameters of the layer, in the same order as `iter`.
+    /// This is useful for diffing and debugging small networks, and can be read back in with `with_text`.
+    #[must_use]
+    pub fn to_text(&self) -> String {
+        let mut layers = Vec::new();
+        self.push_layer_parameters(&mut layers);
+        let mut text = String::new();
+        for ((rows, cols), parameters) in layers {
+            // writing into a string can't fail.
+            let _ = write!(text, "{rows}x{cols}:");
+            for elem in parameters {
+                let _ = write!(text, " {elem}");
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    /// Produces a `NumPy` .npz archive of the parameters of the network, which can be loaded in Python with
+    /// `numpy.load` or read back in with `with_npz`. The archive holds an array named `layer_{index}.weight`
+    /// for each layer that has parameters, with the shape of the layer (input neurons by output neurons),
+    /// followed by an array named `layer_{index}.bias` for those layers with a bias.
+    ///
+    /// # Errors
+    /// `Error` if the network is too large to fit in an archive (over 4 GiB).
+    pub fn to_npz(&self) -> Result<Vec<u8>> {
+        let mut layers = Vec::new();
+        self.push_layer_parameters(&mut layers);
+        let files = named_tensors(layers)
+            .into_iter()
+            .map(|tensor| {
+                let data = npy::encode(&tensor.shape, &tensor.values);
+                (tensor.name + ".npy", data)
+            })
+            .collect::<Vec<_>>();
+        zip::write(&files)
+    }
+
+    /// Produces the parameters of the network in the safetensors format, which records the name, shape and
+    /// type of each tensor along with its little endian data. The tensors are named in the same way as the
+    /// arrays produced by `to_npz`, and the bytes can be read back in with `with_safetensors`.
+    #[must_use]
+    pub fn to_safetensors(&self) -> Vec<u8> {
+        let mut layers = Vec::new();
+        self.push_layer_parameters(&mut layers);
+        safetensors::encode(&named_tensors(layers))
+    }
+
+    /// Produces the parameters of the network in the Eidetic format, which begins with a header recording the
+    /// version of the format, the element type, and the shape of each layer that has parameters. The bytes
+    /// can be read back in with `with_bytes`, which checks that they match the network.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        native::encode(self)
+    }
+
+    /// Saves the parameters of the network to a file in the format produced by `to_bytes`, which can be
+    /// read back in with `load`.
+    ///
+    /// # Errors
+    /// `Error` if the file can't be written.
+    #[cfg(feature = "std")]
+    pub fn save(&self, path: impl AsRef<std::path::Path>) -> Result<()> {
+        std::fs::write(path, self.to_bytes()).map_err(|_| Error::FileAccess)
+    }
+}
+
+impl<T, U> Operation<T, U>
+where
+    Self: InitialisedOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+{
+    /// Runs the given number of forward passes over the input with dropout kept active
+    /// (Monte Carlo dropout), returning the mean prediction and the per-element (population)
+    /// variance of the predictions across passes. The variance can be used as an estimate of
+    /// the uncertainty of the network. The given seed makes the dropout masks reproducible.
+    ///
+    /// # Errors
+    /// `Error` if the number of passes is zero or the prediction fails such as if the input is incorrectly shaped.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn mc_dropout_predict(
+        &self,
+        input: Tensor<rank::Two>,
+        passes: usize,
+        seed: u64,
+    ) -> Result<(Tensor<rank::Two>, Tensor<rank::Two>)> {
+        let mut generator = StdRng::seed_from_u64(seed);
+        let mut count: ElementType = 0.0;
+        let mut mean = None;
+        let mut sum_of_squares = None;
+        for _ in 0..passes {
+            let output = self.predict_stochastic(input.clone(), &mut generator)?.0;
+            let mean = mean.get_or_insert_with(|| Array::zeros(output.raw_dim()));
+            let sum_of_squares =
+                sum_of_squares.get_or_insert_with(|| Array::zeros(output.raw_dim()));
+
+            // Welford's online algorithm to avoid storing every pass.
+            count += 1.0;
+            let delta = &output - &*mean;
+            *mean += &(&delta / count);
+            *sum_of_squares += &(delta * (output - &*mean));
+        }
+        let (mean, sum_of_squares) = mean.zip(sum_of_squares).ok_or(Error::InvalidArgument)?;
+        Ok((Tensor(mean), Tensor(sum_of_squares / count)))
+    }
+
+    /// Runs a single row of features through the network, returning the single row of output. This wraps
+    /// the row in a batch of one before predicting, and unwraps the output afterwards.
+    ///
+    /// # Errors
+    /// `Error` if the prediction fails such as if the input has the wrong number of features.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn predict_one(&self, input: Tensor<rank::One>) -> Result<Tensor<rank::One>> {
+        let input = Tensor(input.0.insert_axis(Axis(0)));
+        self.predict(input)?.row(0)
+    }
+
+    /// Runs the input through the network in the same way as `predict`, but predicts its rows in batches
+    /// of (at most) the given size one after another, before concatenating their outputs back together
+    /// in order. This bounds the memory used by the intermediate output of each layer to that of a single
+    /// batch, which allows large inputs to be predicted on devices with little memory.
+    ///
+    /// # Errors
+    /// `Error` if the batch size is zero or the prediction fails such as if the input is incorrectly shaped.
+    pub fn predict_batched(
+        &self,
+        input: Tensor<rank::Two>,
+        batch_size: usize,
+    ) -> Result<Tensor<rank::Two>> {
+        if batch_size == 0 {
+            return Err(Error::InvalidArgument);
+        }
+        if batch_size >= input.0.nrows() {
+            return self.predict(input);
+        }
+        let outputs = input
+            .0
+            .axis_chunks_iter(Axis(0), batch_size)
+            .map(|batch| self.predict(Tensor(batch.to_owned())))
+            .collect::<Result<Vec<_>>>()?;
+        let views = outputs
+            .iter()
+            .map(|output| output.0.view())
+            .collect::<Vec<_>>();
+        // every batch is predicted by the same network, so the outputs have the same number of columns.
+        Ok(Tensor(concatenate(Axis(0), &views).unwrap()))
+    }
+
+    /// Runs the input through the network in the same way as `predict`, but splits its rows into (at most)
+    /// the given number of chunks which are predicted in parallel across threads, before concatenating
+    /// their outputs back together in order. This reduces the latency of inference on large batches,
+    /// where single threaded matrix multiplication dominates.
+    ///
+    /// # Errors
+    /// `Error` if the prediction fails such as if the input is incorrectly shaped.
+    #[cfg(feature = "rayon")]
+    pub fn predict_parallel(
+        &self,
+        input: Tensor<rank::Two>,
+        chunks: usize,
+    ) -> Result<Tensor<rank::Two>>
+    where
+        Self: Sync,
+    {
+        let rows = input.0.nrows();
+        let size = rows.div_ceil(chunks.max(1)).max(1);
+        if size >= rows {
+            return self.predict(input);
+        }
+        let outputs = input
+            .0
+            .axis_chunks_iter(Axis(0), size)
+            .map(|chunk| Tensor(chunk.to_owned()))
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .map(|chunk| self.predict(chunk))
+            .collect::<Result<Vec<_>>>()?;
+        let views = outputs
+            .iter()
+            .map(|output| output.0.view())
+            .collect::<Vec<_>>();
+        // every chunk is predicted by the same network, so the outputs have the same number of columns.
+        Ok(Tensor(concatenate(Axis(0), &views).unwrap()))
+    }
+
+    /// Calculates the loss of the network over the given input and targets as the parameters
+    /// are moved along a random direction by each of the given step sizes. The direction is drawn
+    /// from a standard normal distribution with the given seed and normalised to unit length, so
+    /// a step is the L2 distance moved in parameter space. Returns a (step, loss) pair for each step,
+    /// which can be used to visualise how sharp the minimum around a trained network is.
+    ///
+    /// # Errors
+    /// `Error` if the prediction or loss can't be calculated such as if the input or targets are incorrectly shaped.
+    pub fn loss_landscape(
+        &self,
+        loss_function: &impl Loss,
+        input: &Tensor<rank::Two>,
+        targets: &Tensor<rank::Two>,
+        steps: &[ElementType],
+        seed: u64,
+    ) -> Result<Vec<(ElementType, ElementType)>>
+    where
+        Self: Clone,
+    {
+        let direction = StdRng::seed_from_u64(seed)
+            .sample_iter(StandardNormal)
+            .take(self.iter().count())
+            .collect::<Vec<ElementType>>();
+        let norm = direction
+            .iter()
+            .fold(0.0, |sum: ElementType, elem| elem.mul_add(*elem, sum))
+            .sqrt();
+        steps
+            .iter()
+            .map(|&step| {
+                let mut network = self.clone();
+                let mut direction = direction.iter();
+                network.map_parameters(&mut |elem| {
+                    let offset = direction.next().map_or(0.0, |offset| offset / norm);
+                    step.mul_add(offset, elem)
+                });
+                let predictions = network.predict(input.clone())?;
+                let (loss, _) = loss_function.loss(&predictions, targets)?;
+                Ok((step, loss))
+            })
+            .collect()
+    }
+
+    /// Quantizes the weights of the dense layers of the network into 8 bit integers, producing a network
+    /// which can only be used for inference. The given calibration data should be representative of the
+    /// input the network will see, as it's run through the network to find the range of the input to each
+    /// dense layer. Only networks made up of dense layers, activation functions and dropout can be quantized.
+    ///
+    /// # Errors
+    /// `Error` if the network contains a layer which can't be quantized, or if the calibration data is empty
+    /// or incorrectly shaped.
+    pub fn quantize(
+        &self,
+        calibration: &Tensor<rank::Two>,
+        granularity: Granularity,
+    ) -> Result<QuantizedNetwork> {
+        let mut stages = Vec::new();
+        self.push_quantization_stages(&mut stages)?;
+        QuantizedNetwork::new(stages, calibration, granularity)
+    }
 }
 
 impl<T, U, V> WithOptimiser<V> for Operation<T, U>
@@ -55,13 +412,23 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::activations::{ReLU, Sigmoid};
-    use crate::layers::{Chain, Dense, Input};
+    use crate::activations::{Linear, ReLU, Sigmoid};
+    use crate::layers::{
+        Chain, Conv2D, Custom, Dense, Dropout, DynNetwork, Flatten, Frozen, ImageInput, Input,
+        MaxPooling2D, Merge, Parallel, SequenceInput, StochasticDepth, GRU,
+    };
+    use crate::loss::{Loss, MeanSquaredError};
     use crate::operations::{
         trainable, InitialisedOperation, UninitialisedOperation, WithOptimiser,
     };
-    use crate::optimisers::NullOptimiser;
+    use crate::optimisers::learning_rate_handlers::FixedLearningRateHandler;
+    use crate::optimisers::{Adam, NullOptimiser};
+    use crate::private::{npy, zip};
+    use crate::summary::LayerOutput;
     use crate::tensors::{rank, Tensor};
+    use crate::Error;
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
 
     #[test]
     fn test_iter() {
@@ -124,7 +491,7 @@ mod tests {
         let factory = NullOptimiser::new();
         let expected = trainable::composite::Operation {
             lhs: Input::new(2)
-                .with_iter([].into_iter())
+                .with_iter(core::iter::empty())
                 .unwrap()
                 .with_optimiser(factory.clone()),
             rhs: Dense::new(3, ReLU::new())
@@ -143,4 +510,580 @@ mod tests {
         // Assert
         assert_eq!(output, expected);
     }
+
+    #[test]
+    fn test_layer_parameter_norms() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(2, Linear::new()))
+            .chain(Dense::new(1, Linear::new()))
+            .with_iter([1.0, 2.0, 2.0, 0.0, 0.0, 4.0, 2.0, 1.0, 2.0].into_iter())
+            .unwrap();
+        let expected = [5.0, 3.0];
+
+        // Act
+        let output = network.layer_parameter_norms();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_mc_dropout_predict_without_dropout() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(3, Linear::new()))
+            .chain(Dropout::new(1.0))
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42);
+        let input = Tensor::<rank::Two>::new((2, 2), [1.0, 2.0, 3.0, 4.0]).unwrap();
+        let expected_mean = network.predict(input.clone()).unwrap();
+        let expected_variance = Tensor::<rank::Two>::new((2, 1), [0.0, 0.0]).unwrap();
+
+        // Act
+        let (mean, variance) = network.mc_dropout_predict(input, 10, 42).unwrap();
+
+        // Assert
+        assert_eq!(mean, expected_mean);
+        assert_eq!(variance, expected_variance);
+    }
+
+    #[test]
+    fn test_mc_dropout_predict_with_dropout() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(8, Linear::new()))
+            .chain(Dropout::new(0.5))
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42);
+        let input = Tensor::<rank::Two>::new((2, 2), [1.0, 2.0, 3.0, 4.0]).unwrap();
+
+        // Act
+        let (_, variance) = network.mc_dropout_predict(input.clone(), 10, 42).unwrap();
+        let (_, repeated) = network.mc_dropout_predict(input, 10, 42).unwrap();
+
+        // Assert
+        assert!(variance.0.iter().all(|elem| *elem > 0.0));
+        assert_eq!(variance, repeated);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_predict_parallel() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(3, ReLU::new()))
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42);
+        let input =
+            Tensor::<rank::Two>::new((7, 2), (0_u8..14).map(crate::ElementType::from)).unwrap();
+        let expected = network.predict(input.clone()).unwrap();
+
+        // Act
+        let output = network.predict_parallel(input.clone(), 3).unwrap();
+        let single = network.predict_parallel(input, 1).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+        assert_eq!(single, expected);
+    }
+
+    /// Checks that the given value can be cloned, compared and debug formatted.
+    fn assert_clone_debug_eq<T: Clone + core::fmt::Debug + PartialEq>(value: &T) {
+        assert_eq!(&value.clone(), value);
+        assert!(!alloc::format!("{value:?}").is_empty());
+    }
+
+    #[test]
+    fn test_typestates_clone_debug_eq() {
+        // Arrange
+        let dense = Input::new(4)
+            .chain(Dense::new(3, ReLU::new()))
+            .chain(Dropout::new(0.5))
+            .chain(Frozen::new(Dense::new(3, ReLU::new())))
+            .chain(StochasticDepth::new(Dense::new(3, ReLU::new()), 0.5))
+            .chain(Parallel::new(
+                Dense::new(2, Sigmoid::new()),
+                Dense::new(2, Linear::new()),
+                Merge::Concatenate,
+            ));
+        let convolutional = ImageInput::new(1)
+            .chain(Conv2D::new(2, 2, 1, 0, ReLU::new()))
+            .chain(MaxPooling2D::new(2, 2))
+            .chain(Flatten::<rank::Four>::new(8))
+            .chain(Dense::new(1, Sigmoid::new()));
+        let recurrent = SequenceInput::new(3).chain(GRU::new(4));
+        let optimiser = Adam::new(FixedLearningRateHandler::new(0.01), 0.9, 0.999, 1e-8);
+
+        // Act
+        let dense_initialised = dense.clone().with_seed(42);
+        let convolutional_initialised = convolutional.clone().with_seed(42);
+        let recurrent_initialised = recurrent.clone().with_seed(42);
+
+        // Assert
+        assert_clone_debug_eq(&dense);
+        assert_clone_debug_eq(&convolutional);
+        assert_clone_debug_eq(&recurrent);
+        assert_clone_debug_eq(&dense_initialised);
+        assert_clone_debug_eq(&convolutional_initialised);
+        assert_clone_debug_eq(&recurrent_initialised);
+        assert_clone_debug_eq(&dense_initialised.with_optimiser(optimiser.clone()));
+        assert_clone_debug_eq(&convolutional_initialised.with_optimiser(optimiser.clone()));
+        assert_clone_debug_eq(&recurrent_initialised.with_optimiser(optimiser));
+    }
+
+    #[test]
+    fn test_send_sync() {
+        // Arrange
+        fn assert_send_sync<T: Send + Sync>(_: &T) {}
+        let network = Input::new(4)
+            .chain(Dense::new(3, ReLU::new()))
+            .chain(Dropout::new(0.5))
+            .chain(Parallel::new(
+                Dense::new(2, Sigmoid::new()),
+                Custom::new(DynNetwork::new().push(Dense::new(2, Linear::new()))),
+                Merge::Add,
+            ))
+            .with_seed(42);
+
+        // Act
+        let trainable = network.clone().with_optimiser(NullOptimiser::new());
+
+        // Assert
+        assert_send_sync(&network);
+        assert_send_sync(&trainable);
+    }
+
+    #[test]
+    fn test_predict_one() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(2, Linear::new()))
+            .with_iter([1.0, 2.0, 3.0, 4.0, 0.5, -0.5].into_iter())
+            .unwrap();
+        let input = Tensor::<rank::One>::new([1.0, -1.0]);
+        let expected = Tensor::<rank::One>::new([-1.5, -2.5]);
+
+        // Act
+        let output = network.predict_one(input).unwrap();
+        let failure = network.predict_one(Tensor::<rank::One>::new([1.0]));
+
+        // Assert
+        assert_eq!(output, expected);
+        assert_eq!(
+            failure,
+            Err(Error::ShapeMismatch {
+                expected: 2,
+                actual: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_predict_batched() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(3, ReLU::new()))
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42);
+        let input =
+            Tensor::<rank::Two>::new((7, 2), (0_u8..14).map(crate::ElementType::from)).unwrap();
+        let expected = network.predict(input.clone()).unwrap();
+
+        // Act
+        let output = network.predict_batched(input.clone(), 3).unwrap();
+        let single = network.predict_batched(input.clone(), 7).unwrap();
+        let empty = network.predict_batched(input, 0);
+
+        // Assert
+        assert_eq!(output, expected);
+        assert_eq!(single, expected);
+        assert_eq!(empty, Err(Error::InvalidArgument));
+    }
+
+    #[test]
+    fn test_mc_dropout_predict_no_passes() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dropout::new(0.5))
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42);
+        let input = Tensor::<rank::Two>::new((1, 2), [1.0, 2.0]).unwrap();
+
+        // Act
+        let result = network.mc_dropout_predict(input, 0, 42);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_summary() {
+        // Arrange
+        let network = Input::new(3)
+            .chain(Dense::new(4, ReLU::new()))
+            .chain(Dropout::new(0.5))
+            .chain(Dense::new(2, Linear::new()))
+            .with_seed(42);
+
+        // Act
+        let summary = network.summary();
+
+        // Assert
+        let layers = summary
+            .layers()
+            .iter()
+            .map(|layer| (layer.name(), layer.output_shape(), layer.parameters()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            layers,
+            [
+                ("Input", &[None, Some(3)][..], 0),
+                ("Dense", &[None, Some(4)][..], 16),
+                ("Dropout", &[None, Some(4)][..], 0),
+                ("Dense", &[None, Some(2)][..], 10),
+            ]
+        );
+        assert_eq!(summary.total_parameters(), 26);
+    }
+
+    #[test]
+    fn test_summary_convolutional() {
+        // Arrange
+        let network = ImageInput::new(1)
+            .chain(Conv2D::new(2, 2, 1, 0, ReLU::new()))
+            .chain(MaxPooling2D::new(2, 2))
+            .chain(Flatten::<rank::Four>::new(8))
+            .chain(Dense::new(1, Sigmoid::new()))
+            .with_seed(42);
+        let expected = "\
+Layer         Output Shape           Parameters
+ImageInput    (None, 1, None, None)  0
+Conv2D        (None, 2, None, None)  10
+MaxPooling2D  (None, 2, None, None)  0
+Reshape       (None, 8)              0
+Dense         (None, 1)              9
+Total parameters: 19";
+
+        // Act
+        let output = network.summary().to_string();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_predict_with_activations() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(2, ReLU::new()))
+            .chain(Dense::new(1, Linear::new()))
+            .with_iter([1.0, -1.0, 1.0, -1.0, 0.0, 0.0, 2.0, 3.0, 0.5].into_iter())
+            .unwrap();
+        let input = Tensor::<rank::Two>::new((2, 2), [1.0, 2.0, 3.0, 1.0]).unwrap();
+        let expected = network.predict(input.clone()).unwrap();
+
+        // Act
+        let (output, activations) = network.predict_with_activations(input.clone()).unwrap();
+
+        // Assert
+        let hidden = Tensor::<rank::Two>::new((2, 2), [3.0, 0.0, 4.0, 0.0]).unwrap();
+        assert_eq!(output, expected);
+        assert_eq!(
+            activations
+                .iter()
+                .map(LayerOutput::name)
+                .collect::<Vec<_>>(),
+            ["Input", "Dense", "Dense"]
+        );
+        assert_eq!(activations[0].to_tensor(), Ok(input));
+        assert_eq!(activations[1].to_tensor(), Ok(hidden));
+        assert_eq!(activations[2].to_tensor(), Ok(output));
+    }
+
+    #[test]
+    fn test_to_text() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(2, Linear::new()))
+            .chain(Dense::new(1, Linear::new()))
+            .with_iter([1.0, 2.0, 2.0, 0.0, 0.0, 4.0, 2.0, 1.5, -2.0].into_iter())
+            .unwrap();
+        let expected = "2x2: 1 2 2 0 0 4\n2x1: 2 1.5 -2\n";
+
+        // Act
+        let output = network.to_text();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_text_round_trip() {
+        // Arrange
+        let network = Input::new(3)
+            .chain(Dense::new(4, Sigmoid::new()))
+            .chain(Dense::new(2, Linear::new()))
+            .with_seed(42);
+        let input = Tensor::<rank::Two>::new((2, 3), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        let expected = network.predict(input.clone()).unwrap();
+
+        // Act
+        let parsed = Input::new(3)
+            .chain(Dense::new(4, Sigmoid::new()))
+            .chain(Dense::new(2, Linear::new()))
+            .with_text(&network.to_text())
+            .unwrap();
+        let output = parsed.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(parsed, network);
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_with_text_failure() {
+        // Arrange
+        let text = "3x1: 1 2 3 4\n";
+
+        // Act
+        let mismatched = Input::new(2)
+            .chain(Dense::new(2, Linear::new()))
+            .with_text(text);
+        let malformed = Input::new(3)
+            .chain(Dense::new(1, Linear::new()))
+            .with_text("3x1: 1 2 three 4\n");
+
+        // Assert
+        assert!(mismatched.is_err());
+        assert!(malformed.is_err());
+    }
+
+    #[test]
+    fn test_npz_round_trip() {
+        // Arrange
+        let network = Input::new(3)
+            .chain(Dense::new(4, Sigmoid::new()))
+            .chain(Dropout::new(0.5))
+            .chain(Dense::new(2, Linear::new()))
+            .with_seed(42);
+
+        // Act
+        let archive = network.to_npz().unwrap();
+        let parsed = Input::new(3)
+            .chain(Dense::new(4, Sigmoid::new()))
+            .chain(Dropout::new(0.5))
+            .chain(Dense::new(2, Linear::new()))
+            .with_npz(&archive)
+            .unwrap();
+
+        // Assert
+        assert!(parsed.iter().eq(network.iter()));
+    }
+
+    #[test]
+    fn test_with_npz_in_any_order() {
+        // Arrange
+        // arrays can be saved from Python in any order, and with the bias as a row.
+        let files = [
+            ("layer_0.bias.npy".into(), npy::encode(&[1, 2], &[5.0, 6.0])),
+            (
+                "layer_1.weight.npy".into(),
+                npy::encode(&[2, 1], &[7.0, 8.0]),
+            ),
+            ("layer_1.bias.npy".into(), npy::encode(&[1], &[9.0])),
+            (
+                "layer_0.weight.npy".into(),
+                npy::encode(&[2, 2], &[1.0, 2.0, 3.0, 4.0]),
+            ),
+        ];
+        let archive = zip::write(&files).unwrap();
+
+        // Act
+        let network = Input::new(2)
+            .chain(Dense::new(2, Linear::new()))
+            .chain(Dense::new(1, Linear::new()))
+            .with_npz(&archive)
+            .unwrap();
+
+        // Assert
+        assert!(network
+            .iter()
+            .eq([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]));
+    }
+
+    #[test]
+    fn test_with_npz_failure() {
+        // Arrange
+        let archive = Input::new(2)
+            .chain(Dense::new(2, Linear::new()))
+            .with_seed(42)
+            .to_npz()
+            .unwrap();
+
+        // Act
+        let mismatched = Input::new(1)
+            .chain(Dense::new(3, Linear::new()))
+            .with_npz(&archive);
+        let malformed = Input::new(2)
+            .chain(Dense::new(2, Linear::new()))
+            .with_npz(&archive[1..]);
+
+        // Assert
+        assert_eq!(mismatched, Err(Error::InvalidArchive));
+        assert_eq!(malformed, Err(Error::InvalidArchive));
+    }
+
+    #[test]
+    fn test_safetensors_round_trip() {
+        // Arrange
+        let network = Input::new(3)
+            .chain(Dense::new(4, Sigmoid::new()))
+            .chain(Dropout::new(0.5))
+            .chain(Dense::new(2, Linear::new()))
+            .with_seed(42);
+
+        // Act
+        let bytes = network.to_safetensors();
+        let parsed = Input::new(3)
+            .chain(Dense::new(4, Sigmoid::new()))
+            .chain(Dropout::new(0.5))
+            .chain(Dense::new(2, Linear::new()))
+            .with_safetensors(&bytes)
+            .unwrap();
+
+        // Assert
+        assert!(parsed.iter().eq(network.iter()));
+    }
+
+    #[test]
+    fn test_with_safetensors_failure() {
+        // Arrange
+        let bytes = Input::new(2)
+            .chain(Dense::new(2, Linear::new()))
+            .with_seed(42)
+            .to_safetensors();
+
+        // Act
+        let mismatched = Input::new(1)
+            .chain(Dense::new(3, Linear::new()))
+            .with_safetensors(&bytes);
+        let malformed = Input::new(2)
+            .chain(Dense::new(2, Linear::new()))
+            .with_safetensors(&bytes[..bytes.len() - 1]);
+
+        // Assert
+        assert_eq!(mismatched, Err(Error::InvalidArchive));
+        assert_eq!(malformed, Err(Error::InvalidArchive));
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        // Arrange
+        let network = Input::new(3)
+            .chain(Dense::new(4, Sigmoid::new()))
+            .chain(Dropout::new(0.5))
+            .chain(Dense::new(2, Linear::new()))
+            .with_seed(42);
+
+        // Act
+        let parsed = Input::new(3)
+            .chain(Dense::new(4, Sigmoid::new()))
+            .chain(Dropout::new(0.5))
+            .chain(Dense::new(2, Linear::new()))
+            .with_bytes(&network.to_bytes())
+            .unwrap();
+
+        // Assert
+        assert!(parsed.iter().eq(network.iter()));
+    }
+
+    #[test]
+    fn test_with_bytes_failure() {
+        // Arrange
+        let bytes = Input::new(2)
+            .chain(Dense::new(2, Linear::new()))
+            .with_seed(42)
+            .to_bytes();
+
+        // Act
+        let mismatched = Input::new(1)
+            .chain(Dense::new(3, Linear::new()))
+            .with_bytes(&bytes);
+
+        // Assert
+        assert_eq!(mismatched, Err(Error::InvalidArchive));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_save_and_load() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(2, Sigmoid::new()))
+            .with_seed(42);
+        let path = std::env::temp_dir().join("eidetic-test-save-and-load.bin");
+
+        // Act
+        network.save(&path).unwrap();
+        let loaded = Input::new(2)
+            .chain(Dense::new(2, Sigmoid::new()))
+            .load(&path);
+        std::fs::remove_file(&path).unwrap();
+        let missing = Input::new(2)
+            .chain(Dense::new(2, Sigmoid::new()))
+            .load(&path);
+
+        // Assert
+        assert_eq!(loaded, Ok(network));
+        assert_eq!(missing, Err(Error::FileAccess));
+    }
+
+    #[test]
+    fn test_loss_landscape() {
+        // Arrange
+        let network = Input::new(1)
+            .chain(Dense::new(1, Linear::new()))
+            .with_iter([2.0, 1.0].into_iter())
+            .unwrap();
+        let input = Tensor::<rank::Two>::new((3, 1), [1.0, 2.0, 3.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((3, 1), [3.1, 4.9, 7.0]).unwrap();
+        let loss_function = MeanSquaredError::new();
+        let predictions = network.predict(input.clone()).unwrap();
+        let (expected, _) = loss_function.loss(&predictions, &targets).unwrap();
+
+        // Act
+        let output = network
+            .loss_landscape(&loss_function, &input, &targets, &[0.0, 0.5, 1.0, 2.0], 42)
+            .unwrap();
+
+        // Assert
+        assert_eq!(output.len(), 4);
+        assert_eq!(output[0], (0.0, expected));
+        assert!(output.windows(2).all(|pair| pair[0].1 < pair[1].1));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        // Arrange
+        fn from_json<T: serde::de::DeserializeOwned>(_like: &T, json: &str) -> T {
+            serde_json::from_str(json).unwrap()
+        }
+        let network = Input::new(2)
+            .chain(Dense::new(3, ReLU::new()))
+            .chain(Dropout::new(0.8))
+            .chain(Dense::new(1, Sigmoid::new()))
+            .with_seed(42);
+        let input = Tensor::<rank::Two>::new((2, 2), [1.0, 2.0, 3.0, 4.0]).unwrap();
+        let expected = network.predict(input.clone()).unwrap();
+
+        // Act
+        let json = serde_json::to_string(&network).unwrap();
+        let output = from_json(&network, &json);
+
+        // Assert
+        assert_eq!(output, network);
+        assert_eq!(output.predict(input).unwrap(), expected);
+    }
 }
@@ -0,0 +1,357 @@
+use crate::operations::{trainable, InitialisedOperation, WithOptimiser};
+use crate::private::Sealed;
+use crate::summary::{LayerOutput, LayerSummary};
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+use ndarray::{Array, Ix2, Ix3};
+use rand::rngs::StdRng;
+
+/// Describes how the kernel of a 1D convolution slides along its input. This is shared by each
+/// of the typestates of the convolution so that they unroll and roll up sequences in the same way.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Window {
+    pub(crate) channels: u16,
+    pub(crate) kernel_size: usize,
+    pub(crate) stride: usize,
+    pub(crate) padding: usize,
+    pub(crate) dilation: usize,
+}
+
+impl Window {
+    /// Calculates the number of steps in the output produced for an input of the given shape.
+    const fn output_size(&self, (_, steps, channels): (usize, usize, usize)) -> Result<usize> {
+        let (kernel_size, stride, dilation) = (self.kernel_size, self.stride, self.dilation);
+        let steps = steps + 2 * self.padding;
+        if channels != self.channels as usize {
+            Err(Error::ShapeMismatch {
+                expected: self.channels as usize,
+                actual: channels,
+            })
+        } else if kernel_size == 0 || stride == 0 || dilation == 0 {
+            Err(Error::InvalidArgument)
+        } else if steps < dilation * (kernel_size - 1) + 1 {
+            // the (padded) sequence is too short for the dilated kernel to fit inside.
+            Err(Error::ShapeMismatch {
+                expected: dilation * (kernel_size - 1) + 1,
+                actual: steps,
+            })
+        } else {
+            Ok((steps - dilation * (kernel_size - 1) - 1) / stride + 1)
+        }
+    }
+
+    /// Gets the step of the unpadded input that the given position of the kernel is applied to
+    /// for the given output step, or `None` if it falls within the padding.
+    fn input_step(&self, output_step: usize, position: usize, steps: usize) -> Option<usize> {
+        (output_step * self.stride + position * self.dilation)
+            .checked_sub(self.padding)
+            .filter(|step| *step < steps)
+    }
+
+    /// Unrolls each patch of the (zero padded) input that the kernel visits into a row of
+    /// a matrix, so that the convolution can be calculated as a single matrix multiplication.
+    /// Each row is ordered by kernel position, then channel.
+    pub(crate) fn columns(
+        &self,
+        input: &Array<ElementType, Ix3>,
+    ) -> Result<Array<ElementType, Ix2>> {
+        let (batch, steps, channels) = input.dim();
+        let output_steps = self.output_size(input.dim())?;
+        let mut columns = Array::zeros((batch * output_steps, self.kernel_size * channels));
+        for (index, mut row) in columns.rows_mut().into_iter().enumerate() {
+            let (sample, output_step) = (index / output_steps, index % output_steps);
+            for position in 0..self.kernel_size {
+                if let Some(step) = self.input_step(output_step, position, steps) {
+                    for channel in 0..channels {
+                        row[position * channels + channel] = input[(sample, step, channel)];
+                    }
+                }
+            }
+        }
+        Ok(columns)
+    }
+
+    /// Rolls the gradient of the unrolled patches back up into a sequence of the given shape by
+    /// summing the contribution of every patch that each element of the input appeared in.
+    pub(crate) fn sequence(
+        &self,
+        columns: &Array<ElementType, Ix2>,
+        (batch, steps, channels): (usize, usize, usize),
+    ) -> Result<Array<ElementType, Ix3>> {
+        let output_steps = self.output_size((batch, steps, channels))?;
+        let expected_dim = (batch * output_steps, self.kernel_size * channels);
+        Error::check_shape(expected_dim, columns.dim())?;
+        let mut sequence = Array::zeros((batch, steps, channels));
+        for (index, row) in columns.rows().into_iter().enumerate() {
+            let (sample, output_step) = (index / output_steps, index % output_steps);
+            for position in 0..self.kernel_size {
+                if let Some(step) = self.input_step(output_step, position, steps) {
+                    for channel in 0..channels {
+                        sequence[(sample, step, channel)] += row[position * channels + channel];
+                    }
+                }
+            }
+        }
+        Ok(sequence)
+    }
+
+    /// Reshapes the output of multiplying the unrolled patches with the kernels, which has a row
+    /// per patch and a column per filter, into a sequence of shape (batch, steps, filters).
+    pub(crate) fn features(
+        &self,
+        output: Array<ElementType, Ix2>,
+        input_shape: (usize, usize, usize),
+    ) -> Result<Array<ElementType, Ix3>> {
+        let output_steps = self.output_size(input_shape)?;
+        let shape = (input_shape.0, output_steps, output.ncols());
+        let (expected, actual) = (input_shape.0 * output_steps, output.nrows());
+        output
+            .into_shape(shape)
+            .map_err(|_| Error::BatchRowMismatch { expected, actual })
+    }
+
+    /// Reshapes a gradient with respect to the output sequence back into a row per patch and a
+    /// column per filter, the inverse of `features`.
+    pub(crate) fn feature_rows(
+        &self,
+        gradient: &Array<ElementType, Ix3>,
+        input_shape: (usize, usize, usize),
+    ) -> Result<Array<ElementType, Ix2>> {
+        let output_steps = self.output_size(input_shape)?;
+        let (batch, steps, filters) = gradient.dim();
+        if batch != input_shape.0 {
+            return Err(Error::BatchRowMismatch {
+                expected: input_shape.0,
+                actual: batch,
+            });
+        }
+        Error::check_size(output_steps, steps)?;
+        let gradient = gradient.as_standard_layout().into_owned();
+        let count = gradient.len();
+        gradient
+            .into_shape((batch * steps, filters))
+            .map_err(|_| Error::ShapeMismatch {
+                expected: batch * steps * filters,
+                actual: count,
+            })
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation<T> {
+    pub(crate) window: Window,
+    pub(crate) dense: T,
+}
+
+impl<T> Sealed for Operation<T> {}
+impl<T: InitialisedOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>>
+    InitialisedOperation for Operation<T>
+{
+    type Input = Tensor<rank::Three>;
+    type Output = Tensor<rank::Three>;
+    type ParameterIter = T::ParameterIter;
+
+    fn iter(&self) -> Self::ParameterIter {
+        self.dense.iter()
+    }
+
+    fn predict(&self, input: Self::Input) -> Result<Self::Output> {
+        let columns = self.window.columns(&input.0)?;
+        let output = self.dense.predict(Tensor(columns))?;
+        let output = self.window.features(output.0, input.0.dim())?;
+        Ok(Tensor(output))
+    }
+
+    fn predict_stochastic(
+        &self,
+        input: Self::Input,
+        generator: &mut StdRng,
+    ) -> Result<Self::Output> {
+        let columns = self.window.columns(&input.0)?;
+        let output = self.dense.predict_stochastic(Tensor(columns), generator)?;
+        let output = self.window.features(output.0, input.0.dim())?;
+        Ok(Tensor(output))
+    }
+
+    fn map_parameters(&mut self, f: &mut impl FnMut(ElementType) -> ElementType) {
+        self.dense.map_parameters(f);
+    }
+
+    fn push_layer_parameters(&self, layers: &mut Vec<((usize, usize), Vec<ElementType>)>) {
+        self.dense.push_layer_parameters(layers);
+    }
+
+    fn push_layer_summaries(
+        &self,
+        input_shape: Vec<Option<usize>>,
+        summaries: &mut Vec<LayerSummary>,
+    ) -> Vec<Option<usize>> {
+        let Window {
+            channels,
+            kernel_size,
+            ..
+        } = self.window;
+        let steps = match *input_shape.as_slice() {
+            [_, Some(steps), _] => self.window.output_size((1, steps, channels as usize)).ok(),
+            _ => None,
+        };
+        // the filters are the output neurons of the dense layer applied to each unrolled patch.
+        let patch_shape = [None, Some(channels as usize * kernel_size)].to_vec();
+        let filters = self
+            .dense
+            .push_layer_summaries(patch_shape, &mut Vec::new())
+            .pop()
+            .flatten();
+        let output_shape = [None, steps, filters].to_vec();
+        summaries.push(LayerSummary::new(
+            "Conv1D",
+            output_shape.clone(),
+            self.iter().count(),
+        ));
+        output_shape
+    }
+
+    fn predict_with_layer_outputs(
+        &self,
+        input: Self::Input,
+        outputs: &mut Vec<LayerOutput>,
+    ) -> Result<Self::Output> {
+        let output = self.predict(input)?;
+        outputs.push(LayerOutput::new("Conv1D", &output));
+        Ok(output)
+    }
+}
+
+impl<T: WithOptimiser<U>, U> WithOptimiser<U> for Operation<T> {
+    type Trainable = trainable::conv1d::Operation<T::Trainable>;
+
+    fn with_optimiser(self, optimiser: U) -> Self::Trainable {
+        Self::Trainable {
+            window: self.window,
+            dense: self.dense.with_optimiser(optimiser),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::Linear;
+    use crate::layers::{Chain, Conv1D, SequenceInput};
+    use crate::operations::UninitialisedOperation;
+
+    #[test]
+    fn test_columns_and_sequence() {
+        // Arrange
+        let window = Window {
+            channels: 1,
+            kernel_size: 2,
+            stride: 1,
+            padding: 0,
+            dilation: 1,
+        };
+        let input = Array::from_shape_vec((1, 3, 1), [1.0, 2.0, 3.0].to_vec()).unwrap();
+        let expected_columns =
+            Array::from_shape_vec((2, 2), [1.0, 2.0, 2.0, 3.0].to_vec()).unwrap();
+        let expected_sequence = Array::from_shape_vec((1, 3, 1), [1.0, 2.0, 1.0].to_vec()).unwrap();
+
+        // Act
+        let columns = window.columns(&input).unwrap();
+        let sequence = window.sequence(&Array::ones((2, 2)), input.dim()).unwrap();
+
+        // Assert
+        assert_eq!(columns, expected_columns);
+        assert_eq!(sequence, expected_sequence);
+    }
+
+    #[test]
+    fn test_columns_with_padding_and_dilation() {
+        // Arrange
+        let window = Window {
+            channels: 1,
+            kernel_size: 2,
+            stride: 1,
+            padding: 1,
+            dilation: 2,
+        };
+        let input = Array::from_shape_vec((1, 3, 1), [1.0, 2.0, 3.0].to_vec()).unwrap();
+        let expected =
+            Array::from_shape_vec((3, 2), [0.0, 2.0, 1.0, 3.0, 2.0, 0.0].to_vec()).unwrap();
+
+        // Act
+        let columns = window.columns(&input).unwrap();
+
+        // Assert
+        assert_eq!(columns, expected);
+    }
+
+    #[test]
+    fn test_predict_with_stride_and_padding() {
+        // Arrange
+        let network = SequenceInput::new(1)
+            .chain(Conv1D::new(1, 3, 2, 1, Linear::new()))
+            .with_iter([1.0, 1.0, 1.0, 0.5].into_iter())
+            .unwrap();
+        let input = Tensor::<rank::Three>::new((1, 4, 1), [1.0, 2.0, 3.0, 4.0]).unwrap();
+        let expected = Tensor::<rank::Three>::new((1, 2, 1), [3.5, 9.5]).unwrap();
+
+        // Act
+        let output = network.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_predict_multiple_channels_and_filters() {
+        // Arrange
+        // kernels are stored with a row per kernel position/channel and a column per filter.
+        let network = SequenceInput::new(2)
+            .chain(Conv1D::new(2, 1, 1, 0, Linear::new()))
+            .with_iter([1.0, 0.0, 1.0, 2.0, 0.0, 1.0].into_iter())
+            .unwrap();
+        let input = Tensor::<rank::Three>::new((1, 2, 2), [1.0, 2.0, 3.0, 4.0]).unwrap();
+        let expected = Tensor::<rank::Three>::new((1, 2, 2), [3.0, 5.0, 7.0, 9.0]).unwrap();
+
+        // Act
+        let output = network.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_predict_failure() {
+        // Arrange
+        let network = SequenceInput::new(1)
+            .chain(Conv1D::new(1, 3, 1, 0, Linear::new()))
+            .with_seed(42);
+        let input = Tensor::<rank::Three>::new((1, 2, 1), [1.0, 2.0]).unwrap();
+
+        // Act
+        let result = network.predict(input);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_summary() {
+        // Arrange
+        let network = SequenceInput::new(2)
+            .chain(Conv1D::new(3, 2, 1, 0, Linear::new()))
+            .with_seed(42);
+
+        // Act
+        let summary = network.summary();
+
+        // Assert
+        let conv = &summary.layers()[1];
+        assert_eq!(conv.name(), "Conv1D");
+        assert_eq!(conv.output_shape(), [None, None, Some(3)]);
+        assert_eq!(conv.parameters(), 15);
+    }
+}
@@ -0,0 +1,393 @@
+use crate::operations::{trainable, InitialisedOperation, WithOptimiser};
+use crate::private::Sealed;
+use crate::summary::{LayerOutput, LayerSummary};
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+use ndarray::{s, Array, Ix2, Ix4};
+use rand::rngs::StdRng;
+
+/// Describes how the kernel of a convolution slides over its input. This is shared by each
+/// of the typestates of the convolution so that they unroll and roll up images in the same way.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", allow(clippy::unsafe_derive_deserialize))] // unsafe is only within the slicing macro
+pub struct Window {
+    pub(crate) channels: u16,
+    pub(crate) kernel_size: usize,
+    pub(crate) stride: usize,
+    pub(crate) padding: usize,
+}
+
+impl Window {
+    /// Calculates the height and width of the feature maps produced for an input of the given shape.
+    pub(crate) const fn output_size(
+        &self,
+        (_, channels, height, width): (usize, usize, usize, usize),
+    ) -> Result<(usize, usize)> {
+        let (kernel_size, stride, padding) = (self.kernel_size, self.stride, self.padding);
+        let (height, width) = (height + 2 * padding, width + 2 * padding);
+        let smallest = if height < width { height } else { width };
+        if channels != self.channels as usize {
+            Err(Error::ShapeMismatch {
+                expected: self.channels as usize,
+                actual: channels,
+            })
+        } else if kernel_size == 0 || stride == 0 {
+            Err(Error::InvalidArgument)
+        } else if smallest < kernel_size {
+            // the (padded) image is too small for the kernel to fit inside.
+            Err(Error::ShapeMismatch {
+                expected: kernel_size,
+                actual: smallest,
+            })
+        } else {
+            let output_height = (height - kernel_size) / stride + 1;
+            let output_width = (width - kernel_size) / stride + 1;
+            Ok((output_height, output_width))
+        }
+    }
+
+    /// Calculates the shape of the image produced by a transposed convolution of an input of the
+    /// given shape, which is the smallest image that this window would convolve back into it.
+    pub(crate) const fn transposed_shape(
+        &self,
+        (batch, _, height, width): (usize, usize, usize, usize),
+    ) -> Result<(usize, usize, usize, usize)> {
+        let (kernel_size, stride, padding) = (self.kernel_size, self.stride, self.padding);
+        let smallest = if height < width { height } else { width };
+        if kernel_size == 0 || stride == 0 || smallest == 0 {
+            Err(Error::InvalidArgument)
+        } else if (smallest - 1) * stride + kernel_size <= 2 * padding {
+            // the padding would remove the whole of the upsampled image.
+            Err(Error::ShapeMismatch {
+                expected: 2 * padding + 1,
+                actual: (smallest - 1) * stride + kernel_size,
+            })
+        } else {
+            let output_height = (height - 1) * stride + kernel_size - 2 * padding;
+            let output_width = (width - 1) * stride + kernel_size - 2 * padding;
+            Ok((batch, self.channels as usize, output_height, output_width))
+        }
+    }
+
+    /// Gets a window with the same number of channels which visits each pixel on its own, so that
+    /// an operation on rows can be applied to each pixel of an image.
+    pub(crate) const fn pointwise(self) -> Self {
+        Self {
+            kernel_size: 1,
+            stride: 1,
+            padding: 0,
+            ..self
+        }
+    }
+
+    /// Unrolls each patch of the (zero padded) input that the kernel visits into a row of
+    /// a matrix, so that the convolution can be calculated as a single matrix multiplication.
+    /// Each row is ordered by channel, then kernel row, then kernel column.
+    pub(crate) fn columns(
+        &self,
+        input: &Array<ElementType, Ix4>,
+    ) -> Result<Array<ElementType, Ix2>> {
+        let (batch, channels, height, width) = input.dim();
+        let (output_height, output_width) = self.output_size(input.dim())?;
+        let (kernel_size, stride, padding) = (self.kernel_size, self.stride, self.padding);
+        let padded_shape = (batch, channels, height + 2 * padding, width + 2 * padding);
+        let mut padded = Array::zeros(padded_shape);
+        padded
+            .slice_mut(s![
+                ..,
+                ..,
+                padding..padding + height,
+                padding..padding + width
+            ])
+            .assign(input);
+        let patch_count = batch * output_height * output_width;
+        let mut columns = Array::zeros((patch_count, channels * kernel_size * kernel_size));
+        for (index, mut row) in columns.rows_mut().into_iter().enumerate() {
+            let sample = index / (output_height * output_width);
+            let y = (index / output_width) % output_height * stride;
+            let x = index % output_width * stride;
+            let patch = padded.slice(s![sample, .., y..y + kernel_size, x..x + kernel_size]);
+            for (elem, value) in row.iter_mut().zip(patch.iter()) {
+                *elem = *value;
+            }
+        }
+        Ok(columns)
+    }
+
+    /// Rolls the gradient of the unrolled patches back up into an image of the given shape by
+    /// summing the contribution of every patch that each element of the input appeared in.
+    pub(crate) fn image(
+        &self,
+        columns: &Array<ElementType, Ix2>,
+        (batch, channels, height, width): (usize, usize, usize, usize),
+    ) -> Result<Array<ElementType, Ix4>> {
+        let (output_height, output_width) = self.output_size((batch, channels, height, width))?;
+        let (kernel_size, stride, padding) = (self.kernel_size, self.stride, self.padding);
+        let expected_dim = (
+            batch * output_height * output_width,
+            channels * kernel_size * kernel_size,
+        );
+        Error::check_shape(expected_dim, columns.dim())?;
+        let padded_shape = (batch, channels, height + 2 * padding, width + 2 * padding);
+        let mut padded = Array::zeros(padded_shape);
+        for (index, row) in columns.rows().into_iter().enumerate() {
+            let sample = index / (output_height * output_width);
+            let y = (index / output_width) % output_height * stride;
+            let x = index % output_width * stride;
+            let mut patch =
+                padded.slice_mut(s![sample, .., y..y + kernel_size, x..x + kernel_size]);
+            for (elem, value) in patch.iter_mut().zip(row.iter()) {
+                *elem += *value;
+            }
+        }
+        let image = padded.slice(s![
+            ..,
+            ..,
+            padding..padding + height,
+            padding..padding + width
+        ]);
+        Ok(image.to_owned())
+    }
+
+    /// Reshapes the output of multiplying the unrolled patches with the kernels, which has a row
+    /// per patch and a column per filter, into feature maps of shape (batch, filters, height, width).
+    pub(crate) fn feature_maps(
+        &self,
+        output: Array<ElementType, Ix2>,
+        input_shape: (usize, usize, usize, usize),
+    ) -> Result<Array<ElementType, Ix4>> {
+        let (output_height, output_width) = self.output_size(input_shape)?;
+        let shape = (input_shape.0, output_height, output_width, output.ncols());
+        let (expected, actual) = (input_shape.0 * output_height * output_width, output.nrows());
+        let output = output
+            .into_shape(shape)
+            .map_err(|_| Error::BatchRowMismatch { expected, actual })?;
+        Ok(output
+            .permuted_axes([0, 3, 1, 2])
+            .as_standard_layout()
+            .into_owned())
+    }
+
+    /// Reshapes a gradient with respect to the feature maps back into a row per patch and a
+    /// column per filter, the inverse of `feature_maps`.
+    pub(crate) fn feature_rows(
+        &self,
+        gradient: &Array<ElementType, Ix4>,
+        input_shape: (usize, usize, usize, usize),
+    ) -> Result<Array<ElementType, Ix2>> {
+        let (output_height, output_width) = self.output_size(input_shape)?;
+        let (batch, filters, height, width) = gradient.dim();
+        if batch != input_shape.0 {
+            return Err(Error::BatchRowMismatch {
+                expected: input_shape.0,
+                actual: batch,
+            });
+        }
+        Error::check_size(output_height, height)?;
+        Error::check_size(output_width, width)?;
+        let gradient = gradient.view().permuted_axes([0, 2, 3, 1]);
+        let gradient = gradient.as_standard_layout().into_owned();
+        let count = gradient.len();
+        gradient
+            .into_shape((batch * height * width, filters))
+            .map_err(|_| Error::ShapeMismatch {
+                expected: batch * height * width * filters,
+                actual: count,
+            })
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation<T> {
+    pub(crate) window: Window,
+    pub(crate) dense: T,
+}
+
+impl<T> Sealed for Operation<T> {}
+impl<T: InitialisedOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>>
+    InitialisedOperation for Operation<T>
+{
+    type Input = Tensor<rank::Four>;
+    type Output = Tensor<rank::Four>;
+    type ParameterIter = T::ParameterIter;
+
+    fn iter(&self) -> Self::ParameterIter {
+        self.dense.iter()
+    }
+
+    fn predict(&self, input: Self::Input) -> Result<Self::Output> {
+        let columns = self.window.columns(&input.0)?;
+        let output = self.dense.predict(Tensor(columns))?;
+        let output = self.window.feature_maps(output.0, input.0.dim())?;
+        Ok(Tensor(output))
+    }
+
+    fn predict_stochastic(
+        &self,
+        input: Self::Input,
+        generator: &mut StdRng,
+    ) -> Result<Self::Output> {
+        let columns = self.window.columns(&input.0)?;
+        let output = self.dense.predict_stochastic(Tensor(columns), generator)?;
+        let output = self.window.feature_maps(output.0, input.0.dim())?;
+        Ok(Tensor(output))
+    }
+
+    fn map_parameters(&mut self, f: &mut impl FnMut(ElementType) -> ElementType) {
+        self.dense.map_parameters(f);
+    }
+
+    fn push_layer_parameters(&self, layers: &mut Vec<((usize, usize), Vec<ElementType>)>) {
+        self.dense.push_layer_parameters(layers);
+    }
+
+    fn push_layer_summaries(
+        &self,
+        input_shape: Vec<Option<usize>>,
+        summaries: &mut Vec<LayerSummary>,
+    ) -> Vec<Option<usize>> {
+        let Window {
+            channels,
+            kernel_size,
+            ..
+        } = self.window;
+        let (height, width) = match *input_shape.as_slice() {
+            [_, _, Some(height), Some(width)] => self
+                .window
+                .output_size((1, channels as usize, height, width))
+                .map_or((None, None), |(height, width)| (Some(height), Some(width))),
+            _ => (None, None),
+        };
+        // the filters are the output neurons of the dense layer applied to each unrolled patch.
+        let patch_shape = [None, Some(channels as usize * kernel_size * kernel_size)].to_vec();
+        let filters = self
+            .dense
+            .push_layer_summaries(patch_shape, &mut Vec::new())
+            .pop()
+            .flatten();
+        let output_shape = [None, filters, height, width].to_vec();
+        summaries.push(LayerSummary::new(
+            "Conv2D",
+            output_shape.clone(),
+            self.iter().count(),
+        ));
+        output_shape
+    }
+
+    fn predict_with_layer_outputs(
+        &self,
+        input: Self::Input,
+        outputs: &mut Vec<LayerOutput>,
+    ) -> Result<Self::Output> {
+        let output = self.predict(input)?;
+        outputs.push(LayerOutput::new("Conv2D", &output));
+        Ok(output)
+    }
+}
+
+impl<T: WithOptimiser<U>, U> WithOptimiser<U> for Operation<T> {
+    type Trainable = trainable::conv2d::Operation<T::Trainable>;
+
+    fn with_optimiser(self, optimiser: U) -> Self::Trainable {
+        Self::Trainable {
+            window: self.window,
+            dense: self.dense.with_optimiser(optimiser),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::Linear;
+    use crate::layers::{Chain, Conv2D, ImageInput};
+    use crate::operations::UninitialisedOperation;
+
+    #[test]
+    fn test_columns_and_image() {
+        // Arrange
+        let window = Window {
+            channels: 1,
+            kernel_size: 2,
+            stride: 1,
+            padding: 0,
+        };
+        let input = Array::from_shape_vec((1, 1, 3, 3), (1u8..=9).map(ElementType::from).collect())
+            .unwrap();
+        let expected_columns = Array::from_shape_vec(
+            (4, 4),
+            [
+                1.0, 2.0, 4.0, 5.0, 2.0, 3.0, 5.0, 6.0, 4.0, 5.0, 7.0, 8.0, 5.0, 6.0, 8.0, 9.0,
+            ]
+            .to_vec(),
+        )
+        .unwrap();
+        let expected_image = Array::from_shape_vec(
+            (1, 1, 3, 3),
+            [1.0, 2.0, 1.0, 2.0, 4.0, 2.0, 1.0, 2.0, 1.0].to_vec(),
+        )
+        .unwrap();
+
+        // Act
+        let columns = window.columns(&input).unwrap();
+        let image = window.image(&Array::ones((4, 4)), input.dim()).unwrap();
+
+        // Assert
+        assert_eq!(columns, expected_columns);
+        assert_eq!(image, expected_image);
+    }
+
+    #[test]
+    fn test_predict_with_stride_and_padding() {
+        // Arrange
+        let network = ImageInput::new(1)
+            .chain(Conv2D::new(1, 3, 2, 1, Linear::new()))
+            .with_iter([1.0; 9].into_iter().chain([0.5]))
+            .unwrap();
+        let input =
+            Tensor::<rank::Four>::new((1, 1, 3, 3), (1u8..=9).map(ElementType::from)).unwrap();
+        let expected = Tensor::<rank::Four>::new((1, 1, 2, 2), [12.5, 16.5, 24.5, 28.5]).unwrap();
+
+        // Act
+        let output = network.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_predict_multiple_channels_and_filters() {
+        // Arrange
+        // kernels are stored with a row per channel/kernel position and a column per filter.
+        let network = ImageInput::new(2)
+            .chain(Conv2D::new(2, 1, 1, 0, Linear::new()))
+            .with_iter([1.0, 0.0, 1.0, 2.0, 0.0, 1.0].into_iter())
+            .unwrap();
+        let input = Tensor::<rank::Four>::new((1, 2, 1, 2), [1.0, 2.0, 3.0, 4.0]).unwrap();
+        let expected = Tensor::<rank::Four>::new((1, 2, 1, 2), [4.0, 6.0, 7.0, 9.0]).unwrap();
+
+        // Act
+        let output = network.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_predict_failure() {
+        // Arrange
+        let network = ImageInput::new(1)
+            .chain(Conv2D::new(1, 3, 1, 0, Linear::new()))
+            .with_seed(42);
+        let input = Tensor::<rank::Four>::new((1, 1, 2, 2), [1.0, 2.0, 3.0, 4.0]).unwrap();
+
+        // Act
+        let result = network.predict(input);
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
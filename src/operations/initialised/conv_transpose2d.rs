@@ -0,0 +1,257 @@
+use crate::operations::initialised::conv2d::Window;
+use crate::operations::{initialised, trainable, InitialisedOperation, WithOptimiser};
+use crate::optimisers::base::OptimiserFactory;
+use crate::private::Sealed;
+use crate::summary::{LayerOutput, LayerSummary};
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Result};
+use alloc::vec::Vec;
+use core::iter::Chain;
+use ndarray::{Array, Ix4};
+use rand::rngs::StdRng;
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation<T> {
+    pub(crate) window: Window,
+    pub(crate) weight_multiply: initialised::weight_multiply::Operation,
+    pub(crate) bias_add: initialised::bias_add::Operation,
+    pub(crate) activation_function: T,
+}
+
+impl<T: InitialisedOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>> Operation<T> {
+    /// Spreads each pixel of the input across the kernels, producing the image that the biases
+    /// and activation function are applied to.
+    fn spread(&self, input: &Array<ElementType, Ix4>) -> Result<Array<ElementType, Ix4>> {
+        let output_shape = self.window.transposed_shape(input.dim())?;
+        let pixels = self.window.feature_rows(input, output_shape)?;
+        let patches = self.weight_multiply.predict(Tensor(pixels))?;
+        self.window.image(&patches.0, output_shape)
+    }
+}
+
+impl<T> Sealed for Operation<T> {}
+impl<T: InitialisedOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>>
+    InitialisedOperation for Operation<T>
+{
+    type Input = Tensor<rank::Four>;
+    type Output = Tensor<rank::Four>;
+    type ParameterIter = Chain<
+        Chain<
+            <initialised::weight_multiply::Operation as InitialisedOperation>::ParameterIter,
+            <initialised::bias_add::Operation as InitialisedOperation>::ParameterIter,
+        >,
+        <T as InitialisedOperation>::ParameterIter,
+    >;
+
+    fn iter(&self) -> Self::ParameterIter {
+        let weight_multiply = self.weight_multiply.iter();
+        let bias_add = self.bias_add.iter();
+        let activation_function = self.activation_function.iter();
+        weight_multiply.chain(bias_add).chain(activation_function)
+    }
+
+    fn predict(&self, input: Self::Input) -> Result<Self::Output> {
+        let image = self.spread(&input.0)?;
+        let (pointwise, shape) = (self.window.pointwise(), image.dim());
+        let pixels = pointwise.feature_rows(&image, shape)?;
+        let pixels = self.bias_add.predict(Tensor(pixels))?;
+        let pixels = self.activation_function.predict(pixels)?;
+        Ok(Tensor(pointwise.feature_maps(pixels.0, shape)?))
+    }
+
+    fn predict_stochastic(
+        &self,
+        input: Self::Input,
+        generator: &mut StdRng,
+    ) -> Result<Self::Output> {
+        let image = self.spread(&input.0)?;
+        let (pointwise, shape) = (self.window.pointwise(), image.dim());
+        let pixels = pointwise.feature_rows(&image, shape)?;
+        let pixels = self.bias_add.predict(Tensor(pixels))?;
+        let pixels = self
+            .activation_function
+            .predict_stochastic(pixels, generator)?;
+        Ok(Tensor(pointwise.feature_maps(pixels.0, shape)?))
+    }
+
+    fn map_parameters(&mut self, f: &mut impl FnMut(ElementType) -> ElementType) {
+        self.weight_multiply.map_parameters(f);
+        self.bias_add.map_parameters(f);
+        self.activation_function.map_parameters(f);
+    }
+
+    fn push_layer_parameters(&self, layers: &mut Vec<((usize, usize), Vec<ElementType>)>) {
+        let shape = self.weight_multiply.parameter.0.dim();
+        layers.push((shape, self.iter().collect()));
+    }
+
+    fn push_layer_summaries(
+        &self,
+        input_shape: Vec<Option<usize>>,
+        summaries: &mut Vec<LayerSummary>,
+    ) -> Vec<Option<usize>> {
+        let (height, width) = match *input_shape.as_slice() {
+            [_, _, Some(height), Some(width)] => self
+                .window
+                .transposed_shape((1, 1, height, width))
+                .map_or((None, None), |(_, _, height, width)| {
+                    (Some(height), Some(width))
+                }),
+            _ => (None, None),
+        };
+        let output_shape = [None, Some(self.window.channels as usize), height, width].to_vec();
+        summaries.push(LayerSummary::new(
+            "ConvTranspose2D",
+            output_shape.clone(),
+            self.iter().count(),
+        ));
+        output_shape
+    }
+
+    fn predict_with_layer_outputs(
+        &self,
+        input: Self::Input,
+        outputs: &mut Vec<LayerOutput>,
+    ) -> Result<Self::Output> {
+        let output = self.predict(input)?;
+        outputs.push(LayerOutput::new("ConvTranspose2D", &output));
+        Ok(output)
+    }
+}
+
+impl<T, U: Clone + OptimiserFactory<Tensor<rank::Two>>> WithOptimiser<U> for Operation<T>
+where
+    initialised::bias_add::Operation: WithOptimiser<U>,
+    T: WithOptimiser<U>,
+{
+    type Trainable = trainable::conv_transpose2d::Operation<
+        <initialised::weight_multiply::Operation as WithOptimiser<U>>::Trainable,
+        <initialised::bias_add::Operation as WithOptimiser<U>>::Trainable,
+        <T as WithOptimiser<U>>::Trainable,
+    >;
+
+    fn with_optimiser(self, factory: U) -> Self::Trainable {
+        let weight_multiply = self.weight_multiply.with_optimiser(factory.clone());
+        let bias_add = self.bias_add.with_optimiser(factory.clone());
+        let activation_function = self.activation_function.with_optimiser(factory);
+        Self::Trainable {
+            window: self.window,
+            weight_multiply,
+            bias_add,
+            activation_function,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::{Linear, ReLU};
+    use crate::layers::{Chain, Conv2D, ConvTranspose2D, ImageInput};
+    use crate::operations::UninitialisedOperation;
+
+    #[test]
+    fn test_predict_with_stride() {
+        // Arrange
+        // each pixel is spread across a 2x2 patch, with the patches placed next to each other.
+        let network = ImageInput::new(1)
+            .chain(ConvTranspose2D::new(1, 2, 2, 0, Linear::new()))
+            .with_iter([1.0, 2.0, 3.0, 4.0, 0.5].into_iter())
+            .unwrap();
+        let input = Tensor::<rank::Four>::new((1, 1, 1, 2), [1.0, 2.0]).unwrap();
+        let expected =
+            Tensor::<rank::Four>::new((1, 1, 2, 4), [1.5, 2.5, 2.5, 4.5, 3.5, 4.5, 6.5, 8.5])
+                .unwrap();
+
+        // Act
+        let output = network.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_predict_overlapping_with_padding() {
+        // Arrange
+        // with a stride of 1 the patches overlap and are summed, then a pixel is cropped from each side.
+        let network = ImageInput::new(1)
+            .chain(ConvTranspose2D::new(1, 3, 1, 1, Linear::new()))
+            .with_iter([1.0; 9].into_iter().chain([0.0]))
+            .unwrap();
+        let input = Tensor::<rank::Four>::new((1, 1, 2, 2), [1.0, 2.0, 3.0, 4.0]).unwrap();
+        let expected = Tensor::<rank::Four>::new((1, 1, 2, 2), [10.0; 4]).unwrap();
+
+        // Act
+        let output = network.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_predict_multiple_channels_and_filters() {
+        // Arrange
+        // kernels are stored with a row per input channel and a column per filter/kernel position.
+        let network = ImageInput::new(2)
+            .chain(ConvTranspose2D::new(2, 1, 1, 0, ReLU::new()))
+            .with_iter([1.0, 0.0, 1.0, -1.0, 0.0, 1.0].into_iter())
+            .unwrap();
+        let input = Tensor::<rank::Four>::new((1, 2, 1, 2), [1.0, 2.0, 3.0, 4.0]).unwrap();
+        let expected = Tensor::<rank::Four>::new((1, 2, 1, 2), [4.0, 6.0, 0.0, 0.0]).unwrap();
+
+        // Act
+        let output = network.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_predict_inverts_conv2d_shape() {
+        // Arrange
+        let network = ImageInput::new(1)
+            .chain(Conv2D::new(4, 3, 2, 1, ReLU::new()))
+            .chain(ConvTranspose2D::new(1, 3, 2, 1, Linear::new()))
+            .with_seed(42);
+        let input = Tensor::<rank::Four>::new((2, 1, 5, 5), [1.0; 50]).unwrap();
+
+        // Act
+        let output = network.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output.dim(), (2, 1, 5, 5));
+    }
+
+    #[test]
+    fn test_predict_failure() {
+        // Arrange
+        let network = ImageInput::new(2)
+            .chain(ConvTranspose2D::new(1, 2, 1, 0, Linear::new()))
+            .with_seed(42);
+        let input = Tensor::<rank::Four>::new((1, 1, 2, 2), [1.0; 4]).unwrap();
+
+        // Act
+        let result = network.predict(input);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_summary() {
+        // Arrange
+        let network = ImageInput::new(3)
+            .chain(ConvTranspose2D::new(2, 2, 2, 0, ReLU::new()))
+            .with_seed(42);
+
+        // Act
+        let summary = network.summary();
+
+        // Assert
+        let layer = &summary.layers()[1];
+        assert_eq!(layer.name(), "ConvTranspose2D");
+        assert_eq!(layer.output_shape(), [None, Some(2), None, None]);
+        assert_eq!(layer.parameters(), 26);
+    }
+}
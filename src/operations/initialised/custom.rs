@@ -0,0 +1,178 @@
+use crate::operations::uninitialised::custom::CustomLayer;
+use crate::operations::{trainable, InitialisedOperation, WithOptimiser};
+use crate::optimisers::base::OptimiserFactory;
+use crate::private::Sealed;
+use crate::summary::{LayerOutput, LayerSummary};
+use crate::tensors::{rank, Tensor, TensorIterator};
+use crate::{ElementType, Result};
+use alloc::vec::Vec;
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation<L> {
+    pub(crate) layer: L,
+    pub(crate) parameters: Tensor<rank::One>,
+}
+
+impl<L> Operation<L> {
+    /// Gets the parameters of the layer as a slice, in the order they're passed to the custom layer.
+    pub(crate) fn parameters(&self) -> &[ElementType] {
+        self.parameters.0.as_slice().unwrap() // unwrapping is safe because the parameters are always in standard layout
+    }
+}
+
+impl<L> Sealed for Operation<L> {}
+impl<L: CustomLayer> InitialisedOperation for Operation<L> {
+    type Input = Tensor<rank::Two>;
+    type Output = Tensor<rank::Two>;
+    type ParameterIter = TensorIterator<rank::One>;
+
+    fn iter(&self) -> Self::ParameterIter {
+        self.parameters.clone().into_iter()
+    }
+
+    fn predict(&self, input: Self::Input) -> Result<Self::Output> {
+        self.layer.forward(self.parameters(), &input)
+    }
+
+    fn map_parameters(&mut self, f: &mut impl FnMut(ElementType) -> ElementType) {
+        self.parameters
+            .0
+            .iter_mut()
+            .for_each(|elem| *elem = f(*elem));
+    }
+
+    fn push_layer_summaries(
+        &self,
+        mut input_shape: Vec<Option<usize>>,
+        summaries: &mut Vec<LayerSummary>,
+    ) -> Vec<Option<usize>> {
+        if let Some(neurons) = input_shape.last_mut() {
+            *neurons = neurons
+                .and_then(|neurons| u16::try_from(neurons).ok())
+                .map(|neurons| self.layer.output_neuron_count(neurons) as usize);
+        }
+        summaries.push(LayerSummary::new(
+            "Custom",
+            input_shape.clone(),
+            self.parameters.0.len(),
+        ));
+        input_shape
+    }
+
+    fn predict_with_layer_outputs(
+        &self,
+        input: Self::Input,
+        outputs: &mut Vec<LayerOutput>,
+    ) -> Result<Self::Output> {
+        let output = self.predict(input)?;
+        outputs.push(LayerOutput::new("Custom", &output));
+        Ok(output)
+    }
+}
+
+impl<L: CustomLayer, T: OptimiserFactory<Tensor<rank::One>>> WithOptimiser<T> for Operation<L> {
+    type Trainable = trainable::custom::Operation<L, T::Optimiser>;
+
+    fn with_optimiser(self, factory: T) -> Self::Trainable {
+        let optimiser = factory.instantiate();
+        trainable::custom::Operation {
+            optimiser,
+            initialised: self,
+            last_input: Tensor::default(),
+            saved: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layers::{Chain, Custom, Input};
+    use crate::operations::uninitialised::custom::tests::Scale;
+    use crate::operations::UninitialisedOperation;
+    use crate::optimisers::NullOptimiser;
+    use crate::Error;
+
+    fn operation() -> Operation<Scale> {
+        Operation {
+            layer: Scale,
+            parameters: Tensor::<rank::One>::new([2.0, -1.0]),
+        }
+    }
+
+    #[test]
+    fn test_iter() {
+        // Arrange
+        let operation = operation();
+
+        // Act
+        let output = operation.iter();
+
+        // Assert
+        assert!(output.eq([2.0, -1.0]));
+    }
+
+    #[test]
+    fn test_predict() {
+        // Arrange
+        let operation = operation();
+        let input = Tensor::<rank::Two>::new((2, 2), [1.0, 2.0, 3.0, 4.0]).unwrap();
+        let expected = Tensor::<rank::Two>::new((2, 2), [2.0, -2.0, 6.0, -4.0]).unwrap();
+
+        // Act
+        let output = operation.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_predict_failure() {
+        // Arrange
+        let operation = operation();
+        let input = Tensor::<rank::Two>::new((1, 3), [1.0, 2.0, 3.0]).unwrap();
+
+        // Act
+        let output = operation.predict(input);
+
+        // Assert
+        assert_eq!(
+            output,
+            Err(Error::ShapeMismatch {
+                expected: 2,
+                actual: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_predict_chained() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Custom::new(Scale))
+            .with_iter([3.0, 0.5].into_iter())
+            .unwrap();
+        let input = Tensor::<rank::Two>::new((1, 2), [2.0, 4.0]).unwrap();
+        let expected = Tensor::<rank::Two>::new((1, 2), [6.0, 2.0]).unwrap();
+
+        // Act
+        let output = network.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_with_optimiser() {
+        // Arrange
+        let operation = operation();
+        let expected = operation.clone();
+
+        // Act
+        let output = operation.with_optimiser(NullOptimiser::new());
+
+        // Assert
+        assert_eq!(output.initialised, expected);
+    }
+}
@@ -1,11 +1,15 @@
+use crate::operations::initialised::Stage;
 use crate::operations::{initialised, trainable, InitialisedOperation, WithOptimiser};
 use crate::optimisers::base::OptimiserFactory;
 use crate::private::Sealed;
+use crate::summary::{LayerOutput, LayerSummary};
 use crate::tensors::{rank, Tensor};
-use crate::Result;
+use crate::{ElementType, Result};
+use alloc::vec::Vec;
 use core::iter::Chain;
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Operation<T> {
     pub(crate) weight_multiply: initialised::weight_multiply::Operation,
     pub(crate) bias_add: initialised::bias_add::Operation,
@@ -39,6 +43,49 @@ impl<T: InitialisedOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Tw
         let output = self.activation_function.predict(input)?;
         Ok(output)
     }
+
+    fn map_parameters(&mut self, f: &mut impl FnMut(ElementType) -> ElementType) {
+        self.weight_multiply.map_parameters(f);
+        self.bias_add.map_parameters(f);
+        self.activation_function.map_parameters(f);
+    }
+
+    fn push_layer_parameters(&self, layers: &mut Vec<((usize, usize), Vec<ElementType>)>) {
+        let shape = self.weight_multiply.parameter.0.dim();
+        layers.push((shape, self.iter().collect()));
+    }
+
+    fn push_layer_summaries(
+        &self,
+        mut input_shape: Vec<Option<usize>>,
+        summaries: &mut Vec<LayerSummary>,
+    ) -> Vec<Option<usize>> {
+        if let Some(neurons) = input_shape.last_mut() {
+            *neurons = Some(self.weight_multiply.parameter.0.ncols());
+        }
+        summaries.push(LayerSummary::new(
+            "Dense",
+            input_shape.clone(),
+            self.iter().count(),
+        ));
+        input_shape
+    }
+
+    fn predict_with_layer_outputs(
+        &self,
+        input: Self::Input,
+        outputs: &mut Vec<LayerOutput>,
+    ) -> Result<Self::Output> {
+        let output = self.predict(input)?;
+        outputs.push(LayerOutput::new("Dense", &output));
+        Ok(output)
+    }
+
+    fn push_quantization_stages(&self, stages: &mut Vec<Stage>) -> Result<()> {
+        stages.push(Stage::WeightMultiply(self.weight_multiply.clone()));
+        stages.push(Stage::BiasAdd(self.bias_add.clone()));
+        self.activation_function.push_quantization_stages(stages)
+    }
 }
 
 impl<T, U: Clone + OptimiserFactory<Tensor<rank::Two>>> WithOptimiser<U> for Operation<T>
@@ -180,7 +227,7 @@ mod tests {
         let bias_add = bias_add.with_optimiser(factory.clone());
         let activation_function = ReLU::new();
         let (activation_function, _) = activation_function
-            .with_iter_private(&mut [].into_iter(), neurons)
+            .with_iter_private(&mut core::iter::empty(), neurons)
             .unwrap();
         let activation_function = activation_function.with_optimiser(factory.clone());
         let expected = trainable::dense::Operation {
@@ -0,0 +1,213 @@
+use crate::operations::initialised::conv2d::Window;
+use crate::operations::{initialised, trainable, InitialisedOperation, WithOptimiser};
+use crate::optimisers::base::OptimiserFactory;
+use crate::private::Sealed;
+use crate::summary::{LayerOutput, LayerSummary};
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Result};
+use alloc::vec::Vec;
+use core::iter::Chain;
+use rand::rngs::StdRng;
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation<T> {
+    pub(crate) window: Window,
+    pub(crate) depthwise_multiply: initialised::depthwise_multiply::Operation,
+    pub(crate) bias_add: initialised::bias_add::Operation,
+    pub(crate) activation_function: T,
+}
+
+impl<T> Sealed for Operation<T> {}
+impl<T: InitialisedOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>>
+    InitialisedOperation for Operation<T>
+{
+    type Input = Tensor<rank::Four>;
+    type Output = Tensor<rank::Four>;
+    type ParameterIter = Chain<
+        Chain<
+            <initialised::depthwise_multiply::Operation as InitialisedOperation>::ParameterIter,
+            <initialised::bias_add::Operation as InitialisedOperation>::ParameterIter,
+        >,
+        <T as InitialisedOperation>::ParameterIter,
+    >;
+
+    fn iter(&self) -> Self::ParameterIter {
+        let depthwise_multiply = self.depthwise_multiply.iter();
+        let bias_add = self.bias_add.iter();
+        let activation_function = self.activation_function.iter();
+        depthwise_multiply
+            .chain(bias_add)
+            .chain(activation_function)
+    }
+
+    fn predict(&self, input: Self::Input) -> Result<Self::Output> {
+        let columns = self.window.columns(&input.0)?;
+        let output = self.depthwise_multiply.predict(Tensor(columns))?;
+        let output = self.bias_add.predict(output)?;
+        let output = self.activation_function.predict(output)?;
+        let output = self.window.feature_maps(output.0, input.0.dim())?;
+        Ok(Tensor(output))
+    }
+
+    fn predict_stochastic(
+        &self,
+        input: Self::Input,
+        generator: &mut StdRng,
+    ) -> Result<Self::Output> {
+        let columns = self.window.columns(&input.0)?;
+        let output = self.depthwise_multiply.predict(Tensor(columns))?;
+        let output = self.bias_add.predict(output)?;
+        let output = self
+            .activation_function
+            .predict_stochastic(output, generator)?;
+        let output = self.window.feature_maps(output.0, input.0.dim())?;
+        Ok(Tensor(output))
+    }
+
+    fn map_parameters(&mut self, f: &mut impl FnMut(ElementType) -> ElementType) {
+        self.depthwise_multiply.map_parameters(f);
+        self.bias_add.map_parameters(f);
+        self.activation_function.map_parameters(f);
+    }
+
+    fn push_layer_parameters(&self, layers: &mut Vec<((usize, usize), Vec<ElementType>)>) {
+        let shape = self.depthwise_multiply.parameter.0.dim();
+        layers.push((shape, self.iter().collect()));
+    }
+
+    fn push_layer_summaries(
+        &self,
+        input_shape: Vec<Option<usize>>,
+        summaries: &mut Vec<LayerSummary>,
+    ) -> Vec<Option<usize>> {
+        let channels = self.window.channels as usize;
+        let (height, width) = match *input_shape.as_slice() {
+            [_, _, Some(height), Some(width)] => self
+                .window
+                .output_size((1, channels, height, width))
+                .map_or((None, None), |(height, width)| (Some(height), Some(width))),
+            _ => (None, None),
+        };
+        let filters = self.bias_add.parameter.0.ncols();
+        let output_shape = [None, Some(filters), height, width].to_vec();
+        summaries.push(LayerSummary::new(
+            "DepthwiseConv2D",
+            output_shape.clone(),
+            self.iter().count(),
+        ));
+        output_shape
+    }
+
+    fn predict_with_layer_outputs(
+        &self,
+        input: Self::Input,
+        outputs: &mut Vec<LayerOutput>,
+    ) -> Result<Self::Output> {
+        let output = self.predict(input)?;
+        outputs.push(LayerOutput::new("DepthwiseConv2D", &output));
+        Ok(output)
+    }
+}
+
+impl<T, U: Clone + OptimiserFactory<Tensor<rank::Two>>> WithOptimiser<U> for Operation<T>
+where
+    initialised::bias_add::Operation: WithOptimiser<U>,
+    T: WithOptimiser<U>,
+{
+    type Trainable = trainable::depthwise_conv2d::Operation<
+        <initialised::depthwise_multiply::Operation as WithOptimiser<U>>::Trainable,
+        <initialised::bias_add::Operation as WithOptimiser<U>>::Trainable,
+        <T as WithOptimiser<U>>::Trainable,
+    >;
+
+    fn with_optimiser(self, factory: U) -> Self::Trainable {
+        let depthwise_multiply = self.depthwise_multiply.with_optimiser(factory.clone());
+        let bias_add = self.bias_add.with_optimiser(factory.clone());
+        let activation_function = self.activation_function.with_optimiser(factory);
+        Self::Trainable {
+            window: self.window,
+            depthwise_multiply,
+            bias_add,
+            activation_function,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::{Linear, ReLU};
+    use crate::layers::{Chain, Conv2D, DepthwiseConv2D, ImageInput};
+    use crate::operations::UninitialisedOperation;
+
+    #[test]
+    fn test_predict_keeps_channels_separate() {
+        // Arrange
+        // each channel is only weighted by its own kernel, then has its own bias added.
+        let network = ImageInput::new(2)
+            .chain(DepthwiseConv2D::new(1, 1, 1, 0, Linear::new()))
+            .with_iter([2.0, 3.0, 0.5, -1.0].into_iter())
+            .unwrap();
+        let input = Tensor::<rank::Four>::new((1, 2, 1, 2), [1.0, 2.0, 3.0, 4.0]).unwrap();
+        let expected = Tensor::<rank::Four>::new((1, 2, 1, 2), [2.5, 4.5, 8.0, 11.0]).unwrap();
+
+        // Act
+        let output = network.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_predict_with_multiplier() {
+        // Arrange
+        // kernels are stored with a row per channel/kernel position and a column per feature map.
+        let network = ImageInput::new(1)
+            .chain(DepthwiseConv2D::new(2, 2, 1, 0, ReLU::new()))
+            .with_iter([1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0, -1.0, 0.0, 1.0].into_iter())
+            .unwrap();
+        let input = Tensor::<rank::Four>::new((1, 1, 2, 2), [1.0, 2.0, 3.0, 4.0]).unwrap();
+        let expected = Tensor::<rank::Four>::new((1, 2, 1, 1), [10.0, 0.0]).unwrap();
+
+        // Act
+        let output = network.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_predict_failure() {
+        // Arrange
+        let network = ImageInput::new(2)
+            .chain(DepthwiseConv2D::new(1, 3, 1, 0, Linear::new()))
+            .with_seed(42);
+        let input = Tensor::<rank::Four>::new((1, 1, 3, 3), [1.0; 9]).unwrap();
+
+        // Act
+        let result = network.predict(input);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_summary_separable() {
+        // Arrange
+        let network = ImageInput::new(3)
+            .chain(DepthwiseConv2D::new(1, 3, 1, 1, ReLU::new()))
+            .chain(Conv2D::pointwise(8, ReLU::new()))
+            .with_seed(42);
+
+        // Act
+        let summary = network.summary();
+
+        // Assert
+        let depthwise = &summary.layers()[1];
+        assert_eq!(depthwise.name(), "DepthwiseConv2D");
+        assert_eq!(depthwise.output_shape(), [None, Some(3), None, None]);
+        assert_eq!(depthwise.parameters(), 30);
+        assert_eq!(summary.total_parameters(), 62);
+    }
+}
@@ -0,0 +1,138 @@
+use crate::operations::{initialised, trainable, WithOptimiser};
+use crate::optimisers::base::OptimiserFactory;
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor, TensorIterator};
+use crate::{ElementType, Error, Result};
+use ndarray::{Array, ArrayView, Axis, Ix2, Slice};
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation {
+    pub(crate) groups: u16,
+    pub(crate) parameter: Tensor<rank::Two>, // the weights of each group stacked on top of each other
+}
+
+/// Gets the given group of a matrix which is split into equally sized groups along the given axis.
+pub fn group(
+    matrix: &Array<ElementType, Ix2>,
+    axis: Axis,
+    groups: usize,
+    index: usize,
+) -> ArrayView<'_, ElementType, Ix2> {
+    let size = matrix.len_of(axis) / groups;
+    matrix.slice_axis(axis, Slice::from(index * size..(index + 1) * size))
+}
+
+impl Operation {
+    /// Performs the weighted sum of each group of the input with the weights of that group.
+    pub(crate) fn multiply(&self, input: &Array<ElementType, Ix2>) -> Array<ElementType, Ix2> {
+        let groups = self.groups as usize;
+        let output_neurons = self.parameter.0.ncols();
+        let mut output = Array::zeros((input.nrows(), groups * output_neurons));
+        for index in 0..groups {
+            let weights = group(&self.parameter.0, Axis(0), groups, index);
+            let product = group(input, Axis(1), groups, index).dot(&weights);
+            let range = Slice::from(index * output_neurons..(index + 1) * output_neurons);
+            output.slice_axis_mut(Axis(1), range).assign(&product);
+        }
+        output
+    }
+}
+
+impl Sealed for Operation {}
+impl initialised::Operation for Operation {
+    type Input = Tensor<rank::Two>;
+    type Output = Tensor<rank::Two>;
+    type ParameterIter = TensorIterator<rank::Two>;
+
+    fn iter(&self) -> Self::ParameterIter {
+        self.parameter.clone().into_iter()
+    }
+
+    fn predict(&self, input: Self::Input) -> Result<Self::Output> {
+        Error::check_size(self.parameter.0.nrows(), input.0.ncols())?;
+        Ok(Tensor(self.multiply(&input.0)))
+    }
+
+    fn map_parameters(&mut self, f: &mut impl FnMut(ElementType) -> ElementType) {
+        self.parameter
+            .0
+            .iter_mut()
+            .for_each(|elem| *elem = f(*elem));
+    }
+}
+
+impl<T: OptimiserFactory<Tensor<rank::Two>>> WithOptimiser<T> for Operation {
+    type Trainable = trainable::depthwise_multiply::Operation<T::Optimiser>;
+
+    fn with_optimiser(self, optimiser: T) -> Self::Trainable {
+        let optimiser = optimiser.instantiate();
+        Self::Trainable {
+            optimiser,
+            initialised: self,
+            last_input: Tensor::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::InitialisedOperation;
+
+    #[test]
+    fn test_predict_success() {
+        // Arrange
+        // the first two inputs are weighted by the first two rows, and the last two by the others.
+        let operation = Operation {
+            groups: 2,
+            parameter: Tensor::<rank::Two>::new((4, 1), [1.0, 2.0, 3.0, 4.0]).unwrap(),
+        };
+        let input =
+            Tensor::<rank::Two>::new((2, 4), [1.0, 1.0, 1.0, 1.0, 1.0, 0.0, 0.0, 2.0]).unwrap();
+        let expected = Tensor::<rank::Two>::new((2, 2), [3.0, 7.0, 1.0, 8.0]).unwrap();
+
+        // Act
+        let output = operation.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_predict_failure() {
+        // Arrange
+        let operation = Operation {
+            groups: 2,
+            parameter: Tensor::<rank::Two>::new((4, 1), [1.0, 2.0, 3.0, 4.0]).unwrap(),
+        };
+        let input = Tensor::<rank::Two>::new((1, 2), [1.0, 2.0]).unwrap();
+
+        // Act
+        let result = operation.predict(input);
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(Error::ShapeMismatch {
+                expected: 4,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_iter_and_map_parameters() {
+        // Arrange
+        let mut operation = Operation {
+            groups: 1,
+            parameter: Tensor::<rank::Two>::new((2, 1), [1.0, 2.0]).unwrap(),
+        };
+
+        // Act
+        operation.map_parameters(&mut |elem| elem * 2.0);
+
+        // Assert
+        assert!(operation.iter().eq([2.0, 4.0]));
+    }
+}
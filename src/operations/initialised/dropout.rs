@@ -1,14 +1,66 @@
+use crate::operations::initialised::Stage;
 use crate::operations::{trainable, InitialisedOperation, WithOptimiser};
 use crate::optimisers::base::OptimiserFactory;
-use crate::private::Sealed;
+use crate::private::{next_generator, Sealed};
+use crate::summary::{LayerOutput, LayerSummary};
 use crate::tensors::{rank, Tensor};
 use crate::{ElementType, Result};
+use alloc::vec::Vec;
 use core::iter::{empty, Empty};
+use rand::rngs::StdRng;
+use rand::Rng;
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Operation {
     pub(crate) keep_probability: ElementType,
     pub(crate) seed: Option<u64>, // used during forward pass to generate dropout mask
+    #[cfg_attr(feature = "serde", serde(default))]
+    // networks saved before inverted scaling existed
+    pub(crate) inverted: bool,
+}
+
+impl Operation {
+    /// Gets the generator used to make the mask of the next forward pass, moving the seed on
+    /// so that the same mask isn't generated every time.
+    pub(crate) fn generator(&mut self) -> StdRng {
+        next_generator(&mut self.seed)
+    }
+
+    /// Gets the amount that every element is scaled by when making predictions, which is
+    /// the keep probability unless inverted scaling is used.
+    pub(crate) const fn inference_scale(&self) -> ElementType {
+        if self.inverted {
+            1.0
+        } else {
+            self.keep_probability
+        }
+    }
+
+    /// Generates a mask of the given shape where each element is kept (1) with
+    /// the keep probability of this operation, and dropped (0) otherwise. With inverted
+    /// scaling, kept elements are 1 divided by the keep probability instead.
+    pub fn generate_mask(
+        &self,
+        shape: (usize, usize),
+        generator: &mut impl Rng,
+    ) -> Tensor<rank::Two> {
+        let keep_probability = self.keep_probability;
+        let kept = if self.inverted {
+            keep_probability.recip()
+        } else {
+            1.0
+        };
+        let iter = (0..shape.0 * shape.1).map(|_| {
+            let gen = generator.gen_range(0.0..=1.0);
+            if gen <= keep_probability {
+                kept
+            } else {
+                0.0
+            }
+        });
+        Tensor::<rank::Two>::new(shape, iter).unwrap()
+    }
 }
 
 impl Sealed for Operation {}
@@ -23,10 +75,43 @@ impl InitialisedOperation for Operation {
     }
 
     fn predict(&self, input: Self::Input) -> Result<Self::Output> {
-        let keep_probability = self.keep_probability;
-        let output = Tensor(input.0 * keep_probability);
+        let output = Tensor(input.0 * self.inference_scale());
+        Ok(output)
+    }
+
+    fn predict_stochastic(
+        &self,
+        input: Self::Input,
+        generator: &mut StdRng,
+    ) -> Result<Self::Output> {
+        let mask = self.generate_mask(input.0.dim(), generator);
+        let output = Tensor(input.0 * mask.0);
+        Ok(output)
+    }
+
+    fn push_layer_summaries(
+        &self,
+        input_shape: Vec<Option<usize>>,
+        summaries: &mut Vec<LayerSummary>,
+    ) -> Vec<Option<usize>> {
+        summaries.push(LayerSummary::new("Dropout", input_shape.clone(), 0));
+        input_shape
+    }
+
+    fn predict_with_layer_outputs(
+        &self,
+        input: Self::Input,
+        outputs: &mut Vec<LayerOutput>,
+    ) -> Result<Self::Output> {
+        let output = self.predict(input)?;
+        outputs.push(LayerOutput::new("Dropout", &output));
         Ok(output)
     }
+
+    fn push_quantization_stages(&self, stages: &mut Vec<Stage>) -> Result<()> {
+        stages.push(Stage::Dropout(self.clone()));
+        Ok(())
+    }
 }
 
 impl<T: OptimiserFactory<()>> WithOptimiser<T> for Operation {
@@ -41,14 +126,16 @@ impl<T: OptimiserFactory<()>> WithOptimiser<T> for Operation {
 mod tests {
     use super::*;
     use crate::optimisers::NullOptimiser;
+    use rand::SeedableRng;
 
     #[test]
     fn test_iter() {
         // Arrange
-        let expected = [].into_iter();
+        let expected = core::iter::empty::<ElementType>();
         let initialised = Operation {
             keep_probability: 0.8,
             seed: None,
+            inverted: false,
         };
 
         // Act
@@ -65,6 +152,7 @@ mod tests {
         let initialised = Operation {
             keep_probability: 0.8,
             seed: None,
+            inverted: false,
         };
         let expected = Tensor::<rank::Two>::new((1, 3), [0.8, 1.6, 3.2]).unwrap();
 
@@ -82,11 +170,13 @@ mod tests {
         let initialised = Operation {
             keep_probability: 0.8,
             seed: None,
+            inverted: false,
         };
         let expected = trainable::dropout::Operation {
             initialised: Operation {
                 keep_probability: 0.8,
                 seed: None,
+                inverted: false,
             },
         };
 
@@ -96,4 +186,26 @@ mod tests {
         // Assert
         assert_eq!(output, expected);
     }
+
+    #[test]
+    fn test_inverted_scaling() {
+        // Arrange
+        // kept elements are doubled, so on average the mask leaves the input the same size.
+        let initialised = Operation {
+            keep_probability: 0.5,
+            seed: None,
+            inverted: true,
+        };
+        let input = Tensor::<rank::Two>::new((1, 3), [1.0, 2.0, 4.0]).unwrap();
+        let mut generator = StdRng::seed_from_u64(42);
+
+        // Act
+        let output = initialised.predict(input.clone()).unwrap();
+        let mask = initialised.generate_mask((100, 100), &mut generator);
+
+        // Assert
+        assert_eq!(output, input);
+        assert!(mask.0.iter().all(|elem| *elem == 0.0 || *elem == 2.0));
+        assert!((mask.0.mean().unwrap() - 1.0).abs() < 0.05);
+    }
 }
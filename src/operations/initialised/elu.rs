@@ -0,0 +1,143 @@
+use crate::operations::initialised::Stage;
+use crate::operations::trainable;
+use crate::operations::{InitialisedOperation, WithOptimiser};
+use crate::optimisers::base::OptimiserFactory;
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+use core::iter::{empty, Empty};
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation {
+    pub(crate) neurons: u16,
+    pub(crate) alpha: ElementType,
+}
+
+impl Operation {
+    pub(crate) fn elu(&self, elem: ElementType) -> ElementType {
+        if elem > 0.0 {
+            elem
+        } else {
+            self.alpha * elem.exp_m1()
+        }
+    }
+}
+
+impl Sealed for Operation {}
+impl InitialisedOperation for Operation {
+    type Input = Tensor<rank::Two>;
+    type Output = Tensor<rank::Two>;
+    type ParameterIter = Empty<ElementType>;
+
+    fn iter(&self) -> Self::ParameterIter {
+        empty()
+    }
+
+    fn predict(&self, input: Self::Input) -> Result<Self::Output> {
+        Error::check_size(self.neurons as usize, input.0.ncols())?;
+        Ok(Tensor(input.0.mapv(|elem| self.elu(elem))))
+    }
+
+    fn push_quantization_stages(&self, stages: &mut Vec<Stage>) -> Result<()> {
+        stages.push(Stage::Elu(self.clone()));
+        Ok(())
+    }
+}
+
+impl<T: OptimiserFactory<()>> WithOptimiser<T> for Operation {
+    type Trainable = trainable::elu::Operation;
+
+    fn with_optimiser(self, _optimiser: T) -> Self::Trainable {
+        trainable::elu::Operation {
+            initialised: self,
+            last_output: Tensor::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optimisers::NullOptimiser;
+    use crate::tensors::*;
+
+    #[test]
+    fn test_iter() {
+        // Arrange
+        let operation = Operation {
+            neurons: 42,
+            alpha: 1.0,
+        };
+
+        // Act
+        let iter_count = operation.iter().count();
+
+        // Assert
+        assert_eq!(iter_count, 0);
+    }
+
+    #[test]
+    fn test_predict_success() {
+        // Arrange
+        let operation = Operation {
+            neurons: 3,
+            alpha: 2.0,
+        };
+        let input = Tensor::<rank::Two>::new((2, 3), [-2.0, -1.0, 0.0, 1.0, 2.0, 3.0]).unwrap();
+        let expected = Tensor::<rank::Two>::new(
+            (2, 3),
+            [
+                2.0 * ElementType::exp_m1(-2.0),
+                2.0 * ElementType::exp_m1(-1.0),
+                0.0,
+                1.0,
+                2.0,
+                3.0,
+            ],
+        )
+        .unwrap();
+
+        // Act
+        let output = operation.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_predict_failure() {
+        // Arrange
+        let operation = Operation {
+            neurons: 2,
+            alpha: 1.0,
+        };
+        let input = Tensor::<rank::Two>::new((2, 3), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+
+        // Act
+        let output = operation.predict(input);
+
+        // Assert
+        assert!(output.is_err());
+    }
+
+    #[test]
+    fn test_with_optimiser() {
+        // Arrange
+        let operation = Operation {
+            neurons: 3,
+            alpha: 1.0,
+        };
+        let expected = trainable::elu::Operation {
+            initialised: operation.clone(),
+            last_output: Tensor::default(),
+        };
+
+        // Act
+        let output = operation.with_optimiser(NullOptimiser::new());
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+}
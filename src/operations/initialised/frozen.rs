@@ -0,0 +1,131 @@
+use crate::operations::initialised::Stage;
+use crate::operations::{trainable, InitialisedOperation, WithOptimiser};
+use crate::optimisers::NullOptimiser;
+use crate::private::Sealed;
+use crate::summary::{LayerOutput, LayerSummary};
+use crate::{ElementType, Result};
+use alloc::vec::Vec;
+use rand::rngs::StdRng;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation<T> {
+    pub(crate) block: T,
+}
+
+impl<T> Sealed for Operation<T> {}
+impl<T: InitialisedOperation> InitialisedOperation for Operation<T> {
+    type Input = T::Input;
+    type Output = T::Output;
+    type ParameterIter = T::ParameterIter;
+
+    fn iter(&self) -> Self::ParameterIter {
+        self.block.iter()
+    }
+
+    fn predict(&self, input: Self::Input) -> Result<Self::Output> {
+        self.block.predict(input)
+    }
+
+    fn predict_stochastic(
+        &self,
+        input: Self::Input,
+        generator: &mut StdRng,
+    ) -> Result<Self::Output> {
+        self.block.predict_stochastic(input, generator)
+    }
+
+    fn map_parameters(&mut self, f: &mut impl FnMut(ElementType) -> ElementType) {
+        self.block.map_parameters(f);
+    }
+
+    fn push_layer_parameters(&self, layers: &mut Vec<((usize, usize), Vec<ElementType>)>) {
+        self.block.push_layer_parameters(layers);
+    }
+
+    fn push_layer_summaries(
+        &self,
+        input_shape: Vec<Option<usize>>,
+        summaries: &mut Vec<LayerSummary>,
+    ) -> Vec<Option<usize>> {
+        self.block.push_layer_summaries(input_shape, summaries)
+    }
+
+    fn predict_with_layer_outputs(
+        &self,
+        input: Self::Input,
+        outputs: &mut Vec<LayerOutput>,
+    ) -> Result<Self::Output> {
+        self.block.predict_with_layer_outputs(input, outputs)
+    }
+
+    fn push_quantization_stages(&self, stages: &mut Vec<Stage>) -> Result<()> {
+        self.block.push_quantization_stages(stages)
+    }
+}
+
+impl<T: WithOptimiser<NullOptimiser>, U> WithOptimiser<U> for Operation<T> {
+    type Trainable = trainable::frozen::Operation<T::Trainable>;
+
+    fn with_optimiser(self, _optimiser: U) -> Self::Trainable {
+        // the block is given an optimiser which does nothing so that its parameters are left unchanged.
+        trainable::frozen::Operation {
+            block: self.block.with_optimiser(NullOptimiser::new()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::Linear;
+    use crate::layers::{Chain, Dense, Frozen, Input};
+    use crate::operations::UninitialisedOperation;
+    use crate::optimisers::learning_rate_handlers::FixedLearningRateHandler;
+    use crate::optimisers::SGD;
+    use crate::tensors::{rank, Tensor};
+
+    #[test]
+    fn test_predict() {
+        // Arrange
+        let frozen = Input::new(2)
+            .chain(Frozen::new(Dense::new(1, Linear::new())))
+            .with_iter([1.0, 2.0, 3.0].into_iter())
+            .unwrap();
+        let unfrozen = Input::new(2)
+            .chain(Dense::new(1, Linear::new()))
+            .with_iter([1.0, 2.0, 3.0].into_iter())
+            .unwrap();
+        let input = Tensor::<rank::Two>::new((1, 2), [1.0, 1.0]).unwrap();
+
+        // Act
+        let output = frozen.predict(input.clone()).unwrap();
+
+        // Assert
+        assert_eq!(output, unfrozen.predict(input).unwrap());
+        assert!(frozen.iter().eq(unfrozen.iter()));
+        assert_eq!(frozen.summary(), unfrozen.summary());
+    }
+
+    #[test]
+    fn test_with_optimiser() {
+        // Arrange
+        let operation = Operation {
+            block: Dense::new(1, Linear::new())
+                .with_iter_private(&mut [1.0, 2.0, 3.0].into_iter(), 2)
+                .unwrap()
+                .0,
+        };
+
+        // Act
+        let output = operation
+            .clone()
+            .with_optimiser(SGD::new(FixedLearningRateHandler::new(0.1)));
+
+        // Assert
+        assert_eq!(
+            output.block,
+            operation.block.with_optimiser(NullOptimiser::new())
+        );
+    }
+}
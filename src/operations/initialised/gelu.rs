@@ -0,0 +1,133 @@
+use crate::operations::initialised::Stage;
+use crate::operations::trainable;
+use crate::operations::{InitialisedOperation, WithOptimiser};
+use crate::optimisers::base::OptimiserFactory;
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+use core::iter::{empty, Empty};
+
+/// The square root of 2 / pi, which scales the input of tanh in the GELU approximation.
+#[cfg(not(feature = "f32"))]
+pub const SQRT_2_OVER_PI: ElementType = 0.797_884_560_802_865_4;
+#[cfg(feature = "f32")]
+pub const SQRT_2_OVER_PI: ElementType = 0.797_884_6;
+
+/// The coefficient of the cubic term in the GELU approximation.
+pub const COEFFICIENT: ElementType = 0.044_715;
+
+pub fn gelu(elem: ElementType) -> ElementType {
+    let inner = SQRT_2_OVER_PI * COEFFICIENT.mul_add(elem * elem * elem, elem);
+    0.5 * elem * (1.0 + inner.tanh())
+}
+
+pub fn gelu_derivative(elem: ElementType) -> ElementType {
+    let inner = SQRT_2_OVER_PI * COEFFICIENT.mul_add(elem * elem * elem, elem);
+    let tanh = inner.tanh();
+    let inner_derivative = SQRT_2_OVER_PI * (3.0 * COEFFICIENT).mul_add(elem * elem, 1.0);
+    (0.5 * elem * tanh.mul_add(-tanh, 1.0)).mul_add(inner_derivative, 0.5 * (1.0 + tanh))
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation {
+    pub(crate) neurons: u16,
+}
+
+impl Sealed for Operation {}
+impl InitialisedOperation for Operation {
+    type Input = Tensor<rank::Two>;
+    type Output = Tensor<rank::Two>;
+    type ParameterIter = Empty<ElementType>;
+
+    fn iter(&self) -> Self::ParameterIter {
+        empty()
+    }
+
+    fn predict(&self, input: Self::Input) -> Result<Self::Output> {
+        Error::check_size(self.neurons as usize, input.0.ncols())?;
+        Ok(Tensor(input.0.mapv(gelu)))
+    }
+
+    fn push_quantization_stages(&self, stages: &mut Vec<Stage>) -> Result<()> {
+        stages.push(Stage::Gelu(self.clone()));
+        Ok(())
+    }
+}
+
+impl<T: OptimiserFactory<()>> WithOptimiser<T> for Operation {
+    type Trainable = trainable::gelu::Operation;
+
+    fn with_optimiser(self, _optimiser: T) -> Self::Trainable {
+        trainable::gelu::Operation {
+            initialised: self,
+            last_input: Tensor::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optimisers::NullOptimiser;
+    use crate::tensors::*;
+
+    #[test]
+    fn test_iter() {
+        // Arrange
+        let operation = Operation { neurons: 42 };
+
+        // Act
+        let iter_count = operation.iter().count();
+
+        // Assert
+        assert_eq!(iter_count, 0);
+    }
+
+    #[test]
+    fn test_predict_success() {
+        // Arrange
+        let operation = Operation { neurons: 3 };
+        let input = Tensor::<rank::Two>::new((1, 3), [-3.0, 0.0, 1.0]).unwrap();
+        let expected = [-0.003_637_392, 0.0, 0.841_191_9];
+
+        // Act
+        let output = operation.predict(input).unwrap();
+
+        // Assert
+        output
+            .into_iter()
+            .zip(expected)
+            .for_each(|(output, expected)| assert!((output - expected).abs() < 1e-6));
+    }
+
+    #[test]
+    fn test_predict_failure() {
+        // Arrange
+        let operation = Operation { neurons: 2 };
+        let input = Tensor::<rank::Two>::new((2, 3), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+
+        // Act
+        let output = operation.predict(input);
+
+        // Assert
+        assert!(output.is_err());
+    }
+
+    #[test]
+    fn test_with_optimiser() {
+        // Arrange
+        let operation = Operation { neurons: 3 };
+        let expected = trainable::gelu::Operation {
+            initialised: Operation { neurons: 3 },
+            last_input: Tensor::default(),
+        };
+
+        // Act
+        let output = operation.with_optimiser(NullOptimiser::new());
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+}
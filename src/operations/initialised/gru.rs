@@ -0,0 +1,261 @@
+use crate::operations::{trainable, InitialisedOperation, WithOptimiser};
+use crate::optimisers::base::OptimiserFactory;
+use crate::private::Sealed;
+use crate::summary::{LayerOutput, LayerSummary};
+use crate::tensors::{rank, Tensor, TensorIterator};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+use core::iter::Chain;
+use ndarray::{s, Array, ArrayView, Axis, Ix2, Ix3};
+
+fn sigmoid(elem: ElementType) -> ElementType {
+    1.0 / (1.0 + (-elem).exp())
+}
+
+/// The gates and hidden state that are calculated by the GRU at a single time step.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Step {
+    pub(crate) update: Array<ElementType, Ix2>,
+    pub(crate) reset: Array<ElementType, Ix2>,
+    pub(crate) candidate: Array<ElementType, Ix2>,
+    pub(crate) hidden: Array<ElementType, Ix2>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", allow(clippy::unsafe_derive_deserialize))] // unsafe is only within the slicing macro
+pub struct Operation {
+    pub(crate) input_weights: Tensor<rank::Two>,
+    pub(crate) hidden_weights: Tensor<rank::Two>,
+    pub(crate) bias: Tensor<rank::Two>,
+}
+
+impl Operation {
+    /// Gets the number of units in the hidden state.
+    pub(crate) fn units(&self) -> usize {
+        self.hidden_weights.0.nrows()
+    }
+
+    /// Checks that the given sequence has the number of features that the layer expects.
+    pub(crate) fn check_input(&self, input: &Array<ElementType, Ix3>) -> Result<()> {
+        Error::check_size(self.input_weights.0.nrows(), input.dim().2)
+    }
+
+    /// Runs a single time step with the given input for the step and the previous hidden state.
+    pub(crate) fn step(
+        &self,
+        input: ArrayView<ElementType, Ix2>,
+        hidden: &Array<ElementType, Ix2>,
+    ) -> Step {
+        let units = self.units();
+        let hidden_weights = &self.hidden_weights.0;
+        let input_part = input.dot(&self.input_weights.0) + &self.bias.0;
+        let gates = &input_part.slice(s![.., ..units * 2])
+            + &hidden.dot(&hidden_weights.slice(s![.., ..units * 2]));
+        let gates = gates.mapv(sigmoid);
+        let update = gates.slice(s![.., ..units]).to_owned();
+        let reset = gates.slice(s![.., units..]).to_owned();
+        let candidate = &input_part.slice(s![.., units * 2..])
+            + &(&reset * hidden).dot(&hidden_weights.slice(s![.., units * 2..]));
+        let candidate = candidate.mapv(ElementType::tanh);
+        let hidden = &update * hidden + (1.0 - &update) * &candidate;
+        Step {
+            update,
+            reset,
+            candidate,
+            hidden,
+        }
+    }
+}
+
+impl Sealed for Operation {}
+impl InitialisedOperation for Operation {
+    type Input = Tensor<rank::Three>;
+    type Output = Tensor<rank::Two>;
+    type ParameterIter = Chain<
+        Chain<TensorIterator<rank::Two>, TensorIterator<rank::Two>>,
+        TensorIterator<rank::Two>,
+    >;
+
+    fn iter(&self) -> Self::ParameterIter {
+        self.input_weights
+            .clone()
+            .into_iter()
+            .chain(self.hidden_weights.clone())
+            .chain(self.bias.clone())
+    }
+
+    fn predict(&self, input: Self::Input) -> Result<Self::Output> {
+        self.check_input(&input.0)?;
+        let hidden = Array::zeros((input.0.dim().0, self.units()));
+        let hidden = input
+            .0
+            .axis_iter(Axis(1))
+            .fold(hidden, |hidden, input| self.step(input, &hidden).hidden);
+        Ok(Tensor(hidden))
+    }
+
+    fn map_parameters(&mut self, f: &mut impl FnMut(ElementType) -> ElementType) {
+        let parameters = [
+            &mut self.input_weights,
+            &mut self.hidden_weights,
+            &mut self.bias,
+        ];
+        for parameter in parameters {
+            parameter.0.iter_mut().for_each(|elem| *elem = f(*elem));
+        }
+    }
+
+    fn push_layer_summaries(
+        &self,
+        _input_shape: Vec<Option<usize>>,
+        summaries: &mut Vec<LayerSummary>,
+    ) -> Vec<Option<usize>> {
+        let output_shape = [None, Some(self.units())].to_vec();
+        summaries.push(LayerSummary::new(
+            "GRU",
+            output_shape.clone(),
+            self.iter().count(),
+        ));
+        output_shape
+    }
+
+    fn predict_with_layer_outputs(
+        &self,
+        input: Self::Input,
+        outputs: &mut Vec<LayerOutput>,
+    ) -> Result<Self::Output> {
+        let output = self.predict(input)?;
+        outputs.push(LayerOutput::new("GRU", &output));
+        Ok(output)
+    }
+}
+
+impl<T: OptimiserFactory<Tensor<rank::Two>>> WithOptimiser<T> for Operation {
+    type Trainable = trainable::gru::Operation<T::Optimiser>;
+
+    fn with_optimiser(self, optimiser: T) -> Self::Trainable {
+        let optimisers = [
+            optimiser.instantiate(),
+            optimiser.instantiate(),
+            optimiser.instantiate(),
+        ];
+        Self::Trainable {
+            optimisers,
+            initialised: self,
+            last_input: Tensor::default(),
+            last_steps: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layers::{Chain, SequenceInput, GRU};
+    use crate::operations::UninitialisedOperation;
+    use crate::optimisers::NullOptimiser;
+
+    fn operation() -> Operation {
+        Operation {
+            input_weights: Tensor::<rank::Two>::new((1, 3), [0.5, -0.5, 1.0]).unwrap(),
+            hidden_weights: Tensor::<rank::Two>::new((1, 3), [1.0, 1.0, -1.0]).unwrap(),
+            bias: Tensor::<rank::Two>::new((1, 3), [0.0, 0.0, 0.0]).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_iter() {
+        // Arrange
+        let operation = operation();
+        let expected = [0.5, -0.5, 1.0, 1.0, 1.0, -1.0, 0.0, 0.0, 0.0];
+
+        // Act
+        let output = operation.iter();
+
+        // Assert
+        assert!(output.eq(expected));
+    }
+
+    #[test]
+    fn test_predict_single_step() {
+        // Arrange
+        let operation = operation();
+        let input = Tensor::<rank::Three>::new((1, 1, 1), [2.0]).unwrap();
+        // with a zero hidden state, h = (1 - sigmoid(1)) * tanh(2).
+        let expected = (1.0 - sigmoid(1.0)) * ElementType::tanh(2.0);
+
+        // Act
+        let output = operation.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output.0.dim(), (1, 1));
+        assert!((output.0[[0, 0]] - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_predict_two_steps() {
+        // Arrange
+        let operation = operation();
+        let input = Tensor::<rank::Three>::new((1, 2, 1), [2.0, -1.0]).unwrap();
+        let first = (1.0 - sigmoid(1.0)) * ElementType::tanh(2.0);
+        let update = sigmoid(first - 0.5);
+        let reset = sigmoid(first + 0.5);
+        let candidate = ElementType::tanh(-1.0 - reset * first);
+        let expected = update.mul_add(first, (1.0 - update) * candidate);
+
+        // Act
+        let output = operation.predict(input).unwrap();
+
+        // Assert
+        assert!((output.0[[0, 0]] - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_predict_failure() {
+        // Arrange
+        let operation = operation();
+        let input = Tensor::<rank::Three>::new((1, 1, 2), [2.0, 1.0]).unwrap();
+
+        // Act
+        let output = operation.predict(input);
+
+        // Assert
+        assert_eq!(
+            output,
+            Err(Error::ShapeMismatch {
+                expected: 1,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_predict_chained() {
+        // Arrange
+        let network = SequenceInput::new(3).chain(GRU::new(4)).with_seed(42);
+        let input =
+            Tensor::<rank::Three>::new((2, 5, 3), (0u8..30).map(ElementType::from)).unwrap();
+
+        // Act
+        let output = network.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output.0.dim(), (2, 4));
+        assert!(output.0.iter().all(|elem| elem.abs() <= 1.0));
+    }
+
+    #[test]
+    fn test_with_optimiser() {
+        // Arrange
+        let operation = operation();
+        let expected = operation.clone();
+
+        // Act
+        let output = operation.with_optimiser(NullOptimiser::new());
+
+        // Assert
+        assert_eq!(output.initialised, expected);
+    }
+}
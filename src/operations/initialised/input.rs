@@ -1,12 +1,16 @@
+use crate::operations::initialised::Stage;
 use crate::operations::trainable;
 use crate::operations::{InitialisedOperation, WithOptimiser};
 use crate::optimisers::base::OptimiserFactory;
 use crate::private::Sealed;
+use crate::summary::{LayerOutput, LayerSummary};
 use crate::tensors::{rank, Tensor};
 use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
 use core::iter::{empty, Empty};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Operation {
     pub(crate) neurons: u16,
 }
@@ -25,9 +29,36 @@ impl InitialisedOperation for Operation {
         if input.0.ncols() == self.neurons as usize {
             Ok(input)
         } else {
-            Err(Error(()))
+            Err(Error::ShapeMismatch {
+                expected: self.neurons as usize,
+                actual: input.0.ncols(),
+            })
         }
     }
+
+    fn push_layer_summaries(
+        &self,
+        _input_shape: Vec<Option<usize>>,
+        summaries: &mut Vec<LayerSummary>,
+    ) -> Vec<Option<usize>> {
+        let output_shape = [None, Some(self.neurons as usize)].to_vec();
+        summaries.push(LayerSummary::new("Input", output_shape.clone(), 0));
+        output_shape
+    }
+
+    fn predict_with_layer_outputs(
+        &self,
+        input: Self::Input,
+        outputs: &mut Vec<LayerOutput>,
+    ) -> Result<Self::Output> {
+        let output = self.predict(input)?;
+        outputs.push(LayerOutput::new("Input", &output));
+        Ok(output)
+    }
+
+    fn push_quantization_stages(&self, _stages: &mut Vec<Stage>) -> Result<()> {
+        Ok(())
+    }
 }
 
 impl<T: OptimiserFactory<()>> WithOptimiser<T> for Operation {
@@ -79,7 +110,13 @@ mod tests {
         let output = operation.predict(input);
 
         // Assert
-        assert!(output.is_err());
+        assert_eq!(
+            output,
+            Err(Error::ShapeMismatch {
+                expected: 2,
+                actual: 3
+            })
+        );
     }
 
     #[test]
@@ -1,12 +1,15 @@
+use crate::operations::initialised::Stage;
 use crate::operations::trainable;
 use crate::operations::{InitialisedOperation, WithOptimiser};
 use crate::optimisers::base::OptimiserFactory;
 use crate::private::Sealed;
 use crate::tensors::{rank, Tensor};
 use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
 use core::iter::{empty, Empty};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Operation {
     pub(crate) neurons: u16,
 }
@@ -25,9 +28,17 @@ impl InitialisedOperation for Operation {
         if input.0.ncols() == self.neurons as usize {
             Ok(input)
         } else {
-            Err(Error(()))
+            Err(Error::ShapeMismatch {
+                expected: self.neurons as usize,
+                actual: input.0.ncols(),
+            })
         }
     }
+
+    fn push_quantization_stages(&self, stages: &mut Vec<Stage>) -> Result<()> {
+        stages.push(Stage::Linear(self.clone()));
+        Ok(())
+    }
 }
 
 impl<T: OptimiserFactory<()>> WithOptimiser<T> for Operation {
@@ -1,19 +1,45 @@
 //! This submodule contains the traits and structures for operations in the
 //! initialised state.
 
+pub mod alpha_dropout;
 pub mod bias_add;
 pub mod composite;
+pub mod conv1d;
+pub mod conv2d;
+pub mod conv_transpose2d;
+pub mod custom;
 pub mod dense;
+pub mod depthwise_conv2d;
+pub mod depthwise_multiply;
 pub mod dropout;
+pub mod elu;
+pub mod frozen;
+pub mod gelu;
+pub mod gru;
+pub mod image_input;
 pub mod input;
 pub mod linear;
+pub mod multi_input;
+pub mod parallel;
+pub mod pooling2d;
 pub mod relu;
+pub mod reshape;
+pub mod selu;
+pub mod sequence_input;
 pub mod sigmoid;
+pub mod silu;
+pub mod spatial_dropout;
+pub mod stochastic_depth;
 pub mod tanh;
+pub mod upsample2d;
 pub mod weight_multiply;
 
 use crate::private::Sealed;
-use crate::{ElementType, Result};
+use crate::summary::{LayerOutput, LayerSummary};
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+use rand::rngs::StdRng;
 
 /// This trait is used to represent an operation in an initialised state that has a valid
 /// parameter stored internally, and which can be used to run inference or prepared for
@@ -42,6 +68,112 @@ pub trait Operation: Sealed {
     /// # Errors
     /// `Error` if the prediction fails such as if the input is incorrectly shaped.
     fn predict(&self, input: Self::Input) -> Result<Self::Output>;
+
+    /// Runs the input through the operation in the same way as `predict`, except that any
+    /// stochastic operations (such as dropout) stay active, drawing randomness from the given generator.
+    ///
+    /// # Errors
+    /// `Error` if the prediction fails such as if the input is incorrectly shaped.
+    #[doc(hidden)]
+    fn predict_stochastic(
+        &self,
+        input: Self::Input,
+        _generator: &mut StdRng,
+    ) -> Result<Self::Output> {
+        self.predict(input)
+    }
+
+    /// Replaces each parameter of this operation with the result of calling the given
+    /// function on it. Parameters are visited in the same order as `iter`.
+    #[doc(hidden)]
+    fn map_parameters(&mut self, _f: &mut impl FnMut(ElementType) -> ElementType) {}
+
+    /// Pushes the shape and flattened parameters of each layer within this operation onto the
+    /// given vector in network order. Layers without any parameters are skipped.
+    #[doc(hidden)]
+    fn push_layer_parameters(&self, layers: &mut Vec<((usize, usize), Vec<ElementType>)>) {
+        let parameters = self.iter().collect::<Vec<_>>();
+        if !parameters.is_empty() {
+            layers.push(((1, parameters.len()), parameters));
+        }
+    }
+
+    /// Runs the input through the operation in the same way as `predict`, pushing the output of each
+    /// layer within this operation onto the given vector in network order.
+    ///
+    /// # Errors
+    /// `Error` if the prediction fails such as if the input is incorrectly shaped.
+    #[doc(hidden)]
+    fn predict_with_layer_outputs(
+        &self,
+        input: Self::Input,
+        _outputs: &mut Vec<LayerOutput>,
+    ) -> Result<Self::Output> {
+        self.predict(input)
+    }
+
+    /// Pushes a summary of each layer within this operation onto the given vector in network order,
+    /// given the shape of the input to the operation, and returns the shape of its output. Operations
+    /// which aren't layers in their own right (such as activation functions) push nothing.
+    #[doc(hidden)]
+    fn push_layer_summaries(
+        &self,
+        input_shape: Vec<Option<usize>>,
+        _summaries: &mut Vec<LayerSummary>,
+    ) -> Vec<Option<usize>> {
+        input_shape
+    }
+
+    /// Pushes the stages of this operation onto the given vector in network order, so that the
+    /// network can be quantized. Operations which can't be quantized give an error.
+    ///
+    /// # Errors
+    /// `Error::UnsupportedLayer` if the operation can't be quantized.
+    #[doc(hidden)]
+    fn push_quantization_stages(&self, _stages: &mut Vec<Stage>) -> Result<()> {
+        Err(Error::UnsupportedLayer)
+    }
+}
+
+/// A stage of a network which is being quantized. The weights of weight multiplication stages are
+/// quantized, while every other stage runs unchanged on the output of the stage before it.
+#[doc(hidden)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Stage {
+    WeightMultiply(weight_multiply::Operation),
+    BiasAdd(bias_add::Operation),
+    Dropout(dropout::Operation),
+    Elu(elu::Operation),
+    Gelu(gelu::Operation),
+    Linear(linear::Operation),
+    ReLU(relu::Operation),
+    Selu(selu::Operation),
+    Sigmoid(sigmoid::Operation),
+    SiLU(silu::Operation),
+    Tanh(tanh::Operation),
+}
+
+impl Stage {
+    /// Runs the input through the stage at full precision.
+    ///
+    /// # Errors
+    /// `Error` if the input is incorrectly shaped.
+    pub fn predict(&self, input: Tensor<rank::Two>) -> Result<Tensor<rank::Two>> {
+        match self {
+            Self::WeightMultiply(operation) => operation.predict(input),
+            Self::BiasAdd(operation) => operation.predict(input),
+            Self::Dropout(operation) => operation.predict(input),
+            Self::Elu(operation) => operation.predict(input),
+            Self::Gelu(operation) => operation.predict(input),
+            Self::Linear(operation) => operation.predict(input),
+            Self::ReLU(operation) => operation.predict(input),
+            Self::Selu(operation) => operation.predict(input),
+            Self::Sigmoid(operation) => operation.predict(input),
+            Self::SiLU(operation) => operation.predict(input),
+            Self::Tanh(operation) => operation.predict(input),
+        }
+    }
 }
 
 /// This trait is used on an Operation type in order to be able to take it
@@ -0,0 +1,198 @@
+use crate::operations::initialised::parallel::merge;
+use crate::operations::uninitialised::parallel::Merge;
+use crate::operations::{trainable, InitialisedOperation, WithOptimiser};
+use crate::private::Sealed;
+use crate::summary::{LayerOutput, LayerSummary};
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+use core::iter::Chain;
+use ndarray::Axis;
+use rand::rngs::StdRng;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation<T, U> {
+    pub(crate) lhs: T,
+    pub(crate) rhs: U,
+    pub(crate) merge: Merge,
+    pub(crate) features: (usize, usize), // the number of input columns taken by each branch
+}
+
+/// Splits the input of a multi-input layer into the inputs of each branch, given the number
+/// of columns taken by each branch.
+pub fn split(
+    features: (usize, usize),
+    input: &Tensor<rank::Two>,
+) -> Result<(Tensor<rank::Two>, Tensor<rank::Two>)> {
+    Error::check_size(features.0 + features.1, input.0.ncols())?;
+    let (lhs, rhs) = input.0.view().split_at(Axis(1), features.0);
+    Ok((Tensor(lhs.to_owned()), Tensor(rhs.to_owned())))
+}
+
+impl<T, U> Sealed for Operation<T, U> {}
+impl<T, U> InitialisedOperation for Operation<T, U>
+where
+    T: InitialisedOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+    U: InitialisedOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+{
+    type Input = Tensor<rank::Two>;
+    type Output = Tensor<rank::Two>;
+    type ParameterIter = Chain<T::ParameterIter, U::ParameterIter>;
+
+    fn iter(&self) -> Self::ParameterIter {
+        self.lhs.iter().chain(self.rhs.iter())
+    }
+
+    fn predict(&self, input: Self::Input) -> Result<Self::Output> {
+        let (lhs, rhs) = split(self.features, &input)?;
+        merge(self.merge, self.lhs.predict(lhs)?, self.rhs.predict(rhs)?)
+    }
+
+    fn predict_stochastic(
+        &self,
+        input: Self::Input,
+        generator: &mut StdRng,
+    ) -> Result<Self::Output> {
+        let (lhs, rhs) = split(self.features, &input)?;
+        let lhs = self.lhs.predict_stochastic(lhs, generator)?;
+        let rhs = self.rhs.predict_stochastic(rhs, generator)?;
+        merge(self.merge, lhs, rhs)
+    }
+
+    fn map_parameters(&mut self, f: &mut impl FnMut(ElementType) -> ElementType) {
+        self.lhs.map_parameters(f);
+        self.rhs.map_parameters(f);
+    }
+
+    fn push_layer_parameters(&self, layers: &mut Vec<((usize, usize), Vec<ElementType>)>) {
+        self.lhs.push_layer_parameters(layers);
+        self.rhs.push_layer_parameters(layers);
+    }
+
+    fn push_layer_summaries(
+        &self,
+        _input_shape: Vec<Option<usize>>,
+        summaries: &mut Vec<LayerSummary>,
+    ) -> Vec<Option<usize>> {
+        // the branches are summarised as a single layer, so their own summaries are discarded.
+        let mut branches = Vec::new();
+        let mut shape = self
+            .lhs
+            .push_layer_summaries([None, Some(self.features.0)].to_vec(), &mut branches);
+        if self.merge == Merge::Concatenate {
+            let rhs_shape = self
+                .rhs
+                .push_layer_summaries([None, Some(self.features.1)].to_vec(), &mut branches);
+            if let (Some(Some(lhs)), Some(Some(rhs))) = (shape.last_mut(), rhs_shape.last()) {
+                *lhs += rhs;
+            }
+        }
+        summaries.push(LayerSummary::new(
+            "MultiInput",
+            shape.clone(),
+            self.iter().count(),
+        ));
+        shape
+    }
+
+    fn predict_with_layer_outputs(
+        &self,
+        input: Self::Input,
+        outputs: &mut Vec<LayerOutput>,
+    ) -> Result<Self::Output> {
+        let output = self.predict(input)?;
+        outputs.push(LayerOutput::new("MultiInput", &output));
+        Ok(output)
+    }
+}
+
+impl<T, U, V> WithOptimiser<V> for Operation<T, U>
+where
+    T: WithOptimiser<V>,
+    U: WithOptimiser<V>,
+    V: Clone,
+{
+    type Trainable = trainable::multi_input::Operation<T::Trainable, U::Trainable>;
+
+    fn with_optimiser(self, optimiser: V) -> Self::Trainable {
+        Self::Trainable {
+            lhs: self.lhs.with_optimiser(optimiser.clone()),
+            rhs: self.rhs.with_optimiser(optimiser),
+            merge: self.merge,
+            features: self.features,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::{Linear, ReLU};
+    use crate::layers::{Chain as _, Dense, Input, MultiInput};
+    use crate::operations::UninitialisedOperation;
+
+    #[test]
+    fn test_predict() {
+        // Arrange
+        let operation = MultiInput::new(
+            Input::new(2).chain(Dense::new(1, Linear::new())),
+            Input::new(1).chain(Dense::new(2, ReLU::new())),
+            Merge::Concatenate,
+        )
+        .with_iter([1.0, 1.0, 0.0, 1.0, -1.0, 0.0, 0.0].into_iter())
+        .unwrap();
+        let lhs = Tensor::<rank::Two>::new((2, 2), [1.0, 2.0, 3.0, -4.0]).unwrap();
+        let rhs = Tensor::<rank::Two>::new((2, 1), [2.0, -3.0]).unwrap();
+        let input = lhs.concatenate_columns(&rhs).unwrap();
+        let expected = Tensor::<rank::Two>::new((2, 3), [3.0, 2.0, 0.0, -1.0, 0.0, 3.0]).unwrap();
+
+        // Act
+        let output = operation.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_predict_failure() {
+        // Arrange
+        let operation =
+            MultiInput::new(Input::new(2), Input::new(1), Merge::Concatenate).with_seed(42);
+        let input = Tensor::<rank::Two>::new((1, 2), [1.0, 2.0]).unwrap();
+
+        // Act
+        let result = operation.predict(input);
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(Error::ShapeMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_summary() {
+        // Arrange
+        let network = MultiInput::new(
+            Input::new(4).chain(Dense::new(3, ReLU::new())),
+            Input::new(2).chain(Dense::new(2, Linear::new())),
+            Merge::Concatenate,
+        )
+        .chain(Dense::new(1, Linear::new()))
+        .with_seed(42);
+
+        // Act
+        let summary = network.summary();
+
+        // Assert
+        let multi_input = &summary.layers()[0];
+        assert_eq!(multi_input.name(), "MultiInput");
+        assert_eq!(multi_input.output_shape(), [None, Some(5)]);
+        assert_eq!(multi_input.parameters(), 21);
+        assert_eq!(summary.total_parameters(), 27);
+    }
+}
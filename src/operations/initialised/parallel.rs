@@ -0,0 +1,228 @@
+use crate::operations::uninitialised::parallel::Merge;
+use crate::operations::{trainable, InitialisedOperation, WithOptimiser};
+use crate::private::Sealed;
+use crate::summary::{LayerOutput, LayerSummary};
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+use core::iter::Chain;
+use ndarray::{concatenate, Axis};
+use rand::rngs::StdRng;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation<T, U> {
+    pub(crate) lhs: T,
+    pub(crate) rhs: U,
+    pub(crate) merge: Merge,
+}
+
+/// Merges the outputs of the branches of a parallel layer in the given way.
+pub fn merge(
+    merge: Merge,
+    lhs: Tensor<rank::Two>,
+    rhs: Tensor<rank::Two>,
+) -> Result<Tensor<rank::Two>> {
+    match merge {
+        Merge::Concatenate => {
+            Error::check_size(lhs.0.nrows(), rhs.0.nrows())?;
+            // both outputs have the same number of rows, so they can always be concatenated.
+            Ok(Tensor(
+                concatenate(Axis(1), &[lhs.0.view(), rhs.0.view()]).unwrap(),
+            ))
+        }
+        Merge::Add => {
+            Error::check_shape(lhs.0.dim(), rhs.0.dim())?;
+            Ok(Tensor(lhs.0 + rhs.0))
+        }
+    }
+}
+
+impl<T, U> Sealed for Operation<T, U> {}
+impl<T, U> InitialisedOperation for Operation<T, U>
+where
+    T: InitialisedOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+    U: InitialisedOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+{
+    type Input = Tensor<rank::Two>;
+    type Output = Tensor<rank::Two>;
+    type ParameterIter = Chain<T::ParameterIter, U::ParameterIter>;
+
+    fn iter(&self) -> Self::ParameterIter {
+        self.lhs.iter().chain(self.rhs.iter())
+    }
+
+    fn predict(&self, input: Self::Input) -> Result<Self::Output> {
+        let lhs = self.lhs.predict(input.clone())?;
+        let rhs = self.rhs.predict(input)?;
+        merge(self.merge, lhs, rhs)
+    }
+
+    fn predict_stochastic(
+        &self,
+        input: Self::Input,
+        generator: &mut StdRng,
+    ) -> Result<Self::Output> {
+        let lhs = self.lhs.predict_stochastic(input.clone(), generator)?;
+        let rhs = self.rhs.predict_stochastic(input, generator)?;
+        merge(self.merge, lhs, rhs)
+    }
+
+    fn map_parameters(&mut self, f: &mut impl FnMut(ElementType) -> ElementType) {
+        self.lhs.map_parameters(f);
+        self.rhs.map_parameters(f);
+    }
+
+    fn push_layer_parameters(&self, layers: &mut Vec<((usize, usize), Vec<ElementType>)>) {
+        self.lhs.push_layer_parameters(layers);
+        self.rhs.push_layer_parameters(layers);
+    }
+
+    fn push_layer_summaries(
+        &self,
+        input_shape: Vec<Option<usize>>,
+        summaries: &mut Vec<LayerSummary>,
+    ) -> Vec<Option<usize>> {
+        // the branches are summarised as a single layer, so their own summaries are discarded.
+        let mut branches = Vec::new();
+        let mut shape = self
+            .lhs
+            .push_layer_summaries(input_shape.clone(), &mut branches);
+        if self.merge == Merge::Concatenate {
+            let rhs_shape = self.rhs.push_layer_summaries(input_shape, &mut branches);
+            if let (Some(Some(lhs)), Some(Some(rhs))) = (shape.last_mut(), rhs_shape.last()) {
+                *lhs += rhs;
+            }
+        }
+        summaries.push(LayerSummary::new(
+            "Parallel",
+            shape.clone(),
+            self.iter().count(),
+        ));
+        shape
+    }
+
+    fn predict_with_layer_outputs(
+        &self,
+        input: Self::Input,
+        outputs: &mut Vec<LayerOutput>,
+    ) -> Result<Self::Output> {
+        let output = self.predict(input)?;
+        outputs.push(LayerOutput::new("Parallel", &output));
+        Ok(output)
+    }
+}
+
+impl<T, U, V> WithOptimiser<V> for Operation<T, U>
+where
+    T: WithOptimiser<V>,
+    U: WithOptimiser<V>,
+    V: Clone,
+{
+    type Trainable = trainable::parallel::Operation<T::Trainable, U::Trainable>;
+
+    fn with_optimiser(self, optimiser: V) -> Self::Trainable {
+        Self::Trainable {
+            lhs: self.lhs.with_optimiser(optimiser.clone()),
+            rhs: self.rhs.with_optimiser(optimiser),
+            merge: self.merge,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::{Linear, ReLU};
+    use crate::layers::{Chain, Dense, Input, Parallel};
+    use crate::operations::UninitialisedOperation;
+    use crate::optimisers::NullOptimiser;
+
+    #[test]
+    fn test_predict_concatenate() {
+        // Arrange
+        let operation = Input::new(2)
+            .chain(Parallel::new(
+                Dense::new(1, Linear::new()),
+                Dense::new(2, ReLU::new()),
+                Merge::Concatenate,
+            ))
+            .with_iter([1.0, 1.0, 0.0, 1.0, 0.0, 0.0, -1.0, 0.0, 0.0].into_iter())
+            .unwrap();
+        let input = Tensor::<rank::Two>::new((2, 2), [1.0, 2.0, 3.0, -4.0]).unwrap();
+        let expected = Tensor::<rank::Two>::new((2, 3), [3.0, 1.0, 0.0, -1.0, 3.0, 4.0]).unwrap();
+
+        // Act
+        let output = operation.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_predict_add() {
+        // Arrange
+        let operation = Input::new(2)
+            .chain(Parallel::new(
+                Dense::new(1, Linear::new()),
+                Dense::new(1, Linear::new()),
+                Merge::Add,
+            ))
+            .with_iter([1.0, 1.0, 0.0, 2.0, 0.0, 0.5].into_iter())
+            .unwrap();
+        let input = Tensor::<rank::Two>::new((1, 2), [1.0, 2.0]).unwrap();
+        let expected = Tensor::<rank::Two>::new((1, 1), [5.5]).unwrap();
+
+        // Act
+        let output = operation.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_summary() {
+        // Arrange
+        let network = Input::new(4)
+            .chain(Parallel::new(
+                Dense::new(3, ReLU::new()),
+                Dense::new(2, Linear::new()),
+                Merge::Concatenate,
+            ))
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42);
+
+        // Act
+        let summary = network.summary();
+
+        // Assert
+        let parallel = &summary.layers()[1];
+        assert_eq!(parallel.name(), "Parallel");
+        assert_eq!(parallel.output_shape(), [None, Some(5)]);
+        assert_eq!(parallel.parameters(), 25);
+        assert_eq!(summary.total_parameters(), 31);
+    }
+
+    #[test]
+    fn test_with_optimiser() {
+        // Arrange
+        let lhs = Dense::new(2, Linear::new()).with_seed_private(42, 2).0;
+        let rhs = Dense::new(1, Linear::new()).with_seed_private(43, 2).0;
+        let operation = Operation {
+            lhs: lhs.clone(),
+            rhs: rhs.clone(),
+            merge: Merge::Concatenate,
+        };
+        let expected = trainable::parallel::Operation {
+            lhs: lhs.with_optimiser(NullOptimiser::new()),
+            rhs: rhs.with_optimiser(NullOptimiser::new()),
+            merge: Merge::Concatenate,
+        };
+
+        // Act
+        let output = operation.with_optimiser(NullOptimiser::new());
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+}
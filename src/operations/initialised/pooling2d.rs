@@ -0,0 +1,210 @@
+use crate::operations::uninitialised::pooling2d::Pool;
+use crate::operations::{trainable, InitialisedOperation, WithOptimiser};
+use crate::optimisers::base::OptimiserFactory;
+use crate::private::Sealed;
+use crate::summary::{LayerOutput, LayerSummary};
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+use core::iter::{empty, Empty};
+use core::marker::PhantomData;
+use ndarray::{s, Array, Ix4};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", allow(clippy::unsafe_derive_deserialize))] // unsafe is only within the slicing macro
+pub struct Operation<T> {
+    pub(crate) channels: u16,
+    pub(crate) pool_size: usize,
+    pub(crate) stride: usize,
+    pub(crate) pooling: PhantomData<T>,
+}
+
+impl<T: Pool> Operation<T> {
+    const fn output_shape(
+        &self,
+        (batch, channels, height, width): (usize, usize, usize, usize),
+    ) -> Result<(usize, usize, usize, usize)> {
+        let (pool_size, stride) = (self.pool_size, self.stride);
+        let smallest = if height < width { height } else { width };
+        if channels != self.channels as usize {
+            Err(Error::ShapeMismatch {
+                expected: self.channels as usize,
+                actual: channels,
+            })
+        } else if pool_size == 0 || stride == 0 {
+            Err(Error::InvalidArgument)
+        } else if smallest < pool_size {
+            // the image is too small for the pooling window to fit inside.
+            Err(Error::ShapeMismatch {
+                expected: pool_size,
+                actual: smallest,
+            })
+        } else {
+            let output_height = (height - pool_size) / stride + 1;
+            let output_width = (width - pool_size) / stride + 1;
+            Ok((batch, channels, output_height, output_width))
+        }
+    }
+
+    /// Pools each window of the input, returning the pooled feature maps along with the
+    /// position within each window that the gradient is to be routed back to.
+    pub(crate) fn pool(
+        &self,
+        input: &Array<ElementType, Ix4>,
+    ) -> Result<(Array<ElementType, Ix4>, Array<usize, Ix4>)> {
+        let (pool_size, stride) = (self.pool_size, self.stride);
+        let pooled = Array::from_shape_fn(self.output_shape(input.dim())?, |(n, c, y, x)| {
+            let (y, x) = (y * stride, x * stride);
+            T::pool(input.slice(s![n, c, y..y + pool_size, x..x + pool_size]))
+        });
+        let output = pooled.mapv(|(elem, _)| elem);
+        let positions = pooled.mapv(|(_, position)| position);
+        Ok((output, positions))
+    }
+
+    /// Routes the gradient of the pooled feature maps back to the windows of an input with the
+    /// given shape, using the positions recorded when pooling.
+    pub(crate) fn route(
+        &self,
+        gradient: &Array<ElementType, Ix4>,
+        positions: &Array<usize, Ix4>,
+        input_shape: (usize, usize, usize, usize),
+    ) -> Result<Array<ElementType, Ix4>> {
+        let output_shape = self.output_shape(input_shape)?;
+        if gradient.dim() != output_shape || positions.dim() != output_shape {
+            return Err(Error::ShapeMismatch {
+                expected: output_shape.0 * output_shape.1 * output_shape.2 * output_shape.3,
+                actual: gradient.len(),
+            });
+        }
+        let (pool_size, stride) = (self.pool_size, self.stride);
+        let mut input_gradient = Array::zeros(input_shape);
+        for ((n, c, y, x), elem) in gradient.indexed_iter() {
+            let position = positions[[n, c, y, x]];
+            let (y, x) = (y * stride, x * stride);
+            let window = input_gradient.slice_mut(s![n, c, y..y + pool_size, x..x + pool_size]);
+            T::route(window, position, *elem);
+        }
+        Ok(input_gradient)
+    }
+}
+
+impl<T> Sealed for Operation<T> {}
+impl<T: Pool> InitialisedOperation for Operation<T> {
+    type Input = Tensor<rank::Four>;
+    type Output = Tensor<rank::Four>;
+    type ParameterIter = Empty<ElementType>;
+
+    fn iter(&self) -> Self::ParameterIter {
+        empty()
+    }
+
+    fn predict(&self, input: Self::Input) -> Result<Self::Output> {
+        let (output, _) = self.pool(&input.0)?;
+        Ok(Tensor(output))
+    }
+
+    fn push_layer_summaries(
+        &self,
+        input_shape: Vec<Option<usize>>,
+        summaries: &mut Vec<LayerSummary>,
+    ) -> Vec<Option<usize>> {
+        let (height, width) = match *input_shape.as_slice() {
+            [_, _, Some(height), Some(width)] => self
+                .output_shape((1, self.channels as usize, height, width))
+                .map_or((None, None), |(_, _, height, width)| {
+                    (Some(height), Some(width))
+                }),
+            _ => (None, None),
+        };
+        let output_shape = [None, Some(self.channels as usize), height, width].to_vec();
+        summaries.push(LayerSummary::new(T::NAME, output_shape.clone(), 0));
+        output_shape
+    }
+
+    fn predict_with_layer_outputs(
+        &self,
+        input: Self::Input,
+        outputs: &mut Vec<LayerOutput>,
+    ) -> Result<Self::Output> {
+        let output = self.predict(input)?;
+        outputs.push(LayerOutput::new(T::NAME, &output));
+        Ok(output)
+    }
+}
+
+impl<T, U: OptimiserFactory<()>> WithOptimiser<U> for Operation<T> {
+    type Trainable = trainable::pooling2d::Operation<T>;
+
+    fn with_optimiser(self, _optimiser: U) -> Self::Trainable {
+        trainable::pooling2d::Operation(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layers::{AveragePooling2D, Chain, ImageInput, MaxPooling2D};
+    use crate::operations::UninitialisedOperation;
+
+    #[test]
+    fn test_predict_max() {
+        // Arrange
+        let network = ImageInput::new(1)
+            .chain(MaxPooling2D::new(2, 2))
+            .with_seed(42);
+        let input = Tensor::<rank::Four>::new(
+            (1, 1, 4, 4),
+            [
+                1.0, 2.0, 5.0, 0.0, //
+                3.0, 4.0, 1.0, 1.0, //
+                0.0, 0.0, -1.0, -2.0, //
+                9.0, 0.0, -3.0, -4.0,
+            ],
+        )
+        .unwrap();
+        let expected = Tensor::<rank::Four>::new((1, 1, 2, 2), [4.0, 5.0, 9.0, -1.0]).unwrap();
+
+        // Act
+        let output = network.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_predict_average_overlapping() {
+        // Arrange
+        let network = ImageInput::new(2)
+            .chain(AveragePooling2D::new(2, 1))
+            .with_seed(42);
+        let input = Tensor::<rank::Four>::new(
+            (1, 2, 2, 3),
+            [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 0.0, 0.0, 4.0, 0.0, 0.0, 4.0],
+        )
+        .unwrap();
+        let expected = Tensor::<rank::Four>::new((1, 2, 1, 2), [3.0, 4.0, 0.0, 2.0]).unwrap();
+
+        // Act
+        let output = network.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_predict_failure() {
+        // Arrange
+        let network = ImageInput::new(1)
+            .chain(MaxPooling2D::new(3, 1))
+            .with_seed(42);
+        let input = Tensor::<rank::Four>::new((1, 1, 2, 2), [1.0, 2.0, 3.0, 4.0]).unwrap();
+
+        // Act
+        let result = network.predict(input);
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,169 @@
+use crate::operations::{trainable, InitialisedOperation, WithOptimiser};
+use crate::optimisers::base::OptimiserFactory;
+use crate::private::Sealed;
+use crate::summary::{LayerOutput, LayerSummary};
+use crate::tensors::rank::Batched;
+use crate::tensors::Tensor;
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+use core::iter::{empty, Empty};
+use core::marker::PhantomData;
+use ndarray::{Array, Dimension};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S::Item: serde::Serialize",
+        deserialize = "S::Item: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Operation<R, S: Batched> {
+    pub(crate) shape: S::Item,
+    pub(crate) input_rank: PhantomData<R>,
+}
+
+/// Reshapes the given array into the given shape, which must have the same number of elements.
+pub fn reshape<D: Dimension, E: Dimension>(
+    array: &Array<ElementType, D>,
+    shape: E,
+) -> Result<Array<ElementType, E>> {
+    Error::check_size(shape.size(), array.len())?;
+    let elements = array.iter().copied().collect();
+    Ok(Array::from_shape_vec(shape, elements).unwrap()) // unwrapping is safe because the sizes were checked above
+}
+
+impl<R: Batched, S: Batched> Operation<R, S> {
+    /// Reshapes each item of the given batch into the shape of this layer.
+    pub(crate) fn reshape_items(
+        &self,
+        input: &Array<ElementType, R::Internal>,
+    ) -> Result<Array<ElementType, S::Internal>> {
+        let batch = input.shape()[0];
+        reshape(input, S::with_batch(batch, self.shape))
+    }
+}
+
+impl<R, S: Batched> Sealed for Operation<R, S> {}
+impl<R: Batched, S: Batched> InitialisedOperation for Operation<R, S> {
+    type Input = Tensor<R>;
+    type Output = Tensor<S>;
+    type ParameterIter = Empty<ElementType>;
+
+    fn iter(&self) -> Self::ParameterIter {
+        empty()
+    }
+
+    fn predict(&self, input: Self::Input) -> Result<Self::Output> {
+        Ok(Tensor(self.reshape_items(&input.0)?))
+    }
+
+    fn push_layer_summaries(
+        &self,
+        _input_shape: Vec<Option<usize>>,
+        summaries: &mut Vec<LayerSummary>,
+    ) -> Vec<Option<usize>> {
+        let shape = S::with_batch(1, self.shape);
+        let output_shape = core::iter::once(None)
+            .chain(shape.slice()[1..].iter().copied().map(Some))
+            .collect::<Vec<_>>();
+        summaries.push(LayerSummary::new("Reshape", output_shape.clone(), 0));
+        output_shape
+    }
+
+    fn predict_with_layer_outputs(
+        &self,
+        input: Self::Input,
+        outputs: &mut Vec<LayerOutput>,
+    ) -> Result<Self::Output> {
+        let output = self.predict(input)?;
+        outputs.push(LayerOutput::new("Reshape", &output));
+        Ok(output)
+    }
+}
+
+impl<R, S: Batched, U: OptimiserFactory<()>> WithOptimiser<U> for Operation<R, S> {
+    type Trainable = trainable::reshape::Operation<R, S>;
+
+    fn with_optimiser(self, _optimiser: U) -> Self::Trainable {
+        trainable::reshape::Operation(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::activations::ReLU;
+    use crate::layers::{Chain, Conv2D, Dense, Flatten, ImageInput, Input, Reshape};
+    use crate::operations::{InitialisedOperation, UninitialisedOperation};
+    use crate::tensors::{rank, Tensor};
+    use crate::{ElementType, Error};
+
+    #[test]
+    fn test_predict_flatten() {
+        // Arrange
+        let network = ImageInput::new(2).chain(Flatten::new(8)).with_seed(42);
+        let input =
+            Tensor::<rank::Four>::new((2, 2, 2, 2), (0u8..16).map(ElementType::from)).unwrap();
+        let expected = Tensor::<rank::Two>::new((2, 8), (0u8..16).map(ElementType::from)).unwrap();
+
+        // Act
+        let output = network.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_predict_reshape() {
+        // Arrange
+        let network = Input::new(6).chain(Reshape::new((3, 2))).with_seed(42);
+        let input = Tensor::<rank::Two>::new((1, 6), (0u8..6).map(ElementType::from)).unwrap();
+        let expected =
+            Tensor::<rank::Three>::new((1, 3, 2), (0u8..6).map(ElementType::from)).unwrap();
+
+        // Act
+        let output = network.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_predict_failure() {
+        // Arrange
+        let network = ImageInput::new(1).chain(Flatten::new(8)).with_seed(42);
+        let input =
+            Tensor::<rank::Four>::new((1, 1, 3, 3), (0u8..9).map(ElementType::from)).unwrap();
+
+        // Act
+        let result = network.predict(input);
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(Error::ShapeMismatch {
+                expected: 8,
+                actual: 9
+            })
+        );
+    }
+
+    #[test]
+    fn test_predict_convolution_into_dense() {
+        // Arrange
+        let network = ImageInput::new(1)
+            .chain(Conv2D::new(2, 3, 1, 1, ReLU::new()))
+            .chain(Flatten::new(32))
+            .chain(Dense::new(3, ReLU::new()))
+            .with_seed(42);
+        let input =
+            Tensor::<rank::Four>::new((2, 1, 4, 4), (0u8..32).map(ElementType::from)).unwrap();
+
+        // Act
+        let output = network.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output.dim(), (2, 3));
+    }
+}
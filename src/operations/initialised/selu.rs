@@ -0,0 +1,141 @@
+use crate::operations::initialised::Stage;
+use crate::operations::trainable;
+use crate::operations::{InitialisedOperation, WithOptimiser};
+use crate::optimisers::base::OptimiserFactory;
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+use core::iter::{empty, Empty};
+
+/// The alpha constant of the SELU function, scaling the exponential part for negative inputs.
+#[cfg(not(feature = "f32"))]
+pub const ALPHA: ElementType = 1.673_263_242_354_377_3;
+#[cfg(feature = "f32")]
+pub const ALPHA: ElementType = 1.673_263_2;
+
+/// The scale constant of the SELU function, applied to the entire output.
+#[cfg(not(feature = "f32"))]
+pub const SCALE: ElementType = 1.050_700_987_355_480_5;
+#[cfg(feature = "f32")]
+pub const SCALE: ElementType = 1.050_701;
+
+pub fn selu(elem: ElementType) -> ElementType {
+    if elem > 0.0 {
+        SCALE * elem
+    } else {
+        SCALE * ALPHA * elem.exp_m1()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation {
+    pub(crate) neurons: u16,
+}
+
+impl Sealed for Operation {}
+impl InitialisedOperation for Operation {
+    type Input = Tensor<rank::Two>;
+    type Output = Tensor<rank::Two>;
+    type ParameterIter = Empty<ElementType>;
+
+    fn iter(&self) -> Self::ParameterIter {
+        empty()
+    }
+
+    fn predict(&self, input: Self::Input) -> Result<Self::Output> {
+        if input.0.ncols() == self.neurons as usize {
+            Ok(Tensor(input.0.mapv(selu)))
+        } else {
+            Err(Error::ShapeMismatch {
+                expected: self.neurons as usize,
+                actual: input.0.ncols(),
+            })
+        }
+    }
+
+    fn push_quantization_stages(&self, stages: &mut Vec<Stage>) -> Result<()> {
+        stages.push(Stage::Selu(self.clone()));
+        Ok(())
+    }
+}
+
+impl<T: OptimiserFactory<()>> WithOptimiser<T> for Operation {
+    type Trainable = trainable::selu::Operation;
+
+    fn with_optimiser(self, _optimiser: T) -> Self::Trainable {
+        trainable::selu::Operation {
+            initialised: self,
+            last_output: Tensor::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optimisers::NullOptimiser;
+    use crate::tensors::*;
+
+    #[test]
+    fn test_iter() {
+        // Arrange
+        let operation = Operation { neurons: 42 };
+
+        // Act
+        let iter_count = operation.iter().count();
+
+        // Assert
+        assert_eq!(iter_count, 0);
+    }
+
+    #[test]
+    fn test_predict_success() {
+        // Arrange
+        let operation = Operation { neurons: 3 };
+        let input = Tensor::<rank::Two>::new((2, 3), [-2.0, -1.0, 0.0, 1.0, 2.0, 3.0]).unwrap();
+        let expected = Tensor::<rank::Two>(input.0.mapv(|elem| {
+            if elem > 0.0 {
+                SCALE * elem
+            } else {
+                SCALE * ALPHA * elem.exp_m1()
+            }
+        }));
+
+        // Act
+        let output = operation.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_predict_failure() {
+        // Arrange
+        let operation = Operation { neurons: 2 };
+        let input = Tensor::<rank::Two>::new((2, 3), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+
+        // Act
+        let output = operation.predict(input);
+
+        // Assert
+        assert!(output.is_err());
+    }
+
+    #[test]
+    fn test_with_optimiser() {
+        // Arrange
+        let operation = Operation { neurons: 3 };
+        let expected = trainable::selu::Operation {
+            initialised: Operation { neurons: 3 },
+            last_output: Tensor::default(),
+        };
+
+        // Act
+        let output = operation.with_optimiser(NullOptimiser::new());
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+}
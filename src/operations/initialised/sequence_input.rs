@@ -0,0 +1,110 @@
+use crate::operations::trainable;
+use crate::operations::{InitialisedOperation, WithOptimiser};
+use crate::optimisers::base::OptimiserFactory;
+use crate::private::Sealed;
+use crate::summary::{LayerOutput, LayerSummary};
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+use core::iter::{empty, Empty};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation {
+    pub(crate) features: u16,
+}
+
+impl Sealed for Operation {}
+impl InitialisedOperation for Operation {
+    type Input = Tensor<rank::Three>;
+    type Output = Tensor<rank::Three>;
+    type ParameterIter = Empty<ElementType>;
+
+    fn iter(&self) -> Self::ParameterIter {
+        empty()
+    }
+
+    fn predict(&self, input: Self::Input) -> Result<Self::Output> {
+        if input.0.dim().2 == self.features as usize {
+            Ok(input)
+        } else {
+            Err(Error::ShapeMismatch {
+                expected: self.features as usize,
+                actual: input.0.dim().2,
+            })
+        }
+    }
+
+    fn push_layer_summaries(
+        &self,
+        _input_shape: Vec<Option<usize>>,
+        summaries: &mut Vec<LayerSummary>,
+    ) -> Vec<Option<usize>> {
+        let output_shape = [None, None, Some(self.features as usize)].to_vec();
+        summaries.push(LayerSummary::new("SequenceInput", output_shape.clone(), 0));
+        output_shape
+    }
+
+    fn predict_with_layer_outputs(
+        &self,
+        input: Self::Input,
+        outputs: &mut Vec<LayerOutput>,
+    ) -> Result<Self::Output> {
+        let output = self.predict(input)?;
+        outputs.push(LayerOutput::new("SequenceInput", &output));
+        Ok(output)
+    }
+}
+
+impl<T: OptimiserFactory<()>> WithOptimiser<T> for Operation {
+    type Trainable = trainable::sequence_input::Operation;
+
+    fn with_optimiser(self, _optimiser: T) -> Self::Trainable {
+        trainable::sequence_input::Operation(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optimisers::NullOptimiser;
+
+    #[test]
+    fn test_predict_success() {
+        // Arrange
+        let operation = Operation { features: 2 };
+        let input = Tensor::<rank::Three>::new((1, 2, 2), [1.0, 2.0, 3.0, 4.0]).unwrap();
+
+        // Act
+        let output = operation.predict(input.clone()).unwrap();
+
+        // Assert
+        assert_eq!(input, output);
+    }
+
+    #[test]
+    fn test_predict_failure() {
+        // Arrange
+        let operation = Operation { features: 2 };
+        let input = Tensor::<rank::Three>::new((1, 4, 1), [1.0, 2.0, 3.0, 4.0]).unwrap();
+
+        // Act
+        let output = operation.predict(input);
+
+        // Assert
+        assert!(output.is_err());
+    }
+
+    #[test]
+    fn test_with_optimiser() {
+        // Arrange
+        let operation = Operation { features: 3 };
+        let expected = trainable::sequence_input::Operation(operation.clone());
+
+        // Act
+        let output = operation.with_optimiser(NullOptimiser::new());
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+}
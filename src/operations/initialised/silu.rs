@@ -0,0 +1,125 @@
+use crate::operations::initialised::Stage;
+use crate::operations::trainable;
+use crate::operations::{InitialisedOperation, WithOptimiser};
+use crate::optimisers::base::OptimiserFactory;
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+use core::iter::{empty, Empty};
+
+fn sigmoid(elem: ElementType) -> ElementType {
+    1.0 / (1.0 + (-elem).exp())
+}
+
+pub fn silu(elem: ElementType) -> ElementType {
+    elem * sigmoid(elem)
+}
+
+pub fn silu_derivative(elem: ElementType) -> ElementType {
+    let sigmoid = sigmoid(elem);
+    sigmoid * elem.mul_add(1.0 - sigmoid, 1.0)
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation {
+    pub(crate) neurons: u16,
+}
+
+impl Sealed for Operation {}
+impl InitialisedOperation for Operation {
+    type Input = Tensor<rank::Two>;
+    type Output = Tensor<rank::Two>;
+    type ParameterIter = Empty<ElementType>;
+
+    fn iter(&self) -> Self::ParameterIter {
+        empty()
+    }
+
+    fn predict(&self, input: Self::Input) -> Result<Self::Output> {
+        Error::check_size(self.neurons as usize, input.0.ncols())?;
+        Ok(Tensor(input.0.mapv(silu)))
+    }
+
+    fn push_quantization_stages(&self, stages: &mut Vec<Stage>) -> Result<()> {
+        stages.push(Stage::SiLU(self.clone()));
+        Ok(())
+    }
+}
+
+impl<T: OptimiserFactory<()>> WithOptimiser<T> for Operation {
+    type Trainable = trainable::silu::Operation;
+
+    fn with_optimiser(self, _optimiser: T) -> Self::Trainable {
+        trainable::silu::Operation {
+            initialised: self,
+            last_input: Tensor::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optimisers::NullOptimiser;
+    use crate::tensors::*;
+
+    #[test]
+    fn test_iter() {
+        // Arrange
+        let operation = Operation { neurons: 42 };
+
+        // Act
+        let iter_count = operation.iter().count();
+
+        // Assert
+        assert_eq!(iter_count, 0);
+    }
+
+    #[test]
+    fn test_predict_success() {
+        // Arrange
+        let operation = Operation { neurons: 3 };
+        let input = Tensor::<rank::Two>::new((1, 3), [-3.0, 0.0, 1.0]).unwrap();
+        let expected = [-0.142_277_1, 0.0, 0.731_058_6];
+
+        // Act
+        let output = operation.predict(input).unwrap();
+
+        // Assert
+        output
+            .into_iter()
+            .zip(expected)
+            .for_each(|(output, expected)| assert!((output - expected).abs() < 1e-6));
+    }
+
+    #[test]
+    fn test_predict_failure() {
+        // Arrange
+        let operation = Operation { neurons: 2 };
+        let input = Tensor::<rank::Two>::new((2, 3), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+
+        // Act
+        let output = operation.predict(input);
+
+        // Assert
+        assert!(output.is_err());
+    }
+
+    #[test]
+    fn test_with_optimiser() {
+        // Arrange
+        let operation = Operation { neurons: 3 };
+        let expected = trainable::silu::Operation {
+            initialised: Operation { neurons: 3 },
+            last_input: Tensor::default(),
+        };
+
+        // Act
+        let output = operation.with_optimiser(NullOptimiser::new());
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+}
@@ -0,0 +1,123 @@
+use crate::operations::{initialised, trainable, InitialisedOperation, WithOptimiser};
+use crate::optimisers::base::OptimiserFactory;
+use crate::private::Sealed;
+use crate::summary::{LayerOutput, LayerSummary};
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Result};
+use alloc::vec::Vec;
+use core::iter::{empty, Empty};
+use rand::rngs::StdRng;
+use rand::Rng;
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation(pub(crate) initialised::dropout::Operation);
+
+impl Operation {
+    /// Generates a mask of the given image shape where each channel of each image is either
+    /// kept (all 1) or dropped (all 0), using the keep probability of this operation.
+    pub(crate) fn generate_mask(
+        &self,
+        shape: (usize, usize, usize, usize),
+        generator: &mut impl Rng,
+    ) -> Tensor<rank::Four> {
+        let (batch, channels, _, _) = shape;
+        let mask = self.0.generate_mask((batch, channels), generator);
+        let mask = mask.0.into_shape((batch, channels, 1, 1)).unwrap(); // unwrapping is safe because only unit axes are added
+        Tensor(mask.broadcast(shape).unwrap().to_owned()) // and unit axes can always be broadcast
+    }
+}
+
+impl Sealed for Operation {}
+impl InitialisedOperation for Operation {
+    type Input = Tensor<rank::Four>;
+    type Output = Tensor<rank::Four>;
+    type ParameterIter = Empty<ElementType>;
+
+    fn iter(&self) -> Self::ParameterIter {
+        empty()
+    }
+
+    fn predict(&self, input: Self::Input) -> Result<Self::Output> {
+        Ok(Tensor(input.0 * self.0.inference_scale()))
+    }
+
+    fn predict_stochastic(
+        &self,
+        input: Self::Input,
+        generator: &mut StdRng,
+    ) -> Result<Self::Output> {
+        let mask = self.generate_mask(input.0.dim(), generator);
+        Ok(Tensor(input.0 * mask.0))
+    }
+
+    fn push_layer_summaries(
+        &self,
+        input_shape: Vec<Option<usize>>,
+        summaries: &mut Vec<LayerSummary>,
+    ) -> Vec<Option<usize>> {
+        summaries.push(LayerSummary::new("SpatialDropout", input_shape.clone(), 0));
+        input_shape
+    }
+
+    fn predict_with_layer_outputs(
+        &self,
+        input: Self::Input,
+        outputs: &mut Vec<LayerOutput>,
+    ) -> Result<Self::Output> {
+        let output = self.predict(input)?;
+        outputs.push(LayerOutput::new("SpatialDropout", &output));
+        Ok(output)
+    }
+}
+
+impl<T: OptimiserFactory<()>> WithOptimiser<T> for Operation {
+    type Trainable = trainable::spatial_dropout::Operation;
+
+    fn with_optimiser(self, _optimiser: T) -> Self::Trainable {
+        trainable::spatial_dropout::Operation(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    fn operation() -> Operation {
+        Operation(initialised::dropout::Operation {
+            keep_probability: 0.5,
+            seed: None,
+            inverted: false,
+        })
+    }
+
+    #[test]
+    fn test_predict() {
+        // Arrange
+        let input = Tensor::<rank::Four>::new((1, 2, 1, 2), [1.0, 2.0, 4.0, 8.0]).unwrap();
+        let expected = Tensor::<rank::Four>::new((1, 2, 1, 2), [0.5, 1.0, 2.0, 4.0]).unwrap();
+
+        // Act
+        let output = operation().predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_generate_mask_drops_whole_channels() {
+        // Arrange
+        let mut generator = StdRng::seed_from_u64(42);
+
+        // Act
+        let mask = operation().generate_mask((4, 8, 3, 3), &mut generator);
+
+        // Assert
+        for ((sample, channel, _, _), elem) in mask.0.indexed_iter() {
+            assert_eq!(*elem, mask.0[[sample, channel, 0, 0]]);
+        }
+        assert!(mask.0.iter().any(|elem| *elem == 0.0));
+        assert!(mask.0.iter().any(|elem| *elem == 1.0));
+    }
+}
@@ -0,0 +1,186 @@
+use crate::operations::{trainable, InitialisedOperation, WithOptimiser};
+use crate::private::Sealed;
+use crate::summary::{LayerOutput, LayerSummary};
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation<T> {
+    pub(crate) block: T,
+    pub(crate) drop_probability: ElementType,
+    pub(crate) seed: Option<u64>, // used during forward pass to decide whether to skip the block
+}
+
+impl<T> Sealed for Operation<T> {}
+impl<T: InitialisedOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>>
+    InitialisedOperation for Operation<T>
+{
+    type Input = Tensor<rank::Two>;
+    type Output = Tensor<rank::Two>;
+    type ParameterIter = T::ParameterIter;
+
+    fn iter(&self) -> Self::ParameterIter {
+        self.block.iter()
+    }
+
+    fn predict(&self, input: Self::Input) -> Result<Self::Output> {
+        let output = self.block.predict(input.clone())?;
+        Error::check_shape(input.0.dim(), output.0.dim())?;
+        let keep_probability = 1.0 - self.drop_probability;
+        let output = Tensor(output.0 * keep_probability + input.0 * self.drop_probability);
+        Ok(output)
+    }
+
+    fn predict_stochastic(
+        &self,
+        input: Self::Input,
+        generator: &mut StdRng,
+    ) -> Result<Self::Output> {
+        if generator.gen_range(0.0..1.0) < self.drop_probability {
+            Ok(input)
+        } else {
+            self.block.predict_stochastic(input, generator)
+        }
+    }
+
+    fn map_parameters(&mut self, f: &mut impl FnMut(ElementType) -> ElementType) {
+        self.block.map_parameters(f);
+    }
+
+    fn push_layer_parameters(&self, layers: &mut Vec<((usize, usize), Vec<ElementType>)>) {
+        self.block.push_layer_parameters(layers);
+    }
+
+    fn push_layer_summaries(
+        &self,
+        input_shape: Vec<Option<usize>>,
+        summaries: &mut Vec<LayerSummary>,
+    ) -> Vec<Option<usize>> {
+        // the block must keep the shape of its input so it can be skipped.
+        summaries.push(LayerSummary::new(
+            "StochasticDepth",
+            input_shape.clone(),
+            self.iter().count(),
+        ));
+        input_shape
+    }
+
+    fn predict_with_layer_outputs(
+        &self,
+        input: Self::Input,
+        outputs: &mut Vec<LayerOutput>,
+    ) -> Result<Self::Output> {
+        let output = self.predict(input)?;
+        outputs.push(LayerOutput::new("StochasticDepth", &output));
+        Ok(output)
+    }
+}
+
+impl<T: WithOptimiser<U>, U> WithOptimiser<U> for Operation<T> {
+    type Trainable = trainable::stochastic_depth::Operation<T::Trainable>;
+
+    fn with_optimiser(self, optimiser: U) -> Self::Trainable {
+        Self::Trainable {
+            block: self.block.with_optimiser(optimiser),
+            drop_probability: self.drop_probability,
+            seed: self.seed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::Linear;
+    use crate::layers::{Chain, Dense, Input, StochasticDepth};
+    use crate::operations::UninitialisedOperation;
+    use crate::optimisers::NullOptimiser;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_iter() {
+        // Arrange
+        let operation = Input::new(2)
+            .chain(StochasticDepth::new(Dense::new(2, Linear::new()), 0.5))
+            .with_iter([1.0, 2.0, 3.0, 4.0, 5.0, 6.0].into_iter())
+            .unwrap();
+        let expected = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0].into_iter();
+
+        // Act
+        let output = operation.iter();
+
+        // Assert
+        assert!(output.eq(expected));
+    }
+
+    #[test]
+    fn test_predict() {
+        // Arrange
+        let operation = Input::new(2)
+            .chain(StochasticDepth::new(Dense::new(2, Linear::new()), 0.25))
+            .with_iter([2.0, 0.0, 0.0, 2.0, 0.0, 0.0].into_iter())
+            .unwrap();
+        let input = Tensor::<rank::Two>::new((1, 2), [1.0, 2.0]).unwrap();
+        let expected = Tensor::<rank::Two>::new((1, 2), [1.75, 3.5]).unwrap();
+
+        // Act
+        let output = operation.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_predict_stochastic() {
+        // Arrange
+        let operation = Input::new(1)
+            .chain(StochasticDepth::new(Dense::new(1, Linear::new()), 0.5))
+            .with_iter([2.0, 0.0].into_iter())
+            .unwrap();
+        let input = Tensor::<rank::Two>::new((1, 1), [1.0]).unwrap();
+        let mut generator = StdRng::seed_from_u64(42);
+
+        // Act
+        let outputs = (0..100)
+            .map(|_| {
+                operation
+                    .predict_stochastic(input.clone(), &mut generator)
+                    .unwrap()
+                    .0[[0, 0]]
+            })
+            .collect::<Vec<_>>();
+
+        // Assert
+        assert!(outputs.contains(&1.0));
+        assert!(outputs.contains(&2.0));
+        assert!(outputs
+            .iter()
+            .all(|output| *output == 1.0 || *output == 2.0));
+    }
+
+    #[test]
+    fn test_with_optimiser() {
+        // Arrange
+        let block = Dense::new(2, Linear::new()).with_seed_private(42, 2).0;
+        let operation = Operation {
+            block: block.clone(),
+            drop_probability: 0.5,
+            seed: Some(42),
+        };
+        let expected = trainable::stochastic_depth::Operation {
+            block: block.with_optimiser(NullOptimiser::new()),
+            drop_probability: 0.5,
+            seed: Some(42),
+        };
+
+        // Act
+        let output = operation.with_optimiser(NullOptimiser::new());
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+}
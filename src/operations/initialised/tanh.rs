@@ -1,12 +1,15 @@
+use crate::operations::initialised::Stage;
 use crate::operations::trainable;
 use crate::operations::{InitialisedOperation, WithOptimiser};
 use crate::optimisers::base::OptimiserFactory;
 use crate::private::Sealed;
 use crate::tensors::{rank, Tensor};
 use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
 use core::iter::{empty, Empty};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Operation {
     pub(crate) neurons: u16,
 }
@@ -25,9 +28,17 @@ impl InitialisedOperation for Operation {
         if input.0.ncols() == self.neurons as usize {
             Ok(Tensor(input.0.mapv(ElementType::tanh)))
         } else {
-            Err(Error(()))
+            Err(Error::ShapeMismatch {
+                expected: self.neurons as usize,
+                actual: input.0.ncols(),
+            })
         }
     }
+
+    fn push_quantization_stages(&self, stages: &mut Vec<Stage>) -> Result<()> {
+        stages.push(Stage::Tanh(self.clone()));
+        Ok(())
+    }
 }
 
 impl<T: OptimiserFactory<()>> WithOptimiser<T> for Operation {
@@ -44,6 +55,7 @@ impl<T: OptimiserFactory<()>> WithOptimiser<T> for Operation {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::assert_tensor_approx_eq;
     use crate::optimisers::NullOptimiser;
     use crate::tensors::*;
 
@@ -64,20 +76,6 @@ mod tests {
         // Arrange
         let operation = Operation { neurons: 3 };
         let input = Tensor::<rank::Two>::new((2, 3), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
-        #[cfg(feature = "f32")]
-        let expected = Tensor::<rank::Two>::new(
-            (2, 3),
-            [
-                0.7615941559557649,
-                0.9640275800758169,
-                0.9950547536867305,
-                0.999329299739067,
-                0.9999092042625951,
-                0.9999877116507956,
-            ],
-        )
-        .unwrap();
-        #[cfg(not(feature = "f32"))]
         let expected = Tensor::<rank::Two>::new(
             (2, 3),
             [
@@ -95,7 +93,7 @@ mod tests {
         let output = operation.predict(input).unwrap();
 
         // Assert
-        assert_eq!(output, expected);
+        assert_tensor_approx_eq!(output, expected);
     }
 
     #[test]
@@ -0,0 +1,230 @@
+use crate::operations::uninitialised::upsample2d::Interpolation;
+use crate::operations::{trainable, InitialisedOperation, WithOptimiser};
+use crate::optimisers::base::OptimiserFactory;
+use crate::private::Sealed;
+use crate::summary::{LayerOutput, LayerSummary};
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+use core::iter::{empty, Empty};
+use ndarray::{Array, Ix4};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation {
+    pub(crate) channels: u16,
+    pub(crate) scale: usize,
+    pub(crate) interpolation: Interpolation,
+}
+
+impl Operation {
+    const fn output_shape(
+        &self,
+        (batch, channels, height, width): (usize, usize, usize, usize),
+    ) -> Result<(usize, usize, usize, usize)> {
+        if channels != self.channels as usize {
+            Err(Error::ShapeMismatch {
+                expected: self.channels as usize,
+                actual: channels,
+            })
+        } else if self.scale == 0 {
+            Err(Error::InvalidArgument)
+        } else {
+            Ok((batch, channels, height * self.scale, width * self.scale))
+        }
+    }
+
+    /// Calculates, for each pixel along an upsampled axis, the two pixels of the original axis of the
+    /// given size that it's interpolated between and the weight given to the second of them.
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )] // images are never large enough to lose precision, and the source position is never negative
+    fn sources(&self, size: usize) -> Vec<(usize, usize, ElementType)> {
+        let last = size.saturating_sub(1);
+        (0..size * self.scale)
+            .map(|index| match self.interpolation {
+                Interpolation::Nearest => (index / self.scale, index / self.scale, 0.0),
+                Interpolation::Bilinear => {
+                    let source =
+                        ((index as ElementType + 0.5) / self.scale as ElementType - 0.5).max(0.0);
+                    let first = (source as usize).min(last);
+                    (first, (first + 1).min(last), source - first as ElementType)
+                }
+            })
+            .collect()
+    }
+
+    /// Upsamples each channel of the input.
+    pub(crate) fn upsample(
+        &self,
+        input: &Array<ElementType, Ix4>,
+    ) -> Result<Array<ElementType, Ix4>> {
+        let (_, _, height, width) = input.dim();
+        let (rows, columns) = (self.sources(height), self.sources(width));
+        let output = Array::from_shape_fn(self.output_shape(input.dim())?, |(n, c, y, x)| {
+            let ((top, bottom, vertical), (left, right, horizontal)) = (rows[y], columns[x]);
+            let upper = input[[n, c, top, right]]
+                .mul_add(horizontal, input[[n, c, top, left]] * (1.0 - horizontal));
+            let lower = input[[n, c, bottom, right]]
+                .mul_add(horizontal, input[[n, c, bottom, left]] * (1.0 - horizontal));
+            lower.mul_add(vertical, upper * (1.0 - vertical))
+        });
+        Ok(output)
+    }
+
+    /// Routes the gradient of the upsampled image back to the pixels of an input with the given shape,
+    /// in proportion to how much each pixel contributed to each upsampled pixel.
+    pub(crate) fn route(
+        &self,
+        gradient: &Array<ElementType, Ix4>,
+        input_shape: (usize, usize, usize, usize),
+    ) -> Result<Array<ElementType, Ix4>> {
+        let output_shape = self.output_shape(input_shape)?;
+        if gradient.dim() != output_shape {
+            return Err(Error::ShapeMismatch {
+                expected: output_shape.0 * output_shape.1 * output_shape.2 * output_shape.3,
+                actual: gradient.len(),
+            });
+        }
+        let (_, _, height, width) = input_shape;
+        let (rows, columns) = (self.sources(height), self.sources(width));
+        let mut input_gradient = Array::zeros(input_shape);
+        for ((n, c, y, x), elem) in gradient.indexed_iter() {
+            let ((top, bottom, vertical), (left, right, horizontal)) = (rows[y], columns[x]);
+            input_gradient[[n, c, top, left]] += elem * (1.0 - vertical) * (1.0 - horizontal);
+            input_gradient[[n, c, top, right]] += elem * (1.0 - vertical) * horizontal;
+            input_gradient[[n, c, bottom, left]] += elem * vertical * (1.0 - horizontal);
+            input_gradient[[n, c, bottom, right]] += elem * vertical * horizontal;
+        }
+        Ok(input_gradient)
+    }
+}
+
+impl Sealed for Operation {}
+impl InitialisedOperation for Operation {
+    type Input = Tensor<rank::Four>;
+    type Output = Tensor<rank::Four>;
+    type ParameterIter = Empty<ElementType>;
+
+    fn iter(&self) -> Self::ParameterIter {
+        empty()
+    }
+
+    fn predict(&self, input: Self::Input) -> Result<Self::Output> {
+        Ok(Tensor(self.upsample(&input.0)?))
+    }
+
+    fn push_layer_summaries(
+        &self,
+        input_shape: Vec<Option<usize>>,
+        summaries: &mut Vec<LayerSummary>,
+    ) -> Vec<Option<usize>> {
+        let (height, width) = match *input_shape.as_slice() {
+            [_, _, height, width] => (
+                height.map(|height| height * self.scale),
+                width.map(|width| width * self.scale),
+            ),
+            _ => (None, None),
+        };
+        let output_shape = [None, Some(self.channels as usize), height, width].to_vec();
+        summaries.push(LayerSummary::new("Upsample2D", output_shape.clone(), 0));
+        output_shape
+    }
+
+    fn predict_with_layer_outputs(
+        &self,
+        input: Self::Input,
+        outputs: &mut Vec<LayerOutput>,
+    ) -> Result<Self::Output> {
+        let output = self.predict(input)?;
+        outputs.push(LayerOutput::new("Upsample2D", &output));
+        Ok(output)
+    }
+}
+
+impl<T: OptimiserFactory<()>> WithOptimiser<T> for Operation {
+    type Trainable = trainable::upsample2d::Operation;
+
+    fn with_optimiser(self, _optimiser: T) -> Self::Trainable {
+        trainable::upsample2d::Operation(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layers::{Chain, ImageInput, Upsample2D};
+    use crate::operations::UninitialisedOperation;
+
+    #[test]
+    fn test_predict_nearest() {
+        // Arrange
+        let network = ImageInput::new(1)
+            .chain(Upsample2D::new(2, Interpolation::Nearest))
+            .with_seed(42);
+        let input = Tensor::<rank::Four>::new((1, 1, 1, 2), [1.0, 2.0]).unwrap();
+        let expected =
+            Tensor::<rank::Four>::new((1, 1, 2, 4), [1.0, 1.0, 2.0, 2.0, 1.0, 1.0, 2.0, 2.0])
+                .unwrap();
+
+        // Act
+        let output = network.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_predict_bilinear() {
+        // Arrange
+        let network = ImageInput::new(1)
+            .chain(Upsample2D::new(2, Interpolation::Bilinear))
+            .with_seed(42);
+        let input = Tensor::<rank::Four>::new((1, 1, 1, 2), [0.0, 1.0]).unwrap();
+        let expected =
+            Tensor::<rank::Four>::new((1, 1, 2, 4), [0.0, 0.25, 0.75, 1.0, 0.0, 0.25, 0.75, 1.0])
+                .unwrap();
+
+        // Act
+        let output = network.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_predict_failure() {
+        // Arrange
+        let network = ImageInput::new(1)
+            .chain(Upsample2D::new(0, Interpolation::Nearest))
+            .with_seed(42);
+        let input = Tensor::<rank::Four>::new((1, 1, 1, 2), [0.0, 1.0]).unwrap();
+
+        // Act
+        let result = network.predict(input);
+
+        // Assert
+        assert_eq!(result, Err(Error::InvalidArgument));
+    }
+
+    #[test]
+    fn test_summary() {
+        // Arrange
+        let operation = Operation {
+            channels: 3,
+            scale: 2,
+            interpolation: Interpolation::Nearest,
+        };
+        let mut summaries = Vec::new();
+
+        // Act
+        let output_shape =
+            operation.push_layer_summaries([None, Some(3), Some(4), None].to_vec(), &mut summaries);
+
+        // Assert
+        assert_eq!(output_shape, [None, Some(3), Some(8), None]);
+        assert_eq!(summaries[0].name(), "Upsample2D");
+    }
+}
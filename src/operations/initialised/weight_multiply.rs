@@ -2,9 +2,10 @@ use crate::operations::{initialised, trainable, WithOptimiser};
 use crate::optimisers::base::OptimiserFactory;
 use crate::private::Sealed;
 use crate::tensors::{rank, Tensor, TensorIterator};
-use crate::{Error, Result};
+use crate::{ElementType, Error, Result};
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Operation {
     pub(crate) input_neurons: u16,
     pub(crate) parameter: Tensor<rank::Two>,
@@ -24,9 +25,19 @@ impl initialised::Operation for Operation {
         if input.0.ncols() == self.input_neurons as usize {
             Ok(Tensor(input.0.dot(&self.parameter.0)))
         } else {
-            Err(Error(()))
+            Err(Error::ShapeMismatch {
+                expected: self.input_neurons as usize,
+                actual: input.0.ncols(),
+            })
         }
     }
+
+    fn map_parameters(&mut self, f: &mut impl FnMut(ElementType) -> ElementType) {
+        self.parameter
+            .0
+            .iter_mut()
+            .for_each(|elem| *elem = f(*elem));
+    }
 }
 
 impl<T: OptimiserFactory<Tensor<rank::Two>>> WithOptimiser<T> for Operation {
@@ -117,7 +128,7 @@ mod tests {
         };
         let operation = Operation {
             input_neurons: 1,
-            parameter: parameter,
+            parameter,
         };
 
         // Act
@@ -13,6 +13,7 @@ pub use backward::Operation as BackwardOperation;
 pub use forward::Forward;
 pub use forward::Operation as ForwardOperation;
 pub use initialised::Operation as InitialisedOperation;
+pub(crate) use initialised::Stage;
 pub use initialised::WithOptimiser;
 pub use trainable::Operation as TrainableOperation;
 pub use uninitialised::Operation as UninitialisedOperation;
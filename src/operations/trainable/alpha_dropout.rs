@@ -0,0 +1,69 @@
+use crate::operations::{forward, initialised, trainable};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::Result;
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation(pub(crate) initialised::alpha_dropout::Operation);
+
+impl Sealed for Operation {}
+impl trainable::Operation for Operation {
+    type Initialised = initialised::alpha_dropout::Operation;
+
+    fn into_initialised(self) -> Self::Initialised {
+        self.0
+    }
+
+    fn init(&mut self, _epochs: u32) {}
+
+    fn end_epoch(&mut self) {}
+
+    fn end_batch(&mut self) {}
+
+    fn reseed(&mut self, seed: u64) {
+        self.0 .0.seed = Some(seed);
+    }
+}
+
+impl<'a> forward::Forward<'a> for Operation {
+    type Input = Tensor<rank::Two>;
+    type Output = Tensor<rank::Two>;
+    type Forward = forward::alpha_dropout::Operation<'a>;
+
+    fn forward(&'a mut self, input: Self::Input) -> Result<(Self::Forward, Self::Output)> {
+        let mut random = self.0 .0.generator();
+        let mask = self.0 .0.generate_mask(input.0.dim(), &mut random);
+        let output = Tensor(self.0.drop_out(input.0, &mask.0));
+        let forward = forward::alpha_dropout::Operation { borrow: self, mask };
+        Ok((forward, output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::{Forward, TrainableOperation};
+
+    #[test]
+    fn test_forward() {
+        // Arrange
+        let mut trainable = Operation(initialised::alpha_dropout::Operation(
+            initialised::dropout::Operation {
+                keep_probability: 0.5,
+                seed: Some(42),
+                inverted: false,
+            },
+        ));
+        let input = Tensor::<rank::Two>::new((2, 3), [1.0; 6]).unwrap();
+
+        // Act
+        let (forward, output) = trainable.forward(input.clone()).unwrap();
+        let expected = forward.borrow.0.drop_out(input.0, &forward.mask.0);
+
+        // Assert
+        assert_eq!(output.0, expected);
+        drop(forward);
+        assert_eq!(trainable.into_initialised().0.seed, Some(43));
+    }
+}
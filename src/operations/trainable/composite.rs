@@ -1,8 +1,13 @@
+use crate::loss::Loss;
 use crate::operations::{forward, initialised, Forward, ForwardOperation, TrainableOperation};
 use crate::private::Sealed;
-use crate::Result;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+use ndarray::Array;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Operation<T, U> {
     pub(crate) lhs: T,
     pub(crate) rhs: U,
@@ -25,7 +30,7 @@ where
         Self::Initialised { lhs, rhs }
     }
 
-    fn init(&mut self, epochs: u16) {
+    fn init(&mut self, epochs: u32) {
         self.lhs.init(epochs);
         self.rhs.init(epochs);
     }
@@ -34,6 +39,31 @@ where
         self.lhs.end_epoch();
         self.rhs.end_epoch();
     }
+
+    fn end_batch(&mut self) {
+        self.lhs.end_batch();
+        self.rhs.end_batch();
+    }
+
+    fn observe_loss(&mut self, loss: ElementType) {
+        self.lhs.observe_loss(loss);
+        self.rhs.observe_loss(loss);
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.lhs.reseed(seed);
+        self.rhs.reseed(seed + 1);
+    }
+
+    fn learning_rate(&self) -> Option<ElementType> {
+        self.lhs
+            .learning_rate()
+            .or_else(|| self.rhs.learning_rate())
+    }
+
+    fn penalty(&self) -> ElementType {
+        self.lhs.penalty() + self.rhs.penalty()
+    }
 }
 
 impl<'a, T, U> Forward<'a> for Operation<T, U>
@@ -59,10 +89,66 @@ where
     }
 }
 
+impl<T, U> Operation<T, U>
+where
+    Self: for<'a> Forward<'a, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+    for<'a> <Self as Forward<'a>>::Forward: ForwardOperation<Input = Tensor<rank::Two>>,
+{
+    /// Calculates the partial derivative of the given output neuron with respect to the
+    /// given input feature for a single observation, holding all other features fixed.
+    /// This runs a forward and backward pass through the network but doesn't apply any
+    /// gradients, so the parameters are left unchanged.
+    ///
+    /// # Errors
+    /// `Error` if the input isn't a single row, or if either the output neuron or feature
+    /// are out of range.
+    pub fn feature_attribution(
+        &mut self,
+        input: Tensor<rank::Two>,
+        output_neuron: usize,
+        feature: usize,
+    ) -> Result<ElementType> {
+        if input.0.nrows() != 1 || feature >= input.0.ncols() {
+            return Err(Error::InvalidArgument);
+        }
+        let (forward, output) = self.forward(input)?;
+        if output_neuron >= output.0.ncols() {
+            return Err(Error::InvalidArgument);
+        }
+        let mut output_gradient = Array::zeros(output.0.raw_dim());
+        output_gradient[[0, output_neuron]] = 1.0;
+        let (_, input_gradient) = forward.backward(Tensor(output_gradient))?;
+        Ok(input_gradient.0[[0, feature]])
+    }
+
+    /// Runs a single forward and backward pass through the network for the given input
+    /// and targets, returning the L2 norm of the gradient entering each layer (the gradient
+    /// of the loss with respect to the layer's output), in the order the layers were chained.
+    /// This can be used to spot where gradients vanish or explode. No gradients are applied,
+    /// so the parameters are left unchanged.
+    ///
+    /// # Errors
+    /// `Error` if the input or targets are incorrectly shaped for the network.
+    pub fn gradient_flow(
+        &mut self,
+        input: Tensor<rank::Two>,
+        loss_function: &impl Loss,
+        targets: &Tensor<rank::Two>,
+    ) -> Result<Vec<ElementType>> {
+        let (forward, output) = self.forward(input)?;
+        let (_, loss_gradient) = loss_function.loss(&output, targets)?;
+        let mut norms = Vec::new();
+        forward.backward_recording(loss_gradient, &mut norms)?;
+        norms.reverse();
+        Ok(norms)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::activations::{ReLU, Sigmoid};
+    use crate::activations::{Linear, ReLU, Sigmoid};
     use crate::layers::{Chain, Dense, Input};
+    use crate::loss::MeanSquaredError;
     use crate::operations::{Forward, TrainableOperation, UninitialisedOperation, WithOptimiser};
     use crate::optimisers::NullOptimiser;
     use crate::tensors::{rank, Tensor};
@@ -123,4 +209,84 @@ mod tests {
         // Assert
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_feature_attribution() {
+        // Arrange
+        let mut operation = Input::new(2)
+            .chain(Dense::new(2, Linear::new()))
+            .chain(Dense::new(1, Linear::new()))
+            .with_iter([1.0, 2.0, 3.0, 4.0, 0.5, 0.5, 5.0, 6.0, 1.0].into_iter())
+            .unwrap()
+            .with_optimiser(NullOptimiser::new());
+        let input = Tensor::<rank::Two>::new((1, 2), [7.0, 8.0]).unwrap();
+
+        // Act
+        let first = operation.feature_attribution(input.clone(), 0, 0).unwrap();
+        let second = operation.feature_attribution(input, 0, 1).unwrap();
+
+        // Assert (sum over hidden neurons of the products of the weights along each path)
+        assert_eq!(first, 17.0);
+        assert_eq!(second, 39.0);
+    }
+
+    #[test]
+    fn test_feature_attribution_failure() {
+        // Arrange
+        let mut operation = Input::new(2)
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(NullOptimiser::new());
+        let single = Tensor::<rank::Two>::new((1, 2), [1.0, 2.0]).unwrap();
+        let batch = Tensor::<rank::Two>::new((2, 2), [1.0, 2.0, 3.0, 4.0]).unwrap();
+
+        // Act
+        let batch_result = operation.feature_attribution(batch, 0, 0);
+        let output_result = operation.feature_attribution(single.clone(), 1, 0);
+        let feature_result = operation.feature_attribution(single, 0, 2);
+
+        // Assert
+        assert!(batch_result.is_err());
+        assert!(output_result.is_err());
+        assert!(feature_result.is_err());
+    }
+
+    #[test]
+    fn test_gradient_flow() {
+        // Arrange
+        let mut operation = Input::new(1)
+            .chain(Dense::new(2, Linear::new()))
+            .chain(Dense::new(1, Linear::new()))
+            .with_iter([1.0, 2.0, 0.0, 0.0, 3.0, 4.0, 0.0].into_iter())
+            .unwrap()
+            .with_optimiser(NullOptimiser::new());
+        let input = Tensor::<rank::Two>::new((1, 1), [1.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((1, 1), [1.0]).unwrap();
+        let expected = [220.0, 100.0, 20.0];
+
+        // Act
+        let output = operation
+            .gradient_flow(input, &MeanSquaredError::new(), &targets)
+            .unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_gradient_flow_failure() {
+        // Arrange
+        let mut operation = Input::new(1)
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(NullOptimiser::new());
+        let input = Tensor::<rank::Two>::new((1, 1), [1.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((1, 2), [1.0, 2.0]).unwrap();
+
+        // Act
+        let result = operation.gradient_flow(input, &MeanSquaredError::new(), &targets);
+
+        // Assert
+        assert!(result.is_err());
+    }
 }
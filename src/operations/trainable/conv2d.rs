@@ -0,0 +1,116 @@
+use crate::operations::initialised::conv2d::Window;
+use crate::operations::{forward, initialised, Forward, ForwardOperation, TrainableOperation};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Result};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation<T> {
+    pub(crate) window: Window,
+    pub(crate) dense: T,
+}
+
+impl<T> Sealed for Operation<T> {}
+impl<T: TrainableOperation> TrainableOperation for Operation<T> {
+    type Initialised = initialised::conv2d::Operation<T::Initialised>;
+
+    fn into_initialised(self) -> Self::Initialised {
+        Self::Initialised {
+            window: self.window,
+            dense: self.dense.into_initialised(),
+        }
+    }
+
+    fn init(&mut self, epochs: u32) {
+        self.dense.init(epochs);
+    }
+
+    fn end_epoch(&mut self) {
+        self.dense.end_epoch();
+    }
+
+    fn end_batch(&mut self) {
+        self.dense.end_batch();
+    }
+
+    fn observe_loss(&mut self, loss: ElementType) {
+        self.dense.observe_loss(loss);
+    }
+
+    fn learning_rate(&self) -> Option<ElementType> {
+        self.dense.learning_rate()
+    }
+
+    fn penalty(&self) -> ElementType {
+        self.dense.penalty()
+    }
+}
+
+impl<'a, T> Forward<'a> for Operation<T>
+where
+    T: Forward<'a, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+    <T as Forward<'a>>::Forward: ForwardOperation<Input = Tensor<rank::Two>>,
+{
+    type Input = Tensor<rank::Four>;
+    type Output = Tensor<rank::Four>;
+    type Forward = forward::conv2d::Operation<<T as Forward<'a>>::Forward>;
+
+    fn forward(&'a mut self, input: Self::Input) -> Result<(Self::Forward, Self::Output)> {
+        let (window, input_shape) = (self.window, input.0.dim());
+        let columns = window.columns(&input.0)?;
+        let (dense, output) = self.dense.forward(Tensor(columns))?;
+        let output = window.feature_maps(output.0, input_shape)?;
+        let forward = forward::conv2d::Operation {
+            window,
+            input_shape,
+            dense,
+        };
+        Ok((forward, Tensor(output)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::ReLU;
+    use crate::layers::{Chain, Conv2D, ImageInput};
+    use crate::operations::{InitialisedOperation, UninitialisedOperation, WithOptimiser};
+    use crate::optimisers::NullOptimiser;
+
+    #[test]
+    fn test_into_initialised() {
+        // Arrange
+        let initialised = ImageInput::new(1)
+            .chain(Conv2D::new(2, 2, 1, 0, ReLU::new()))
+            .with_seed(42);
+        let expected = initialised.clone();
+
+        // Act
+        let output = initialised
+            .with_optimiser(NullOptimiser::new())
+            .into_initialised();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_forward_matches_predict() {
+        // Arrange
+        let initialised = ImageInput::new(2)
+            .chain(Conv2D::new(3, 2, 1, 1, ReLU::new()))
+            .with_seed(42);
+        let input =
+            Tensor::<rank::Four>::new((2, 2, 3, 3), (0u8..36).map(crate::ElementType::from))
+                .unwrap();
+        let expected = initialised.predict(input.clone()).unwrap();
+        let mut trainable = initialised.with_optimiser(NullOptimiser::new());
+
+        // Act
+        let (_, output) = trainable.forward(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+}
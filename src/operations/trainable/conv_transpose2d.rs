@@ -0,0 +1,150 @@
+use crate::operations::initialised::conv2d::Window;
+use crate::operations::{forward, initialised, Forward, ForwardOperation, TrainableOperation};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Result};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation<T, U, V> {
+    pub(crate) window: Window,
+    pub(crate) weight_multiply: T,
+    pub(crate) bias_add: U,
+    pub(crate) activation_function: V,
+}
+
+impl<T, U, V> Sealed for Operation<T, U, V> {}
+impl<
+        T: TrainableOperation<Initialised = initialised::weight_multiply::Operation>,
+        U: TrainableOperation<Initialised = initialised::bias_add::Operation>,
+        V: TrainableOperation,
+    > TrainableOperation for Operation<T, U, V>
+{
+    type Initialised = initialised::conv_transpose2d::Operation<V::Initialised>;
+
+    fn into_initialised(self) -> Self::Initialised {
+        Self::Initialised {
+            window: self.window,
+            weight_multiply: self.weight_multiply.into_initialised(),
+            bias_add: self.bias_add.into_initialised(),
+            activation_function: self.activation_function.into_initialised(),
+        }
+    }
+
+    fn init(&mut self, epochs: u32) {
+        self.weight_multiply.init(epochs);
+        self.bias_add.init(epochs);
+        self.activation_function.init(epochs);
+    }
+
+    fn end_epoch(&mut self) {
+        self.weight_multiply.end_epoch();
+        self.bias_add.end_epoch();
+        self.activation_function.end_epoch();
+    }
+
+    fn end_batch(&mut self) {
+        self.weight_multiply.end_batch();
+        self.bias_add.end_batch();
+        self.activation_function.end_batch();
+    }
+
+    fn observe_loss(&mut self, loss: ElementType) {
+        self.weight_multiply.observe_loss(loss);
+        self.bias_add.observe_loss(loss);
+        self.activation_function.observe_loss(loss);
+    }
+
+    fn learning_rate(&self) -> Option<ElementType> {
+        self.weight_multiply.learning_rate()
+    }
+
+    fn penalty(&self) -> ElementType {
+        self.weight_multiply.penalty() + self.bias_add.penalty()
+    }
+}
+
+impl<'a, T, U, V> Forward<'a> for Operation<T, U, V>
+where
+    T: Forward<'a, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>
+        + TrainableOperation<Initialised = initialised::weight_multiply::Operation>,
+    U: Forward<'a, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>
+        + TrainableOperation<Initialised = initialised::bias_add::Operation>,
+    V: Forward<'a, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>> + TrainableOperation,
+    <T as Forward<'a>>::Forward: ForwardOperation<Input = Tensor<rank::Two>>,
+    <U as Forward<'a>>::Forward: ForwardOperation<Input = Tensor<rank::Two>>,
+    <V as Forward<'a>>::Forward: ForwardOperation<Input = Tensor<rank::Two>>,
+{
+    type Input = Tensor<rank::Four>;
+    type Output = Tensor<rank::Four>;
+    type Forward = forward::conv_transpose2d::Operation<
+        <T as Forward<'a>>::Forward,
+        <U as Forward<'a>>::Forward,
+        <V as Forward<'a>>::Forward,
+    >;
+
+    fn forward(&'a mut self, input: Self::Input) -> Result<(Self::Forward, Self::Output)> {
+        let window = self.window;
+        let output_shape = window.transposed_shape(input.0.dim())?;
+        let pixels = window.feature_rows(&input.0, output_shape)?;
+        let (weight_multiply, patches) = self.weight_multiply.forward(Tensor(pixels))?;
+        let image = window.image(&patches.0, output_shape)?;
+        let pixels = window.pointwise().feature_rows(&image, output_shape)?;
+        let (bias_add, pixels) = self.bias_add.forward(Tensor(pixels))?;
+        let (activation_function, pixels) = self.activation_function.forward(pixels)?;
+        let output = window.pointwise().feature_maps(pixels.0, output_shape)?;
+        let forward = forward::conv_transpose2d::Operation {
+            window,
+            output_shape,
+            weight_multiply,
+            bias_add,
+            activation_function,
+        };
+        Ok((forward, Tensor(output)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::ReLU;
+    use crate::layers::{Chain, ConvTranspose2D, ImageInput};
+    use crate::operations::{InitialisedOperation, UninitialisedOperation, WithOptimiser};
+    use crate::optimisers::NullOptimiser;
+
+    #[test]
+    fn test_into_initialised() {
+        // Arrange
+        let initialised = ImageInput::new(1)
+            .chain(ConvTranspose2D::new(2, 2, 1, 0, ReLU::new()))
+            .with_seed(42);
+        let expected = initialised.clone();
+
+        // Act
+        let output = initialised
+            .with_optimiser(NullOptimiser::new())
+            .into_initialised();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_forward_matches_predict() {
+        // Arrange
+        let initialised = ImageInput::new(2)
+            .chain(ConvTranspose2D::new(3, 3, 2, 1, ReLU::new()))
+            .with_seed(42);
+        let input =
+            Tensor::<rank::Four>::new((2, 2, 2, 3), (0u8..24).map(crate::ElementType::from))
+                .unwrap();
+        let expected = initialised.predict(input.clone()).unwrap();
+        let mut trainable = initialised.with_optimiser(NullOptimiser::new());
+
+        // Act
+        let (_, output) = trainable.forward(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+}
@@ -0,0 +1,118 @@
+use crate::operations::uninitialised::custom::CustomLayer;
+use crate::operations::{forward, initialised, TrainableOperation};
+use crate::optimisers::base::Optimiser;
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Result};
+use alloc::vec::Vec;
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation<L, T> {
+    pub(crate) optimiser: T,
+    pub(crate) initialised: initialised::custom::Operation<L>,
+    pub(crate) last_input: Tensor<rank::Two>,
+    pub(crate) saved: Vec<Tensor<rank::Two>>,
+}
+
+impl<L, T> Sealed for Operation<L, T> {}
+impl<L: CustomLayer, T: Optimiser<Tensor<rank::One>>> TrainableOperation for Operation<L, T> {
+    type Initialised = initialised::custom::Operation<L>;
+
+    fn into_initialised(self) -> Self::Initialised {
+        self.initialised
+    }
+
+    fn init(&mut self, epochs: u32) {
+        self.optimiser.init(epochs);
+    }
+
+    fn end_epoch(&mut self) {
+        self.optimiser.end_epoch();
+    }
+
+    fn end_batch(&mut self) {
+        self.optimiser.end_batch();
+    }
+
+    fn observe_loss(&mut self, loss: ElementType) {
+        self.optimiser.observe_loss(loss);
+    }
+
+    fn learning_rate(&self) -> Option<ElementType> {
+        self.optimiser.learning_rate()
+    }
+
+    fn penalty(&self) -> ElementType {
+        self.optimiser.penalty(&self.initialised.parameters)
+    }
+}
+
+impl<'a, L: 'a + CustomLayer, T: 'a + Optimiser<Tensor<rank::One>>> forward::Forward<'a>
+    for Operation<L, T>
+{
+    type Input = Tensor<rank::Two>;
+    type Output = Tensor<rank::Two>;
+    type Forward = forward::custom::Operation<'a, L, T>;
+
+    fn forward(&'a mut self, input: Self::Input) -> Result<(Self::Forward, Self::Output)> {
+        self.saved.clear();
+        let initialised = &self.initialised;
+        let output =
+            initialised
+                .layer
+                .forward_saving(initialised.parameters(), &input, &mut self.saved)?;
+        self.last_input = input;
+        let forward = forward::custom::Operation { borrow: self };
+        Ok((forward, output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::uninitialised::custom::tests::Scale;
+    use crate::operations::Forward;
+    use crate::optimisers::base::OptimiserFactory;
+    use crate::optimisers::NullOptimiser;
+
+    fn operation() -> Operation<Scale, crate::optimisers::null::Optimiser> {
+        Operation {
+            optimiser: <NullOptimiser as OptimiserFactory<()>>::instantiate(&NullOptimiser::new()),
+            initialised: initialised::custom::Operation {
+                layer: Scale,
+                parameters: Tensor::<rank::One>::new([2.0, -1.0]),
+            },
+            last_input: Tensor::default(),
+            saved: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_into_initialised() {
+        // Arrange
+        let operation = operation();
+        let expected = operation.initialised.clone();
+
+        // Act
+        let initialised = operation.into_initialised();
+
+        // Assert
+        assert_eq!(initialised, expected);
+    }
+
+    #[test]
+    fn test_forward() {
+        // Arrange
+        let mut operation = operation();
+        let input = Tensor::<rank::Two>::new((1, 2), [3.0, 4.0]).unwrap();
+        let expected = Tensor::<rank::Two>::new((1, 2), [6.0, -4.0]).unwrap();
+
+        // Act
+        let (_, output) = operation.forward(input.clone()).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+        assert_eq!(operation.last_input, input);
+    }
+}
@@ -1,8 +1,9 @@
 use crate::operations::{forward, initialised, Forward, ForwardOperation, TrainableOperation};
 use crate::private::Sealed;
-use crate::Result;
+use crate::{ElementType, Result};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Operation<T, U, V> {
     pub(crate) weight_multiply: T,
     pub(crate) bias_add: U,
@@ -29,7 +30,7 @@ impl<
         }
     }
 
-    fn init(&mut self, epochs: u16) {
+    fn init(&mut self, epochs: u32) {
         self.weight_multiply.init(epochs);
         self.bias_add.init(epochs);
         self.activation_function.init(epochs);
@@ -40,6 +41,26 @@ impl<
         self.bias_add.end_epoch();
         self.activation_function.end_epoch();
     }
+
+    fn end_batch(&mut self) {
+        self.weight_multiply.end_batch();
+        self.bias_add.end_batch();
+        self.activation_function.end_batch();
+    }
+
+    fn observe_loss(&mut self, loss: ElementType) {
+        self.weight_multiply.observe_loss(loss);
+        self.bias_add.observe_loss(loss);
+        self.activation_function.observe_loss(loss);
+    }
+
+    fn learning_rate(&self) -> Option<ElementType> {
+        self.weight_multiply.learning_rate()
+    }
+
+    fn penalty(&self) -> ElementType {
+        self.weight_multiply.penalty() + self.bias_add.penalty()
+    }
 }
 
 impl<
@@ -106,14 +127,14 @@ mod tests {
                     input_neurons: 1,
                     parameter: Tensor::<rank::Two>::new((1, 3), [1.0, 2.0, 3.0]).unwrap(),
                 },
-                optimiser: <NullOptimiser as OptimiserFactory<f64>>::instantiate(&factory),
+                optimiser: <NullOptimiser as OptimiserFactory<f64>>::instantiate(factory),
                 last_input: Tensor::default(),
             },
             bias_add: trainable::bias_add::Operation {
                 initialised: initialised::bias_add::Operation {
                     parameter: Tensor::<rank::Two>::new((1, 3), [1.0, 2.0, 3.0]).unwrap(),
                 },
-                optimiser: <NullOptimiser as OptimiserFactory<f64>>::instantiate(&factory),
+                optimiser: <NullOptimiser as OptimiserFactory<f64>>::instantiate(factory),
                 last_input: Tensor::default(),
             },
             activation_function: trainable::sigmoid::Operation {
@@ -0,0 +1,210 @@
+use crate::operations::initialised::conv2d::Window;
+use crate::operations::{forward, initialised, Forward, ForwardOperation, TrainableOperation};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Result};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation<T, U, V> {
+    pub(crate) window: Window,
+    pub(crate) depthwise_multiply: T,
+    pub(crate) bias_add: U,
+    pub(crate) activation_function: V,
+}
+
+impl<T, U, V> Sealed for Operation<T, U, V> {}
+impl<
+        T: TrainableOperation<Initialised = initialised::depthwise_multiply::Operation>,
+        U: TrainableOperation<Initialised = initialised::bias_add::Operation>,
+        V: TrainableOperation,
+    > TrainableOperation for Operation<T, U, V>
+{
+    type Initialised = initialised::depthwise_conv2d::Operation<V::Initialised>;
+
+    fn into_initialised(self) -> Self::Initialised {
+        Self::Initialised {
+            window: self.window,
+            depthwise_multiply: self.depthwise_multiply.into_initialised(),
+            bias_add: self.bias_add.into_initialised(),
+            activation_function: self.activation_function.into_initialised(),
+        }
+    }
+
+    fn init(&mut self, epochs: u32) {
+        self.depthwise_multiply.init(epochs);
+        self.bias_add.init(epochs);
+        self.activation_function.init(epochs);
+    }
+
+    fn end_epoch(&mut self) {
+        self.depthwise_multiply.end_epoch();
+        self.bias_add.end_epoch();
+        self.activation_function.end_epoch();
+    }
+
+    fn end_batch(&mut self) {
+        self.depthwise_multiply.end_batch();
+        self.bias_add.end_batch();
+        self.activation_function.end_batch();
+    }
+
+    fn observe_loss(&mut self, loss: ElementType) {
+        self.depthwise_multiply.observe_loss(loss);
+        self.bias_add.observe_loss(loss);
+        self.activation_function.observe_loss(loss);
+    }
+
+    fn learning_rate(&self) -> Option<ElementType> {
+        self.depthwise_multiply.learning_rate()
+    }
+
+    fn penalty(&self) -> ElementType {
+        self.depthwise_multiply.penalty() + self.bias_add.penalty()
+    }
+}
+
+impl<'a, T, U, V> Forward<'a> for Operation<T, U, V>
+where
+    T: Forward<'a, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>
+        + TrainableOperation<Initialised = initialised::depthwise_multiply::Operation>,
+    U: Forward<'a, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>
+        + TrainableOperation<Initialised = initialised::bias_add::Operation>,
+    V: Forward<'a, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>> + TrainableOperation,
+    <T as Forward<'a>>::Forward: ForwardOperation<Input = Tensor<rank::Two>>,
+    <U as Forward<'a>>::Forward: ForwardOperation<Input = Tensor<rank::Two>>,
+    <V as Forward<'a>>::Forward: ForwardOperation<Input = Tensor<rank::Two>>,
+{
+    type Input = Tensor<rank::Four>;
+    type Output = Tensor<rank::Four>;
+    // once the patches are unrolled, the layer is trained the same as a convolution of a dense layer.
+    type Forward = forward::conv2d::Operation<
+        forward::dense::Operation<
+            <T as Forward<'a>>::Forward,
+            <U as Forward<'a>>::Forward,
+            <V as Forward<'a>>::Forward,
+        >,
+    >;
+
+    fn forward(&'a mut self, input: Self::Input) -> Result<(Self::Forward, Self::Output)> {
+        let (window, input_shape) = (self.window, input.0.dim());
+        let columns = window.columns(&input.0)?;
+        let (depthwise_multiply, output) = self.depthwise_multiply.forward(Tensor(columns))?;
+        let (bias_add, output) = self.bias_add.forward(output)?;
+        let (activation_function, output) = self.activation_function.forward(output)?;
+        let output = window.feature_maps(output.0, input_shape)?;
+        let dense = forward::dense::Operation {
+            weight_multiply: depthwise_multiply,
+            bias_add,
+            activation_function,
+        };
+        let forward = forward::conv2d::Operation {
+            window,
+            input_shape,
+            dense,
+        };
+        Ok((forward, Tensor(output)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::{Linear, ReLU};
+    use crate::layers::{Chain, DepthwiseConv2D, ImageInput};
+    use crate::operations::{
+        BackwardOperation, InitialisedOperation, UninitialisedOperation, WithOptimiser,
+    };
+    use crate::optimisers::learning_rate_handlers::FixedLearningRateHandler;
+    use crate::optimisers::{NullOptimiser, SGD};
+
+    #[test]
+    fn test_into_initialised() {
+        // Arrange
+        let initialised = ImageInput::new(2)
+            .chain(DepthwiseConv2D::new(2, 2, 1, 0, ReLU::new()))
+            .with_seed(42);
+        let expected = initialised.clone();
+
+        // Act
+        let output = initialised
+            .with_optimiser(NullOptimiser::new())
+            .into_initialised();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_forward_matches_predict() {
+        // Arrange
+        let initialised = ImageInput::new(2)
+            .chain(DepthwiseConv2D::new(3, 2, 1, 1, ReLU::new()))
+            .with_seed(42);
+        let input =
+            Tensor::<rank::Four>::new((2, 2, 3, 3), (0u8..36).map(ElementType::from)).unwrap();
+        let expected = initialised.predict(input.clone()).unwrap();
+        let mut trainable = initialised.with_optimiser(NullOptimiser::new());
+
+        // Act
+        let (_, output) = trainable.forward(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_backward_matches_numerical_gradient() {
+        // Arrange
+        // the layer is linear in its input, so a large nudge gives an exact numerical gradient.
+        const DELTA: ElementType = 1.0;
+        let initialised = ImageInput::new(2)
+            .chain(DepthwiseConv2D::new(2, 2, 2, 1, Linear::new()))
+            .with_seed(42);
+        let input = Tensor::<rank::Four>::new(
+            (1, 2, 3, 3),
+            (0u8..18).map(|elem| ElementType::from(elem) / 10.0),
+        )
+        .unwrap();
+        let output_gradient = Tensor::<rank::Four>::new((1, 4, 2, 2), [1.0; 16]).unwrap();
+        let mut network = initialised.clone().with_optimiser(NullOptimiser::new());
+
+        // Act
+        let (forward, _) = network.forward(input.clone()).unwrap();
+        let (_, input_gradient) = forward.backward(output_gradient).unwrap();
+
+        // Assert
+        for (index, gradient) in input_gradient.0.indexed_iter() {
+            let mut nudged = input.clone();
+            nudged.0[index] += DELTA;
+            let before = initialised.predict(input.clone()).unwrap().0.sum();
+            let after = initialised.predict(nudged).unwrap().0.sum();
+            let numerical = (after - before) / DELTA;
+            assert!((numerical - gradient).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_optimise() {
+        // Arrange
+        // a 1x1 kernel per channel, so each weight's gradient is the sum of its channel's pixels.
+        let mut network = ImageInput::new(2)
+            .chain(DepthwiseConv2D::new(1, 1, 1, 0, Linear::new()))
+            .with_iter([1.0, 1.0, 0.0, 0.0].into_iter())
+            .unwrap()
+            .with_optimiser(SGD::new(FixedLearningRateHandler::new(1.0)));
+        let input = Tensor::<rank::Four>::new((1, 2, 1, 2), [1.0, 2.0, 3.0, 4.0]).unwrap();
+        let output_gradient = Tensor::<rank::Four>::new((1, 2, 1, 2), [1.0; 4]).unwrap();
+
+        // Act
+        let (forward, _) = network.forward(input).unwrap();
+        let (backward, _) = forward.backward(output_gradient).unwrap();
+        backward.optimise();
+
+        // Assert
+        assert!(network
+            .into_initialised()
+            .iter()
+            .eq([-2.0, -6.0, -2.0, -2.0]));
+    }
+}
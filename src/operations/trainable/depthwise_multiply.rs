@@ -0,0 +1,104 @@
+use crate::operations::{forward, initialised, trainable};
+use crate::optimisers::base::Optimiser;
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation<T> {
+    pub(crate) optimiser: T,
+    pub(crate) initialised: initialised::depthwise_multiply::Operation,
+    pub(crate) last_input: Tensor<rank::Two>,
+}
+
+impl<T> Sealed for Operation<T> {}
+impl<T: Optimiser<Tensor<rank::Two>>> trainable::Operation for Operation<T> {
+    type Initialised = initialised::depthwise_multiply::Operation;
+
+    fn into_initialised(self) -> Self::Initialised {
+        self.initialised
+    }
+
+    fn init(&mut self, epochs: u32) {
+        self.optimiser.init(epochs);
+    }
+
+    fn end_epoch(&mut self) {
+        self.optimiser.end_epoch();
+    }
+
+    fn end_batch(&mut self) {
+        self.optimiser.end_batch();
+    }
+
+    fn observe_loss(&mut self, loss: ElementType) {
+        self.optimiser.observe_loss(loss);
+    }
+
+    fn learning_rate(&self) -> Option<ElementType> {
+        self.optimiser.learning_rate()
+    }
+
+    fn penalty(&self) -> ElementType {
+        self.optimiser.penalty(&self.initialised.parameter)
+    }
+}
+
+impl<'a, T: 'a + Optimiser<Tensor<rank::Two>>> forward::Forward<'a> for Operation<T> {
+    type Input = Tensor<rank::Two>;
+    type Output = Tensor<rank::Two>;
+    type Forward = forward::depthwise_multiply::Operation<'a, T>;
+
+    fn forward(&'a mut self, input: Self::Input) -> Result<(Self::Forward, Self::Output)> {
+        Error::check_size(self.initialised.parameter.0.nrows(), input.0.ncols())?;
+        let output = Tensor(self.initialised.multiply(&input.0));
+        self.last_input = input;
+        let forward = forward::depthwise_multiply::Operation { borrow: self };
+        Ok((forward, output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::{Forward, InitialisedOperation, TrainableOperation, WithOptimiser};
+    use crate::optimisers::NullOptimiser;
+
+    #[test]
+    fn test_forward_matches_predict() {
+        // Arrange
+        let initialised = initialised::depthwise_multiply::Operation {
+            groups: 2,
+            parameter: Tensor::<rank::Two>::new((4, 2), (1u8..=8).map(ElementType::from)).unwrap(),
+        };
+        let input = Tensor::<rank::Two>::new((1, 4), [1.0, -1.0, 2.0, 0.5]).unwrap();
+        let expected = initialised.predict(input.clone()).unwrap();
+        let mut operation = initialised.clone().with_optimiser(NullOptimiser::new());
+
+        // Act
+        let (_, output) = operation.forward(input.clone()).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+        assert_eq!(operation.last_input, input);
+        assert_eq!(operation.into_initialised(), initialised);
+    }
+
+    #[test]
+    fn test_forward_failure() {
+        // Arrange
+        let mut operation = initialised::depthwise_multiply::Operation {
+            groups: 1,
+            parameter: Tensor::<rank::Two>::new((2, 1), [1.0, 2.0]).unwrap(),
+        }
+        .with_optimiser(NullOptimiser::new());
+        let input = Tensor::<rank::Two>::new((1, 3), [1.0, 2.0, 3.0]).unwrap();
+
+        // Act
+        let result = operation.forward(input);
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
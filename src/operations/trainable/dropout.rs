@@ -2,10 +2,9 @@ use crate::operations::{forward, initialised, Forward, TrainableOperation};
 use crate::private::Sealed;
 use crate::tensors::{rank, Tensor};
 use crate::Result;
-use rand::rngs::StdRng;
-use rand::{thread_rng, Rng, SeedableRng};
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Operation {
     pub(crate) initialised: initialised::dropout::Operation,
 }
@@ -19,9 +18,15 @@ impl TrainableOperation for Operation {
         self.initialised
     }
 
-    fn init(&mut self, _epochs: u16) {}
+    fn init(&mut self, _epochs: u32) {}
 
     fn end_epoch(&mut self) {}
+
+    fn end_batch(&mut self) {}
+
+    fn reseed(&mut self, seed: u64) {
+        self.initialised.seed = Some(seed);
+    }
 }
 
 impl<'a> Forward<'a> for Operation {
@@ -30,25 +35,8 @@ impl<'a> Forward<'a> for Operation {
     type Forward = forward::dropout::Operation<'a>;
 
     fn forward(&'a mut self, input: Self::Input) -> Result<(Self::Forward, Self::Output)> {
-        let mut random = match self.initialised.seed {
-            Some(seed) => {
-                self.initialised.seed = Some(seed + 1); // so we don't get same mask next time
-                StdRng::seed_from_u64(seed)
-            }
-            None => StdRng::from_rng(thread_rng()).unwrap(),
-        };
-        let dims = input.0.raw_dim();
-        let element_count = dims[0] * dims[1];
-        let keep_probability = self.initialised.keep_probability;
-        let iter = (0..element_count).map(|_| {
-            let gen = random.gen_range(0.0..=1.0);
-            if gen <= keep_probability {
-                1.0
-            } else {
-                0.0
-            }
-        });
-        let mask = Tensor::<rank::Two>::new((dims[0], dims[1]), iter).unwrap();
+        let mut random = self.initialised.generator();
+        let mask = self.initialised.generate_mask(input.0.dim(), &mut random);
         let output = Tensor(input.0 * &mask.0);
         let forward = Self::Forward {
             _borrow: self,
@@ -69,11 +57,13 @@ mod tests {
             initialised: initialised::dropout::Operation {
                 keep_probability: 0.8,
                 seed: None,
+                inverted: false,
             },
         };
         let expected = initialised::dropout::Operation {
             keep_probability: 0.8,
             seed: None,
+            inverted: false,
         };
 
         // Act
@@ -90,12 +80,14 @@ mod tests {
             initialised: initialised::dropout::Operation {
                 keep_probability: 0.6,
                 seed: Some(42),
+                inverted: false,
             },
         };
         let mut expected_backing = Operation {
             initialised: initialised::dropout::Operation {
                 keep_probability: 0.6,
                 seed: Some(43),
+                inverted: false,
             },
         };
         let input = Tensor::<rank::Two>::new((1, 3), [1.0, 2.0, 3.0]).unwrap();
@@ -120,6 +112,24 @@ mod tests {
         assert_eq!(output, expected_output);
     }
 
+    #[test]
+    fn test_reseed() {
+        // Arrange
+        let mut trainable = Operation {
+            initialised: initialised::dropout::Operation {
+                keep_probability: 0.6,
+                seed: None,
+                inverted: false,
+            },
+        };
+
+        // Act
+        trainable.reseed(42);
+
+        // Assert
+        assert_eq!(trainable.initialised.seed, Some(42));
+    }
+
     #[test]
     fn test_forward_without_seed() {
         // Arrange
@@ -127,6 +137,7 @@ mod tests {
             initialised: initialised::dropout::Operation {
                 keep_probability: 0.6,
                 seed: None,
+                inverted: false,
             },
         };
         let input = Tensor::<rank::Two>::new((1, 3), [1.0, 2.0, 3.0]).unwrap();
@@ -142,6 +153,7 @@ mod tests {
             initialised: initialised::dropout::Operation {
                 keep_probability: 0.6,
                 seed: None,
+                inverted: false,
             },
         };
         let expected = trainable.clone();
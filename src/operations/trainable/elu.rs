@@ -0,0 +1,101 @@
+use crate::operations::InitialisedOperation;
+use crate::operations::{forward, initialised, trainable};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::Result;
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation {
+    pub(crate) initialised: initialised::elu::Operation,
+    pub(crate) last_output: Tensor<rank::Two>,
+}
+
+impl Sealed for Operation {}
+impl trainable::Operation for Operation {
+    type Initialised = initialised::elu::Operation;
+
+    fn into_initialised(self) -> Self::Initialised {
+        self.initialised
+    }
+
+    fn init(&mut self, _epochs: u32) {}
+
+    fn end_epoch(&mut self) {}
+
+    fn end_batch(&mut self) {}
+}
+
+impl<'a> forward::Forward<'a> for Operation {
+    type Input = Tensor<rank::Two>;
+    type Output = Tensor<rank::Two>;
+    type Forward = forward::elu::Operation<'a>;
+
+    fn forward(&'a mut self, input: Self::Input) -> Result<(Self::Forward, Self::Output)> {
+        self.last_output = self.initialised.predict(input)?;
+        let clone = self.last_output.clone();
+        Ok((forward::elu::Operation(self), clone))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::{Forward, TrainableOperation};
+
+    fn initialised(neurons: u16) -> initialised::elu::Operation {
+        initialised::elu::Operation {
+            neurons,
+            alpha: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_into_initialised() {
+        // Arrange
+        let operation = Operation {
+            initialised: initialised(42),
+            last_output: Tensor::default(),
+        };
+
+        // Act
+        let output = operation.into_initialised();
+
+        // Assert
+        assert_eq!(output, initialised(42));
+    }
+
+    #[test]
+    fn test_forward_success() {
+        // Arrange
+        let mut operation = Operation {
+            initialised: initialised(3),
+            last_output: Tensor::default(),
+        };
+        let input = Tensor::<rank::Two>::new((2, 3), [-2.0, -1.0, 0.0, 1.0, 2.0, 3.0]).unwrap();
+        let expected = Tensor(input.0.mapv(|elem| initialised(3).elu(elem)));
+
+        // Act
+        let (_, output) = operation.forward(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+        assert_eq!(operation.last_output, expected);
+    }
+
+    #[test]
+    fn test_forward_failure() {
+        // Arrange
+        let mut operation = Operation {
+            initialised: initialised(2),
+            last_output: Tensor::default(),
+        };
+        let input = Tensor::<rank::Two>::new((2, 3), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+
+        // Act
+        let result = operation.forward(input);
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
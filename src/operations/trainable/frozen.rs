@@ -0,0 +1,78 @@
+use crate::operations::{forward, initialised, Forward, ForwardOperation, TrainableOperation};
+use crate::private::Sealed;
+use crate::Result;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation<T> {
+    pub(crate) block: T,
+}
+
+impl<T> Sealed for Operation<T> {}
+
+impl<T: TrainableOperation> TrainableOperation for Operation<T> {
+    type Initialised = initialised::frozen::Operation<T::Initialised>;
+
+    fn into_initialised(self) -> Self::Initialised {
+        Self::Initialised {
+            block: self.block.into_initialised(),
+        }
+    }
+
+    fn init(&mut self, epochs: u32) {
+        self.block.init(epochs);
+    }
+
+    fn end_epoch(&mut self) {
+        self.block.end_epoch();
+    }
+
+    fn end_batch(&mut self) {
+        self.block.end_batch();
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.block.reseed(seed);
+    }
+}
+
+impl<'a, T> Forward<'a> for Operation<T>
+where
+    T: Forward<'a>,
+    <T as Forward<'a>>::Forward: ForwardOperation<Input = <T as Forward<'a>>::Input>,
+{
+    type Input = <T as Forward<'a>>::Input;
+    type Output = <T as Forward<'a>>::Output;
+    type Forward = forward::frozen::Operation<<T as Forward<'a>>::Forward>;
+
+    fn forward(&'a mut self, input: Self::Input) -> Result<(Self::Forward, Self::Output)> {
+        let (forward, output) = self.block.forward(input)?;
+        Ok((forward::frozen::Operation(forward), output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::activations::Linear;
+    use crate::layers::{Chain, Dense, Frozen, Input};
+    use crate::operations::{TrainableOperation, UninitialisedOperation, WithOptimiser};
+    use crate::optimisers::learning_rate_handlers::FixedLearningRateHandler;
+    use crate::optimisers::SGD;
+
+    #[test]
+    fn test_into_initialised() {
+        // Arrange
+        let initialised = Input::new(2)
+            .chain(Frozen::new(Dense::new(1, Linear::new())))
+            .with_seed(42);
+        let trainable = initialised
+            .clone()
+            .with_optimiser(SGD::new(FixedLearningRateHandler::new(0.1)));
+
+        // Act
+        let output = trainable.into_initialised();
+
+        // Assert
+        assert_eq!(output, initialised);
+    }
+}
@@ -0,0 +1,95 @@
+use crate::operations::InitialisedOperation;
+use crate::operations::{forward, initialised, trainable};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::Result;
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation {
+    pub(crate) initialised: initialised::gelu::Operation,
+    pub(crate) last_input: Tensor<rank::Two>,
+}
+
+impl Sealed for Operation {}
+impl trainable::Operation for Operation {
+    type Initialised = initialised::gelu::Operation;
+
+    fn into_initialised(self) -> Self::Initialised {
+        self.initialised
+    }
+
+    fn init(&mut self, _epochs: u32) {}
+
+    fn end_epoch(&mut self) {}
+
+    fn end_batch(&mut self) {}
+}
+
+impl<'a> forward::Forward<'a> for Operation {
+    type Input = Tensor<rank::Two>;
+    type Output = Tensor<rank::Two>;
+    type Forward = forward::gelu::Operation<'a>;
+
+    fn forward(&'a mut self, input: Self::Input) -> Result<(Self::Forward, Self::Output)> {
+        let output = self.initialised.predict(input.clone())?;
+        self.last_input = input;
+        Ok((forward::gelu::Operation(self), output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::{Forward, TrainableOperation};
+
+    #[test]
+    fn test_into_initialised() {
+        // Arrange
+        let operation = Operation {
+            initialised: initialised::gelu::Operation { neurons: 42 },
+            last_input: Tensor::default(),
+        };
+        let expected = initialised::gelu::Operation { neurons: 42 };
+
+        // Act
+        let output = operation.into_initialised();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_forward_success() {
+        // Arrange
+        let mut operation = Operation {
+            initialised: initialised::gelu::Operation { neurons: 3 },
+            last_input: Tensor::default(),
+        };
+        let input = Tensor::<rank::Two>::new((2, 3), [-2.0, -1.0, 0.0, 1.0, 2.0, 3.0]).unwrap();
+        let expected = Tensor(input.0.mapv(initialised::gelu::gelu));
+
+        // Act
+        let (_, output) = operation.forward(input.clone()).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+        assert_eq!(operation.last_input, input);
+    }
+
+    #[test]
+    fn test_forward_failure() {
+        // Arrange
+        let mut operation = Operation {
+            initialised: initialised::gelu::Operation { neurons: 2 },
+            last_input: Tensor::default(),
+        };
+        let input = Tensor::<rank::Two>::new((2, 3), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+
+        // Act
+        let result = operation.forward(input);
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
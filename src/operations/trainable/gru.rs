@@ -0,0 +1,141 @@
+use crate::operations::initialised::gru::Step;
+use crate::operations::{forward, initialised, trainable};
+use crate::optimisers::base::Optimiser;
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Result};
+use alloc::vec::Vec;
+use ndarray::{Array, Axis};
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation<T> {
+    pub(crate) optimisers: [T; 3],
+    pub(crate) initialised: initialised::gru::Operation,
+    pub(crate) last_input: Tensor<rank::Three>,
+    pub(crate) last_steps: Vec<Step>,
+}
+
+impl<T> Sealed for Operation<T> {}
+impl<T: Optimiser<Tensor<rank::Two>>> trainable::Operation for Operation<T> {
+    type Initialised = initialised::gru::Operation;
+
+    fn into_initialised(self) -> Self::Initialised {
+        self.initialised
+    }
+
+    fn init(&mut self, epochs: u32) {
+        self.optimisers
+            .iter_mut()
+            .for_each(|optimiser| optimiser.init(epochs));
+    }
+
+    fn end_epoch(&mut self) {
+        self.optimisers.iter_mut().for_each(Optimiser::end_epoch);
+    }
+
+    fn end_batch(&mut self) {
+        self.optimisers.iter_mut().for_each(Optimiser::end_batch);
+    }
+
+    fn observe_loss(&mut self, loss: ElementType) {
+        self.optimisers
+            .iter_mut()
+            .for_each(|optimiser| optimiser.observe_loss(loss));
+    }
+
+    fn learning_rate(&self) -> Option<ElementType> {
+        self.optimisers[0].learning_rate()
+    }
+
+    fn penalty(&self) -> ElementType {
+        let initialised = &self.initialised;
+        let parameters = [
+            &initialised.input_weights,
+            &initialised.hidden_weights,
+            &initialised.bias,
+        ];
+        self.optimisers
+            .iter()
+            .zip(parameters)
+            .map(|(optimiser, parameter)| optimiser.penalty(parameter))
+            .sum()
+    }
+}
+
+impl<'a, T: 'a + Optimiser<Tensor<rank::Two>>> forward::Forward<'a> for Operation<T> {
+    type Input = Tensor<rank::Three>;
+    type Output = Tensor<rank::Two>;
+    type Forward = forward::gru::Operation<'a, T>;
+
+    fn forward(&'a mut self, input: Self::Input) -> Result<(Self::Forward, Self::Output)> {
+        self.initialised.check_input(&input.0)?;
+        let mut hidden = Array::zeros((input.0.dim().0, self.initialised.units()));
+        self.last_steps = input
+            .0
+            .axis_iter(Axis(1))
+            .map(|input| {
+                let step = self.initialised.step(input, &hidden);
+                hidden.clone_from(&step.hidden);
+                step
+            })
+            .collect();
+        self.last_input = input;
+        let forward = forward::gru::Operation { borrow: self };
+        Ok((forward, Tensor(hidden)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layers::GRU;
+    use crate::operations::InitialisedOperation;
+    use crate::operations::{Forward, TrainableOperation, UninitialisedOperation, WithOptimiser};
+    use crate::optimisers::NullOptimiser;
+
+    #[test]
+    fn test_into_initialised() {
+        // Arrange
+        let (initialised, _) = GRU::new(2).with_seed_private(42, 3);
+        let expected = initialised.clone();
+        let operation = initialised.with_optimiser(NullOptimiser::new());
+
+        // Act
+        let output = operation.into_initialised();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_forward() {
+        // Arrange
+        let (initialised, _) = GRU::new(2).with_seed_private(42, 3);
+        let input =
+            Tensor::<rank::Three>::new((2, 4, 3), (0u8..24).map(ElementType::from)).unwrap();
+        let expected = initialised.predict(input.clone()).unwrap();
+        let mut operation = initialised.with_optimiser(NullOptimiser::new());
+
+        // Act
+        let (_, output) = operation.forward(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+        assert_eq!(operation.last_steps.len(), 4);
+    }
+
+    #[test]
+    fn test_forward_failure() {
+        // Arrange
+        let (initialised, _) = GRU::new(2).with_seed_private(42, 3);
+        let mut operation = initialised.with_optimiser(NullOptimiser::new());
+        let input = Tensor::<rank::Three>::new((1, 2, 2), [1.0, 2.0, 3.0, 4.0]).unwrap();
+
+        // Act
+        let result = operation.forward(input);
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,77 @@
+use crate::operations::{forward, initialised, trainable};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{Error, Result};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation(pub(crate) initialised::image_input::Operation);
+
+impl Sealed for Operation {}
+impl trainable::Operation for Operation {
+    type Initialised = initialised::image_input::Operation;
+
+    fn into_initialised(self) -> Self::Initialised {
+        self.0
+    }
+
+    fn init(&mut self, _epochs: u32) {}
+
+    fn end_epoch(&mut self) {}
+
+    fn end_batch(&mut self) {}
+}
+
+impl<'a> forward::Forward<'a> for Operation {
+    type Input = Tensor<rank::Four>;
+    type Output = Tensor<rank::Four>;
+    type Forward = forward::image_input::Operation<'a>;
+
+    fn forward(&'a mut self, input: Self::Input) -> Result<(Self::Forward, Self::Output)> {
+        if self.0.channels as usize == input.0.dim().1 {
+            Ok((forward::image_input::Operation(self), input))
+        } else {
+            Err(Error::ShapeMismatch {
+                expected: self.0.channels as usize,
+                actual: input.0.dim().1,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::{Forward, TrainableOperation};
+
+    #[test]
+    fn test_into_initialised() {
+        let operation = Operation(initialised::image_input::Operation { channels: 3 });
+        let expected = initialised::image_input::Operation { channels: 3 };
+
+        let output = operation.into_initialised();
+
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_forward_success() {
+        let mut operation = Operation(initialised::image_input::Operation { channels: 1 });
+        let input = Tensor::<rank::Four>::new((1, 1, 2, 2), [1.0, 2.0, 3.0, 4.0]).unwrap();
+        let expected = input.clone();
+
+        let (_, output) = operation.forward(input).unwrap();
+
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_forward_failure() {
+        let mut operation = Operation(initialised::image_input::Operation { channels: 2 });
+        let input = Tensor::<rank::Four>::new((1, 1, 2, 2), [1.0, 2.0, 3.0, 4.0]).unwrap();
+
+        let result = operation.forward(input);
+
+        assert!(result.is_err());
+    }
+}
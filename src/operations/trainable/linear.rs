@@ -4,6 +4,7 @@ use crate::tensors::{rank, Tensor};
 use crate::{Error, Result};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)] // code coverage hack, I dislike <100% in the report :(
 pub struct Operation(pub(crate) initialised::linear::Operation);
 
@@ -15,9 +16,11 @@ impl trainable::Operation for Operation {
         self.0
     }
 
-    fn init(&mut self, _epochs: u16) {}
+    fn init(&mut self, _epochs: u32) {}
 
     fn end_epoch(&mut self) {}
+
+    fn end_batch(&mut self) {}
 }
 
 impl<'a> forward::Forward<'a> for Operation {
@@ -29,7 +32,10 @@ impl<'a> forward::Forward<'a> for Operation {
         if self.0.neurons as usize == input.0.ncols() {
             Ok((forward::linear::Operation(self), input))
         } else {
-            Err(Error(()))
+            Err(Error::ShapeMismatch {
+                expected: self.0.neurons as usize,
+                actual: input.0.ncols(),
+            })
         }
     }
 }
@@ -1,18 +1,41 @@
 //! Module containing the traits and types relating
 //! to operations and chains of operations in the trainable typestate.
 
+pub mod alpha_dropout;
 pub mod bias_add;
 pub mod composite;
+pub mod conv1d;
+pub mod conv2d;
+pub mod conv_transpose2d;
+pub mod custom;
 pub mod dense;
+pub mod depthwise_conv2d;
+pub mod depthwise_multiply;
 pub mod dropout;
+pub mod elu;
+pub mod frozen;
+pub mod gelu;
+pub mod gru;
+pub mod image_input;
 pub mod input;
 pub mod linear;
+pub mod multi_input;
+pub mod parallel;
+pub mod pooling2d;
 pub mod relu;
+pub mod reshape;
+pub mod selu;
+pub mod sequence_input;
 pub mod sigmoid;
+pub mod silu;
+pub mod spatial_dropout;
+pub mod stochastic_depth;
 pub mod tanh;
+pub mod upsample2d;
 pub mod weight_multiply;
 
 use crate::private::Sealed;
+use crate::ElementType;
 
 /// This trait is implemented on those types that represent
 /// an operation that is in a state ready to be trained.
@@ -31,9 +54,39 @@ pub trait Operation: Sealed {
     /// This function can be called at the beginning of training by the trainer
     /// to initialise the optimisers in the network if needed to account for the
     /// specific number of epochs we'll be training over.
-    fn init(&mut self, epochs: u16);
+    fn init(&mut self, epochs: u32);
 
     /// This function can be called at the end of an epoch by the trainer to provide
     /// a chance to update any internal optimisers as needed.
     fn end_epoch(&mut self);
+
+    /// This function can be called at the end of each batch by the trainer to provide
+    /// a chance to update any internal optimisers at a finer granularity than per epoch.
+    fn end_batch(&mut self);
+
+    /// This function is called by the trainer with the loss against the testing data each time
+    /// the network is evaluated, and provides a chance for any learning rate handlers that react
+    /// to the evaluation loss to update. Operations without any optimisers can ignore it.
+    fn observe_loss(&mut self, _loss: ElementType) {}
+
+    /// This function is called by the trainer before training starts when deterministic training
+    /// is requested, and seeds every layer which makes random decisions during the forward pass
+    /// (such as dropout) from the given seed. Layers built with `with_iter` otherwise draw from the
+    /// thread's random generator (or a fixed seed without the *std* feature). Operations without any
+    /// randomness can ignore it.
+    fn reseed(&mut self, _seed: u64) {}
+
+    /// Gets the learning rate currently being used by the optimisers in the operation, or `None` if
+    /// the operation doesn't have any parameters to optimise (or they don't use a learning rate).
+    /// Where there are multiple optimisers, the learning rate of the first one is provided.
+    fn learning_rate(&self) -> Option<ElementType> {
+        None
+    }
+
+    /// Gets the regularisation penalty that the optimisers in the operation apply to its current
+    /// parameters, which is added to the loss reported during training. This is zero unless the
+    /// optimisers have been wrapped in a `Regularisation` optimiser.
+    fn penalty(&self) -> ElementType {
+        0.0
+    }
 }
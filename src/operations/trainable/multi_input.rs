@@ -0,0 +1,125 @@
+use crate::operations::initialised::multi_input::split;
+use crate::operations::initialised::parallel::merge;
+use crate::operations::uninitialised::parallel::Merge;
+use crate::operations::{forward, initialised, Forward, ForwardOperation, TrainableOperation};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Result};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation<T, U> {
+    pub(crate) lhs: T,
+    pub(crate) rhs: U,
+    pub(crate) merge: Merge,
+    pub(crate) features: (usize, usize),
+}
+
+impl<T, U> Sealed for Operation<T, U> {}
+impl<T: TrainableOperation, U: TrainableOperation> TrainableOperation for Operation<T, U> {
+    type Initialised = initialised::multi_input::Operation<T::Initialised, U::Initialised>;
+
+    fn into_initialised(self) -> Self::Initialised {
+        Self::Initialised {
+            lhs: self.lhs.into_initialised(),
+            rhs: self.rhs.into_initialised(),
+            merge: self.merge,
+            features: self.features,
+        }
+    }
+
+    fn init(&mut self, epochs: u32) {
+        self.lhs.init(epochs);
+        self.rhs.init(epochs);
+    }
+
+    fn end_epoch(&mut self) {
+        self.lhs.end_epoch();
+        self.rhs.end_epoch();
+    }
+
+    fn end_batch(&mut self) {
+        self.lhs.end_batch();
+        self.rhs.end_batch();
+    }
+
+    fn observe_loss(&mut self, loss: ElementType) {
+        self.lhs.observe_loss(loss);
+        self.rhs.observe_loss(loss);
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.lhs.reseed(seed);
+        self.rhs.reseed(seed + 1);
+    }
+
+    fn learning_rate(&self) -> Option<ElementType> {
+        self.lhs
+            .learning_rate()
+            .or_else(|| self.rhs.learning_rate())
+    }
+
+    fn penalty(&self) -> ElementType {
+        self.lhs.penalty() + self.rhs.penalty()
+    }
+}
+
+impl<'a, T, U> Forward<'a> for Operation<T, U>
+where
+    T: Forward<'a, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+    U: Forward<'a, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+    <T as Forward<'a>>::Forward: ForwardOperation<Input = Tensor<rank::Two>>,
+    <U as Forward<'a>>::Forward: ForwardOperation<Input = Tensor<rank::Two>>,
+{
+    type Input = Tensor<rank::Two>;
+    type Output = Tensor<rank::Two>;
+    type Forward =
+        forward::multi_input::Operation<<T as Forward<'a>>::Forward, <U as Forward<'a>>::Forward>;
+
+    fn forward(&'a mut self, input: Self::Input) -> Result<(Self::Forward, Self::Output)> {
+        let (lhs_input, rhs_input) = split(self.features, &input)?;
+        let (lhs, lhs_output) = self.lhs.forward(lhs_input)?;
+        let (rhs, rhs_output) = self.rhs.forward(rhs_input)?;
+        let neurons = (lhs_output.0.ncols(), rhs_output.0.ncols());
+        let output = merge(self.merge, lhs_output, rhs_output)?;
+        let forward = Self::Forward {
+            lhs,
+            rhs,
+            merge: self.merge,
+            neurons,
+        };
+        Ok((forward, output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::Linear;
+    use crate::layers::{Chain, Dense, Input, MultiInput};
+    use crate::operations::{UninitialisedOperation, WithOptimiser};
+    use crate::optimisers::NullOptimiser;
+
+    #[test]
+    fn test_forward() {
+        // Arrange
+        let mut operation = MultiInput::new(
+            Input::new(1).chain(Dense::new(1, Linear::new())),
+            Input::new(1).chain(Dense::new(1, Linear::new())),
+            Merge::Add,
+        )
+        .with_iter([2.0, 0.0, 3.0, 1.0].into_iter())
+        .unwrap()
+        .with_optimiser(NullOptimiser::new());
+        let input = Tensor::<rank::Two>::new((2, 2), [1.0, 2.0, 3.0, 4.0]).unwrap();
+
+        // Act
+        let (_, output) = operation.forward(input).unwrap();
+
+        // Assert
+        assert_eq!(
+            output,
+            Tensor::<rank::Two>::new((2, 1), [9.0, 19.0]).unwrap()
+        );
+    }
+}
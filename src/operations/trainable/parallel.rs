@@ -0,0 +1,156 @@
+use crate::operations::initialised::parallel::merge;
+use crate::operations::uninitialised::parallel::Merge;
+use crate::operations::{forward, initialised, Forward, ForwardOperation, TrainableOperation};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Result};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation<T, U> {
+    pub(crate) lhs: T,
+    pub(crate) rhs: U,
+    pub(crate) merge: Merge,
+}
+
+impl<T, U> Sealed for Operation<T, U> {}
+impl<T: TrainableOperation, U: TrainableOperation> TrainableOperation for Operation<T, U> {
+    type Initialised = initialised::parallel::Operation<T::Initialised, U::Initialised>;
+
+    fn into_initialised(self) -> Self::Initialised {
+        Self::Initialised {
+            lhs: self.lhs.into_initialised(),
+            rhs: self.rhs.into_initialised(),
+            merge: self.merge,
+        }
+    }
+
+    fn init(&mut self, epochs: u32) {
+        self.lhs.init(epochs);
+        self.rhs.init(epochs);
+    }
+
+    fn end_epoch(&mut self) {
+        self.lhs.end_epoch();
+        self.rhs.end_epoch();
+    }
+
+    fn end_batch(&mut self) {
+        self.lhs.end_batch();
+        self.rhs.end_batch();
+    }
+
+    fn observe_loss(&mut self, loss: ElementType) {
+        self.lhs.observe_loss(loss);
+        self.rhs.observe_loss(loss);
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.lhs.reseed(seed);
+        self.rhs.reseed(seed + 1);
+    }
+
+    fn learning_rate(&self) -> Option<ElementType> {
+        self.lhs
+            .learning_rate()
+            .or_else(|| self.rhs.learning_rate())
+    }
+
+    fn penalty(&self) -> ElementType {
+        self.lhs.penalty() + self.rhs.penalty()
+    }
+}
+
+impl<'a, T, U> Forward<'a> for Operation<T, U>
+where
+    T: Forward<'a, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+    U: Forward<'a, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+    <T as Forward<'a>>::Forward: ForwardOperation<Input = Tensor<rank::Two>>,
+    <U as Forward<'a>>::Forward: ForwardOperation<Input = Tensor<rank::Two>>,
+{
+    type Input = Tensor<rank::Two>;
+    type Output = Tensor<rank::Two>;
+    type Forward =
+        forward::parallel::Operation<<T as Forward<'a>>::Forward, <U as Forward<'a>>::Forward>;
+
+    fn forward(&'a mut self, input: Self::Input) -> Result<(Self::Forward, Self::Output)> {
+        let (lhs, lhs_output) = self.lhs.forward(input.clone())?;
+        let (rhs, rhs_output) = self.rhs.forward(input)?;
+        let neurons = (lhs_output.0.ncols(), rhs_output.0.ncols());
+        let output = merge(self.merge, lhs_output, rhs_output)?;
+        let forward = Self::Forward {
+            lhs,
+            rhs,
+            merge: self.merge,
+            neurons,
+        };
+        Ok((forward, output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::Linear;
+    use crate::layers::Dense;
+    use crate::operations::{UninitialisedOperation, WithOptimiser};
+    use crate::optimisers::NullOptimiser;
+
+    type Block = <initialised::dense::Operation<initialised::linear::Operation> as WithOptimiser<
+        NullOptimiser,
+    >>::Trainable;
+
+    fn operation(merge: Merge) -> Operation<Block, Block> {
+        let block = |parameters: [ElementType; 2]| {
+            Dense::new(1, Linear::new())
+                .with_iter_private(&mut parameters.into_iter(), 1)
+                .unwrap()
+                .0
+                .with_optimiser(NullOptimiser::new())
+        };
+        Operation {
+            lhs: block([2.0, 0.0]),
+            rhs: block([3.0, 1.0]),
+            merge,
+        }
+    }
+
+    #[test]
+    fn test_into_initialised() {
+        // Arrange
+        let trainable = operation(Merge::Add);
+        let expected = initialised::parallel::Operation {
+            lhs: trainable.lhs.clone().into_initialised(),
+            rhs: trainable.rhs.clone().into_initialised(),
+            merge: Merge::Add,
+        };
+
+        // Act
+        let output = trainable.into_initialised();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_forward() {
+        // Arrange
+        let mut concatenate = operation(Merge::Concatenate);
+        let mut add = operation(Merge::Add);
+        let input = Tensor::<rank::Two>::new((2, 1), [1.0, 2.0]).unwrap();
+
+        // Act
+        let (_, concatenated) = concatenate.forward(input.clone()).unwrap();
+        let (_, added) = add.forward(input).unwrap();
+
+        // Assert
+        assert_eq!(
+            concatenated,
+            Tensor::<rank::Two>::new((2, 2), [2.0, 4.0, 4.0, 7.0]).unwrap()
+        );
+        assert_eq!(
+            added,
+            Tensor::<rank::Two>::new((2, 1), [6.0, 11.0]).unwrap()
+        );
+    }
+}
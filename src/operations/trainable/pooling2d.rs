@@ -0,0 +1,89 @@
+use crate::operations::uninitialised::pooling2d::Pool;
+use crate::operations::{forward, initialised, trainable};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::Result;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation<T>(pub(crate) initialised::pooling2d::Operation<T>);
+
+impl<T> Sealed for Operation<T> {}
+impl<T: Pool> trainable::Operation for Operation<T> {
+    type Initialised = initialised::pooling2d::Operation<T>;
+
+    fn into_initialised(self) -> Self::Initialised {
+        self.0
+    }
+
+    fn init(&mut self, _epochs: u32) {}
+
+    fn end_epoch(&mut self) {}
+
+    fn end_batch(&mut self) {}
+}
+
+impl<'a, T: Pool + 'a> forward::Forward<'a> for Operation<T> {
+    type Input = Tensor<rank::Four>;
+    type Output = Tensor<rank::Four>;
+    type Forward = forward::pooling2d::Operation<'a, T>;
+
+    fn forward(&'a mut self, input: Self::Input) -> Result<(Self::Forward, Self::Output)> {
+        let (output, positions) = self.0.pool(&input.0)?;
+        let forward = forward::pooling2d::Operation {
+            borrow: self,
+            input_shape: input.0.dim(),
+            positions,
+        };
+        Ok((forward, Tensor(output)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layers::{Chain, ImageInput, MaxPooling2D};
+    use crate::operations::uninitialised::pooling2d::Max;
+    use crate::operations::{
+        Forward, InitialisedOperation, TrainableOperation, UninitialisedOperation, WithOptimiser,
+    };
+    use crate::optimisers::NullOptimiser;
+    use core::marker::PhantomData;
+
+    #[test]
+    fn test_into_initialised() {
+        // Arrange
+        let initialised = initialised::pooling2d::Operation {
+            channels: 2,
+            pool_size: 2,
+            stride: 2,
+            pooling: PhantomData::<Max>,
+        };
+        let operation = Operation(initialised);
+
+        // Act
+        let output = operation.into_initialised();
+
+        // Assert
+        assert_eq!(output, initialised);
+    }
+
+    #[test]
+    fn test_forward_matches_predict() {
+        // Arrange
+        let initialised = ImageInput::new(1)
+            .chain(MaxPooling2D::new(2, 1))
+            .with_seed(42);
+        let input =
+            Tensor::<rank::Four>::new((1, 1, 3, 3), [1.0, 5.0, 2.0, 0.0, 3.0, 4.0, 7.0, 1.0, 6.0])
+                .unwrap();
+        let expected = initialised.predict(input.clone()).unwrap();
+        let mut trainable = initialised.with_optimiser(NullOptimiser::new());
+
+        // Act
+        let (_, output) = trainable.forward(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+}
@@ -5,6 +5,7 @@ use crate::tensors::{rank, Tensor};
 use crate::Result;
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Operation {
     pub(crate) initialised: initialised::relu::Operation,
     pub(crate) last_output: Tensor<rank::Two>,
@@ -18,9 +19,11 @@ impl trainable::Operation for Operation {
         self.initialised
     }
 
-    fn init(&mut self, _epochs: u16) {}
+    fn init(&mut self, _epochs: u32) {}
 
     fn end_epoch(&mut self) {}
+
+    fn end_batch(&mut self) {}
 }
 
 impl<'a> forward::Forward<'a> for Operation {
@@ -0,0 +1,88 @@
+use crate::operations::{forward, initialised, trainable};
+use crate::private::Sealed;
+use crate::tensors::rank::Batched;
+use crate::tensors::Tensor;
+use crate::Result;
+use core::marker::PhantomData;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S::Item: serde::Serialize",
+        deserialize = "S::Item: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Operation<R, S: Batched>(pub(crate) initialised::reshape::Operation<R, S>);
+
+impl<R, S: Batched> Sealed for Operation<R, S> {}
+impl<R: Batched, S: Batched> trainable::Operation for Operation<R, S> {
+    type Initialised = initialised::reshape::Operation<R, S>;
+
+    fn into_initialised(self) -> Self::Initialised {
+        self.0
+    }
+
+    fn init(&mut self, _epochs: u32) {}
+
+    fn end_epoch(&mut self) {}
+
+    fn end_batch(&mut self) {}
+}
+
+impl<'a, R: Batched + 'a, S: Batched + 'a> forward::Forward<'a> for Operation<R, S> {
+    type Input = Tensor<R>;
+    type Output = Tensor<S>;
+    type Forward = forward::reshape::Operation<R, S>;
+
+    fn forward(&'a mut self, input: Self::Input) -> Result<(Self::Forward, Self::Output)> {
+        let output = self.0.reshape_items(&input.0)?;
+        let forward = forward::reshape::Operation {
+            input_shape: input.0.raw_dim(),
+            output_rank: PhantomData,
+        };
+        Ok((forward, Tensor(output)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layers::Flatten;
+    use crate::operations::{
+        Forward, InitialisedOperation, TrainableOperation, UninitialisedOperation, WithOptimiser,
+    };
+    use crate::optimisers::NullOptimiser;
+    use crate::tensors::rank;
+    use crate::ElementType;
+
+    #[test]
+    fn test_into_initialised() {
+        // Arrange
+        let (initialised, _) = Flatten::<rank::Three>::new(6).with_seed_private(42, 2);
+        let operation = Operation(initialised.clone());
+
+        // Act
+        let output = operation.into_initialised();
+
+        // Assert
+        assert_eq!(output, initialised);
+    }
+
+    #[test]
+    fn test_forward() {
+        // Arrange
+        let (initialised, _) = Flatten::<rank::Three>::new(6).with_seed_private(42, 2);
+        let input =
+            Tensor::<rank::Three>::new((2, 3, 2), (0u8..12).map(ElementType::from)).unwrap();
+        let expected = initialised.predict(input.clone()).unwrap();
+        let mut operation = initialised.with_optimiser(NullOptimiser::new());
+
+        // Act
+        let (_, output) = operation.forward(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+}
@@ -0,0 +1,77 @@
+use crate::operations::{forward, initialised, trainable};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{Error, Result};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation(pub(crate) initialised::sequence_input::Operation);
+
+impl Sealed for Operation {}
+impl trainable::Operation for Operation {
+    type Initialised = initialised::sequence_input::Operation;
+
+    fn into_initialised(self) -> Self::Initialised {
+        self.0
+    }
+
+    fn init(&mut self, _epochs: u32) {}
+
+    fn end_epoch(&mut self) {}
+
+    fn end_batch(&mut self) {}
+}
+
+impl<'a> forward::Forward<'a> for Operation {
+    type Input = Tensor<rank::Three>;
+    type Output = Tensor<rank::Three>;
+    type Forward = forward::sequence_input::Operation<'a>;
+
+    fn forward(&'a mut self, input: Self::Input) -> Result<(Self::Forward, Self::Output)> {
+        if self.0.features as usize == input.0.dim().2 {
+            Ok((forward::sequence_input::Operation(self), input))
+        } else {
+            Err(Error::ShapeMismatch {
+                expected: self.0.features as usize,
+                actual: input.0.dim().2,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::{Forward, TrainableOperation};
+
+    #[test]
+    fn test_into_initialised() {
+        let operation = Operation(initialised::sequence_input::Operation { features: 3 });
+        let expected = initialised::sequence_input::Operation { features: 3 };
+
+        let output = operation.into_initialised();
+
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_forward_success() {
+        let mut operation = Operation(initialised::sequence_input::Operation { features: 1 });
+        let input = Tensor::<rank::Three>::new((1, 4, 1), [1.0, 2.0, 3.0, 4.0]).unwrap();
+        let expected = input.clone();
+
+        let (_, output) = operation.forward(input).unwrap();
+
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_forward_failure() {
+        let mut operation = Operation(initialised::sequence_input::Operation { features: 2 });
+        let input = Tensor::<rank::Three>::new((1, 4, 1), [1.0, 2.0, 3.0, 4.0]).unwrap();
+
+        let result = operation.forward(input);
+
+        assert!(result.is_err());
+    }
+}
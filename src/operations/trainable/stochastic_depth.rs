@@ -0,0 +1,155 @@
+use crate::operations::{forward, initialised, Forward, ForwardOperation, TrainableOperation};
+use crate::private::{next_generator, Sealed};
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Result};
+use rand::Rng;
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation<T> {
+    pub(crate) block: T,
+    pub(crate) drop_probability: ElementType,
+    pub(crate) seed: Option<u64>,
+}
+
+impl<T> Sealed for Operation<T> {}
+
+impl<T: TrainableOperation> TrainableOperation for Operation<T> {
+    type Initialised = initialised::stochastic_depth::Operation<T::Initialised>;
+
+    fn into_initialised(self) -> Self::Initialised {
+        Self::Initialised {
+            block: self.block.into_initialised(),
+            drop_probability: self.drop_probability,
+            seed: self.seed,
+        }
+    }
+
+    fn init(&mut self, epochs: u32) {
+        self.block.init(epochs);
+    }
+
+    fn end_epoch(&mut self) {
+        self.block.end_epoch();
+    }
+
+    fn end_batch(&mut self) {
+        self.block.end_batch();
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.seed = Some(seed);
+        self.block.reseed(seed + 1);
+    }
+
+    fn observe_loss(&mut self, loss: ElementType) {
+        self.block.observe_loss(loss);
+    }
+
+    fn learning_rate(&self) -> Option<ElementType> {
+        self.block.learning_rate()
+    }
+
+    fn penalty(&self) -> ElementType {
+        self.block.penalty()
+    }
+}
+
+impl<'a, T> Forward<'a> for Operation<T>
+where
+    T: Forward<'a, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+    <T as Forward<'a>>::Forward: ForwardOperation<Input = Tensor<rank::Two>>,
+{
+    type Input = Tensor<rank::Two>;
+    type Output = Tensor<rank::Two>;
+    type Forward = forward::stochastic_depth::Operation<<T as Forward<'a>>::Forward>;
+
+    fn forward(&'a mut self, input: Self::Input) -> Result<(Self::Forward, Self::Output)> {
+        let mut random = next_generator(&mut self.seed);
+        if random.gen_range(0.0..1.0) < self.drop_probability {
+            Ok((Self::Forward::Skipped, input))
+        } else {
+            let (forward, output) = self.block.forward(input)?;
+            Ok((Self::Forward::Applied(forward), output))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::Linear;
+    use crate::layers::Dense;
+    use crate::operations::{UninitialisedOperation, WithOptimiser};
+    use crate::optimisers::NullOptimiser;
+
+    type Block = <initialised::dense::Operation<initialised::linear::Operation> as WithOptimiser<
+        NullOptimiser,
+    >>::Trainable;
+
+    fn operation(drop_probability: ElementType) -> Operation<Block> {
+        Operation {
+            block: Dense::new(1, Linear::new())
+                .with_iter_private(&mut [2.0, 0.0].into_iter(), 1)
+                .unwrap()
+                .0
+                .with_optimiser(NullOptimiser::new()),
+            drop_probability,
+            seed: Some(42),
+        }
+    }
+
+    #[test]
+    fn test_into_initialised() {
+        // Arrange
+        let trainable = operation(0.5);
+        let expected = initialised::stochastic_depth::Operation {
+            block: Dense::new(1, Linear::new())
+                .with_iter_private(&mut [2.0, 0.0].into_iter(), 1)
+                .unwrap()
+                .0,
+            drop_probability: 0.5,
+            seed: Some(42),
+        };
+
+        // Act
+        let output = trainable.into_initialised();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_forward_skip_rate() {
+        // Arrange
+        let mut operation = operation(0.3);
+        let input = Tensor::<rank::Two>::new((1, 1), [1.0]).unwrap();
+
+        // Act
+        let skipped = (0..1000)
+            .filter(|_| {
+                let (_, output) = operation.forward(input.clone()).unwrap();
+                output == input
+            })
+            .count();
+
+        // Assert
+        assert!((250..350).contains(&skipped));
+    }
+
+    #[test]
+    fn test_forward_never_skipped() {
+        // Arrange
+        let mut operation = operation(0.0);
+        let input = Tensor::<rank::Two>::new((1, 1), [1.0]).unwrap();
+        let expected = Tensor::<rank::Two>::new((1, 1), [2.0]).unwrap();
+
+        // Act
+        let outputs = (0..100)
+            .map(|_| operation.forward(input.clone()).unwrap().1)
+            .collect::<alloc::vec::Vec<_>>();
+
+        // Assert
+        assert!(outputs.into_iter().all(|output| output == expected));
+    }
+}
@@ -0,0 +1,82 @@
+use crate::operations::{forward, initialised, trainable};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::Result;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation(pub(crate) initialised::upsample2d::Operation);
+
+impl Sealed for Operation {}
+impl trainable::Operation for Operation {
+    type Initialised = initialised::upsample2d::Operation;
+
+    fn into_initialised(self) -> Self::Initialised {
+        self.0
+    }
+
+    fn init(&mut self, _epochs: u32) {}
+
+    fn end_epoch(&mut self) {}
+
+    fn end_batch(&mut self) {}
+}
+
+impl<'a> forward::Forward<'a> for Operation {
+    type Input = Tensor<rank::Four>;
+    type Output = Tensor<rank::Four>;
+    type Forward = forward::upsample2d::Operation<'a>;
+
+    fn forward(&'a mut self, input: Self::Input) -> Result<(Self::Forward, Self::Output)> {
+        let output = self.0.upsample(&input.0)?;
+        let forward = forward::upsample2d::Operation {
+            borrow: self,
+            input_shape: input.0.dim(),
+        };
+        Ok((forward, Tensor(output)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layers::{Chain, ImageInput, Interpolation, Upsample2D};
+    use crate::operations::{
+        Forward, InitialisedOperation, TrainableOperation, UninitialisedOperation, WithOptimiser,
+    };
+    use crate::optimisers::NullOptimiser;
+
+    #[test]
+    fn test_into_initialised() {
+        // Arrange
+        let initialised = initialised::upsample2d::Operation {
+            channels: 2,
+            scale: 2,
+            interpolation: Interpolation::Nearest,
+        };
+        let operation = Operation(initialised);
+
+        // Act
+        let output = operation.into_initialised();
+
+        // Assert
+        assert_eq!(output, initialised);
+    }
+
+    #[test]
+    fn test_forward_matches_predict() {
+        // Arrange
+        let initialised = ImageInput::new(1)
+            .chain(Upsample2D::new(3, Interpolation::Bilinear))
+            .with_seed(42);
+        let input = Tensor::<rank::Four>::new((1, 1, 2, 2), [1.0, 5.0, 2.0, 0.0]).unwrap();
+        let expected = initialised.predict(input.clone()).unwrap();
+        let mut trainable = initialised.with_optimiser(NullOptimiser::new());
+
+        // Act
+        let (_, output) = trainable.forward(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+}
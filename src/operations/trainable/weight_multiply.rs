@@ -2,9 +2,10 @@ use crate::operations::{forward, initialised, trainable, InitialisedOperation};
 use crate::optimisers::base::Optimiser;
 use crate::private::Sealed;
 use crate::tensors::{rank, Tensor};
-use crate::Result;
+use crate::{ElementType, Result};
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Operation<T> {
     pub(crate) optimiser: T,
     pub(crate) initialised: initialised::weight_multiply::Operation,
@@ -19,13 +20,29 @@ impl<T: Optimiser<Tensor<rank::Two>>> trainable::Operation for Operation<T> {
         self.initialised
     }
 
-    fn init(&mut self, epochs: u16) {
+    fn init(&mut self, epochs: u32) {
         self.optimiser.init(epochs);
     }
 
     fn end_epoch(&mut self) {
         self.optimiser.end_epoch();
     }
+
+    fn end_batch(&mut self) {
+        self.optimiser.end_batch();
+    }
+
+    fn observe_loss(&mut self, loss: ElementType) {
+        self.optimiser.observe_loss(loss);
+    }
+
+    fn learning_rate(&self) -> Option<ElementType> {
+        self.optimiser.learning_rate()
+    }
+
+    fn penalty(&self) -> ElementType {
+        self.optimiser.penalty(&self.initialised.parameter)
+    }
 }
 
 impl<'a, T: 'a + Optimiser<Tensor<rank::Two>>> forward::Forward<'a> for Operation<T> {
@@ -0,0 +1,67 @@
+use crate::operations::uninitialised::composite::ChainTarget;
+use crate::operations::{initialised, uninitialised, UninitialisedOperation};
+use crate::private::Sealed;
+use crate::{ElementType, Result};
+
+/// Represents the alpha dropout layer, which is the variant of dropout used in self-normalising
+/// networks built from `SELU` layers. Rather than setting dropped out neurons to 0, they are set
+/// to the value that `SELU` saturates to for large negative inputs, and the result is then scaled
+/// and shifted so that the mean and variance of the activations are kept the same. This means that,
+/// unlike `Dropout`, nothing needs to be scaled when making predictions so the input is passed
+/// through untouched.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Operation(uninitialised::dropout::Operation);
+
+impl Operation {
+    /// Constructs a new instance of the alpha dropout layer with the
+    /// specified keep probability.
+    #[must_use]
+    pub const fn new(keep_probability: ElementType) -> Self {
+        Self(uninitialised::dropout::Operation::new(keep_probability))
+    }
+}
+
+impl Sealed for Operation {}
+impl ChainTarget for Operation {}
+impl UninitialisedOperation for Operation {
+    type Initialised = initialised::alpha_dropout::Operation;
+
+    fn with_iter_private(
+        self,
+        iter: &mut impl Iterator<Item = ElementType>,
+        input_neuron_count: u16,
+    ) -> Result<(Self::Initialised, u16)> {
+        let (dropout, neurons) = self.0.with_iter_private(iter, input_neuron_count)?;
+        Ok((initialised::alpha_dropout::Operation(dropout), neurons))
+    }
+
+    fn with_seed_private(self, seed: u64, input_neuron_count: u16) -> (Self::Initialised, u16) {
+        let (dropout, neurons) = self.0.with_seed_private(seed, input_neuron_count);
+        (initialised::alpha_dropout::Operation(dropout), neurons)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_iter_private() {
+        // Arrange
+        let uninitialised = Operation::new(0.9);
+        let expected = initialised::alpha_dropout::Operation(initialised::dropout::Operation {
+            keep_probability: 0.9,
+            seed: None,
+            inverted: false,
+        });
+
+        // Act
+        let (output, neurons) = uninitialised
+            .with_iter_private(&mut core::iter::empty(), 4)
+            .unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+        assert_eq!(neurons, 4);
+    }
+}
@@ -1,7 +1,7 @@
 use crate::operations::{initialised, UninitialisedOperation};
 use crate::private::Sealed;
 use crate::tensors::{rank, Tensor};
-use crate::{ElementType, Result};
+use crate::{ElementType, Error, Result};
 use core::iter::repeat_with;
 use rand::rngs::StdRng;
 use rand::{Rng, SeedableRng};
@@ -9,7 +9,7 @@ use rand::{Rng, SeedableRng};
 /// This operation performs the bias addition portion of a dense layer.
 /// The bias is a tensor that is added in at the end of the weighted sum
 /// before passing through an activation function.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Operation {
     neurons: u16,
 }
@@ -35,7 +35,13 @@ impl UninitialisedOperation for Operation {
     ) -> Result<(Self::Initialised, u16)> {
         let weight_dim = (1, self.neurons as usize);
         let weight_count = weight_dim.0 * weight_dim.1;
-        let parameter = Tensor::<rank::Two>::new(weight_dim, iter.take(weight_count))?;
+        let parameter =
+            Tensor::<rank::Two>::new(weight_dim, iter.take(weight_count)).map_err(|_| {
+                Error::InsufficientWeights {
+                    layer_index: 0,
+                    expected: weight_count,
+                }
+            })?;
         Ok((initialised::bias_add::Operation { parameter }, self.neurons))
     }
 
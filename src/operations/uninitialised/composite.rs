@@ -1,12 +1,14 @@
+use crate::initialisers::Initialiser;
 use crate::operations::{initialised, InitialisedOperation, UninitialisedOperation};
 use crate::private::Sealed;
 use crate::{ElementType, Result};
+use alloc::vec::Vec;
 
 /// This structure represents a composite, or a chained
 /// layer. This is most likely constructed by calling the `.chain(ChainTarget)` method
 /// on a `Chain` implementation, which is implemented by the input layer and the composite
 /// layer itself.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Operation<T, U> {
     lhs: T,
     rhs: U,
@@ -53,7 +55,16 @@ where
         input_neuron_count: u16,
     ) -> Result<(Self::Initialised, u16)> {
         let (lhs, input_neuron_count) = self.lhs.with_iter_private(iter, input_neuron_count)?;
-        let (rhs, input_neuron_count) = self.rhs.with_iter_private(iter, input_neuron_count)?;
+        let (rhs, input_neuron_count) = self
+            .rhs
+            .with_iter_private(iter, input_neuron_count)
+            .map_err(|error| {
+                // layer indices in the rhs are reported relative to it, so offset them by the
+                // number of parameterised layers that have already been initialised.
+                let mut layers = Vec::new();
+                lhs.push_layer_parameters(&mut layers);
+                error.offset_layer_index(layers.len())
+            })?;
         let initialised = Self::Initialised { lhs, rhs };
         Ok((initialised, input_neuron_count))
     }
@@ -64,6 +75,17 @@ where
         let initialised = Self::Initialised { lhs, rhs };
         (initialised, input_neuron_count)
     }
+
+    fn with_initialiser_private(self, initialiser: Initialiser) -> Self {
+        Self {
+            lhs: self.lhs.with_initialiser_private(initialiser),
+            rhs: self.rhs.with_initialiser_private(initialiser),
+        }
+    }
+
+    fn input_neuron_count(&self) -> Option<u16> {
+        self.lhs.input_neuron_count()
+    }
 }
 
 #[cfg(test)]
@@ -71,6 +93,9 @@ mod tests {
     use super::*;
     use crate::activations::Sigmoid;
     use crate::layers::{Dense, Input};
+    use crate::operations::InitialisedOperation;
+    use crate::Error;
+    use alloc::vec::Vec;
 
     #[test]
     fn test_with_iter_private_success() {
@@ -104,7 +129,34 @@ mod tests {
         let result = composite.with_iter_private(&mut iter, 0);
 
         // Assert
-        assert!(result.is_err());
+        assert_eq!(
+            result.err(),
+            Some(Error::InsufficientWeights {
+                layer_index: 0,
+                expected: 8
+            })
+        );
+    }
+
+    #[test]
+    fn test_with_iter_private_failure_layer_index() {
+        // Arrange
+        let composite = Input::new(2)
+            .chain(Dense::new(2, Sigmoid::new()))
+            .chain(Dense::new(1, Sigmoid::new()));
+        let mut iter = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0].into_iter();
+
+        // Act
+        let result = composite.with_iter_private(&mut iter, 0);
+
+        // Assert
+        assert_eq!(
+            result.err(),
+            Some(Error::InsufficientWeights {
+                layer_index: 1,
+                expected: 3
+            })
+        );
     }
 
     #[test]
@@ -123,4 +175,63 @@ mod tests {
         assert_eq!(output, expected);
         assert_eq!(output_neurons, 2);
     }
+
+    #[test]
+    fn test_with_iter_and_seed() {
+        // Arrange
+        let network = || {
+            Input::new(2)
+                .chain(Dense::new(1, Sigmoid::new()))
+                .chain(Dense::new(2, Sigmoid::new()))
+        };
+        let random = network().with_seed(42).iter().collect::<Vec<_>>();
+
+        // Act
+        let output = network()
+            .with_iter_and_seed([1.0, 2.0, 3.0].into_iter(), 42)
+            .unwrap();
+
+        // Assert
+        let output = output.iter().collect::<Vec<_>>();
+        assert_eq!(output[..3], [1.0, 2.0, 3.0]);
+        assert_eq!(output[3..], random[3..]);
+    }
+
+    #[test]
+    fn test_with_iter_and_seed_failure() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(1, Sigmoid::new()))
+            .chain(Dense::new(2, Sigmoid::new()));
+
+        // Act
+        let output = network.with_iter_and_seed([1.0, 2.0, 3.0, 4.0].into_iter(), 42);
+
+        // Assert
+        assert_eq!(
+            output,
+            Err(Error::InsufficientWeights {
+                layer_index: 1,
+                expected: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_with_seed_and_initialiser() {
+        // Arrange
+        // the last layer chose its own initialiser, so only the first uses the given one.
+        let network = Input::new(2)
+            .chain(Dense::new(2, Sigmoid::new()))
+            .chain(Dense::new(1, Sigmoid::new()).with_initialiser(Initialiser::Zeros));
+
+        // Act
+        let initialised = network.with_seed_and_initialiser(42, Initialiser::Constant(0.5));
+
+        // Assert
+        let mut layers = Vec::new();
+        initialised.push_layer_parameters(&mut layers);
+        assert!(layers[0].1[..4].iter().all(|weight| *weight == 0.5));
+        assert!(layers[1].1[..2].iter().all(|weight| *weight == 0.0));
+    }
 }
@@ -0,0 +1,178 @@
+use crate::activations::ActivationFunction;
+use crate::initialisers::Initialiser;
+use crate::operations::initialised::conv1d::Window;
+use crate::operations::uninitialised::composite::ChainTarget;
+use crate::operations::{initialised, uninitialised, InitialisedOperation, UninitialisedOperation};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+
+/// This is a 1D convolutional layer which slides a number of kernels (filters) along the time
+/// steps of a sequence, producing a feature per filter at each position that is passed through an
+/// activation function. Sequences are rank 3 tensors with the shape (batch, steps, channels), the
+/// same as the input to a recurrent layer, and the output has the shape (batch, steps, filters).
+///
+/// The kernel is moved by the stride at each step, and the sequence is padded with the given
+/// number of zeros at the start and end before the kernel is applied. The kernel can also be
+/// dilated so that it skips over steps of the sequence, covering a wider span with the same
+/// number of parameters. The number of input channels multiplied by the size of the kernel must
+/// fit in a `u16`, the same as the neuron count of a dense layer.
+///
+/// The parameters are ordered the same as for a dense layer, with the kernels as a matrix that
+/// has a row for each kernel position and channel (in that order) and a column for each
+/// filter, followed by a bias for each filter.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Operation<T> {
+    kernel_size: usize,
+    stride: usize,
+    padding: usize,
+    dilation: usize,
+    dense: uninitialised::dense::Operation<T>,
+}
+
+impl<T: ActivationFunction> Operation<T> {
+    /// Constructs a new convolutional layer with the given number of filters, the size of the
+    /// kernel, the stride and padding to use, and the activation function to use. The kernel
+    /// isn't dilated unless `with_dilation` is used.
+    pub const fn new(
+        filters: u16,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        activation_function: T,
+    ) -> Self {
+        Self {
+            kernel_size,
+            stride,
+            padding,
+            dilation: 1,
+            dense: uninitialised::dense::Operation::new(filters, activation_function),
+        }
+    }
+
+    /// Sets the dilation of the kernel, which is the distance between the steps of the sequence
+    /// that neighbouring positions of the kernel are applied to. A dilation of 1 (the default)
+    /// applies the kernel to consecutive steps.
+    #[must_use]
+    pub fn with_dilation(self, dilation: usize) -> Self {
+        Self { dilation, ..self }
+    }
+
+    /// Sets the strategy used to generate the kernels of this layer when the network
+    /// is initialised from a random seed. By default, Xavier uniform initialisation is used.
+    #[must_use]
+    pub fn with_initialiser(self, initialiser: Initialiser) -> Self {
+        Self {
+            dense: self.dense.with_initialiser(initialiser),
+            ..self
+        }
+    }
+
+    const fn window(&self, channels: u16) -> Window {
+        Window {
+            channels,
+            kernel_size: self.kernel_size,
+            stride: self.stride,
+            padding: self.padding,
+            dilation: self.dilation,
+        }
+    }
+
+    fn patch_neurons(&self, channels: u16) -> Option<u16> {
+        u16::try_from(usize::from(channels) * self.kernel_size).ok()
+    }
+}
+
+impl<T> Sealed for Operation<T> {}
+impl<T> ChainTarget for Operation<T> {}
+impl<T: ActivationFunction> UninitialisedOperation for Operation<T>
+where
+    <T as UninitialisedOperation>::Initialised:
+        InitialisedOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+{
+    type Initialised =
+        initialised::conv1d::Operation<initialised::dense::Operation<T::Initialised>>;
+
+    fn with_iter_private(
+        self,
+        iter: &mut impl Iterator<Item = ElementType>,
+        input_neuron_count: u16,
+    ) -> Result<(Self::Initialised, u16)> {
+        let window = self.window(input_neuron_count);
+        let patch_neurons = self
+            .patch_neurons(input_neuron_count)
+            .ok_or(Error::InvalidArgument)?;
+        let (dense, output_neurons) = self.dense.with_iter_private(iter, patch_neurons)?;
+        Ok((Self::Initialised { window, dense }, output_neurons))
+    }
+
+    fn with_seed_private(self, seed: u64, input_neuron_count: u16) -> (Self::Initialised, u16) {
+        let window = self.window(input_neuron_count);
+        let patch_neurons = self.patch_neurons(input_neuron_count).unwrap(); // see the documentation on the layer for this limit
+        let (dense, output_neurons) = self.dense.with_seed_private(seed, patch_neurons);
+        (Self::Initialised { window, dense }, output_neurons)
+    }
+
+    fn with_initialiser_private(self, initialiser: Initialiser) -> Self {
+        Self {
+            dense: self.dense.with_initialiser_private(initialiser),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::ReLU;
+    use crate::layers::{Conv1D, Dense};
+
+    #[test]
+    fn test_with_iter_private_success() {
+        // Arrange
+        let operation = Conv1D::new(2, 3, 1, 1, ReLU::new());
+        let mut iter = (0u8..14).map(ElementType::from);
+        let expected_dense = Dense::new(2, ReLU::new())
+            .with_iter_private(&mut (0u8..14).map(ElementType::from), 6)
+            .unwrap()
+            .0;
+
+        // Act
+        let (output, output_neurons) = operation.with_iter_private(&mut iter, 2).unwrap();
+
+        // Assert
+        assert_eq!(output.dense, expected_dense);
+        assert_eq!(output.window.channels, 2);
+        assert_eq!(output_neurons, 2);
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_with_iter_private_failure() {
+        // Arrange
+        let operation = Conv1D::new(2, 3, 1, 1, ReLU::new());
+        let mut iter = (0u8..13).map(ElementType::from);
+
+        // Act
+        let result = operation.with_iter_private(&mut iter, 2);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_with_seed_private() {
+        // Arrange
+        let operation = Conv1D::new(4, 3, 2, 0, ReLU::new()).with_dilation(2);
+        let expected_dense = Dense::new(4, ReLU::new()).with_seed_private(42, 9).0;
+
+        // Act
+        let (output, output_neurons) = operation.with_seed_private(42, 3);
+
+        // Assert
+        assert_eq!(output.dense, expected_dense);
+        assert_eq!(output.window.stride, 2);
+        assert_eq!(output.window.dilation, 2);
+        assert_eq!(output_neurons, 4);
+    }
+}
@@ -0,0 +1,186 @@
+use crate::activations::ActivationFunction;
+use crate::initialisers::Initialiser;
+use crate::operations::initialised::conv2d::Window;
+use crate::operations::uninitialised::composite::ChainTarget;
+use crate::operations::{initialised, uninitialised, InitialisedOperation, UninitialisedOperation};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+
+/// This is a 2D convolutional layer which slides a number of square kernels (filters) over the
+/// channels of an image, producing a feature map per filter that is passed through an activation
+/// function. Images are rank 4 tensors with the shape (batch, channels, height, width).
+///
+/// The kernel is moved by the stride in each direction, and the image is padded with the given
+/// number of zeros on every side before the kernel is applied. The number of input channels
+/// multiplied by the area of the kernel must fit in a `u16`, the same as the neuron count of a
+/// dense layer.
+///
+/// The parameters are ordered the same as for a dense layer, with the kernels as a matrix that
+/// has a row for each channel and kernel position (in that order) and a column for each
+/// filter, followed by a bias for each filter.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Operation<T> {
+    kernel_size: usize,
+    stride: usize,
+    padding: usize,
+    dense: uninitialised::dense::Operation<T>,
+}
+
+impl<T: ActivationFunction> Operation<T> {
+    /// Constructs a new convolutional layer with the given number of filters, the size of each
+    /// side of the square kernel, the stride and padding to use, and the activation function to use.
+    pub const fn new(
+        filters: u16,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        activation_function: T,
+    ) -> Self {
+        Self {
+            kernel_size,
+            stride,
+            padding,
+            dense: uninitialised::dense::Operation::new(filters, activation_function),
+        }
+    }
+
+    /// Constructs a new pointwise (1x1) convolutional layer with the given number of filters and
+    /// activation function, which mixes the channels of each pixel without looking at its
+    /// neighbours. Placed after a `DepthwiseConv2D`, this completes a depthwise separable convolution.
+    pub const fn pointwise(filters: u16, activation_function: T) -> Self {
+        Self::new(filters, 1, 1, 0, activation_function)
+    }
+
+    /// Sets the strategy used to generate the kernels of this layer when the network
+    /// is initialised from a random seed. By default, Xavier uniform initialisation is used.
+    #[must_use]
+    pub fn with_initialiser(self, initialiser: Initialiser) -> Self {
+        Self {
+            dense: self.dense.with_initialiser(initialiser),
+            ..self
+        }
+    }
+
+    const fn window(&self, channels: u16) -> Window {
+        Window {
+            channels,
+            kernel_size: self.kernel_size,
+            stride: self.stride,
+            padding: self.padding,
+        }
+    }
+
+    fn patch_neurons(&self, channels: u16) -> Option<u16> {
+        let patch_neurons = usize::from(channels) * self.kernel_size * self.kernel_size;
+        u16::try_from(patch_neurons).ok()
+    }
+}
+
+impl<T> Sealed for Operation<T> {}
+impl<T> ChainTarget for Operation<T> {}
+impl<T: ActivationFunction> UninitialisedOperation for Operation<T>
+where
+    <T as UninitialisedOperation>::Initialised:
+        InitialisedOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+{
+    type Initialised =
+        initialised::conv2d::Operation<initialised::dense::Operation<T::Initialised>>;
+
+    fn with_iter_private(
+        self,
+        iter: &mut impl Iterator<Item = ElementType>,
+        input_neuron_count: u16,
+    ) -> Result<(Self::Initialised, u16)> {
+        let window = self.window(input_neuron_count);
+        let patch_neurons = self
+            .patch_neurons(input_neuron_count)
+            .ok_or(Error::InvalidArgument)?;
+        let (dense, output_neurons) = self.dense.with_iter_private(iter, patch_neurons)?;
+        Ok((Self::Initialised { window, dense }, output_neurons))
+    }
+
+    fn with_seed_private(self, seed: u64, input_neuron_count: u16) -> (Self::Initialised, u16) {
+        let window = self.window(input_neuron_count);
+        let patch_neurons = self.patch_neurons(input_neuron_count).unwrap(); // see the documentation on the layer for this limit
+        let (dense, output_neurons) = self.dense.with_seed_private(seed, patch_neurons);
+        (Self::Initialised { window, dense }, output_neurons)
+    }
+
+    fn with_initialiser_private(self, initialiser: Initialiser) -> Self {
+        Self {
+            dense: self.dense.with_initialiser_private(initialiser),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::ReLU;
+    use crate::layers::{Conv2D, Dense};
+
+    #[test]
+    fn test_with_iter_private_success() {
+        // Arrange
+        let operation = Conv2D::new(2, 3, 1, 1, ReLU::new());
+        let mut iter = (0u8..38).map(ElementType::from);
+        let expected_dense = Dense::new(2, ReLU::new())
+            .with_iter_private(&mut (0u8..38).map(ElementType::from), 18)
+            .unwrap()
+            .0;
+
+        // Act
+        let (output, output_neurons) = operation.with_iter_private(&mut iter, 2).unwrap();
+
+        // Assert
+        assert_eq!(output.dense, expected_dense);
+        assert_eq!(output.window.channels, 2);
+        assert_eq!(output_neurons, 2);
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_with_iter_private_failure() {
+        // Arrange
+        let operation = Conv2D::new(2, 3, 1, 1, ReLU::new());
+        let mut iter = (0u8..37).map(ElementType::from);
+
+        // Act
+        let result = operation.with_iter_private(&mut iter, 2);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_with_seed_private() {
+        // Arrange
+        let operation = Conv2D::new(4, 3, 2, 0, ReLU::new());
+        let expected_dense = Dense::new(4, ReLU::new()).with_seed_private(42, 27).0;
+
+        // Act
+        let (output, output_neurons) = operation.with_seed_private(42, 3);
+
+        // Assert
+        assert_eq!(output.dense, expected_dense);
+        assert_eq!(output.window.stride, 2);
+        assert_eq!(output_neurons, 4);
+    }
+
+    #[test]
+    fn test_pointwise() {
+        // Arrange
+        let operation = Conv2D::pointwise(4, ReLU::new());
+
+        // Act
+        let (output, output_neurons) = operation.with_seed_private(42, 3);
+
+        // Assert
+        assert_eq!(output.window.kernel_size, 1);
+        assert_eq!(output.window.stride, 1);
+        assert_eq!(output.window.padding, 0);
+        assert_eq!(output_neurons, 4);
+    }
+}
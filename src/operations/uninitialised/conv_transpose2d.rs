@@ -0,0 +1,209 @@
+use crate::activations::ActivationFunction;
+use crate::initialisers::Initialiser;
+use crate::operations::initialised::conv2d::Window;
+use crate::operations::uninitialised::composite::ChainTarget;
+use crate::operations::{initialised, uninitialised, InitialisedOperation, UninitialisedOperation};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+
+/// This is a 2D transposed convolutional layer (sometimes called a deconvolution) which spreads
+/// each pixel of the input across a square patch of every output channel, upsampling the image
+/// in a learned way. It's the counterpart of `Conv2D` used in the decoders of autoencoders and
+/// segmentation networks. Images are rank 4 tensors with the shape (batch, channels, height, width).
+///
+/// The patches are placed the stride apart, and the given number of pixels are then cropped from
+/// every side of the output, so that a `Conv2D` with the same kernel size, stride and padding turns
+/// an image of the output size back into an image of the input size. Each output channel has a bias
+/// and the result is passed through an activation function. The number of filters multiplied by the
+/// area of the kernel must fit in a `u16`, the same as the neuron count of a dense layer.
+///
+/// The parameters are ordered as a matrix with a row for each input channel and a column for each
+/// filter and kernel position (in that order), followed by a bias for each filter.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Operation<T> {
+    filters: u16,
+    kernel_size: usize,
+    stride: usize,
+    padding: usize,
+    initialiser: Option<Initialiser>,
+    bias_add: uninitialised::bias_add::Operation,
+    activation_function: T,
+}
+
+impl<T: ActivationFunction> Operation<T> {
+    /// Constructs a new transposed convolutional layer with the given number of filters, the size
+    /// of each side of the square kernel, the stride and padding to use, and the activation function
+    /// to use.
+    pub const fn new(
+        filters: u16,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        activation_function: T,
+    ) -> Self {
+        Self {
+            filters,
+            kernel_size,
+            stride,
+            padding,
+            initialiser: None,
+            bias_add: uninitialised::bias_add::Operation::new(filters),
+            activation_function,
+        }
+    }
+
+    /// Sets the strategy used to generate the kernels of this layer when the network
+    /// is initialised from a random seed. By default, Xavier uniform initialisation is used.
+    #[must_use]
+    pub fn with_initialiser(self, initialiser: Initialiser) -> Self {
+        Self {
+            initialiser: Some(initialiser),
+            ..self
+        }
+    }
+
+    const fn window(&self) -> Window {
+        Window {
+            channels: self.filters,
+            kernel_size: self.kernel_size,
+            stride: self.stride,
+            padding: self.padding,
+        }
+    }
+
+    /// Gets the operation multiplying each pixel of the input by the kernels, which produces
+    /// a patch of every filter for each pixel.
+    fn weight_multiply(&self) -> Option<uninitialised::weight_multiply::Operation> {
+        let patch_neurons = usize::from(self.filters) * self.kernel_size * self.kernel_size;
+        Some(uninitialised::weight_multiply::Operation {
+            output_neurons: u16::try_from(patch_neurons).ok()?,
+            initialiser: self.initialiser,
+        })
+    }
+}
+
+impl<T> Sealed for Operation<T> {}
+impl<T> ChainTarget for Operation<T> {}
+impl<T: ActivationFunction> UninitialisedOperation for Operation<T>
+where
+    <T as UninitialisedOperation>::Initialised:
+        InitialisedOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+{
+    type Initialised = initialised::conv_transpose2d::Operation<T::Initialised>;
+
+    fn with_iter_private(
+        self,
+        iter: &mut impl Iterator<Item = ElementType>,
+        input_neuron_count: u16,
+    ) -> Result<(Self::Initialised, u16)> {
+        // the kernels and biases are a single layer, so report the total parameter count if either is short.
+        let (window, filters) = (self.window(), self.filters);
+        let weight_multiply = self.weight_multiply().ok_or(Error::InvalidArgument)?;
+        let insufficient_weights = |_| Error::InsufficientWeights {
+            layer_index: 0,
+            expected: (input_neuron_count as usize * weight_multiply.output_neurons as usize)
+                + filters as usize,
+        };
+        let weight_multiply = weight_multiply
+            .clone()
+            .with_iter_private(iter, input_neuron_count);
+        let (weight_multiply, _) = weight_multiply.map_err(insufficient_weights)?;
+        let bias_add = self.bias_add.with_iter_private(iter, input_neuron_count);
+        let (bias_add, _) = bias_add.map_err(insufficient_weights)?;
+        let activation_function = self.activation_function;
+        let activation_function = activation_function.with_iter_private(iter, filters)?.0;
+        let initialised = Self::Initialised {
+            window,
+            weight_multiply,
+            bias_add,
+            activation_function,
+        };
+        Ok((initialised, filters))
+    }
+
+    fn with_seed_private(self, seed: u64, input_neuron_count: u16) -> (Self::Initialised, u16) {
+        let (window, filters) = (self.window(), self.filters);
+        let weight_multiply = self.weight_multiply().unwrap(); // see the documentation on the layer for this limit
+        let (weight_multiply, _) = weight_multiply.with_seed_private(seed, input_neuron_count);
+        let (bias_add, _) = self
+            .bias_add
+            .with_seed_private(seed + 1, input_neuron_count);
+        let activation_function = self.activation_function;
+        let (activation_function, _) = activation_function.with_seed_private(seed + 2, filters);
+        let initialised = Self::Initialised {
+            window,
+            weight_multiply,
+            bias_add,
+            activation_function,
+        };
+        (initialised, filters)
+    }
+
+    fn with_initialiser_private(self, initialiser: Initialiser) -> Self {
+        Self {
+            initialiser: self.initialiser.or(Some(initialiser)),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::ReLU;
+    use crate::layers::ConvTranspose2D;
+
+    #[test]
+    fn test_with_iter_private_success() {
+        // Arrange
+        let operation = ConvTranspose2D::new(2, 2, 2, 0, ReLU::new());
+        let mut iter = (0u8..26).map(ElementType::from);
+        let expected_weights = Tensor::<rank::Two>::new((3, 8), (0u8..24).map(ElementType::from));
+        let expected_bias = Tensor::<rank::Two>::new((1, 2), [24.0, 25.0]);
+
+        // Act
+        let (output, output_neurons) = operation.with_iter_private(&mut iter, 3).unwrap();
+
+        // Assert
+        assert_eq!(Ok(output.weight_multiply.parameter), expected_weights);
+        assert_eq!(Ok(output.bias_add.parameter), expected_bias);
+        assert_eq!(output.window.channels, 2);
+        assert_eq!(output_neurons, 2);
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_with_iter_private_failure() {
+        // Arrange
+        let operation = ConvTranspose2D::new(2, 2, 2, 0, ReLU::new());
+        let mut iter = (0u8..25).map(ElementType::from);
+
+        // Act
+        let result = operation.with_iter_private(&mut iter, 3);
+
+        // Assert
+        assert_eq!(
+            result.map(|_| ()),
+            Err(Error::InsufficientWeights {
+                layer_index: 0,
+                expected: 26
+            })
+        );
+    }
+
+    #[test]
+    fn test_with_seed_private() {
+        // Arrange
+        let operation = ConvTranspose2D::new(4, 3, 2, 1, ReLU::new());
+
+        // Act
+        let (output, output_neurons) = operation.with_seed_private(42, 3);
+
+        // Assert
+        assert_eq!(output.weight_multiply.parameter.dim(), (3, 36));
+        assert_eq!(output.bias_add.parameter.dim(), (1, 4));
+        assert_eq!(output.window.stride, 2);
+        assert_eq!(output_neurons, 4);
+    }
+}
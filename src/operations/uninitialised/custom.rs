@@ -0,0 +1,314 @@
+use crate::initialisers::Initialiser;
+use crate::operations::uninitialised::composite::ChainTarget;
+use crate::operations::{initialised, UninitialisedOperation};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// This trait can be implemented to define a layer which isn't provided by the library. The layer
+/// works on rank 2 tensors with the shape (batch, neurons) and has a flat list of parameters, and only
+/// needs to define how to calculate its output and gradients. Wrapping it in a `Custom` layer then allows
+/// it to be chained, initialised, trained and used for inference in the same way as any other layer.
+///
+/// Unlike most traits in the library this trait isn't sealed, so that it can be implemented outside of the crate.
+pub trait CustomLayer {
+    /// Gets the number of neurons that the layer outputs given the number of neurons that are input to it.
+    fn output_neuron_count(&self, input_neuron_count: u16) -> u16;
+
+    /// Gets the number of parameters that the layer has given the number of neurons that are input to it.
+    fn parameter_count(&self, input_neuron_count: u16) -> usize;
+
+    /// Calculates the output of the layer for the given input, using the given parameters.
+    ///
+    /// # Errors
+    /// `Error` if the output can't be calculated such as due to the input being incorrectly shaped.
+    fn forward(
+        &self,
+        parameters: &[ElementType],
+        input: &Tensor<rank::Two>,
+    ) -> Result<Tensor<rank::Two>>;
+
+    /// Calculates the gradients of the layer given the parameters, the input that was passed
+    /// to `forward`, and the gradient of the loss with respect to the output. Returns the gradient
+    /// of the loss with respect to the input, followed by the gradient with respect to each parameter.
+    ///
+    /// # Errors
+    /// `Error` if the gradients can't be calculated such as due to the output gradient being incorrectly shaped.
+    fn backward(
+        &self,
+        parameters: &[ElementType],
+        input: &Tensor<rank::Two>,
+        output_gradient: &Tensor<rank::Two>,
+    ) -> Result<(Tensor<rank::Two>, Vec<ElementType>)>;
+
+    /// Invoked when the layer is initialised with the number of neurons that are input to it, before its parameters
+    /// are counted, so that anything which only depends on the shape of the input can be worked out once rather than
+    /// each time the layer is run. By default this does nothing.
+    fn prepare(&mut self, _input_neuron_count: u16) {}
+
+    /// Calculates the output of the layer in the same way as `forward` during training, pushing any values calculated
+    /// along the way that `backward_saved` can reuse (such as the inputs of inner layers) onto `saved`. By default
+    /// nothing is saved.
+    ///
+    /// # Errors
+    /// `Error` if the output can't be calculated such as due to the input being incorrectly shaped.
+    fn forward_saving(
+        &self,
+        parameters: &[ElementType],
+        input: &Tensor<rank::Two>,
+        _saved: &mut Vec<Tensor<rank::Two>>,
+    ) -> Result<Tensor<rank::Two>> {
+        self.forward(parameters, input)
+    }
+
+    /// Calculates the gradients in the same way as `backward`, popping the values that `forward_saving` pushed
+    /// onto `saved` (in reverse order). By default nothing is popped and `backward` is called.
+    ///
+    /// # Errors
+    /// `Error` if the gradients can't be calculated such as due to the output gradient being incorrectly shaped.
+    fn backward_saved(
+        &self,
+        parameters: &[ElementType],
+        input: &Tensor<rank::Two>,
+        _saved: &mut Vec<Tensor<rank::Two>>,
+        output_gradient: &Tensor<rank::Two>,
+    ) -> Result<(Tensor<rank::Two>, Vec<ElementType>)> {
+        self.backward(parameters, input, output_gradient)
+    }
+}
+
+/// This is a layer which adapts a user defined `CustomLayer` so that it can take part in a network.
+/// The parameters of the layer are stored by this operation and passed to the custom layer whenever
+/// it's run, so that they can be initialised, optimised and saved along with the rest of the network.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Operation<L> {
+    layer: L,
+    initialiser: Option<Initialiser>,
+}
+
+impl<L: CustomLayer> Operation<L> {
+    /// Constructs a new layer which runs the given custom layer.
+    #[must_use]
+    pub const fn new(layer: L) -> Self {
+        Self {
+            layer,
+            initialiser: None,
+        }
+    }
+
+    /// Sets the strategy used to generate the parameters of this layer when the network
+    /// is initialised from a random seed. By default, Xavier uniform initialisation is used.
+    #[must_use]
+    pub fn with_initialiser(self, initialiser: Initialiser) -> Self {
+        Self {
+            layer: self.layer,
+            initialiser: Some(initialiser),
+        }
+    }
+}
+
+impl<L> Sealed for Operation<L> {}
+impl<L: CustomLayer> ChainTarget for Operation<L> {}
+impl<L: CustomLayer> UninitialisedOperation for Operation<L> {
+    type Initialised = initialised::custom::Operation<L>;
+
+    fn with_iter_private(
+        mut self,
+        iter: &mut impl Iterator<Item = ElementType>,
+        input_neuron_count: u16,
+    ) -> Result<(Self::Initialised, u16)> {
+        self.layer.prepare(input_neuron_count);
+        let count = self.layer.parameter_count(input_neuron_count);
+        let parameters = iter.take(count).collect::<Vec<_>>();
+        if parameters.len() < count {
+            return Err(Error::InsufficientWeights {
+                layer_index: 0,
+                expected: count,
+            });
+        }
+        let output_neuron_count = self.layer.output_neuron_count(input_neuron_count);
+        let initialised = Self::Initialised {
+            layer: self.layer,
+            parameters: Tensor::<rank::One>::new(parameters),
+        };
+        Ok((initialised, output_neuron_count))
+    }
+
+    fn with_seed_private(mut self, seed: u64, input_neuron_count: u16) -> (Self::Initialised, u16) {
+        self.layer.prepare(input_neuron_count);
+        let mut generator = StdRng::seed_from_u64(seed);
+        let output_neuron_count = self.layer.output_neuron_count(input_neuron_count);
+        let initialiser = self.initialiser.unwrap_or_default();
+        let parameters = (0..self.layer.parameter_count(input_neuron_count))
+            .map(|_| initialiser.sample(&mut generator, input_neuron_count, output_neuron_count))
+            .collect::<Vec<_>>();
+        self.with_iter_private(&mut parameters.into_iter(), input_neuron_count)
+            .unwrap() // unwrapping is safe because we've generated exactly enough parameters
+    }
+
+    fn with_initialiser_private(self, initialiser: Initialiser) -> Self {
+        Self {
+            initialiser: self.initialiser.or(Some(initialiser)),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::layers::{Chain, Input};
+    use crate::loss::MeanSquaredError;
+    use crate::operations::{InitialisedOperation, TrainableOperation, WithOptimiser};
+    use crate::optimisers::learning_rate_handlers::FixedLearningRateHandler;
+    use crate::optimisers::SGD;
+    use crate::training::train;
+
+    /// A custom layer which scales each neuron by its own parameter, implemented only with the
+    /// public API as a downstream crate would.
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Scale;
+
+    impl CustomLayer for Scale {
+        fn output_neuron_count(&self, input_neuron_count: u16) -> u16 {
+            input_neuron_count
+        }
+
+        fn parameter_count(&self, input_neuron_count: u16) -> usize {
+            input_neuron_count.into()
+        }
+
+        fn forward(
+            &self,
+            parameters: &[ElementType],
+            input: &Tensor<rank::Two>,
+        ) -> Result<Tensor<rank::Two>> {
+            if parameters.len() != input.ncols() {
+                return Err(Error::ShapeMismatch {
+                    expected: parameters.len(),
+                    actual: input.ncols(),
+                });
+            }
+            let output = input
+                .clone()
+                .into_iter()
+                .zip(parameters.iter().cycle())
+                .map(|(elem, parameter)| elem * parameter);
+            Tensor::<rank::Two>::new(input.dim(), output)
+        }
+
+        fn backward(
+            &self,
+            parameters: &[ElementType],
+            input: &Tensor<rank::Two>,
+            output_gradient: &Tensor<rank::Two>,
+        ) -> Result<(Tensor<rank::Two>, Vec<ElementType>)> {
+            if input.dim() != output_gradient.dim() {
+                return Err(Error::InvalidArgument);
+            }
+            let mut parameter_gradient = parameters.iter().map(|_| 0.0).collect::<Vec<_>>();
+            let input_gradient = input
+                .clone()
+                .into_iter()
+                .zip(output_gradient.clone())
+                .enumerate()
+                .map(|(index, (elem, gradient))| {
+                    let column = index % parameters.len();
+                    parameter_gradient[column] += elem * gradient;
+                    gradient * parameters[column]
+                })
+                .collect::<Vec<_>>();
+            let input_gradient = Tensor::<rank::Two>::new(input.dim(), input_gradient)?;
+            Ok((input_gradient, parameter_gradient))
+        }
+    }
+
+    #[test]
+    fn test_with_iter_private_success() {
+        // Arrange
+        let operation = Operation::new(Scale);
+        let mut iter = (1u8..=4).map(ElementType::from);
+        let expected = initialised::custom::Operation {
+            layer: Scale,
+            parameters: Tensor::<rank::One>::new([1.0, 2.0, 3.0]),
+        };
+
+        // Act
+        let (output, neurons) = operation.with_iter_private(&mut iter, 3).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+        assert_eq!(neurons, 3);
+        assert_eq!(iter.next(), Some(4.0));
+    }
+
+    #[test]
+    fn test_with_iter_private_failure() {
+        // Arrange
+        let operation = Operation::new(Scale);
+        let mut iter = (1u8..=2).map(ElementType::from);
+
+        // Act
+        let result = operation.with_iter_private(&mut iter, 3);
+
+        // Assert
+        assert_eq!(
+            result.err(),
+            Some(Error::InsufficientWeights {
+                layer_index: 0,
+                expected: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_with_seed_private() {
+        // Arrange
+        let operation = Operation::new(Scale);
+
+        // Act
+        let (output, neurons) = operation.with_seed_private(42, 4);
+
+        // Assert
+        assert_eq!(output.parameters.len(), 4);
+        assert_eq!(neurons, 4);
+    }
+
+    #[test]
+    fn test_training() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Operation::new(Scale))
+            .with_iter([1.0, 1.0].into_iter())
+            .unwrap()
+            .with_optimiser(SGD::new(FixedLearningRateHandler::new(0.05)));
+        let batch = Tensor::<rank::Two>::new((3, 2), [1.0, 1.0, 2.0, -1.0, -1.0, 2.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((3, 2), [3.0, -2.0, 6.0, 2.0, -3.0, -4.0]).unwrap();
+
+        // Act
+        let network = train(
+            network,
+            &MeanSquaredError::new(),
+            batch.clone(),
+            targets.clone(),
+            &batch,
+            &targets,
+            200,
+            200,
+            3,
+            42,
+        )
+        .unwrap()
+        .into_initialised();
+
+        // Assert
+        assert!(network
+            .iter()
+            .zip([3.0, -2.0])
+            .all(|(parameter, expected)| (parameter - expected).abs() < 1e-3));
+    }
+}
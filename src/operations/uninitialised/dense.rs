@@ -1,15 +1,17 @@
 use crate::activations::ActivationFunction;
+use crate::initialisers::Initialiser;
 use crate::operations::uninitialised::composite::ChainTarget;
 use crate::operations::{initialised, uninitialised, InitialisedOperation, UninitialisedOperation};
 use crate::private::Sealed;
 use crate::tensors::{rank, Tensor};
-use crate::{ElementType, Result};
+use crate::{ElementType, Error, Result};
 
 /// This is a "dense" layer which is the most common layer type in
 /// a neural network, consisting of a weighted sum of the input with some
 /// weights matrix, and a bias term added, and then passed through a specific
 /// activation function. This layer is therefore generic over the activation function
 /// in use.
+#[derive(Clone, Debug, PartialEq)]
 pub struct Operation<T> {
     weight_multiply: uninitialised::weight_multiply::Operation,
     bias_add: uninitialised::bias_add::Operation,
@@ -26,6 +28,16 @@ impl<T: ActivationFunction> Operation<T> {
             activation_function,
         }
     }
+
+    /// Sets the strategy used to generate the weights of this layer when the network
+    /// is initialised from a random seed. By default, Xavier uniform initialisation is used.
+    #[must_use]
+    pub fn with_initialiser(self, initialiser: Initialiser) -> Self {
+        Self {
+            weight_multiply: self.weight_multiply.with_initialiser(initialiser),
+            ..self
+        }
+    }
 }
 
 impl<T> Sealed for Operation<T> {}
@@ -42,10 +54,17 @@ where
         iter: &mut impl Iterator<Item = ElementType>,
         input_neuron_count: u16,
     ) -> Result<(Self::Initialised, u16)> {
+        // the weights and biases are a single layer, so report the total parameter count if either is short.
+        let output_neurons = self.weight_multiply.output_neurons as usize;
+        let insufficient_weights = |_| Error::InsufficientWeights {
+            layer_index: 0,
+            expected: (input_neuron_count as usize + 1) * output_neurons,
+        };
         let weight_multiply = self.weight_multiply;
         let weight_multiply = weight_multiply.with_iter_private(iter, input_neuron_count);
-        let (weight_multiply, output_neurons) = weight_multiply?;
-        let (bias_add, _) = self.bias_add.with_iter_private(iter, input_neuron_count)?;
+        let (weight_multiply, output_neurons) = weight_multiply.map_err(insufficient_weights)?;
+        let bias_add = self.bias_add.with_iter_private(iter, input_neuron_count);
+        let (bias_add, _) = bias_add.map_err(insufficient_weights)?;
         let activation_function = self.activation_function;
         let activation_function = activation_function.with_iter_private(iter, output_neurons);
         let activation_function = activation_function?.0;
@@ -77,12 +96,19 @@ where
         };
         (initialised, output_neurons)
     }
+
+    fn with_initialiser_private(self, initialiser: Initialiser) -> Self {
+        Self {
+            weight_multiply: self.weight_multiply.with_initialiser_private(initialiser),
+            ..self
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::activations::ReLU;
+    use crate::activations::{ReLU, SELU};
 
     #[test]
     fn test_with_iter_private_success() {
@@ -128,7 +154,13 @@ mod tests {
         let result = dense.with_iter_private(&mut iter, 1);
 
         // Assert
-        assert!(result.is_err());
+        assert_eq!(
+            result.err(),
+            Some(Error::InsufficientWeights {
+                layer_index: 0,
+                expected: 6
+            })
+        );
     }
 
     #[test]
@@ -185,4 +217,18 @@ mod tests {
         assert_eq!(dense, expected);
         assert_eq!(output_neurons, 3);
     }
+
+    #[test]
+    fn test_with_seed_private_lecun_normal() {
+        // Arrange
+        let dense = Operation::new(100, SELU::new()).with_initialiser(Initialiser::LeCunNormal);
+
+        // Act
+        let (dense, _) = dense.with_seed_private(42, 400);
+        let weights = &dense.weight_multiply.parameter.0;
+
+        // Assert
+        assert!(weights.mean().unwrap().abs() < 0.001);
+        assert!(weights.var(0.0).mul_add(400.0, -1.0).abs() < 0.02);
+    }
 }
@@ -0,0 +1,221 @@
+use crate::activations::ActivationFunction;
+use crate::initialisers::Initialiser;
+use crate::operations::initialised::conv2d::Window;
+use crate::operations::uninitialised::composite::ChainTarget;
+use crate::operations::{initialised, uninitialised, InitialisedOperation, UninitialisedOperation};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+
+/// This is a 2D depthwise convolutional layer which slides a separate set of square kernels over
+/// each channel of an image on its own, rather than combining the channels as `Conv2D` does. Each
+/// channel produces the given number of feature maps (the depth multiplier), and the feature maps
+/// of the first channel come first in the output. Images are rank 4 tensors with the shape
+/// (batch, channels, height, width).
+///
+/// Following this layer with `Conv2D::pointwise` to mix the channels gives a depthwise separable
+/// convolution, the building block of efficient models for mobile and embedded devices, which
+/// needs far fewer parameters and operations than a `Conv2D` of the same kernel size. The number of input channels multiplied by the area of the
+/// kernel, and the number of input channels multiplied by the depth multiplier, must both fit in
+/// a `u16`, the same as the neuron count of a dense layer.
+///
+/// The parameters are ordered as a matrix with a row for each channel and kernel position (in that
+/// order) and a column for each feature map of that channel, followed by a bias for each output
+/// channel.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Operation<T> {
+    multiplier: u16,
+    kernel_size: usize,
+    stride: usize,
+    padding: usize,
+    initialiser: Option<Initialiser>,
+    activation_function: T,
+}
+
+impl<T: ActivationFunction> Operation<T> {
+    /// Constructs a new depthwise convolutional layer with the given number of feature maps per
+    /// channel, the size of each side of the square kernel, the stride and padding to use, and the
+    /// activation function to use.
+    pub const fn new(
+        multiplier: u16,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        activation_function: T,
+    ) -> Self {
+        Self {
+            multiplier,
+            kernel_size,
+            stride,
+            padding,
+            initialiser: None,
+            activation_function,
+        }
+    }
+
+    /// Sets the strategy used to generate the kernels of this layer when the network
+    /// is initialised from a random seed. By default, Xavier uniform initialisation is used.
+    #[must_use]
+    pub fn with_initialiser(self, initialiser: Initialiser) -> Self {
+        Self {
+            initialiser: Some(initialiser),
+            ..self
+        }
+    }
+
+    const fn window(&self, channels: u16) -> Window {
+        Window {
+            channels,
+            kernel_size: self.kernel_size,
+            stride: self.stride,
+            padding: self.padding,
+        }
+    }
+
+    /// Gets the number of neurons in each unrolled patch, and the number of output channels.
+    fn neurons(&self, channels: u16) -> Option<(u16, u16)> {
+        let patch_neurons = usize::from(channels) * self.kernel_size * self.kernel_size;
+        let filters = channels.checked_mul(self.multiplier)?;
+        (channels > 0).then_some((u16::try_from(patch_neurons).ok()?, filters))
+    }
+
+    const fn depthwise_multiply(
+        &self,
+        channels: u16,
+    ) -> uninitialised::depthwise_multiply::Operation {
+        uninitialised::depthwise_multiply::Operation {
+            initialiser: self.initialiser,
+            ..uninitialised::depthwise_multiply::Operation::new(channels, self.multiplier)
+        }
+    }
+}
+
+impl<T> Sealed for Operation<T> {}
+impl<T> ChainTarget for Operation<T> {}
+impl<T: ActivationFunction> UninitialisedOperation for Operation<T>
+where
+    <T as UninitialisedOperation>::Initialised:
+        InitialisedOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+{
+    type Initialised = initialised::depthwise_conv2d::Operation<T::Initialised>;
+
+    fn with_iter_private(
+        self,
+        iter: &mut impl Iterator<Item = ElementType>,
+        input_neuron_count: u16,
+    ) -> Result<(Self::Initialised, u16)> {
+        let window = self.window(input_neuron_count);
+        let (patch_neurons, filters) = self
+            .neurons(input_neuron_count)
+            .ok_or(Error::InvalidArgument)?;
+        let expected = patch_neurons as usize * self.multiplier as usize + filters as usize;
+        let insufficient_weights = |_| Error::InsufficientWeights {
+            layer_index: 0,
+            expected,
+        };
+        let depthwise_multiply = self.depthwise_multiply(input_neuron_count);
+        let depthwise_multiply = depthwise_multiply.with_iter_private(iter, patch_neurons);
+        let (depthwise_multiply, _) = depthwise_multiply.map_err(insufficient_weights)?;
+        let bias_add = uninitialised::bias_add::Operation::new(filters);
+        let bias_add = bias_add.with_iter_private(iter, patch_neurons);
+        let (bias_add, _) = bias_add.map_err(insufficient_weights)?;
+        let activation_function = self.activation_function;
+        let activation_function = activation_function.with_iter_private(iter, filters)?.0;
+        let initialised = Self::Initialised {
+            window,
+            depthwise_multiply,
+            bias_add,
+            activation_function,
+        };
+        Ok((initialised, filters))
+    }
+
+    fn with_seed_private(self, seed: u64, input_neuron_count: u16) -> (Self::Initialised, u16) {
+        let window = self.window(input_neuron_count);
+        let (patch_neurons, filters) = self.neurons(input_neuron_count).unwrap(); // see the documentation on the layer for these limits
+        let depthwise_multiply = self.depthwise_multiply(input_neuron_count);
+        let (depthwise_multiply, _) = depthwise_multiply.with_seed_private(seed, patch_neurons);
+        let (bias_add, _) = uninitialised::bias_add::Operation::new(filters)
+            .with_seed_private(seed + 1, patch_neurons);
+        let activation_function = self.activation_function;
+        let (activation_function, _) = activation_function.with_seed_private(seed + 2, filters);
+        let initialised = Self::Initialised {
+            window,
+            depthwise_multiply,
+            bias_add,
+            activation_function,
+        };
+        (initialised, filters)
+    }
+
+    fn with_initialiser_private(self, initialiser: Initialiser) -> Self {
+        Self {
+            initialiser: self.initialiser.or(Some(initialiser)),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::ReLU;
+    use crate::layers::DepthwiseConv2D;
+
+    #[test]
+    fn test_with_iter_private_success() {
+        // Arrange
+        let operation = DepthwiseConv2D::new(2, 2, 1, 0, ReLU::new());
+        let mut iter = (0u8..30).map(ElementType::from);
+        let expected_weights = Tensor::<rank::Two>::new((12, 2), (0u8..24).map(ElementType::from));
+        let expected_bias = Tensor::<rank::Two>::new((1, 6), (24u8..30).map(ElementType::from));
+
+        // Act
+        let (output, output_neurons) = operation.with_iter_private(&mut iter, 3).unwrap();
+
+        // Assert
+        assert_eq!(Ok(output.depthwise_multiply.parameter), expected_weights);
+        assert_eq!(Ok(output.bias_add.parameter), expected_bias);
+        assert_eq!(output.depthwise_multiply.groups, 3);
+        assert_eq!(output.window.channels, 3);
+        assert_eq!(output_neurons, 6);
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_with_iter_private_failure() {
+        // Arrange
+        let operation = DepthwiseConv2D::new(2, 2, 1, 0, ReLU::new());
+        let mut iter = (0u8..29).map(ElementType::from);
+
+        // Act
+        let insufficient = operation.with_iter_private(&mut iter, 3);
+        let overflow = DepthwiseConv2D::new(u16::MAX, 1, 1, 0, ReLU::new())
+            .with_iter_private(&mut (0u8..10).map(ElementType::from), 2);
+
+        // Assert
+        assert_eq!(
+            insufficient.map(|_| ()),
+            Err(Error::InsufficientWeights {
+                layer_index: 0,
+                expected: 30
+            })
+        );
+        assert_eq!(overflow.map(|_| ()), Err(Error::InvalidArgument));
+    }
+
+    #[test]
+    fn test_with_seed_private() {
+        // Arrange
+        let operation = DepthwiseConv2D::new(1, 3, 2, 1, ReLU::new());
+
+        // Act
+        let (output, output_neurons) = operation.with_seed_private(42, 4);
+
+        // Assert
+        assert_eq!(output.depthwise_multiply.parameter.dim(), (36, 1));
+        assert_eq!(output.bias_add.parameter.dim(), (1, 4));
+        assert_eq!(output.window.stride, 2);
+        assert_eq!(output_neurons, 4);
+    }
+}
@@ -0,0 +1,153 @@
+use crate::initialisers::Initialiser;
+use crate::operations::{initialised, uninitialised};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use core::iter::repeat_with;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// This operation splits the input neurons into a number of equally sized groups, and performs a
+/// separate weighted sum of each group, so that no output neuron depends on more than one group.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Operation {
+    pub(crate) groups: u16,
+    pub(crate) output_neurons: u16, // per group
+    pub(crate) initialiser: Option<Initialiser>,
+}
+
+impl Operation {
+    /// This function constructs a new grouped weighted sum operation with the
+    /// given number of groups and neurons to output from each group.
+    #[must_use]
+    pub const fn new(groups: u16, output_neurons: u16) -> Self {
+        Self {
+            groups,
+            output_neurons,
+            initialiser: None,
+        }
+    }
+
+    /// Gets the number of input neurons in each group, and the total number of output neurons.
+    fn neurons(&self, input_neuron_count: u16) -> Option<(u16, u16)> {
+        let groups = self.groups;
+        if groups == 0 || !input_neuron_count.is_multiple_of(groups) {
+            return None;
+        }
+        let output_neurons = groups.checked_mul(self.output_neurons)?;
+        Some((input_neuron_count / groups, output_neurons))
+    }
+}
+
+impl Sealed for Operation {}
+impl uninitialised::Operation for Operation {
+    type Initialised = initialised::depthwise_multiply::Operation;
+
+    fn with_iter_private(
+        self,
+        iter: &mut impl Iterator<Item = ElementType>,
+        input_neuron_count: u16,
+    ) -> Result<(Self::Initialised, u16)> {
+        let (_, output_neurons) = self
+            .neurons(input_neuron_count)
+            .ok_or(Error::InvalidArgument)?;
+        let weight_dim = (input_neuron_count as usize, self.output_neurons as usize);
+        let weight_count = weight_dim.0 * weight_dim.1;
+        let parameter =
+            Tensor::<rank::Two>::new(weight_dim, iter.take(weight_count)).map_err(|_| {
+                Error::InsufficientWeights {
+                    layer_index: 0,
+                    expected: weight_count,
+                }
+            })?;
+        Ok((
+            initialised::depthwise_multiply::Operation {
+                groups: self.groups,
+                parameter,
+            },
+            output_neurons,
+        ))
+    }
+
+    fn with_seed_private(self, seed: u64, input_neuron_count: u16) -> (Self::Initialised, u16) {
+        let mut generator = StdRng::seed_from_u64(seed);
+        let (initialiser, output_neurons) =
+            (self.initialiser.unwrap_or_default(), self.output_neurons);
+        let group_neurons = self
+            .neurons(input_neuron_count)
+            .map_or(input_neuron_count, |(group_neurons, _)| group_neurons);
+        let mut iter =
+            repeat_with(|| initialiser.sample(&mut generator, group_neurons, output_neurons));
+        self.with_iter_private(&mut iter, input_neuron_count)
+            .unwrap() // see the documentation on the layer for the limits on the groups
+    }
+
+    fn with_initialiser_private(self, initialiser: Initialiser) -> Self {
+        Self {
+            initialiser: self.initialiser.or(Some(initialiser)),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::UninitialisedOperation;
+
+    #[test]
+    fn test_with_iter_private_success() {
+        // Arrange
+        let mut iter = (1u8..=8).map(ElementType::from);
+        let operation = Operation::new(2, 2);
+        let expected = initialised::depthwise_multiply::Operation {
+            groups: 2,
+            parameter: Tensor::<rank::Two>::new((4, 2), (1u8..=8).map(ElementType::from)).unwrap(),
+        };
+
+        // Act
+        let (operation, output_neurons) = operation.with_iter_private(&mut iter, 4).unwrap();
+
+        // Assert
+        assert_eq!(output_neurons, 4);
+        assert_eq!(operation, expected);
+    }
+
+    #[test]
+    fn test_with_iter_private_failure() {
+        // Arrange
+        let operation = Operation::new(2, 1);
+
+        // Act
+        let uneven = operation
+            .clone()
+            .with_iter_private(&mut [1.0; 3].into_iter(), 3);
+        let insufficient = operation.with_iter_private(&mut [1.0; 3].into_iter(), 4);
+
+        // Assert
+        assert_eq!(uneven.map(|_| ()), Err(Error::InvalidArgument));
+        assert_eq!(
+            insufficient.map(|_| ()),
+            Err(Error::InsufficientWeights {
+                layer_index: 0,
+                expected: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_with_seed_private() {
+        // Arrange
+        let operation = Operation {
+            initialiser: Some(Initialiser::LeCunNormal),
+            ..Operation::new(3, 2)
+        };
+
+        // Act
+        let (operation, output_neurons) = operation.with_seed_private(42, 6);
+
+        // Assert
+        assert_eq!(output_neurons, 6);
+        assert_eq!(operation.parameter.dim(), (6, 2));
+    }
+}
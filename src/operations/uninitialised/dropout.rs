@@ -7,9 +7,12 @@ use crate::{ElementType, Result};
 /// randomly drops out neurons (sets to 0) from the previous layer.
 /// When running in inference mode (making predictions), then the weights
 /// aren't dropped out but all weights are scaled using the keep probability.
-#[derive(Debug, PartialEq)]
+/// Alternatively, inverted scaling can be used, where the kept neurons are
+/// scaled up during training instead so that predictions pass straight through.
+#[derive(Clone, Debug, PartialEq)]
 pub struct Operation {
     keep_probability: ElementType,
+    inverted: bool,
 }
 
 impl Operation {
@@ -17,7 +20,22 @@ impl Operation {
     /// specified keep probability.
     #[must_use]
     pub const fn new(keep_probability: ElementType) -> Self {
-        Self { keep_probability }
+        Self {
+            keep_probability,
+            inverted: false,
+        }
+    }
+
+    /// Uses inverted dropout, which divides the neurons that are kept during training by the
+    /// keep probability rather than multiplying every neuron by it when making predictions.
+    /// The expected activations are the same either way, but this leaves predictions untouched
+    /// so a trained network can be used without the dropout layer at all.
+    #[must_use]
+    pub const fn with_inverted_scaling(self) -> Self {
+        Self {
+            inverted: true,
+            ..self
+        }
     }
 }
 
@@ -36,6 +54,7 @@ impl UninitialisedOperation for Operation {
         let initialised = Self::Initialised {
             keep_probability,
             seed,
+            inverted: self.inverted,
         };
         Ok((initialised, input_neuron_count))
     }
@@ -46,6 +65,7 @@ impl UninitialisedOperation for Operation {
         let initialised = Self::Initialised {
             keep_probability,
             seed,
+            inverted: self.inverted,
         };
         (initialised, input_neuron_count)
     }
@@ -59,7 +79,10 @@ mod tests {
     fn test_new() {
         // Arrange
         let keep_probability = 0.8;
-        let expected = Operation { keep_probability };
+        let expected = Operation {
+            keep_probability,
+            inverted: false,
+        };
 
         // Act
         let output = Operation::new(keep_probability);
@@ -71,7 +94,7 @@ mod tests {
     #[test]
     fn test_with_iter_private() {
         // Arrange
-        let mut iter = [].into_iter();
+        let mut iter = core::iter::empty();
         let keep_probability = 0.8;
         let input_neuron_count = 3;
         let seed: Option<u64> = None;
@@ -79,6 +102,7 @@ mod tests {
             initialised::dropout::Operation {
                 keep_probability,
                 seed,
+                inverted: false,
             },
             3,
         );
@@ -103,6 +127,7 @@ mod tests {
             initialised::dropout::Operation {
                 keep_probability,
                 seed: Some(seed),
+                inverted: false,
             },
             3,
         );
@@ -114,4 +139,16 @@ mod tests {
         // Assert
         assert_eq!(output, expected);
     }
+
+    #[test]
+    fn test_with_inverted_scaling() {
+        // Arrange
+        let uninitialised = Operation::new(0.8).with_inverted_scaling();
+
+        // Act
+        let (output, _) = uninitialised.with_seed_private(42, 3);
+
+        // Assert
+        assert!(output.inverted);
+    }
 }
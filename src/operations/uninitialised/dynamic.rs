@@ -0,0 +1,508 @@
+use crate::operations::uninitialised::custom::CustomLayer;
+use crate::operations::{
+    BackwardOperation, Forward, ForwardOperation, InitialisedOperation, TrainableOperation,
+    UninitialisedOperation, WithOptimiser,
+};
+use crate::optimisers::capture;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Formatter};
+
+/// A layer of a dynamic network, which is a custom layer that can be cloned behind a box. Layers are
+/// required to be `Send` and `Sync` so that the network can be shared between threads.
+trait Layer: CustomLayer + Send + Sync {
+    fn clone_box(&self) -> Box<dyn Layer>;
+}
+
+impl<T: CustomLayer + Clone + Send + Sync + 'static> Layer for T {
+    fn clone_box(&self) -> Box<dyn Layer> {
+        Box::new(self.clone())
+    }
+}
+
+/// Adapts one of the layers provided by the library into a custom layer by initialising
+/// it from the given parameters each time it's run. The number of parameters and output neurons
+/// are cached along with the number of input neurons they were worked out for.
+#[derive(Clone)]
+struct Builtin<U> {
+    layer: U,
+    counts: Option<(u16, usize, u16)>,
+}
+
+impl<U: UninitialisedOperation + Clone> Builtin<U> {
+    fn initialise(
+        &self,
+        parameters: &[ElementType],
+        input_neuron_count: u16,
+    ) -> Result<(U::Initialised, u16)> {
+        self.layer
+            .clone()
+            .with_iter_private(&mut parameters.iter().copied(), input_neuron_count)
+    }
+
+    /// Gets the number of parameters and output neurons for the given number of input neurons,
+    /// from the cache if they've already been worked out for it, otherwise by initialising the
+    /// layer with zeros (so that no random weights are generated).
+    fn counts(&self, input_neuron_count: u16) -> (usize, u16) {
+        match self.counts {
+            Some((input, parameters, output)) if input == input_neuron_count => {
+                (parameters, output)
+            }
+            _ => {
+                let (initialised, output) = self
+                    .layer
+                    .clone()
+                    .with_iter_private(&mut core::iter::repeat(0.0), input_neuron_count)
+                    .unwrap(); // unwrapping is safe because an endless iterator never runs out of parameters
+                (initialised.iter().count(), output)
+            }
+        }
+    }
+}
+
+impl<U> CustomLayer for Builtin<U>
+where
+    U: UninitialisedOperation + Clone,
+    U::Initialised: InitialisedOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>
+        + WithOptimiser<capture::OptimiserFactory>,
+    for<'a> <U::Initialised as WithOptimiser<capture::OptimiserFactory>>::Trainable:
+        TrainableOperation<Initialised = U::Initialised>
+            + Forward<
+                'a,
+                Input = Tensor<rank::Two>,
+                Output = Tensor<rank::Two>,
+                Forward: ForwardOperation<Input = Tensor<rank::Two>>,
+            >,
+{
+    fn output_neuron_count(&self, input_neuron_count: u16) -> u16 {
+        self.counts(input_neuron_count).1
+    }
+
+    fn parameter_count(&self, input_neuron_count: u16) -> usize {
+        self.counts(input_neuron_count).0
+    }
+
+    fn prepare(&mut self, input_neuron_count: u16) {
+        let (parameters, output) = self.counts(input_neuron_count);
+        self.counts = Some((input_neuron_count, parameters, output));
+    }
+
+    fn forward(
+        &self,
+        parameters: &[ElementType],
+        input: &Tensor<rank::Two>,
+    ) -> Result<Tensor<rank::Two>> {
+        let (initialised, _) = self.initialise(parameters, neuron_count(input)?)?;
+        initialised.predict(input.clone())
+    }
+
+    fn backward(
+        &self,
+        parameters: &[ElementType],
+        input: &Tensor<rank::Two>,
+        output_gradient: &Tensor<rank::Two>,
+    ) -> Result<(Tensor<rank::Two>, Vec<ElementType>)> {
+        // the capturing optimiser replaces each parameter with its gradient, so reading the
+        // parameters back out afterwards gives the gradients in the same order.
+        let (initialised, _) = self.initialise(parameters, neuron_count(input)?)?;
+        let mut trainable = initialised.with_optimiser(capture::OptimiserFactory::new());
+        let (forward, _) = trainable.forward(input.clone())?;
+        let (backward, input_gradient) = forward.backward(output_gradient.clone())?;
+        backward.optimise();
+        let parameter_gradient = trainable.into_initialised().iter().collect();
+        Ok((input_gradient, parameter_gradient))
+    }
+}
+
+/// Gets the number of neurons (columns) of the given input.
+fn neuron_count(input: &Tensor<rank::Two>) -> Result<u16> {
+    u16::try_from(input.ncols()).map_err(|_| Error::InvalidArgument)
+}
+
+/// This is a network whose layers are stored in a list and can be decided at runtime (for example
+/// from a configuration file), rather than being fixed by the type of a chain of layers. This trades
+/// the compile time checking of a chain for flexibility, so mismatched layers are only reported as
+/// errors when the network is run.
+///
+/// Each layer takes and produces rank 2 tensors, and can be either one of the layers provided by the
+/// library (such as `Dense`) or a `CustomLayer`. The network is itself a `CustomLayer`, so it's wrapped
+/// in a `Custom` layer and chained after an `Input` layer to be initialised, trained and used for inference.
+/// Layers are always run as they are during inference, so stochastic layers such as `Dropout` shouldn't be used.
+///
+/// The number of parameters and output neurons of each layer are worked out once when the network is initialised,
+/// and the input to each layer is kept from the forward pass during training to be used by the backward pass.
+#[derive(Default)]
+pub struct Network {
+    layers: Vec<Box<dyn Layer>>,
+}
+
+impl Network {
+    /// Constructs a new dynamic network without any layers.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds one of the layers provided by the library to the end of the network.
+    #[must_use]
+    pub fn push<U>(self, layer: U) -> Self
+    where
+        U: UninitialisedOperation + Clone + Send + Sync + 'static,
+        U::Initialised: InitialisedOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>
+            + WithOptimiser<capture::OptimiserFactory>,
+        for<'a> <U::Initialised as WithOptimiser<capture::OptimiserFactory>>::Trainable:
+            TrainableOperation<Initialised = U::Initialised>
+                + Forward<
+                    'a,
+                    Input = Tensor<rank::Two>,
+                    Output = Tensor<rank::Two>,
+                    Forward: ForwardOperation<Input = Tensor<rank::Two>>,
+                >,
+    {
+        self.push_custom(Builtin {
+            layer,
+            counts: None,
+        })
+    }
+
+    /// Adds a custom layer to the end of the network.
+    #[must_use]
+    pub fn push_custom(mut self, layer: impl CustomLayer + Clone + Send + Sync + 'static) -> Self {
+        self.layers.push(Box::new(layer));
+        self
+    }
+
+    /// Gets the number of layers in the network.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Checks whether the network doesn't have any layers.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// Splits the given parameters into those for each layer, given the number of input neurons.
+    fn split<'a>(
+        &self,
+        mut parameters: &'a [ElementType],
+        mut input_neuron_count: u16,
+    ) -> Result<Vec<&'a [ElementType]>> {
+        self.layers
+            .iter()
+            .map(|layer| {
+                let count = layer.parameter_count(input_neuron_count);
+                if parameters.len() < count {
+                    return Err(Error::InvalidArgument);
+                }
+                let (layer_parameters, rest) = parameters.split_at(count);
+                parameters = rest;
+                input_neuron_count = layer.output_neuron_count(input_neuron_count);
+                Ok(layer_parameters)
+            })
+            .collect()
+    }
+}
+
+impl Clone for Network {
+    fn clone(&self) -> Self {
+        Self {
+            layers: self.layers.iter().map(|layer| layer.clone_box()).collect(),
+        }
+    }
+}
+
+impl Debug for Network {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Network")
+            .field("layers", &self.layers.len())
+            .finish()
+    }
+}
+
+impl CustomLayer for Network {
+    fn output_neuron_count(&self, input_neuron_count: u16) -> u16 {
+        self.layers.iter().fold(input_neuron_count, |count, layer| {
+            layer.output_neuron_count(count)
+        })
+    }
+
+    fn parameter_count(&self, input_neuron_count: u16) -> usize {
+        self.layers
+            .iter()
+            .fold((0, input_neuron_count), |(total, count), layer| {
+                (
+                    total + layer.parameter_count(count),
+                    layer.output_neuron_count(count),
+                )
+            })
+            .0
+    }
+
+    fn forward(
+        &self,
+        parameters: &[ElementType],
+        input: &Tensor<rank::Two>,
+    ) -> Result<Tensor<rank::Two>> {
+        let split = self.split(parameters, neuron_count(input)?)?;
+        self.layers
+            .iter()
+            .zip(split)
+            .try_fold(input.clone(), |input, (layer, parameters)| {
+                layer.forward(parameters, &input)
+            })
+    }
+
+    fn backward(
+        &self,
+        parameters: &[ElementType],
+        input: &Tensor<rank::Two>,
+        output_gradient: &Tensor<rank::Two>,
+    ) -> Result<(Tensor<rank::Two>, Vec<ElementType>)> {
+        let mut saved = Vec::with_capacity(self.layers.len());
+        self.forward_saving(parameters, input, &mut saved)?;
+        self.backward_saved(parameters, input, &mut saved, output_gradient)
+    }
+
+    fn prepare(&mut self, input_neuron_count: u16) {
+        self.layers
+            .iter_mut()
+            .fold(input_neuron_count, |count, layer| {
+                layer.prepare(count);
+                layer.output_neuron_count(count)
+            });
+    }
+
+    fn forward_saving(
+        &self,
+        parameters: &[ElementType],
+        input: &Tensor<rank::Two>,
+        saved: &mut Vec<Tensor<rank::Two>>,
+    ) -> Result<Tensor<rank::Two>> {
+        // each layer saves its own values before the input to it is saved, so that the backward pass
+        // can pop the input to a layer followed by the values the layer saved.
+        let split = self.split(parameters, neuron_count(input)?)?;
+        let mut last = input.clone();
+        for (layer, parameters) in self.layers.iter().zip(split) {
+            let output = layer.forward_saving(parameters, &last, saved)?;
+            saved.push(last);
+            last = output;
+        }
+        Ok(last)
+    }
+
+    fn backward_saved(
+        &self,
+        parameters: &[ElementType],
+        input: &Tensor<rank::Two>,
+        saved: &mut Vec<Tensor<rank::Two>>,
+        output_gradient: &Tensor<rank::Two>,
+    ) -> Result<(Tensor<rank::Two>, Vec<ElementType>)> {
+        let split = self.split(parameters, neuron_count(input)?)?;
+        let mut gradients = Vec::with_capacity(self.layers.len());
+        let mut gradient = output_gradient.clone();
+        for (layer, parameters) in self.layers.iter().zip(split).rev() {
+            let input = saved.pop().ok_or(Error::InvalidArgument)?;
+            let (input_gradient, parameter_gradient) =
+                layer.backward_saved(parameters, &input, saved, &gradient)?;
+            gradients.push(parameter_gradient);
+            gradient = input_gradient;
+        }
+        let parameter_gradient = gradients.into_iter().rev().flatten().collect();
+        Ok((gradient, parameter_gradient))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::{Linear, ReLU};
+    use crate::layers::{Chain, Custom, Dense, Input};
+    use crate::loss::MeanSquaredError;
+    use crate::operations::uninitialised::custom::tests::Scale;
+    use crate::optimisers::learning_rate_handlers::FixedLearningRateHandler;
+    use crate::optimisers::SGD;
+    use crate::training::train;
+
+    #[test]
+    fn test_predict_matches_chain() {
+        // Arrange
+        let parameters = (0u8..17).map(|elem| ElementType::from(elem) / 10.0 - 0.8);
+        let chain = Input::new(2)
+            .chain(Dense::new(3, ReLU::new()))
+            .chain(Dense::new(2, Linear::new()))
+            .with_iter(parameters.clone())
+            .unwrap();
+        let dynamic = Input::new(2)
+            .chain(Custom::new(
+                Network::new()
+                    .push(Dense::new(3, ReLU::new()))
+                    .push(Dense::new(2, Linear::new())),
+            ))
+            .with_iter(parameters)
+            .unwrap();
+        let input = Tensor::<rank::Two>::new((2, 2), [1.0, 2.0, -3.0, 0.5]).unwrap();
+
+        // Act
+        let expected = chain.predict(input.clone()).unwrap();
+        let output = dynamic.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+        assert!(chain.iter().eq(dynamic.iter()));
+    }
+
+    #[test]
+    fn test_parameter_and_neuron_counts() {
+        // Arrange
+        let network = Network::new()
+            .push(Dense::new(3, ReLU::new()))
+            .push_custom(Scale)
+            .push(Dense::new(1, Linear::new()));
+
+        // Act
+        let parameters = network.parameter_count(2);
+        let neurons = network.output_neuron_count(2);
+
+        // Assert
+        assert_eq!(network.len(), 3);
+        assert_eq!(parameters, 9 + 3 + 4);
+        assert_eq!(neurons, 1);
+    }
+
+    #[test]
+    fn test_prepare_caches_counts() {
+        // Arrange
+        let mut network = Network::new()
+            .push(Dense::new(3, ReLU::new()))
+            .push(Dense::new(1, Linear::new()));
+
+        // Act
+        network.prepare(2);
+        let prepared = network.layers[0].parameter_count(2);
+        let other = network.layers[0].parameter_count(4);
+
+        // Assert
+        assert_eq!(prepared, 9);
+        assert_eq!(other, 15);
+        assert_eq!(network.parameter_count(2), 9 + 4);
+    }
+
+    #[test]
+    fn test_backward_saved_matches_backward() {
+        // Arrange
+        let network = Network::new()
+            .push(Dense::new(2, ReLU::new()))
+            .push_custom(Network::new().push_custom(Scale))
+            .push(Dense::new(1, Linear::new()));
+        let parameters = [0.5, -0.2, 0.3, 0.8, 0.1, 0.1, 2.0, -1.0, 1.5, 0.7, 0.2];
+        let input = Tensor::<rank::Two>::new((1, 2), [1.0, 2.0]).unwrap();
+        let output_gradient = Tensor::<rank::Two>::new((1, 1), [1.0]).unwrap();
+        let mut saved = Vec::new();
+
+        // Act
+        let output = network
+            .forward_saving(&parameters, &input, &mut saved)
+            .unwrap();
+        let saved_count = saved.len();
+        let gradients = network
+            .backward_saved(&parameters, &input, &mut saved, &output_gradient)
+            .unwrap();
+
+        // Assert
+        assert_eq!(output, network.forward(&parameters, &input).unwrap());
+        assert_eq!(saved_count, 4);
+        assert!(saved.is_empty());
+        assert_eq!(
+            gradients,
+            network
+                .backward(&parameters, &input, &output_gradient)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_backward_matches_chain() {
+        // Arrange
+        let network = Network::new()
+            .push(Dense::new(2, ReLU::new()))
+            .push_custom(Scale)
+            .push(Dense::new(1, Linear::new()));
+        let parameters = [0.5, -0.2, 0.3, 0.8, 0.1, 0.1, 2.0, -1.0, 1.5, 0.7, 0.2];
+        let input = Tensor::<rank::Two>::new((1, 2), [1.0, 2.0]).unwrap();
+        let output_gradient = Tensor::<rank::Two>::new((1, 1), [1.0]).unwrap();
+        let epsilon = 1e-3;
+
+        // Act
+        let (_, gradient) = network
+            .backward(&parameters, &input, &output_gradient)
+            .unwrap();
+
+        // Assert
+        // compare against the numeric gradient of the output with respect to each parameter.
+        assert_eq!(gradient.len(), parameters.len());
+        for (index, analytic) in gradient.into_iter().enumerate() {
+            let output = |delta: ElementType| {
+                let mut parameters = parameters;
+                parameters[index] += delta;
+                let output = network.forward(&parameters, &input).unwrap();
+                output.into_iter().next().unwrap()
+            };
+            let numeric = (output(epsilon) - output(-epsilon)) / (2.0 * epsilon);
+            assert!((analytic - numeric).abs() < 1e-2);
+        }
+    }
+
+    #[test]
+    fn test_forward_failure() {
+        // Arrange
+        let network = Network::new().push(Dense::new(2, Linear::new()));
+        let input = Tensor::<rank::Two>::new((1, 2), [1.0, 2.0]).unwrap();
+
+        // Act
+        let result = network.forward(&[1.0, 2.0], &input);
+
+        // Assert
+        assert_eq!(result, Err(Error::InvalidArgument));
+    }
+
+    #[test]
+    fn test_training() {
+        // Arrange
+        let network = Input::new(1)
+            .chain(Custom::new(
+                Network::new().push(Dense::new(1, Linear::new())),
+            ))
+            .with_iter([0.0, 0.0].into_iter())
+            .unwrap()
+            .with_optimiser(SGD::new(FixedLearningRateHandler::new(0.05)));
+        let batch = Tensor::<rank::Two>::new((3, 1), [1.0, 2.0, 3.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((3, 1), [3.0, 5.0, 7.0]).unwrap();
+
+        // Act
+        let network = train(
+            network,
+            &MeanSquaredError::new(),
+            batch.clone(),
+            targets.clone(),
+            &batch,
+            &targets,
+            500,
+            500,
+            3,
+            42,
+        )
+        .unwrap()
+        .into_initialised();
+
+        // Assert
+        assert!(network
+            .iter()
+            .zip([2.0, 1.0])
+            .all(|(parameter, expected)| (parameter - expected).abs() < 1e-2));
+    }
+}
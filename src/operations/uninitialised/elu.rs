@@ -0,0 +1,82 @@
+use crate::activations::ActivationFunction;
+use crate::operations::initialised;
+use crate::operations::UninitialisedOperation;
+use crate::private::Sealed;
+use crate::ElementType;
+use crate::Result;
+
+/// This is an implementation of the exponential linear unit (ELU) activation function.
+/// Positive inputs are passed through unchanged, and negative inputs smoothly saturate
+/// towards the negative of alpha, which should be positive.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Operation {
+    alpha: ElementType,
+}
+
+impl Operation {
+    /// This function is used to construct a new ELU activation with the given alpha
+    /// to be passed in to a dense layer within a network. An alpha of 1 is commonly used.
+    #[must_use]
+    pub const fn new(alpha: ElementType) -> Self {
+        Self { alpha }
+    }
+}
+
+impl Sealed for Operation {}
+impl ActivationFunction for Operation {}
+impl UninitialisedOperation for Operation {
+    type Initialised = initialised::elu::Operation;
+
+    fn with_iter_private(
+        self,
+        _iter: &mut impl Iterator<Item = ElementType>,
+        input_neuron_count: u16,
+    ) -> Result<(Self::Initialised, u16)> {
+        Ok(self.with_seed_private(0, input_neuron_count))
+    }
+
+    fn with_seed_private(self, _seed: u64, input_neuron_count: u16) -> (Self::Initialised, u16) {
+        (
+            initialised::elu::Operation {
+                neurons: input_neuron_count,
+                alpha: self.alpha,
+            },
+            input_neuron_count,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        // Arrange
+        let expected = Operation { alpha: 0.5 };
+
+        // Act
+        let output = Operation::new(0.5);
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_with_iter() {
+        // Arrange
+        let operation = Operation::new(1.0);
+        let expected_initialised = initialised::elu::Operation {
+            neurons: 122,
+            alpha: 1.0,
+        };
+        let mut iter = core::iter::empty();
+
+        // Act
+        let (initialised, output_neurons) = operation.with_iter_private(&mut iter, 122).unwrap();
+
+        // Assert
+        assert_eq!(initialised, expected_initialised);
+        assert_eq!(output_neurons, 122);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::initialisers::Initialiser;
+use crate::operations::uninitialised::composite::ChainTarget;
+use crate::operations::{initialised, UninitialisedOperation};
+use crate::private::Sealed;
+use crate::{ElementType, Result};
+
+/// Represents a block of layers whose parameters are frozen, so that they're left unchanged
+/// when the network is trained. The block is still used for the forward and backward passes,
+/// so gradients flow through it to any earlier layers, but the optimiser given to `with_optimiser`
+/// is never applied to it. This enables transfer learning, where a trained backbone is reused
+/// and only a new head is trained.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Operation<T> {
+    block: T,
+}
+
+impl<T> Operation<T> {
+    /// Constructs a new instance of the frozen layer which wraps the given block.
+    #[must_use]
+    pub const fn new(block: T) -> Self {
+        Self { block }
+    }
+}
+
+impl<T> Sealed for Operation<T> {}
+impl<T> ChainTarget for Operation<T> {}
+impl<T: UninitialisedOperation> UninitialisedOperation for Operation<T> {
+    type Initialised = initialised::frozen::Operation<T::Initialised>;
+
+    fn with_iter_private(
+        self,
+        iter: &mut impl Iterator<Item = ElementType>,
+        input_neuron_count: u16,
+    ) -> Result<(Self::Initialised, u16)> {
+        let (block, output_neuron_count) =
+            self.block.with_iter_private(iter, input_neuron_count)?;
+        Ok((Self::Initialised { block }, output_neuron_count))
+    }
+
+    fn with_seed_private(self, seed: u64, input_neuron_count: u16) -> (Self::Initialised, u16) {
+        let (block, output_neuron_count) = self.block.with_seed_private(seed, input_neuron_count);
+        (Self::Initialised { block }, output_neuron_count)
+    }
+
+    fn with_initialiser_private(self, initialiser: Initialiser) -> Self {
+        Self {
+            block: self.block.with_initialiser_private(initialiser),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::Linear;
+    use crate::layers::Dense;
+
+    #[test]
+    fn test_with_iter_private() {
+        // Arrange
+        let operation = Operation::new(Dense::new(1, Linear::new()));
+        let expected = initialised::frozen::Operation {
+            block: Dense::new(1, Linear::new())
+                .with_iter_private(&mut [1.0, 2.0, 3.0].into_iter(), 2)
+                .unwrap()
+                .0,
+        };
+
+        // Act
+        let (output, output_neurons) = operation
+            .with_iter_private(&mut [1.0, 2.0, 3.0].into_iter(), 2)
+            .unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+        assert_eq!(output_neurons, 1);
+    }
+
+    #[test]
+    fn test_with_seed_private() {
+        // Arrange
+        let operation = Operation::new(Dense::new(3, Linear::new()));
+        let expected = initialised::frozen::Operation {
+            block: Dense::new(3, Linear::new()).with_seed_private(42, 2).0,
+        };
+
+        // Act
+        let (output, output_neurons) = operation.with_seed_private(42, 2);
+
+        // Assert
+        assert_eq!(output, expected);
+        assert_eq!(output_neurons, 3);
+    }
+}
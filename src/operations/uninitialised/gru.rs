@@ -0,0 +1,165 @@
+use crate::initialisers::Initialiser;
+use crate::operations::uninitialised::composite::ChainTarget;
+use crate::operations::{initialised, UninitialisedOperation};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// This is a gated recurrent unit (GRU) layer which runs over each time step of a sequence,
+/// carrying a hidden state of the given number of units from one step to the next. Sequences
+/// are rank 3 tensors with the shape (batch, time, features), and the output of the layer is the
+/// hidden state after the final time step as a rank 2 tensor with the shape (batch, units).
+///
+/// At each step an update gate and a reset gate are calculated from the input and the previous
+/// hidden state. The reset gate controls how much of the previous hidden state is used when
+/// calculating a candidate hidden state, and the update gate controls how much of the previous
+/// hidden state is kept rather than being replaced by the candidate. The hidden state starts as zeros.
+///
+/// The parameters are ordered as the input weights (a row per feature), followed by the hidden
+/// weights (a row per unit), followed by the biases. Each of these has a column per unit for each
+/// of the update gate, the reset gate and the candidate, in that order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Operation {
+    units: u16,
+    initialiser: Option<Initialiser>,
+}
+
+impl Operation {
+    /// Constructs a new GRU layer with the given number of units in the hidden state.
+    #[must_use]
+    pub const fn new(units: u16) -> Self {
+        Self {
+            units,
+            initialiser: None,
+        }
+    }
+
+    /// Sets the strategy used to generate the weights of this layer when the network
+    /// is initialised from a random seed. By default, Xavier uniform initialisation is used.
+    /// The biases are always initialised to zero.
+    #[must_use]
+    pub const fn with_initialiser(self, initialiser: Initialiser) -> Self {
+        Self {
+            units: self.units,
+            initialiser: Some(initialiser),
+        }
+    }
+}
+
+impl Sealed for Operation {}
+impl ChainTarget for Operation {}
+impl UninitialisedOperation for Operation {
+    type Initialised = initialised::gru::Operation;
+
+    fn with_iter_private(
+        self,
+        iter: &mut impl Iterator<Item = ElementType>,
+        input_neuron_count: u16,
+    ) -> Result<(Self::Initialised, u16)> {
+        let (features, units) = (input_neuron_count as usize, self.units as usize);
+        let insufficient_weights = |_| Error::InsufficientWeights {
+            layer_index: 0,
+            expected: (features + units + 1) * units * 3,
+        };
+        let input_weights =
+            Tensor::<rank::Two>::new((features, units * 3), iter.take(features * units * 3))
+                .map_err(insufficient_weights)?;
+        let hidden_weights =
+            Tensor::<rank::Two>::new((units, units * 3), iter.take(units * units * 3))
+                .map_err(insufficient_weights)?;
+        let bias = Tensor::<rank::Two>::new((1, units * 3), iter.take(units * 3))
+            .map_err(insufficient_weights)?;
+        let initialised = Self::Initialised {
+            input_weights,
+            hidden_weights,
+            bias,
+        };
+        Ok((initialised, self.units))
+    }
+
+    fn with_seed_private(self, seed: u64, input_neuron_count: u16) -> (Self::Initialised, u16) {
+        let mut generator = StdRng::seed_from_u64(seed);
+        let (initialiser, units) = (self.initialiser.unwrap_or_default(), self.units);
+        let (features, unit_count) = (input_neuron_count as usize, units as usize);
+        let mut parameters = Vec::with_capacity((features + unit_count + 1) * unit_count * 3);
+        (0..features * unit_count * 3).for_each(|_| {
+            parameters.push(initialiser.sample(&mut generator, input_neuron_count, units));
+        });
+        (0..unit_count * unit_count * 3).for_each(|_| {
+            parameters.push(initialiser.sample(&mut generator, units, units));
+        });
+        parameters.resize(parameters.len() + unit_count * 3, 0.0);
+        self.with_iter_private(&mut parameters.into_iter(), input_neuron_count)
+            .unwrap() // unwrapping is safe because we've generated exactly enough parameters
+    }
+
+    fn with_initialiser_private(self, initialiser: Initialiser) -> Self {
+        Self {
+            initialiser: self.initialiser.or(Some(initialiser)),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_iter_private_success() {
+        // Arrange
+        let operation = Operation::new(1);
+        let mut iter = (1u8..=12).map(ElementType::from);
+        let expected = initialised::gru::Operation {
+            input_weights: Tensor::<rank::Two>::new((2, 3), (1u8..=6).map(ElementType::from))
+                .unwrap(),
+            hidden_weights: Tensor::<rank::Two>::new((1, 3), [7.0, 8.0, 9.0]).unwrap(),
+            bias: Tensor::<rank::Two>::new((1, 3), [10.0, 11.0, 12.0]).unwrap(),
+        };
+
+        // Act
+        let (output, units) = operation.with_iter_private(&mut iter, 2).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+        assert_eq!(units, 1);
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_with_iter_private_failure() {
+        // Arrange
+        let operation = Operation::new(2);
+        let mut iter = (1u8..=20).map(ElementType::from);
+
+        // Act
+        let result = operation.with_iter_private(&mut iter, 1);
+
+        // Assert
+        assert_eq!(
+            result.err(),
+            Some(Error::InsufficientWeights {
+                layer_index: 0,
+                expected: 24
+            })
+        );
+    }
+
+    #[test]
+    fn test_with_seed_private() {
+        // Arrange
+        let operation = Operation::new(4);
+
+        // Act
+        let (output, units) = operation.with_seed_private(42, 3);
+
+        // Assert
+        assert_eq!(output.input_weights.0.dim(), (3, 12));
+        assert_eq!(output.hidden_weights.0.dim(), (4, 12));
+        assert!(output.bias.0.iter().all(|elem| *elem == 0.0));
+        assert_eq!(units, 4);
+    }
+}
@@ -0,0 +1,96 @@
+use crate::operations::uninitialised::composite::{Chain, ChainTarget};
+use crate::operations::{initialised, UninitialisedOperation};
+use crate::private::Sealed;
+use crate::ElementType;
+use crate::Result;
+
+/// This structure represents an input operation for image data which will be used as the very
+/// first operation in a sequence of convolutional layers. Images are provided as rank 4 tensors
+/// with the shape (batch, channels, height, width) and this operation defines the number of
+/// channels that the following layers will receive. The height and width are allowed to vary.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Operation {
+    channels: u16,
+}
+
+impl Operation {
+    /// This function is used to construct a new image input operation with a given
+    /// channel count. If data is provided when running/training the network and the
+    /// channel count doesn't match then this will be an error.
+    #[must_use]
+    pub const fn new(channels: u16) -> Self {
+        Self { channels }
+    }
+}
+
+impl Sealed for Operation {}
+impl Chain for Operation {}
+impl ChainTarget for Operation {}
+impl UninitialisedOperation for Operation {
+    type Initialised = initialised::image_input::Operation;
+
+    fn with_iter_private(
+        self,
+        _iter: &mut impl Iterator<Item = ElementType>,
+        _input_neuron_count: u16,
+    ) -> Result<(Self::Initialised, u16)> {
+        Ok((
+            initialised::image_input::Operation {
+                channels: self.channels,
+            },
+            self.channels,
+        ))
+    }
+
+    fn with_seed_private(self, _seed: u64, _input_neuron_count: u16) -> (Self::Initialised, u16) {
+        (
+            initialised::image_input::Operation {
+                channels: self.channels,
+            },
+            self.channels,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        // Arrange
+        let expected = Operation { channels: 3 };
+
+        // Act
+        let output = Operation::new(3);
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_with_iter() {
+        // Arrange
+        let operation = Operation::new(3);
+        let expected_initialised = initialised::image_input::Operation { channels: 3 };
+
+        // Act
+        let initialised = operation.with_iter(core::iter::empty()).unwrap();
+
+        // Assert
+        assert_eq!(initialised, expected_initialised);
+    }
+
+    #[test]
+    fn test_with_seed() {
+        // Arrange
+        let operation = Operation::new(1);
+        let expected_initialised = initialised::image_input::Operation { channels: 1 };
+
+        // Act
+        let initialised = operation.with_seed(42);
+
+        // Assert
+        assert_eq!(initialised, expected_initialised);
+    }
+}
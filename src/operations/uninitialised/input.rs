@@ -10,7 +10,7 @@ use crate::Result;
 /// count is allowed to not be specified for the input. This is different from the Linear activation
 /// function for example where the output neuron count is the same as the input - hence they need to be
 /// two different functions.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Operation {
     neuron_count: u16,
 }
@@ -52,6 +52,10 @@ impl UninitialisedOperation for Operation {
             self.neuron_count,
         )
     }
+
+    fn input_neuron_count(&self) -> Option<u16> {
+        Some(self.neuron_count)
+    }
 }
 
 #[cfg(test)]
@@ -77,7 +81,7 @@ mod tests {
         let expected_initialised = initialised::input::Operation { neurons: 122 };
 
         // Act
-        let initialised = operation.with_iter([].into_iter()).unwrap();
+        let initialised = operation.with_iter(core::iter::empty()).unwrap();
 
         // Assert
         assert_eq!(initialised, expected_initialised);
@@ -8,7 +8,7 @@ use crate::Result;
 /// This is a linear activation function intended to be used at the end of a dense
 /// layer in a neural network. It is linear in that it allows the data to pass through
 /// unchanged.
-#[derive(Debug, Default, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Operation(());
 
 impl Operation {
@@ -69,7 +69,7 @@ mod tests {
         // Arrange
         let operation = Operation::new();
         let expected_initialised = initialised::linear::Operation { neurons: 122 };
-        let mut iter = [].into_iter();
+        let mut iter = core::iter::empty();
 
         // Act
         let (initialised, output_neurons) = operation.with_iter_private(&mut iter, 122).unwrap();
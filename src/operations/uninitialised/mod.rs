@@ -2,21 +2,46 @@
 //! uninitialised state. These are operations that will accept and iterator
 //! or random seed and will generate the correct size parameter for the operation.
 
+pub mod alpha_dropout;
 pub mod bias_add;
 pub mod composite;
+pub mod conv1d;
+pub mod conv2d;
+pub mod conv_transpose2d;
+pub mod custom;
 pub mod dense;
+pub mod depthwise_conv2d;
+pub mod depthwise_multiply;
 pub mod dropout;
+pub mod dynamic;
+pub mod elu;
+pub mod frozen;
+pub mod gelu;
+pub mod gru;
+pub mod image_input;
 pub mod input;
 pub mod linear;
+pub mod multi_input;
+pub mod parallel;
+pub mod pooling2d;
 pub mod relu;
+pub mod reshape;
+pub mod selu;
+pub mod sequence_input;
 pub mod sigmoid;
+pub mod silu;
+pub mod spatial_dropout;
+pub mod stochastic_depth;
 pub mod tanh;
+pub mod upsample2d;
 pub mod weight_multiply;
 
+use crate::initialisers::Initialiser;
 use crate::operations::initialised;
-use crate::private::Sealed;
-use crate::ElementType;
-use crate::Result;
+use crate::private::named::{join_named_tensors, LayerShape, NamedTensor};
+use crate::private::{native, npy, safetensors, zip, Sealed};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
 
 /// This trait is used to represent an operation in an uninitialised state
 /// that must be initialised before it's used. These operations can be
@@ -45,6 +70,138 @@ pub trait Operation: Sealed + Sized {
         self.with_seed_private(seed, 0).0
     }
 
+    /// This function is called to initialise the parameters of the operation from a random seed
+    /// in the same way as `with_seed`, except that the weights of every layer which hasn't been given
+    /// its own initialiser are generated with the given one rather than Xavier uniform initialisation.
+    /// Layers inside a `DynNetwork` always keep their own initialiser.
+    fn with_seed_and_initialiser(self, seed: u64, initialiser: Initialiser) -> Self::Initialised {
+        self.with_initialiser_private(initialiser).with_seed(seed)
+    }
+
+    /// This function can be called to warm start a network, initialising the parameters of a prefix of
+    /// its layers from an iterator (such as the weights of a trained backbone) and the parameters of the
+    /// remaining layers (such as a new head) from a random seed in the same way as `with_seed`. The
+    /// iterator yields the parameters of each layer in the same order as `with_iter`, and the prefix ends
+    /// at the layer where the iterator runs out.
+    ///
+    /// # Errors
+    /// `Error` if the iterator runs out part way through the parameters of a layer.
+    fn with_iter_and_seed(
+        self,
+        iter: impl Iterator<Item = ElementType>,
+        seed: u64,
+    ) -> Result<Self::Initialised> {
+        let mut initialised = self.with_seed(seed);
+        let mut layers = Vec::new();
+        initialised::Operation::push_layer_parameters(&initialised, &mut layers);
+        let mut iter = iter.fuse();
+        let mut prefix = Vec::new();
+        for (layer_index, (_, parameters)) in layers.iter().enumerate() {
+            let expected = parameters.len();
+            let weights = iter.by_ref().take(expected).collect::<Vec<_>>();
+            if weights.is_empty() {
+                break;
+            } else if weights.len() < expected {
+                return Err(Error::InsufficientWeights {
+                    layer_index,
+                    expected,
+                });
+            }
+            prefix.extend(weights);
+        }
+        let mut prefix = prefix.into_iter();
+        initialised::Operation::map_parameters(&mut initialised, &mut |random| {
+            prefix.next().unwrap_or(random)
+        });
+        Ok(initialised)
+    }
+
+    /// This function can be called to initialise the parameters of the operation from the
+    /// human-readable text format produced by `to_text` on an initialised network. The
+    /// shape of each layer in the text must match the shape of the corresponding layer in this network.
+    ///
+    /// # Errors
+    /// `Error` if the text can't be parsed, or if it doesn't match the shape of the network.
+    fn with_text(self, text: &str) -> Result<Self::Initialised> {
+        let mut shapes = Vec::new();
+        let mut parameters = Vec::new();
+        for line in text.lines().filter(|line| !line.trim().is_empty()) {
+            let (shape, values) = line.split_once(':').ok_or(Error::InvalidText)?;
+            let (rows, cols) = shape.trim().split_once('x').ok_or(Error::InvalidText)?;
+            let rows = rows.parse::<usize>().map_err(|_| Error::InvalidText)?;
+            let cols = cols.parse::<usize>().map_err(|_| Error::InvalidText)?;
+            let values = values
+                .split_whitespace()
+                .map(|value| value.parse::<ElementType>().map_err(|_| Error::InvalidText))
+                .collect::<Result<Vec<_>>>()?;
+            shapes.push(((rows, cols), values.len()));
+            parameters.extend(values);
+        }
+        with_layers(self, &shapes, parameters, Error::InvalidText)
+    }
+
+    /// This function can be called to initialise the parameters of the operation from a `NumPy`
+    /// .npz archive, such as one produced by `to_npz` on an initialised network or saved from Python.
+    ///
+    /// The archive holds an array named `layer_{index}.weight` for each layer that has parameters,
+    /// with the shape of the layer (input neurons by output neurons), and an array named `layer_{index}.bias`
+    /// for those layers with a bias. Arrays can be 32 or 64 bit floats in row major order, and can be stored
+    /// in any order but not compressed (so are saved with `numpy.savez` rather than `numpy.savez_compressed`).
+    ///
+    /// # Errors
+    /// `Error` if the archive can't be read, or if it doesn't match the shape of the network.
+    fn with_npz(self, archive: &[u8]) -> Result<Self::Initialised> {
+        let tensors = zip::read(archive)?
+            .into_iter()
+            .map(|(name, data)| {
+                let name = name.strip_suffix(".npy").ok_or(Error::InvalidArchive)?;
+                let (shape, values) = npy::decode(data)?;
+                Ok(NamedTensor {
+                    name: name.into(),
+                    shape,
+                    values,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let (shapes, parameters) = join_named_tensors(tensors)?;
+        with_layers(self, &shapes, parameters, Error::InvalidArchive)
+    }
+
+    /// This function can be called to initialise the parameters of the operation from bytes in the
+    /// safetensors format, such as those produced by `to_safetensors` on an initialised network or saved from Python.
+    ///
+    /// The tensors are named in the same way as the arrays read by `with_npz`, and can be 32 or 64 bit floats.
+    ///
+    /// # Errors
+    /// `Error` if the bytes can't be read, or if they don't match the shape of the network.
+    fn with_safetensors(self, bytes: &[u8]) -> Result<Self::Initialised> {
+        let (shapes, parameters) = join_named_tensors(safetensors::decode(bytes)?)?;
+        with_layers(self, &shapes, parameters, Error::InvalidArchive)
+    }
+
+    /// This function can be called to initialise the parameters of the operation from bytes in the
+    /// Eidetic format produced by `to_bytes` on an initialised network. The format records the element type
+    /// and shape of each layer, so parameters saved by a network of a different shape or element type are rejected.
+    ///
+    /// # Errors
+    /// `Error` if the bytes can't be read, were saved with a different element type or an unsupported
+    /// version of the format, or if they don't match the shape of the network.
+    fn with_bytes(self, bytes: &[u8]) -> Result<Self::Initialised> {
+        let (shapes, parameters) = native::decode(bytes)?;
+        with_layers(self, &shapes, parameters, Error::InvalidArchive)
+    }
+
+    /// This function can be called to initialise the parameters of the operation from a file saved with
+    /// `save` on an initialised network. This is the same as passing the contents of the file to `with_bytes`.
+    ///
+    /// # Errors
+    /// `Error` if the file can't be read, or for any of the reasons that `with_bytes` fails.
+    #[cfg(feature = "std")]
+    fn load(self, path: impl AsRef<std::path::Path>) -> Result<Self::Initialised> {
+        let bytes = std::fs::read(path).map_err(|_| Error::FileAccess)?;
+        self.with_bytes(&bytes)
+    }
+
     #[doc(hidden)]
     fn with_iter_private(
         self,
@@ -54,4 +211,37 @@ pub trait Operation: Sealed + Sized {
 
     #[doc(hidden)]
     fn with_seed_private(self, seed: u64, input_neuron_count: u16) -> (Self::Initialised, u16);
+
+    #[doc(hidden)]
+    #[must_use]
+    fn with_initialiser_private(self, _initialiser: Initialiser) -> Self {
+        self
+    }
+
+    #[doc(hidden)]
+    fn input_neuron_count(&self) -> Option<u16> {
+        None
+    }
+}
+
+/// Initialises the given operation with the given parameters, checking that each layer with parameters
+/// has the given shape and number of parameters. Returns the given error if the shapes don't match.
+fn with_layers<T: Operation>(
+    operation: T,
+    shapes: &[LayerShape],
+    parameters: Vec<ElementType>,
+    error: Error,
+) -> Result<T::Initialised> {
+    let initialised = operation.with_iter(parameters.into_iter())?;
+    let mut layers = Vec::new();
+    initialised::Operation::push_layer_parameters(&initialised, &mut layers);
+    if layers
+        .iter()
+        .map(|(shape, parameters)| (*shape, parameters.len()))
+        .eq(shapes.iter().copied())
+    {
+        Ok(initialised)
+    } else {
+        Err(error)
+    }
 }
@@ -0,0 +1,194 @@
+use crate::initialisers::Initialiser;
+use crate::operations::uninitialised::composite::Chain;
+use crate::operations::uninitialised::parallel::Merge;
+use crate::operations::{initialised, InitialisedOperation, UninitialisedOperation};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+
+/// Represents the first layer of a network with two separate inputs (such as tabular features and an
+/// embedding), each processed by its own chain of layers before their outputs are merged into a single output.
+/// Each branch starts with its own `Input` layer, and the input of the network is made by placing the columns of
+/// the second input after the columns of the first, such as with `Tensor::concatenate_columns`. During training,
+/// the gradient of each branch only flows back through the layers of that branch.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Operation<T, U> {
+    lhs: T,
+    rhs: U,
+    merge: Merge,
+}
+
+impl<T, U> Operation<T, U> {
+    /// Constructs a new instance of the multi-input layer which feeds the first columns of its input
+    /// through the first branch and the remaining columns through the second, merging their outputs in
+    /// the given way. The number of columns each branch takes is the size of its `Input` layer.
+    #[must_use]
+    pub const fn new(lhs: T, rhs: U, merge: Merge) -> Self {
+        Self { lhs, rhs, merge }
+    }
+}
+
+impl<T, U> Sealed for Operation<T, U> {}
+impl<T, U> Chain for Operation<T, U> {}
+impl<T: UninitialisedOperation, U: UninitialisedOperation> UninitialisedOperation
+    for Operation<T, U>
+where
+    <T as UninitialisedOperation>::Initialised:
+        InitialisedOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+    <U as UninitialisedOperation>::Initialised:
+        InitialisedOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+{
+    type Initialised = initialised::multi_input::Operation<T::Initialised, U::Initialised>;
+
+    fn with_iter_private(
+        self,
+        iter: &mut impl Iterator<Item = ElementType>,
+        _input_neuron_count: u16,
+    ) -> Result<(Self::Initialised, u16)> {
+        let features = self.features();
+        let (lhs, lhs_neuron_count) = self.lhs.with_iter_private(iter, features.0)?;
+        let (rhs, rhs_neuron_count) =
+            self.rhs
+                .with_iter_private(iter, features.1)
+                .map_err(|error| {
+                    // layer indices in the rhs are reported relative to it, so offset them by the
+                    // number of parameterised layers in the lhs.
+                    let mut layers = Vec::new();
+                    lhs.push_layer_parameters(&mut layers);
+                    error.offset_layer_index(layers.len())
+                })?;
+        let output_neuron_count = match self.merge {
+            Merge::Concatenate => lhs_neuron_count + rhs_neuron_count,
+            Merge::Add => {
+                Error::check_size(lhs_neuron_count as usize, rhs_neuron_count as usize)?;
+                lhs_neuron_count
+            }
+        };
+        let initialised = Self::Initialised {
+            lhs,
+            rhs,
+            merge: self.merge,
+            features: (features.0 as usize, features.1 as usize),
+        };
+        Ok((initialised, output_neuron_count))
+    }
+
+    fn with_seed_private(self, seed: u64, _input_neuron_count: u16) -> (Self::Initialised, u16) {
+        let features = self.features();
+        let (lhs, lhs_neuron_count) = self.lhs.with_seed_private(seed, features.0);
+        let (rhs, rhs_neuron_count) = self.rhs.with_seed_private(seed + 1, features.1);
+        let output_neuron_count = match self.merge {
+            Merge::Concatenate => lhs_neuron_count + rhs_neuron_count,
+            Merge::Add => lhs_neuron_count,
+        };
+        let initialised = Self::Initialised {
+            lhs,
+            rhs,
+            merge: self.merge,
+            features: (features.0 as usize, features.1 as usize),
+        };
+        (initialised, output_neuron_count)
+    }
+
+    fn with_initialiser_private(self, initialiser: Initialiser) -> Self {
+        Self {
+            lhs: self.lhs.with_initialiser_private(initialiser),
+            rhs: self.rhs.with_initialiser_private(initialiser),
+            ..self
+        }
+    }
+
+    fn input_neuron_count(&self) -> Option<u16> {
+        let (lhs, rhs) = self.features();
+        Some(lhs + rhs)
+    }
+}
+
+impl<T: UninitialisedOperation, U: UninitialisedOperation> Operation<T, U> {
+    /// Gets the number of columns taken by each branch, which is the size of its `Input` layer.
+    /// A branch which doesn't start with an `Input` layer takes no columns.
+    fn features(&self) -> (u16, u16) {
+        (
+            self.lhs.input_neuron_count().unwrap_or_default(),
+            self.rhs.input_neuron_count().unwrap_or_default(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::Linear;
+    use crate::layers::{Dense, Input};
+
+    #[test]
+    fn test_with_iter_private() {
+        // Arrange
+        let operation = Operation::new(
+            Input::new(2).chain(Dense::new(1, Linear::new())),
+            Input::new(1).chain(Dense::new(2, Linear::new())),
+            Merge::Concatenate,
+        );
+        let mut iter = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0].into_iter();
+        let expected = initialised::multi_input::Operation {
+            lhs: Input::new(2)
+                .chain(Dense::new(1, Linear::new()))
+                .with_iter([1.0, 2.0, 3.0].into_iter())
+                .unwrap(),
+            rhs: Input::new(1)
+                .chain(Dense::new(2, Linear::new()))
+                .with_iter([4.0, 5.0, 6.0, 7.0].into_iter())
+                .unwrap(),
+            merge: Merge::Concatenate,
+            features: (2, 1),
+        };
+
+        // Act
+        let (output, output_neurons) = operation.with_iter_private(&mut iter, 0).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+        assert_eq!(output_neurons, 3);
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_with_iter_private_add_failure() {
+        // Arrange
+        let operation = Operation::new(
+            Input::new(2).chain(Dense::new(1, Linear::new())),
+            Input::new(1).chain(Dense::new(2, Linear::new())),
+            Merge::Add,
+        );
+        let mut iter = [1.0; 7].into_iter();
+
+        // Act
+        let result = operation.with_iter_private(&mut iter, 0);
+
+        // Assert
+        assert_eq!(
+            result.map(|_| ()),
+            Err(Error::ShapeMismatch {
+                expected: 1,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_input_neuron_count() {
+        // Arrange
+        let operation = Operation::new(
+            Input::new(3).chain(Dense::new(1, Linear::new())),
+            Input::new(4),
+            Merge::Concatenate,
+        );
+
+        // Act
+        let output = operation.input_neuron_count();
+
+        // Assert
+        assert_eq!(output, Some(7));
+    }
+}
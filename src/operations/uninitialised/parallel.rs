@@ -0,0 +1,247 @@
+use crate::initialisers::Initialiser;
+use crate::operations::uninitialised::composite::ChainTarget;
+use crate::operations::{initialised, InitialisedOperation, UninitialisedOperation};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+
+/// How the outputs of the branches of a parallel layer are merged into a single output.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Merge {
+    /// The columns of the output of the second branch are placed after the columns of the
+    /// output of the first branch, so the number of output neurons is the sum of both.
+    Concatenate,
+    /// The outputs of the branches are added together elementwise, so both branches must
+    /// have the same number of output neurons.
+    Add,
+}
+
+/// Represents a layer which feeds the same input through two blocks of layers in parallel,
+/// and merges their outputs into a single output. This allows architectures which aren't purely
+/// sequential to be built, such as inception blocks or wide-and-deep models. More than two
+/// branches can be made by nesting parallel layers, and a branch made up of several layers can
+/// be made with a `Custom` layer holding a `DynNetwork`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Operation<T, U> {
+    lhs: T,
+    rhs: U,
+    merge: Merge,
+}
+
+impl<T, U> Operation<T, U> {
+    /// Constructs a new instance of the parallel layer which feeds its input through both of
+    /// the given blocks, and merges their outputs in the given way.
+    #[must_use]
+    pub const fn new(lhs: T, rhs: U, merge: Merge) -> Self {
+        Self { lhs, rhs, merge }
+    }
+
+    /// Constructs a new instance of the parallel layer whose blocks are two output heads of a network,
+    /// such as a classification head and a regression head on a shared trunk. The outputs of the heads
+    /// are placed side by side, so that each head can be trained with its own loss with `loss::MultiHead`.
+    #[must_use]
+    pub const fn heads(lhs: T, rhs: U) -> Self {
+        Self::new(lhs, rhs, Merge::Concatenate)
+    }
+}
+
+impl<T, U> Sealed for Operation<T, U> {}
+impl<T, U> ChainTarget for Operation<T, U> {}
+impl<T: UninitialisedOperation, U: UninitialisedOperation> UninitialisedOperation
+    for Operation<T, U>
+where
+    <T as UninitialisedOperation>::Initialised:
+        InitialisedOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+    <U as UninitialisedOperation>::Initialised:
+        InitialisedOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+{
+    type Initialised = initialised::parallel::Operation<T::Initialised, U::Initialised>;
+
+    fn with_iter_private(
+        self,
+        iter: &mut impl Iterator<Item = ElementType>,
+        input_neuron_count: u16,
+    ) -> Result<(Self::Initialised, u16)> {
+        let (lhs, lhs_neuron_count) = self.lhs.with_iter_private(iter, input_neuron_count)?;
+        let (rhs, rhs_neuron_count) = self
+            .rhs
+            .with_iter_private(iter, input_neuron_count)
+            .map_err(|error| {
+                // layer indices in the rhs are reported relative to it, so offset them by the
+                // number of parameterised layers in the lhs.
+                let mut layers = Vec::new();
+                lhs.push_layer_parameters(&mut layers);
+                error.offset_layer_index(layers.len())
+            })?;
+        let output_neuron_count = match self.merge {
+            Merge::Concatenate => lhs_neuron_count + rhs_neuron_count,
+            Merge::Add => {
+                Error::check_size(lhs_neuron_count as usize, rhs_neuron_count as usize)?;
+                lhs_neuron_count
+            }
+        };
+        let initialised = Self::Initialised {
+            lhs,
+            rhs,
+            merge: self.merge,
+        };
+        Ok((initialised, output_neuron_count))
+    }
+
+    fn with_seed_private(self, seed: u64, input_neuron_count: u16) -> (Self::Initialised, u16) {
+        let (lhs, lhs_neuron_count) = self.lhs.with_seed_private(seed, input_neuron_count);
+        let (rhs, rhs_neuron_count) = self.rhs.with_seed_private(seed + 1, input_neuron_count);
+        let output_neuron_count = match self.merge {
+            Merge::Concatenate => lhs_neuron_count + rhs_neuron_count,
+            Merge::Add => lhs_neuron_count,
+        };
+        let initialised = Self::Initialised {
+            lhs,
+            rhs,
+            merge: self.merge,
+        };
+        (initialised, output_neuron_count)
+    }
+
+    fn with_initialiser_private(self, initialiser: Initialiser) -> Self {
+        Self {
+            lhs: self.lhs.with_initialiser_private(initialiser),
+            rhs: self.rhs.with_initialiser_private(initialiser),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::{Linear, ReLU};
+    use crate::layers::Dense;
+
+    #[test]
+    fn test_new() {
+        // Arrange
+        let expected = Operation {
+            lhs: Linear::new(),
+            rhs: ReLU::new(),
+            merge: Merge::Add,
+        };
+
+        // Act
+        let output = Operation::new(Linear::new(), ReLU::new(), Merge::Add);
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_heads() {
+        // Arrange
+        let expected = Operation::new(Linear::new(), ReLU::new(), Merge::Concatenate);
+
+        // Act
+        let output = Operation::heads(Linear::new(), ReLU::new());
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_with_iter_private_concatenate() {
+        // Arrange
+        let operation = Operation::new(
+            Dense::new(1, Linear::new()),
+            Dense::new(2, Linear::new()),
+            Merge::Concatenate,
+        );
+        let mut iter = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0].into_iter();
+        let expected = initialised::parallel::Operation {
+            lhs: Dense::new(1, Linear::new())
+                .with_iter_private(&mut [1.0, 2.0, 3.0].into_iter(), 2)
+                .unwrap()
+                .0,
+            rhs: Dense::new(2, Linear::new())
+                .with_iter_private(&mut [4.0, 5.0, 6.0, 7.0, 8.0, 9.0].into_iter(), 2)
+                .unwrap()
+                .0,
+            merge: Merge::Concatenate,
+        };
+
+        // Act
+        let (output, output_neurons) = operation.with_iter_private(&mut iter, 2).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+        assert_eq!(output_neurons, 3);
+    }
+
+    #[test]
+    fn test_with_iter_private_add_failure() {
+        // Arrange
+        let operation = Operation::new(
+            Dense::new(1, Linear::new()),
+            Dense::new(2, Linear::new()),
+            Merge::Add,
+        );
+        let mut iter = [1.0; 9].into_iter();
+
+        // Act
+        let result = operation.with_iter_private(&mut iter, 2);
+
+        // Assert
+        assert_eq!(
+            result.map(|_| ()),
+            Err(Error::ShapeMismatch {
+                expected: 1,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_with_iter_private_insufficient_weights() {
+        // Arrange
+        let operation = Operation::new(
+            Dense::new(1, Linear::new()),
+            Dense::new(1, Linear::new()),
+            Merge::Add,
+        );
+        let mut iter = [1.0; 4].into_iter();
+
+        // Act
+        let result = operation.with_iter_private(&mut iter, 2);
+
+        // Assert
+        assert_eq!(
+            result.map(|_| ()),
+            Err(Error::InsufficientWeights {
+                layer_index: 1,
+                expected: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_with_seed_private() {
+        // Arrange
+        let operation = Operation::new(
+            Dense::new(2, Linear::new()),
+            Dense::new(2, Linear::new()),
+            Merge::Add,
+        );
+        let expected = initialised::parallel::Operation {
+            lhs: Dense::new(2, Linear::new()).with_seed_private(42, 3).0,
+            rhs: Dense::new(2, Linear::new()).with_seed_private(43, 3).0,
+            merge: Merge::Add,
+        };
+
+        // Act
+        let (output, output_neurons) = operation.with_seed_private(42, 3);
+
+        // Assert
+        assert_eq!(output, expected);
+        assert_eq!(output_neurons, 2);
+    }
+}
@@ -0,0 +1,190 @@
+use crate::operations::uninitialised::composite::ChainTarget;
+use crate::operations::{initialised, UninitialisedOperation};
+use crate::private::Sealed;
+use crate::{ElementType, Result};
+use core::marker::PhantomData;
+use ndarray::{ArrayView, ArrayViewMut, Ix2};
+
+/// This trait is used to identify the strategy that a pooling layer uses to summarise each
+/// window of a feature map into a single element, and how the gradient of that element is
+/// routed back to the window during backpropagation.
+///
+/// Note that this trait is sealed so the only implementations are `Max` and `Average`.
+pub trait Pool: Sealed {
+    /// The name of the pooling layer which uses this strategy, as shown in summaries of networks.
+    #[doc(hidden)]
+    const NAME: &'static str;
+
+    /// Summarises the given window, returning the pooled element and the flattened position within
+    /// the window that needs to be remembered in order to route the gradient back.
+    #[doc(hidden)]
+    fn pool(window: ArrayView<ElementType, Ix2>) -> (ElementType, usize);
+
+    /// Adds the gradient of the pooled element onto the elements of the window that contributed to it.
+    #[doc(hidden)]
+    fn route(window: ArrayViewMut<ElementType, Ix2>, position: usize, gradient: ElementType);
+}
+
+/// Pooling strategy which takes the largest element of each window. The gradient is routed
+/// entirely to the position of the largest element.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Max;
+
+impl Sealed for Max {}
+impl Pool for Max {
+    const NAME: &'static str = "MaxPooling2D";
+
+    fn pool(window: ArrayView<ElementType, Ix2>) -> (ElementType, usize) {
+        window
+            .iter()
+            .enumerate()
+            .fold((ElementType::MIN, 0), |(max, position), (index, elem)| {
+                if *elem > max {
+                    (*elem, index)
+                } else {
+                    (max, position)
+                }
+            })
+    }
+
+    fn route(window: ArrayViewMut<ElementType, Ix2>, position: usize, gradient: ElementType) {
+        if let Some(elem) = window.into_iter().nth(position) {
+            *elem += gradient;
+        }
+    }
+}
+
+/// Pooling strategy which takes the mean of the elements of each window. The gradient is
+/// shared equally between all the elements of the window.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Average;
+
+impl Sealed for Average {}
+impl Pool for Average {
+    const NAME: &'static str = "AveragePooling2D";
+
+    fn pool(window: ArrayView<ElementType, Ix2>) -> (ElementType, usize) {
+        (window.mean().unwrap_or(0.0), 0)
+    }
+
+    #[allow(clippy::cast_precision_loss)] // windows are never large enough to lose precision
+    fn route(mut window: ArrayViewMut<ElementType, Ix2>, _position: usize, gradient: ElementType) {
+        let share = gradient / window.len() as ElementType;
+        window.mapv_inplace(|elem| elem + share);
+    }
+}
+
+/// This is a 2D pooling layer which downsamples each channel of an image independently by
+/// summarising square windows of the given size, moving the window by the stride in each
+/// direction. Images are rank 4 tensors with the shape (batch, channels, height, width).
+/// The strategy used to summarise each window is given by the type parameter.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Operation<T> {
+    pool_size: usize,
+    stride: usize,
+    pooling: PhantomData<T>,
+}
+
+impl<T: Pool> Operation<T> {
+    /// Constructs a new pooling layer with the size of each side of the square window
+    /// and the stride to move the window by.
+    #[must_use]
+    pub const fn new(pool_size: usize, stride: usize) -> Self {
+        Self {
+            pool_size,
+            stride,
+            pooling: PhantomData,
+        }
+    }
+}
+
+impl<T> Sealed for Operation<T> {}
+impl<T> ChainTarget for Operation<T> {}
+impl<T: Pool> UninitialisedOperation for Operation<T> {
+    type Initialised = initialised::pooling2d::Operation<T>;
+
+    fn with_iter_private(
+        self,
+        _iter: &mut impl Iterator<Item = ElementType>,
+        input_neuron_count: u16,
+    ) -> Result<(Self::Initialised, u16)> {
+        Ok(self.with_seed_private(0, input_neuron_count))
+    }
+
+    fn with_seed_private(self, _seed: u64, input_neuron_count: u16) -> (Self::Initialised, u16) {
+        let initialised = initialised::pooling2d::Operation {
+            channels: input_neuron_count,
+            pool_size: self.pool_size,
+            stride: self.stride,
+            pooling: PhantomData,
+        };
+        (initialised, input_neuron_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::Array;
+
+    #[test]
+    fn test_max_pool() {
+        // Arrange
+        let window = Array::from_shape_vec((2, 2), [1.0, 4.0, -2.0, 3.0].to_vec()).unwrap();
+
+        // Act
+        let output = Max::pool(window.view());
+
+        // Assert
+        assert_eq!(output, (4.0, 1));
+    }
+
+    #[test]
+    fn test_max_route() {
+        // Arrange
+        let mut window = Array::from_elem((2, 2), 1.0);
+        let expected = Array::from_shape_vec((2, 2), [1.0, 1.0, 3.0, 1.0].to_vec()).unwrap();
+
+        // Act
+        Max::route(window.view_mut(), 2, 2.0);
+
+        // Assert
+        assert_eq!(window, expected);
+    }
+
+    #[test]
+    fn test_average_pool_and_route() {
+        // Arrange
+        let window = Array::from_shape_vec((2, 2), [1.0, 4.0, -2.0, 3.0].to_vec()).unwrap();
+        let mut gradient = Array::zeros((2, 2));
+
+        // Act
+        let output = Average::pool(window.view());
+        Average::route(gradient.view_mut(), output.1, 2.0);
+
+        // Assert
+        assert_eq!(output.0, 1.5);
+        assert_eq!(gradient, Array::from_elem((2, 2), 0.5));
+    }
+
+    #[test]
+    fn test_with_seed_private() {
+        // Arrange
+        let operation = Operation::<Max>::new(2, 1);
+        let expected = initialised::pooling2d::Operation {
+            channels: 3,
+            pool_size: 2,
+            stride: 1,
+            pooling: PhantomData::<Max>,
+        };
+
+        // Act
+        let (output, output_neurons) = operation.with_seed_private(42, 3);
+
+        // Assert
+        assert_eq!(output, expected);
+        assert_eq!(output_neurons, 3);
+    }
+}
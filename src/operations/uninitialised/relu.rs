@@ -9,7 +9,7 @@ use crate::Result;
 /// which can either be run in leaky mode (negative values
 /// are multiplied by a factor) or standard (negative values are mapped
 /// to 0).
-#[derive(Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct Operation {
     pub(crate) factor: ElementType,
 }
@@ -96,7 +96,7 @@ mod tests {
             neurons: 122,
             factor: 0.0,
         };
-        let mut iter = [].into_iter();
+        let mut iter = core::iter::empty();
 
         // Act
         let (initialised, output_neurons) = operation.with_iter_private(&mut iter, 122).unwrap();
@@ -0,0 +1,101 @@
+use crate::operations::uninitialised::composite::ChainTarget;
+use crate::operations::{initialised, UninitialisedOperation};
+use crate::private::Sealed;
+use crate::tensors::rank::Batched;
+use crate::{ElementType, Error, Result};
+use core::marker::PhantomData;
+
+/// This is a layer which reshapes each item of a batch into the given shape, which can be of a
+/// different rank to the input. This allows layers that work with different ranks of tensor
+/// to be chained together, with the ranks on either side of the connection checked at compile time.
+///
+/// The shape is given without the batch axis and must hold the same number of elements as each
+/// item of the input. The number of neurons the following layer sees is taken from the new
+/// shape, being the features for rank 2 and rank 3 tensors and the channels for higher ranks,
+/// and must fit in a `u16`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Operation<R, S: Batched> {
+    shape: S::Item,
+    input_rank: PhantomData<R>,
+}
+
+impl<R: Batched, S: Batched> Operation<R, S> {
+    /// Constructs a new reshape layer which reshapes each item of the batch into the given shape.
+    #[must_use]
+    pub const fn new(shape: S::Item) -> Self {
+        Self {
+            shape,
+            input_rank: PhantomData,
+        }
+    }
+}
+
+impl<R, S: Batched> Sealed for Operation<R, S> {}
+impl<R, S: Batched> ChainTarget for Operation<R, S> {}
+impl<R: Batched, S: Batched> UninitialisedOperation for Operation<R, S> {
+    type Initialised = initialised::reshape::Operation<R, S>;
+
+    fn with_iter_private(
+        self,
+        _iter: &mut impl Iterator<Item = ElementType>,
+        _input_neuron_count: u16,
+    ) -> Result<(Self::Initialised, u16)> {
+        let output_neurons =
+            u16::try_from(S::neurons(self.shape)).map_err(|_| Error::InvalidArgument)?;
+        let initialised = Self::Initialised {
+            shape: self.shape,
+            input_rank: PhantomData,
+        };
+        Ok((initialised, output_neurons))
+    }
+
+    fn with_seed_private(self, _seed: u64, input_neuron_count: u16) -> (Self::Initialised, u16) {
+        self.with_iter_private(&mut core::iter::empty(), input_neuron_count)
+            .unwrap() // see the documentation on the layer for this limit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layers::{Flatten, Reshape};
+    use crate::tensors::rank;
+
+    #[test]
+    fn test_with_seed_private_flatten() {
+        // Arrange
+        let operation = Flatten::<rank::Four>::new(48);
+
+        // Act
+        let (output, output_neurons) = operation.with_seed_private(42, 3);
+
+        // Assert
+        assert_eq!(output.shape, 48);
+        assert_eq!(output_neurons, 48);
+    }
+
+    #[test]
+    fn test_with_seed_private_image() {
+        // Arrange
+        let operation = Reshape::<rank::Two, rank::Four>::new((3, 4, 4));
+
+        // Act
+        let (output, output_neurons) = operation.with_seed_private(42, 48);
+
+        // Assert
+        assert_eq!(output.shape, (3, 4, 4));
+        assert_eq!(output_neurons, 3);
+    }
+
+    #[test]
+    fn test_with_iter_private_failure() {
+        // Arrange
+        let operation = Flatten::<rank::Four>::new(70_000);
+
+        // Act
+        let result = operation.with_iter_private(&mut core::iter::empty(), 3);
+
+        // Assert
+        assert_eq!(result.err(), Some(Error::InvalidArgument));
+    }
+}
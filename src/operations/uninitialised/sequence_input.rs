@@ -0,0 +1,96 @@
+use crate::operations::uninitialised::composite::{Chain, ChainTarget};
+use crate::operations::{initialised, UninitialisedOperation};
+use crate::private::Sealed;
+use crate::ElementType;
+use crate::Result;
+
+/// This structure represents an input operation for sequence data which will be used as the very
+/// first operation in front of recurrent layers. Sequences are provided as rank 3 tensors with
+/// the shape (batch, time, features) and this operation defines the number of features that the
+/// following layers will receive at each time step. The number of time steps is allowed to vary.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Operation {
+    features: u16,
+}
+
+impl Operation {
+    /// This function is used to construct a new sequence input operation with a given
+    /// feature count. If data is provided when running/training the network and the
+    /// feature count doesn't match then this will be an error.
+    #[must_use]
+    pub const fn new(features: u16) -> Self {
+        Self { features }
+    }
+}
+
+impl Sealed for Operation {}
+impl Chain for Operation {}
+impl ChainTarget for Operation {}
+impl UninitialisedOperation for Operation {
+    type Initialised = initialised::sequence_input::Operation;
+
+    fn with_iter_private(
+        self,
+        _iter: &mut impl Iterator<Item = ElementType>,
+        _input_neuron_count: u16,
+    ) -> Result<(Self::Initialised, u16)> {
+        Ok((
+            initialised::sequence_input::Operation {
+                features: self.features,
+            },
+            self.features,
+        ))
+    }
+
+    fn with_seed_private(self, _seed: u64, _input_neuron_count: u16) -> (Self::Initialised, u16) {
+        (
+            initialised::sequence_input::Operation {
+                features: self.features,
+            },
+            self.features,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        // Arrange
+        let expected = Operation { features: 3 };
+
+        // Act
+        let output = Operation::new(3);
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_with_iter() {
+        // Arrange
+        let operation = Operation::new(3);
+        let expected_initialised = initialised::sequence_input::Operation { features: 3 };
+
+        // Act
+        let initialised = operation.with_iter(core::iter::empty()).unwrap();
+
+        // Assert
+        assert_eq!(initialised, expected_initialised);
+    }
+
+    #[test]
+    fn test_with_seed() {
+        // Arrange
+        let operation = Operation::new(1);
+        let expected_initialised = initialised::sequence_input::Operation { features: 1 };
+
+        // Act
+        let initialised = operation.with_seed(42);
+
+        // Assert
+        assert_eq!(initialised, expected_initialised);
+    }
+}
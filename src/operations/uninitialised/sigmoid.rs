@@ -7,7 +7,7 @@ use crate::Result;
 
 /// This is a sigmoidal activation function which is a nonlinear
 /// activation function using the sigmoid function.
-#[derive(Debug, Default, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Operation(());
 
 impl Operation {
@@ -68,7 +68,7 @@ mod tests {
         // Arrange
         let operation = Operation::new();
         let expected_initialised = initialised::sigmoid::Operation { neurons: 122 };
-        let mut iter = [].into_iter();
+        let mut iter = core::iter::empty();
 
         // Act
         let (initialised, output_neurons) = operation.with_iter_private(&mut iter, 122).unwrap();
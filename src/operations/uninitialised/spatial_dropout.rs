@@ -0,0 +1,72 @@
+use crate::operations::uninitialised::composite::ChainTarget;
+use crate::operations::{initialised, uninitialised, UninitialisedOperation};
+use crate::private::Sealed;
+use crate::{ElementType, Result};
+
+/// Represents the spatial dropout layer, which randomly drops out whole channels (feature maps)
+/// of an image rather than single elements. Neighbouring pixels of a feature map are strongly
+/// correlated, so dropping them one at a time does little to regularise a convolutional network.
+/// Images are rank 4 tensors with the shape (batch, channels, height, width). When making
+/// predictions, nothing is dropped out but every channel is scaled using the keep probability,
+/// unless inverted scaling is used the same as for `Dropout`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Operation(uninitialised::dropout::Operation);
+
+impl Operation {
+    /// Constructs a new instance of the spatial dropout layer with the
+    /// specified probability of keeping each channel.
+    #[must_use]
+    pub const fn new(keep_probability: ElementType) -> Self {
+        Self(uninitialised::dropout::Operation::new(keep_probability))
+    }
+
+    /// Uses inverted dropout, which divides the channels that are kept during training by the
+    /// keep probability rather than multiplying every channel by it when making predictions.
+    #[must_use]
+    pub const fn with_inverted_scaling(self) -> Self {
+        Self(self.0.with_inverted_scaling())
+    }
+}
+
+impl Sealed for Operation {}
+impl ChainTarget for Operation {}
+impl UninitialisedOperation for Operation {
+    type Initialised = initialised::spatial_dropout::Operation;
+
+    fn with_iter_private(
+        self,
+        iter: &mut impl Iterator<Item = ElementType>,
+        input_neuron_count: u16,
+    ) -> Result<(Self::Initialised, u16)> {
+        let (dropout, channels) = self.0.with_iter_private(iter, input_neuron_count)?;
+        Ok((initialised::spatial_dropout::Operation(dropout), channels))
+    }
+
+    fn with_seed_private(self, seed: u64, input_neuron_count: u16) -> (Self::Initialised, u16) {
+        let (dropout, channels) = self.0.with_seed_private(seed, input_neuron_count);
+        (initialised::spatial_dropout::Operation(dropout), channels)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_seed_private() {
+        // Arrange
+        let uninitialised = Operation::new(0.8);
+        let expected = initialised::spatial_dropout::Operation(initialised::dropout::Operation {
+            keep_probability: 0.8,
+            seed: Some(42),
+            inverted: false,
+        });
+
+        // Act
+        let (output, channels) = uninitialised.with_seed_private(42, 3);
+
+        // Assert
+        assert_eq!(output, expected);
+        assert_eq!(channels, 3);
+    }
+}
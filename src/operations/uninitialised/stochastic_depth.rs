@@ -0,0 +1,153 @@
+use crate::initialisers::Initialiser;
+use crate::operations::uninitialised::composite::ChainTarget;
+use crate::operations::{initialised, InitialisedOperation, UninitialisedOperation};
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+
+/// Represents the stochastic depth (layer dropout) operation which wraps a block of layers
+/// that keeps the number of neurons the same. During training the whole block is randomly
+/// skipped with the given drop probability, passing the input straight through. When running in
+/// inference mode (making predictions), the block's output is blended with the input using the
+/// probability the block would be kept, giving the expected output over training.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Operation<T> {
+    block: T,
+    drop_probability: ElementType,
+}
+
+impl<T> Operation<T> {
+    /// Constructs a new instance of the stochastic depth layer which wraps the given
+    /// block and skips it with the specified drop probability during training.
+    #[must_use]
+    pub const fn new(block: T, drop_probability: ElementType) -> Self {
+        Self {
+            block,
+            drop_probability,
+        }
+    }
+}
+
+impl<T> Sealed for Operation<T> {}
+impl<T> ChainTarget for Operation<T> {}
+impl<T: UninitialisedOperation> UninitialisedOperation for Operation<T>
+where
+    <T as UninitialisedOperation>::Initialised:
+        InitialisedOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+{
+    type Initialised = initialised::stochastic_depth::Operation<T::Initialised>;
+
+    fn with_iter_private(
+        self,
+        iter: &mut impl Iterator<Item = ElementType>,
+        input_neuron_count: u16,
+    ) -> Result<(Self::Initialised, u16)> {
+        let (block, output_neuron_count) =
+            self.block.with_iter_private(iter, input_neuron_count)?;
+        if output_neuron_count == input_neuron_count {
+            let initialised = Self::Initialised {
+                block,
+                drop_probability: self.drop_probability,
+                seed: None,
+            };
+            Ok((initialised, output_neuron_count))
+        } else {
+            Err(Error::ShapeMismatch {
+                expected: input_neuron_count as usize,
+                actual: output_neuron_count as usize,
+            })
+        }
+    }
+
+    fn with_seed_private(self, seed: u64, input_neuron_count: u16) -> (Self::Initialised, u16) {
+        let (block, output_neuron_count) = self.block.with_seed_private(seed, input_neuron_count);
+        let initialised = Self::Initialised {
+            block,
+            drop_probability: self.drop_probability,
+            seed: Some(seed),
+        };
+        (initialised, output_neuron_count)
+    }
+
+    fn with_initialiser_private(self, initialiser: Initialiser) -> Self {
+        Self {
+            block: self.block.with_initialiser_private(initialiser),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::Linear;
+    use crate::layers::Dense;
+
+    #[test]
+    fn test_new() {
+        // Arrange
+        let expected = Operation {
+            block: Linear::new(),
+            drop_probability: 0.2,
+        };
+
+        // Act
+        let output = Operation::new(Linear::new(), 0.2);
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_with_iter_private_success() {
+        // Arrange
+        let operation = Operation::new(Dense::new(2, Linear::new()), 0.2);
+        let mut iter = [1.0, 0.0, 0.0, 1.0, 0.5, 0.5].into_iter();
+        let expected = initialised::stochastic_depth::Operation {
+            block: Dense::new(2, Linear::new())
+                .with_iter_private(&mut [1.0, 0.0, 0.0, 1.0, 0.5, 0.5].into_iter(), 2)
+                .unwrap()
+                .0,
+            drop_probability: 0.2,
+            seed: None,
+        };
+
+        // Act
+        let (output, output_neurons) = operation.with_iter_private(&mut iter, 2).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+        assert_eq!(output_neurons, 2);
+    }
+
+    #[test]
+    fn test_with_iter_private_failure() {
+        // Arrange
+        let operation = Operation::new(Dense::new(3, Linear::new()), 0.2);
+        let mut iter = [1.0; 9].into_iter();
+
+        // Act
+        let result = operation.with_iter_private(&mut iter, 2);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_with_seed_private() {
+        // Arrange
+        let operation = Operation::new(Dense::new(2, Linear::new()), 0.2);
+        let expected = initialised::stochastic_depth::Operation {
+            block: Dense::new(2, Linear::new()).with_seed_private(42, 2).0,
+            drop_probability: 0.2,
+            seed: Some(42),
+        };
+
+        // Act
+        let (output, output_neurons) = operation.with_seed_private(42, 2);
+
+        // Assert
+        assert_eq!(output, expected);
+        assert_eq!(output_neurons, 2);
+    }
+}
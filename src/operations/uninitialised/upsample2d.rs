@@ -0,0 +1,98 @@
+use crate::operations::uninitialised::composite::ChainTarget;
+use crate::operations::{initialised, UninitialisedOperation};
+use crate::private::Sealed;
+use crate::{ElementType, Result};
+
+/// How an upsampling layer fills in the pixels between those of the original image.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Interpolation {
+    /// Each pixel is copied into a square block of pixels the size of the scale.
+    Nearest,
+    /// Each pixel is a weighted average of the (up to) four nearest pixels of the original image,
+    /// treating pixels as squares with their value at the centre so that the edges stay aligned.
+    Bilinear,
+}
+
+/// This is a 2D upsampling layer which enlarges each channel of an image by a whole number scale in
+/// both directions without any parameters, filling in the new pixels by interpolation. It's a
+/// cheaper alternative to a `ConvTranspose2D` for the decoder of a network, and is often followed
+/// by a `Conv2D`. Images are rank 4 tensors with the shape (batch, channels, height, width).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Operation {
+    scale: usize,
+    interpolation: Interpolation,
+}
+
+impl Operation {
+    /// Constructs a new upsampling layer which multiplies the height and width of the image by the
+    /// given scale, using the given interpolation.
+    #[must_use]
+    pub const fn new(scale: usize, interpolation: Interpolation) -> Self {
+        Self {
+            scale,
+            interpolation,
+        }
+    }
+}
+
+impl Sealed for Operation {}
+impl ChainTarget for Operation {}
+impl UninitialisedOperation for Operation {
+    type Initialised = initialised::upsample2d::Operation;
+
+    fn with_iter_private(
+        self,
+        _iter: &mut impl Iterator<Item = ElementType>,
+        input_neuron_count: u16,
+    ) -> Result<(Self::Initialised, u16)> {
+        Ok(self.with_seed_private(0, input_neuron_count))
+    }
+
+    fn with_seed_private(self, _seed: u64, input_neuron_count: u16) -> (Self::Initialised, u16) {
+        let initialised = initialised::upsample2d::Operation {
+            channels: input_neuron_count,
+            scale: self.scale,
+            interpolation: self.interpolation,
+        };
+        (initialised, input_neuron_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_seed_private() {
+        // Arrange
+        let operation = Operation::new(2, Interpolation::Bilinear);
+        let expected = initialised::upsample2d::Operation {
+            channels: 3,
+            scale: 2,
+            interpolation: Interpolation::Bilinear,
+        };
+
+        // Act
+        let (output, output_neurons) = operation.with_seed_private(42, 3);
+
+        // Assert
+        assert_eq!(output, expected);
+        assert_eq!(output_neurons, 3);
+    }
+
+    #[test]
+    fn test_with_iter_private() {
+        // Arrange
+        let operation = Operation::new(3, Interpolation::Nearest);
+        let mut iter = [1.0, 2.0].into_iter();
+
+        // Act
+        let (output, output_neurons) = operation.with_iter_private(&mut iter, 2).unwrap();
+
+        // Assert
+        assert_eq!(output.scale, 3);
+        assert_eq!(output_neurons, 2);
+        assert_eq!(iter.next(), Some(1.0));
+    }
+}
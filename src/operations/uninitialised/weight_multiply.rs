@@ -1,16 +1,18 @@
+use crate::initialisers::Initialiser;
 use crate::operations::{initialised, uninitialised};
 use crate::private::Sealed;
 use crate::tensors::{rank, Tensor};
 use crate::{ElementType, Error, Result};
 use core::iter::repeat_with;
 use rand::rngs::StdRng;
-use rand::{Rng, SeedableRng};
+use rand::SeedableRng;
 
 /// This operation will perform a weighted sum of the parameters with the
 /// input assuming they're of compatible shapes.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Operation {
     pub(crate) output_neurons: u16,
+    pub(crate) initialiser: Option<Initialiser>,
 }
 
 impl Operation {
@@ -18,7 +20,20 @@ impl Operation {
     /// given number of neurons to output from the operation.
     #[must_use]
     pub const fn new(output_neurons: u16) -> Self {
-        Self { output_neurons }
+        Self {
+            output_neurons,
+            initialiser: None,
+        }
+    }
+
+    /// This function sets the strategy used to generate the weights when the
+    /// operation is initialised from a random seed.
+    #[must_use]
+    pub const fn with_initialiser(self, initialiser: Initialiser) -> Self {
+        Self {
+            output_neurons: self.output_neurons,
+            initialiser: Some(initialiser),
+        }
     }
 }
 
@@ -34,7 +49,12 @@ impl uninitialised::Operation for Operation {
         let weight_dim = (input_neuron_count as usize, self.output_neurons as usize);
         let weight_count = weight_dim.0 * weight_dim.1;
         let parameter =
-            Tensor::<rank::Two>::new(weight_dim, iter.take(weight_count)).map_err(|_| Error(()))?;
+            Tensor::<rank::Two>::new(weight_dim, iter.take(weight_count)).map_err(|_| {
+                Error::InsufficientWeights {
+                    layer_index: 0,
+                    expected: weight_count,
+                }
+            })?;
         let output_neurons = self.output_neurons;
         Ok((
             initialised::weight_multiply::Operation {
@@ -47,13 +67,20 @@ impl uninitialised::Operation for Operation {
 
     fn with_seed_private(self, seed: u64, input_neuron_count: u16) -> (Self::Initialised, u16) {
         let mut generator = StdRng::seed_from_u64(seed);
-        let xavier_delta = ElementType::sqrt(6.0)
-            / ElementType::sqrt((input_neuron_count + self.output_neurons).into());
-        // see Xavier initialization
-        let mut iter = repeat_with(|| generator.gen_range(-xavier_delta..=xavier_delta));
+        let (initialiser, output_neurons) =
+            (self.initialiser.unwrap_or_default(), self.output_neurons);
+        let mut iter =
+            repeat_with(|| initialiser.sample(&mut generator, input_neuron_count, output_neurons));
         self.with_iter_private(&mut iter, input_neuron_count)
             .unwrap() // unwrapping is safe because we're generating an infinite sequence so there's always enough
     }
+
+    fn with_initialiser_private(self, initialiser: Initialiser) -> Self {
+        Self {
+            initialiser: self.initialiser.or(Some(initialiser)),
+            ..self
+        }
+    }
 }
 
 #[cfg(test)]
@@ -64,7 +91,10 @@ mod tests {
     #[test]
     fn test_new() {
         // Arrange
-        let expected = Operation { output_neurons: 42 };
+        let expected = Operation {
+            output_neurons: 42,
+            initialiser: None,
+        };
 
         // Act
         let operation = Operation::new(42);
@@ -130,4 +160,19 @@ mod tests {
         assert_eq!(output_neurons, 1);
         assert_eq!(operation, expected);
     }
+
+    #[test]
+    fn test_with_initialiser() {
+        // Arrange
+        let expected = Operation {
+            output_neurons: 42,
+            initialiser: Some(Initialiser::LeCunNormal),
+        };
+
+        // Act
+        let operation = Operation::new(42).with_initialiser(Initialiser::LeCunNormal);
+
+        // Assert
+        assert_eq!(operation, expected);
+    }
 }
@@ -0,0 +1,254 @@
+use crate::optimisers;
+use crate::optimisers::learning_rate_handlers::LearningRateHandler;
+use crate::optimisers::{base, NullOptimiser};
+use crate::private::Sealed;
+use crate::tensors::rank::Rank;
+use crate::tensors::Tensor;
+use crate::ElementType;
+use ndarray::{azip, Array};
+
+/// This is an implementation of the Adam (adaptive moment estimation)
+/// optimisation strategy which keeps a decaying average of both the gradients
+/// (first moment) and the squared gradients (second moment) for each parameter, and uses
+/// them to adapt the size of the update made to each parameter individually.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OptimiserFactory<T> {
+    learning_rate_handler: T,
+    beta1: ElementType,
+    beta2: ElementType,
+    epsilon: ElementType,
+}
+
+impl<T> OptimiserFactory<T> {
+    /// Constructs a new instance of the Adam optimiser with the given learning rate
+    /// handler to get the learning rate from, the decay rates of the first and second moments,
+    /// and a small epsilon to avoid dividing by zero. Typical values are 0.9, 0.999 and 1e-8.
+    #[must_use]
+    pub const fn new(
+        learning_rate_handler: T,
+        beta1: ElementType,
+        beta2: ElementType,
+        epsilon: ElementType,
+    ) -> Self {
+        Self {
+            learning_rate_handler,
+            beta1,
+            beta2,
+            epsilon,
+        }
+    }
+}
+
+impl<T: LearningRateHandler + Clone, R: Rank> optimisers::base::OptimiserFactory<Tensor<R>>
+    for OptimiserFactory<T>
+{
+    type Optimiser = Optimiser<T, R>;
+    fn instantiate(&self) -> Self::Optimiser {
+        Self::Optimiser {
+            learning_rate_handler: self.learning_rate_handler.clone(),
+            beta1: self.beta1,
+            beta2: self.beta2,
+            epsilon: self.epsilon,
+            first_moment: None,
+            second_moment: None,
+            step: 0,
+        }
+    }
+}
+
+impl<T> optimisers::base::OptimiserFactory<()> for OptimiserFactory<T> {
+    type Optimiser = optimisers::null::Optimiser;
+    fn instantiate(&self) -> Self::Optimiser {
+        base::OptimiserFactory::<()>::instantiate(&NullOptimiser::new())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "T: serde::Serialize, R::Internal: serde::Serialize",
+        deserialize = "T: serde::Deserialize<'de>, R::Internal: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Optimiser<T, R: Rank> {
+    learning_rate_handler: T,
+    beta1: ElementType,
+    beta2: ElementType,
+    epsilon: ElementType,
+    first_moment: Option<Array<ElementType, R::Internal>>,
+    second_moment: Option<Array<ElementType, R::Internal>>,
+    step: i32,
+}
+
+impl<T, R: Rank> Sealed for Optimiser<T, R> {}
+impl<T: LearningRateHandler, R: Rank> optimisers::base::Optimiser<Tensor<R>> for Optimiser<T, R> {
+    fn optimise(&mut self, parameter: &mut Tensor<R>, gradient: &Tensor<R>) {
+        let (parameter, gradient) = (&mut parameter.0, &gradient.0);
+        let first = &mut self.first_moment;
+        let first = first.get_or_insert_with(|| Array::zeros(parameter.raw_dim()));
+        let second = &mut self.second_moment;
+        let second = second.get_or_insert_with(|| Array::zeros(parameter.raw_dim()));
+        self.step = self.step.saturating_add(1);
+        let (beta1, beta2, epsilon) = (self.beta1, self.beta2, self.epsilon);
+        let first_correction = 1.0 - beta1.powi(self.step);
+        let second_correction = 1.0 - beta2.powi(self.step);
+        let learning_rate = self.learning_rate_handler.learning_rate();
+        azip!((parameter in parameter, gradient in gradient, first in first, second in second) {
+            *first = (*first).mul_add(beta1, (1.0 - beta1) * gradient);
+            *second = (*second).mul_add(beta2, (1.0 - beta2) * gradient * gradient);
+            let first = *first / first_correction;
+            let second = *second / second_correction;
+            *parameter -= learning_rate * first / (second.sqrt() + epsilon);
+        });
+    }
+
+    fn init(&mut self, epochs: u32) {
+        self.learning_rate_handler.init(epochs);
+    }
+
+    fn end_epoch(&mut self) {
+        self.learning_rate_handler.end_epoch();
+    }
+
+    fn end_batch(&mut self) {
+        self.learning_rate_handler.end_batch();
+    }
+
+    fn observe_loss(&mut self, loss: ElementType) {
+        self.learning_rate_handler.observe_loss(loss);
+    }
+
+    fn learning_rate(&self) -> Option<ElementType> {
+        Some(self.learning_rate_handler.learning_rate())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::Linear;
+    use crate::layers::{Chain, Dense, Input};
+    use crate::operations::{
+        BackwardOperation, Forward, ForwardOperation, InitialisedOperation, TrainableOperation,
+        UninitialisedOperation, WithOptimiser,
+    };
+    use crate::optimisers::base::Optimiser as BaseOptimiser;
+    use crate::optimisers::base::OptimiserFactory as BaseOptimiserFactory;
+    use crate::optimisers::learning_rate_handlers::{
+        FixedLearningRateHandler, LinearDecayLearningRateHandler,
+    };
+    use crate::optimisers::Adam;
+    use crate::tensors::{rank, Tensor};
+
+    #[test]
+    fn test_optimise_first_step() {
+        // Arrange
+        // after bias correction, the first step moves each parameter by the learning rate.
+        let factory = Adam::new(FixedLearningRateHandler::new(0.1), 0.9, 0.999, 0.0);
+        let mut optimiser = BaseOptimiserFactory::<Tensor<rank::Two>>::instantiate(&factory);
+        let mut parameter = Tensor::<rank::Two>::new((1, 3), [1.0, 2.0, 3.0]).unwrap();
+        let gradient = Tensor::<rank::Two>::new((1, 3), [0.5, -4.0, 0.001]).unwrap();
+        let expected = [0.9, 2.1, 2.9];
+
+        // Act
+        optimiser.optimise(&mut parameter, &gradient);
+
+        // Assert
+        for (output, expected) in parameter.into_iter().zip(expected) {
+            assert!((output - expected).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_optimise_second_step() {
+        // Arrange
+        let factory = Adam::new(FixedLearningRateHandler::new(0.1), 0.5, 0.5, 0.0);
+        let mut optimiser = BaseOptimiserFactory::<Tensor<rank::One>>::instantiate(&factory);
+        let mut parameter = Tensor::<rank::One>::new([0.0]);
+        // first = (0.5 * 1 + 0.5 * 4) / 0.75 = 10 / 3, second = (0.5 * 2 + 0.5 * 16) / 0.75 = 12
+        let expected = -0.1 - 0.1 * (10.0 / 3.0) / ElementType::sqrt(12.0);
+
+        // Act
+        optimiser.optimise(&mut parameter, &Tensor::<rank::One>::new([2.0]));
+        optimiser.optimise(&mut parameter, &Tensor::<rank::One>::new([4.0]));
+
+        // Assert
+        assert!((parameter.into_iter().next().unwrap() - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_optimise_network() {
+        // Arrange
+        let network = Input::new(1)
+            .chain(Dense::new(1, Linear::new()))
+            .with_iter([1.0, 1.0].into_iter())
+            .unwrap();
+        let mut network = network.with_optimiser(Adam::new(
+            FixedLearningRateHandler::new(0.5),
+            0.9,
+            0.999,
+            0.0,
+        ));
+        let input = Tensor::<rank::Two>::new((1, 1), [2.0]).unwrap();
+        let output_gradient = Tensor::<rank::Two>::new((1, 1), [-1.0]).unwrap();
+        let expected = [1.5, 1.5];
+
+        // Act
+        let (forward, _) = network.forward(input).unwrap();
+        let (backward, _) = forward.backward(output_gradient).unwrap();
+        backward.optimise();
+        let output = network.into_initialised().iter();
+
+        // Assert
+        for (output, expected) in output.zip(expected) {
+            assert!((output - expected).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_instantiate_with_unit() {
+        // Arrange
+        let optimiser =
+            OptimiserFactory::new(FixedLearningRateHandler::new(0.01), 0.9, 0.999, 1e-8);
+        let expected =
+            <NullOptimiser as BaseOptimiserFactory<()>>::instantiate(&NullOptimiser::new());
+
+        // Act
+        let optimiser =
+            <OptimiserFactory<FixedLearningRateHandler> as BaseOptimiserFactory<()>>::instantiate(
+                &optimiser,
+            );
+
+        // Assert
+        assert_eq!(optimiser, expected);
+    }
+
+    #[test]
+    fn test_learning_rate_update_functions() {
+        // Arrange
+        let mut optimiser: Optimiser<_, rank::Two> = Optimiser {
+            learning_rate_handler: LinearDecayLearningRateHandler::new(0.1, 0.01),
+            beta1: 0.9,
+            beta2: 0.999,
+            epsilon: 1e-8,
+            first_moment: None,
+            second_moment: None,
+            step: 0,
+        };
+        let mut expected = LinearDecayLearningRateHandler::new(0.1, 0.01);
+        expected.init(3);
+        expected.end_epoch();
+        expected.end_batch();
+
+        // Act
+        optimiser.init(3);
+        optimiser.end_epoch();
+        optimiser.end_batch();
+
+        // Assert
+        assert_eq!(optimiser.learning_rate_handler, expected);
+    }
+}
@@ -0,0 +1,286 @@
+use crate::optimisers::base;
+use crate::private::Sealed;
+use crate::tensors::rank::Rank;
+use crate::tensors::Tensor;
+use crate::ElementType;
+
+/// The lower bound used for the norm of a parameter so that parameters initialised
+/// at (or close to) zero can still receive updates.
+const EPSILON: ElementType = 1e-3;
+
+/// This is an optimiser which wraps another optimiser and performs adaptive gradient
+/// clipping before passing the gradient on. Whenever the ratio of the norm of a gradient
+/// to the norm of the parameter it's for exceeds the threshold, the gradient is scaled down so
+/// that the ratio equals the threshold. This keeps updates small relative to the size of the weights.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OptimiserFactory<T> {
+    inner: T,
+    threshold: ElementType,
+}
+
+impl<T> OptimiserFactory<T> {
+    /// Constructs a new adaptive gradient clipping optimiser wrapping the given inner
+    /// optimiser, which will clip gradients whose norm relative to the parameter's norm
+    /// exceeds the given threshold.
+    #[must_use]
+    pub const fn new(inner: T, threshold: ElementType) -> Self {
+        Self { inner, threshold }
+    }
+}
+
+impl<T> Sealed for OptimiserFactory<T> {}
+impl<T: base::OptimiserFactory<Tensor<R>>, R: Rank> base::OptimiserFactory<Tensor<R>>
+    for OptimiserFactory<T>
+{
+    type Optimiser = Optimiser<T::Optimiser>;
+    fn instantiate(&self) -> Self::Optimiser {
+        Self::Optimiser {
+            inner: self.inner.instantiate(),
+            threshold: self.threshold,
+        }
+    }
+}
+
+impl<T: base::OptimiserFactory<()>> base::OptimiserFactory<()> for OptimiserFactory<T> {
+    type Optimiser = T::Optimiser;
+    fn instantiate(&self) -> Self::Optimiser {
+        self.inner.instantiate()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Optimiser<T> {
+    inner: T,
+    threshold: ElementType,
+}
+
+impl<T> Sealed for Optimiser<T> {}
+impl<T: base::Optimiser<Tensor<R>>, R: Rank> base::Optimiser<Tensor<R>> for Optimiser<T> {
+    fn optimise(&mut self, parameter: &mut Tensor<R>, gradient: &Tensor<R>) {
+        self.optimise_with_norm(parameter, gradient, gradient.l2_norm());
+    }
+
+    fn optimise_with_norm(
+        &mut self,
+        parameter: &mut Tensor<R>,
+        gradient: &Tensor<R>,
+        global_norm: ElementType,
+    ) {
+        let parameter_norm = parameter.l2_norm().max(EPSILON);
+        let gradient_norm = gradient.l2_norm();
+        let max_gradient_norm = self.threshold * parameter_norm;
+        if gradient_norm > max_gradient_norm {
+            let gradient = Tensor(&gradient.0 * (max_gradient_norm / gradient_norm));
+            self.inner
+                .optimise_with_norm(parameter, &gradient, global_norm);
+        } else {
+            self.inner
+                .optimise_with_norm(parameter, gradient, global_norm);
+        }
+    }
+
+    fn init(&mut self, epochs: u32) {
+        self.inner.init(epochs);
+    }
+
+    fn end_epoch(&mut self) {
+        self.inner.end_epoch();
+    }
+
+    fn end_batch(&mut self) {
+        self.inner.end_batch();
+    }
+
+    fn observe_loss(&mut self, loss: ElementType) {
+        self.inner.observe_loss(loss);
+    }
+
+    fn learning_rate(&self) -> Option<ElementType> {
+        self.inner.learning_rate()
+    }
+
+    fn penalty(&self, parameter: &Tensor<R>) -> ElementType {
+        self.inner.penalty(parameter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::Linear;
+    use crate::layers::{Chain, Dense, Input};
+    use crate::operations::{
+        BackwardOperation, Forward, ForwardOperation, InitialisedOperation, TrainableOperation,
+        UninitialisedOperation, WithOptimiser,
+    };
+    use crate::optimisers::base::Optimiser as BaseOptimiser;
+    use crate::optimisers::base::OptimiserFactory as BaseOptimiserFactory;
+    use crate::optimisers::learning_rate_handlers::{
+        FixedLearningRateHandler, LinearDecayLearningRateHandler,
+    };
+    use crate::optimisers::{AdaptiveGradientClip, NullOptimiser, SGD};
+    use crate::tensors::{rank, Tensor};
+    use alloc::rc::Rc;
+    use alloc::vec::Vec;
+    use core::cell::RefCell;
+
+    #[derive(Clone, Default)]
+    struct RecordingOptimiserFactory(Rc<RefCell<Vec<ElementType>>>);
+
+    impl BaseOptimiserFactory<Tensor<rank::Two>> for RecordingOptimiserFactory {
+        type Optimiser = Self;
+
+        fn instantiate(&self) -> Self::Optimiser {
+            self.clone()
+        }
+    }
+
+    impl BaseOptimiserFactory<()> for RecordingOptimiserFactory {
+        type Optimiser = <NullOptimiser as BaseOptimiserFactory<()>>::Optimiser;
+
+        fn instantiate(&self) -> Self::Optimiser {
+            BaseOptimiserFactory::<()>::instantiate(&NullOptimiser::new())
+        }
+    }
+
+    impl BaseOptimiser<Tensor<rank::Two>> for RecordingOptimiserFactory {
+        fn optimise(&mut self, _parameter: &mut Tensor<rank::Two>, _gradient: &Tensor<rank::Two>) {}
+
+        fn optimise_with_norm(
+            &mut self,
+            _parameter: &mut Tensor<rank::Two>,
+            _gradient: &Tensor<rank::Two>,
+            global_norm: ElementType,
+        ) {
+            self.0.borrow_mut().push(global_norm);
+        }
+
+        fn init(&mut self, _epochs: u32) {}
+
+        fn end_epoch(&mut self) {}
+
+        fn end_batch(&mut self) {}
+    }
+
+    #[test]
+    fn test_optimise_clipped() {
+        // Arrange
+        let factory = AdaptiveGradientClip::new(SGD::new(FixedLearningRateHandler::new(1.0)), 0.5);
+        let mut optimiser = BaseOptimiserFactory::<Tensor<rank::Two>>::instantiate(&factory);
+        let mut parameter = Tensor::<rank::Two>::new((1, 2), [3.0, 4.0]).unwrap();
+        let gradient = Tensor::<rank::Two>::new((1, 2), [6.0, 8.0]).unwrap();
+        let expected = Tensor::<rank::Two>::new((1, 2), [1.5, 2.0]).unwrap();
+
+        // Act
+        optimiser.optimise(&mut parameter, &gradient);
+
+        // Assert
+        assert_eq!(parameter, expected);
+    }
+
+    #[test]
+    fn test_optimise_unclipped() {
+        // Arrange
+        let factory = AdaptiveGradientClip::new(SGD::new(FixedLearningRateHandler::new(1.0)), 0.5);
+        let mut optimiser = BaseOptimiserFactory::<Tensor<rank::Two>>::instantiate(&factory);
+        let mut parameter = Tensor::<rank::Two>::new((1, 2), [3.0, 4.0]).unwrap();
+        let gradient = Tensor::<rank::Two>::new((1, 2), [0.5, 1.0]).unwrap();
+        let expected = Tensor::<rank::Two>::new((1, 2), [2.5, 3.0]).unwrap();
+
+        // Act
+        optimiser.optimise(&mut parameter, &gradient);
+
+        // Assert
+        assert_eq!(parameter, expected);
+    }
+
+    #[test]
+    fn test_optimise_zero_parameter() {
+        // Arrange
+        let factory = AdaptiveGradientClip::new(SGD::new(FixedLearningRateHandler::new(1.0)), 0.5);
+        let mut optimiser = BaseOptimiserFactory::<Tensor<rank::Two>>::instantiate(&factory);
+        let mut parameter = Tensor::<rank::Two>::new((1, 2), [0.0, 0.0]).unwrap();
+        let gradient = Tensor::<rank::Two>::new((1, 2), [3.0, 4.0]).unwrap();
+
+        // Act
+        optimiser.optimise(&mut parameter, &gradient);
+
+        // Assert
+        assert!(parameter.l2_norm() > 0.0);
+        assert!(parameter.l2_norm() <= EPSILON.mul_add(0.5, ElementType::EPSILON));
+    }
+
+    #[test]
+    fn test_network_optimise() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(1, Linear::new()))
+            .with_iter([3.0, 4.0, 0.0].into_iter())
+            .unwrap();
+        let factory = AdaptiveGradientClip::new(SGD::new(FixedLearningRateHandler::new(1.0)), 0.1);
+        let mut network = network.with_optimiser(factory);
+        let input = Tensor::<rank::Two>::new((1, 2), [3.0, 4.0]).unwrap();
+        let output_gradient = Tensor::<rank::Two>::new((1, 1), [2.0]).unwrap();
+        let expected = [2.7, 3.6, -0.0001];
+
+        // Act
+        let (forward, _) = network.forward(input).unwrap();
+        let (backward, _) = forward.backward(output_gradient).unwrap();
+        backward.optimise();
+
+        // Assert
+        network
+            .into_initialised()
+            .iter()
+            .zip(expected)
+            .for_each(|(output, expected)| assert!((output - expected).abs() < 1e-6));
+    }
+
+    #[test]
+    fn test_network_optimise_global_norm() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(1, Linear::new()))
+            .with_iter([3.0, 4.0, 0.0].into_iter())
+            .unwrap();
+        let recording = RecordingOptimiserFactory::default();
+        let factory = AdaptiveGradientClip::new(recording.clone(), 0.1);
+        let mut network = network.with_optimiser(factory);
+        let input = Tensor::<rank::Two>::new((1, 2), [3.0, 4.0]).unwrap();
+        let output_gradient = Tensor::<rank::Two>::new((1, 1), [2.0]).unwrap();
+        let expected = ElementType::sqrt(104.0);
+
+        // Act
+        let (forward, _) = network.forward(input).unwrap();
+        let (backward, _) = forward.backward(output_gradient).unwrap();
+        backward.optimise();
+
+        // Assert
+        let global_norms = recording.0.borrow();
+        assert_eq!(global_norms.len(), 2);
+        for global_norm in global_norms.iter() {
+            assert!((global_norm - expected).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_learning_rate_update_functions() {
+        // Arrange
+        let factory =
+            AdaptiveGradientClip::new(SGD::new(LinearDecayLearningRateHandler::new(1.0, 0.5)), 0.5);
+        let mut optimiser = BaseOptimiserFactory::<Tensor<rank::Two>>::instantiate(&factory);
+        let mut parameter = Tensor::<rank::Two>::new((1, 2), [3.0, 4.0]).unwrap();
+        let gradient = Tensor::<rank::Two>::new((1, 2), [0.5, 1.0]).unwrap();
+        let expected = Tensor::<rank::Two>::new((1, 2), [2.625, 3.25]).unwrap();
+
+        // Act
+        BaseOptimiser::<Tensor<rank::Two>>::init(&mut optimiser, 3);
+        BaseOptimiser::<Tensor<rank::Two>>::end_epoch(&mut optimiser);
+        optimiser.optimise(&mut parameter, &gradient);
+
+        // Assert
+        assert_eq!(parameter, expected);
+    }
+}
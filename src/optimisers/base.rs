@@ -1,3 +1,5 @@
+use crate::ElementType;
+
 pub trait OptimiserFactory<T> {
     type Optimiser: Optimiser<T>;
     fn instantiate(&self) -> Self::Optimiser;
@@ -5,6 +7,17 @@ pub trait OptimiserFactory<T> {
 
 pub trait Optimiser<T> {
     fn optimise(&mut self, parameter: &mut T, gradient: &T);
-    fn init(&mut self, epochs: u16);
+    fn optimise_with_norm(&mut self, parameter: &mut T, gradient: &T, _global_norm: ElementType) {
+        self.optimise(parameter, gradient);
+    }
+    fn init(&mut self, epochs: u32);
     fn end_epoch(&mut self);
+    fn end_batch(&mut self);
+    fn observe_loss(&mut self, _loss: ElementType) {}
+    fn learning_rate(&self) -> Option<ElementType> {
+        None
+    }
+    fn penalty(&self, _parameter: &T) -> ElementType {
+        0.0
+    }
 }
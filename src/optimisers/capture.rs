@@ -0,0 +1,66 @@
+//! This module contains an optimiser which replaces each parameter with its gradient
+//! rather than updating it. This allows the gradients calculated by an operation to be read
+//! back out in the same order as its parameters, such as when running layers within a dynamic network.
+
+use crate::optimisers;
+use crate::private::Sealed;
+
+/// This is an optimiser factory producing optimisers which replace each
+/// parameter with its gradient during the optimisation step.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct OptimiserFactory(());
+
+impl OptimiserFactory {
+    /// Constructs a new instance of the gradient capturing optimiser.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(())
+    }
+}
+
+impl Sealed for OptimiserFactory {}
+impl<T: Clone> optimisers::base::OptimiserFactory<T> for OptimiserFactory {
+    type Optimiser = Optimiser;
+    fn instantiate(&self) -> Self::Optimiser {
+        Optimiser(())
+    }
+}
+
+/// This struct is the concrete optimiser that is produced by the
+/// gradient capturing `OptimiserFactory`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Optimiser(());
+
+impl Sealed for Optimiser {}
+impl<T: Clone> optimisers::base::Optimiser<T> for Optimiser {
+    fn optimise(&mut self, parameter: &mut T, gradient: &T) {
+        parameter.clone_from(gradient);
+    }
+
+    fn init(&mut self, _epochs: u32) {}
+
+    fn end_epoch(&mut self) {}
+
+    fn end_batch(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optimisers::base::Optimiser as BaseOptimiser;
+    use crate::tensors::{rank, Tensor};
+
+    #[test]
+    fn test_optimise() {
+        // Arrange
+        let mut optimiser = Optimiser(());
+        let mut parameter = Tensor::<rank::Two>::new((1, 3), [1.0, 2.0, 3.0]).unwrap();
+        let gradient = Tensor::<rank::Two>::new((1, 3), [0.5, -1.0, 0.0]).unwrap();
+
+        // Act
+        optimiser.optimise(&mut parameter, &gradient);
+
+        // Assert
+        assert_eq!(parameter, gradient);
+    }
+}
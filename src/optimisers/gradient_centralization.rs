@@ -0,0 +1,192 @@
+use crate::optimisers::base;
+use crate::private::Sealed;
+use crate::tensors::{rank, Tensor};
+use crate::ElementType;
+use ndarray::Axis;
+
+/// Centralises the given gradient by subtracting the mean of each column from
+/// the elements of that column. Gradients with a single row (such as for biases)
+/// are returned unchanged, as centralising them would zero them out.
+fn centralise(gradient: &Tensor<rank::Two>) -> Tensor<rank::Two> {
+    match gradient.0.mean_axis(Axis(0)) {
+        Some(mean) if gradient.0.nrows() > 1 => Tensor(&gradient.0 - &mean),
+        _ => gradient.clone(),
+    }
+}
+
+/// This is an optimiser which wraps another optimiser and performs gradient
+/// centralization before passing the gradient on. The mean of each column of a weight
+/// gradient is subtracted from that column, which constrains the update and has been
+/// shown to improve training. Only applies to rank 2 gradients with more than one row.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OptimiserFactory<T> {
+    inner: T,
+}
+
+impl<T> OptimiserFactory<T> {
+    /// Constructs a new gradient centralization optimiser wrapping the given inner optimiser.
+    #[must_use]
+    pub const fn new(inner: T) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T> Sealed for OptimiserFactory<T> {}
+impl<T: base::OptimiserFactory<Tensor<rank::Two>>> base::OptimiserFactory<Tensor<rank::Two>>
+    for OptimiserFactory<T>
+{
+    type Optimiser = Optimiser<T::Optimiser>;
+    fn instantiate(&self) -> Self::Optimiser {
+        Self::Optimiser {
+            inner: self.inner.instantiate(),
+        }
+    }
+}
+
+impl<T: base::OptimiserFactory<()>> base::OptimiserFactory<()> for OptimiserFactory<T> {
+    type Optimiser = T::Optimiser;
+    fn instantiate(&self) -> Self::Optimiser {
+        self.inner.instantiate()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Optimiser<T> {
+    inner: T,
+}
+
+impl<T> Sealed for Optimiser<T> {}
+impl<T: base::Optimiser<Tensor<rank::Two>>> base::Optimiser<Tensor<rank::Two>> for Optimiser<T> {
+    fn optimise(&mut self, parameter: &mut Tensor<rank::Two>, gradient: &Tensor<rank::Two>) {
+        self.optimise_with_norm(parameter, gradient, gradient.l2_norm());
+    }
+
+    fn optimise_with_norm(
+        &mut self,
+        parameter: &mut Tensor<rank::Two>,
+        gradient: &Tensor<rank::Two>,
+        global_norm: ElementType,
+    ) {
+        self.inner
+            .optimise_with_norm(parameter, &centralise(gradient), global_norm);
+    }
+
+    fn init(&mut self, epochs: u32) {
+        self.inner.init(epochs);
+    }
+
+    fn end_epoch(&mut self) {
+        self.inner.end_epoch();
+    }
+
+    fn end_batch(&mut self) {
+        self.inner.end_batch();
+    }
+
+    fn observe_loss(&mut self, loss: ElementType) {
+        self.inner.observe_loss(loss);
+    }
+
+    fn learning_rate(&self) -> Option<ElementType> {
+        self.inner.learning_rate()
+    }
+
+    fn penalty(&self, parameter: &Tensor<rank::Two>) -> ElementType {
+        self.inner.penalty(parameter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::Linear;
+    use crate::layers::{Chain, Dense, Input};
+    use crate::operations::TrainableOperation;
+    use crate::operations::{UninitialisedOperation, WithOptimiser};
+    use crate::optimisers::base::Optimiser as BaseOptimiser;
+    use crate::optimisers::base::OptimiserFactory as BaseOptimiserFactory;
+    use crate::optimisers::learning_rate_handlers::FixedLearningRateHandler;
+    use crate::optimisers::{GradientCentralization, NullOptimiser, SGD};
+
+    #[test]
+    fn test_centralise() {
+        // Arrange
+        let gradient = Tensor::<rank::Two>::new((3, 2), [1.0, 4.0, 2.0, 8.0, 6.0, 0.0]).unwrap();
+        let expected = Tensor::<rank::Two>::new((3, 2), [-2.0, 0.0, -1.0, 4.0, 3.0, -4.0]).unwrap();
+
+        // Act
+        let output = centralise(&gradient);
+
+        // Assert
+        assert_eq!(output, expected);
+        assert!(output
+            .0
+            .mean_axis(Axis(0))
+            .unwrap()
+            .iter()
+            .all(|mean| *mean == 0.0));
+    }
+
+    #[test]
+    fn test_centralise_single_row() {
+        // Arrange
+        let gradient = Tensor::<rank::Two>::new((1, 2), [1.0, 4.0]).unwrap();
+
+        // Act
+        let output = centralise(&gradient);
+
+        // Assert
+        assert_eq!(output, gradient);
+    }
+
+    #[test]
+    fn test_optimise() {
+        // Arrange
+        let factory = GradientCentralization::new(SGD::new(FixedLearningRateHandler::new(1.0)));
+        let mut optimiser = BaseOptimiserFactory::<Tensor<rank::Two>>::instantiate(&factory);
+        let mut parameter = Tensor::<rank::Two>::new((2, 2), [0.0; 4]).unwrap();
+        let gradient = Tensor::<rank::Two>::new((2, 2), [1.0, 4.0, 3.0, 8.0]).unwrap();
+        let expected = Tensor::<rank::Two>::new((2, 2), [1.0, 2.0, -1.0, -2.0]).unwrap();
+
+        // Act
+        optimiser.optimise(&mut parameter, &gradient);
+
+        // Assert
+        assert_eq!(parameter, expected);
+    }
+
+    #[test]
+    fn test_optimise_null_optimiser() {
+        // Arrange
+        let factory = GradientCentralization::new(NullOptimiser::new());
+        let mut optimiser = BaseOptimiserFactory::<Tensor<rank::Two>>::instantiate(&factory);
+        let mut parameter = Tensor::<rank::Two>::new((2, 2), [1.0, 2.0, 3.0, 4.0]).unwrap();
+        let gradient = Tensor::<rank::Two>::new((2, 2), [1.0, 4.0, 3.0, 8.0]).unwrap();
+        let expected = parameter.clone();
+
+        // Act
+        optimiser.optimise(&mut parameter, &gradient);
+
+        // Assert
+        assert_eq!(parameter, expected);
+    }
+
+    #[test]
+    fn test_with_optimiser() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42);
+        let expected = network.clone();
+
+        // Act
+        let output = network
+            .with_optimiser(GradientCentralization::new(NullOptimiser::new()))
+            .into_initialised();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+}
@@ -0,0 +1,233 @@
+use crate::optimisers::base;
+use crate::private::Sealed;
+use crate::tensors::rank::Rank;
+use crate::tensors::Tensor;
+use crate::ElementType;
+
+/// The strategy used to bound gradients by a gradient clipping optimiser.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Clip {
+    Value(ElementType),
+    GlobalNorm(ElementType),
+}
+
+/// This is an optimiser which wraps another optimiser and clips gradients before passing them on,
+/// so that exploding gradients in deep or recurrent networks are bounded before the update.
+///
+/// Clipping by value limits each element of a gradient to lie within plus or minus the maximum.
+/// Clipping by global norm calculates the norm of the gradients of every parameter in the network
+/// together, and if it exceeds the maximum, scales all the gradients down by the same amount so
+/// that the global norm equals the maximum. This keeps the direction of the overall update unchanged.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OptimiserFactory<T> {
+    inner: T,
+    clip: Clip,
+}
+
+impl<T> OptimiserFactory<T> {
+    /// Constructs a new gradient clipping optimiser wrapping the given inner optimiser, which
+    /// clips each element of a gradient to lie between the negative and positive of the maximum.
+    #[must_use]
+    pub const fn by_value(inner: T, max_value: ElementType) -> Self {
+        Self {
+            inner,
+            clip: Clip::Value(max_value),
+        }
+    }
+
+    /// Constructs a new gradient clipping optimiser wrapping the given inner optimiser, which
+    /// scales down the gradients when the norm of the gradients of all parameters together
+    /// exceeds the maximum.
+    #[must_use]
+    pub const fn by_global_norm(inner: T, max_norm: ElementType) -> Self {
+        Self {
+            inner,
+            clip: Clip::GlobalNorm(max_norm),
+        }
+    }
+}
+
+impl<T> Sealed for OptimiserFactory<T> {}
+impl<T: base::OptimiserFactory<Tensor<R>>, R: Rank> base::OptimiserFactory<Tensor<R>>
+    for OptimiserFactory<T>
+{
+    type Optimiser = Optimiser<T::Optimiser>;
+    fn instantiate(&self) -> Self::Optimiser {
+        Self::Optimiser {
+            inner: self.inner.instantiate(),
+            clip: self.clip,
+        }
+    }
+}
+
+impl<T: base::OptimiserFactory<()>> base::OptimiserFactory<()> for OptimiserFactory<T> {
+    type Optimiser = T::Optimiser;
+    fn instantiate(&self) -> Self::Optimiser {
+        self.inner.instantiate()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Optimiser<T> {
+    inner: T,
+    clip: Clip,
+}
+
+impl<T> Sealed for Optimiser<T> {}
+impl<T: base::Optimiser<Tensor<R>>, R: Rank> base::Optimiser<Tensor<R>> for Optimiser<T> {
+    fn optimise(&mut self, parameter: &mut Tensor<R>, gradient: &Tensor<R>) {
+        self.optimise_with_norm(parameter, gradient, gradient.l2_norm());
+    }
+
+    fn optimise_with_norm(
+        &mut self,
+        parameter: &mut Tensor<R>,
+        gradient: &Tensor<R>,
+        global_norm: ElementType,
+    ) {
+        match self.clip {
+            Clip::Value(max_value) => {
+                let gradient = Tensor(gradient.0.mapv(|elem| elem.clamp(-max_value, max_value)));
+                self.inner
+                    .optimise_with_norm(parameter, &gradient, global_norm);
+            }
+            Clip::GlobalNorm(max_norm) if global_norm > max_norm => {
+                let gradient = Tensor(&gradient.0 * (max_norm / global_norm));
+                self.inner
+                    .optimise_with_norm(parameter, &gradient, max_norm);
+            }
+            Clip::GlobalNorm(_) => {
+                self.inner
+                    .optimise_with_norm(parameter, gradient, global_norm);
+            }
+        }
+    }
+
+    fn init(&mut self, epochs: u32) {
+        self.inner.init(epochs);
+    }
+
+    fn end_epoch(&mut self) {
+        self.inner.end_epoch();
+    }
+
+    fn end_batch(&mut self) {
+        self.inner.end_batch();
+    }
+
+    fn observe_loss(&mut self, loss: ElementType) {
+        self.inner.observe_loss(loss);
+    }
+
+    fn learning_rate(&self) -> Option<ElementType> {
+        self.inner.learning_rate()
+    }
+
+    fn penalty(&self, parameter: &Tensor<R>) -> ElementType {
+        self.inner.penalty(parameter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::Linear;
+    use crate::layers::{Chain, Dense, Input};
+    use crate::operations::{
+        BackwardOperation, Forward, ForwardOperation, InitialisedOperation, TrainableOperation,
+        UninitialisedOperation, WithOptimiser,
+    };
+    use crate::optimisers::base::Optimiser as BaseOptimiser;
+    use crate::optimisers::base::OptimiserFactory as BaseOptimiserFactory;
+    use crate::optimisers::learning_rate_handlers::FixedLearningRateHandler;
+    use crate::optimisers::{GradientClipping, SGD};
+    use crate::tensors::rank;
+
+    #[test]
+    fn test_optimise_by_value() {
+        // Arrange
+        let sgd = SGD::new(FixedLearningRateHandler::new(1.0));
+        let factory = GradientClipping::by_value(sgd, 1.0);
+        let mut optimiser = BaseOptimiserFactory::<Tensor<rank::Two>>::instantiate(&factory);
+        let mut parameter = Tensor::<rank::Two>::new((1, 3), [0.0; 3]).unwrap();
+        let gradient = Tensor::<rank::Two>::new((1, 3), [5.0, -0.5, -3.0]).unwrap();
+        let expected = Tensor::<rank::Two>::new((1, 3), [-1.0, 0.5, 1.0]).unwrap();
+
+        // Act
+        optimiser.optimise(&mut parameter, &gradient);
+
+        // Assert
+        assert_eq!(parameter, expected);
+    }
+
+    #[test]
+    fn test_optimise_by_norm_clipped() {
+        // Arrange
+        let sgd = SGD::new(FixedLearningRateHandler::new(1.0));
+        let factory = GradientClipping::by_global_norm(sgd, 1.0);
+        let mut optimiser = BaseOptimiserFactory::<Tensor<rank::Two>>::instantiate(&factory);
+        let mut parameter = Tensor::<rank::Two>::new((1, 2), [0.0; 2]).unwrap();
+        let gradient = Tensor::<rank::Two>::new((1, 2), [3.0, 4.0]).unwrap();
+        let expected = Tensor::<rank::Two>::new((1, 2), [-0.6, -0.8]).unwrap();
+
+        // Act
+        optimiser.optimise(&mut parameter, &gradient);
+
+        // Assert
+        parameter
+            .into_iter()
+            .zip(expected)
+            .for_each(|(output, expected)| assert!((output - expected).abs() < 1e-6));
+    }
+
+    #[test]
+    fn test_optimise_by_norm_unclipped() {
+        // Arrange
+        let sgd = SGD::new(FixedLearningRateHandler::new(1.0));
+        let factory = GradientClipping::by_global_norm(sgd, 10.0);
+        let mut optimiser = BaseOptimiserFactory::<Tensor<rank::Two>>::instantiate(&factory);
+        let mut parameter = Tensor::<rank::Two>::new((1, 2), [0.0; 2]).unwrap();
+        let gradient = Tensor::<rank::Two>::new((1, 2), [3.0, 4.0]).unwrap();
+        let expected = Tensor::<rank::Two>::new((1, 2), [-3.0, -4.0]).unwrap();
+
+        // Act
+        optimiser.optimise(&mut parameter, &gradient);
+
+        // Assert
+        assert_eq!(parameter, expected);
+    }
+
+    #[test]
+    fn test_optimise_network_by_global_norm() {
+        // Arrange
+        // with a single input of 1 and a single target of 0, the gradients of the weight and
+        // bias of the dense layer are equal, so the global norm is larger than either of them.
+        let mut network = Input::new(1)
+            .chain(Dense::new(1, Linear::new()))
+            .with_iter([3.0, 4.0].into_iter())
+            .unwrap()
+            .with_optimiser(GradientClipping::by_global_norm(
+                SGD::new(FixedLearningRateHandler::new(1.0)),
+                1.0,
+            ));
+        let input = Tensor::<rank::Two>::new((1, 1), [1.0]).unwrap();
+        let output_gradient = Tensor::<rank::Two>::new((1, 1), [7.0]).unwrap();
+        let clipped = 7.0 * (1.0 / (98.0 as ElementType).sqrt());
+        let expected = [3.0 - clipped, 4.0 - clipped];
+
+        // Act
+        let (forward, _) = network.forward(input).unwrap();
+        let (backward, _) = forward.backward(output_gradient).unwrap();
+        backward.optimise();
+
+        // Assert
+        network
+            .into_initialised()
+            .iter()
+            .zip(expected)
+            .for_each(|(output, expected)| assert!((output - expected).abs() < 1e-6));
+    }
+}
@@ -0,0 +1,293 @@
+//! This module contains the limited-memory BFGS optimiser, which minimises a function of all of the
+//! parameters of a network at once rather than updating each parameter from its own gradient.
+
+use crate::{ElementType, Error, Result};
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+/// The fraction of the decrease predicted by the gradient that a step must achieve to be accepted.
+const SUFFICIENT_DECREASE: ElementType = 1e-4;
+
+/// This trait is implemented by functions that an optimiser can evaluate on demand, giving the loss and the
+/// gradient of the loss for any values of the parameters. Unlike the optimisers which are bound to each layer
+/// of a network and given a single gradient per batch, an optimiser working with an objective can evaluate the
+/// loss several times per step, such as to search along a direction for a step which decreases the loss.
+///
+/// Closures which take the parameters and return the loss and the gradients (in the same order as the
+/// parameters) implement this trait.
+pub trait Objective {
+    /// Evaluates the loss and the gradient of the loss with respect to each of the given parameters.
+    ///
+    /// # Errors
+    /// Returns an `eidetic::Error` if the objective couldn't be evaluated, which stops the optimisation.
+    fn evaluate(&mut self, parameters: &[ElementType]) -> Result<(ElementType, Vec<ElementType>)>;
+}
+
+impl<F> Objective for F
+where
+    F: FnMut(&[ElementType]) -> Result<(ElementType, Vec<ElementType>)>,
+{
+    fn evaluate(&mut self, parameters: &[ElementType]) -> Result<(ElementType, Vec<ElementType>)> {
+        self(parameters)
+    }
+}
+
+/// This is an implementation of the limited-memory BFGS (L-BFGS) optimisation strategy, which approximates
+/// the curvature of the loss from the last few steps to choose both the direction and the size of each step.
+/// It's a deterministic method which suits full-batch training of small networks, such as for regression
+/// and control problems, where it usually converges in far fewer steps than gradient descent.
+///
+/// Each iteration searches back along its direction for a step which decreases the loss by enough, and the
+/// optimisation stops early once the norm of the gradient falls below the tolerance, or if no such step can be found.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LBFGS {
+    history: usize,
+    tolerance: ElementType,
+    max_line_search: u16,
+}
+
+impl LBFGS {
+    /// Constructs a new instance of the L-BFGS optimiser which remembers the given number of the
+    /// most recent steps to approximate the curvature of the loss (usually between 3 and 20).
+    #[must_use]
+    pub const fn new(history: usize) -> Self {
+        Self {
+            history,
+            tolerance: 1e-5,
+            max_line_search: 20,
+        }
+    }
+
+    /// Sets the norm of the gradient below which the loss is considered to be minimised, which is 1e-5 by default.
+    #[must_use]
+    pub const fn with_tolerance(self, tolerance: ElementType) -> Self {
+        Self { tolerance, ..self }
+    }
+
+    /// Sets the maximum number of times that the loss is evaluated while searching for the size of
+    /// each step, which is 20 by default. Each evaluation halves the size of the step.
+    #[must_use]
+    pub const fn with_max_line_search(self, max_line_search: u16) -> Self {
+        Self {
+            max_line_search,
+            ..self
+        }
+    }
+
+    /// Minimises the objective by updating the given parameters in place for up to the given number of iterations,
+    /// and returns the loss at the final parameters.
+    ///
+    /// # Errors
+    /// Returns an `eidetic::Error` if the objective returns one, `Error::ShapeMismatch` if it returns a different number
+    /// of gradients to parameters, or `Error::InvalidArgument` if the loss at the initial parameters isn't finite.
+    pub fn minimise(
+        &self,
+        parameters: &mut [ElementType],
+        objective: &mut impl Objective,
+        iterations: u32,
+    ) -> Result<ElementType> {
+        let (mut loss, mut gradient) = objective.evaluate(parameters)?;
+        Error::check_size(parameters.len(), gradient.len())?;
+        if !loss.is_finite() {
+            return Err(Error::InvalidArgument);
+        }
+        let mut steps = VecDeque::with_capacity(self.history);
+        for _ in 0..iterations {
+            let norm = dot(&gradient, &gradient).sqrt();
+            if norm <= self.tolerance {
+                break;
+            }
+
+            // fall back to the direction of steepest descent if the curvature approximation doesn't go downhill.
+            let mut direction = search_direction(&steps, &gradient);
+            let mut slope = dot(&gradient, &direction);
+            if slope >= 0.0 || !slope.is_finite() {
+                steps.clear();
+                direction = gradient.iter().map(|elem| -elem).collect();
+                slope = -norm * norm;
+            }
+
+            // without any history the scale of the direction is unknown, so the first step is kept small.
+            let mut step_size = if steps.is_empty() {
+                (1.0 / norm).min(1.0)
+            } else {
+                1.0
+            };
+            let mut accepted = None;
+            for _ in 0..self.max_line_search {
+                let candidate = parameters
+                    .iter()
+                    .zip(&direction)
+                    .map(|(parameter, direction)| step_size.mul_add(*direction, *parameter))
+                    .collect::<Vec<_>>();
+                let (candidate_loss, candidate_gradient) = objective.evaluate(&candidate)?;
+                Error::check_size(parameters.len(), candidate_gradient.len())?;
+                if candidate_loss.is_finite()
+                    && candidate_loss <= (SUFFICIENT_DECREASE * step_size).mul_add(slope, loss)
+                {
+                    accepted = Some((candidate, candidate_loss, candidate_gradient));
+                    break;
+                }
+                step_size *= 0.5;
+            }
+            let Some((candidate, candidate_loss, candidate_gradient)) = accepted else {
+                break;
+            };
+
+            // only remember steps with positive curvature, which keeps the approximation positive definite.
+            let step = direction
+                .iter()
+                .map(|elem| elem * step_size)
+                .collect::<Vec<_>>();
+            let change = candidate_gradient
+                .iter()
+                .zip(&gradient)
+                .map(|(new, old)| new - old)
+                .collect::<Vec<_>>();
+            let curvature = dot(&step, &change);
+            if curvature > ElementType::EPSILON * dot(&change, &change) {
+                if steps.len() == self.history {
+                    steps.pop_front();
+                }
+                if self.history > 0 {
+                    steps.push_back((step, change, 1.0 / curvature));
+                }
+            }
+            parameters.copy_from_slice(&candidate);
+            loss = candidate_loss;
+            gradient = candidate_gradient;
+        }
+        Ok(loss)
+    }
+}
+
+/// Calculates the direction of the next step from the gradient and the remembered steps (along with the change
+/// in the gradient over each step, and the reciprocal of their dot product) using the L-BFGS two-loop recursion.
+fn search_direction(
+    steps: &VecDeque<(Vec<ElementType>, Vec<ElementType>, ElementType)>,
+    gradient: &[ElementType],
+) -> Vec<ElementType> {
+    let mut direction = gradient.to_vec();
+    let mut alphas = Vec::with_capacity(steps.len());
+    for (step, change, rho) in steps.iter().rev() {
+        let alpha = rho * dot(step, &direction);
+        scaled_add(&mut direction, -alpha, change);
+        alphas.push(alpha);
+    }
+    let scale = steps.back().map_or(1.0, |(step, change, _)| {
+        dot(step, change) / dot(change, change)
+    });
+    for elem in &mut direction {
+        *elem *= scale;
+    }
+    for ((step, change, rho), alpha) in steps.iter().zip(alphas.into_iter().rev()) {
+        let beta = rho * dot(change, &direction);
+        scaled_add(&mut direction, alpha - beta, step);
+    }
+    for elem in &mut direction {
+        *elem = -*elem;
+    }
+    direction
+}
+
+/// Calculates the dot product of two vectors.
+fn dot(lhs: &[ElementType], rhs: &[ElementType]) -> ElementType {
+    lhs.iter()
+        .zip(rhs)
+        .fold(0.0, |sum, (lhs, rhs)| lhs.mul_add(*rhs, sum))
+}
+
+/// Adds the given multiple of the second vector to the first.
+fn scaled_add(lhs: &mut [ElementType], scale: ElementType, rhs: &[ElementType]) {
+    for (lhs, rhs) in lhs.iter_mut().zip(rhs) {
+        *lhs = scale.mul_add(*rhs, *lhs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rosenbrock(parameters: &[ElementType]) -> (ElementType, Vec<ElementType>) {
+        let (x, y) = (parameters[0], parameters[1]);
+        let residual = x.mul_add(-x, y);
+        let loss = (100.0 * residual).mul_add(residual, (1.0 - x).powi(2));
+        let gradient = [
+            (-400.0 * x).mul_add(residual, -2.0 * (1.0 - x)),
+            200.0 * residual,
+        ];
+        (loss, gradient.to_vec())
+    }
+
+    #[test]
+    fn test_minimise_quadratic() {
+        // Arrange
+        let mut parameters = [0.0, 0.0, 0.0];
+        let mut objective = |parameters: &[ElementType]| {
+            let targets = [1.0, -2.0, 3.0];
+            let scales = [1.0, 10.0, 0.5];
+            let loss = parameters
+                .iter()
+                .zip(targets.iter().zip(&scales))
+                .map(|(parameter, (target, scale))| scale * (parameter - target).powi(2))
+                .sum();
+            let gradient = parameters
+                .iter()
+                .zip(targets.iter().zip(&scales))
+                .map(|(parameter, (target, scale))| 2.0 * scale * (parameter - target))
+                .collect();
+            Ok((loss, gradient))
+        };
+
+        // Act
+        let loss = LBFGS::new(5)
+            .minimise(&mut parameters, &mut objective, 20)
+            .unwrap();
+
+        // Assert
+        assert!(loss < 1e-6);
+        for (parameter, target) in parameters.iter().zip([1.0, -2.0, 3.0]) {
+            assert!((parameter - target).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_minimise_rosenbrock() {
+        // Arrange
+        let mut parameters = [-1.2, 1.0];
+
+        // Act
+        let loss = LBFGS::new(10)
+            .minimise(
+                &mut parameters,
+                &mut |parameters: &[ElementType]| Ok(rosenbrock(parameters)),
+                200,
+            )
+            .unwrap();
+
+        // Assert
+        assert!(loss < 1e-4);
+        assert!((parameters[0] - 1.0).abs() < 1e-2);
+        assert!((parameters[1] - 1.0).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_minimise_gradient_size_mismatch() {
+        // Arrange
+        let mut parameters = [1.0, 2.0];
+        let mut objective = |_: &[ElementType]| Ok((1.0, [1.0].to_vec()));
+
+        // Act
+        let result = LBFGS::new(5).minimise(&mut parameters, &mut objective, 10);
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(Error::ShapeMismatch {
+                expected: 2,
+                actual: 1
+            })
+        );
+    }
+}
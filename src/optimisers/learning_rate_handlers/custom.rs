@@ -0,0 +1,86 @@
+use crate::private::Sealed;
+use crate::ElementType;
+
+/// This is a provider for a learning rate which is calculated by a user supplied
+/// schedule. The schedule is called with the current (zero-based) epoch and the total
+/// number of epochs, and returns the learning rate to use for that epoch.
+#[derive(Clone, Debug)]
+pub struct LearningRateHandler<F> {
+    schedule: F,
+    epoch: u32,
+    total_epochs: u32,
+}
+
+impl<F: Fn(u32, u32) -> ElementType> LearningRateHandler<F> {
+    /// Constructs a new instance of the `LearningRateHandler` which will evaluate
+    /// the given schedule to determine the learning rate each epoch.
+    #[must_use]
+    pub const fn new(schedule: F) -> Self {
+        Self {
+            schedule,
+            epoch: 0,
+            total_epochs: 0,
+        }
+    }
+}
+
+impl<F> Sealed for LearningRateHandler<F> {}
+impl<F: Fn(u32, u32) -> ElementType> super::LearningRateHandler for LearningRateHandler<F> {
+    fn learning_rate(&self) -> ElementType {
+        (self.schedule)(self.epoch, self.total_epochs)
+    }
+
+    fn init(&mut self, epochs: u32) {
+        self.epoch = 0;
+        self.total_epochs = epochs;
+    }
+
+    fn end_epoch(&mut self) {
+        self.epoch += 1;
+    }
+
+    fn end_batch(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optimisers::learning_rate_handlers::LearningRateHandler as BaseLearningRateHandler;
+
+    fn square_root_decay(epoch: u32, _total_epochs: u32) -> ElementType {
+        0.1 / ((epoch + 1) as ElementType).sqrt()
+    }
+
+    #[test]
+    fn test_init() {
+        // Arrange
+        let mut handler =
+            LearningRateHandler::new(|epoch, total_epochs| (total_epochs - epoch) as ElementType);
+
+        // Act
+        handler.init(10);
+
+        // Assert
+        assert_eq!(handler.learning_rate(), 10.0);
+    }
+
+    #[test]
+    fn test_rates_match_schedule() {
+        // Arrange
+        let mut handler = LearningRateHandler::new(square_root_decay);
+        let mut rates = [0.0; 5];
+
+        // Act
+        handler.init(5);
+        for rate in &mut rates {
+            *rate = handler.learning_rate();
+            handler.end_epoch();
+        }
+
+        // Assert
+        (0..5)
+            .zip(rates)
+            .for_each(|(epoch, rate)| assert_eq!(rate, square_root_decay(epoch, 5)));
+        assert!(rates.windows(2).all(|rates| rates[1] < rates[0]));
+    }
+}
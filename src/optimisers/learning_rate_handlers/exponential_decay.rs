@@ -5,6 +5,7 @@ use crate::ElementType;
 /// which will decay per epoch from the given starting rate to the given
 /// ending rate.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LearningRateHandler {
     starting_rate: ElementType,
     ending_rate: ElementType,
@@ -32,14 +33,16 @@ impl super::LearningRateHandler for LearningRateHandler {
         self.current_rate
     }
 
-    fn init(&mut self, epochs: u16) {
-        self.decay_per_epoch =
-            (self.ending_rate / self.starting_rate).powf(1.0 / (ElementType::from(epochs) - 1.0));
+    fn init(&mut self, epochs: u32) {
+        self.decay_per_epoch = (self.ending_rate / self.starting_rate)
+            .powf(1.0 / (super::epochs_to_element(epochs) - 1.0));
     }
 
     fn end_epoch(&mut self) {
         self.current_rate *= self.decay_per_epoch;
     }
+
+    fn end_batch(&mut self) {}
 }
 
 #[cfg(test)]
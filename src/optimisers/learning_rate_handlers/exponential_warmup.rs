@@ -0,0 +1,113 @@
+use crate::private::Sealed;
+use crate::ElementType;
+
+/// A structure representing an exponential warmup of the learning rate which
+/// wraps another learning rate handler. Over the given number of batches the rate
+/// grows geometrically from the given fraction of the wrapped handler's rate up
+/// to the full rate, after which the wrapped handler's rate is reported unchanged.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LearningRateHandler<T> {
+    inner: T,
+    initial_fraction: ElementType,
+    warmup_batches: u16,
+    batch: u16,
+}
+
+impl<T> LearningRateHandler<T> {
+    /// Constructs a new instance of an exponential warmup around the given learning rate handler.
+    /// Takes the fraction of the wrapped rate to start from and the number of batches to warm up over.
+    #[must_use]
+    pub const fn new(inner: T, initial_fraction: ElementType, warmup_batches: u16) -> Self {
+        Self {
+            inner,
+            initial_fraction,
+            warmup_batches,
+            batch: 0,
+        }
+    }
+}
+
+impl<T> Sealed for LearningRateHandler<T> {}
+impl<T: super::LearningRateHandler> super::LearningRateHandler for LearningRateHandler<T> {
+    fn learning_rate(&self) -> ElementType {
+        let learning_rate = self.inner.learning_rate();
+        if self.batch < self.warmup_batches {
+            let progress = ElementType::from(self.batch) / ElementType::from(self.warmup_batches);
+            learning_rate * self.initial_fraction.powf(1.0 - progress)
+        } else {
+            learning_rate
+        }
+    }
+
+    fn init(&mut self, epochs: u32) {
+        self.batch = 0;
+        self.inner.init(epochs);
+    }
+
+    fn end_epoch(&mut self) {
+        self.inner.end_epoch();
+    }
+
+    fn end_batch(&mut self) {
+        self.batch = self.batch.saturating_add(1);
+        self.inner.end_batch();
+    }
+
+    fn observe_loss(&mut self, loss: ElementType) {
+        self.inner.observe_loss(loss);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optimisers::learning_rate_handlers::FixedLearningRateHandler;
+    use crate::optimisers::learning_rate_handlers::LearningRateHandler as BaseLearningRateHandler;
+
+    #[test]
+    fn test_initial_rate_is_fraction_of_inner_rate() {
+        // Arrange
+        let mut handler = LearningRateHandler::new(FixedLearningRateHandler::new(0.5), 0.01, 4);
+
+        // Act
+        handler.init(1);
+
+        // Assert
+        assert_eq!(handler.learning_rate(), 0.005);
+    }
+
+    #[test]
+    fn test_rate_increases_across_batches() {
+        // Arrange
+        let mut handler = LearningRateHandler::new(FixedLearningRateHandler::new(1.0), 0.01, 4);
+        let mut rates = [0.0; 6];
+
+        // Act
+        handler.init(1);
+        for rate in &mut rates {
+            *rate = handler.learning_rate();
+            handler.end_batch();
+        }
+
+        // Assert
+        assert!(rates[..5].windows(2).all(|rates| rates[0] < rates[1]));
+        assert!((rates[2] - 0.1).abs() < 1e-6);
+        assert_eq!(rates[4], 1.0);
+        assert_eq!(rates[5], 1.0);
+    }
+
+    #[test]
+    fn test_init_restarts_warmup() {
+        // Arrange
+        let mut handler = LearningRateHandler::new(FixedLearningRateHandler::new(1.0), 0.01, 4);
+        handler.init(1);
+        (0..4).for_each(|_| handler.end_batch());
+
+        // Act
+        handler.init(1);
+
+        // Assert
+        assert_eq!(handler.learning_rate(), 0.01);
+    }
+}
@@ -4,6 +4,7 @@ use crate::ElementType;
 /// This is a provider for a constant learning rate that doesn't change or degrade
 /// based on epoch. It's the most basic type of learning rate handling.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LearningRateHandler {
     learning_rate: ElementType,
 }
@@ -23,9 +24,11 @@ impl super::LearningRateHandler for LearningRateHandler {
         self.learning_rate
     }
 
-    fn init(&mut self, _epochs: u16) {}
+    fn init(&mut self, _epochs: u32) {}
 
     fn end_epoch(&mut self) {}
+
+    fn end_batch(&mut self) {}
 }
 
 #[cfg(test)]
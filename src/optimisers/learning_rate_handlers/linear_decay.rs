@@ -5,6 +5,7 @@ use crate::ElementType;
 /// which will decay per epoch from the given starting rate to the given
 /// ending rate.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LearningRateHandler {
     starting_rate: ElementType,
     ending_rate: ElementType,
@@ -32,14 +33,16 @@ impl super::LearningRateHandler for LearningRateHandler {
         self.current_rate
     }
 
-    fn init(&mut self, epochs: u16) {
+    fn init(&mut self, epochs: u32) {
         self.decay_per_epoch =
-            (self.starting_rate - self.ending_rate) / (ElementType::from(epochs) - 1.0);
+            (self.starting_rate - self.ending_rate) / (super::epochs_to_element(epochs) - 1.0);
     }
 
     fn end_epoch(&mut self) {
         self.current_rate -= self.decay_per_epoch;
     }
+
+    fn end_batch(&mut self) {}
 }
 
 #[cfg(test)]
@@ -98,4 +101,17 @@ mod tests {
         // Assert
         assert_eq!(handler.learning_rate(), expected);
     }
+
+    #[test]
+    fn test_learning_rate_is_correct_after_more_than_u16_epochs() {
+        // Arrange
+        let mut handler = LearningRateHandler::new(0.1, 0.05);
+
+        // Act
+        handler.init(100_001);
+        handler.end_epoch();
+
+        // Assert
+        assert!((handler.learning_rate() - 0.0999995).abs() < 1e-7);
+    }
 }
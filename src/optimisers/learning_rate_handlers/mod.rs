@@ -1,21 +1,31 @@
 //! This module will contain all the handlers for tracking and updating the
 //! learning rate for use with optimisers such as SGD.
 
+mod custom;
 mod exponential_decay;
+mod exponential_warmup;
 mod fixed;
 mod linear_decay;
+mod multi_step_decay;
+mod reduce_on_plateau;
+mod step_decay;
 
 use crate::private::Sealed;
 use crate::ElementType;
 
+pub use custom::LearningRateHandler as CustomLearningRateHandler;
 pub use exponential_decay::LearningRateHandler as ExponentialDecayLearningRateHandler;
+pub use exponential_warmup::LearningRateHandler as ExponentialWarmupLearningRateHandler;
 pub use fixed::LearningRateHandler as FixedLearningRateHandler;
 pub use linear_decay::LearningRateHandler as LinearDecayLearningRateHandler;
+pub use multi_step_decay::LearningRateHandler as MultiStepDecayLearningRateHandler;
+pub use reduce_on_plateau::LearningRateHandler as ReduceOnPlateauLearningRateHandler;
+pub use step_decay::LearningRateHandler as StepDecayLearningRateHandler;
 
 /// This trait defines the functionality for a type to be used
 /// in optimisation to handle and provide the learning rate. Is able
 /// to be initialised at the beginning of training, report the current
-/// learning rate, and perform some logic at the end of an epoch.
+/// learning rate, and perform some logic at the end of an epoch or batch.
 /// Note that like all traits in the library, this trait is sealed so cannot be implemented by foreign types.
 pub trait LearningRateHandler: Sealed {
     /// Provides the current value of the learning rate to the
@@ -25,9 +35,25 @@ pub trait LearningRateHandler: Sealed {
     /// Called at the beginning of training with the number of epochs
     /// we will be running over. Can be used to determine the increments
     /// for learning rate update each epoch.
-    fn init(&mut self, epochs: u16);
+    fn init(&mut self, epochs: u32);
 
     /// Called at the end of every epoch and provides an opportunity to update
     /// the learning rate for next time.
     fn end_epoch(&mut self);
+
+    /// Called at the end of every batch within an epoch and provides an opportunity
+    /// to update the learning rate at a finer granularity than per epoch.
+    fn end_batch(&mut self);
+
+    /// Called with the loss against the testing data each time the network is evaluated
+    /// during training, and provides an opportunity to update the learning rate based on
+    /// how training is progressing. By default the loss is ignored.
+    fn observe_loss(&mut self, _loss: ElementType) {}
+}
+
+/// Converts a number of epochs into an element. This is exact for any realistic number of epochs,
+/// as a 32 bit float only starts losing precision beyond 2^24 epochs.
+#[allow(clippy::cast_lossless, clippy::cast_precision_loss)]
+pub(crate) const fn epochs_to_element(epochs: u32) -> ElementType {
+    epochs as ElementType
 }
@@ -0,0 +1,105 @@
+use crate::private::Sealed;
+use crate::ElementType;
+use alloc::vec::Vec;
+
+/// A structure representing a learning rate which is multiplied by the given factor
+/// once training reaches each of the given milestone epochs, starting from the given rate.
+/// The milestones are (zero based) epoch numbers, so a milestone of 10 means the
+/// reduced rate is used from the eleventh epoch onwards.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LearningRateHandler {
+    starting_rate: ElementType,
+    current_rate: ElementType,
+    factor: ElementType,
+    milestones: Vec<u32>,
+    epoch: u32,
+}
+
+impl LearningRateHandler {
+    /// Constructs a new instance of a multi-step decaying learning rate which starts at the given
+    /// rate and is multiplied by the given factor at each of the milestones, which can be given in any order.
+    #[must_use]
+    pub fn new(
+        starting_rate: ElementType,
+        factor: ElementType,
+        milestones: impl IntoIterator<Item = u32>,
+    ) -> Self {
+        let mut milestones = milestones.into_iter().collect::<Vec<_>>();
+        milestones.sort_unstable();
+        Self {
+            starting_rate,
+            current_rate: starting_rate,
+            factor,
+            milestones,
+            epoch: 0,
+        }
+    }
+}
+
+impl Sealed for LearningRateHandler {}
+impl super::LearningRateHandler for LearningRateHandler {
+    fn learning_rate(&self) -> ElementType {
+        self.current_rate
+    }
+
+    fn init(&mut self, _epochs: u32) {
+        self.current_rate = self.starting_rate;
+        self.epoch = 0;
+    }
+
+    fn end_epoch(&mut self) {
+        self.epoch = self.epoch.saturating_add(1);
+        // a milestone which is repeated reduces the learning rate once for each time it's given.
+        let reached = self
+            .milestones
+            .iter()
+            .filter(|&&milestone| milestone == self.epoch)
+            .count();
+        for _ in 0..reached {
+            self.current_rate *= self.factor;
+        }
+    }
+
+    fn end_batch(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optimisers::learning_rate_handlers::LearningRateHandler as BaseLearningRateHandler;
+
+    #[test]
+    fn test_learning_rate_drops_at_milestones() {
+        // Arrange
+        let mut handler = LearningRateHandler::new(0.8, 0.5, [5, 2]);
+        handler.init(10);
+
+        // Act
+        let rates = (0..7)
+            .map(|_| {
+                let rate = handler.learning_rate();
+                handler.end_epoch();
+                rate
+            })
+            .collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(rates, [0.8, 0.8, 0.4, 0.4, 0.4, 0.2, 0.2]);
+    }
+
+    #[test]
+    fn test_init_resets_learning_rate() {
+        // Arrange
+        let mut handler = LearningRateHandler::new(0.8, 0.5, [1, 1, 3]);
+        handler.init(10);
+        (0..4).for_each(|_| handler.end_epoch());
+
+        // Act
+        handler.init(10);
+        handler.end_epoch();
+
+        // Assert
+        assert_eq!(handler.learning_rate(), 0.2);
+    }
+}
@@ -0,0 +1,155 @@
+use crate::private::Sealed;
+use crate::ElementType;
+
+/// A structure representing a learning rate which is reduced whenever the loss against
+/// the testing data stops improving. Each time the network is evaluated during training, the
+/// evaluation loss is compared against the best loss seen so far. If it fails to improve for
+/// `patience` evaluations in a row then the learning rate is multiplied by `factor`, down to
+/// a given minimum rate.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LearningRateHandler {
+    starting_rate: ElementType,
+    current_rate: ElementType,
+    factor: ElementType,
+    patience: u16,
+    minimum_rate: ElementType,
+    best_loss: Option<ElementType>,
+    evaluations_without_improvement: u16,
+}
+
+impl LearningRateHandler {
+    /// Constructs a new instance of a learning rate which starts at the given rate and is
+    /// multiplied by the given factor whenever the evaluation loss hasn't improved for
+    /// `patience` evaluations. The learning rate is never reduced below zero unless
+    /// a minimum rate is set with `with_minimum_rate`.
+    #[must_use]
+    pub const fn new(starting_rate: ElementType, factor: ElementType, patience: u16) -> Self {
+        Self {
+            starting_rate,
+            current_rate: starting_rate,
+            factor,
+            patience,
+            minimum_rate: 0.0,
+            best_loss: None,
+            evaluations_without_improvement: 0,
+        }
+    }
+
+    /// Sets the lowest value that the learning rate will be reduced to.
+    #[must_use]
+    pub const fn with_minimum_rate(self, minimum_rate: ElementType) -> Self {
+        Self {
+            minimum_rate,
+            ..self
+        }
+    }
+}
+
+impl Sealed for LearningRateHandler {}
+impl super::LearningRateHandler for LearningRateHandler {
+    fn learning_rate(&self) -> ElementType {
+        self.current_rate
+    }
+
+    fn init(&mut self, _epochs: u32) {
+        self.current_rate = self.starting_rate;
+        self.best_loss = None;
+        self.evaluations_without_improvement = 0;
+    }
+
+    fn end_epoch(&mut self) {}
+
+    fn end_batch(&mut self) {}
+
+    fn observe_loss(&mut self, loss: ElementType) {
+        if self.best_loss.is_none_or(|best_loss| loss < best_loss) {
+            self.best_loss = Some(loss);
+            self.evaluations_without_improvement = 0;
+        } else {
+            self.evaluations_without_improvement += 1;
+            if self.evaluations_without_improvement >= self.patience {
+                self.current_rate = (self.current_rate * self.factor).max(self.minimum_rate);
+                self.evaluations_without_improvement = 0;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optimisers::learning_rate_handlers::LearningRateHandler as BaseLearningRateHandler;
+
+    #[test]
+    fn test_initial_rate_is_starting_rate() {
+        // Arrange
+        let handler = LearningRateHandler::new(0.1, 0.5, 2);
+
+        // Assert
+        assert_eq!(handler.learning_rate(), 0.1);
+    }
+
+    #[test]
+    fn test_learning_rate_is_kept_while_loss_improves() {
+        // Arrange
+        let mut handler = LearningRateHandler::new(0.1, 0.5, 1);
+
+        // Act
+        handler.init(10);
+        for loss in [3.0, 2.0, 1.0] {
+            handler.observe_loss(loss);
+            handler.end_epoch();
+        }
+
+        // Assert
+        assert_eq!(handler.learning_rate(), 0.1);
+    }
+
+    #[test]
+    fn test_learning_rate_is_reduced_after_patience() {
+        // Arrange
+        let mut handler = LearningRateHandler::new(0.1, 0.5, 2);
+
+        // Act
+        handler.init(10);
+        handler.observe_loss(1.0);
+        handler.observe_loss(1.5);
+        let before_patience = handler.learning_rate();
+        handler.observe_loss(1.0);
+
+        // Assert
+        assert_eq!(before_patience, 0.1);
+        assert_eq!(handler.learning_rate(), 0.05);
+    }
+
+    #[test]
+    fn test_learning_rate_is_not_reduced_below_minimum() {
+        // Arrange
+        let mut handler = LearningRateHandler::new(0.1, 0.5, 1).with_minimum_rate(0.04);
+
+        // Act
+        handler.init(10);
+        for loss in [1.0, 2.0, 2.0, 2.0] {
+            handler.observe_loss(loss);
+        }
+
+        // Assert
+        assert_eq!(handler.learning_rate(), 0.04);
+    }
+
+    #[test]
+    fn test_init_resets_learning_rate() {
+        // Arrange
+        let mut handler = LearningRateHandler::new(0.1, 0.5, 1);
+        handler.init(10);
+        handler.observe_loss(1.0);
+        handler.observe_loss(2.0);
+
+        // Act
+        handler.init(10);
+
+        // Assert
+        assert_eq!(handler.learning_rate(), 0.1);
+    }
+}
@@ -0,0 +1,105 @@
+use crate::private::Sealed;
+use crate::ElementType;
+
+/// A structure representing a learning rate which is multiplied by the given factor
+/// every given number of epochs, starting from the given rate. A step size of zero
+/// leaves the learning rate unchanged.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LearningRateHandler {
+    starting_rate: ElementType,
+    current_rate: ElementType,
+    factor: ElementType,
+    step_size: u32,
+    epoch: u32,
+}
+
+impl LearningRateHandler {
+    /// Constructs a new instance of a step decaying learning rate which starts at the given
+    /// rate and is multiplied by the given factor at the end of every `step_size` epochs.
+    #[must_use]
+    pub const fn new(starting_rate: ElementType, factor: ElementType, step_size: u32) -> Self {
+        Self {
+            starting_rate,
+            current_rate: starting_rate,
+            factor,
+            step_size,
+            epoch: 0,
+        }
+    }
+}
+
+impl Sealed for LearningRateHandler {}
+impl super::LearningRateHandler for LearningRateHandler {
+    fn learning_rate(&self) -> ElementType {
+        self.current_rate
+    }
+
+    fn init(&mut self, _epochs: u32) {
+        self.current_rate = self.starting_rate;
+        self.epoch = 0;
+    }
+
+    fn end_epoch(&mut self) {
+        self.epoch = self.epoch.saturating_add(1);
+        if self.epoch.is_multiple_of(self.step_size) {
+            self.current_rate *= self.factor;
+        }
+    }
+
+    fn end_batch(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optimisers::learning_rate_handlers::LearningRateHandler as BaseLearningRateHandler;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_learning_rate_drops_every_step() {
+        // Arrange
+        let mut handler = LearningRateHandler::new(0.8, 0.5, 3);
+        handler.init(10);
+
+        // Act
+        let rates = (0..8)
+            .map(|_| {
+                let rate = handler.learning_rate();
+                handler.end_epoch();
+                rate
+            })
+            .collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(rates, [0.8, 0.8, 0.8, 0.4, 0.4, 0.4, 0.2, 0.2]);
+    }
+
+    #[test]
+    fn test_init_resets_learning_rate() {
+        // Arrange
+        let mut handler = LearningRateHandler::new(0.8, 0.5, 1);
+        handler.init(10);
+        (0..4).for_each(|_| handler.end_epoch());
+
+        // Act
+        handler.init(10);
+        handler.end_epoch();
+
+        // Assert
+        assert_eq!(handler.learning_rate(), 0.4);
+    }
+
+    #[test]
+    fn test_zero_step_size_never_drops() {
+        // Arrange
+        let mut handler = LearningRateHandler::new(0.8, 0.5, 0);
+        handler.init(10);
+
+        // Act
+        (0..10).for_each(|_| handler.end_epoch());
+
+        // Assert
+        assert_eq!(handler.learning_rate(), 0.8);
+    }
+}
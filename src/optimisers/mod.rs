@@ -1,12 +1,25 @@
 //! This module will contain the traits and structures for the various methods
 //! of optimisation that can be used when updating an operation's parameter.
 
+mod adam;
+mod adaptive_gradient_clip;
 pub(crate) mod base;
+pub(crate) mod capture;
+mod gradient_centralization;
+mod gradient_clipping;
+mod lbfgs;
 pub mod learning_rate_handlers;
 pub(crate) mod null;
+mod regularisation;
 mod sgd;
 mod sgd_momentum;
 
+pub use adam::OptimiserFactory as Adam;
+pub use adaptive_gradient_clip::OptimiserFactory as AdaptiveGradientClip;
+pub use gradient_centralization::OptimiserFactory as GradientCentralization;
+pub use gradient_clipping::OptimiserFactory as GradientClipping;
+pub use lbfgs::{Objective, LBFGS};
 pub use null::OptimiserFactory as NullOptimiser;
+pub use regularisation::OptimiserFactory as Regularisation;
 pub use sgd::OptimiserFactory as SGD;
 pub use sgd_momentum::OptimiserFactory as SGDMomentum;
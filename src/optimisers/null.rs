@@ -11,6 +11,7 @@ use crate::private::Sealed;
 /// one needs to provide an optimiser to the API but might not want to
 /// necessarily do anything.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OptimiserFactory(());
 
 impl OptimiserFactory {
@@ -32,6 +33,7 @@ impl<T> optimisers::base::OptimiserFactory<T> for OptimiserFactory {
 /// This struct is the concrete optimiser that is produced by the
 /// null `OptimiserFactory`.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Optimiser(());
 
 impl Optimiser {
@@ -43,8 +45,10 @@ impl Optimiser {
 impl Sealed for Optimiser {}
 impl<T> optimisers::base::Optimiser<T> for Optimiser {
     fn optimise(&mut self, _parameter: &mut T, _gradient: &T) {}
-    fn init(&mut self, _epochs: u16) {}
+    fn init(&mut self, _epochs: u32) {}
     fn end_epoch(&mut self) {}
+
+    fn end_batch(&mut self) {}
 }
 
 #[cfg(test)]
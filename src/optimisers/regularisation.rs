@@ -0,0 +1,228 @@
+use crate::optimisers::base;
+use crate::private::Sealed;
+use crate::tensors::rank::Rank;
+use crate::tensors::Tensor;
+use crate::ElementType;
+
+/// This is an optimiser which wraps another optimiser and regularises the parameters by adding
+/// a weight decay term to each gradient before passing it on. L1 regularisation adds the sign of
+/// each parameter scaled by the L1 coefficient, and L2 regularisation adds each parameter scaled
+/// by the L2 coefficient. Both default to zero and can be combined.
+///
+/// The penalty being minimised (the L1 coefficient multiplied by the sum of the absolute values of
+/// the parameters, plus half the L2 coefficient multiplied by the sum of their squares) is included
+/// in the losses reported during training.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OptimiserFactory<T> {
+    inner: T,
+    l1: ElementType,
+    l2: ElementType,
+}
+
+impl<T> OptimiserFactory<T> {
+    /// Constructs a new regularisation optimiser wrapping the given inner optimiser.
+    /// No regularisation is applied until a coefficient is set.
+    #[must_use]
+    pub const fn new(inner: T) -> Self {
+        Self {
+            inner,
+            l1: 0.0,
+            l2: 0.0,
+        }
+    }
+
+    /// Sets the coefficient of the L1 regularisation, which pushes parameters towards zero
+    /// by a constant amount and so encourages sparse weights.
+    #[must_use]
+    pub fn with_l1(self, l1: ElementType) -> Self {
+        Self { l1, ..self }
+    }
+
+    /// Sets the coefficient of the L2 regularisation (weight decay), which pushes parameters
+    /// towards zero in proportion to their size.
+    #[must_use]
+    pub fn with_l2(self, l2: ElementType) -> Self {
+        Self { l2, ..self }
+    }
+}
+
+impl<T> Sealed for OptimiserFactory<T> {}
+impl<T: base::OptimiserFactory<Tensor<R>>, R: Rank> base::OptimiserFactory<Tensor<R>>
+    for OptimiserFactory<T>
+{
+    type Optimiser = Optimiser<T::Optimiser>;
+    fn instantiate(&self) -> Self::Optimiser {
+        Self::Optimiser {
+            inner: self.inner.instantiate(),
+            l1: self.l1,
+            l2: self.l2,
+        }
+    }
+}
+
+impl<T: base::OptimiserFactory<()>> base::OptimiserFactory<()> for OptimiserFactory<T> {
+    type Optimiser = T::Optimiser;
+    fn instantiate(&self) -> Self::Optimiser {
+        self.inner.instantiate()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Optimiser<T> {
+    inner: T,
+    l1: ElementType,
+    l2: ElementType,
+}
+
+impl<T> Sealed for Optimiser<T> {}
+impl<T: base::Optimiser<Tensor<R>>, R: Rank> base::Optimiser<Tensor<R>> for Optimiser<T> {
+    fn optimise(&mut self, parameter: &mut Tensor<R>, gradient: &Tensor<R>) {
+        self.optimise_with_norm(parameter, gradient, gradient.l2_norm());
+    }
+
+    fn optimise_with_norm(
+        &mut self,
+        parameter: &mut Tensor<R>,
+        gradient: &Tensor<R>,
+        global_norm: ElementType,
+    ) {
+        let (l1, l2) = (self.l1, self.l2);
+        let decay = parameter.0.mapv(|elem| {
+            let sign = if elem == 0.0 { 0.0 } else { elem.signum() };
+            l1.mul_add(sign, l2 * elem)
+        });
+        let gradient = Tensor(&gradient.0 + &decay);
+        self.inner
+            .optimise_with_norm(parameter, &gradient, global_norm);
+    }
+
+    fn init(&mut self, epochs: u32) {
+        self.inner.init(epochs);
+    }
+
+    fn end_epoch(&mut self) {
+        self.inner.end_epoch();
+    }
+
+    fn end_batch(&mut self) {
+        self.inner.end_batch();
+    }
+
+    fn observe_loss(&mut self, loss: ElementType) {
+        self.inner.observe_loss(loss);
+    }
+
+    fn learning_rate(&self) -> Option<ElementType> {
+        self.inner.learning_rate()
+    }
+
+    fn penalty(&self, parameter: &Tensor<R>) -> ElementType {
+        let (l1, l2) = (self.l1, self.l2);
+        let penalty = parameter
+            .0
+            .iter()
+            .map(|elem| l1.mul_add(elem.abs(), 0.5 * l2 * elem * elem))
+            .sum::<ElementType>();
+        penalty + self.inner.penalty(parameter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::Linear;
+    use crate::layers::{Chain, Dense, Input};
+    use crate::operations::{TrainableOperation, UninitialisedOperation, WithOptimiser};
+    use crate::optimisers::base::Optimiser as BaseOptimiser;
+    use crate::optimisers::base::OptimiserFactory as BaseOptimiserFactory;
+    use crate::optimisers::learning_rate_handlers::FixedLearningRateHandler;
+    use crate::optimisers::{NullOptimiser, Regularisation, SGD};
+    use crate::tensors::rank;
+
+    #[test]
+    fn test_optimise_l2() {
+        // Arrange
+        let sgd = SGD::new(FixedLearningRateHandler::new(0.5));
+        let factory = Regularisation::new(sgd).with_l2(0.1);
+        let mut optimiser = BaseOptimiserFactory::<Tensor<rank::Two>>::instantiate(&factory);
+        let mut parameter = Tensor::<rank::Two>::new((1, 2), [2.0, -4.0]).unwrap();
+        let gradient = Tensor::<rank::Two>::new((1, 2), [0.0, 1.0]).unwrap();
+        let expected = Tensor::<rank::Two>::new((1, 2), [1.9, -4.3]).unwrap();
+
+        // Act
+        optimiser.optimise(&mut parameter, &gradient);
+
+        // Assert
+        parameter
+            .into_iter()
+            .zip(expected)
+            .for_each(|(output, expected)| assert!((output - expected).abs() < 1e-6));
+    }
+
+    #[test]
+    fn test_optimise_l1() {
+        // Arrange
+        let sgd = SGD::new(FixedLearningRateHandler::new(1.0));
+        let factory = Regularisation::new(sgd).with_l1(0.5);
+        let mut optimiser = BaseOptimiserFactory::<Tensor<rank::Two>>::instantiate(&factory);
+        let mut parameter = Tensor::<rank::Two>::new((1, 3), [2.0, 0.0, -4.0]).unwrap();
+        let gradient = Tensor::<rank::Two>::new((1, 3), [0.0, 0.0, 0.0]).unwrap();
+        let expected = Tensor::<rank::Two>::new((1, 3), [1.5, 0.0, -3.5]).unwrap();
+
+        // Act
+        optimiser.optimise(&mut parameter, &gradient);
+
+        // Assert
+        assert_eq!(parameter, expected);
+    }
+
+    #[test]
+    fn test_penalty() {
+        // Arrange
+        let factory = Regularisation::new(NullOptimiser::new())
+            .with_l1(0.5)
+            .with_l2(2.0);
+        let optimiser = BaseOptimiserFactory::<Tensor<rank::Two>>::instantiate(&factory);
+        let parameter = Tensor::<rank::Two>::new((1, 2), [1.0, -2.0]).unwrap();
+        // 0.5 * (1 + 2) + 0.5 * 2.0 * (1 + 4)
+        let expected = 6.5;
+
+        // Act
+        let output = optimiser.penalty(&parameter);
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_network_penalty() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(1, Linear::new()))
+            .with_iter([1.0, -2.0, 3.0].into_iter())
+            .unwrap();
+        let factory = Regularisation::new(NullOptimiser::new()).with_l2(1.0);
+
+        // Act
+        let output = network.with_optimiser(factory).penalty();
+
+        // Assert
+        assert_eq!(output, 7.0);
+    }
+
+    #[test]
+    fn test_network_penalty_without_regularisation() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42);
+
+        // Act
+        let output = network.with_optimiser(NullOptimiser::new()).penalty();
+
+        // Assert
+        assert_eq!(output, 0.0);
+    }
+}
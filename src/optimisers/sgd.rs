@@ -4,12 +4,14 @@ use crate::optimisers::NullOptimiser;
 use crate::private::Sealed;
 use crate::tensors::rank::Rank;
 use crate::tensors::Tensor;
+use crate::ElementType;
 
 /// This is an implementation of a standard stochastic
 /// gradient descent (SGD) optimisation strategy which is
 /// simply updating the parameter with some proportion of
 /// the gradient.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OptimiserFactory<T> {
     learning_rate_handler: T,
 }
@@ -44,6 +46,7 @@ impl<T> optimisers::base::OptimiserFactory<()> for OptimiserFactory<T> {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Optimiser<T> {
     learning_rate_handler: T,
 }
@@ -57,13 +60,25 @@ impl<T: LearningRateHandler, R: Rank> optimisers::base::Optimiser<Tensor<R>> for
         *parameter = &*parameter - (gradient * learning_rate);
     }
 
-    fn init(&mut self, epochs: u16) {
+    fn init(&mut self, epochs: u32) {
         self.learning_rate_handler.init(epochs);
     }
 
     fn end_epoch(&mut self) {
         self.learning_rate_handler.end_epoch();
     }
+
+    fn end_batch(&mut self) {
+        self.learning_rate_handler.end_batch();
+    }
+
+    fn observe_loss(&mut self, loss: ElementType) {
+        self.learning_rate_handler.observe_loss(loss);
+    }
+
+    fn learning_rate(&self) -> Option<ElementType> {
+        Some(self.learning_rate_handler.learning_rate())
+    }
 }
 
 #[cfg(test)]
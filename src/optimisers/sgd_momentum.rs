@@ -11,6 +11,7 @@ use ndarray::{azip, Array};
 /// gradient descent (SGD) optimisation strategy but with
 /// an amount of momentum given to the updates.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OptimiserFactory<T> {
     learning_rate_handler: T,
     momentum: ElementType,
@@ -49,6 +50,14 @@ impl<T> optimisers::base::OptimiserFactory<()> for OptimiserFactory<T> {
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "T: serde::Serialize, R::Internal: serde::Serialize",
+        deserialize = "T: serde::Deserialize<'de>, R::Internal: serde::Deserialize<'de>"
+    ))
+)]
 pub struct Optimiser<T, R: Rank> {
     learning_rate_handler: T,
     velocity: Option<Array<ElementType, R::Internal>>,
@@ -69,13 +78,25 @@ impl<T: LearningRateHandler, R: Rank> optimisers::base::Optimiser<Tensor<R>> for
         });
     }
 
-    fn init(&mut self, epochs: u16) {
+    fn init(&mut self, epochs: u32) {
         self.learning_rate_handler.init(epochs);
     }
 
     fn end_epoch(&mut self) {
         self.learning_rate_handler.end_epoch();
     }
+
+    fn end_batch(&mut self) {
+        self.learning_rate_handler.end_batch();
+    }
+
+    fn observe_loss(&mut self, loss: ElementType) {
+        self.learning_rate_handler.observe_loss(loss);
+    }
+
+    fn learning_rate(&self) -> Option<ElementType> {
+        Some(self.learning_rate_handler.learning_rate())
+    }
 }
 
 #[cfg(test)]
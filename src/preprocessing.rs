@@ -0,0 +1,325 @@
+//! This module contains types for preprocessing the data that is fed into
+//! a network. Preprocessors are fit on the training data only and can then be
+//! applied consistently to both training and testing data to avoid data leakage.
+
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use ndarray::{Array, Axis, Ix1, Ix2};
+
+/// Calculates the mean and (population) standard deviation of each column
+/// of the given data. Returns `None` if there are no rows to calculate statistics over.
+fn column_stats(
+    data: &Array<ElementType, Ix2>,
+) -> Option<(Array<ElementType, Ix1>, Array<ElementType, Ix1>)> {
+    let mean = data.mean_axis(Axis(0))?;
+    let std = data.std_axis(Axis(0), 0.0);
+    Some((mean, std))
+}
+
+/// A preprocessor which standardises each column of the data to have zero mean
+/// and unit standard deviation, using statistics calculated from the data it was fit on.
+///
+/// Columns with no variance in the fitted data are only shifted by their mean.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Standardiser {
+    mean: Tensor<rank::One>,
+    std: Tensor<rank::One>,
+}
+
+impl Standardiser {
+    /// Fits a new `Standardiser` to the given (training) data by recording the
+    /// mean and standard deviation of each column.
+    ///
+    /// # Errors
+    /// `Error` if the data has no rows to calculate statistics from.
+    pub fn fit(data: &Tensor<rank::Two>) -> Result<Self> {
+        let (mean, std) = column_stats(&data.0).ok_or(Error::EmptyData)?;
+        let std = std.mapv(|elem| if elem == 0.0 { 1.0 } else { elem });
+        Ok(Self {
+            mean: Tensor(mean),
+            std: Tensor(std),
+        })
+    }
+
+    /// Constructs a `Standardiser` from the mean and standard deviation of each column, such as
+    /// those recorded by a `Standardiser` that was fit previously.
+    ///
+    /// # Errors
+    /// `Error` if there isn't a standard deviation for every mean, or if any standard deviation
+    /// isn't positive.
+    pub fn from_parameters(mean: Tensor<rank::One>, std: Tensor<rank::One>) -> Result<Self> {
+        Error::check_size(mean.dim(), std.dim())?;
+        if std.0.iter().all(|elem| *elem > 0.0) {
+            Ok(Self { mean, std })
+        } else {
+            Err(Error::InvalidArgument)
+        }
+    }
+
+    /// Gets the mean of each column of the data that was fit.
+    #[must_use]
+    pub const fn mean(&self) -> &Tensor<rank::One> {
+        &self.mean
+    }
+
+    /// Gets the standard deviation of each column of the data that was fit, where
+    /// columns with no variance have a standard deviation of 1.
+    #[must_use]
+    pub const fn std(&self) -> &Tensor<rank::One> {
+        &self.std
+    }
+
+    /// Transforms the given data using the statistics recorded when fitting, shifting
+    /// each column by its mean and scaling by its standard deviation.
+    ///
+    /// # Errors
+    /// `Error` if the data doesn't have the same number of columns as the data that was fit.
+    pub fn transform(&self, data: Tensor<rank::Two>) -> Result<Tensor<rank::Two>> {
+        Error::check_size(self.mean.dim(), data.0.ncols())?;
+        Ok(Tensor((data.0 - &self.mean.0) / &self.std.0))
+    }
+}
+
+/// A preprocessor which rescales each column of the data to lie between 0 and 1, using the
+/// minimum and maximum of each column of the data it was fit on. Data which wasn't fit may
+/// fall outside of that range.
+///
+/// Columns with a single value in the fitted data are only shifted by their minimum.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Normaliser {
+    min: Tensor<rank::One>,
+    max: Tensor<rank::One>,
+}
+
+impl Normaliser {
+    /// Fits a new `Normaliser` to the given (training) data by recording the
+    /// minimum and maximum of each column.
+    ///
+    /// # Errors
+    /// `Error` if the data has no rows to calculate the minimum and maximum from.
+    pub fn fit(data: &Tensor<rank::Two>) -> Result<Self> {
+        if data.0.nrows() == 0 {
+            return Err(Error::EmptyData);
+        }
+        let min = data
+            .0
+            .fold_axis(Axis(0), ElementType::INFINITY, |min, elem| min.min(*elem));
+        let max = data
+            .0
+            .fold_axis(Axis(0), ElementType::NEG_INFINITY, |max, elem| {
+                max.max(*elem)
+            });
+        Ok(Self {
+            min: Tensor(min),
+            max: Tensor(max),
+        })
+    }
+
+    /// Constructs a `Normaliser` from the minimum and maximum of each column, such as those
+    /// recorded by a `Normaliser` that was fit previously.
+    ///
+    /// # Errors
+    /// `Error` if there isn't a maximum for every minimum, or if any maximum is less than its minimum.
+    pub fn from_parameters(min: Tensor<rank::One>, max: Tensor<rank::One>) -> Result<Self> {
+        Error::check_size(min.dim(), max.dim())?;
+        if min.0.iter().zip(&max.0).all(|(min, max)| min <= max) {
+            Ok(Self { min, max })
+        } else {
+            Err(Error::InvalidArgument)
+        }
+    }
+
+    /// Gets the minimum of each column of the data that was fit.
+    #[must_use]
+    pub const fn min(&self) -> &Tensor<rank::One> {
+        &self.min
+    }
+
+    /// Gets the maximum of each column of the data that was fit.
+    #[must_use]
+    pub const fn max(&self) -> &Tensor<rank::One> {
+        &self.max
+    }
+
+    /// Transforms the given data using the minimum and maximum recorded when fitting, shifting
+    /// each column by its minimum and scaling by its range.
+    ///
+    /// # Errors
+    /// `Error` if the data doesn't have the same number of columns as the data that was fit.
+    pub fn transform(&self, data: Tensor<rank::Two>) -> Result<Tensor<rank::Two>> {
+        Error::check_size(self.min.dim(), data.0.ncols())?;
+        let range = (&self.max.0 - &self.min.0).mapv(|elem| if elem == 0.0 { 1.0 } else { elem });
+        Ok(Tensor((data.0 - &self.min.0) / range))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_failure() {
+        // Arrange
+        let data = Tensor::<rank::Two>::new((0, 2), []).unwrap();
+
+        // Act
+        let result = Standardiser::fit(&data);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_transform_training_data() {
+        // Arrange
+        let data =
+            Tensor::<rank::Two>::new((4, 2), [1.0, 2.0, 3.0, 6.0, 1.0, 2.0, 3.0, 6.0]).unwrap();
+        let standardiser = Standardiser::fit(&data).unwrap();
+
+        // Act
+        let output = standardiser.transform(data).unwrap();
+        let (mean, std) = column_stats(&output.0).unwrap();
+
+        // Assert
+        assert_eq!(mean, Array::from_iter([0.0, 0.0]));
+        assert_eq!(std, Array::from_iter([1.0, 1.0]));
+    }
+
+    #[test]
+    fn test_transform_testing_data() {
+        // Arrange
+        let training = Tensor::<rank::Two>::new((2, 2), [1.0, 2.0, 3.0, 6.0]).unwrap();
+        let testing = Tensor::<rank::Two>::new((2, 2), [5.0, 10.0, 2.0, 4.0]).unwrap();
+        let standardiser = Standardiser::fit(&training).unwrap();
+        let expected = Tensor::<rank::Two>::new((2, 2), [3.0, 3.0, 0.0, 0.0]).unwrap();
+
+        // Act
+        let output = standardiser.transform(testing).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_transform_constant_column() {
+        // Arrange
+        let training = Tensor::<rank::Two>::new((2, 1), [4.0, 4.0]).unwrap();
+        let standardiser = Standardiser::fit(&training).unwrap();
+        let testing = Tensor::<rank::Two>::new((2, 1), [4.0, 6.0]).unwrap();
+        let expected = Tensor::<rank::Two>::new((2, 1), [0.0, 2.0]).unwrap();
+
+        // Act
+        let output = standardiser.transform(testing).unwrap();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_transform_failure() {
+        // Arrange
+        let training = Tensor::<rank::Two>::new((2, 2), [1.0, 2.0, 3.0, 6.0]).unwrap();
+        let testing = Tensor::<rank::Two>::new((2, 1), [1.0, 2.0]).unwrap();
+        let standardiser = Standardiser::fit(&training).unwrap();
+
+        // Act
+        let result = standardiser.transform(testing);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_standardiser_parameters() {
+        // Arrange
+        let training = Tensor::<rank::Two>::new((2, 2), [1.0, 2.0, 3.0, 6.0]).unwrap();
+        let standardiser = Standardiser::fit(&training).unwrap();
+
+        // Act
+        let output =
+            Standardiser::from_parameters(standardiser.mean().clone(), standardiser.std().clone());
+
+        // Assert
+        assert_eq!(standardiser.mean(), &Tensor::<rank::One>::new([2.0, 4.0]));
+        assert_eq!(standardiser.std(), &Tensor::<rank::One>::new([1.0, 2.0]));
+        assert_eq!(output, Ok(standardiser));
+        assert_eq!(
+            Standardiser::from_parameters(
+                Tensor::<rank::One>::new([0.0]),
+                Tensor::<rank::One>::new([0.0])
+            ),
+            Err(Error::InvalidArgument)
+        );
+        assert!(Standardiser::from_parameters(
+            Tensor::<rank::One>::new([0.0]),
+            Tensor::<rank::One>::new([1.0, 1.0])
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_normaliser_fit_failure() {
+        // Arrange
+        let data = Tensor::<rank::Two>::new((0, 2), []).unwrap();
+
+        // Act
+        let result = Normaliser::fit(&data);
+
+        // Assert
+        assert_eq!(result, Err(Error::EmptyData));
+    }
+
+    #[test]
+    fn test_normaliser_transform() {
+        // Arrange
+        let training =
+            Tensor::<rank::Two>::new((3, 3), [1.0, -2.0, 5.0, 3.0, 6.0, 5.0, 2.0, 2.0, 5.0])
+                .unwrap();
+        let testing = Tensor::<rank::Two>::new((2, 3), [5.0, 4.0, 5.0, 0.0, -2.0, 7.0]).unwrap();
+        let normaliser = Normaliser::fit(&training).unwrap();
+        let expected =
+            Tensor::<rank::Two>::new((3, 3), [0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.5, 0.5, 0.0])
+                .unwrap();
+        let expected_testing =
+            Tensor::<rank::Two>::new((2, 3), [2.0, 0.75, 0.0, -0.5, 0.0, 2.0]).unwrap();
+
+        // Act
+        let output = normaliser.transform(training).unwrap();
+        let output_testing = normaliser.transform(testing).unwrap();
+
+        // Assert
+        assert_eq!(
+            normaliser.min(),
+            &Tensor::<rank::One>::new([1.0, -2.0, 5.0])
+        );
+        assert_eq!(normaliser.max(), &Tensor::<rank::One>::new([3.0, 6.0, 5.0]));
+        assert_eq!(output, expected);
+        assert_eq!(output_testing, expected_testing);
+    }
+
+    #[test]
+    fn test_normaliser_parameters() {
+        // Arrange
+        let min = Tensor::<rank::One>::new([0.0, 1.0]);
+        let max = Tensor::<rank::One>::new([2.0, 1.0]);
+        let data = Tensor::<rank::Two>::new((1, 2), [1.0, 3.0]).unwrap();
+
+        // Act
+        let normaliser = Normaliser::from_parameters(min.clone(), max.clone()).unwrap();
+
+        // Assert
+        assert_eq!(
+            normaliser.transform(data),
+            Tensor::<rank::Two>::new((1, 2), [0.5, 2.0])
+        );
+        assert_eq!(
+            Normaliser::from_parameters(max, min),
+            Err(Error::InvalidArgument)
+        );
+        assert!(normaliser
+            .transform(Tensor::<rank::Two>::new((1, 1), [1.0]).unwrap())
+            .is_err());
+    }
+}
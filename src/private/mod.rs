@@ -1,3 +1,25 @@
+pub mod named;
+pub mod native;
+pub mod npy;
 pub mod padding;
+pub mod safetensors;
+pub mod zip;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 
 pub trait Sealed {}
+
+/// Constructs the generator for the next random decision of an operation from its seed, moving the seed on so that
+/// the same decision isn't made next time. With the *std* feature an operation without a seed draws from the thread's
+/// random generator, but without it there may be no source of entropy (such as on wasm32-unknown-unknown), so the
+/// operation starts from a fixed seed instead.
+pub fn next_generator(seed: &mut Option<u64>) -> StdRng {
+    #[cfg(feature = "std")]
+    if seed.is_none() {
+        return StdRng::from_rng(rand::thread_rng()).unwrap();
+    }
+    let current = seed.unwrap_or(0);
+    *seed = Some(current + 1);
+    StdRng::seed_from_u64(current)
+}
@@ -0,0 +1,166 @@
+use crate::{ElementType, Error, Result};
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// A tensor of parameters with the name and shape it's stored under in a file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NamedTensor {
+    pub name: String,
+    pub shape: Vec<usize>,
+    pub values: Vec<ElementType>,
+}
+
+/// The shape of a layer (input neurons by output neurons) along with its number of parameters.
+pub type LayerShape = ((usize, usize), usize);
+
+/// Splits the parameters of each layer into a tensor named `layer_{index}.weight` with the shape
+/// of the layer, followed by a tensor named `layer_{index}.bias` holding the remaining parameters
+/// of the layer if there are any.
+pub fn named_tensors(layers: Vec<((usize, usize), Vec<ElementType>)>) -> Vec<NamedTensor> {
+    let mut tensors = Vec::new();
+    for (index, ((rows, cols), mut weight)) in layers.into_iter().enumerate() {
+        let bias = weight.split_off((rows * cols).min(weight.len()));
+        tensors.push(NamedTensor {
+            name: format!("layer_{index}.weight"),
+            shape: [rows, cols].to_vec(),
+            values: weight,
+        });
+        if !bias.is_empty() {
+            tensors.push(NamedTensor {
+                name: format!("layer_{index}.bias"),
+                shape: [bias.len()].to_vec(),
+                values: bias,
+            });
+        }
+    }
+    tensors
+}
+
+/// Joins tensors named as by `named_tensors` back together, in any order, into the shape and
+/// number of parameters of each layer along with the flattened parameters of every layer. Weights
+/// can be one dimensional, in which case they're treated as a single row.
+pub fn join_named_tensors(
+    tensors: Vec<NamedTensor>,
+) -> Result<(Vec<LayerShape>, Vec<ElementType>)> {
+    let mut layers = BTreeMap::new();
+    for tensor in tensors {
+        let (layer, kind) = tensor
+            .name
+            .strip_prefix("layer_")
+            .and_then(|name| name.split_once('.'))
+            .ok_or(Error::InvalidArchive)?;
+        let layer = layer.parse::<usize>().map_err(|_| Error::InvalidArchive)?;
+        let (weight, bias) = layers.entry(layer).or_insert((None, None));
+        let slot = match kind {
+            "weight" => weight,
+            "bias" => bias,
+            _ => return Err(Error::InvalidArchive),
+        };
+        if slot.replace(tensor).is_some() {
+            return Err(Error::InvalidArchive);
+        }
+    }
+    let mut shapes = Vec::new();
+    let mut parameters = Vec::new();
+    for (index, (layer, (weight, bias))) in layers.into_iter().enumerate() {
+        if layer != index {
+            return Err(Error::InvalidArchive);
+        }
+        let weight = weight.ok_or(Error::InvalidArchive)?;
+        let shape = match *weight.shape.as_slice() {
+            [rows, cols] => (rows, cols),
+            [cols] => (1, cols),
+            _ => return Err(Error::InvalidArchive),
+        };
+        let mut count = weight.values.len();
+        parameters.extend(weight.values);
+        if let Some(bias) = bias {
+            if !matches!(*bias.shape.as_slice(), [_] | [1, _]) {
+                return Err(Error::InvalidArchive);
+            }
+            count += bias.values.len();
+            parameters.extend(bias.values);
+        }
+        shapes.push((shape, count));
+    }
+    Ok((shapes, parameters))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tensor(name: &str, shape: &[usize], values: &[ElementType]) -> NamedTensor {
+        NamedTensor {
+            name: name.into(),
+            shape: shape.to_vec(),
+            values: values.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_named_tensors() {
+        // Arrange
+        let layers = [
+            ((2, 1), [1.0, 2.0, 3.0].to_vec()),
+            ((1, 2), [4.0, 5.0].to_vec()),
+        ]
+        .to_vec();
+        let expected = [
+            tensor("layer_0.weight", &[2, 1], &[1.0, 2.0]),
+            tensor("layer_0.bias", &[1], &[3.0]),
+            tensor("layer_1.weight", &[1, 2], &[4.0, 5.0]),
+        ];
+
+        // Act
+        let output = named_tensors(layers);
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_join_named_tensors() {
+        // Arrange
+        let tensors = [
+            tensor("layer_1.weight", &[2], &[4.0, 5.0]),
+            tensor("layer_0.bias", &[1, 1], &[3.0]),
+            tensor("layer_0.weight", &[2, 1], &[1.0, 2.0]),
+        ]
+        .to_vec();
+
+        // Act
+        let (shapes, parameters) = join_named_tensors(tensors).unwrap();
+
+        // Assert
+        assert_eq!(shapes, [((2, 1), 3), ((1, 2), 2)]);
+        assert_eq!(parameters, [1.0, 2.0, 3.0, 4.0, 5.0]);
+    }
+
+    #[test]
+    fn test_join_named_tensors_failure() {
+        // Arrange
+        let archives = [
+            [tensor("weights", &[1], &[1.0])].to_vec(),
+            [tensor("layer_0.kernel", &[1], &[1.0])].to_vec(),
+            [tensor("layer_1.weight", &[1], &[1.0])].to_vec(),
+            [tensor("layer_0.bias", &[1], &[1.0])].to_vec(),
+            [tensor("layer_0.weight", &[1, 1, 1], &[1.0])].to_vec(),
+            [
+                tensor("layer_0.weight", &[1], &[1.0]),
+                tensor("layer_0.weight", &[1], &[1.0]),
+            ]
+            .to_vec(),
+        ];
+
+        // Act
+        let results = archives.map(join_named_tensors);
+
+        // Assert
+        assert!(results
+            .into_iter()
+            .all(|result| result == Err(Error::InvalidArchive)));
+    }
+}
@@ -0,0 +1,201 @@
+use crate::operations::InitialisedOperation;
+use crate::private::named::LayerShape;
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+
+const MAGIC: &[u8; 8] = b"EIDETIC\0";
+
+/// The version of the format that's written, which is increased whenever the layout changes.
+const VERSION: u16 = 1;
+
+/// The number of bits in each element that's written, which is recorded so that parameters
+/// saved from a network using one element type aren't silently misread by another.
+#[cfg(not(feature = "f32"))]
+const ELEMENT_BITS: u16 = 64;
+#[cfg(feature = "f32")]
+const ELEMENT_BITS: u16 = 32;
+
+/// Encodes the parameters of the given network. The format is the magic bytes, the version, the
+/// number of bits in each element, and the number of layers with parameters, followed by the
+/// number of rows, columns and parameters of each layer, and then every parameter in network order.
+/// All numbers are little endian, with the counts written as 64 bit integers.
+pub fn encode(network: &impl InitialisedOperation) -> Vec<u8> {
+    let mut layers = Vec::new();
+    network.push_layer_parameters(&mut layers);
+    let mut bytes = MAGIC.to_vec();
+    bytes.extend_from_slice(&VERSION.to_le_bytes());
+    bytes.extend_from_slice(&ELEMENT_BITS.to_le_bytes());
+    bytes.extend_from_slice(&(layers.len() as u64).to_le_bytes());
+    for ((rows, cols), parameters) in &layers {
+        for count in [*rows, *cols, parameters.len()] {
+            bytes.extend_from_slice(&(count as u64).to_le_bytes());
+        }
+    }
+    for (_, parameters) in layers {
+        for parameter in parameters {
+            bytes.extend_from_slice(&parameter.to_le_bytes());
+        }
+    }
+    bytes
+}
+
+/// Decodes parameters written by `encode` into the shape of each layer and the parameters of every layer.
+pub fn decode(bytes: &[u8]) -> Result<(Vec<LayerShape>, Vec<ElementType>)> {
+    let mut reader = Reader { bytes };
+    if reader.take(MAGIC.len())? != MAGIC {
+        return Err(Error::InvalidArchive);
+    }
+    let version = u16::from_le_bytes(reader.array()?);
+    if version != VERSION {
+        return Err(Error::UnsupportedVersion { version });
+    }
+    let bits = u16::from_le_bytes(reader.array()?);
+    if bits != ELEMENT_BITS {
+        return Err(Error::ElementTypeMismatch {
+            expected: ELEMENT_BITS.into(),
+            actual: bits.into(),
+        });
+    }
+    let layers = reader.count()?;
+    let shapes = (0..layers)
+        .map(|_| Ok(((reader.count()?, reader.count()?), reader.count()?)))
+        .collect::<Result<Vec<_>>>()?;
+    let parameters = shapes
+        .iter()
+        .map(|(_, count)| count)
+        .sum::<usize>()
+        .checked_mul(size_of::<ElementType>())
+        .ok_or(Error::InvalidArchive)?;
+    let parameters = reader
+        .take(parameters)?
+        .chunks_exact(size_of::<ElementType>())
+        .map(|chunk| ElementType::from_le_bytes(chunk.try_into().unwrap())) // unwrapping is safe because every chunk is the size of an element
+        .collect();
+    if reader.bytes.is_empty() {
+        Ok((shapes, parameters))
+    } else {
+        Err(Error::InvalidArchive)
+    }
+}
+
+/// Reads values from the front of some bytes.
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, length: usize) -> Result<&'a [u8]> {
+        let (taken, rest) = self
+            .bytes
+            .split_at_checked(length)
+            .ok_or(Error::InvalidArchive)?;
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        Ok(self.take(N)?.try_into().unwrap()) // unwrapping is safe because exactly N bytes were taken
+    }
+
+    fn count(&mut self) -> Result<usize> {
+        usize::try_from(u64::from_le_bytes(self.array()?)).map_err(|_| Error::InvalidArchive)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::Linear;
+    use crate::layers::{Chain, Dense, Input};
+    use crate::operations::UninitialisedOperation;
+
+    fn network_bytes() -> Vec<u8> {
+        let network = Input::new(2)
+            .chain(Dense::new(1, Linear::new()))
+            .with_iter([1.0, 2.0, 3.0].into_iter())
+            .unwrap();
+        encode(&network)
+    }
+
+    #[test]
+    fn test_encode() {
+        // Arrange
+        let mut expected = b"EIDETIC\0\x01\x00".to_vec();
+        expected.extend_from_slice(&ELEMENT_BITS.to_le_bytes());
+        for count in [1u64, 2, 1, 3] {
+            expected.extend_from_slice(&count.to_le_bytes());
+        }
+        for parameter in [1.0 as ElementType, 2.0, 3.0] {
+            expected.extend_from_slice(&parameter.to_le_bytes());
+        }
+
+        // Act
+        let bytes = network_bytes();
+
+        // Assert
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn test_decode() {
+        // Arrange
+        let bytes = network_bytes();
+
+        // Act
+        let output = decode(&bytes).unwrap();
+
+        // Assert
+        assert_eq!(output, ([((2, 1), 3)].to_vec(), [1.0, 2.0, 3.0].to_vec()));
+    }
+
+    #[test]
+    fn test_decode_version_mismatch() {
+        // Arrange
+        let mut bytes = network_bytes();
+        bytes[8] = 2;
+
+        // Act
+        let output = decode(&bytes);
+
+        // Assert
+        assert_eq!(output, Err(Error::UnsupportedVersion { version: 2 }));
+    }
+
+    #[test]
+    fn test_decode_element_type_mismatch() {
+        // Arrange
+        let mut bytes = network_bytes();
+        let other = 96 - ELEMENT_BITS;
+        bytes[10..12].copy_from_slice(&other.to_le_bytes());
+
+        // Act
+        let output = decode(&bytes);
+
+        // Assert
+        assert_eq!(
+            output,
+            Err(Error::ElementTypeMismatch {
+                expected: ELEMENT_BITS.into(),
+                actual: other.into()
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_failure() {
+        // Arrange
+        let bytes = network_bytes();
+        let mut extended = bytes.clone();
+        extended.push(0);
+
+        // Act
+        let truncated = decode(&bytes[..bytes.len() - 1]);
+        let extended = decode(&extended);
+        let not_eidetic = decode(b"NUMPY\0\0\0\x01\x00");
+
+        // Assert
+        assert_eq!(truncated, Err(Error::InvalidArchive));
+        assert_eq!(extended, Err(Error::InvalidArchive));
+        assert_eq!(not_eidetic, Err(Error::InvalidArchive));
+    }
+}
@@ -0,0 +1,210 @@
+use crate::{ElementType, Error, Result};
+use alloc::format;
+use alloc::vec::Vec;
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+/// The `NumPy` type description of the element type that arrays are written with.
+#[cfg(not(feature = "f32"))]
+const DESCRIPTION: &str = "<f8";
+#[cfg(feature = "f32")]
+const DESCRIPTION: &str = "<f4";
+
+/// Encodes the given values as a `NumPy` .npy array of the given shape in row major order.
+pub fn encode(shape: &[usize], values: &[ElementType]) -> Vec<u8> {
+    let shape = match shape {
+        [length] => format!("{length},"),
+        shape => shape
+            .iter()
+            .map(|length| format!("{length}"))
+            .collect::<Vec<_>>()
+            .join(", "),
+    };
+    let mut header =
+        format!("{{'descr': '{DESCRIPTION}', 'fortran_order': False, 'shape': ({shape}), }}");
+
+    // the header is padded with spaces and a trailing new line so that the data is 64 byte aligned.
+    let unpadded = MAGIC.len() + 4 + header.len() + 1;
+    header.extend(core::iter::repeat_n(
+        ' ',
+        unpadded.next_multiple_of(64) - unpadded,
+    ));
+    header.push('\n');
+    let mut bytes = Vec::with_capacity(unpadded + core::mem::size_of_val(values));
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&[1, 0]);
+    // a header that doesn't fit in version 1 would need a shape with thousands of dimensions.
+    bytes.extend_from_slice(
+        &u16::try_from(header.len())
+            .unwrap_or(u16::MAX)
+            .to_le_bytes(),
+    );
+    bytes.extend_from_slice(header.as_bytes());
+    for value in values {
+        bytes.extend_from_slice(&value.to_le_bytes());
+    }
+    bytes
+}
+
+/// Decodes a `NumPy` .npy array of 32 or 64 bit floats in row major order, returning its shape and values.
+pub fn decode(bytes: &[u8]) -> Result<(Vec<usize>, Vec<ElementType>)> {
+    let (header, data) = match bytes.strip_prefix(MAGIC) {
+        Some([1, _, a, b, rest @ ..]) => split(rest, usize::from(u16::from_le_bytes([*a, *b])))?,
+        Some([2 | 3, _, a, b, c, d, rest @ ..]) => {
+            let length = usize::try_from(u32::from_le_bytes([*a, *b, *c, *d]))
+                .map_err(|_| Error::InvalidArchive)?;
+            split(rest, length)?
+        }
+        _ => return Err(Error::InvalidArchive),
+    };
+    let header = core::str::from_utf8(header).map_err(|_| Error::InvalidArchive)?;
+    if !value(header, "fortran_order")?.starts_with("False") {
+        return Err(Error::InvalidArchive);
+    }
+    let description = value(header, "descr")?;
+    let description = description
+        .strip_prefix('\'')
+        .and_then(|description| description.split('\'').next())
+        .ok_or(Error::InvalidArchive)?;
+    let shape = value(header, "shape")?
+        .strip_prefix('(')
+        .and_then(|shape| shape.split(')').next())
+        .ok_or(Error::InvalidArchive)?
+        .split(',')
+        .map(str::trim)
+        .filter(|length| !length.is_empty())
+        .map(|length| length.parse::<usize>().map_err(|_| Error::InvalidArchive))
+        .collect::<Result<Vec<_>>>()?;
+    let values = match description {
+        "<f8" => convert(data, |bytes| f64_to_element(f64::from_le_bytes(bytes))),
+        ">f8" => convert(data, |bytes| f64_to_element(f64::from_be_bytes(bytes))),
+        "<f4" => convert(data, |bytes| f32_to_element(f32::from_le_bytes(bytes))),
+        ">f4" => convert(data, |bytes| f32_to_element(f32::from_be_bytes(bytes))),
+        _ => return Err(Error::InvalidArchive),
+    };
+    if values.len() == shape.iter().product::<usize>() {
+        Ok((shape, values))
+    } else {
+        Err(Error::InvalidArchive)
+    }
+}
+
+/// Splits the given bytes after the given length.
+fn split(bytes: &[u8], length: usize) -> Result<(&[u8], &[u8])> {
+    bytes.split_at_checked(length).ok_or(Error::InvalidArchive)
+}
+
+/// Gets the text of the value of the given key within a header, up until the end of the header.
+fn value<'a>(header: &'a str, key: &str) -> Result<&'a str> {
+    let (_, value) = header
+        .split_once(&format!("'{key}':"))
+        .ok_or(Error::InvalidArchive)?;
+    Ok(value.trim_start())
+}
+
+/// Converts data made up of elements with the given number of bytes using the given function.
+pub fn convert<const N: usize>(
+    data: &[u8],
+    f: impl Fn([u8; N]) -> ElementType,
+) -> Vec<ElementType> {
+    data.chunks_exact(N)
+        .map(|chunk| f(chunk.try_into().unwrap())) // unwrapping is safe because every chunk has N bytes
+        .collect()
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::unnecessary_cast)]
+pub const fn f64_to_element(value: f64) -> ElementType {
+    value as ElementType
+}
+
+#[allow(clippy::cast_lossless, clippy::unnecessary_cast)]
+pub const fn f32_to_element(value: f32) -> ElementType {
+    value as ElementType
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+
+    /// Decodes a header written by `encode` for inspection.
+    fn header(bytes: &[u8]) -> String {
+        let length = usize::from(u16::from_le_bytes([bytes[8], bytes[9]]));
+        String::from_utf8(bytes[10..10 + length].to_vec()).unwrap()
+    }
+
+    #[test]
+    fn test_encode() {
+        // Arrange
+        let values = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+
+        // Act
+        let bytes = encode(&[2, 3], &values);
+
+        // Assert
+        assert_eq!(&bytes[..8], b"\x93NUMPY\x01\x00");
+        assert_eq!(
+            (bytes.len() - values.len() * size_of::<ElementType>()) % 64,
+            0
+        );
+        assert!(header(&bytes).starts_with(&format!(
+            "{{'descr': '{DESCRIPTION}', 'fortran_order': False, 'shape': (2, 3), }}"
+        )));
+        assert!(header(&bytes).ends_with(" \n"));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        // Arrange
+        let values = [1.5, -2.0, 0.25];
+
+        // Act
+        let output = decode(&encode(&[3], &values)).unwrap();
+
+        // Assert
+        assert_eq!(output, ([3].to_vec(), values.to_vec()));
+    }
+
+    #[test]
+    fn test_decode_other_types() {
+        // Arrange
+        let header = "{'descr': '>f4', 'fortran_order': False, 'shape': (1, 2), }\n";
+        let mut bytes = b"\x93NUMPY\x02\x00".to_vec();
+        bytes.extend_from_slice(&u32::try_from(header.len()).unwrap().to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(&0.5f32.to_be_bytes());
+        bytes.extend_from_slice(&(-4.0f32).to_be_bytes());
+
+        // Act
+        let output = decode(&bytes).unwrap();
+
+        // Assert
+        assert_eq!(output, ([1, 2].to_vec(), [0.5, -4.0].to_vec()));
+    }
+
+    #[test]
+    fn test_decode_failure() {
+        // Arrange
+        let headers = [
+            "{'descr': '<i8', 'fortran_order': False, 'shape': (1,), }\n",
+            "{'descr': '<f8', 'fortran_order': True, 'shape': (1,), }\n",
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (2,), }\n",
+            "{'descr': '<f8', 'shape': (1,), }\n",
+        ];
+
+        // Act
+        let results = headers.map(|header| {
+            let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+            bytes.extend_from_slice(&u16::try_from(header.len()).unwrap().to_le_bytes());
+            bytes.extend_from_slice(header.as_bytes());
+            bytes.extend_from_slice(&1.0f64.to_le_bytes());
+            decode(&bytes)
+        });
+
+        // Assert
+        assert_eq!(decode(b"NUMPY"), Err(Error::InvalidArchive));
+        assert!(results
+            .into_iter()
+            .all(|result| result == Err(Error::InvalidArchive)));
+    }
+}
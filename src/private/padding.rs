@@ -2,7 +2,7 @@ use crate::ElementType;
 use ndarray::{Array, Axis, Ix1};
 
 pub fn _pad_1d(input: &Array<ElementType, Ix1>, num: usize) -> Array<ElementType, Ix1> {
-    let padding = Array::from_iter(core::iter::repeat(0.0).take(num));
+    let padding = Array::from_iter(core::iter::repeat_n(0.0, num));
     let mut output = padding.clone();
     output.append(Axis(0), input.view()).unwrap();
     output.append(Axis(0), padding.view()).unwrap();
@@ -0,0 +1,366 @@
+use crate::private::named::NamedTensor;
+use crate::private::npy::{convert, f32_to_element, f64_to_element};
+use crate::{Error, Result};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+/// The safetensors type of the element type that tensors are written with.
+#[cfg(not(feature = "f32"))]
+const DTYPE: &str = "F64";
+#[cfg(feature = "f32")]
+const DTYPE: &str = "F32";
+
+/// Encodes the given tensors in the safetensors format, which is a little endian header length
+/// followed by a JSON header describing each tensor and then the little endian data of every tensor.
+pub fn encode(tensors: &[NamedTensor]) -> Vec<u8> {
+    let mut header = String::from("{\"__metadata__\":{\"format\":\"eidetic\"}");
+    let mut data = Vec::new();
+    for tensor in tensors {
+        let start = data.len();
+        for value in &tensor.values {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        let shape = tensor
+            .shape
+            .iter()
+            .map(|length| format!("{length}"))
+            .collect::<Vec<_>>()
+            .join(",");
+        // writing into a string can't fail.
+        let _ = write!(
+            header,
+            ",{}:{{\"dtype\":\"{DTYPE}\",\"shape\":[{shape}],\"data_offsets\":[{start},{}]}}",
+            quote(&tensor.name),
+            data.len()
+        );
+    }
+    header.push('}');
+
+    // the header is padded with spaces so that the data is 8 byte aligned.
+    header.extend(core::iter::repeat_n(
+        ' ',
+        header.len().next_multiple_of(8) - header.len(),
+    ));
+    let mut bytes = Vec::with_capacity(8 + header.len() + data.len());
+    bytes.extend_from_slice(&(header.len() as u64).to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    bytes.extend_from_slice(&data);
+    bytes
+}
+
+/// Decodes the tensors of 32 or 64 bit floats from bytes in the safetensors format.
+pub fn decode(bytes: &[u8]) -> Result<Vec<NamedTensor>> {
+    let (length, rest) = bytes
+        .split_first_chunk::<8>()
+        .ok_or(Error::InvalidArchive)?;
+    let length = usize::try_from(u64::from_le_bytes(*length)).map_err(|_| Error::InvalidArchive)?;
+    let (header, data) = rest.split_at_checked(length).ok_or(Error::InvalidArchive)?;
+    let header = core::str::from_utf8(header).map_err(|_| Error::InvalidArchive)?;
+    let mut parser = Parser { text: header };
+    let Json::Object(entries) = parser.value()? else {
+        return Err(Error::InvalidArchive);
+    };
+    if !parser.text.trim().is_empty() {
+        return Err(Error::InvalidArchive);
+    }
+    entries
+        .into_iter()
+        .filter(|(name, _)| name != "__metadata__")
+        .map(|(name, tensor)| {
+            let dtype = tensor.field("dtype")?.string()?;
+            let shape = tensor
+                .field("shape")?
+                .array()?
+                .iter()
+                .map(Json::number)
+                .collect::<Result<Vec<_>>>()?;
+            let offsets = tensor
+                .field("data_offsets")?
+                .array()?
+                .iter()
+                .map(Json::number)
+                .collect::<Result<Vec<_>>>()?;
+            let [start, end] = *offsets.as_slice() else {
+                return Err(Error::InvalidArchive);
+            };
+            let data = data.get(start..end).ok_or(Error::InvalidArchive)?;
+            let (size, values) = match dtype {
+                "F64" => (
+                    8,
+                    convert(data, |bytes| f64_to_element(f64::from_le_bytes(bytes))),
+                ),
+                "F32" => (
+                    4,
+                    convert(data, |bytes| f32_to_element(f32::from_le_bytes(bytes))),
+                ),
+                _ => return Err(Error::InvalidArchive),
+            };
+            if data.len() % size != 0 || values.len() != shape.iter().product::<usize>() {
+                return Err(Error::InvalidArchive);
+            }
+            Ok(NamedTensor {
+                name,
+                shape,
+                values,
+            })
+        })
+        .collect()
+}
+
+/// Quotes the given text as a JSON string.
+fn quote(text: &str) -> String {
+    let mut quoted = String::from("\"");
+    for character in text.chars() {
+        match character {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            character if character.is_control() => {
+                let _ = write!(quoted, "\\u{:04x}", u32::from(character));
+            }
+            character => quoted.push(character),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// The subset of JSON values that can appear in a safetensors header.
+enum Json {
+    Object(Vec<(String, Self)>),
+    Array(Vec<Self>),
+    String(String),
+    Number(usize),
+}
+
+impl Json {
+    fn field(&self, key: &str) -> Result<&Self> {
+        match self {
+            Self::Object(entries) => entries
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value)
+                .ok_or(Error::InvalidArchive),
+            _ => Err(Error::InvalidArchive),
+        }
+    }
+
+    fn array(&self) -> Result<&[Self]> {
+        match self {
+            Self::Array(values) => Ok(values),
+            _ => Err(Error::InvalidArchive),
+        }
+    }
+
+    fn string(&self) -> Result<&str> {
+        match self {
+            Self::String(value) => Ok(value),
+            _ => Err(Error::InvalidArchive),
+        }
+    }
+
+    const fn number(&self) -> Result<usize> {
+        match self {
+            Self::Number(value) => Ok(*value),
+            _ => Err(Error::InvalidArchive),
+        }
+    }
+}
+
+/// A parser for the JSON header, consuming the text as values are parsed.
+struct Parser<'a> {
+    text: &'a str,
+}
+
+impl Parser<'_> {
+    /// Skips any whitespace and then consumes the given token if it's next.
+    fn eat(&mut self, token: char) -> bool {
+        self.text = self.text.trim_start();
+        self.text
+            .strip_prefix(token)
+            .map(|rest| self.text = rest)
+            .is_some()
+    }
+
+    fn expect(&mut self, token: char) -> Result<()> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(Error::InvalidArchive)
+        }
+    }
+
+    fn value(&mut self) -> Result<Json> {
+        if self.eat('{') {
+            let mut entries = Vec::new();
+            if !self.eat('}') {
+                loop {
+                    let key = self.string()?;
+                    self.expect(':')?;
+                    entries.push((key, self.value()?));
+                    if self.eat('}') {
+                        break;
+                    }
+                    self.expect(',')?;
+                }
+            }
+            Ok(Json::Object(entries))
+        } else if self.eat('[') {
+            let mut values = Vec::new();
+            if !self.eat(']') {
+                loop {
+                    values.push(self.value()?);
+                    if self.eat(']') {
+                        break;
+                    }
+                    self.expect(',')?;
+                }
+            }
+            Ok(Json::Array(values))
+        } else if self.text.starts_with('"') {
+            Ok(Json::String(self.string()?))
+        } else {
+            let length = self
+                .text
+                .find(|character: char| !character.is_ascii_digit())
+                .unwrap_or(self.text.len());
+            let (number, rest) = self.text.split_at(length);
+            self.text = rest;
+            number
+                .parse()
+                .map(Json::Number)
+                .map_err(|_| Error::InvalidArchive)
+        }
+    }
+
+    fn string(&mut self) -> Result<String> {
+        self.expect('"')?;
+        let mut string = String::new();
+        let mut characters = self.text.char_indices();
+        while let Some((index, character)) = characters.next() {
+            match character {
+                '"' => {
+                    self.text = &self.text[index + 1..];
+                    return Ok(string);
+                }
+                '\\' => {
+                    let escaped = match characters.next().ok_or(Error::InvalidArchive)?.1 {
+                        'n' => '\n',
+                        't' => '\t',
+                        'r' => '\r',
+                        'b' => '\u{8}',
+                        'f' => '\u{c}',
+                        'u' => {
+                            let code = (0..4)
+                                .map(|_| characters.next().map(|(_, digit)| digit))
+                                .collect::<Option<String>>()
+                                .ok_or(Error::InvalidArchive)?;
+                            u32::from_str_radix(&code, 16)
+                                .ok()
+                                .and_then(char::from_u32)
+                                .ok_or(Error::InvalidArchive)?
+                        }
+                        character => character,
+                    };
+                    string.push(escaped);
+                }
+                character => string.push(character),
+            }
+        }
+        Err(Error::InvalidArchive)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ElementType;
+
+    fn tensor(name: &str, shape: &[usize], values: &[ElementType]) -> NamedTensor {
+        NamedTensor {
+            name: name.into(),
+            shape: shape.to_vec(),
+            values: values.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_encode() {
+        // Arrange
+        let tensors = [tensor("layer_0.weight", &[1, 2], &[1.0, 2.0])];
+        let size = size_of::<ElementType>() * 2;
+        let expected = format!(
+            "{{\"__metadata__\":{{\"format\":\"eidetic\"}},\"layer_0.weight\":{{\"dtype\":\"{DTYPE}\",\"shape\":[1,2],\"data_offsets\":[0,{size}]}}}}"
+        );
+
+        // Act
+        let bytes = encode(&tensors);
+
+        // Assert
+        let length = usize::try_from(u64::from_le_bytes(bytes[..8].try_into().unwrap())).unwrap();
+        let header = core::str::from_utf8(&bytes[8..8 + length]).unwrap();
+        assert_eq!(length % 8, 0);
+        assert_eq!(header.trim_end(), expected);
+        assert_eq!(bytes.len(), 8 + length + size);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        // Arrange
+        let tensors = [
+            tensor("layer_0.weight", &[2, 1], &[1.0, 2.0]),
+            tensor("layer_0.bias", &[1], &[-0.5]),
+        ];
+
+        // Act
+        let output = decode(&encode(&tensors)).unwrap();
+
+        // Assert
+        assert_eq!(output, tensors);
+    }
+
+    #[test]
+    fn test_decode_other_types() {
+        // Arrange
+        let header = r#" { "a\"b" : { "dtype" : "F32", "shape" : [ 2 ], "data_offsets" : [ 0, 8 ] },
+            "__metadata__": {"note": "A\n"} } "#;
+        let mut bytes = (header.len() as u64).to_le_bytes().to_vec();
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(&0.5f32.to_le_bytes());
+        bytes.extend_from_slice(&(-4.0f32).to_le_bytes());
+
+        // Act
+        let output = decode(&bytes).unwrap();
+
+        // Assert
+        assert_eq!(output, [tensor("a\"b", &[2], &[0.5, -4.0])]);
+    }
+
+    #[test]
+    fn test_decode_failure() {
+        // Arrange
+        let headers = [
+            r#"{"a": {"dtype": "I64", "shape": [1], "data_offsets": [0, 8]}}"#,
+            r#"{"a": {"dtype": "F64", "shape": [2], "data_offsets": [0, 8]}}"#,
+            r#"{"a": {"dtype": "F64", "shape": [1], "data_offsets": [0, 16]}}"#,
+            r#"{"a": {"dtype": "F64", "shape": [1]}}"#,
+            r#"{"a": {"dtype": "F64", "shape": [1], "data_offsets": [0, 8]}"#,
+            r#"["a"]"#,
+        ];
+
+        // Act
+        let results = headers.map(|header| {
+            let mut bytes = (header.len() as u64).to_le_bytes().to_vec();
+            bytes.extend_from_slice(header.as_bytes());
+            bytes.extend_from_slice(&1.0f64.to_le_bytes());
+            decode(&bytes)
+        });
+
+        // Assert
+        assert_eq!(decode(b"short"), Err(Error::InvalidArchive));
+        assert!(results
+            .into_iter()
+            .all(|result| result == Err(Error::InvalidArchive)));
+    }
+}
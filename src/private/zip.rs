@@ -0,0 +1,266 @@
+use crate::{Error, Result};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+const LOCAL_HEADER: u32 = 0x0403_4b50;
+const CENTRAL_HEADER: u32 = 0x0201_4b50;
+const END_OF_CENTRAL_DIRECTORY: u32 = 0x0605_4b50;
+const ZIP64_EXTRA: u16 = 0x0001;
+
+/// The version of the zip format needed to extract entries that are stored without compression.
+const VERSION: u16 = 20;
+
+/// The date (1980-01-01) that every entry is given in MS-DOS format, so that archives are reproducible.
+const DATE: u16 = 0x21;
+
+/// Calculates the CRC-32 checksum of the given bytes as used by the zip format.
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0, |crc, byte| {
+        (0..8).fold(crc ^ u32::from(*byte), |crc, _| {
+            (crc >> 1) ^ (0xedb8_8320 & (crc & 1).wrapping_neg())
+        })
+    })
+}
+
+fn to_u16(value: usize) -> Result<u16> {
+    u16::try_from(value).map_err(|_| Error::InvalidArchive)
+}
+
+fn to_u32(value: usize) -> Result<u32> {
+    u32::try_from(value).map_err(|_| Error::InvalidArchive)
+}
+
+/// Writes the given named files into a zip archive, storing them without compression.
+pub fn write(files: &[(String, Vec<u8>)]) -> Result<Vec<u8>> {
+    let mut archive = Vec::new();
+    let mut directory = Vec::new();
+    for (name, data) in files {
+        let offset = to_u32(archive.len())?;
+        let mut header = Vec::new();
+        for field in [VERSION, 0, 0, 0, DATE] {
+            header.extend_from_slice(&field.to_le_bytes());
+        }
+        for field in [crc32(data), to_u32(data.len())?, to_u32(data.len())?] {
+            header.extend_from_slice(&field.to_le_bytes());
+        }
+        header.extend_from_slice(&to_u16(name.len())?.to_le_bytes());
+        header.extend_from_slice(&0u16.to_le_bytes());
+
+        archive.extend_from_slice(&LOCAL_HEADER.to_le_bytes());
+        archive.extend_from_slice(&header);
+        archive.extend_from_slice(name.as_bytes());
+        archive.extend_from_slice(data);
+
+        directory.extend_from_slice(&CENTRAL_HEADER.to_le_bytes());
+        directory.extend_from_slice(&VERSION.to_le_bytes());
+        directory.extend_from_slice(&header);
+        // no comment, starting on the first disk, and no file attributes.
+        for field in [0u16; 3] {
+            directory.extend_from_slice(&field.to_le_bytes());
+        }
+        directory.extend_from_slice(&0u32.to_le_bytes());
+        directory.extend_from_slice(&offset.to_le_bytes());
+        directory.extend_from_slice(name.as_bytes());
+    }
+    let offset = to_u32(archive.len())?;
+    let count = to_u16(files.len())?;
+    archive.extend_from_slice(&directory);
+    archive.extend_from_slice(&END_OF_CENTRAL_DIRECTORY.to_le_bytes());
+    for field in [0, 0, count, count] {
+        archive.extend_from_slice(&field.to_le_bytes());
+    }
+    archive.extend_from_slice(&to_u32(directory.len())?.to_le_bytes());
+    archive.extend_from_slice(&offset.to_le_bytes());
+    archive.extend_from_slice(&0u16.to_le_bytes());
+    Ok(archive)
+}
+
+/// Reads the named files from a zip archive. Only files that are stored without compression are
+/// supported, though (as written by Python's zipfile module) sizes may be held in zip64 extra fields.
+pub fn read(archive: &[u8]) -> Result<Vec<(String, &[u8])>> {
+    let end = (0..archive.len().saturating_sub(21))
+        .rev()
+        .find(|&offset| read_u32(archive, offset) == Ok(END_OF_CENTRAL_DIRECTORY))
+        .ok_or(Error::InvalidArchive)?;
+    let count = read_u16(archive, end + 10)?;
+    let mut offset =
+        usize::try_from(read_u32(archive, end + 16)?).map_err(|_| Error::InvalidArchive)?;
+    let mut files = Vec::new();
+    for _ in 0..count {
+        if read_u32(archive, offset)? != CENTRAL_HEADER || read_u16(archive, offset + 10)? != 0 {
+            return Err(Error::InvalidArchive);
+        }
+        let crc = read_u32(archive, offset + 16)?;
+        let name_length = usize::from(read_u16(archive, offset + 28)?);
+        let extra_length = usize::from(read_u16(archive, offset + 30)?);
+        let comment_length = usize::from(read_u16(archive, offset + 32)?);
+        let name = slice(archive, offset + 46, name_length)?;
+        let extra = slice(archive, offset + 46 + name_length, extra_length)?;
+
+        // sizes and offsets which don't fit are replaced by the maximum value and moved into
+        // the zip64 extra field, in the order of uncompressed size, compressed size then offset.
+        let mut large = zip64_fields(extra)?;
+        let mut field = |position| -> Result<usize> {
+            let value = read_u32(archive, offset + position)?;
+            let value = if value == u32::MAX {
+                large.next().ok_or(Error::InvalidArchive)?
+            } else {
+                value.into()
+            };
+            usize::try_from(value).map_err(|_| Error::InvalidArchive)
+        };
+        let size = field(24)?;
+        if field(20)? != size {
+            return Err(Error::InvalidArchive);
+        }
+        let local = field(42)?;
+
+        if read_u32(archive, local)? != LOCAL_HEADER {
+            return Err(Error::InvalidArchive);
+        }
+        let start = local
+            + 30
+            + usize::from(read_u16(archive, local + 26)?)
+            + usize::from(read_u16(archive, local + 28)?);
+        let data = slice(archive, start, size)?;
+        if crc32(data) != crc {
+            return Err(Error::InvalidArchive);
+        }
+        let name = String::from_utf8(name.to_vec()).map_err(|_| Error::InvalidArchive)?;
+        files.push((name, data));
+        offset += 46 + name_length + extra_length + comment_length;
+    }
+    Ok(files)
+}
+
+/// Gets an iterator over the 64 bit values in the zip64 field of the given extra fields.
+fn zip64_fields(mut extra: &[u8]) -> Result<impl Iterator<Item = u64> + '_> {
+    let mut fields: &[u8] = &[];
+    while extra.len() >= 4 {
+        let length = usize::from(read_u16(extra, 2)?);
+        let data = slice(extra, 4, length)?;
+        if read_u16(extra, 0)? == ZIP64_EXTRA {
+            fields = data;
+            break;
+        }
+        extra = &extra[4 + length..];
+    }
+    Ok(fields
+        .chunks_exact(8)
+        .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))) // unwrapping is safe because every chunk has 8 bytes
+}
+
+fn slice(bytes: &[u8], offset: usize, length: usize) -> Result<&[u8]> {
+    bytes
+        .get(offset..offset + length)
+        .ok_or(Error::InvalidArchive)
+}
+
+fn read_u16(bytes: &[u8], offset: usize) -> Result<u16> {
+    let bytes = slice(bytes, offset, 2)?;
+    Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> Result<u32> {
+    let bytes = slice(bytes, offset, 4)?;
+    Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crc32() {
+        // Assert
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        // Arrange
+        let files = [
+            (String::from("a.npy"), b"first".to_vec()),
+            (String::from("b.npy"), b"second file".to_vec()),
+        ];
+
+        // Act
+        let archive = write(&files).unwrap();
+        let output = read(&archive).unwrap();
+
+        // Assert
+        assert_eq!(
+            output,
+            [
+                ("a.npy".into(), &b"first"[..]),
+                ("b.npy".into(), &b"second file"[..])
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_zip64_sizes() {
+        // Arrange
+        // an archive as written by Python's zipfile module with force_zip64 set, where the
+        // local header has a zip64 extra field and the central directory has its sizes moved into one.
+        let data = b"data";
+        let mut archive = LOCAL_HEADER.to_le_bytes().to_vec();
+        for field in [45u16, 0, 0, 0, DATE] {
+            archive.extend_from_slice(&field.to_le_bytes());
+        }
+        archive.extend_from_slice(&crc32(data).to_le_bytes());
+        archive.extend_from_slice(&[0xff; 8]);
+        archive.extend_from_slice(&5u16.to_le_bytes());
+        archive.extend_from_slice(&20u16.to_le_bytes());
+        archive.extend_from_slice(b"x.npy");
+        archive.extend_from_slice(&ZIP64_EXTRA.to_le_bytes());
+        archive.extend_from_slice(&16u16.to_le_bytes());
+        archive.extend_from_slice(&[4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0]);
+        archive.extend_from_slice(data);
+        let directory = archive.len();
+        archive.extend_from_slice(&CENTRAL_HEADER.to_le_bytes());
+        for field in [45u16, 45, 0, 0, 0, DATE] {
+            archive.extend_from_slice(&field.to_le_bytes());
+        }
+        archive.extend_from_slice(&crc32(data).to_le_bytes());
+        archive.extend_from_slice(&[0xff; 8]);
+        for field in [5u16, 20, 0, 0, 0] {
+            archive.extend_from_slice(&field.to_le_bytes());
+        }
+        archive.extend_from_slice(&[0; 8]);
+        archive.extend_from_slice(b"x.npy");
+        archive.extend_from_slice(&ZIP64_EXTRA.to_le_bytes());
+        archive.extend_from_slice(&16u16.to_le_bytes());
+        archive.extend_from_slice(&[4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0]);
+        let size = archive.len() - directory;
+        archive.extend_from_slice(&END_OF_CENTRAL_DIRECTORY.to_le_bytes());
+        for field in [0u16, 0, 1, 1] {
+            archive.extend_from_slice(&field.to_le_bytes());
+        }
+        archive.extend_from_slice(&u32::try_from(size).unwrap().to_le_bytes());
+        archive.extend_from_slice(&u32::try_from(directory).unwrap().to_le_bytes());
+        archive.extend_from_slice(&0u16.to_le_bytes());
+
+        // Act
+        let output = read(&archive).unwrap();
+
+        // Assert
+        assert_eq!(output, [("x.npy".into(), &data[..])]);
+    }
+
+    #[test]
+    fn test_read_failure() {
+        // Arrange
+        let files = [(String::from("a.npy"), b"first".to_vec())];
+        let mut corrupted = write(&files).unwrap();
+        corrupted[36] ^= 1;
+
+        // Act
+        let not_archive = read(b"not an archive");
+        let corrupted = read(&corrupted);
+
+        // Assert
+        assert_eq!(not_archive, Err(Error::InvalidArchive));
+        assert_eq!(corrupted, Err(Error::InvalidArchive));
+    }
+}
@@ -0,0 +1,330 @@
+//! This module contains the post-training quantization of initialised networks, which converts the
+//! weights of their dense layers into 8 bit integers for inference. This makes the weights four (or eight)
+//! times smaller and replaces floating point multiplication with integer multiplication, at the cost
+//! of a small loss of accuracy.
+//!
+//! A network is quantized by calling `quantize` on it with some representative input data, which is run
+//! through the network to calibrate the range of the inputs to each dense layer. The resulting
+//! `QuantizedNetwork` can only be used for inference.
+//!
+//! ```
+//! use eidetic::activations::{Linear, ReLU};
+//! use eidetic::layers::{Chain, Dense, Input};
+//! use eidetic::operations::{InitialisedOperation, UninitialisedOperation};
+//! use eidetic::quantization::Granularity;
+//! use eidetic::tensors::{rank, Tensor};
+//! let network = Input::new(2)
+//!     .chain(Dense::new(4, ReLU::new()))
+//!     .chain(Dense::new(1, Linear::new()))
+//!     .with_seed(42);
+//! let calibration = Tensor::<rank::Two>::new((2, 2), [1.0, 2.0, -1.0, 0.5]).unwrap();
+//! let quantized = network.quantize(&calibration, Granularity::PerChannel).unwrap();
+//! let output = quantized.predict(calibration).unwrap();
+//! assert_eq!(output.dim(), (2, 1));
+//! ```
+
+use crate::operations::Stage;
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+use ndarray::{Array, Axis, Ix2};
+
+/// The largest magnitude of a quantized value. The range is kept symmetric so that zero is always
+/// represented exactly, and -128 is never used.
+const MAX_QUANTIZED: ElementType = 127.0;
+
+/// How finely the scales used to quantize the weights of a layer are chosen.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Granularity {
+    /// A single scale is used for all the weights of a layer.
+    PerTensor,
+    /// A scale is used for the weights of each output neuron (column) of a layer, which is more accurate
+    /// when the weights of the neurons have very different ranges.
+    PerChannel,
+}
+
+/// The weights of a dense layer quantized to 8 bit integers, along with the scales which convert them
+/// (and the quantized inputs) back into real values.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct QuantizedWeights {
+    weights: Array<i8, Ix2>,
+    scales: Vec<ElementType>, // the scale of each output neuron
+    input_scale: ElementType,
+}
+
+impl QuantizedWeights {
+    /// Quantizes the given weights, given the largest magnitude of the inputs seen during calibration.
+    fn new(
+        weights: &Tensor<rank::Two>,
+        input_range: ElementType,
+        granularity: Granularity,
+    ) -> Self {
+        let scales = match granularity {
+            Granularity::PerTensor => {
+                let scale = scale(weights.0.iter());
+                (0..weights.0.ncols()).map(|_| scale).collect()
+            }
+            Granularity::PerChannel => weights
+                .0
+                .axis_iter(Axis(1))
+                .map(|column| scale(column.iter()))
+                .collect::<Vec<_>>(),
+        };
+        let mut quantized = Array::zeros(weights.0.raw_dim());
+        for ((row, column), weight) in weights.0.indexed_iter() {
+            quantized[[row, column]] = quantize(*weight, scales[column]);
+        }
+        Self {
+            weights: quantized,
+            scales,
+            input_scale: scale_of(input_range),
+        }
+    }
+
+    /// Multiplies the input by the weights in integer arithmetic, and rescales the result.
+    fn predict(&self, input: &Tensor<rank::Two>) -> Result<Tensor<rank::Two>> {
+        Error::check_size(self.weights.nrows(), input.0.ncols())?;
+        let input = input
+            .0
+            .mapv(|elem| i32::from(quantize(elem, self.input_scale)));
+        let weights = self.weights.mapv(i32::from);
+        let mut output = input.dot(&weights).mapv(to_element);
+        for (mut column, scale) in output.axis_iter_mut(Axis(1)).zip(&self.scales) {
+            column *= self.input_scale * scale;
+        }
+        Ok(Tensor(output))
+    }
+}
+
+/// A stage of a quantized network, which either multiplies by quantized weights or runs at full precision.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum QuantizedStage {
+    WeightMultiply(QuantizedWeights),
+    Unchanged(Stage),
+}
+
+/// A network whose dense layers have had their weights quantized to 8 bit integers, which is produced
+/// by calling `quantize` on an initialised network. Biases and activation functions are kept at full
+/// precision, so only the weight multiplications are done in integer arithmetic.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QuantizedNetwork {
+    stages: Vec<QuantizedStage>,
+}
+
+impl QuantizedNetwork {
+    /// Quantizes the given stages of a network, running the calibration data through them at full
+    /// precision to find the range of the input to each weight multiplication.
+    pub(crate) fn new(
+        stages: Vec<Stage>,
+        calibration: &Tensor<rank::Two>,
+        granularity: Granularity,
+    ) -> Result<Self> {
+        if calibration.0.is_empty() {
+            return Err(Error::EmptyData);
+        }
+        let mut input = calibration.clone();
+        let mut quantized = Vec::with_capacity(stages.len());
+        for stage in stages {
+            if let Stage::WeightMultiply(ref operation) = stage {
+                let range = input
+                    .0
+                    .iter()
+                    .fold(0.0, |max: ElementType, elem| max.max(elem.abs()));
+                quantized.push(QuantizedStage::WeightMultiply(QuantizedWeights::new(
+                    &operation.parameter,
+                    range,
+                    granularity,
+                )));
+            }
+            input = stage.predict(input)?;
+            if !matches!(stage, Stage::WeightMultiply(_)) {
+                quantized.push(QuantizedStage::Unchanged(stage));
+            }
+        }
+        Ok(Self { stages: quantized })
+    }
+
+    /// Runs the input through the quantized network to produce a prediction.
+    ///
+    /// # Errors
+    /// `Error` if the prediction fails such as if the input is incorrectly shaped.
+    pub fn predict(&self, input: Tensor<rank::Two>) -> Result<Tensor<rank::Two>> {
+        self.stages
+            .iter()
+            .try_fold(input, |input, stage| match stage {
+                QuantizedStage::WeightMultiply(weights) => weights.predict(&input),
+                QuantizedStage::Unchanged(stage) => stage.predict(input),
+            })
+    }
+}
+
+/// Gets the scale which maps the largest magnitude of the given values onto the largest quantized value.
+fn scale<'a>(values: impl Iterator<Item = &'a ElementType>) -> ElementType {
+    scale_of(values.fold(0.0, |max: ElementType, elem| max.max(elem.abs())))
+}
+
+/// Gets the scale which maps the given range onto the largest quantized value. A range of zero
+/// would give a scale of zero, so a scale of one is used instead as every value quantizes to zero anyway.
+fn scale_of(range: ElementType) -> ElementType {
+    if range > 0.0 {
+        range / MAX_QUANTIZED
+    } else {
+        1.0
+    }
+}
+
+/// Quantizes a value with the given scale, saturating values outside of the quantized range.
+#[allow(clippy::cast_possible_truncation)]
+fn quantize(value: ElementType, scale: ElementType) -> i8 {
+    (value / scale).round().clamp(-MAX_QUANTIZED, MAX_QUANTIZED) as i8
+}
+
+/// Converts an accumulated integer into an element.
+#[allow(clippy::cast_lossless, clippy::cast_precision_loss)]
+const fn to_element(value: i32) -> ElementType {
+    value as ElementType
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::{Linear, ReLU, Tanh};
+    use crate::layers::{Chain, Dense, Dropout, Input, Merge, Parallel};
+    use crate::operations::{InitialisedOperation, UninitialisedOperation};
+
+    /// Gets the largest absolute difference between the elements of two tensors.
+    fn max_error(lhs: &Tensor<rank::Two>, rhs: &Tensor<rank::Two>) -> ElementType {
+        lhs.0
+            .iter()
+            .zip(rhs.0.iter())
+            .fold(0.0, |max: ElementType, (lhs, rhs)| {
+                max.max((lhs - rhs).abs())
+            })
+    }
+
+    fn input() -> Tensor<rank::Two> {
+        Tensor::<rank::Two>::new(
+            (8, 3),
+            (0_u8..24).map(|elem| ElementType::from(elem) / 12.0 - 1.0),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_quantize() {
+        // Arrange
+        let network = Input::new(3)
+            .chain(Dense::new(6, ReLU::new()))
+            .chain(Dense::new(2, Tanh::new()))
+            .with_seed(42);
+        let input = input();
+        let expected = network.predict(input.clone()).unwrap();
+
+        // Act
+        let quantized = network.quantize(&input, Granularity::PerTensor).unwrap();
+        let output = quantized.predict(input).unwrap();
+
+        // Assert
+        assert_eq!(output.dim(), expected.dim());
+        assert!(max_error(&output, &expected) < 0.05);
+    }
+
+    #[test]
+    fn test_quantize_per_channel() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(2, Linear::new()))
+            .with_iter([100.0, 0.01, -50.0, 0.02, 0.0, 0.0].into_iter())
+            .unwrap();
+        let input = Tensor::<rank::Two>::new((2, 2), [0.3, 0.7, -0.9, 0.1]).unwrap();
+        let expected = network.predict(input.clone()).unwrap();
+
+        // Act
+        let per_tensor = network.quantize(&input, Granularity::PerTensor).unwrap();
+        let per_channel = network.quantize(&input, Granularity::PerChannel).unwrap();
+        let per_tensor = per_tensor.predict(input.clone()).unwrap();
+        let per_channel = per_channel.predict(input).unwrap();
+
+        // Assert
+        // the small weights of the second neuron all quantize to zero with a single scale for the layer.
+        let column =
+            |output: &Tensor<rank::Two>| Tensor(output.0.column(1).to_owned().insert_axis(Axis(1)));
+        assert_eq!(
+            column(&per_tensor),
+            Tensor::<rank::Two>::new((2, 1), [0.0, 0.0]).unwrap()
+        );
+        assert!(max_error(&column(&per_channel), &column(&expected)) < 0.001);
+    }
+
+    #[test]
+    fn test_quantize_dropout() {
+        // Arrange
+        let network = Input::new(1)
+            .chain(Dense::new(1, Linear::new()))
+            .chain(Dropout::new(0.5))
+            .with_iter([2.0, 1.0].into_iter())
+            .unwrap();
+        let input = Tensor::<rank::Two>::new((1, 1), [1.0]).unwrap();
+
+        // Act
+        let quantized = network.quantize(&input, Granularity::PerTensor).unwrap();
+        let output = quantized.predict(input.clone()).unwrap();
+
+        // Assert
+        assert_eq!(output, network.predict(input).unwrap());
+    }
+
+    #[test]
+    fn test_quantize_failure() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Parallel::new(
+                Dense::new(1, Linear::new()),
+                Dense::new(1, Linear::new()),
+                Merge::Add,
+            ))
+            .with_seed(42);
+        let input = Tensor::<rank::Two>::new((1, 2), [1.0, 2.0]).unwrap();
+        let empty = Tensor::<rank::Two>::new((0, 2), []).unwrap();
+        let dense = Input::new(2)
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42);
+
+        // Act
+        let unsupported = network.quantize(&input, Granularity::PerTensor);
+        let empty = dense.quantize(&empty, Granularity::PerTensor);
+
+        // Assert
+        assert_eq!(unsupported, Err(Error::UnsupportedLayer));
+        assert_eq!(empty, Err(Error::EmptyData));
+    }
+
+    #[test]
+    fn test_predict_failure() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42);
+        let calibration = Tensor::<rank::Two>::new((1, 2), [1.0, 2.0]).unwrap();
+        let quantized = network
+            .quantize(&calibration, Granularity::PerChannel)
+            .unwrap();
+        let input = Tensor::<rank::Two>::new((1, 3), [1.0, 2.0, 3.0]).unwrap();
+
+        // Act
+        let output = quantized.predict(input);
+
+        // Assert
+        assert_eq!(
+            output,
+            Err(Error::ShapeMismatch {
+                expected: 2,
+                actual: 3
+            })
+        );
+    }
+}
@@ -0,0 +1,263 @@
+//! This module contains the types used to inspect an initialised network. A summary lists each layer
+//! along with the shape of its output and its number of parameters in the same way as Keras' `model.summary()`,
+//! which can be used to check the structure of a network before committing to a long training run.
+//!
+//! ```
+//! use eidetic::activations::{ReLU, Sigmoid};
+//! use eidetic::layers::{Chain, Dense, Input};
+//! use eidetic::operations::UninitialisedOperation;
+//! let network = Input::new(4)
+//!     .chain(Dense::new(8, ReLU::new()))
+//!     .chain(Dense::new(1, Sigmoid::new()))
+//!     .with_seed(42);
+//! let summary = network.summary();
+//! assert_eq!(summary.total_parameters(), 49);
+//! println!("{summary}");
+//! ```
+//!
+//! The output of every layer for some input can also be captured with `predict_with_activations`, which
+//! can be used to find dead units or to visualise the features a network has learned.
+
+use crate::tensors::rank::Rank;
+use crate::tensors::Tensor;
+use crate::{ElementType, Error, Result};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+use ndarray::{Array, Dimension, IxDyn};
+
+/// The summary of a single layer of a network.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LayerSummary {
+    name: &'static str,
+    output_shape: Vec<Option<usize>>,
+    parameters: usize,
+}
+
+impl LayerSummary {
+    pub(crate) const fn new(
+        name: &'static str,
+        output_shape: Vec<Option<usize>>,
+        parameters: usize,
+    ) -> Self {
+        Self {
+            name,
+            output_shape,
+            parameters,
+        }
+    }
+
+    /// Gets the name of the type of the layer, such as "Dense".
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Gets the shape of the output of the layer, starting with the batch axis. Sizes that aren't
+    /// known until the network is given some input (such as the batch size, the height and width of
+    /// images, or the length of sequences) are `None`.
+    #[must_use]
+    pub fn output_shape(&self) -> &[Option<usize>] {
+        &self.output_shape
+    }
+
+    /// Gets the number of parameters of the layer.
+    #[must_use]
+    pub const fn parameters(&self) -> usize {
+        self.parameters
+    }
+}
+
+/// The summary of an initialised network, holding a summary of each of its layers in the order
+/// they were chained. The summary can be displayed as a table, with the total number of parameters
+/// at the bottom.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Summary {
+    layers: Vec<LayerSummary>,
+}
+
+impl Summary {
+    pub(crate) const fn new(layers: Vec<LayerSummary>) -> Self {
+        Self { layers }
+    }
+
+    /// Gets the summaries of the layers of the network, in the order they were chained.
+    #[must_use]
+    pub fn layers(&self) -> &[LayerSummary] {
+        &self.layers
+    }
+
+    /// Gets the total number of parameters of every layer in the network.
+    #[must_use]
+    pub fn total_parameters(&self) -> usize {
+        self.layers.iter().map(LayerSummary::parameters).sum()
+    }
+}
+
+/// The output of a single layer of a network for some input, as captured by `predict_with_activations`.
+/// Since layers produce tensors of different ranks, the output is held without a rank and can be
+/// converted back to a tensor of the right rank with `to_tensor`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LayerOutput {
+    name: &'static str,
+    output: Array<ElementType, IxDyn>,
+}
+
+impl LayerOutput {
+    pub(crate) fn new<R: Rank>(name: &'static str, output: &Tensor<R>) -> Self {
+        Self {
+            name,
+            output: output.0.clone().into_dyn(),
+        }
+    }
+
+    /// Gets the name of the type of the layer, such as "Dense".
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Gets the shape of the output of the layer, starting with the batch axis.
+    #[must_use]
+    pub fn shape(&self) -> &[usize] {
+        self.output.shape()
+    }
+
+    /// Gets an iterator over the elements of the output of the layer in row major order.
+    pub fn iter(&self) -> impl Iterator<Item = ElementType> + '_ {
+        self.output.iter().copied()
+    }
+
+    /// Converts the output of the layer into a tensor of the given rank.
+    ///
+    /// # Errors
+    /// `Error` if the output of the layer doesn't have the given rank.
+    pub fn to_tensor<R: Rank>(&self) -> Result<Tensor<R>> {
+        let rank = self.output.ndim();
+        self.output
+            .clone()
+            .into_dimensionality::<R::Internal>()
+            .map(Tensor)
+            .map_err(|_| Error::ShapeMismatch {
+                expected: R::Internal::NDIM.unwrap_or(rank),
+                actual: rank,
+            })
+    }
+}
+
+/// Formats the given output shape as a tuple, such as "(None, 10)".
+fn format_shape(shape: &[Option<usize>]) -> String {
+    let sizes = shape
+        .iter()
+        .map(|size| size.map_or_else(|| String::from("None"), |size| format!("{size}")))
+        .collect::<Vec<_>>();
+    match sizes.as_slice() {
+        [size] => format!("({size},)"),
+        sizes => format!("({})", sizes.join(", ")),
+    }
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let rows = self
+            .layers
+            .iter()
+            .map(|layer| {
+                (
+                    layer.name,
+                    format_shape(&layer.output_shape),
+                    format!("{}", layer.parameters),
+                )
+            })
+            .collect::<Vec<_>>();
+        let name_width = rows
+            .iter()
+            .map(|(name, _, _)| name.len())
+            .fold("Layer".len(), usize::max);
+        let shape_width = rows
+            .iter()
+            .map(|(_, shape, _)| shape.len())
+            .fold("Output Shape".len(), usize::max);
+        writeln!(
+            f,
+            "{:name_width$}  {:shape_width$}  Parameters",
+            "Layer", "Output Shape"
+        )?;
+        for (name, shape, parameters) in rows {
+            writeln!(f, "{name:name_width$}  {shape:shape_width$}  {parameters}")?;
+        }
+        write!(f, "Total parameters: {}", self.total_parameters())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensors::rank;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_total_parameters() {
+        // Arrange
+        let summary = Summary::new(
+            [
+                LayerSummary::new("Input", [None, Some(2)].to_vec(), 0),
+                LayerSummary::new("Dense", [None, Some(3)].to_vec(), 9),
+                LayerSummary::new("Dense", [None, Some(1)].to_vec(), 4),
+            ]
+            .to_vec(),
+        );
+
+        // Act
+        let total = summary.total_parameters();
+
+        // Assert
+        assert_eq!(total, 13);
+    }
+
+    #[test]
+    fn test_layer_output() {
+        // Arrange
+        let tensor = Tensor::<rank::Two>::new((2, 2), [0.0, 1.0, 2.0, 0.0]).unwrap();
+
+        // Act
+        let output = LayerOutput::new("Dense", &tensor);
+
+        // Assert
+        assert_eq!(output.name(), "Dense");
+        assert_eq!(output.shape(), [2, 2]);
+        assert_eq!(output.iter().collect::<Vec<_>>(), [0.0, 1.0, 2.0, 0.0]);
+        assert_eq!(output.to_tensor::<rank::Two>(), Ok(tensor));
+        assert_eq!(
+            output.to_tensor::<rank::Four>(),
+            Err(Error::ShapeMismatch {
+                expected: 4,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        // Arrange
+        let summary = Summary::new(
+            [
+                LayerSummary::new("ImageInput", [None, Some(3), None, None].to_vec(), 0),
+                LayerSummary::new("Reshape", [Some(12)].to_vec(), 0),
+            ]
+            .to_vec(),
+        );
+        let expected = "\
+Layer       Output Shape           Parameters
+ImageInput  (None, 3, None, None)  0
+Reshape     (12,)                  0
+Total parameters: 0";
+
+        // Act
+        let output = summary.to_string();
+
+        // Assert
+        assert_eq!(output, expected);
+    }
+}
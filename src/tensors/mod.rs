@@ -6,8 +6,13 @@
 pub mod rank;
 
 use crate::{ElementType, Error, Result};
-use ndarray::{arr0, Array, Ix1, Ix2};
-use rank::Rank;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::ops::{Bound, RangeBounds};
+use ndarray::{arr0, Array, Axis, Ix1, Ix2, Slice};
+use rand::distributions::{Distribution, Uniform};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rank::{Batched, Rank};
 
 /// Represents a tensor with a specific rank
 /// given by the R generic type parameter.
@@ -18,7 +23,25 @@ use rank::Rank;
 /// All operations in Eidetic will use the rank of the tensor to check at compile time
 /// if operations and layers are connected correctly and won't allow mismatching ranks to
 /// be connected depending on what the layer input/output supports.
-#[derive(Clone, Debug, Default, PartialEq)]
+///
+/// Tensors are displayed with their elements laid out in nested rows, and large tensors are
+/// truncated to the elements at the start and end of each axis. The precision given in the format
+/// string is applied to each element, and the alternate flag (`{:#}`) shows every element.
+///
+/// ```
+/// use eidetic::tensors::{Tensor, rank};
+/// let tensor = Tensor::<rank::Two>::new((2, 2), [1.0, 2.5, -3.0, 4.25]).unwrap();
+/// assert_eq!(format!("{tensor:.2}"), "[[1.00, 2.50],\n [-3.00, 4.25]]");
+/// ```
+#[derive(Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "R::Internal: serde::Serialize",
+        deserialize = "R::Internal: serde::Deserialize<'de>"
+    ))
+)]
 pub struct Tensor<R: Rank>(pub(crate) Array<ElementType, R::Internal>);
 
 impl Tensor<rank::Zero> {
@@ -36,6 +59,12 @@ impl Tensor<rank::One> {
     pub fn new(iter: impl IntoIterator<Item = ElementType>) -> Self {
         Self(Array::from_iter(iter))
     }
+
+    /// Gets the number of elements along the single axis of the tensor.
+    #[must_use]
+    pub fn dim(&self) -> usize {
+        self.0.dim()
+    }
 }
 
 impl Tensor<rank::Two> {
@@ -46,9 +75,91 @@ impl Tensor<rank::Two> {
     /// `Error` if the provided number of elements does not match the requested shape.
     pub fn new(shape: (usize, usize), iter: impl IntoIterator<Item = ElementType>) -> Result<Self> {
         let array: Array<ElementType, Ix1> = Array::from_iter(iter);
-        let array: Array<ElementType, Ix2> = array.into_shape(shape).map_err(|_| Error(()))?;
+        let actual = array.len();
+        let array: Array<ElementType, Ix2> =
+            array.into_shape(shape).map_err(|_| Error::ShapeMismatch {
+                expected: shape.0 * shape.1,
+                actual,
+            })?;
         Ok(Self(array))
     }
+
+    /// Gets the shape of the tensor as a (rows, columns) tuple.
+    #[must_use]
+    pub fn dim(&self) -> (usize, usize) {
+        self.0.dim()
+    }
+
+    /// Gets the number of rows in the tensor, which is usually the number of observations in a batch.
+    #[must_use]
+    pub fn nrows(&self) -> usize {
+        self.0.nrows()
+    }
+
+    /// Gets the number of columns in the tensor, which is usually the number of features or neurons.
+    #[must_use]
+    pub fn ncols(&self) -> usize {
+        self.0.ncols()
+    }
+
+    /// Gets the transpose of the tensor, where the rows become the columns.
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        Self(self.0.t().as_standard_layout().into_owned())
+    }
+
+    /// Calculates the matrix product of this tensor with the given tensor.
+    ///
+    /// # Errors
+    /// `Error` if the number of columns of this tensor doesn't match the number of rows of the given tensor.
+    pub fn matmul(&self, other: &Self) -> Result<Self> {
+        Error::check_size(self.ncols(), other.nrows())?;
+        Ok(Self(self.0.dot(&other.0)))
+    }
+
+    /// Gets the row at the given index, which is usually a single observation of a batch.
+    ///
+    /// # Errors
+    /// `Error` if the index is out of range.
+    pub fn row(&self, index: usize) -> Result<Tensor<rank::One>> {
+        self.item(index)
+    }
+
+    /// Gets an iterator over the rows of the tensor in order.
+    pub fn rows(&self) -> impl Iterator<Item = Tensor<rank::One>> + '_ {
+        self.items()
+    }
+
+    /// Gets the part of the tensor within the given ranges of rows and columns.
+    ///
+    /// # Errors
+    /// `Error` if either range isn't within the tensor.
+    pub fn slice(
+        &self,
+        rows: impl RangeBounds<usize>,
+        columns: impl RangeBounds<usize>,
+    ) -> Result<Self> {
+        let rows = range(&rows, self.nrows())?;
+        let columns = range(&columns, self.ncols())?;
+        Ok(Self(
+            self.0
+                .slice_axis(Axis(0), Slice::from(rows))
+                .slice_axis(Axis(1), Slice::from(columns))
+                .to_owned(),
+        ))
+    }
+
+    /// Places the columns of the given tensor after the columns of this tensor, such as to
+    /// join the features of the two inputs of a `MultiInput` layer into a single input.
+    ///
+    /// # Errors
+    /// `Error` if the tensors don't have the same number of rows.
+    pub fn concatenate_columns(&self, other: &Self) -> Result<Self> {
+        Error::check_size(self.nrows(), other.nrows())?;
+        ndarray::concatenate(Axis(1), &[self.0.view(), other.0.view()])
+            .map(Self)
+            .map_err(|_| Error::InvalidArgument)
+    }
 }
 
 impl Tensor<rank::Three> {
@@ -61,11 +172,22 @@ impl Tensor<rank::Three> {
         shape: (usize, usize, usize),
         iter: impl IntoIterator<Item = ElementType>,
     ) -> Result<Self> {
-        Array::from_iter(iter)
+        let array: Array<ElementType, Ix1> = Array::from_iter(iter);
+        let actual = array.len();
+        array
             .into_shape(shape)
-            .map_err(|_| Error(()))
+            .map_err(|_| Error::ShapeMismatch {
+                expected: shape.0 * shape.1 * shape.2,
+                actual,
+            })
             .map(Self)
     }
+
+    /// Gets the shape of the tensor as a tuple, in the same order as it was constructed with.
+    #[must_use]
+    pub fn dim(&self) -> (usize, usize, usize) {
+        self.0.dim()
+    }
 }
 
 impl Tensor<rank::Four> {
@@ -78,11 +200,22 @@ impl Tensor<rank::Four> {
         shape: (usize, usize, usize, usize),
         iter: impl IntoIterator<Item = ElementType>,
     ) -> Result<Self> {
-        Array::from_iter(iter)
+        let array: Array<ElementType, Ix1> = Array::from_iter(iter);
+        let actual = array.len();
+        array
             .into_shape(shape)
-            .map_err(|_| Error(()))
+            .map_err(|_| Error::ShapeMismatch {
+                expected: shape.0 * shape.1 * shape.2 * shape.3,
+                actual,
+            })
             .map(Self)
     }
+
+    /// Gets the shape of the tensor as a tuple, in the same order as it was constructed with.
+    #[must_use]
+    pub fn dim(&self) -> (usize, usize, usize, usize) {
+        self.0.dim()
+    }
 }
 
 impl Tensor<rank::Five> {
@@ -95,11 +228,212 @@ impl Tensor<rank::Five> {
         shape: (usize, usize, usize, usize, usize),
         iter: impl IntoIterator<Item = ElementType>,
     ) -> Result<Self> {
-        Array::from_iter(iter)
+        let array: Array<ElementType, Ix1> = Array::from_iter(iter);
+        let actual = array.len();
+        array
             .into_shape(shape)
-            .map_err(|_| Error(()))
+            .map_err(|_| Error::ShapeMismatch {
+                expected: shape.0 * shape.1 * shape.2 * shape.3 * shape.4,
+                actual,
+            })
             .map(Self)
     }
+
+    /// Gets the shape of the tensor as a tuple, in the same order as it was constructed with.
+    #[must_use]
+    pub fn dim(&self) -> (usize, usize, usize, usize, usize) {
+        self.0.dim()
+    }
+}
+
+impl<R: Rank> Tensor<R> {
+    /// Constructs a tensor of the given shape where every element is zero.
+    #[must_use]
+    pub fn zeros(shape: R::Shape) -> Self {
+        Self::full(shape, 0.0)
+    }
+
+    /// Constructs a tensor of the given shape where every element is one.
+    #[must_use]
+    pub fn ones(shape: R::Shape) -> Self {
+        Self::full(shape, 1.0)
+    }
+
+    /// Constructs a tensor of the given shape where every element is the given value.
+    #[must_use]
+    pub fn full(shape: R::Shape, value: ElementType) -> Self {
+        Self(Array::from_elem(R::internal(shape), value))
+    }
+
+    /// Constructs a tensor of the given shape where the elements are sampled uniformly between
+    /// 0 (inclusive) and 1 (exclusive) from a random number generator with the given seed.
+    #[must_use]
+    pub fn random(shape: R::Shape, seed: u64) -> Self {
+        let distribution = Uniform::new(0.0, 1.0);
+        let mut random_generator = StdRng::seed_from_u64(seed);
+        Self(Array::from_shape_simple_fn(R::internal(shape), move || {
+            distribution.sample(&mut random_generator)
+        }))
+    }
+
+    /// Gets the shape of the tensor as a slice with the size of each axis.
+    #[must_use]
+    pub fn shape(&self) -> &[usize] {
+        self.0.shape()
+    }
+
+    /// Gets the total number of elements in the tensor.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Checks whether the tensor has no elements, i.e. if any of its axes have a size of zero.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Checks whether every element of the tensor is finite, that is, neither NaN nor infinite.
+    #[must_use]
+    pub fn is_finite(&self) -> bool {
+        self.0.iter().all(|elem| elem.is_finite())
+    }
+
+    /// Checks whether this tensor has the same shape as the given tensor, and whether each of its
+    /// elements is within the given tolerance of the corresponding element of the given tensor.
+    /// This is useful for comparing results which are subject to rounding errors, which differ
+    /// between the f32 and f64 element types.
+    #[must_use]
+    pub fn approx_eq(&self, other: &Self, tolerance: ElementType) -> bool {
+        self.shape() == other.shape()
+            && self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|(left, right)| (left - right).abs() <= tolerance)
+    }
+
+    /// Calculates the L2 (Euclidean/Frobenius) norm of the tensor, which is the
+    /// square root of the sum of the squares of its elements.
+    #[must_use]
+    pub fn l2_norm(&self) -> ElementType {
+        self.0
+            .iter()
+            .fold(0.0, |sum: ElementType, elem| elem.mul_add(*elem, sum))
+            .sqrt()
+    }
+}
+
+impl<R: Batched> Tensor<R> {
+    /// Gets the item of the batch at the given index as a tensor of one lower rank, such as a
+    /// single image of a batch of images.
+    ///
+    /// # Errors
+    /// `Error` if the index is out of range.
+    pub fn item(&self, index: usize) -> Result<Tensor<R::ItemRank>> {
+        if index < self.0.len_of(Axis(0)) {
+            Ok(Tensor(self.0.index_axis(Axis(0), index).to_owned()))
+        } else {
+            Err(Error::InvalidArgument)
+        }
+    }
+
+    /// Gets an iterator over the items of the batch in order, as tensors of one lower rank.
+    pub fn items(&self) -> impl Iterator<Item = Tensor<R::ItemRank>> + '_ {
+        self.0
+            .axis_iter(Axis(0))
+            .map(|item| Tensor(item.to_owned()))
+    }
+
+    /// Gets the items of the batch within the given range, keeping the rank of the tensor.
+    ///
+    /// # Errors
+    /// `Error` if the range isn't within the batch.
+    pub fn slice_items(&self, items: impl RangeBounds<usize>) -> Result<Self> {
+        let items = range(&items, self.0.len_of(Axis(0)))?;
+        Ok(Self(
+            self.0.slice_axis(Axis(0), Slice::from(items)).to_owned(),
+        ))
+    }
+}
+
+/// Converts the given range into a range of indices for an axis of the given length.
+/// Returns an error if the range doesn't lie within the axis.
+fn range(range: &impl RangeBounds<usize>, length: usize) -> Result<core::ops::Range<usize>> {
+    let start = match range.start_bound() {
+        Bound::Included(start) => *start,
+        Bound::Excluded(start) => start.checked_add(1).ok_or(Error::InvalidArgument)?,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(end) => end.checked_add(1).ok_or(Error::InvalidArgument)?,
+        Bound::Excluded(end) => *end,
+        Bound::Unbounded => length,
+    };
+    if start <= end && end <= length {
+        Ok(start..end)
+    } else {
+        Err(Error::InvalidArgument)
+    }
+}
+
+impl<R: Rank> Display for Tensor<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<R: Rank> Debug for Tensor<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Tensor(shape={:?})", self.shape())?;
+        Display::fmt(&self.0, f)
+    }
+}
+
+/// Converts an `ndarray` array of the corresponding dimensionality into a tensor without copying its elements.
+#[cfg(feature = "ndarray-interop")]
+impl<R: Rank> From<Array<ElementType, R::Internal>> for Tensor<R> {
+    fn from(array: Array<ElementType, R::Internal>) -> Self {
+        Self(array)
+    }
+}
+
+/// Converts a tensor into an `ndarray` array of the corresponding dimensionality without copying its elements.
+#[cfg(feature = "ndarray-interop")]
+impl<R: Rank> From<Tensor<R>> for Array<ElementType, R::Internal> {
+    fn from(tensor: Tensor<R>) -> Self {
+        tensor.0
+    }
+}
+
+/// Asserts that two tensors are approximately equal using `Tensor::approx_eq`, with the given tolerance
+/// or a tolerance of `1e-6` if none is given. On failure, this panics and shows both tensors.
+///
+/// ```
+/// use eidetic::assert_tensor_approx_eq;
+/// use eidetic::tensors::{Tensor, rank};
+/// let tensor = Tensor::<rank::One>::new([0.1 + 0.2, 1.0 / 3.0]);
+/// assert_tensor_approx_eq!(tensor, Tensor::<rank::One>::new([0.3, 0.333_333_3]));
+/// assert_tensor_approx_eq!(tensor, Tensor::<rank::One>::new([0.3, 0.33]), 0.01);
+/// ```
+#[macro_export]
+macro_rules! assert_tensor_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_tensor_approx_eq!($left, $right, 1e-6)
+    };
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !left.approx_eq(right, $tolerance) {
+                    panic!(
+                        "assertion failed: tensors are not approximately equal (tolerance: {:?})\n  left: {:?}\n right: {:?}",
+                        $tolerance, left, right
+                    );
+                }
+            }
+        }
+    };
 }
 
 /// This struct is the type that is returned from calling `into_iter()`
@@ -125,6 +459,7 @@ impl<R: Rank> Iterator for TensorIterator<R> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::format;
 
     #[test]
     fn test_tensor_rank_0_construction() {
@@ -141,7 +476,7 @@ mod tests {
     #[test]
     fn test_tensor_rank_1_construction() {
         // Arrange
-        let tensor = Tensor::<rank::One>::new((1..=3u16).map(|elem| ElementType::from(elem)));
+        let tensor = Tensor::<rank::One>::new((1..=3u16).map(ElementType::from));
 
         // Act
         let mut iter = tensor.into_iter();
@@ -155,9 +490,7 @@ mod tests {
     #[test]
     fn test_tensor_rank_2_construction() {
         // Arrange
-        let tensor =
-            Tensor::<rank::Two>::new((2, 2), (1..=4u16).map(|elem| ElementType::from(elem)))
-                .unwrap();
+        let tensor = Tensor::<rank::Two>::new((2, 2), (1..=4u16).map(ElementType::from)).unwrap();
 
         // Act
         let mut iter = tensor.into_iter();
@@ -173,9 +506,8 @@ mod tests {
     fn test_tensor_rank_3_construction() {
         // Arrange
         let tensor =
-            Tensor::<rank::Three>::new((2, 3, 2), (1..=12u16).map(|elem| ElementType::from(elem)))
-                .unwrap();
-        let expected = (1..=12u16).map(|elem| ElementType::from(elem));
+            Tensor::<rank::Three>::new((2, 3, 2), (1..=12u16).map(ElementType::from)).unwrap();
+        let expected = (1..=12u16).map(ElementType::from);
 
         // Act
         let output = tensor.into_iter();
@@ -187,12 +519,9 @@ mod tests {
     #[test]
     fn test_tensor_rank_4_construction() {
         // Arrange
-        let tensor = Tensor::<rank::Four>::new(
-            (2, 3, 2, 2),
-            (1..=24u16).map(|elem| ElementType::from(elem)),
-        )
-        .unwrap();
-        let expected = (1..=24u16).map(|elem| ElementType::from(elem));
+        let tensor =
+            Tensor::<rank::Four>::new((2, 3, 2, 2), (1..=24u16).map(ElementType::from)).unwrap();
+        let expected = (1..=24u16).map(ElementType::from);
 
         // Act
         let output = tensor.into_iter();
@@ -204,12 +533,9 @@ mod tests {
     #[test]
     fn test_tensor_rank_5_construction() {
         // Arrange
-        let tensor = Tensor::<rank::Five>::new(
-            (2, 3, 2, 2, 3),
-            (1..=72u16).map(|elem| ElementType::from(elem)),
-        )
-        .unwrap();
-        let expected = (1..=72u16).map(|elem| ElementType::from(elem));
+        let tensor =
+            Tensor::<rank::Five>::new((2, 3, 2, 2, 3), (1..=72u16).map(ElementType::from)).unwrap();
+        let expected = (1..=72u16).map(ElementType::from);
 
         // Act
         let output = tensor.into_iter();
@@ -221,18 +547,22 @@ mod tests {
     #[test]
     fn test_tensor_rank_2_construction_failure() {
         // Arrange
-        let tensor =
-            Tensor::<rank::Two>::new((2, 2), (1..=5u16).map(|elem| ElementType::from(elem)));
+        let tensor = Tensor::<rank::Two>::new((2, 2), (1..=5u16).map(ElementType::from));
 
         // Assert
-        assert!(tensor.is_err());
+        assert_eq!(
+            tensor,
+            Err(Error::ShapeMismatch {
+                expected: 4,
+                actual: 5
+            })
+        );
     }
 
     #[test]
     fn test_tensor_rank_3_construction_failure() {
         // Arrange
-        let tensor =
-            Tensor::<rank::Three>::new((2, 3, 2), (1..=13u16).map(|elem| ElementType::from(elem)));
+        let tensor = Tensor::<rank::Three>::new((2, 3, 2), (1..=13u16).map(ElementType::from));
 
         // Assert
         assert!(tensor.is_err());
@@ -241,10 +571,7 @@ mod tests {
     #[test]
     fn test_tensor_rank_4_construction_failure() {
         // Arrange
-        let tensor = Tensor::<rank::Four>::new(
-            (2, 3, 2, 4),
-            (1..=51u16).map(|elem| ElementType::from(elem)),
-        );
+        let tensor = Tensor::<rank::Four>::new((2, 3, 2, 4), (1..=51u16).map(ElementType::from));
 
         // Assert
         assert!(tensor.is_err());
@@ -253,12 +580,328 @@ mod tests {
     #[test]
     fn test_tensor_rank_5_construction_failure() {
         // Arrange
-        let tensor = Tensor::<rank::Five>::new(
-            (2, 3, 2, 4, 2),
-            (1..=97u16).map(|elem| ElementType::from(elem)),
-        );
+        let tensor = Tensor::<rank::Five>::new((2, 3, 2, 4, 2), (1..=97u16).map(ElementType::from));
 
         // Assert
         assert!(tensor.is_err());
     }
+
+    #[test]
+    fn test_constructors() {
+        // Arrange
+        let shape = (2, 1, 3);
+
+        // Act
+        let zeros = Tensor::<rank::Three>::zeros(shape);
+        let ones = Tensor::<rank::One>::ones(2);
+        let full = Tensor::<rank::Zero>::full((), 4.0);
+
+        // Assert
+        assert_eq!(zeros, Tensor::<rank::Three>::new(shape, [0.0; 6]).unwrap());
+        assert_eq!(ones, Tensor::<rank::One>::new([1.0, 1.0]));
+        assert_eq!(full, Tensor::<rank::Zero>::new(4.0));
+    }
+
+    #[test]
+    fn test_random() {
+        // Arrange
+        let shape = (3, 4, 2, 2);
+
+        // Act
+        let first = Tensor::<rank::Four>::random(shape, 42);
+        let second = Tensor::<rank::Four>::random(shape, 42);
+
+        // Assert
+        assert_eq!(first.dim(), shape);
+        assert!(first
+            .clone()
+            .into_iter()
+            .all(|elem| (0.0..1.0).contains(&elem)));
+        assert_eq!(first, second);
+        assert_ne!(first, Tensor::<rank::Four>::random(shape, 43));
+    }
+
+    #[test]
+    fn test_display() {
+        // Arrange
+        let tensor = Tensor::<rank::Two>::new((2, 3), (1..=6u16).map(ElementType::from)).unwrap();
+
+        // Act
+        let output = format!("{tensor}");
+        let debug = format!("{tensor:?}");
+
+        // Assert
+        assert_eq!(output, "[[1, 2, 3],\n [4, 5, 6]]");
+        assert_eq!(debug, "Tensor(shape=[2, 3])\n[[1, 2, 3],\n [4, 5, 6]]");
+    }
+
+    #[test]
+    fn test_display_truncated() {
+        // Arrange
+        let tensor = Tensor::<rank::One>::zeros(1000);
+
+        // Act
+        let output = format!("{tensor}");
+        let alternate = format!("{tensor:#}");
+
+        // Assert
+        assert!(output.contains("..."));
+        assert!(output.len() < 100);
+        assert!(!alternate.contains("..."));
+    }
+
+    #[test]
+    fn test_is_finite() {
+        // Arrange
+        let finite = Tensor::<rank::One>::new([1.0, -2.0]);
+        let nan = Tensor::<rank::One>::new([1.0, ElementType::NAN]);
+        let infinite = Tensor::<rank::One>::new([ElementType::NEG_INFINITY]);
+
+        // Act/Assert
+        assert!(finite.is_finite());
+        assert!(!nan.is_finite());
+        assert!(!infinite.is_finite());
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        // Arrange
+        let tensor = Tensor::<rank::Two>::new((1, 2), [1.0, 2.0]).unwrap();
+        let close = Tensor::<rank::Two>::new((1, 2), [1.05, 1.99]).unwrap();
+        let transposed = Tensor::<rank::Two>::new((2, 1), [1.0, 2.0]).unwrap();
+
+        // Act
+        let within = tensor.approx_eq(&close, 0.1);
+        let outside = tensor.approx_eq(&close, 0.01);
+
+        // Assert
+        assert!(within);
+        assert!(!outside);
+        assert!(!tensor.approx_eq(&transposed, 0.1));
+        assert_tensor_approx_eq!(tensor, close, 0.1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_assert_tensor_approx_eq_failure() {
+        // Arrange
+        let tensor = Tensor::<rank::One>::new([1.0]);
+
+        // Act/Assert
+        assert_tensor_approx_eq!(tensor, Tensor::<rank::One>::new([1.001]));
+    }
+
+    #[test]
+    fn test_l2_norm() {
+        // Arrange
+        let tensor = Tensor::<rank::Two>::new((2, 2), [1.0, 2.0, 2.0, 4.0]).unwrap();
+
+        // Act
+        let output = tensor.l2_norm();
+
+        // Assert
+        assert_eq!(output, 5.0);
+    }
+
+    #[test]
+    fn test_rank_2_shape_accessors() {
+        // Arrange
+        let tensor = Tensor::<rank::Two>::new((2, 3), (1..=6u16).map(ElementType::from)).unwrap();
+
+        // Act
+        let (dim, nrows, ncols) = (tensor.dim(), tensor.nrows(), tensor.ncols());
+        let (shape, len) = (tensor.shape(), tensor.len());
+
+        // Assert
+        assert_eq!(dim, (2, 3));
+        assert_eq!((nrows, ncols), (2, 3));
+        assert_eq!(shape, &[2, 3]);
+        assert_eq!(len, 6);
+        assert!(!tensor.is_empty());
+    }
+
+    #[test]
+    fn test_rank_4_shape_accessors() {
+        // Arrange
+        let tensor = Tensor::<rank::Four>::new((2, 1, 0, 3), []).unwrap();
+
+        // Act
+        let (dim, shape, len) = (tensor.dim(), tensor.shape(), tensor.len());
+
+        // Assert
+        assert_eq!(dim, (2, 1, 0, 3));
+        assert_eq!(shape, &[2, 1, 0, 3]);
+        assert_eq!(len, 0);
+        assert!(tensor.is_empty());
+    }
+
+    #[test]
+    fn test_transpose() {
+        // Arrange
+        let tensor = Tensor::<rank::Two>::new((2, 3), (1..=6u16).map(ElementType::from)).unwrap();
+
+        // Act
+        let output = tensor.transpose();
+
+        // Assert
+        assert_eq!(
+            output.into_iter().collect::<alloc::vec::Vec<_>>(),
+            [1.0, 4.0, 2.0, 5.0, 3.0, 6.0]
+        );
+    }
+
+    #[test]
+    fn test_matmul() {
+        // Arrange
+        let left = Tensor::<rank::Two>::new((2, 3), (1..=6u16).map(ElementType::from)).unwrap();
+        let right = Tensor::<rank::Two>::new((3, 1), [1.0, 0.0, -1.0]).unwrap();
+
+        // Act
+        let output = left.matmul(&right);
+
+        // Assert
+        assert_eq!(output, Tensor::<rank::Two>::new((2, 1), [-2.0, -2.0]));
+        assert_eq!(
+            left.matmul(&left),
+            Err(Error::ShapeMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+        assert_eq!(left.transpose().matmul(&left).unwrap().dim(), (3, 3));
+    }
+
+    #[test]
+    fn test_rows() {
+        // Arrange
+        let tensor = Tensor::<rank::Two>::new((3, 2), (1..=6u16).map(ElementType::from)).unwrap();
+
+        // Act
+        let rows = tensor.rows().collect::<alloc::vec::Vec<_>>();
+        let row = tensor.row(1);
+
+        // Assert
+        assert_eq!(
+            rows,
+            [
+                Tensor::<rank::One>::new([1.0, 2.0]),
+                Tensor::<rank::One>::new([3.0, 4.0]),
+                Tensor::<rank::One>::new([5.0, 6.0])
+            ]
+        );
+        assert_eq!(row, Ok(Tensor::<rank::One>::new([3.0, 4.0])));
+        assert_eq!(tensor.row(3), Err(Error::InvalidArgument));
+    }
+
+    #[test]
+    fn test_slice() {
+        // Arrange
+        let tensor = Tensor::<rank::Two>::new((3, 3), (1..=9u16).map(ElementType::from)).unwrap();
+
+        // Act
+        let output = tensor.slice(1.., ..=1);
+
+        // Assert
+        assert_eq!(
+            output,
+            Tensor::<rank::Two>::new((2, 2), [4.0, 5.0, 7.0, 8.0])
+        );
+        assert_eq!(tensor.slice(.., 3..3).unwrap().dim(), (3, 0));
+        assert_eq!(tensor.slice(..4, ..), Err(Error::InvalidArgument));
+        assert_eq!(
+            tensor.slice(.., (Bound::Included(2), Bound::Excluded(1))),
+            Err(Error::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn test_concatenate_columns() {
+        // Arrange
+        let lhs = Tensor::<rank::Two>::new((2, 2), [1.0, 2.0, 4.0, 5.0]).unwrap();
+        let rhs = Tensor::<rank::Two>::new((2, 1), [3.0, 6.0]).unwrap();
+
+        // Act
+        let output = lhs.concatenate_columns(&rhs);
+
+        // Assert
+        assert_eq!(
+            output,
+            Tensor::<rank::Two>::new((2, 3), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0])
+        );
+        assert_eq!(
+            lhs.concatenate_columns(&rhs.transpose()),
+            Err(Error::ShapeMismatch {
+                expected: 2,
+                actual: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_items() {
+        // Arrange
+        let tensor =
+            Tensor::<rank::Four>::new((3, 1, 1, 2), (1..=6u16).map(ElementType::from)).unwrap();
+
+        // Act
+        let item = tensor.item(2);
+        let items = tensor.items().count();
+        let sliced = tensor.slice_items(1..);
+
+        // Assert
+        assert_eq!(item, Tensor::<rank::Three>::new((1, 1, 2), [5.0, 6.0]));
+        assert_eq!(items, 3);
+        assert_eq!(
+            sliced,
+            Tensor::<rank::Four>::new((2, 1, 1, 2), [3.0, 4.0, 5.0, 6.0])
+        );
+        assert_eq!(tensor.item(3), Err(Error::InvalidArgument));
+        assert_eq!(tensor.slice_items(..=3), Err(Error::InvalidArgument));
+    }
+
+    #[cfg(feature = "ndarray-interop")]
+    #[test]
+    fn test_ndarray_conversions() {
+        // Arrange
+        let array = ndarray::arr2(&[[1.0, 2.0], [3.0, 4.0]]);
+
+        // Act
+        let tensor = Tensor::<rank::Two>::from(array.clone());
+        let output: Array<ElementType, Ix2> = tensor.clone().into();
+
+        // Assert
+        assert_eq!(
+            tensor,
+            Tensor::<rank::Two>::new((2, 2), [1.0, 2.0, 3.0, 4.0]).unwrap()
+        );
+        assert_eq!(output, array);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        // Arrange
+        let tensor = Tensor::<rank::Three>::new((1, 2, 2), [1.0, 2.0, 3.0, 4.0]).unwrap();
+
+        // Act
+        let json = serde_json::to_string(&tensor).unwrap();
+        let output: Tensor<rank::Three> = serde_json::from_str(&json).unwrap();
+
+        // Assert
+        assert_eq!(output, tensor);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_deserialize_wrong_rank() {
+        // Arrange
+        let tensor = Tensor::<rank::Three>::new((1, 2, 2), [1.0, 2.0, 3.0, 4.0]).unwrap();
+        let json = serde_json::to_string(&tensor).unwrap();
+
+        // Act
+        let result = serde_json::from_str::<Tensor<rank::Two>>(&json);
+
+        // Assert
+        assert!(result.is_err());
+    }
 }
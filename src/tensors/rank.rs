@@ -2,7 +2,8 @@
 //! that can be used by tensors.
 
 use crate::private::Sealed;
-use ndarray::{Dimension, Ix0, Ix1, Ix2, Ix3, Ix4, Ix5};
+use core::fmt::Debug;
+use ndarray::{Dim, Dimension, IntoDimension, Ix0, Ix1, Ix2, Ix3, Ix4, Ix5, RemoveAxis};
 
 /// This trait represents the rank of a Tensor in Eidetic
 /// which has a specific shape to define it. The rank of the tensor
@@ -15,6 +16,13 @@ use ndarray::{Dimension, Ix0, Ix1, Ix2, Ix3, Ix4, Ix5};
 pub trait Rank: Clone + Sealed {
     #[doc(hidden)]
     type Internal: Dimension;
+
+    /// The shape of a tensor of this rank, which is a tuple with the size of each axis (or
+    /// a single size for rank 1 tensors, and nothing for rank 0 tensors).
+    type Shape: Clone + Copy + Debug + Eq + PartialEq;
+
+    #[doc(hidden)]
+    fn internal(shape: Self::Shape) -> Self::Internal;
 }
 
 /// This is a unit struct that can be used to identify a rank 0 tensor.
@@ -22,6 +30,11 @@ pub trait Rank: Clone + Sealed {
 pub struct Zero;
 impl Rank for Zero {
     type Internal = Ix0;
+    type Shape = ();
+
+    fn internal(shape: Self::Shape) -> Self::Internal {
+        shape.into_dimension()
+    }
 }
 impl Sealed for Zero {}
 
@@ -30,6 +43,11 @@ impl Sealed for Zero {}
 pub struct One;
 impl Rank for One {
     type Internal = Ix1;
+    type Shape = usize;
+
+    fn internal(shape: Self::Shape) -> Self::Internal {
+        shape.into_dimension()
+    }
 }
 impl Sealed for One {}
 
@@ -38,6 +56,11 @@ impl Sealed for One {}
 pub struct Two;
 impl Rank for Two {
     type Internal = Ix2;
+    type Shape = (usize, usize);
+
+    fn internal(shape: Self::Shape) -> Self::Internal {
+        shape.into_dimension()
+    }
 }
 impl Sealed for Two {}
 
@@ -46,6 +69,11 @@ impl Sealed for Two {}
 pub struct Three;
 impl Rank for Three {
     type Internal = Ix3;
+    type Shape = (usize, usize, usize);
+
+    fn internal(shape: Self::Shape) -> Self::Internal {
+        shape.into_dimension()
+    }
 }
 impl Sealed for Three {}
 
@@ -54,6 +82,11 @@ impl Sealed for Three {}
 pub struct Four;
 impl Rank for Four {
     type Internal = Ix4;
+    type Shape = (usize, usize, usize, usize);
+
+    fn internal(shape: Self::Shape) -> Self::Internal {
+        shape.into_dimension()
+    }
 }
 impl Sealed for Four {}
 
@@ -62,5 +95,84 @@ impl Sealed for Four {}
 pub struct Five;
 impl Rank for Five {
     type Internal = Ix5;
+    type Shape = (usize, usize, usize, usize, usize);
+
+    fn internal(shape: Self::Shape) -> Self::Internal {
+        shape.into_dimension()
+    }
 }
 impl Sealed for Five {}
+
+/// This trait represents the ranks of tensors that hold a batch of items, where the
+/// first axis is the batch axis. It allows layers to work with the shape of a single
+/// item of the batch, such as when reshaping items from one rank to another.
+///
+/// Note that this trait is sealed in the same way as `Rank`.
+pub trait Batched: Rank<Internal: RemoveAxis> {
+    /// The shape of a single item of the batch, that is, the shape without the batch axis.
+    type Item: Clone + Copy + Debug + Eq + PartialEq;
+
+    /// The rank of a tensor holding a single item of the batch, which is one lower than this rank.
+    type ItemRank: Rank<Internal = <Self::Internal as Dimension>::Smaller>;
+
+    /// Constructs the full shape of a tensor with the given batch size and item shape.
+    #[doc(hidden)]
+    fn with_batch(batch: usize, item: Self::Item) -> Self::Internal;
+
+    /// Gets the number of neurons a layer sees for items of the given shape. This is
+    /// the features for rank 2 and rank 3 tensors, and the channels for higher ranks.
+    #[doc(hidden)]
+    fn neurons(item: Self::Item) -> usize;
+}
+
+impl Batched for Two {
+    type Item = usize;
+    type ItemRank = One;
+
+    fn with_batch(batch: usize, item: Self::Item) -> Self::Internal {
+        Dim([batch, item])
+    }
+
+    fn neurons(item: Self::Item) -> usize {
+        item
+    }
+}
+
+impl Batched for Three {
+    type Item = (usize, usize);
+    type ItemRank = Two;
+
+    fn with_batch(batch: usize, (time, features): Self::Item) -> Self::Internal {
+        Dim([batch, time, features])
+    }
+
+    fn neurons((_, features): Self::Item) -> usize {
+        features
+    }
+}
+
+impl Batched for Four {
+    type Item = (usize, usize, usize);
+    type ItemRank = Three;
+
+    fn with_batch(batch: usize, (channels, height, width): Self::Item) -> Self::Internal {
+        Dim([batch, channels, height, width])
+    }
+
+    fn neurons((channels, _, _): Self::Item) -> usize {
+        channels
+    }
+}
+
+impl Batched for Five {
+    type Item = (usize, usize, usize, usize);
+    type ItemRank = Four;
+
+    fn with_batch(batch: usize, (channels, depth, height, width): Self::Item) -> Self::Internal {
+        Dim([batch, channels, depth, height, width])
+    }
+
+    fn neurons((channels, _, _, _): Self::Item) -> usize {
+        channels
+    }
+}
@@ -0,0 +1,4138 @@
+//! This module contains training related functionality for
+//! taking an initialised network and training it over a certain
+//! number of epochs with a certain optimisation strategy, etc.
+
+use crate::data::{DataLoader, Dataset};
+use crate::loss::Loss;
+#[cfg(feature = "rayon")]
+use crate::loss::{rows_to_element, Reduction};
+use crate::metrics::{class_of, Metric};
+use crate::operations::{
+    BackwardOperation, Forward, ForwardOperation, InitialisedOperation, TrainableOperation,
+    WithOptimiser,
+};
+use crate::optimisers::{capture, LBFGS};
+use crate::tensors::{rank, Tensor};
+use crate::{ElementType, Error, Result};
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::ops::{ControlFlow, Range};
+use ndarray::{s, Array, ArrayView, Axis, Ix2};
+use rand::rngs::StdRng;
+use rand::seq::index::sample;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+#[cfg(feature = "progress")]
+mod progress;
+mod search;
+
+#[cfg(feature = "progress")]
+pub use progress::Progress;
+pub use search::{grid, random, search, SearchResult};
+
+/// Generates batches of the given size from the rows of the batch and targets, visiting the rows in the
+/// order of the given indices. Only the rows of each batch are copied, as the batch is generated.
+fn generate_batches<'a>(
+    batch: &'a Array<ElementType, Ix2>,
+    targets: &'a Array<ElementType, Ix2>,
+    indices: Vec<usize>,
+    size: usize,
+) -> impl Iterator<Item = (Array<ElementType, Ix2>, Array<ElementType, Ix2>)> + 'a {
+    (0..indices.len()).step_by(size).map(move |start| {
+        let rows = &indices[start..indices.len().min(start + size)];
+        (batch.select(Axis(0), rows), targets.select(Axis(0), rows))
+    })
+}
+
+/// Generates a random permutation of the indices of the given number of rows from the given seed. This
+/// is the same permutation that `permute_data` applies to the rows of data.
+fn permuted_indices(rows: usize, seed: u64) -> Vec<usize> {
+    let mut random_generator = StdRng::seed_from_u64(seed);
+    sample(&mut random_generator, rows, rows).into_vec()
+}
+
+fn permute_data(
+    mut batch: Array<ElementType, Ix2>,
+    targets: &Array<ElementType, Ix2>,
+    seed: u64,
+) -> (Array<ElementType, Ix2>, Array<ElementType, Ix2>) {
+    // get dimensions for later use.
+    let (batch_row_count, batch_col_count) = (batch.nrows(), batch.ncols());
+    let (targets_row_count, _) = (targets.nrows(), targets.ncols());
+    assert_eq!(batch_row_count, targets_row_count);
+
+    // construct RNG from provided seed.
+    let mut random_generator = StdRng::seed_from_u64(seed);
+
+    // join batch and targets together side by side for row permutation
+    batch.append(Axis(1), targets.into()).unwrap();
+
+    // permute the rows of the axis, don't re-use the indices though as we want
+    // to juggle them around.
+    let indices = sample(&mut random_generator, batch_row_count, batch_row_count).into_vec();
+    let shuffled = batch.select(Axis(0), &indices);
+
+    // split up again into batch/target arrays for return.
+    let shuffled = ArrayView::from(&shuffled);
+    let (batch, targets) = shuffled.split_at(Axis(1), batch_col_count);
+
+    // done!
+    (batch.into_owned(), targets.into_owned())
+}
+
+/// Splits the given number of rows into k contiguous folds of (as close as possible to)
+/// equal size, returning the range of rows in each fold. Earlier folds take the remainder.
+fn fold_ranges(rows: usize, k: usize) -> Vec<Range<usize>> {
+    let (size, remainder) = (rows / k, rows % k);
+    let mut start = 0;
+    (0..k)
+        .map(|fold| {
+            let end = start + size + usize::from(fold < remainder);
+            let range = start..end;
+            start = end;
+            range
+        })
+        .collect()
+}
+
+/// A batch of training data paired with the targets for it.
+type Batch = (Tensor<rank::Two>, Tensor<rank::Two>);
+
+/// The outcome of a training run, which is the trained network, the best snapshots of it that were
+/// kept (paired with their testing loss), and the history of the run.
+type Run<N> = (N, Vec<(ElementType, N)>, TrainingHistory);
+
+/// Checks that the given training data has the same number of rows as the targets, and then creates the
+/// source of the batches for each epoch, which orders the rows with the given shuffling strategy using the
+/// seed plus the epoch number.
+fn in_memory_batches<'a>(
+    batch_train: &'a Tensor<rank::Two>,
+    targets_train: &'a Tensor<rank::Two>,
+    batch_size: usize,
+    seed: u64,
+    shuffle: Shuffle,
+) -> Result<impl FnMut(u32) -> Box<dyn Iterator<Item = Result<Batch>> + 'a>> {
+    let (batch_train, targets_train) = (&batch_train.0, &targets_train.0);
+    if batch_train.nrows() == targets_train.nrows() {
+        Ok(
+            move |epoch| match shuffle.indices(targets_train, batch_size, seed + u64::from(epoch)) {
+                Ok(indices) => {
+                    let batches = generate_batches(batch_train, targets_train, indices, batch_size)
+                        .map(|(batch, targets)| Ok((Tensor(batch), Tensor(targets))));
+                    Box::new(batches) as Box<dyn Iterator<Item = _>>
+                }
+                Err(error) => Box::new(core::iter::once(Err(error))),
+            },
+        )
+    } else {
+        Err(Error::BatchRowMismatch {
+            expected: batch_train.nrows(),
+            actual: targets_train.nrows(),
+        })
+    }
+}
+
+/// A way of running a training step of a network on a single batch.
+trait Step<N, L> {
+    /// Calculates the loss of the network for the given batch (including any regularisation penalty)
+    /// and then optimises the network with the gradients, returning the loss. When checking that they're
+    /// finite, the network is left unchanged and `None` is returned if the loss or gradients aren't.
+    fn step(
+        &self,
+        network: &mut N,
+        loss_function: &L,
+        batch: Batch,
+        check_finite: bool,
+    ) -> Result<Option<ElementType>>;
+}
+
+/// Runs each training step over the whole batch at once.
+struct Sequential;
+
+impl<N, L: Loss> Step<N, L> for Sequential
+where
+    for<'a> N:
+        TrainableOperation + Forward<'a, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+{
+    fn step(
+        &self,
+        network: &mut N,
+        loss_function: &L,
+        (batch, targets): Batch,
+        check_finite: bool,
+    ) -> Result<Option<ElementType>> {
+        let penalty = network.penalty();
+        let (forward, output) = network.forward(batch)?;
+        let (loss, loss_gradient) = loss_function.loss(&output, &targets)?;
+        let loss = loss + penalty;
+        let (backward, _) = forward.backward(loss_gradient)?;
+        if check_finite && !(loss.is_finite() && backward.gradient_norm_squared().is_finite()) {
+            return Ok(None);
+        }
+        backward.optimise();
+        Ok(Some(loss))
+    }
+}
+
+/// Runs each training step by splitting the batch between the given number of replicas of the network,
+/// which run their forward and backward passes in parallel. Their losses and gradients are combined in the same
+/// way as the loss function reduces the rows of a batch before the network is optimised with them, so they're
+/// averaged (weighted by the number of rows each replica was given) for a mean, and added together for a sum.
+#[cfg(feature = "rayon")]
+struct DataParallel(usize);
+
+#[cfg(feature = "rayon")]
+impl<N, L> Step<N, L> for DataParallel
+where
+    for<'a> N: TrainableOperation
+        + Forward<'a, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>
+        + Clone
+        + Send,
+    L: Loss + Sync,
+{
+    fn step(
+        &self,
+        network: &mut N,
+        loss_function: &L,
+        (batch, targets): Batch,
+        check_finite: bool,
+    ) -> Result<Option<ElementType>> {
+        let reduction = loss_function.reduction().ok_or(Error::InvalidArgument)?;
+        let rows = batch.0.nrows();
+        let size = rows.div_ceil(self.0.max(1)).max(1);
+        if size >= rows {
+            return Sequential.step(network, loss_function, (batch, targets), check_finite);
+        }
+
+        // the network itself runs over the first share of the rows on this thread, while a replica runs over each
+        // of the other shares in parallel, so that the combined gradients can be applied through its backward pass.
+        let weight = |shard_rows| match reduction {
+            Reduction::Mean => rows_to_element(shard_rows) / rows_to_element(rows),
+            Reduction::Sum => 1.0,
+        };
+        let mut shards = (0..rows).step_by(size).map(|start| {
+            let range = start..rows.min(start + size);
+            let batch = Tensor(batch.0.slice(s![range.clone(), ..]).to_owned());
+            (batch, Tensor(targets.0.slice(s![range, ..]).to_owned()))
+        });
+        let (own_batch, own_targets) = shards.next().ok_or(Error::EmptyData)?;
+        let replicas = shards
+            .map(|(batch, targets)| (network.clone(), batch, targets))
+            .collect::<Vec<_>>();
+        let penalty = network.penalty();
+        let mut results = Ok(Vec::new());
+        let results_ref = &mut results;
+        let (mut loss, mut backward, own_weight) = rayon::in_place_scope(move |scope| {
+            scope.spawn(move |_| {
+                *results_ref = replicas
+                    .into_par_iter()
+                    .map(|(mut replica, batch, targets)| {
+                        let weight = weight(batch.0.nrows());
+                        let (forward, output) = replica.forward(batch)?;
+                        let (loss, loss_gradient) = loss_function.loss(&output, &targets)?;
+                        let (backward, _) = forward.backward(loss_gradient)?;
+                        let mut gradients = Vec::new();
+                        backward.push_gradients(&mut gradients);
+                        Ok((loss * weight, gradients, weight))
+                    })
+                    .collect::<Result<Vec<_>>>();
+            });
+            let weight = weight(own_batch.0.nrows());
+            let (forward, output) = network.forward(own_batch)?;
+            let (loss, loss_gradient) = loss_function.loss(&output, &own_targets)?;
+            let (backward, _) = forward.backward(loss_gradient)?;
+            Ok::<_, Error>((loss.mul_add(weight, penalty), backward, weight))
+        })?;
+
+        // combine the weighted gradients of the network with those of the replicas.
+        let mut gradients = Vec::new();
+        backward.push_gradients(&mut gradients);
+        for gradient in &mut gradients {
+            *gradient *= own_weight;
+        }
+        for (replica_loss, replica_gradients, weight) in results? {
+            Error::check_size(gradients.len(), replica_gradients.len())?;
+            for (total, gradient) in gradients.iter_mut().zip(replica_gradients) {
+                *total = gradient.mul_add(weight, *total);
+            }
+            loss += replica_loss;
+        }
+        backward.set_gradients(&mut gradients.into_iter());
+        if check_finite && !(loss.is_finite() && backward.gradient_norm_squared().is_finite()) {
+            return Ok(None);
+        }
+        backward.optimise();
+        Ok(Some(loss))
+    }
+}
+
+/// How the rows of the training data are ordered into batches each epoch. Each epoch is shuffled
+/// differently, using the seed of training plus the (zero based) epoch number.
+#[derive(Clone, Copy, Debug, Default)]
+pub enum Shuffle {
+    /// The rows are visited in order every epoch, such as for time series where the order matters.
+    Off,
+    /// The rows are shuffled across the whole of the training data.
+    #[default]
+    Full,
+    /// The rows are split into batches in order, and only the rows within each batch are shuffled.
+    WithinBatch,
+    /// The rows are shuffled within each class and then interleaved, so that every batch has roughly the
+    /// same proportion of each class as the whole of the training data, which helps training on imbalanced
+    /// datasets. The class of a row is the index of the largest element of its one-hot encoded targets,
+    /// or the (rounded) target itself if the targets are a single column of class indices.
+    Stratified,
+    /// The rows are visited in the order of the indices returned by the given function, which is given
+    /// the number of rows and the seed of the epoch. The indices must be a permutation of the rows.
+    Custom(fn(usize, u64) -> Vec<usize>),
+}
+
+impl Shuffle {
+    /// Gets the order that the rows with the given targets are visited in for the epoch with the given seed.
+    fn indices(
+        self,
+        targets: &Array<ElementType, Ix2>,
+        batch_size: usize,
+        seed: u64,
+    ) -> Result<Vec<usize>> {
+        let rows = targets.nrows();
+        match self {
+            Self::Off => Ok((0..rows).collect()),
+            Self::Full => Ok(permuted_indices(rows, seed)),
+            Self::WithinBatch => {
+                let mut random_generator = StdRng::seed_from_u64(seed);
+                let mut indices = (0..rows).collect::<Vec<_>>();
+                for batch in indices.chunks_mut(batch_size) {
+                    batch.shuffle(&mut random_generator);
+                }
+                Ok(indices)
+            }
+            Self::Stratified => Ok(stratified_indices(targets, seed)),
+            Self::Custom(permutation) => {
+                let indices = permutation(rows, seed);
+                Error::check_size(rows, indices.len())?;
+                let mut sorted = indices.clone();
+                sorted.sort_unstable();
+                if !sorted.into_iter().eq(0..rows) {
+                    return Err(Error::InvalidArgument);
+                }
+                Ok(indices)
+            }
+        }
+    }
+}
+
+/// Groups the rows by the class of their targets and shuffles each class with the given seed. Each row is then
+/// placed at the fraction of the way through its class that it's been shuffled to, so that the classes are spread
+/// evenly through the order and any run of consecutive rows keeps roughly the proportions of the classes.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+fn stratified_indices(targets: &Array<ElementType, Ix2>, seed: u64) -> Vec<usize> {
+    let mut classes = BTreeMap::<usize, Vec<usize>>::new();
+    for (index, row) in targets.rows().into_iter().enumerate() {
+        let class = if row.len() == 1 {
+            row[0].max(0.0).round() as usize
+        } else {
+            class_of(row)
+        };
+        classes.entry(class).or_default().push(index);
+    }
+    let mut random_generator = StdRng::seed_from_u64(seed);
+    let mut positions = Vec::with_capacity(targets.nrows());
+    for indices in classes.values_mut() {
+        indices.shuffle(&mut random_generator);
+        let count = indices.len() as ElementType;
+        positions.extend(
+            indices
+                .iter()
+                .enumerate()
+                .map(|(rank, &index)| ((rank as ElementType + 0.5) / count, index)),
+        );
+    }
+    positions.sort_by(|(lhs, _), (rhs, _)| lhs.total_cmp(rhs));
+    positions.into_iter().map(|(_, index)| index).collect()
+}
+
+/// What training does when the loss or the gradients of a batch become NaN or infinite, which usually
+/// means that the learning rate is too high or that the inputs haven't been normalised.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum NonFiniteHandling {
+    /// Training carries on as normal, which will usually fill the parameters of the network with NaN.
+    #[default]
+    Ignore,
+    /// Training stops and returns an `Error::NonFiniteValue`.
+    Abort,
+    /// The batch isn't used to update the network and training carries on with the next batch.
+    SkipBatch,
+}
+
+/// The state that training needs in order to carry on from where a run was stopped, which is the epoch to start
+/// from along with the state of early stopping (the best testing loss seen and how many evaluations have gone
+/// without improving on it). Each run records this into its `TrainingHistory`, and with the *serde* feature it
+/// can be saved along with the trainable network so that training can be resumed later with `TrainingOptions::with_resume`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResumeState {
+    epoch: u32,
+    best_loss: Option<ElementType>,
+    evaluations_without_improvement: u16,
+}
+
+impl ResumeState {
+    /// Gets the (zero based) epoch that training resumes from, which is the first epoch that wasn't completed.
+    /// A run which was stopped part way through an epoch resumes from the start of that epoch.
+    #[must_use]
+    pub const fn epoch(&self) -> u32 {
+        self.epoch
+    }
+
+    /// Gets the lowest testing loss seen so far, or `None` if the network hasn't been evaluated yet.
+    #[must_use]
+    pub const fn best_loss(&self) -> Option<ElementType> {
+        self.best_loss
+    }
+
+    /// Gets the number of consecutive evaluations since the testing loss last improved on the best loss.
+    #[must_use]
+    pub const fn evaluations_without_improvement(&self) -> u16 {
+        self.evaluations_without_improvement
+    }
+
+    /// Checks whether the given testing loss improves on the best loss by at least the given amount.
+    fn improves(&self, loss: ElementType, min_delta: ElementType) -> bool {
+        self.best_loss
+            .is_none_or(|best_loss| loss <= best_loss - min_delta)
+    }
+}
+
+/// Options which configure how training decides to stop early when the loss against the
+/// testing data stops improving, and where training resumes from. The default options
+/// start from the first epoch and stop training as soon as the testing loss worsens at a single evaluation.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TrainingOptions {
+    patience: u16,
+    min_delta: ElementType,
+    resume: ResumeState,
+    non_finite: NonFiniteHandling,
+    seed: Option<u64>,
+}
+
+impl TrainingOptions {
+    /// Constructs the default training options.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            patience: 0,
+            min_delta: 0.0,
+            resume: ResumeState {
+                epoch: 0,
+                best_loss: None,
+                evaluations_without_improvement: 0,
+            },
+            non_finite: NonFiniteHandling::Ignore,
+            seed: None,
+        }
+    }
+
+    /// Sets the number of consecutive evaluations that the testing loss is allowed to go without
+    /// improving before training is stopped and the best network seen is returned.
+    #[must_use]
+    pub const fn with_patience(self, patience: u16) -> Self {
+        Self { patience, ..self }
+    }
+
+    /// Sets the amount that the testing loss must decrease by (compared to the best loss seen)
+    /// for an evaluation to be counted as an improvement.
+    #[must_use]
+    pub const fn with_min_delta(self, min_delta: ElementType) -> Self {
+        Self { min_delta, ..self }
+    }
+
+    /// Resumes training from the state recorded in the `TrainingHistory` of a run which was stopped early (for example
+    /// by a callback), carrying on with the same total number of epochs and seed. When resuming, the optimisers in the
+    /// network aren't initialised again so that their state (such as momentum and the learning rate schedule) carries on
+    /// from where it was, and early stopping carries on counting from the best loss seen before the pause. Combined with
+    /// the *serde* feature, this allows a trainable network to be saved with the state and resumed later with identical results.
+    ///
+    /// The best network seen before the pause isn't part of the state, so it should be kept with a `Checkpoint` if
+    /// needed. If training runs out of patience before finding a better one, the latest evaluated network is returned.
+    #[must_use]
+    pub const fn with_resume(self, resume: ResumeState) -> Self {
+        Self { resume, ..self }
+    }
+
+    /// Sets what training does when the loss or the gradients of a batch become NaN or infinite.
+    /// By default, this isn't checked for.
+    #[must_use]
+    pub const fn with_non_finite(self, non_finite: NonFiniteHandling) -> Self {
+        Self { non_finite, ..self }
+    }
+
+    /// Makes training deterministic by seeding every layer which makes random decisions during
+    /// training (such as dropout) from the given seed before the first epoch, rather than leaving
+    /// those of a network built with `with_iter` to draw from the thread's random generator (or a fixed
+    /// seed without the *std* feature). Using the same seed for `with_seed`, this option and the seed of the
+    /// trainer makes a whole run reproducible.
+    #[must_use]
+    pub const fn with_deterministic(self, seed: u64) -> Self {
+        Self {
+            seed: Some(seed),
+            ..self
+        }
+    }
+}
+
+/// This trait can be implemented to observe the progress of training, with each hook being
+/// invoked by the training loop at the corresponding point. Each hook can return
+/// `ControlFlow::Break` to abort training, in which case the network trained so far is returned.
+/// All hooks default to doing nothing and continuing, so only the ones of interest need implementing.
+///
+/// The unit type implements this trait as a callback which does nothing.
+pub trait Callback {
+    /// Invoked at the start of each epoch with the (zero based) epoch number.
+    fn on_epoch_start(&mut self, _epoch: u32) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+
+    /// Invoked after each batch has been trained on with the epoch number and the loss of
+    /// the batch (calculated before the parameters were updated).
+    fn on_batch_end(&mut self, _epoch: u32, _batch_loss: ElementType) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+
+    /// Invoked at the end of each epoch with the epoch number and, if the network was evaluated
+    /// against the testing data this epoch, the evaluation loss.
+    fn on_epoch_end(
+        &mut self,
+        _epoch: u32,
+        _evaluation_loss: Option<ElementType>,
+    ) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+
+    /// Invoked each time the network is evaluated by a `Trainer` with a loss gap sample, just before `on_epoch_end`,
+    /// with the epoch number, the loss against the held sample of the training data, and the evaluation loss. The
+    /// gap between them is the evaluation loss minus the training loss, and a growing gap is a sign of overfitting.
+    fn on_loss_gap(
+        &mut self,
+        _epoch: u32,
+        _training_loss: ElementType,
+        _evaluation_loss: ElementType,
+    ) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+}
+
+impl Callback for () {}
+
+/// This trait is implemented by types that can save the best network seen so far during training,
+/// so that a long training run can be recovered if it's interrupted. Each time the network is
+/// evaluated against the testing data and the loss improves on the best seen so far, the checkpoint is
+/// saved with the epoch number, the evaluation loss, and the network in the state that was evaluated.
+///
+/// The unit type implements this trait as a checkpoint which saves nothing. Closures which take the epoch
+/// number, the evaluation loss and an iterator over the weights of the network also implement it, as does
+/// `FileCheckpoint` when the *std* feature is enabled.
+pub trait Checkpoint<N> {
+    /// Saves the given network as the best seen so far.
+    ///
+    /// # Errors
+    /// Returns an `eidetic::Error` if the checkpoint couldn't be saved, which stops training.
+    fn save(&mut self, epoch: u32, evaluation_loss: ElementType, network: &N) -> Result<()>;
+}
+
+impl<N> Checkpoint<N> for () {
+    fn save(&mut self, _epoch: u32, _evaluation_loss: ElementType, _network: &N) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<N, F> Checkpoint<N> for F
+where
+    N: TrainableOperation + Clone,
+    N::Initialised: InitialisedOperation,
+    F: FnMut(u32, ElementType, &mut dyn Iterator<Item = ElementType>) -> Result<()>,
+{
+    fn save(&mut self, epoch: u32, evaluation_loss: ElementType, network: &N) -> Result<()> {
+        let mut weights = network.clone().into_initialised().iter();
+        self(epoch, evaluation_loss, &mut weights)
+    }
+}
+
+/// A checkpoint which writes the weights of the best network seen so far to a file, replacing its previous
+/// contents each time. The weights are written in the same format as `save` on an initialised network, and can
+/// be read back into an initialised network with `load`. Each checkpoint is written to a temporary file next to the
+/// path (with `.tmp` appended to its name) which is then renamed over it, so that the previous checkpoint is kept
+/// whole if training is killed part way through writing the next one.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FileCheckpoint {
+    path: std::path::PathBuf,
+}
+
+#[cfg(feature = "std")]
+impl FileCheckpoint {
+    /// Constructs a new checkpoint which writes the weights to the file at the given path.
+    #[must_use]
+    pub fn new(path: impl Into<std::path::PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<N> Checkpoint<N> for FileCheckpoint
+where
+    N: TrainableOperation + Clone,
+    N::Initialised: InitialisedOperation,
+{
+    fn save(&mut self, _epoch: u32, _evaluation_loss: ElementType, network: &N) -> Result<()> {
+        let bytes = crate::private::native::encode(&network.clone().into_initialised());
+        let mut temporary = self.path.clone().into_os_string();
+        temporary.push(".tmp");
+        std::fs::write(&temporary, bytes)
+            .and_then(|()| std::fs::rename(&temporary, &self.path))
+            .map_err(|_| Error::CheckpointFailed)
+    }
+}
+
+/// The history of a training run, recording the training loss, evaluation loss and learning
+/// rate of each epoch that was completed. This can be used to plot learning curves.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TrainingHistory {
+    training_losses: Vec<Option<ElementType>>,
+    evaluation_losses: Vec<Option<ElementType>>,
+    evaluation_metrics: Vec<Option<ElementType>>,
+    learning_rates: Vec<Option<ElementType>>,
+    loss_gaps: Vec<Option<ElementType>>,
+    resume: ResumeState,
+}
+
+impl TrainingHistory {
+    /// Gets the training loss of each epoch, which is the mean of the losses of the batches in the
+    /// epoch (calculated before the parameters were updated with each batch), or `None` for those epochs
+    /// where every batch was skipped with `NonFiniteHandling::SkipBatch`.
+    #[must_use]
+    pub fn training_losses(&self) -> &[Option<ElementType>] {
+        &self.training_losses
+    }
+
+    /// Gets the loss against the testing data of each epoch, which is `None` for those
+    /// epochs where the network wasn't evaluated.
+    #[must_use]
+    pub fn evaluation_losses(&self) -> &[Option<ElementType>] {
+        &self.evaluation_losses
+    }
+
+    /// Gets the value of the metric against the testing data of each epoch, which is `None` for those
+    /// epochs where the network wasn't evaluated, or if training wasn't given a metric to evaluate.
+    #[must_use]
+    pub fn evaluation_metrics(&self) -> &[Option<ElementType>] {
+        &self.evaluation_metrics
+    }
+
+    /// Gets the learning rate at the start of each epoch, which is `None` if the
+    /// network doesn't have an optimiser that uses a learning rate.
+    #[must_use]
+    pub fn learning_rates(&self) -> &[Option<ElementType>] {
+        &self.learning_rates
+    }
+
+    /// Gets the gap between the evaluation loss and the loss against the held sample of the training data of each
+    /// epoch, which is `None` for those epochs where the network wasn't evaluated, or if training wasn't given a loss
+    /// gap sample. A gap which grows over training is a sign that the network is overfitting the training data.
+    #[must_use]
+    pub fn loss_gaps(&self) -> &[Option<ElementType>] {
+        &self.loss_gaps
+    }
+
+    /// Gets the state that training can be resumed from with `TrainingOptions::with_resume`, as of the end
+    /// of the run.
+    #[must_use]
+    pub const fn resume_state(&self) -> ResumeState {
+        self.resume
+    }
+
+    /// Gets the number of epochs that were recorded.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.training_losses.len()
+    }
+
+    /// Checks whether no epochs were recorded.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.training_losses.is_empty()
+    }
+
+    fn push(
+        &mut self,
+        training_loss: Option<ElementType>,
+        evaluation_loss: Option<ElementType>,
+        evaluation_metric: Option<ElementType>,
+        learning_rate: Option<ElementType>,
+        loss_gap: Option<ElementType>,
+    ) {
+        self.training_losses.push(training_loss);
+        self.evaluation_losses.push(evaluation_loss);
+        self.evaluation_metrics.push(evaluation_metric);
+        self.learning_rates.push(learning_rate);
+        self.loss_gaps.push(loss_gap);
+    }
+}
+
+/// The reason that a `Trainer` (or a network described by an `Architecture`) couldn't be built from its configuration.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "thiserror", derive(thiserror::Error))]
+#[non_exhaustive]
+pub enum ConfigError {
+    /// The number of epochs to train for was zero (or wasn't set).
+    #[cfg_attr(
+        feature = "thiserror",
+        error("the number of epochs must be greater than zero")
+    )]
+    ZeroEpochs,
+    /// The number of rows in each batch was zero.
+    #[cfg_attr(
+        feature = "thiserror",
+        error("the batch size must be greater than zero")
+    )]
+    ZeroBatchSize,
+    /// The number of epochs between evaluations against the testing data was zero.
+    #[cfg_attr(
+        feature = "thiserror",
+        error("the number of epochs between evaluations must be greater than zero")
+    )]
+    ZeroEvalEvery,
+    /// The amount that the testing loss must improve by for early stopping was negative or NaN.
+    #[cfg_attr(
+        feature = "thiserror",
+        error("the minimum improvement must be at least zero")
+    )]
+    InvalidMinDelta,
+    /// The fraction of the training data to hold out for validation wasn't between zero and one.
+    #[cfg_attr(
+        feature = "thiserror",
+        error("the validation split must be between zero and one")
+    )]
+    InvalidValidationSplit,
+    /// The maximum number of optimisation steps was zero.
+    #[cfg_attr(
+        feature = "thiserror",
+        error("the maximum number of steps must be greater than zero")
+    )]
+    ZeroMaxSteps,
+    /// The number of rows of the training data held to measure the loss gap was zero.
+    #[cfg_attr(
+        feature = "thiserror",
+        error("the loss gap sample must have at least one row")
+    )]
+    ZeroLossGapSample,
+    /// The number of snapshots of the best networks to keep was zero.
+    #[cfg_attr(
+        feature = "thiserror",
+        error("the number of snapshots to keep must be greater than zero")
+    )]
+    ZeroTopN,
+    /// An architecture contained a layer which makes random decisions during training (such as dropout), which
+    /// a `DynNetwork` can't train because it always runs its layers as they are during inference.
+    #[cfg_attr(
+        feature = "thiserror",
+        error("dynamic networks don't support stochastic layers such as dropout")
+    )]
+    StochasticLayer,
+}
+
+impl<T: Callback + ?Sized> Callback for &mut T {
+    fn on_epoch_start(&mut self, epoch: u32) -> ControlFlow<()> {
+        (**self).on_epoch_start(epoch)
+    }
+
+    fn on_batch_end(&mut self, epoch: u32, batch_loss: ElementType) -> ControlFlow<()> {
+        (**self).on_batch_end(epoch, batch_loss)
+    }
+
+    fn on_epoch_end(
+        &mut self,
+        epoch: u32,
+        evaluation_loss: Option<ElementType>,
+    ) -> ControlFlow<()> {
+        (**self).on_epoch_end(epoch, evaluation_loss)
+    }
+
+    fn on_loss_gap(
+        &mut self,
+        epoch: u32,
+        training_loss: ElementType,
+        evaluation_loss: ElementType,
+    ) -> ControlFlow<()> {
+        (**self).on_loss_gap(epoch, training_loss, evaluation_loss)
+    }
+}
+
+/// A list of callbacks which are all invoked in order at each hook, aborting training if any of them do.
+struct Callbacks<'a>(Vec<Box<dyn Callback + 'a>>);
+
+impl Callbacks<'_> {
+    /// Invokes the given hook on every callback, breaking if any of them broke.
+    fn each(
+        &mut self,
+        mut hook: impl FnMut(&mut dyn Callback) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        let mut flow = ControlFlow::Continue(());
+        for callback in &mut self.0 {
+            if hook(&mut **callback).is_break() {
+                flow = ControlFlow::Break(());
+            }
+        }
+        flow
+    }
+}
+
+impl Callback for Callbacks<'_> {
+    fn on_epoch_start(&mut self, epoch: u32) -> ControlFlow<()> {
+        self.each(|callback| callback.on_epoch_start(epoch))
+    }
+
+    fn on_batch_end(&mut self, epoch: u32, batch_loss: ElementType) -> ControlFlow<()> {
+        self.each(|callback| callback.on_batch_end(epoch, batch_loss))
+    }
+
+    fn on_epoch_end(
+        &mut self,
+        epoch: u32,
+        evaluation_loss: Option<ElementType>,
+    ) -> ControlFlow<()> {
+        self.each(|callback| callback.on_epoch_end(epoch, evaluation_loss))
+    }
+
+    fn on_loss_gap(
+        &mut self,
+        epoch: u32,
+        training_loss: ElementType,
+        evaluation_loss: ElementType,
+    ) -> ControlFlow<()> {
+        self.each(|callback| callback.on_loss_gap(epoch, training_loss, evaluation_loss))
+    }
+}
+
+/// Wraps the callbacks of a trainer to stop training once it has taken its maximum number of optimisation
+/// steps or, with the *std* feature, has run for its maximum duration. The budget is checked after each batch.
+struct Budget<'c> {
+    callback: &'c mut dyn Callback,
+    steps: u64,
+    max_steps: Option<u64>,
+    #[cfg(feature = "std")]
+    deadline: Option<std::time::Instant>,
+}
+
+impl Budget<'_> {
+    /// Checks whether training has used up its budget.
+    fn exhausted(&self) -> bool {
+        let exhausted = self
+            .max_steps
+            .is_some_and(|max_steps| self.steps >= max_steps);
+        #[cfg(feature = "std")]
+        let exhausted = exhausted
+            || self
+                .deadline
+                .is_some_and(|deadline| std::time::Instant::now() >= deadline);
+        exhausted
+    }
+}
+
+impl Callback for Budget<'_> {
+    fn on_epoch_start(&mut self, epoch: u32) -> ControlFlow<()> {
+        self.callback.on_epoch_start(epoch)
+    }
+
+    fn on_batch_end(&mut self, epoch: u32, batch_loss: ElementType) -> ControlFlow<()> {
+        self.steps += 1;
+        let flow = self.callback.on_batch_end(epoch, batch_loss);
+        if self.exhausted() {
+            ControlFlow::Break(())
+        } else {
+            flow
+        }
+    }
+
+    fn on_epoch_end(
+        &mut self,
+        epoch: u32,
+        evaluation_loss: Option<ElementType>,
+    ) -> ControlFlow<()> {
+        self.callback.on_epoch_end(epoch, evaluation_loss)
+    }
+
+    fn on_loss_gap(
+        &mut self,
+        epoch: u32,
+        training_loss: ElementType,
+        evaluation_loss: ElementType,
+    ) -> ControlFlow<()> {
+        self.callback
+            .on_loss_gap(epoch, training_loss, evaluation_loss)
+    }
+}
+
+/// Builds a `Trainer` one setting at a time, starting from `Trainer::builder`. The loss function must be given
+/// before the trainer can be built, and the other settings default to a batch size of 32, evaluating every epoch,
+/// a seed of zero, shuffling all of the training data each epoch, keeping the single best network for `train_top_n`,
+/// no metric or checkpoint, and the default `TrainingOptions`.
+pub struct TrainerBuilder<'a, L, C = ()> {
+    loss_function: L,
+    epochs: u32,
+    batch_size: usize,
+    eval_every: u32,
+    seed: u64,
+    shuffle: Shuffle,
+    validation_split: Option<ElementType>,
+    max_steps: Option<u64>,
+    #[cfg(feature = "std")]
+    max_duration: Option<std::time::Duration>,
+    loss_gap_sample: Option<usize>,
+    options: TrainingOptions,
+    early_stopping: Option<(u16, ElementType)>,
+    callbacks: Vec<Box<dyn Callback + 'a>>,
+    metric: Option<Box<dyn Metric + 'a>>,
+    checkpoint: C,
+    top_n: usize,
+}
+
+impl<'a, L, C> TrainerBuilder<'a, L, C> {
+    /// Sets the loss function that the network is trained to minimise.
+    #[must_use]
+    pub fn loss<M: Loss>(self, loss_function: M) -> TrainerBuilder<'a, M, C> {
+        TrainerBuilder {
+            loss_function,
+            epochs: self.epochs,
+            batch_size: self.batch_size,
+            eval_every: self.eval_every,
+            seed: self.seed,
+            shuffle: self.shuffle,
+            validation_split: self.validation_split,
+            max_steps: self.max_steps,
+            #[cfg(feature = "std")]
+            max_duration: self.max_duration,
+            loss_gap_sample: self.loss_gap_sample,
+            options: self.options,
+            early_stopping: self.early_stopping,
+            callbacks: self.callbacks,
+            metric: self.metric,
+            checkpoint: self.checkpoint,
+            top_n: self.top_n,
+        }
+    }
+
+    /// Sets the checkpoint which the best network seen so far is saved to each time the evaluation loss improves.
+    #[must_use]
+    pub fn checkpoint<D>(self, checkpoint: D) -> TrainerBuilder<'a, L, D> {
+        TrainerBuilder {
+            loss_function: self.loss_function,
+            epochs: self.epochs,
+            batch_size: self.batch_size,
+            eval_every: self.eval_every,
+            seed: self.seed,
+            shuffle: self.shuffle,
+            validation_split: self.validation_split,
+            max_steps: self.max_steps,
+            #[cfg(feature = "std")]
+            max_duration: self.max_duration,
+            loss_gap_sample: self.loss_gap_sample,
+            options: self.options,
+            early_stopping: self.early_stopping,
+            callbacks: self.callbacks,
+            metric: self.metric,
+            checkpoint,
+            top_n: self.top_n,
+        }
+    }
+
+    /// Sets the number of epochs to train for, which must be greater than zero.
+    #[must_use]
+    pub fn epochs(self, epochs: u32) -> Self {
+        Self { epochs, ..self }
+    }
+
+    /// Sets the number of rows in each batch, which must be greater than zero.
+    #[must_use]
+    pub fn batch_size(self, batch_size: usize) -> Self {
+        Self { batch_size, ..self }
+    }
+
+    /// Sets the number of epochs between each evaluation of the network against the testing data,
+    /// which must be greater than zero.
+    #[must_use]
+    pub fn eval_every(self, eval_every: u32) -> Self {
+        Self { eval_every, ..self }
+    }
+
+    /// Sets the seed that the rows of the training data are shuffled with.
+    #[must_use]
+    pub fn seed(self, seed: u64) -> Self {
+        Self { seed, ..self }
+    }
+
+    /// Sets how the rows of the training data are shuffled each epoch, which is across all of the rows by default.
+    #[must_use]
+    pub fn shuffle(self, shuffle: Shuffle) -> Self {
+        Self { shuffle, ..self }
+    }
+
+    /// Sets the fraction of the training data (between zero and one) that `fit` holds out to evaluate the
+    /// network against, chosen at random using the seed. Without a validation split, `fit` evaluates the
+    /// network against the training data itself.
+    #[must_use]
+    pub fn validation_split(self, fraction: ElementType) -> Self {
+        Self {
+            validation_split: Some(fraction),
+            ..self
+        }
+    }
+
+    /// Stops training once the network has been optimised the given number of times in total, which must be
+    /// greater than zero. Batches which are skipped because of NaN or infinite losses don't count. Training stops
+    /// straight after the last step, even part way through an epoch, and returns the network as it is then.
+    #[must_use]
+    pub fn max_steps(self, max_steps: u64) -> Self {
+        Self {
+            max_steps: Some(max_steps),
+            ..self
+        }
+    }
+
+    /// Stops training at the end of the first batch which finishes after the given amount of time has passed
+    /// since training started, returning the network as it is then. Together with `max_steps`, this allows a
+    /// number of configurations to be given the same budget, with the number of epochs as an upper limit.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn max_duration(self, max_duration: std::time::Duration) -> Self {
+        Self {
+            max_duration: Some(max_duration),
+            ..self
+        }
+    }
+
+    /// Holds a sample of the given number of rows of the training data (chosen at random from the seed), and
+    /// measures the loss against it each time the network is evaluated, so that the gap between the training
+    /// and evaluation losses can be watched for overfitting. The gap is reported to the callbacks through
+    /// `Callback::on_loss_gap` and recorded in the `TrainingHistory`. The number of rows must be greater than zero.
+    /// The sample can only be taken when the training data is given as tensors, so it isn't measured by
+    /// `train_with_loader` or `train_with_batches`.
+    #[must_use]
+    pub fn loss_gap_sample(self, rows: usize) -> Self {
+        Self {
+            loss_gap_sample: Some(rows),
+            ..self
+        }
+    }
+
+    /// Sets the options of training, such as how NaN and infinite losses are handled. The patience and minimum
+    /// improvement of the options are used unless `early_stopping` is also called, which takes precedence over them
+    /// whichever order the two are called in.
+    #[must_use]
+    pub fn options(self, options: TrainingOptions) -> Self {
+        Self { options, ..self }
+    }
+
+    /// Stops training early once the testing loss has gone the given number of evaluations without
+    /// improving on the best loss seen by at least the given amount, which must be at least zero.
+    /// This replaces the patience and minimum improvement of any `options`.
+    #[must_use]
+    pub fn early_stopping(self, patience: u16, min_delta: ElementType) -> Self {
+        Self {
+            early_stopping: Some((patience, min_delta)),
+            ..self
+        }
+    }
+
+    /// Adds a callback which is invoked as training progresses, after any callbacks added before it.
+    /// A mutable reference to a callback can be given so that it can be inspected after training.
+    #[must_use]
+    pub fn callback(mut self, callback: impl Callback + 'a) -> Self {
+        self.callbacks.push(Box::new(callback));
+        self
+    }
+
+    /// Sets a metric (such as `Accuracy`) to evaluate against the testing data each time the loss is
+    /// evaluated, which is recorded in the `TrainingHistory` alongside the evaluation loss.
+    #[must_use]
+    pub fn metric(self, metric: impl Metric + 'a) -> Self {
+        Self {
+            metric: Some(Box::new(metric)),
+            ..self
+        }
+    }
+
+    /// Sets the number of snapshots of the network with the lowest testing loss that `train_top_n` returns,
+    /// which must be greater than zero.
+    #[must_use]
+    pub fn top_n(self, count: usize) -> Self {
+        Self {
+            top_n: count,
+            ..self
+        }
+    }
+}
+
+impl<'a, L: Loss, C> TrainerBuilder<'a, L, C> {
+    /// Builds the trainer, checking that its configuration is valid.
+    ///
+    /// # Errors
+    /// Returns an `Error::InvalidConfig` if the number of epochs, batch size, number of epochs between
+    /// evaluations, maximum number of steps, loss gap sample or number of snapshots to keep is zero, if the
+    /// minimum improvement for early stopping is negative, or if the validation split isn't between zero and one.
+    pub fn build(self) -> Result<Trainer<'a, L, C>> {
+        let error = |error| Err(Error::InvalidConfig(error));
+        let options = match self.early_stopping {
+            Some((patience, min_delta)) => self
+                .options
+                .with_patience(patience)
+                .with_min_delta(min_delta),
+            None => self.options,
+        };
+        if self.epochs == 0 {
+            return error(ConfigError::ZeroEpochs);
+        }
+        if self.batch_size == 0 {
+            return error(ConfigError::ZeroBatchSize);
+        }
+        if self.eval_every == 0 {
+            return error(ConfigError::ZeroEvalEvery);
+        }
+        if options.min_delta.is_nan() || options.min_delta < 0.0 {
+            return error(ConfigError::InvalidMinDelta);
+        }
+        if self
+            .validation_split
+            .is_some_and(|fraction| !(fraction > 0.0 && fraction < 1.0))
+        {
+            return error(ConfigError::InvalidValidationSplit);
+        }
+        if self.max_steps == Some(0) {
+            return error(ConfigError::ZeroMaxSteps);
+        }
+        if self.loss_gap_sample == Some(0) {
+            return error(ConfigError::ZeroLossGapSample);
+        }
+        if self.top_n == 0 {
+            return error(ConfigError::ZeroTopN);
+        }
+        Ok(Trainer {
+            loss_function: self.loss_function,
+            epochs: self.epochs,
+            batch_size: self.batch_size,
+            eval_every: self.eval_every,
+            seed: self.seed,
+            shuffle: self.shuffle,
+            validation_split: self.validation_split,
+            max_steps: self.max_steps,
+            #[cfg(feature = "std")]
+            max_duration: self.max_duration,
+            loss_gap_sample: self.loss_gap_sample,
+            options,
+            callbacks: Callbacks(self.callbacks),
+            metric: self.metric,
+            checkpoint: self.checkpoint,
+            top_n: self.top_n,
+        })
+    }
+}
+
+/// Runs the standard training process of a network in the same way as the `train` function, but
+/// configured through a builder rather than a long list of arguments. Every setting of the builder
+/// applies to each of the ways of training, so they can be freely combined.
+///
+/// ```
+/// use eidetic::activations::Linear;
+/// use eidetic::layers::{Chain, Dense, Input};
+/// use eidetic::loss::MeanSquaredError;
+/// use eidetic::operations::{UninitialisedOperation, WithOptimiser};
+/// use eidetic::optimisers::learning_rate_handlers::FixedLearningRateHandler;
+/// use eidetic::optimisers::SGD;
+/// use eidetic::tensors::{rank, Tensor};
+/// use eidetic::training::Trainer;
+/// let network = Input::new(1)
+///     .chain(Dense::new(1, Linear::new()))
+///     .with_seed(42)
+///     .with_optimiser(SGD::new(FixedLearningRateHandler::new(0.1)));
+/// let batch = Tensor::<rank::Two>::new((4, 1), [1.0, 2.0, 3.0, 4.0]).unwrap();
+/// let targets = Tensor::<rank::Two>::new((4, 1), [3.0, 5.0, 7.0, 9.0]).unwrap();
+/// let mut trainer = Trainer::builder()
+///     .loss(MeanSquaredError::new())
+///     .epochs(100)
+///     .batch_size(2)
+///     .eval_every(10)
+///     .early_stopping(3, 0.0)
+///     .build()
+///     .unwrap();
+/// let network = trainer
+///     .train(network, batch.clone(), targets.clone(), &batch, &targets)
+///     .unwrap();
+/// ```
+pub struct Trainer<'a, L, C = ()> {
+    loss_function: L,
+    epochs: u32,
+    batch_size: usize,
+    eval_every: u32,
+    seed: u64,
+    shuffle: Shuffle,
+    validation_split: Option<ElementType>,
+    max_steps: Option<u64>,
+    #[cfg(feature = "std")]
+    max_duration: Option<std::time::Duration>,
+    loss_gap_sample: Option<usize>,
+    options: TrainingOptions,
+    callbacks: Callbacks<'a>,
+    metric: Option<Box<dyn Metric + 'a>>,
+    checkpoint: C,
+    top_n: usize,
+}
+
+impl<'a> Trainer<'a, ()> {
+    /// Starts building a trainer, which needs at least a loss function and a number of epochs.
+    #[must_use]
+    pub fn builder() -> TrainerBuilder<'a, ()> {
+        TrainerBuilder {
+            loss_function: (),
+            epochs: 0,
+            batch_size: 32,
+            eval_every: 1,
+            seed: 0,
+            shuffle: Shuffle::Full,
+            validation_split: None,
+            max_steps: None,
+            #[cfg(feature = "std")]
+            max_duration: None,
+            loss_gap_sample: None,
+            options: TrainingOptions::new(),
+            early_stopping: None,
+            callbacks: Vec::new(),
+            metric: None,
+            checkpoint: (),
+            top_n: 1,
+        }
+    }
+}
+
+impl<L: Loss, C> Trainer<'_, L, C> {
+    /// Trains the network on the training data, evaluating it against the testing data as configured,
+    /// and returns the trained network.
+    ///
+    /// # Errors
+    /// Returns an `eidetic::Error` if the shapes of batches or targets don't agree with the network, if the number of
+    /// rows in a batch doesn't match the number of rows in a targets tensor, if the metric can't be evaluated, or if
+    /// a checkpoint couldn't be saved.
+    pub fn train<N>(
+        &mut self,
+        network: N,
+        batch_train: Tensor<rank::Two>,
+        targets_train: Tensor<rank::Two>,
+        batch_test: &Tensor<rank::Two>,
+        targets_test: &Tensor<rank::Two>,
+    ) -> Result<N>
+    where
+        for<'b> N: TrainableOperation
+            + Forward<'b, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>
+            + Clone,
+        C: Checkpoint<N>,
+    {
+        let (network, _) = self.train_with_history(
+            network,
+            batch_train,
+            targets_train,
+            batch_test,
+            targets_test,
+        )?;
+        Ok(network)
+    }
+
+    /// Trains the network in the same way as `train`, but also returns the history of the
+    /// training loss, evaluation loss, metric and learning rate of each epoch.
+    ///
+    /// # Errors
+    /// Returns an `eidetic::Error` if the shapes of batches or targets don't agree with the network, if the number of
+    /// rows in a batch doesn't match the number of rows in a targets tensor, if the metric can't be evaluated, or if
+    /// a checkpoint couldn't be saved.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn train_with_history<N>(
+        &mut self,
+        network: N,
+        batch_train: Tensor<rank::Two>,
+        targets_train: Tensor<rank::Two>,
+        batch_test: &Tensor<rank::Two>,
+        targets_test: &Tensor<rank::Two>,
+    ) -> Result<(N, TrainingHistory)>
+    where
+        for<'b> N: TrainableOperation
+            + Forward<'b, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>
+            + Clone,
+        C: Checkpoint<N>,
+    {
+        let (network, _, history) = self.run_in_memory(
+            network,
+            &Sequential,
+            (&batch_train, &targets_train),
+            (batch_test, targets_test),
+            0,
+        )?;
+        Ok((network, history))
+    }
+
+    /// Trains the network in the same way as `train_with_history`, but also retains snapshots of the network each
+    /// time it's evaluated against the testing data. Returns the configured number of those snapshots (see `top_n`)
+    /// with the lowest testing loss, paired with that loss and ordered by ascending loss, along with the history.
+    /// This is useful for ensembling the best networks seen during training.
+    ///
+    /// # Errors
+    /// Returns an `eidetic::Error` if the shapes of batches or targets don't agree with the network, if the number of
+    /// rows in a batch doesn't match the number of rows in a targets tensor, if the metric can't be evaluated, or if
+    /// a checkpoint couldn't be saved.
+    pub fn train_top_n<N>(
+        &mut self,
+        network: N,
+        batch_train: &Tensor<rank::Two>,
+        targets_train: &Tensor<rank::Two>,
+        batch_test: &Tensor<rank::Two>,
+        targets_test: &Tensor<rank::Two>,
+    ) -> Result<(Vec<(ElementType, N)>, TrainingHistory)>
+    where
+        for<'b> N: TrainableOperation
+            + Forward<'b, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>
+            + Clone,
+        C: Checkpoint<N>,
+    {
+        let (_, snapshots, history) = self.run_in_memory(
+            network,
+            &Sequential,
+            (batch_train, targets_train),
+            (batch_test, targets_test),
+            self.top_n,
+        )?;
+        Ok((snapshots, history))
+    }
+
+    /// Trains the network in the same way as `train_with_history`, but takes the batches of training data from the
+    /// given data loader rather than from a tensor holding all of the training data. This allows samples to be loaded
+    /// or generated lazily as each batch is needed. The samples are shuffled each epoch using the seed plus the epoch
+    /// number, and the batch size and shuffling of the data loader are used rather than those of the trainer.
+    ///
+    /// # Errors
+    /// Returns an `eidetic::Error` if the shapes of batches or targets don't agree with the network, if the number of
+    /// rows in the testing batch doesn't match the number of rows in the testing targets, if the data loader can't
+    /// provide a batch, if the metric can't be evaluated, or if a checkpoint couldn't be saved.
+    pub fn train_with_loader<N, D: Dataset>(
+        &mut self,
+        network: N,
+        loader: &DataLoader<D>,
+        batch_test: &Tensor<rank::Two>,
+        targets_test: &Tensor<rank::Two>,
+    ) -> Result<(N, TrainingHistory)>
+    where
+        for<'b> N: TrainableOperation
+            + Forward<'b, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>
+            + Clone,
+        C: Checkpoint<N>,
+    {
+        let seed = self.seed;
+        let (network, _, history) = self.run(
+            network,
+            &Sequential,
+            &mut |epoch| loader.batches(seed + u64::from(epoch)),
+            (batch_test, targets_test),
+            None,
+            0,
+        )?;
+        Ok((network, history))
+    }
+
+    /// Trains the network in the same way as `train_with_history`, but streams the batches of training data from the
+    /// given function rather than taking a tensor holding all of the training data. The function is called with the
+    /// number of each epoch as it starts, and provides the pairs of batches and targets to train on during that epoch.
+    /// This allows datasets which are larger than memory to be read in as each batch is needed, and shuffled in
+    /// whatever way suits how they're stored. The batch size, seed and shuffling of the trainer aren't used.
+    ///
+    /// # Errors
+    /// Returns an `eidetic::Error` if the shapes of batches or targets don't agree with the network, if the number of
+    /// rows in a batch doesn't match the number of rows in a targets tensor, if the metric can't be evaluated, or if
+    /// a checkpoint couldn't be saved.
+    pub fn train_with_batches<N, I: IntoIterator<Item = (Tensor<rank::Two>, Tensor<rank::Two>)>>(
+        &mut self,
+        network: N,
+        mut batches: impl FnMut(u32) -> I,
+        batch_test: &Tensor<rank::Two>,
+        targets_test: &Tensor<rank::Two>,
+    ) -> Result<(N, TrainingHistory)>
+    where
+        for<'b> N: TrainableOperation
+            + Forward<'b, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>
+            + Clone,
+        C: Checkpoint<N>,
+    {
+        let (network, _, history) = self.run(
+            network,
+            &Sequential,
+            &mut |epoch| batches(epoch).into_iter().map(Ok),
+            (batch_test, targets_test),
+            None,
+            0,
+        )?;
+        Ok((network, history))
+    }
+
+    /// Trains the network in the same way as `train_with_history`, but splits each batch between the given number of
+    /// replicas of the network, running their forward and backward passes in parallel across threads. The losses and
+    /// gradients of the replicas are combined according to the reduction of the loss function before the network is
+    /// optimised with them, averaging them (weighted by the number of rows each replica was given) for losses which
+    /// average over the rows of a batch and adding them for losses which sum, which matches training on the whole
+    /// batch. Layers which make random decisions are always seeded as with `TrainingOptions::with_deterministic`
+    /// (using the seed of the trainer unless the options have their own), so that every replica makes the same
+    /// decisions as the network. The network itself trains on the first share of each batch and is optimised with
+    /// the combined gradients through its own backward pass, so no extra pass is run.
+    ///
+    /// # Errors
+    /// Returns an `eidetic::Error` if the shapes of batches or targets don't agree with the network, if the number of
+    /// rows in a batch doesn't match the number of rows in a targets tensor, if the metric can't be evaluated, or if
+    /// a checkpoint couldn't be saved. Returns `Error::InvalidArgument` if the loss function doesn't report its
+    /// reduction (which is the default for loss functions outside of this crate), or neither averages nor sums the
+    /// losses of the rows, such as a `MultiHead` loss with heads of both kinds.
+    #[cfg(feature = "rayon")]
+    pub fn train_data_parallel<N>(
+        &mut self,
+        network: N,
+        replicas: usize,
+        batch_train: &Tensor<rank::Two>,
+        targets_train: &Tensor<rank::Two>,
+        batch_test: &Tensor<rank::Two>,
+        targets_test: &Tensor<rank::Two>,
+    ) -> Result<(N, TrainingHistory)>
+    where
+        for<'b> N: TrainableOperation
+            + Forward<'b, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>
+            + Clone
+            + Send,
+        L: Sync,
+        C: Checkpoint<N>,
+    {
+        let options = self.options;
+        self.options.seed = options.seed.or(Some(self.seed));
+        let result = self.run_in_memory(
+            network,
+            &DataParallel(replicas),
+            (batch_train, targets_train),
+            (batch_test, targets_test),
+            0,
+        );
+        self.options = options;
+        let (network, _, history) = result?;
+        Ok((network, history))
+    }
+
+    /// Trains the network in the same way as `train`, but without being given testing data. Instead, the
+    /// configured validation split of the rows of the training data is held out to evaluate the network against.
+    ///
+    /// # Errors
+    /// Returns an `eidetic::Error` if the shapes of the batch or targets don't agree with the network, if the number of
+    /// rows in the batch doesn't match the number of rows in the targets, if there are too few rows to split, if the
+    /// metric can't be evaluated, or if a checkpoint couldn't be saved.
+    pub fn fit<N>(
+        &mut self,
+        network: N,
+        batch: &Tensor<rank::Two>,
+        targets: &Tensor<rank::Two>,
+    ) -> Result<N>
+    where
+        for<'b> N: TrainableOperation
+            + Forward<'b, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>
+            + Clone,
+        C: Checkpoint<N>,
+    {
+        let (network, _) = self.fit_with_history(network, batch, targets)?;
+        Ok(network)
+    }
+
+    /// Trains the network in the same way as `fit`, but also returns the history of the
+    /// training loss, evaluation loss, metric and learning rate of each epoch.
+    ///
+    /// # Errors
+    /// Returns an `eidetic::Error` if the shapes of the batch or targets don't agree with the network, if the number of
+    /// rows in the batch doesn't match the number of rows in the targets, if there are too few rows to split, if the
+    /// metric can't be evaluated, or if a checkpoint couldn't be saved.
+    pub fn fit_with_history<N>(
+        &mut self,
+        network: N,
+        batch: &Tensor<rank::Two>,
+        targets: &Tensor<rank::Two>,
+    ) -> Result<(N, TrainingHistory)>
+    where
+        for<'b> N: TrainableOperation
+            + Forward<'b, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>
+            + Clone,
+        C: Checkpoint<N>,
+    {
+        match self.validation_split {
+            Some(fraction) => {
+                let ((batch_train, targets_train), (batch_test, targets_test)) =
+                    split_validation(batch, targets, fraction, self.seed)?;
+                self.train_with_history(
+                    network,
+                    batch_train,
+                    targets_train,
+                    &batch_test,
+                    &targets_test,
+                )
+            }
+            None => {
+                self.train_with_history(network, batch.clone(), targets.clone(), batch, targets)
+            }
+        }
+    }
+
+    /// Runs the training process on the training data held in tensors, which are split into batches with the
+    /// batch size, seed and shuffling of the trainer, and from which any loss gap sample is taken.
+    fn run_in_memory<N>(
+        &mut self,
+        network: N,
+        step: &impl Step<N, L>,
+        (batch_train, targets_train): (&Tensor<rank::Two>, &Tensor<rank::Two>),
+        test: (&Tensor<rank::Two>, &Tensor<rank::Two>),
+        snapshots: usize,
+    ) -> Result<Run<N>>
+    where
+        for<'b> N: TrainableOperation
+            + Forward<'b, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>
+            + Clone,
+        C: Checkpoint<N>,
+    {
+        let gap_sample = self.loss_gap_sample.map(|rows| {
+            let indices = permuted_indices(batch_train.nrows(), self.seed);
+            let indices = &indices[..rows.min(indices.len())];
+            (
+                Tensor(batch_train.0.select(Axis(0), indices)),
+                Tensor(targets_train.0.select(Axis(0), indices)),
+            )
+        });
+        let mut batches = in_memory_batches(
+            batch_train,
+            targets_train,
+            self.batch_size,
+            self.seed,
+            self.shuffle,
+        )?;
+        self.run(
+            network,
+            step,
+            &mut batches,
+            test,
+            gap_sample.as_ref(),
+            snapshots,
+        )
+    }
+
+    /// Runs the training process with the settings of the trainer on the batches given for each epoch, returning
+    /// the trained network, up to the given number of the best snapshots, and the history.
+    fn run<N, I: IntoIterator<Item = Result<Batch>>>(
+        &mut self,
+        network: N,
+        step: &impl Step<N, L>,
+        batches: &mut impl FnMut(u32) -> I,
+        (batch_test, targets_test): (&Tensor<rank::Two>, &Tensor<rank::Two>),
+        gap_sample: Option<&Batch>,
+        snapshots: usize,
+    ) -> Result<Run<N>>
+    where
+        for<'b> N: TrainableOperation
+            + Forward<'b, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>
+            + Clone,
+        C: Checkpoint<N>,
+    {
+        let mut history = TrainingHistory::default();
+        let mut budget = Budget {
+            callback: &mut self.callbacks,
+            steps: 0,
+            max_steps: self.max_steps,
+            #[cfg(feature = "std")]
+            deadline: self
+                .max_duration
+                .and_then(|max_duration| std::time::Instant::now().checked_add(max_duration)),
+        };
+        let (network, snapshots) = train_private(
+            network,
+            &self.loss_function,
+            step,
+            batches,
+            batch_test,
+            targets_test,
+            self.epochs,
+            self.eval_every,
+            self.options,
+            &mut budget,
+            &mut self.checkpoint,
+            self.metric.as_deref(),
+            &mut history,
+            snapshots,
+            gap_sample,
+        )?;
+        Ok((network, snapshots, history))
+    }
+}
+
+/// Holds out the given fraction of the rows of the data (chosen at random from the seed) for validation,
+/// returning the remaining rows for training followed by the rows for validation. Both get at least one row.
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn split_validation(
+    batch: &Tensor<rank::Two>,
+    targets: &Tensor<rank::Two>,
+    fraction: ElementType,
+    seed: u64,
+) -> Result<(Batch, Batch)> {
+    let rows = batch.nrows();
+    Error::check_shape((rows, 1), (targets.nrows(), 1))?;
+    if rows < 2 {
+        return Err(Error::EmptyData);
+    }
+    let validation_rows = ((rows as ElementType * fraction).round() as usize).clamp(1, rows - 1);
+    let indices = permuted_indices(rows, seed);
+    let (train, validation) = indices.split_at(rows - validation_rows);
+    let select = |indices: &[usize]| {
+        (
+            Tensor(batch.0.select(Axis(0), indices)),
+            Tensor(targets.0.select(Axis(0), indices)),
+        )
+    };
+    Ok((select(train), select(validation)))
+}
+
+/// Function which runs a standard feed forward training process on a single
+/// neural network with a given loss function for calculating error, as well as
+/// a factory which can be used to define the optimisation strategy to use.
+/// A `Trainer` runs the same process configured with named settings, which is less error prone.
+///
+/// # Errors
+/// Returns an `eidetic::Error` if the shapes of batches or targets don't agree with the network, or if the number of
+/// rows in a batch doesn't match the number of rows in a targets tensor.
+#[allow(clippy::too_many_arguments, clippy::needless_pass_by_value)]
+pub fn train<N>(
+    network: N,
+    loss_function: &impl Loss,
+    batch_train: Tensor<rank::Two>,
+    targets_train: Tensor<rank::Two>,
+    batch_test: &Tensor<rank::Two>,
+    targets_test: &Tensor<rank::Two>,
+    epochs: u32,
+    eval_every: u32,
+    batch_size: usize,
+    seed: u64,
+) -> Result<N>
+where
+    for<'a> N: TrainableOperation
+        + Forward<'a, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>
+        + Clone,
+{
+    let (network, _) = train_private(
+        network,
+        loss_function,
+        &Sequential,
+        &mut in_memory_batches(
+            &batch_train,
+            &targets_train,
+            batch_size,
+            seed,
+            Shuffle::Full,
+        )?,
+        batch_test,
+        targets_test,
+        epochs,
+        eval_every,
+        TrainingOptions::new(),
+        &mut (),
+        &mut (),
+        None,
+        &mut TrainingHistory::default(),
+        0,
+        None,
+    )?;
+    Ok(network)
+}
+
+/// Function which trains an initialised network on the whole of the training data at once with the L-BFGS optimiser,
+/// for up to the given number of iterations. Each evaluation of the loss runs a forward and backward pass over all of
+/// the data, so this suits small networks and datasets, and because the data isn't shuffled or split into batches the
+/// training is deterministic. Returns the trained network along with its loss on the training data.
+///
+/// Layers which make random decisions during training (such as `Dropout`) would make the loss differ between evaluations
+/// at the same parameters, so they shouldn't be used. Any regularisation is ignored, as the optimisers which apply it
+/// aren't used.
+///
+/// # Errors
+/// Returns an `eidetic::Error` if the shapes of the batch or targets don't agree with the network, if the number of rows in
+/// the batch doesn't match the number of rows in the targets, or if the initial loss isn't finite.
+pub fn train_lbfgs<N>(
+    mut network: N,
+    loss_function: &impl Loss,
+    lbfgs: &LBFGS,
+    batch: &Tensor<rank::Two>,
+    targets: &Tensor<rank::Two>,
+    iterations: u32,
+) -> Result<(N, ElementType)>
+where
+    N: InitialisedOperation + WithOptimiser<capture::OptimiserFactory> + Clone,
+    for<'a> N::Trainable: TrainableOperation<Initialised = N>
+        + Forward<
+            'a,
+            Input = Tensor<rank::Two>,
+            Output = Tensor<rank::Two>,
+            Forward: ForwardOperation<Input = Tensor<rank::Two>>,
+        >,
+{
+    if batch.nrows() != targets.nrows() {
+        return Err(Error::BatchRowMismatch {
+            expected: batch.nrows(),
+            actual: targets.nrows(),
+        });
+    }
+    let mut objective = |parameters: &[ElementType]| {
+        // the capturing optimiser replaces each parameter with its gradient, so reading the
+        // parameters back out afterwards gives the gradients in the same order.
+        let mut trainable = with_parameters(network.clone(), parameters)
+            .with_optimiser(capture::OptimiserFactory::new());
+        let (forward, output) = trainable.forward(batch.clone())?;
+        let (loss, gradient) = loss_function.loss(&output, targets)?;
+        let (backward, _) = forward.backward(gradient)?;
+        backward.optimise();
+        Ok((loss, trainable.into_initialised().iter().collect()))
+    };
+    let mut parameters = network.iter().collect::<Vec<_>>();
+    let loss = lbfgs.minimise(&mut parameters, &mut objective, iterations)?;
+    network = with_parameters(network, &parameters);
+    Ok((network, loss))
+}
+
+/// Replaces the parameters of the network with the given parameters, in the same order as `iter`.
+fn with_parameters<N: InitialisedOperation>(mut network: N, parameters: &[ElementType]) -> N {
+    let mut parameters = parameters.iter();
+    network.map_parameters(&mut |elem| parameters.next().copied().unwrap_or(elem));
+    network
+}
+
+/// Function which estimates how well a network generalises by k-fold cross-validation. The data is
+/// shuffled with the given seed and partitioned into k folds. For each fold, a fresh network is built
+/// with the builder and trained on the other k - 1 folds (which are also used for early stopping so
+/// that the held-out fold is never seen), before calculating the loss of its predictions for the
+/// held-out fold. Returns the k validation losses in fold order.
+///
+/// # Errors
+/// Returns an `eidetic::Error` if k is less than 2 or greater than the number of rows, if the number of rows in the
+/// data doesn't match the number of rows in the targets, or if the shapes don't agree with the network.
+#[allow(clippy::too_many_arguments)]
+pub fn cross_validate<N>(
+    builder: impl Fn() -> N,
+    loss_function: &impl Loss,
+    data: &Tensor<rank::Two>,
+    targets: &Tensor<rank::Two>,
+    k: usize,
+    epochs: u32,
+    batch_size: usize,
+    seed: u64,
+) -> Result<Vec<ElementType>>
+where
+    for<'a> N: TrainableOperation
+        + Forward<'a, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>
+        + Clone,
+    <N as TrainableOperation>::Initialised:
+        InitialisedOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+{
+    let rows = data.0.nrows();
+    if rows != targets.0.nrows() {
+        return Err(Error::BatchRowMismatch {
+            expected: rows,
+            actual: targets.0.nrows(),
+        });
+    }
+    if k < 2 || k > rows {
+        return Err(Error::InvalidArgument);
+    }
+    let (data, targets) = permute_data(data.0.clone(), &targets.0, seed);
+    fold_ranges(rows, k)
+        .into_iter()
+        .map(|fold| {
+            let training_rows = (0..rows)
+                .filter(|row| !fold.contains(row))
+                .collect::<Vec<_>>();
+            let batch_train = Tensor(data.select(Axis(0), &training_rows));
+            let targets_train = Tensor(targets.select(Axis(0), &training_rows));
+            let batch_validation = Tensor(data.slice(s![fold.clone(), ..]).to_owned());
+            let targets_validation = Tensor(targets.slice(s![fold, ..]).to_owned());
+            let network = train(
+                builder(),
+                loss_function,
+                batch_train.clone(),
+                targets_train.clone(),
+                &batch_train,
+                &targets_train,
+                epochs,
+                epochs,
+                batch_size,
+                seed,
+            )?;
+            let predictions = network.into_initialised().predict(batch_validation)?;
+            let (loss, _) = loss_function.loss(&predictions, &targets_validation)?;
+            Ok(loss)
+        })
+        .collect()
+}
+
+/// Evaluates the network against the testing data, returning the loss (including any regularisation penalty), the
+/// value of the metric if given, and the loss against the sample of the training data if given, along with the network.
+fn evaluate<N, L: Loss>(
+    mut network: N,
+    loss_function: &L,
+    (batch_test, targets_test): (&Tensor<rank::Two>, &Tensor<rank::Two>),
+    metric: Option<&dyn Metric>,
+    gap_sample: Option<&Batch>,
+) -> Result<(ElementType, Option<ElementType>, Option<ElementType>, N)>
+where
+    for<'a> N:
+        TrainableOperation + Forward<'a, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+{
+    let penalty = network.penalty();
+    let (_, output) = network.forward(batch_test.clone())?;
+    let (loss, _) = loss_function.loss(&output, targets_test)?;
+    let metric = metric
+        .map(|metric| metric.evaluate(&output, targets_test))
+        .transpose()?;
+    let training_loss = gap_sample
+        .map(|(batch, targets)| {
+            let (_, output) = network.forward(batch.clone())?;
+            let (loss, _) = loss_function.loss(&output, targets)?;
+            Ok(loss + penalty)
+        })
+        .transpose()?;
+    Ok((loss + penalty, metric, training_loss, network))
+}
+
+/// Inserts the snapshot of a network with the given testing loss into the snapshots with the lowest loss (which are in
+/// order of ascending loss) if it's one of the best, keeping no more than the given number of snapshots.
+fn keep_snapshot<N: Clone>(
+    snapshots: &mut Vec<(ElementType, N)>,
+    count: usize,
+    loss: ElementType,
+    network: &N,
+) {
+    let position = snapshots.partition_point(|(other, _)| *other <= loss);
+    if position < count {
+        snapshots.insert(position, (loss, network.clone()));
+        snapshots.truncate(count);
+    }
+}
+
+/// Runs the training process, returning the trained network along with up to `checkpoint_count`
+/// of the evaluated snapshots with the lowest testing loss, in order of ascending loss. Each completed
+/// epoch is recorded into the given history, and each new best network is saved to the given checkpoint. If a
+/// sample of the training data is given, the loss against it is measured at each evaluation to find the loss gap.
+#[allow(clippy::too_many_arguments)]
+fn train_private<N, L, I: IntoIterator<Item = Result<Batch>>>(
+    mut network: N,
+    loss_function: &L,
+    step: &impl Step<N, L>,
+    batches: &mut impl FnMut(u32) -> I,
+    batch_test: &Tensor<rank::Two>,
+    targets_test: &Tensor<rank::Two>,
+    epochs: u32,
+    eval_every: u32,
+    options: TrainingOptions,
+    callback: &mut impl Callback,
+    checkpoint: &mut impl Checkpoint<N>,
+    metric: Option<&dyn Metric>,
+    history: &mut TrainingHistory,
+    checkpoint_count: usize,
+    gap_sample: Option<&Batch>,
+) -> Result<(N, Vec<(ElementType, N)>)>
+where
+    for<'a> N: TrainableOperation
+        + Forward<'a, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>
+        + Clone,
+    L: Loss,
+{
+    // check the testing data is correctly shaped first (number of rows in the
+    // batch should match number of rows in the targets).
+    if batch_test.0.nrows() != targets_test.0.nrows() {
+        return Err(Error::BatchRowMismatch {
+            expected: batch_test.0.nrows(),
+            actual: targets_test.0.nrows(),
+        });
+    }
+
+    // make the network trainable first.
+    let mut best_network: Option<N> = None;
+    let mut checkpoints: Vec<(ElementType, N)> = Vec::new();
+    history.resume = options.resume;
+    if options.resume.epoch == 0 {
+        network.init(epochs);
+        if let Some(seed) = options.seed {
+            network.reseed(seed);
+        }
+    }
+
+    // loop number of epochs. For each one, permute data, generate batches
+    // and every "eval_every" epochs, check against testing data.
+    'epochs: for e in options.resume.epoch..epochs {
+        if callback.on_epoch_start(e).is_break() {
+            break;
+        }
+        let learning_rate = network.learning_rate();
+
+        // potentially store the last model if this is an epoch where we may need to return to it.
+        let last_model = if (e + 1) % eval_every == 0 {
+            Some(network.clone())
+        } else {
+            None
+        };
+
+        // get the batches of this epoch, and for each one run a training pass for it.
+        let (mut epoch_loss, mut batch_count): (ElementType, ElementType) = (0.0, 0.0);
+        for (index, batch) in batches(e).into_iter().enumerate() {
+            let check_finite = options.non_finite != NonFiniteHandling::Ignore;
+            let Some(loss) = step.step(&mut network, loss_function, batch?, check_finite)? else {
+                if options.non_finite == NonFiniteHandling::Abort {
+                    return Err(Error::NonFiniteValue {
+                        epoch: e,
+                        batch: index,
+                    });
+                }
+                continue;
+            };
+            network.end_batch();
+            epoch_loss += loss;
+            batch_count += 1.0;
+            if callback.on_batch_end(e, loss).is_break() {
+                break 'epochs;
+            }
+        }
+
+        // if we're on an epoch that's evaluating the loss against the test batch,
+        // then we will do this and early out if the loss stops improving.
+        let evaluation = last_model
+            .map(|last_model| {
+                evaluate(
+                    last_model,
+                    loss_function,
+                    (batch_test, targets_test),
+                    metric,
+                    gap_sample,
+                )
+            })
+            .transpose()?;
+        let evaluation_loss = evaluation.as_ref().map(|(loss, _, _, _)| *loss);
+        let evaluation_metric = evaluation.as_ref().and_then(|(_, metric, _, _)| *metric);
+        let losses = evaluation
+            .as_ref()
+            .and_then(|(loss, _, training_loss, _)| Some((*loss, (*training_loss)?)));
+        let gap_flow = losses.map_or(ControlFlow::Continue(()), |(loss, training_loss)| {
+            callback.on_loss_gap(e, training_loss, loss)
+        });
+        history.push(
+            (batch_count > 0.0).then(|| epoch_loss / batch_count),
+            evaluation_loss,
+            evaluation_metric,
+            learning_rate,
+            losses.map(|(loss, training_loss)| loss - training_loss),
+        );
+        let flow = callback.on_epoch_end(e, evaluation_loss);
+        if let Some((loss, _, _, last_model)) = evaluation {
+            // let any learning rate handlers react to the evaluation loss.
+            network.observe_loss(loss);
+
+            // keep the snapshot if it's one of the best seen so far.
+            keep_snapshot(&mut checkpoints, checkpoint_count, loss.abs(), &last_model);
+
+            // if the loss has improved on the best loss then remember this network, otherwise
+            // early return the best network once we've run out of patience.
+            let loss = loss.abs();
+            let resume = &mut history.resume;
+            if resume.improves(loss, options.min_delta) {
+                checkpoint.save(e, loss, &last_model)?;
+                resume.best_loss = Some(loss);
+                best_network = Some(last_model);
+                resume.evaluations_without_improvement = 0;
+            } else if resume.evaluations_without_improvement < options.patience {
+                resume.evaluations_without_improvement += 1;
+            } else {
+                resume.epoch = epochs;
+                return Ok((best_network.unwrap_or(last_model), checkpoints));
+            }
+        }
+
+        // Update the network to update the optimisers, etc. at the end of the epoch.
+        if e < (epochs - 1) {
+            network.end_epoch();
+        }
+        history.resume.epoch = e + 1;
+
+        if flow.is_break() || gap_flow.is_break() {
+            break;
+        }
+    }
+
+    // get the trained network out of the training wrapper.
+    Ok((network, checkpoints))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::{Linear, Tanh};
+    use crate::data::TensorDataset;
+    use crate::layers::{Chain, Dense, Dropout, Input, Parallel};
+    use crate::loss::{MeanSquaredError, MultiHead, SoftmaxCrossEntropy};
+    use crate::metrics::Accuracy;
+    use crate::operations::{InitialisedOperation, UninitialisedOperation, WithOptimiser};
+    use crate::optimisers::learning_rate_handlers::{
+        FixedLearningRateHandler, LinearDecayLearningRateHandler,
+        ReduceOnPlateauLearningRateHandler,
+    };
+    use crate::optimisers::{NullOptimiser, Regularisation, SGDMomentum, SGD};
+    use rand::distributions::Standard;
+    use rand::Rng;
+
+    #[test]
+    fn test_generate_batches_with_size_greater_than_rows() {
+        // Arrange
+        let batch = Array::ones((3, 1));
+        let targets = Array::ones((3, 1));
+
+        // Act
+        let mut iter = generate_batches(&batch, &targets, [0, 1, 2].to_vec(), 4);
+
+        // Assert
+        let (batch, targets) = iter.next().unwrap();
+        assert_eq!(batch.nrows(), 3);
+        assert_eq!(targets.nrows(), 3);
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_generate_batches_with_size_as_a_multiplier_of_rows() {
+        // Arrange
+        let batch = Array::ones((3, 1));
+        let targets = Array::ones((3, 1));
+
+        // Act
+        let mut iter = generate_batches(&batch, &targets, [0, 1, 2].to_vec(), 3);
+
+        // Assert
+        let (batch, targets) = iter.next().unwrap();
+        assert_eq!(batch.nrows(), 3);
+        assert_eq!(targets.nrows(), 3);
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_generate_batches_with_size_as_a_non_multiplier_of_rows() {
+        // Arrange
+        let batch = Array::ones((3, 1));
+        let targets = Array::ones((3, 1));
+
+        // Act
+        let mut iter = generate_batches(&batch, &targets, [0, 1, 2].to_vec(), 2);
+
+        // Assert
+        let (batch, targets) = iter.next().unwrap();
+        assert_eq!(batch.nrows(), 2);
+        assert_eq!(targets.nrows(), 2);
+        let (batch, targets) = iter.next().unwrap();
+        assert_eq!(batch.nrows(), 1);
+        assert_eq!(targets.nrows(), 1);
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_generate_batches_in_order_of_indices() {
+        // Arrange
+        let batch = Array::from_shape_vec((3, 1), [1.0, 2.0, 3.0].to_vec()).unwrap();
+        let targets = Array::from_shape_vec((3, 1), [4.0, 5.0, 6.0].to_vec()).unwrap();
+
+        // Act
+        let mut iter = generate_batches(&batch, &targets, [2, 0, 1].to_vec(), 2);
+
+        // Assert
+        let (batch, targets) = iter.next().unwrap();
+        assert_eq!(batch.into_raw_vec(), [3.0, 1.0]);
+        assert_eq!(targets.into_raw_vec(), [6.0, 4.0]);
+        let (batch, _) = iter.next().unwrap();
+        assert_eq!(batch.into_raw_vec(), [2.0]);
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_permuted_indices() {
+        // Arrange
+        let batch = Array::from_iter((0_u16..100).map(ElementType::from))
+            .into_shape((100, 1))
+            .unwrap();
+
+        // Act
+        let indices = permuted_indices(100, 42);
+
+        // Assert
+        let (permuted, _) = permute_data(batch, &Array::zeros((100, 1)), 42);
+        assert!(indices
+            .into_iter()
+            .map(|index| index as ElementType)
+            .eq(permuted.into_iter()));
+    }
+
+    #[test]
+    fn test_permute_data() {
+        // Arrange
+        let batch = Array::from_iter((1_u16..=100).map(ElementType::from))
+            .into_shape((100, 1))
+            .unwrap();
+        let targets = Array::from_iter((101_u16..=200).map(ElementType::from))
+            .into_shape((100, 1))
+            .unwrap();
+        let seed = 42;
+
+        // Act
+        let (batch, targets) = permute_data(batch, &targets, seed);
+        let expected = batch.mapv(|elem| 100.0 + elem);
+
+        // Assert
+        assert_eq!(targets, expected);
+    }
+
+    #[test]
+    fn test_training() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(10, Tanh::new()))
+            .chain(Dropout::new(0.99))
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(SGDMomentum::new(
+                LinearDecayLearningRateHandler::new(0.1, 0.01),
+                0.9,
+            ));
+        let loss_function = MeanSquaredError::new();
+
+        const TRAINING_BATCH_COUNT: usize = 100;
+        let training_batch = Tensor::<rank::Two>::new(
+            (TRAINING_BATCH_COUNT, 2),
+            StdRng::seed_from_u64(42)
+                .sample_iter(Standard)
+                .take(TRAINING_BATCH_COUNT * 2),
+        )
+        .unwrap();
+        let training_targets = Tensor::<rank::Two>::new(
+            (TRAINING_BATCH_COUNT, 1),
+            training_batch
+                .0
+                .as_slice()
+                .unwrap()
+                .chunks(2)
+                .map(|slice| (slice[0] < slice[1]) as u64 as ElementType),
+        )
+        .unwrap();
+
+        const TESTING_BATCH_COUNT: usize = 20;
+        let testing_batch = Tensor::<rank::Two>::new(
+            (TESTING_BATCH_COUNT, 2),
+            StdRng::seed_from_u64(43)
+                .sample_iter(Standard)
+                .take(TESTING_BATCH_COUNT * 2),
+        )
+        .unwrap();
+        let testing_targets = Tensor::<rank::Two>::new(
+            (TESTING_BATCH_COUNT, 1),
+            testing_batch
+                .0
+                .as_slice()
+                .unwrap()
+                .chunks(2)
+                .map(|slice| (slice[0] < slice[1]) as u64 as ElementType),
+        )
+        .unwrap();
+
+        // Act
+        let network = train(
+            network,
+            &loss_function,
+            training_batch,
+            training_targets,
+            &testing_batch,
+            &testing_targets,
+            1000,
+            10,
+            5,
+            42,
+        )
+        .unwrap()
+        .into_initialised();
+
+        // Assert
+        let mut testing_outputs = network.predict(testing_batch).unwrap();
+        testing_outputs
+            .0
+            .mapv_inplace(|elem| if elem < 0.5 { 0.0 } else { 1.0 });
+        assert_eq!(
+            loss_function
+                .loss(&testing_outputs, &testing_targets)
+                .unwrap()
+                .0,
+            0.0
+        );
+        assert_eq!(testing_targets, testing_outputs);
+    }
+
+    #[test]
+    fn test_training_failure() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(10, Tanh::new()))
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(SGDMomentum::new(
+                LinearDecayLearningRateHandler::new(0.1, 0.01),
+                0.9,
+            ));
+        let loss_function = MeanSquaredError::new();
+        let training_batch = Tensor::<rank::Two>::new((1, 2), [1.0, 2.0]).unwrap();
+        let training_targets = Tensor::<rank::Two>::new((2, 1), [1.0, 2.0]).unwrap();
+        let testing_batch = Tensor::<rank::Two>::new((1, 2), [1.0, 2.0]).unwrap();
+        let testing_targets = Tensor::<rank::Two>::new((1, 1), [1.0]).unwrap();
+
+        // Act
+        let result = train(
+            network,
+            &loss_function,
+            training_batch,
+            training_targets,
+            &testing_batch,
+            &testing_targets,
+            1000,
+            10,
+            5,
+            42,
+        );
+
+        // Assert
+        assert_eq!(
+            result.err(),
+            Some(Error::BatchRowMismatch {
+                expected: 1,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_training_exhausts() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(10, Tanh::new()))
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(SGDMomentum::new(
+                LinearDecayLearningRateHandler::new(0.1, 0.01),
+                0.9,
+            ));
+        let loss_function = MeanSquaredError::new();
+        let training_batch = Tensor::<rank::Two>::new((1, 2), [1.0, 2.0]).unwrap();
+        let training_targets = Tensor::<rank::Two>::new((1, 1), [1.0]).unwrap();
+        let testing_batch = Tensor::<rank::Two>::new((1, 2), [1.0, 2.0]).unwrap();
+        let testing_targets = Tensor::<rank::Two>::new((1, 1), [1.0]).unwrap();
+
+        // Act
+        let result = train(
+            network,
+            &loss_function,
+            training_batch,
+            training_targets,
+            &testing_batch,
+            &testing_targets,
+            10,
+            10,
+            5,
+            42,
+        );
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_training_with_patience() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(10, Tanh::new()))
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(SGDMomentum::new(
+                LinearDecayLearningRateHandler::new(0.1, 0.01),
+                0.9,
+            ));
+        let loss_function = MeanSquaredError::new();
+        let batch = Tensor::<rank::Two>::new((2, 2), [1.0, 2.0, 3.0, 4.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((2, 1), [1.0, -1.0]).unwrap();
+        let options = TrainingOptions::new().with_patience(u16::MAX);
+
+        // Act
+        let patient = Trainer::builder()
+            .loss(MeanSquaredError::new())
+            .epochs(20)
+            .batch_size(1)
+            .seed(42)
+            .options(options)
+            .build()
+            .unwrap()
+            .train(
+                network.clone(),
+                batch.clone(),
+                targets.clone(),
+                &batch,
+                &targets,
+            )
+            .unwrap();
+        let unevaluated = train(
+            network,
+            &loss_function,
+            batch.clone(),
+            targets.clone(),
+            &batch,
+            &targets,
+            20,
+            21,
+            1,
+            42,
+        )
+        .unwrap();
+
+        // Assert
+        assert_eq!(patient.into_initialised(), unevaluated.into_initialised());
+    }
+
+    #[test]
+    fn test_training_with_min_delta() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(10, Tanh::new()))
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(SGDMomentum::new(
+                LinearDecayLearningRateHandler::new(0.1, 0.01),
+                0.9,
+            ));
+        let loss_function = MeanSquaredError::new();
+        let batch = Tensor::<rank::Two>::new((2, 2), [1.0, 2.0, 3.0, 4.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((2, 1), [1.0, -1.0]).unwrap();
+        let options = TrainingOptions::new().with_min_delta(ElementType::MAX);
+
+        // Act
+        let output = Trainer::builder()
+            .loss(loss_function)
+            .epochs(20)
+            .batch_size(1)
+            .seed(42)
+            .options(options)
+            .build()
+            .unwrap()
+            .train(
+                network.clone(),
+                batch.clone(),
+                targets.clone(),
+                &batch,
+                &targets,
+            )
+            .unwrap();
+
+        // Assert
+        // no evaluation can improve enough, so the network from before any training is returned.
+        assert_eq!(output.into_initialised(), network.into_initialised());
+    }
+
+    #[derive(Default)]
+    struct RecordingCallback {
+        epoch_starts: Vec<u32>,
+        batch_ends: Vec<u32>,
+        evaluation_losses: Vec<Option<ElementType>>,
+        loss_gaps: Vec<(u32, ElementType, ElementType)>,
+        abort_at_epoch: Option<u32>,
+    }
+
+    impl Callback for RecordingCallback {
+        fn on_epoch_start(&mut self, epoch: u32) -> ControlFlow<()> {
+            self.epoch_starts.push(epoch);
+            if self.abort_at_epoch == Some(epoch) {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        }
+
+        fn on_batch_end(&mut self, epoch: u32, _batch_loss: ElementType) -> ControlFlow<()> {
+            self.batch_ends.push(epoch);
+            ControlFlow::Continue(())
+        }
+
+        fn on_epoch_end(
+            &mut self,
+            _epoch: u32,
+            evaluation_loss: Option<ElementType>,
+        ) -> ControlFlow<()> {
+            self.evaluation_losses.push(evaluation_loss);
+            ControlFlow::Continue(())
+        }
+
+        fn on_loss_gap(
+            &mut self,
+            epoch: u32,
+            training_loss: ElementType,
+            evaluation_loss: ElementType,
+        ) -> ControlFlow<()> {
+            self.loss_gaps.push((epoch, training_loss, evaluation_loss));
+            ControlFlow::Continue(())
+        }
+    }
+
+    #[test]
+    fn test_training_multi_head() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(8, Tanh::new()))
+            .chain(Parallel::heads(
+                Dense::new(1, Linear::new()),
+                Dense::new(1, Linear::new()),
+            ))
+            .with_seed(42)
+            .with_optimiser(SGD::new(FixedLearningRateHandler::new(0.05)));
+        let heads = || {
+            MultiHead::new()
+                .with_head(MeanSquaredError::new(), 1, 1.0)
+                .with_head(MeanSquaredError::new(), 1, 0.5)
+        };
+        let batch = Tensor::<rank::Two>::new(
+            (50, 2),
+            StdRng::seed_from_u64(42).sample_iter(Standard).take(100),
+        )
+        .unwrap();
+        let targets = Tensor::<rank::Two>::new(
+            (50, 2),
+            batch.rows().flat_map(|row| {
+                let row = row.into_iter().collect::<Vec<_>>();
+                [row[0] + row[1], row[0] - row[1]]
+            }),
+        )
+        .unwrap();
+        let before = heads()
+            .head_losses(
+                &network
+                    .clone()
+                    .into_initialised()
+                    .predict(batch.clone())
+                    .unwrap(),
+                &targets,
+            )
+            .unwrap();
+
+        // Act
+        let network = Trainer::builder()
+            .loss(heads())
+            .epochs(200)
+            .eval_every(10)
+            .batch_size(10)
+            .seed(42)
+            .build()
+            .unwrap()
+            .train(network, batch.clone(), targets.clone(), &batch, &targets)
+            .unwrap()
+            .into_initialised();
+
+        // Assert
+        let after = heads()
+            .head_losses(&network.predict(batch).unwrap(), &targets)
+            .unwrap();
+        assert!(after[0] < before[0] * 0.1);
+        assert!(after[1] < before[1] * 0.1);
+    }
+
+    #[test]
+    fn test_training_multi_head_failure() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(2, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(NullOptimiser::new());
+        let heads = MultiHead::new().with_head(MeanSquaredError::new(), 1, 1.0);
+        let batch = Tensor::<rank::Two>::new((1, 2), [1.0, 2.0]).unwrap();
+
+        // Act
+        let result = Trainer::builder()
+            .loss(heads)
+            .epochs(1)
+            .batch_size(1)
+            .seed(42)
+            .build()
+            .unwrap()
+            .train(network, batch.clone(), batch.clone(), &batch, &batch);
+
+        // Assert
+        assert_eq!(
+            result.map(|_| ()),
+            Err(Error::ShapeMismatch {
+                expected: 1,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_training_with_callback() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(SGDMomentum::new(
+                LinearDecayLearningRateHandler::new(0.01, 0.001),
+                0.9,
+            ));
+        let batch = Tensor::<rank::Two>::new((3, 2), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((3, 1), [3.0, 7.0, 11.0]).unwrap();
+        let mut callback = RecordingCallback::default();
+
+        // Act
+        Trainer::builder()
+            .loss(MeanSquaredError::new())
+            .epochs(3)
+            .eval_every(2)
+            .batch_size(2)
+            .seed(42)
+            .early_stopping(u16::MAX, 0.0)
+            .callback(&mut callback)
+            .build()
+            .unwrap()
+            .train(network, batch.clone(), targets.clone(), &batch, &targets)
+            .unwrap();
+
+        // Assert
+        assert_eq!(callback.epoch_starts, [0, 1, 2]);
+        assert_eq!(callback.batch_ends, [0, 0, 1, 1, 2, 2]);
+        assert_eq!(callback.evaluation_losses.len(), 3);
+        assert!(callback.evaluation_losses[0].is_none());
+        assert!(callback.evaluation_losses[1].is_some());
+        assert!(callback.evaluation_losses[2].is_none());
+    }
+
+    #[test]
+    fn test_trainer() {
+        // Arrange
+        let network = Input::new(1)
+            .chain(Dense::new(1, Linear::new()))
+            .with_iter([0.0, 0.0].into_iter())
+            .unwrap()
+            .with_optimiser(SGD::new(FixedLearningRateHandler::new(0.05)));
+        let batch = Tensor::<rank::Two>::new((4, 1), [1.0, 2.0, 3.0, 4.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((4, 1), [3.0, 5.0, 7.0, 9.0]).unwrap();
+        let mut callback = RecordingCallback::default();
+        let mut trainer = Trainer::builder()
+            .loss(MeanSquaredError::new())
+            .epochs(200)
+            .batch_size(2)
+            .eval_every(50)
+            .callback(&mut callback)
+            .build()
+            .unwrap();
+
+        // Act
+        let (network, history) = trainer
+            .train_with_history(network, batch.clone(), targets.clone(), &batch, &targets)
+            .unwrap();
+        drop(trainer);
+
+        // Assert
+        let output = network.into_initialised().predict(batch).unwrap();
+        assert!(output.approx_eq(&targets, 0.1));
+        assert_eq!(history.len(), 200);
+        assert_eq!(callback.batch_ends.len(), 400);
+        assert_eq!(
+            callback
+                .evaluation_losses
+                .iter()
+                .filter(|loss| loss.is_some())
+                .count(),
+            4
+        );
+    }
+
+    #[test]
+    fn test_trainer_callback_abort() {
+        // Arrange
+        let network = Input::new(1)
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(NullOptimiser::new());
+        let batch = Tensor::<rank::Two>::new((2, 1), [1.0, 2.0]).unwrap();
+        let mut recording = RecordingCallback::default();
+        let mut trainer = Trainer::builder()
+            .loss(MeanSquaredError::new())
+            .epochs(10)
+            .callback(RecordingCallback {
+                abort_at_epoch: Some(3),
+                ..RecordingCallback::default()
+            })
+            .callback(&mut recording)
+            .build()
+            .unwrap();
+
+        // Act
+        trainer
+            .train(network, batch.clone(), batch.clone(), &batch, &batch)
+            .unwrap();
+        drop(trainer);
+
+        // Assert
+        // every callback is invoked at the hook which aborted training.
+        assert_eq!(recording.epoch_starts, [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_trainer_without_shuffle() {
+        // Arrange
+        let network = Input::new(1)
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(SGD::new(FixedLearningRateHandler::new(0.01)));
+        let batch = Tensor::<rank::Two>::new((4, 1), [1.0, 2.0, 3.0, 4.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((4, 1), [3.0, 5.0, 7.0, 9.0]).unwrap();
+        let train = |seed, shuffle| {
+            Trainer::builder()
+                .loss(MeanSquaredError::new())
+                .epochs(5)
+                .batch_size(1)
+                .seed(seed)
+                .shuffle(shuffle)
+                .build()
+                .unwrap()
+                .train(
+                    network.clone(),
+                    batch.clone(),
+                    targets.clone(),
+                    &batch,
+                    &targets,
+                )
+                .unwrap()
+                .into_initialised()
+        };
+
+        // Act
+        let ordered = [train(1, Shuffle::Off), train(2, Shuffle::Off)];
+        let shuffled = [train(1, Shuffle::Full), train(2, Shuffle::Full)];
+
+        // Assert
+        assert_eq!(ordered[0], ordered[1]);
+        assert_ne!(shuffled[0], shuffled[1]);
+    }
+
+    #[test]
+    fn test_shuffle_indices() {
+        // Arrange
+        let reversed = Shuffle::Custom(|rows, _| (0..rows).rev().collect());
+        let duplicated = Shuffle::Custom(|rows, _| (0..rows).map(|_| 0).collect());
+        let short = Shuffle::Custom(|rows, _| (1..rows).collect());
+
+        let targets = Array::zeros((5, 1));
+
+        // Act
+        let off = Shuffle::Off.indices(&targets, 2, 42).unwrap();
+        let full = Shuffle::Full.indices(&targets, 2, 42).unwrap();
+        let within_batch = Shuffle::WithinBatch.indices(&targets, 2, 42).unwrap();
+
+        // Assert
+        assert_eq!(off, [0, 1, 2, 3, 4]);
+        assert_eq!(full, permuted_indices(5, 42));
+        let mut batches = within_batch
+            .chunks(2)
+            .map(<[usize]>::to_vec)
+            .collect::<Vec<_>>();
+        for batch in &mut batches {
+            batch.sort_unstable();
+        }
+        assert_eq!(batches, [[0, 1].to_vec(), [2, 3].to_vec(), [4].to_vec()]);
+        let targets = Array::zeros((3, 1));
+        assert_eq!(reversed.indices(&targets, 2, 42), Ok([2, 1, 0].to_vec()));
+        assert_eq!(
+            duplicated.indices(&targets, 2, 42),
+            Err(Error::InvalidArgument)
+        );
+        assert_eq!(
+            short.indices(&targets, 2, 42),
+            Err(Error::ShapeMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_stratified_indices() {
+        // Arrange
+        let classes = (0..20u8)
+            .map(|index| if index % 5 == 0 { 1.0 } else { 0.0 })
+            .collect::<Vec<_>>();
+        let index_targets = Array::from_shape_vec((20, 1), classes.clone()).unwrap();
+        let one_hot_targets = Array::from_shape_fn((20, 2), |(row, column)| {
+            if (classes[row] == 1.0) == (column == 1) {
+                1.0
+            } else {
+                0.0
+            }
+        });
+
+        // Act
+        let from_indices = Shuffle::Stratified.indices(&index_targets, 5, 42).unwrap();
+        let from_one_hot = Shuffle::Stratified
+            .indices(&one_hot_targets, 5, 42)
+            .unwrap();
+
+        // Assert
+        assert_eq!(from_indices, from_one_hot);
+        let mut sorted = from_indices.clone();
+        sorted.sort_unstable();
+        assert!(sorted.into_iter().eq(0..20));
+        for batch in from_indices.chunks(5) {
+            let minority = batch.iter().filter(|&&index| index % 5 == 0).count();
+            assert_eq!(minority, 1);
+        }
+    }
+
+    #[test]
+    fn test_split_validation() {
+        // Arrange
+        let batch = Tensor::<rank::Two>::new((10, 1), (0..10u8).map(ElementType::from)).unwrap();
+        let targets = Tensor(batch.0.mapv(|elem| elem * 2.0));
+
+        // Act
+        let ((batch_train, targets_train), (batch_validation, targets_validation)) =
+            split_validation(&batch, &targets, 0.25, 42).unwrap();
+
+        // Assert
+        assert_eq!(batch_train.dim(), (7, 1));
+        assert_eq!(batch_validation.dim(), (3, 1));
+        assert_eq!(targets_train, Tensor(batch_train.0.mapv(|elem| elem * 2.0)));
+        assert_eq!(
+            targets_validation,
+            Tensor(batch_validation.0.mapv(|elem| elem * 2.0))
+        );
+        let mut rows = batch_train
+            .into_iter()
+            .chain(batch_validation)
+            .collect::<Vec<_>>();
+        rows.sort_by(ElementType::total_cmp);
+        assert_eq!(rows, batch.clone().into_iter().collect::<Vec<_>>());
+        let row = batch.slice(..1, ..).unwrap();
+        assert_eq!(
+            split_validation(&row, &row, 0.5, 42).map(|_| ()),
+            Err(Error::EmptyData)
+        );
+    }
+
+    #[test]
+    fn test_trainer_fit() {
+        // Arrange
+        let network = Input::new(1)
+            .chain(Dense::new(1, Linear::new()))
+            .with_iter([0.0, 0.0].into_iter())
+            .unwrap()
+            .with_optimiser(SGD::new(FixedLearningRateHandler::new(0.1)));
+        let batch = Tensor::<rank::Two>::new(
+            (10, 1),
+            (0..10u8).map(|elem| ElementType::from(elem) / 10.0),
+        )
+        .unwrap();
+        let targets = Tensor(batch.0.mapv(|elem| elem.mul_add(2.0, 1.0)));
+        let mut trainer = Trainer::builder()
+            .loss(MeanSquaredError::new())
+            .epochs(300)
+            .batch_size(4)
+            .eval_every(100)
+            .early_stopping(u16::MAX, 0.0)
+            .validation_split(0.2)
+            .build()
+            .unwrap();
+
+        // Act
+        let (network, history) = trainer.fit_with_history(network, &batch, &targets).unwrap();
+
+        // Assert
+        let output = network.into_initialised().predict(batch).unwrap();
+        assert!(output.approx_eq(&targets, 0.1));
+        assert_eq!(history.evaluation_losses().iter().flatten().count(), 3);
+        assert_eq!(history.len(), 300);
+    }
+
+    #[test]
+    fn test_train_lbfgs() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(4, Tanh::new()))
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42);
+        let batch = Tensor::<rank::Two>::new(
+            (6, 2),
+            [0.0, 0.0, 0.5, 0.0, 0.0, 0.5, 1.0, 0.5, 0.5, 1.0, 1.0, 1.0],
+        )
+        .unwrap();
+        let targets = Tensor(
+            batch
+                .0
+                .map_axis(Axis(1), |row| row[1].mul_add(-2.0, row[0] + 0.5))
+                .insert_axis(Axis(1)),
+        );
+        let initial_loss = MeanSquaredError::new()
+            .loss(&network.predict(batch.clone()).unwrap(), &targets)
+            .unwrap()
+            .0;
+
+        // Act
+        let (network, loss) = train_lbfgs(
+            network,
+            &MeanSquaredError::new(),
+            &LBFGS::new(10),
+            &batch,
+            &targets,
+            100,
+        )
+        .unwrap();
+
+        // Assert
+        let (expected, _) = MeanSquaredError::new()
+            .loss(&network.predict(batch).unwrap(), &targets)
+            .unwrap();
+        assert!((loss - expected).abs() < 1e-6);
+        assert!(loss < initial_loss * 1e-3);
+    }
+
+    #[test]
+    fn test_train_lbfgs_row_mismatch() {
+        // Arrange
+        let network = Input::new(1)
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42);
+        let batch = Tensor::<rank::Two>::new((2, 1), [1.0, 2.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((3, 1), [1.0, 2.0, 3.0]).unwrap();
+
+        // Act
+        let result = train_lbfgs(
+            network,
+            &MeanSquaredError::new(),
+            &LBFGS::new(5),
+            &batch,
+            &targets,
+            10,
+        );
+
+        // Assert
+        assert_eq!(
+            result.map(|_| ()),
+            Err(Error::BatchRowMismatch {
+                expected: 2,
+                actual: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_trainer_max_steps() {
+        // Arrange
+        let network = Input::new(1)
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(NullOptimiser::new());
+        let batch = Tensor::<rank::Two>::new((4, 1), [1.0, 2.0, 3.0, 4.0]).unwrap();
+        let mut recording = RecordingCallback::default();
+        let mut trainer = Trainer::builder()
+            .loss(MeanSquaredError::new())
+            .epochs(10)
+            .batch_size(1)
+            .max_steps(6)
+            .callback(&mut recording)
+            .build()
+            .unwrap();
+
+        // Act
+        let (_, history) = trainer
+            .train_with_history(network, batch.clone(), batch.clone(), &batch, &batch)
+            .unwrap();
+        drop(trainer);
+
+        // Assert
+        assert_eq!(recording.batch_ends, [0, 0, 0, 0, 1, 1]);
+        assert_eq!(history.len(), 1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_trainer_max_duration() {
+        // Arrange
+        let network = Input::new(1)
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(NullOptimiser::new());
+        let batch = Tensor::<rank::Two>::new((4, 1), [1.0, 2.0, 3.0, 4.0]).unwrap();
+        let mut recording = RecordingCallback::default();
+        let mut trainer = Trainer::builder()
+            .loss(MeanSquaredError::new())
+            .epochs(10)
+            .batch_size(1)
+            .max_duration(std::time::Duration::ZERO)
+            .callback(&mut recording)
+            .build()
+            .unwrap();
+
+        // Act
+        trainer
+            .train(network, batch.clone(), batch.clone(), &batch, &batch)
+            .unwrap();
+        drop(trainer);
+
+        // Assert
+        assert_eq!(recording.batch_ends, [0]);
+    }
+
+    #[test]
+    fn test_trainer_loss_gap() {
+        // Arrange
+        let network = Input::new(1)
+            .chain(Dense::new(8, Tanh::new()))
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(SGD::new(FixedLearningRateHandler::new(0.05)));
+        let mut random_generator = StdRng::seed_from_u64(42);
+        let mut synthetic = |rows: usize, noise: ElementType| {
+            let inputs = (0..rows)
+                .map(|_| random_generator.gen_range(-1.0..1.0))
+                .collect::<Vec<ElementType>>();
+            let targets = inputs
+                .iter()
+                .map(|input| noise.mul_add(random_generator.gen_range(-1.0..1.0), 2.0 * input))
+                .collect::<Vec<_>>();
+            (
+                Tensor::<rank::Two>::new((rows, 1), inputs).unwrap(),
+                Tensor::<rank::Two>::new((rows, 1), targets).unwrap(),
+            )
+        };
+        let (batch_train, targets_train) = synthetic(16, 0.5);
+        let (batch_test, targets_test) = synthetic(16, 0.0);
+        let mut recording = RecordingCallback::default();
+        let mut trainer = Trainer::builder()
+            .loss(MeanSquaredError::new())
+            .epochs(6)
+            .eval_every(2)
+            .batch_size(4)
+            .early_stopping(u16::MAX, 0.0)
+            .loss_gap_sample(8)
+            .callback(&mut recording)
+            .build()
+            .unwrap();
+
+        // Act
+        let (_, history) = trainer
+            .train_with_history(
+                network,
+                batch_train,
+                targets_train,
+                &batch_test,
+                &targets_test,
+            )
+            .unwrap();
+        drop(trainer);
+
+        // Assert
+        let epochs = recording
+            .loss_gaps
+            .iter()
+            .map(|(epoch, _, _)| *epoch)
+            .collect::<Vec<_>>();
+        assert_eq!(epochs, [1, 3, 5]);
+        assert_eq!(history.loss_gaps().len(), 6);
+        for (epoch, loss_gap) in history.loss_gaps().iter().enumerate() {
+            let reported = recording
+                .loss_gaps
+                .iter()
+                .find(|(reported, _, _)| *reported as usize == epoch);
+            match reported {
+                Some((_, training_loss, evaluation_loss)) => {
+                    assert!(training_loss.is_finite());
+                    assert_eq!(history.evaluation_losses()[epoch], Some(*evaluation_loss));
+                    assert_eq!(*loss_gap, Some(evaluation_loss - training_loss));
+                }
+                None => assert_eq!(*loss_gap, None),
+            }
+        }
+    }
+
+    #[test]
+    fn test_trainer_loss_gap_on_training_data() {
+        // Arrange
+        let network = Input::new(1)
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(SGD::new(FixedLearningRateHandler::new(0.01)));
+        let batch = Tensor::<rank::Two>::new((4, 1), [0.1, 0.2, 0.3, 0.4]).unwrap();
+        let targets = Tensor::<rank::Two>::new((4, 1), [0.3, 0.5, 0.7, 0.9]).unwrap();
+        let mut trainer = Trainer::builder()
+            .loss(MeanSquaredError::new())
+            .epochs(3)
+            .batch_size(2)
+            .early_stopping(u16::MAX, 0.0)
+            .loss_gap_sample(10)
+            .build()
+            .unwrap();
+
+        // Act
+        let (_, history) = trainer
+            .train_with_history(network, batch.clone(), targets.clone(), &batch, &targets)
+            .unwrap();
+
+        // Assert
+        assert_eq!(history.loss_gaps().len(), 3);
+        assert!(history
+            .loss_gaps()
+            .iter()
+            .all(|loss_gap| loss_gap.is_some_and(|loss_gap| loss_gap.abs() < 1e-6)));
+    }
+
+    #[test]
+    fn test_trainer_invalid_config() {
+        // Arrange
+        let builder = || Trainer::builder().loss(MeanSquaredError::new()).epochs(1);
+
+        // Act
+        let results = [
+            Trainer::builder().loss(MeanSquaredError::new()).build(),
+            builder().batch_size(0).build(),
+            builder().eval_every(0).build(),
+            builder().early_stopping(1, -1.0).build(),
+            builder().validation_split(1.0).build(),
+            builder().max_steps(0).build(),
+            builder().loss_gap_sample(0).build(),
+            builder().top_n(0).build(),
+        ];
+
+        // Assert
+        let errors = results.map(|result| result.map(|_| ()));
+        assert_eq!(
+            errors,
+            [
+                Err(Error::InvalidConfig(ConfigError::ZeroEpochs)),
+                Err(Error::InvalidConfig(ConfigError::ZeroBatchSize)),
+                Err(Error::InvalidConfig(ConfigError::ZeroEvalEvery)),
+                Err(Error::InvalidConfig(ConfigError::InvalidMinDelta)),
+                Err(Error::InvalidConfig(ConfigError::InvalidValidationSplit)),
+                Err(Error::InvalidConfig(ConfigError::ZeroMaxSteps)),
+                Err(Error::InvalidConfig(ConfigError::ZeroLossGapSample)),
+                Err(Error::InvalidConfig(ConfigError::ZeroTopN)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_trainer_early_stopping_precedence() {
+        // Arrange
+        let builder = || Trainer::builder().loss(MeanSquaredError::new()).epochs(1);
+        let options = TrainingOptions::new()
+            .with_patience(5)
+            .with_min_delta(0.5)
+            .with_non_finite(NonFiniteHandling::SkipBatch);
+        let expected = options.with_patience(2).with_min_delta(0.1);
+
+        // Act
+        let before = builder()
+            .early_stopping(2, 0.1)
+            .options(options)
+            .build()
+            .unwrap();
+        let after = builder()
+            .options(options)
+            .early_stopping(2, 0.1)
+            .build()
+            .unwrap();
+        let without = builder().options(options).build().unwrap();
+
+        // Assert
+        assert_eq!(before.options, expected);
+        assert_eq!(after.options, expected);
+        assert_eq!(without.options, options);
+    }
+
+    #[test]
+    fn test_training_with_callback_abort() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(SGDMomentum::new(
+                LinearDecayLearningRateHandler::new(0.01, 0.001),
+                0.9,
+            ));
+        let loss_function = MeanSquaredError::new();
+        let batch = Tensor::<rank::Two>::new((3, 2), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((3, 1), [3.0, 7.0, 11.0]).unwrap();
+        let mut callback = RecordingCallback {
+            abort_at_epoch: Some(1),
+            ..RecordingCallback::default()
+        };
+
+        // Act
+        let aborted = Trainer::builder()
+            .loss(MeanSquaredError::new())
+            .epochs(10)
+            .eval_every(11)
+            .batch_size(2)
+            .seed(42)
+            .callback(&mut callback)
+            .build()
+            .unwrap()
+            .train(
+                network.clone(),
+                batch.clone(),
+                targets.clone(),
+                &batch,
+                &targets,
+            )
+            .unwrap();
+        let single_epoch = train(
+            network,
+            &loss_function,
+            batch.clone(),
+            targets.clone(),
+            &batch,
+            &targets,
+            1,
+            11,
+            2,
+            42,
+        )
+        .unwrap();
+
+        // Assert
+        assert_eq!(callback.epoch_starts, [0, 1]);
+        assert_eq!(aborted.into_initialised(), single_epoch.into_initialised());
+    }
+
+    #[test]
+    fn test_training_with_history() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(SGDMomentum::new(
+                LinearDecayLearningRateHandler::new(0.01, 0.001),
+                0.9,
+            ));
+        let batch = Tensor::<rank::Two>::new((3, 2), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((3, 1), [3.0, 7.0, 11.0]).unwrap();
+
+        // Act
+        let (_, history) = Trainer::builder()
+            .loss(MeanSquaredError::new())
+            .epochs(4)
+            .eval_every(2)
+            .batch_size(2)
+            .seed(42)
+            .early_stopping(u16::MAX, 0.0)
+            .build()
+            .unwrap()
+            .train_with_history(network, batch.clone(), targets.clone(), &batch, &targets)
+            .unwrap();
+
+        // Assert
+        assert_eq!(history.len(), 4);
+        assert!(history
+            .training_losses()
+            .iter()
+            .all(|loss| loss.is_some_and(ElementType::is_finite)));
+        assert!(history.evaluation_losses()[0].is_none());
+        assert!(history.evaluation_losses()[1].is_some());
+        assert!(history.evaluation_losses()[2].is_none());
+        assert!(history.evaluation_losses()[3].is_some());
+        assert_eq!(history.learning_rates()[0], Some(0.01));
+        assert!(history
+            .learning_rates()
+            .windows(2)
+            .all(|pair| pair[0] > pair[1]));
+    }
+
+    #[test]
+    fn test_training_with_checkpoint() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(1, Linear::new()))
+            .with_iter([0.5, 0.5, 0.0].into_iter())
+            .unwrap()
+            .with_optimiser(SGDMomentum::new(
+                LinearDecayLearningRateHandler::new(0.01, 0.001),
+                0.9,
+            ));
+        let batch = Tensor::<rank::Two>::new((3, 2), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((3, 1), [3.0, 7.0, 11.0]).unwrap();
+        let mut saved = Vec::new();
+        let mut checkpoint =
+            |epoch, loss, weights: &mut dyn Iterator<Item = ElementType>| -> Result<()> {
+                saved.push((epoch, loss, weights.collect::<Vec<_>>()));
+                Ok(())
+            };
+
+        // Act
+        Trainer::builder()
+            .loss(MeanSquaredError::new())
+            .epochs(3)
+            .batch_size(3)
+            .seed(42)
+            .early_stopping(u16::MAX, 0.0)
+            .checkpoint(&mut checkpoint)
+            .build()
+            .unwrap()
+            .train(network, batch.clone(), targets.clone(), &batch, &targets)
+            .unwrap();
+
+        // Assert
+        assert!(!saved.is_empty());
+        assert_eq!(saved[0].0, 0);
+        assert_eq!(saved[0].2, [0.5, 0.5, 0.0]);
+        assert!(saved.windows(2).all(|pair| pair[0].1 > pair[1].1));
+    }
+
+    #[test]
+    fn test_training_with_checkpoint_failure() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(NullOptimiser::new());
+        let batch = Tensor::<rank::Two>::new((3, 2), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((3, 1), [3.0, 7.0, 11.0]).unwrap();
+        let checkpoint = |_, _, _: &mut dyn Iterator<Item = ElementType>| -> Result<()> {
+            Err(Error::CheckpointFailed)
+        };
+
+        // Act
+        let result = Trainer::builder()
+            .loss(MeanSquaredError::new())
+            .epochs(3)
+            .batch_size(3)
+            .seed(42)
+            .checkpoint(checkpoint)
+            .build()
+            .unwrap()
+            .train(network, batch.clone(), targets.clone(), &batch, &targets);
+
+        // Assert
+        assert_eq!(result.err(), Some(Error::CheckpointFailed));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_training_with_file_checkpoint() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(1, Linear::new()))
+            .with_iter([1.0, 1.0, 0.0].into_iter())
+            .unwrap()
+            .with_optimiser(NullOptimiser::new());
+        let batch = Tensor::<rank::Two>::new((3, 2), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((3, 1), [3.0, 7.0, 11.0]).unwrap();
+        let path = std::env::temp_dir().join("eidetic-test-training-with-file-checkpoint.bin");
+
+        // Act
+        Trainer::builder()
+            .loss(MeanSquaredError::new())
+            .epochs(1)
+            .batch_size(3)
+            .seed(42)
+            .checkpoint(FileCheckpoint::new(&path))
+            .build()
+            .unwrap()
+            .train(network, batch.clone(), targets.clone(), &batch, &targets)
+            .unwrap();
+        let loaded = Input::new(2)
+            .chain(Dense::new(1, Linear::new()))
+            .load(&path);
+        let mut temporary = path.clone().into_os_string();
+        temporary.push(".tmp");
+        let temporary_exists = std::path::Path::new(&temporary).exists();
+        std::fs::remove_file(&path).unwrap();
+
+        // Assert
+        assert!(loaded.unwrap().iter().eq([1.0, 1.0, 0.0]));
+        assert!(!temporary_exists);
+    }
+
+    /// Trains the network with a trainer configured with the given options, aborting at the start of the given epoch.
+    fn train_until<N>(
+        network: N,
+        options: TrainingOptions,
+        abort_at_epoch: Option<u32>,
+        batch: &Tensor<rank::Two>,
+        targets: &Tensor<rank::Two>,
+    ) -> (N, TrainingHistory)
+    where
+        for<'a> N: TrainableOperation
+            + Forward<'a, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>
+            + Clone,
+    {
+        Trainer::builder()
+            .loss(MeanSquaredError::new())
+            .epochs(6)
+            .batch_size(2)
+            .eval_every(1)
+            .options(options)
+            .callback(RecordingCallback {
+                abort_at_epoch,
+                ..RecordingCallback::default()
+            })
+            .build()
+            .unwrap()
+            .train_with_history(network, batch.clone(), targets.clone(), batch, targets)
+            .unwrap()
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_training_resumed_from_snapshot() {
+        // Arrange
+        fn deserialize_like<T: serde::de::DeserializeOwned>(_value: &T, json: &str) -> T {
+            serde_json::from_str(json).unwrap()
+        }
+        let network = Input::new(2)
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(SGDMomentum::new(
+                LinearDecayLearningRateHandler::new(0.01, 0.001),
+                0.9,
+            ));
+        let batch = Tensor::<rank::Two>::new((3, 2), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((3, 1), [3.0, 7.0, 11.0]).unwrap();
+        let options = TrainingOptions::new().with_patience(u16::MAX);
+        let (expected, _) = train_until(network.clone(), options, None, &batch, &targets);
+
+        // Act
+        let (paused, history) = train_until(network, options, Some(3), &batch, &targets);
+        let json = serde_json::to_string(&(&paused, history.resume_state())).unwrap();
+        let (snapshot, resume) = deserialize_like(&(paused.clone(), history.resume_state()), &json);
+        let (output, _) = train_until(
+            snapshot,
+            options.with_resume(resume),
+            None,
+            &batch,
+            &targets,
+        );
+
+        // Assert
+        assert_eq!(resume.epoch(), 3);
+        assert_ne!(paused, expected);
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_training_resumed_with_early_stopping() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(SGD::new(FixedLearningRateHandler::new(1.0)));
+        let batch = Tensor::<rank::Two>::new((3, 2), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((3, 1), [3.0, 7.0, 11.0]).unwrap();
+        let options = TrainingOptions::new().with_patience(1);
+        let (_, expected) = train_until(network.clone(), options, None, &batch, &targets);
+
+        // Act
+        let (paused, history) = train_until(network, options, Some(2), &batch, &targets);
+        let (_, resumed) = train_until(
+            paused,
+            options.with_resume(history.resume_state()),
+            None,
+            &batch,
+            &targets,
+        );
+
+        // Assert
+        assert_eq!(expected.len(), 3);
+        assert_eq!(history.resume_state().evaluations_without_improvement(), 1);
+        assert_eq!(resumed.len(), 1);
+        assert_eq!(resumed.resume_state(), expected.resume_state());
+        assert_eq!(resumed.resume_state().epoch(), 6);
+    }
+
+    #[test]
+    fn test_training_with_metric() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(2, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(SGDMomentum::new(
+                LinearDecayLearningRateHandler::new(0.01, 0.001),
+                0.9,
+            ));
+        let batch =
+            Tensor::<rank::Two>::new((4, 2), [1.0, 0.0, 0.9, 0.1, 0.0, 1.0, 0.2, 0.8]).unwrap();
+        let targets =
+            Tensor::<rank::Two>::new((4, 2), [1.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 1.0]).unwrap();
+
+        // Act
+        let (_, history) = Trainer::builder()
+            .loss(SoftmaxCrossEntropy::new())
+            .epochs(4)
+            .eval_every(2)
+            .batch_size(2)
+            .seed(42)
+            .early_stopping(u16::MAX, 0.0)
+            .metric(Accuracy::new())
+            .build()
+            .unwrap()
+            .train_with_history(network, batch.clone(), targets.clone(), &batch, &targets)
+            .unwrap();
+
+        // Assert
+        assert_eq!(history.evaluation_metrics().len(), 4);
+        assert!(history.evaluation_metrics()[0].is_none());
+        assert!(
+            history.evaluation_metrics()[1].is_some_and(|accuracy| (0.0..=1.0).contains(&accuracy))
+        );
+        assert!(history.evaluation_metrics()[2].is_none());
+        assert!(history.evaluation_metrics()[3].is_some());
+    }
+
+    #[test]
+    fn test_training_without_metric() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(NullOptimiser::new());
+        let batch = Tensor::<rank::Two>::new((3, 2), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((3, 1), [3.0, 7.0, 11.0]).unwrap();
+
+        // Act
+        let (_, history) = Trainer::builder()
+            .loss(MeanSquaredError::new())
+            .epochs(2)
+            .batch_size(2)
+            .seed(42)
+            .early_stopping(u16::MAX, 0.0)
+            .build()
+            .unwrap()
+            .train_with_history(network, batch.clone(), targets.clone(), &batch, &targets)
+            .unwrap();
+
+        // Assert
+        assert_eq!(history.evaluation_metrics(), [None, None]);
+    }
+
+    #[test]
+    fn test_training_reduces_learning_rate_on_plateau() {
+        // Arrange
+        let network = Input::new(1)
+            .chain(Dense::new(1, Linear::new()))
+            .with_iter([0.0, 0.0].into_iter())
+            .unwrap()
+            .with_optimiser(SGD::new(ReduceOnPlateauLearningRateHandler::new(
+                0.01, 0.5, 1,
+            )));
+        let batch = Tensor::<rank::Two>::new((2, 1), [1.0, 2.0]).unwrap();
+        let targets_train = Tensor::<rank::Two>::new((2, 1), [2.0, 4.0]).unwrap();
+        // the testing targets pull in the opposite direction, so the evaluation loss never improves.
+        let targets_test = Tensor::<rank::Two>::new((2, 1), [-2.0, -4.0]).unwrap();
+
+        // Act
+        let (_, history) = Trainer::builder()
+            .loss(MeanSquaredError::new())
+            .epochs(4)
+            .batch_size(2)
+            .seed(42)
+            .early_stopping(u16::MAX, 0.0)
+            .build()
+            .unwrap()
+            .train_with_history(network, batch.clone(), targets_train, &batch, &targets_test)
+            .unwrap();
+
+        // Assert
+        assert_eq!(
+            history.learning_rates(),
+            [Some(0.01), Some(0.01), Some(0.005), Some(0.0025)]
+        );
+    }
+
+    #[test]
+    fn test_training_reports_regularisation_penalty() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(1, Linear::new()))
+            .with_iter([1.0, 2.0, 3.0].into_iter())
+            .unwrap();
+        let batch = Tensor::<rank::Two>::new((2, 2), [1.0, 2.0, 3.0, 4.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((2, 1), [8.0, 14.0]).unwrap();
+        let regularisation = Regularisation::new(NullOptimiser::new()).with_l2(2.0);
+        fn train<N>(
+            network: N,
+            batch: &Tensor<rank::Two>,
+            targets: &Tensor<rank::Two>,
+        ) -> TrainingHistory
+        where
+            for<'a> N: TrainableOperation
+                + Forward<'a, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>
+                + Clone,
+        {
+            let (_, history) = Trainer::builder()
+                .loss(MeanSquaredError::new())
+                .epochs(1)
+                .batch_size(2)
+                .seed(42)
+                .build()
+                .unwrap()
+                .train_with_history(network, batch.clone(), targets.clone(), batch, targets)
+                .unwrap();
+            history
+        }
+        // the penalty is half of the l2 coefficient multiplied by the sum of squared parameters.
+        let expected = 14.0;
+
+        // Act
+        let regularised = train(
+            network.clone().with_optimiser(regularisation),
+            &batch,
+            &targets,
+        );
+        let unregularised = train(
+            network.with_optimiser(NullOptimiser::new()),
+            &batch,
+            &targets,
+        );
+
+        // Assert
+        assert_eq!(
+            regularised.training_losses()[0].unwrap() - unregularised.training_losses()[0].unwrap(),
+            expected
+        );
+        assert_eq!(
+            regularised.evaluation_losses()[0].unwrap()
+                - unregularised.evaluation_losses()[0].unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_training_top_n() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(10, Tanh::new()))
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(SGDMomentum::new(
+                LinearDecayLearningRateHandler::new(0.1, 0.01),
+                0.9,
+            ));
+        let loss_function = MeanSquaredError::new();
+        let batch = Tensor::<rank::Two>::new(
+            (50, 2),
+            StdRng::seed_from_u64(42).sample_iter(Standard).take(100),
+        )
+        .unwrap();
+        let targets = Tensor::<rank::Two>::new(
+            (50, 1),
+            batch
+                .0
+                .as_slice()
+                .unwrap()
+                .chunks(2)
+                .map(|slice| slice[0] + slice[1]),
+        )
+        .unwrap();
+
+        let builder = || {
+            Trainer::builder()
+                .loss(MeanSquaredError::new())
+                .epochs(20)
+                .eval_every(2)
+                .batch_size(10)
+                .seed(42)
+        };
+
+        // Act
+        let (best, _) = builder()
+            .build()
+            .unwrap()
+            .train_top_n(network.clone(), &batch, &targets, &batch, &targets)
+            .unwrap();
+        let (checkpoints, history) = builder()
+            .top_n(3)
+            .build()
+            .unwrap()
+            .train_top_n(network, &batch, &targets, &batch, &targets)
+            .unwrap();
+
+        // Assert
+        assert_eq!(best.len(), 1);
+        assert!(!history.is_empty());
+        assert!(checkpoints.len() > 1);
+        assert!(checkpoints.len() <= 3);
+        assert_eq!(best[0].0, checkpoints[0].0);
+        assert!(checkpoints.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+        for (loss, mut network) in checkpoints {
+            let (_, output) = network.forward(batch.clone()).unwrap();
+            let (expected, _) = loss_function.loss(&output, &targets).unwrap();
+            assert_eq!(loss, expected.abs());
+        }
+    }
+
+    #[test]
+    fn test_fold_ranges() {
+        // Arrange
+        let expected = [0..3, 3..5, 5..7];
+
+        // Act
+        let output = fold_ranges(7, 3);
+
+        // Assert
+        assert_eq!(output, expected);
+        (0..7).for_each(|row| {
+            assert_eq!(output.iter().filter(|fold| fold.contains(&row)).count(), 1);
+        });
+    }
+
+    #[test]
+    fn test_cross_validate() {
+        // Arrange
+        let builder = || {
+            Input::new(2)
+                .chain(Dense::new(1, Linear::new()))
+                .with_seed(42)
+                .with_optimiser(SGDMomentum::new(
+                    LinearDecayLearningRateHandler::new(0.1, 0.01),
+                    0.9,
+                ))
+        };
+        let loss_function = MeanSquaredError::new();
+        let data = Tensor::<rank::Two>::new(
+            (20, 2),
+            StdRng::seed_from_u64(42).sample_iter(Standard).take(40),
+        )
+        .unwrap();
+        let targets = Tensor::<rank::Two>::new(
+            (20, 1),
+            data.0
+                .as_slice()
+                .unwrap()
+                .chunks(2)
+                .map(|slice| slice[0] - slice[1]),
+        )
+        .unwrap();
+
+        // Act
+        let scores =
+            cross_validate(builder, &loss_function, &data, &targets, 4, 50, 5, 42).unwrap();
+
+        // Assert
+        assert_eq!(scores.len(), 4);
+        assert!(scores.iter().all(|score| *score >= 0.0 && *score < 0.1));
+    }
+
+    #[test]
+    fn test_cross_validate_failure() {
+        // Arrange
+        let builder = || {
+            Input::new(1)
+                .chain(Dense::new(1, Linear::new()))
+                .with_seed(42)
+                .with_optimiser(SGDMomentum::new(
+                    LinearDecayLearningRateHandler::new(0.1, 0.01),
+                    0.9,
+                ))
+        };
+        let loss_function = MeanSquaredError::new();
+        let data = Tensor::<rank::Two>::new((3, 1), [1.0, 2.0, 3.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((3, 1), [1.0, 2.0, 3.0]).unwrap();
+
+        // Act
+        let too_few = cross_validate(builder, &loss_function, &data, &targets, 1, 10, 1, 42);
+        let too_many = cross_validate(builder, &loss_function, &data, &targets, 4, 10, 1, 42);
+
+        // Assert
+        assert!(too_few.is_err());
+        assert!(too_many.is_err());
+    }
+
+    #[test]
+    fn test_training_with_loader() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(SGD::new(FixedLearningRateHandler::new(0.01)));
+        let loss_function = MeanSquaredError::new();
+        let batch = Tensor::<rank::Two>::new((3, 2), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((3, 1), [3.0, 7.0, 11.0]).unwrap();
+        let loader = DataLoader::new(
+            TensorDataset::new(batch.clone(), targets.clone()).unwrap(),
+            2,
+        );
+        let (initial_loss, _) = loss_function
+            .loss(
+                &network
+                    .clone()
+                    .into_initialised()
+                    .predict(batch.clone())
+                    .unwrap(),
+                &targets,
+            )
+            .unwrap();
+
+        // Act
+        let (network, _) = Trainer::builder()
+            .loss(MeanSquaredError::new())
+            .epochs(20)
+            .eval_every(20)
+            .seed(42)
+            .build()
+            .unwrap()
+            .train_with_loader(network, &loader, &batch, &targets)
+            .unwrap();
+
+        // Assert
+        let output = network.into_initialised().predict(batch).unwrap();
+        let (loss, _) = loss_function.loss(&output, &targets).unwrap();
+        assert!(loss < initial_loss);
+    }
+
+    #[test]
+    fn test_training_with_loader_failure() {
+        // Arrange
+        struct Failing;
+        impl Dataset for Failing {
+            fn len(&self) -> usize {
+                1
+            }
+
+            fn get(&self, _index: usize) -> Result<crate::data::Sample> {
+                Err(Error::EmptyData)
+            }
+        }
+        let network = Input::new(1)
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(NullOptimiser::new());
+        let batch = Tensor::<rank::Two>::new((1, 1), [1.0]).unwrap();
+
+        // Act
+        let output = Trainer::builder()
+            .loss(MeanSquaredError::new())
+            .epochs(1)
+            .seed(42)
+            .build()
+            .unwrap()
+            .train_with_loader(network, &DataLoader::new(Failing, 1), &batch, &batch);
+
+        // Assert
+        assert!(matches!(output, Err(Error::EmptyData)));
+    }
+
+    #[test]
+    fn test_training_with_batches() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(SGD::new(FixedLearningRateHandler::new(0.01)));
+        let loss_function = MeanSquaredError::new();
+        let batch = Tensor::<rank::Two>::new((3, 2), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((3, 1), [3.0, 7.0, 11.0]).unwrap();
+        let mut epochs = Vec::new();
+
+        // Act
+        let (network, _) = Trainer::builder()
+            .loss(MeanSquaredError::new())
+            .epochs(3)
+            .eval_every(3)
+            .build()
+            .unwrap()
+            .train_with_batches(
+                network,
+                |epoch| {
+                    epochs.push(epoch);
+                    [
+                        (
+                            Tensor::<rank::Two>::new((1, 2), [1.0, 2.0]).unwrap(),
+                            Tensor::<rank::Two>::new((1, 1), [3.0]).unwrap(),
+                        ),
+                        (
+                            Tensor::<rank::Two>::new((2, 2), [3.0, 4.0, 5.0, 6.0]).unwrap(),
+                            Tensor::<rank::Two>::new((2, 1), [7.0, 11.0]).unwrap(),
+                        ),
+                    ]
+                },
+                &batch,
+                &targets,
+            )
+            .unwrap();
+
+        // Assert
+        let expected = train(
+            Input::new(2)
+                .chain(Dense::new(1, Linear::new()))
+                .with_seed(42)
+                .with_optimiser(SGD::new(FixedLearningRateHandler::new(0.01))),
+            &loss_function,
+            batch.clone(),
+            targets.clone(),
+            &batch,
+            &targets,
+            3,
+            3,
+            3,
+            42,
+        )
+        .unwrap();
+        assert_eq!(epochs, [0, 1, 2]);
+        assert_ne!(
+            network.clone().into_initialised(),
+            expected.into_initialised()
+        );
+        assert!(network
+            .into_initialised()
+            .iter()
+            .all(ElementType::is_finite));
+    }
+
+    #[test]
+    fn test_training_with_deterministic() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dropout::new(0.5))
+            .chain(Dense::new(1, Linear::new()))
+            .with_iter([1.0, 1.0, 0.0].into_iter())
+            .unwrap()
+            .with_optimiser(SGD::new(FixedLearningRateHandler::new(0.01)));
+        let batch = Tensor::<rank::Two>::new((3, 2), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((3, 1), [3.0, 7.0, 11.0]).unwrap();
+        let options = TrainingOptions::new()
+            .with_patience(u16::MAX)
+            .with_deterministic(42);
+        let train = |network| {
+            Trainer::builder()
+                .loss(MeanSquaredError::new())
+                .epochs(10)
+                .batch_size(1)
+                .seed(42)
+                .options(options)
+                .build()
+                .unwrap()
+                .train(network, batch.clone(), targets.clone(), &batch, &targets)
+                .unwrap()
+        };
+
+        // Act
+        let first = train(network.clone());
+        let second = train(network);
+
+        // Assert
+        assert_eq!(first, second);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_training_data_parallel() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(3, Tanh::new()))
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(SGD::new(FixedLearningRateHandler::new(0.01)));
+        let batch =
+            Tensor::<rank::Two>::new((5, 2), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0])
+                .unwrap();
+        let targets = Tensor::<rank::Two>::new((5, 1), [3.0, 7.0, 11.0, 15.0, 19.0]).unwrap();
+        let mut trainer = Trainer::builder()
+            .loss(MeanSquaredError::new())
+            .epochs(5)
+            .batch_size(5)
+            .seed(42)
+            .early_stopping(u16::MAX, 0.0)
+            .build()
+            .unwrap();
+        let expected = trainer
+            .train(
+                network.clone(),
+                batch.clone(),
+                targets.clone(),
+                &batch,
+                &targets,
+            )
+            .unwrap();
+
+        // Act
+        let (output, _) = trainer
+            .train_data_parallel(network, 3, &batch, &targets, &batch, &targets)
+            .unwrap();
+
+        // Assert
+        let expected = expected.into_initialised();
+        let output = output.into_initialised();
+        assert!(expected
+            .iter()
+            .zip(output.iter())
+            .all(|(expected, output)| (expected - output).abs() < 1e-4));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_training_data_parallel_shard_counts() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(3, Tanh::new()))
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(SGD::new(FixedLearningRateHandler::new(0.01)));
+        let batch = Tensor::<rank::Two>::new(
+            (8, 2),
+            [
+                0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0, 1.1, 1.2, 1.3, 1.4, 1.5, 1.6,
+            ],
+        )
+        .unwrap();
+        let targets =
+            Tensor::<rank::Two>::new((8, 1), [0.3, 0.7, 1.1, 1.5, 1.9, 2.3, 2.7, 3.1]).unwrap();
+        let mut trainer = Trainer::builder()
+            .loss(MeanSquaredError::new())
+            .epochs(5)
+            .batch_size(8)
+            .seed(42)
+            .early_stopping(u16::MAX, 0.0)
+            .build()
+            .unwrap();
+        let expected = trainer
+            .train(
+                network.clone(),
+                batch.clone(),
+                targets.clone(),
+                &batch,
+                &targets,
+            )
+            .unwrap()
+            .into_initialised();
+
+        // Act
+        let outputs = [1, 2, 4].map(|replicas| {
+            let (output, _) = trainer
+                .train_data_parallel(
+                    network.clone(),
+                    replicas,
+                    &batch,
+                    &targets,
+                    &batch,
+                    &targets,
+                )
+                .unwrap();
+            output.into_initialised()
+        });
+
+        // Assert
+        // floating point addition isn't associative, so splitting the batch regroups the sums over its rows and
+        // the updates can only be expected to match those of the whole batch to within rounding error.
+        let tolerance = 1e-5;
+        for output in outputs {
+            assert!(expected
+                .iter()
+                .zip(output.iter())
+                .all(|(expected, output)| (expected - output).abs() < tolerance));
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_training_data_parallel_sum_reduction() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(3, Tanh::new()))
+            .chain(Dense::new(2, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(SGD::new(FixedLearningRateHandler::new(0.01)));
+        let batch = Tensor::<rank::Two>::new(
+            (6, 2),
+            [0.1, 0.9, 0.8, 0.2, 0.3, 0.7, 0.9, 0.1, 0.2, 0.6, 0.7, 0.4],
+        )
+        .unwrap();
+        let targets = Tensor::<rank::Two>::new(
+            (6, 2),
+            [0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0],
+        )
+        .unwrap();
+        let mut trainer = Trainer::builder()
+            .loss(SoftmaxCrossEntropy::new())
+            .epochs(5)
+            .batch_size(6)
+            .seed(42)
+            .early_stopping(u16::MAX, 0.0)
+            .build()
+            .unwrap();
+        let expected = trainer
+            .train(
+                network.clone(),
+                batch.clone(),
+                targets.clone(),
+                &batch,
+                &targets,
+            )
+            .unwrap();
+
+        // Act
+        let (output, _) = trainer
+            .train_data_parallel(network, 3, &batch, &targets, &batch, &targets)
+            .unwrap();
+
+        // Assert
+        let expected = expected.into_initialised();
+        let output = output.into_initialised();
+        assert!(expected
+            .iter()
+            .zip(output.iter())
+            .all(|(expected, output)| (expected - output).abs() < 1e-4));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_training_data_parallel_mixed_reduction() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(3, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(SGD::new(FixedLearningRateHandler::new(0.01)));
+        let batch = Tensor::<rank::Two>::new((2, 2), [1.0, 2.0, 3.0, 4.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((2, 3), [0.0, 1.0, 0.5, 1.0, 0.0, 1.5]).unwrap();
+        let loss = MultiHead::new()
+            .with_head(SoftmaxCrossEntropy::new(), 2, 1.0)
+            .with_head(MeanSquaredError::new(), 1, 1.0);
+
+        // Act
+        let output = Trainer::builder()
+            .loss(loss)
+            .epochs(1)
+            .batch_size(2)
+            .seed(42)
+            .build()
+            .unwrap()
+            .train_data_parallel(network, 2, &batch, &targets, &batch, &targets);
+
+        // Assert
+        assert_eq!(output.map(|_| ()), Err(Error::InvalidArgument));
+    }
+
+    fn train_with_non_finite_batch(
+        non_finite: NonFiniteHandling,
+    ) -> Result<impl InitialisedOperation + PartialEq> {
+        let network = Input::new(2)
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(SGD::new(FixedLearningRateHandler::new(0.01)));
+        let batch = Tensor::<rank::Two>::new((1, 2), [1.0, 2.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((1, 1), [3.0]).unwrap();
+        let (network, _) = Trainer::builder()
+            .loss(MeanSquaredError::new())
+            .epochs(2)
+            .eval_every(2)
+            .options(TrainingOptions::new().with_non_finite(non_finite))
+            .build()?
+            .train_with_batches(
+                network,
+                |_| {
+                    [
+                        (batch.clone(), targets.clone()),
+                        (
+                            Tensor::<rank::Two>::new((1, 2), [ElementType::NAN, 2.0]).unwrap(),
+                            targets.clone(),
+                        ),
+                    ]
+                },
+                &batch,
+                &targets,
+            )?;
+        Ok(network.into_initialised())
+    }
+
+    #[test]
+    fn test_training_with_non_finite_abort() {
+        // Arrange
+        let non_finite = NonFiniteHandling::Abort;
+
+        // Act
+        let output = train_with_non_finite_batch(non_finite).map(|_| ());
+
+        // Assert
+        assert_eq!(output, Err(Error::NonFiniteValue { epoch: 0, batch: 1 }));
+    }
+
+    #[test]
+    fn test_training_with_non_finite_skip_batch() {
+        // Arrange
+        let non_finite = NonFiniteHandling::SkipBatch;
+
+        // Act
+        let network = train_with_non_finite_batch(non_finite).unwrap();
+
+        // Assert
+        assert!(network.iter().all(ElementType::is_finite));
+    }
+
+    #[test]
+    fn test_training_with_non_finite_skip_every_batch() {
+        // Arrange
+        let network = Input::new(2)
+            .chain(Dense::new(1, Linear::new()))
+            .with_seed(42)
+            .with_optimiser(SGD::new(FixedLearningRateHandler::new(0.01)));
+        let batch = Tensor::<rank::Two>::new((2, 2), [ElementType::NAN, 2.0, 3.0, 4.0]).unwrap();
+        let targets = Tensor::<rank::Two>::new((2, 1), [3.0, 7.0]).unwrap();
+        let batch_test = Tensor::<rank::Two>::new((1, 2), [3.0, 4.0]).unwrap();
+        let targets_test = Tensor::<rank::Two>::new((1, 1), [7.0]).unwrap();
+
+        // Act
+        let (_, history) = Trainer::builder()
+            .loss(MeanSquaredError::new())
+            .epochs(2)
+            .batch_size(2)
+            .seed(42)
+            .options(TrainingOptions::new().with_non_finite(NonFiniteHandling::SkipBatch))
+            .early_stopping(u16::MAX, 0.0)
+            .build()
+            .unwrap()
+            .train_with_history(network, batch, targets, &batch_test, &targets_test)
+            .unwrap();
+
+        // Assert
+        assert_eq!(history.training_losses(), [None, None]);
+        assert!(history
+            .evaluation_losses()
+            .iter()
+            .all(|loss| loss.is_some_and(ElementType::is_finite)));
+    }
+
+    #[test]
+    fn test_training_with_non_finite_ignore() {
+        // Arrange
+        let non_finite = NonFiniteHandling::Ignore;
+
+        // Act
+        let network = train_with_non_finite_batch(non_finite).unwrap();
+
+        // Assert
+        assert!(network.iter().all(ElementType::is_nan));
+    }
+}
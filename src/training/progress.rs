@@ -0,0 +1,165 @@
+use crate::loss::rows_to_element;
+use crate::training::Callback;
+use crate::ElementType;
+use alloc::string::String;
+use core::fmt::Write as _;
+use core::ops::ControlFlow;
+use std::io::{Stderr, Write};
+use std::time::Instant;
+
+/// A callback which reports the progress of training, so that long training runs aren't silent. After each batch
+/// the current line is rewritten with the epoch, the number of batches trained on so far in the epoch and the loss
+/// of the batch. At the end of each epoch the line is finished with the mean loss of the epoch, the evaluation loss
+/// (if the network was evaluated) and an estimate of the time remaining, based on how long the epochs so far took.
+///
+/// Progress never stops training, and any errors writing the progress are ignored.
+#[derive(Debug)]
+pub struct Progress<W = Stderr> {
+    writer: W,
+    epochs: u32,
+    started: Option<(Instant, u32)>, // when the first epoch started, and its number
+    batches: usize,
+    total_loss: ElementType,
+    line_length: usize,
+}
+
+impl Progress {
+    /// Constructs a new instance of the callback which reports the progress to standard error, for training
+    /// which runs up to the given number of epochs.
+    #[must_use]
+    pub fn new(epochs: u32) -> Self {
+        Self::with_writer(epochs, std::io::stderr())
+    }
+}
+
+impl<W: Write> Progress<W> {
+    /// Constructs a new instance of the callback which reports the progress to the given writer, for training
+    /// which runs up to the given number of epochs.
+    #[must_use]
+    pub const fn with_writer(epochs: u32, writer: W) -> Self {
+        Self {
+            writer,
+            epochs,
+            started: None,
+            batches: 0,
+            total_loss: 0.0,
+            line_length: 0,
+        }
+    }
+
+    /// Consumes the callback to get back the writer that the progress was reported to.
+    pub fn into_writer(self) -> W {
+        self.writer
+    }
+
+    /// Rewrites the current line with the given line, padding it to cover the whole of the previous line.
+    fn rewrite(&mut self, line: &str) {
+        let width = self.line_length;
+        self.line_length = line.len();
+        write!(self.writer, "\r{line:<width$}").ok();
+        self.writer.flush().ok();
+    }
+}
+
+impl<W: Write> Callback for Progress<W> {
+    fn on_epoch_start(&mut self, epoch: u32) -> ControlFlow<()> {
+        self.started.get_or_insert_with(|| (Instant::now(), epoch));
+        self.batches = 0;
+        self.total_loss = 0.0;
+        ControlFlow::Continue(())
+    }
+
+    fn on_batch_end(&mut self, epoch: u32, batch_loss: ElementType) -> ControlFlow<()> {
+        self.batches += 1;
+        self.total_loss += batch_loss;
+        let line = format!(
+            "epoch {}/{} batch {} loss {batch_loss:.6}",
+            epoch + 1,
+            self.epochs,
+            self.batches
+        );
+        self.rewrite(&line);
+        ControlFlow::Continue(())
+    }
+
+    fn on_epoch_end(
+        &mut self,
+        epoch: u32,
+        evaluation_loss: Option<ElementType>,
+    ) -> ControlFlow<()> {
+        let mut line = format!("epoch {}/{}", epoch + 1, self.epochs);
+        if self.batches > 0 {
+            let loss = self.total_loss / rows_to_element(self.batches);
+            write!(line, " loss {loss:.6}").ok();
+        }
+        if let Some(evaluation_loss) = evaluation_loss {
+            write!(line, " evaluation loss {evaluation_loss:.6}").ok();
+        }
+        if let Some((started, first_epoch)) = self.started {
+            let completed = epoch + 1 - first_epoch.min(epoch);
+            let remaining = self.epochs.saturating_sub(epoch + 1);
+            let eta = started.elapsed() / completed * remaining;
+            write!(line, " eta {}", format_duration(eta.as_secs())).ok();
+        }
+        self.rewrite(&line);
+        writeln!(self.writer).ok();
+        self.line_length = 0;
+        ControlFlow::Continue(())
+    }
+}
+
+/// Formats the given number of seconds as hours, minutes and seconds.
+fn format_duration(seconds: u64) -> String {
+    format!(
+        "{}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_progress() {
+        // Arrange
+        let mut progress = Progress::with_writer(2, Vec::new());
+
+        // Act
+        for epoch in 0..2 {
+            let _ = progress.on_epoch_start(epoch);
+            let _ = progress.on_batch_end(epoch, 1.5);
+            let _ = progress.on_batch_end(epoch, 0.5);
+            let _ = progress.on_epoch_end(epoch, (epoch == 1).then_some(0.25));
+        }
+        let output = String::from_utf8(progress.into_writer()).unwrap();
+
+        // Assert
+        let lines = output
+            .lines()
+            .map(|line| line.rsplit('\r').next().unwrap().trim_end())
+            .collect::<Vec<_>>();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("epoch 1/2 loss 1.000000 eta "));
+        assert_eq!(
+            lines[1],
+            "epoch 2/2 loss 1.000000 evaluation loss 0.250000 eta 0:00:00"
+        );
+        assert!(output.contains("\repoch 1/2 batch 2 loss 0.500000"));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        // Arrange
+        let seconds = 2 * 3600 + 3 * 60 + 4;
+
+        // Act
+        let output = format_duration(seconds);
+
+        // Assert
+        assert_eq!(output, "2:03:04");
+    }
+}
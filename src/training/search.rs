@@ -0,0 +1,240 @@
+use crate::loss::Loss;
+use crate::operations::{Forward, InitialisedOperation, TrainableOperation};
+use crate::tensors::{rank, Tensor};
+use crate::training::Trainer;
+use crate::{ElementType, Error, Result};
+use alloc::vec::Vec;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// The outcome of a hyperparameter search, holding the parameters of each trial along with the loss of its trained
+/// network against the validation data, and the trained network of the best trial.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SearchResult<P, N> {
+    trials: Vec<(P, ElementType)>,
+    best: usize,
+    network: N,
+}
+
+impl<P, N> SearchResult<P, N> {
+    /// Gets the parameters of the trial whose network had the lowest validation loss.
+    #[must_use]
+    pub fn best_parameters(&self) -> &P {
+        &self.trials[self.best].0
+    }
+
+    /// Gets the lowest validation loss of any trial.
+    #[must_use]
+    pub fn best_loss(&self) -> ElementType {
+        self.trials[self.best].1
+    }
+
+    /// Gets the parameters and validation loss of every trial, in the order they were run.
+    #[must_use]
+    pub fn trials(&self) -> &[(P, ElementType)] {
+        &self.trials
+    }
+
+    /// Consumes the result to get the trained network of the best trial, in the initialised typestate.
+    pub fn into_network(self) -> N {
+        self.network
+    }
+}
+
+/// Builds a grid of parameters from the values of two hyperparameters, containing every combination of them. Grids of
+/// more hyperparameters can be built by nesting calls, or by collecting the candidates in any other way.
+#[must_use]
+pub fn grid<A: Clone, B: Clone>(lhs: &[A], rhs: &[B]) -> Vec<(A, B)> {
+    lhs.iter()
+        .flat_map(|lhs| rhs.iter().map(move |rhs| (lhs.clone(), rhs.clone())))
+        .collect()
+}
+
+/// Draws the given number of random parameters for a random search, by calling the given function with a
+/// generator seeded from the given seed.
+pub fn random<P>(trials: usize, seed: u64, mut sample: impl FnMut(&mut StdRng) -> P) -> Vec<P> {
+    let mut random_generator = StdRng::seed_from_u64(seed);
+    (0..trials).map(|_| sample(&mut random_generator)).collect()
+}
+
+/// Searches for the best hyperparameters from the given candidates, such as from `grid` or `random`. For each
+/// candidate, the given function builds a network ready for training, usually by cloning an uninitialised chain,
+/// initialising it and binding an optimiser configured from the parameters. The network is trained with the given
+/// trainer (which should usually have a small number of epochs, or a budget of steps), and its loss is measured
+/// against the validation data without any regularisation penalty, so that candidates with different amounts of
+/// regularisation can be compared fairly. A trial whose loss is NaN is never the best, unless every trial's is.
+///
+/// ```
+/// use eidetic::activations::Linear;
+/// use eidetic::layers::{Chain, Dense, Input};
+/// use eidetic::loss::MeanSquaredError;
+/// use eidetic::operations::{UninitialisedOperation, WithOptimiser};
+/// use eidetic::optimisers::learning_rate_handlers::FixedLearningRateHandler;
+/// use eidetic::optimisers::SGDMomentum;
+/// use eidetic::tensors::{rank, Tensor};
+/// use eidetic::training::{grid, search, Trainer};
+/// let network = Input::new(1).chain(Dense::new(1, Linear::new()));
+/// let batch = Tensor::<rank::Two>::new((4, 1), [0.1, 0.2, 0.3, 0.4]).unwrap();
+/// let targets = Tensor::<rank::Two>::new((4, 1), [0.3, 0.5, 0.7, 0.9]).unwrap();
+/// let mut trainer = Trainer::builder()
+///     .loss(MeanSquaredError::new())
+///     .epochs(10)
+///     .batch_size(2)
+///     .build()
+///     .unwrap();
+/// let result = search(
+///     &mut trainer,
+///     grid(&[0.001, 0.1], &[0.0, 0.9]),
+///     |&(learning_rate, momentum)| {
+///         network
+///             .clone()
+///             .with_seed(42)
+///             .with_optimiser(SGDMomentum::new(
+///                 FixedLearningRateHandler::new(learning_rate),
+///                 momentum,
+///             ))
+///     },
+///     &batch,
+///     &targets,
+///     &batch,
+///     &targets,
+/// )
+/// .unwrap();
+/// assert_eq!(result.trials().len(), 4);
+/// ```
+///
+/// # Errors
+/// Returns `Error::EmptyData` if there are no candidates, or any other `eidetic::Error` that training
+/// one of the networks returns.
+pub fn search<P, N, L>(
+    trainer: &mut Trainer<'_, L>,
+    candidates: impl IntoIterator<Item = P>,
+    mut build: impl FnMut(&P) -> N,
+    batch_train: &Tensor<rank::Two>,
+    targets_train: &Tensor<rank::Two>,
+    batch_validation: &Tensor<rank::Two>,
+    targets_validation: &Tensor<rank::Two>,
+) -> Result<SearchResult<P, N::Initialised>>
+where
+    for<'b> N: TrainableOperation
+        + Forward<'b, Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>
+        + Clone,
+    N::Initialised: InitialisedOperation<Input = Tensor<rank::Two>, Output = Tensor<rank::Two>>,
+    L: Loss,
+{
+    let mut trials = Vec::new();
+    let mut best: Option<(usize, ElementType, N::Initialised)> = None;
+    for parameters in candidates {
+        let network = trainer
+            .train(
+                build(&parameters),
+                batch_train.clone(),
+                targets_train.clone(),
+                batch_validation,
+                targets_validation,
+            )?
+            .into_initialised();
+        let output = network.predict(batch_validation.clone())?;
+        let (loss, _) = trainer.loss_function.loss(&output, targets_validation)?;
+        if best.as_ref().is_none_or(|(_, best_loss, _)| {
+            loss < *best_loss || (best_loss.is_nan() && !loss.is_nan())
+        }) {
+            best = Some((trials.len(), loss, network));
+        }
+        trials.push((parameters, loss));
+    }
+    let (best, _, network) = best.ok_or(Error::EmptyData)?;
+    Ok(SearchResult {
+        trials,
+        best,
+        network,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activations::Linear;
+    use crate::layers::{Chain, Dense, Input};
+    use crate::loss::MeanSquaredError;
+    use crate::operations::{UninitialisedOperation, WithOptimiser};
+    use crate::optimisers::learning_rate_handlers::FixedLearningRateHandler;
+    use crate::optimisers::SGD;
+    use rand::Rng;
+
+    #[test]
+    fn test_grid() {
+        // Arrange
+        let lhs = [1, 2];
+        let rhs = ['a', 'b', 'c'];
+
+        // Act
+        let output = grid(&lhs, &rhs);
+
+        // Assert
+        assert_eq!(
+            output,
+            [(1, 'a'), (1, 'b'), (1, 'c'), (2, 'a'), (2, 'b'), (2, 'c')]
+        );
+    }
+
+    #[test]
+    fn test_random() {
+        // Arrange
+        let sample = |random_generator: &mut StdRng| random_generator.gen_range(0.0..1.0);
+
+        // Act
+        let first = random(5, 42, sample);
+        let second = random(5, 42, sample);
+
+        // Assert
+        assert_eq!(first.len(), 5);
+        assert_eq!(first, second);
+        assert!(first
+            .iter()
+            .all(|&value: &ElementType| (0.0..1.0).contains(&value)));
+    }
+
+    #[test]
+    fn test_search() {
+        // Arrange
+        let network = Input::new(1).chain(Dense::new(1, Linear::new()));
+        let batch = Tensor::<rank::Two>::new((4, 1), [0.1, 0.2, 0.3, 0.4]).unwrap();
+        let targets = Tensor::<rank::Two>::new((4, 1), [0.3, 0.5, 0.7, 0.9]).unwrap();
+        let mut trainer = Trainer::builder()
+            .loss(MeanSquaredError::new())
+            .epochs(50)
+            .batch_size(2)
+            .early_stopping(u16::MAX, 0.0)
+            .build()
+            .unwrap();
+
+        // Act
+        let result = search(
+            &mut trainer,
+            [0.0, 0.5, 0.0001],
+            |&learning_rate| {
+                network
+                    .clone()
+                    .with_seed(42)
+                    .with_optimiser(SGD::new(FixedLearningRateHandler::new(learning_rate)))
+            },
+            &batch,
+            &targets,
+            &batch,
+            &targets,
+        )
+        .unwrap();
+
+        // Assert
+        assert_eq!(result.trials().len(), 3);
+        assert_eq!(*result.best_parameters(), 0.5);
+        assert_eq!(result.best_loss(), result.trials()[1].1);
+        assert!(result.trials()[1].1 < result.trials()[2].1);
+        let best_loss = result.best_loss();
+        let (loss, _) = MeanSquaredError::new()
+            .loss(&result.into_network().predict(batch).unwrap(), &targets)
+            .unwrap();
+        assert_eq!(loss, best_loss);
+    }
+}